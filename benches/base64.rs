@@ -1,3 +1,5 @@
+#![allow(clippy::unit_arg)]
+
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
 
 use draco_utilities::base64::{decode_base64, encode_base64};
@@ -1,8 +1,9 @@
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
 
-use draco_utilities::globals::decode_uri;
+use draco_utilities::globals::utils::{decode_code_point, hex_value};
+use draco_utilities::globals::{decode_uri, decode_uri_component};
 
-criterion_group!(benches, decode_group);
+criterion_group!(benches, decode_group, decode_long_group);
 criterion_main!(benches);
 
 static ENCODED_STRING: &str =
@@ -22,3 +23,99 @@ fn decode_group(c: &mut Criterion) {
 
   group.finish();
 }
+
+/// A long encoded URL with few escapes.
+fn long_string() -> String {
+  "https://example.com/articles/2021/some-long-article-title_with-many-words?page=2&sort=asc%2F"
+    .repeat(24)
+    + "%C3%BCn%C3%AFc%C3%B6d%C3%A9"
+}
+
+/// Reads the byte escaped as `%XX` at `idx`.
+fn escaped_byte(bytes: &[u8], idx: usize) -> Option<u8> {
+  match bytes.get(idx..idx + 3) {
+    Some(&[b'%', one, two]) if hex_value(one, two) <= u8::MAX as u32 => {
+      Some(hex_value(one, two) as u8)
+    }
+    _ => None,
+  }
+}
+
+/// The scalar baseline of `decode_uri_component`, which validates and finds escapes one byte at
+/// a time instead of skipping runs of bytes with SIMD.
+fn decode_uri_component_scalar(bytes: &[u8], decoded: &mut Vec<u8>) -> bool {
+  let mut idx = 0;
+
+  while idx < bytes.len() {
+    let byte = bytes[idx];
+
+    if byte != b'%' {
+      let length = match decode_code_point(&bytes[idx..], false) {
+        Ok((_, length)) => length,
+        Err(_) => return false,
+      };
+
+      decoded.extend_from_slice(&bytes[idx..idx + length]);
+      idx += length;
+      continue;
+    }
+
+    let mut octets = [0; 4];
+    let lead = match escaped_byte(bytes, idx) {
+      Some(lead) => lead,
+      None => return false,
+    };
+    let length = match lead {
+      0x00..=0x7F => 1,
+      0xC0..=0xDF => 2,
+      0xE0..=0xEF => 3,
+      _ => 4,
+    };
+
+    for (octet, value) in octets[..length].iter_mut().enumerate() {
+      match escaped_byte(bytes, idx + octet * 3) {
+        Some(byte) => *value = byte,
+        None => return false,
+      }
+    }
+
+    if decode_code_point(&octets[..length], false).is_err() {
+      return false;
+    }
+
+    decoded.extend_from_slice(&octets[..length]);
+    idx += length * 3;
+  }
+
+  true
+}
+
+fn decode_long_group(c: &mut Criterion) {
+  let string = long_string();
+  let mut group = c.benchmark_group("decode long string");
+
+  group.throughput(Throughput::Bytes(string.len() as u64));
+  group.bench_function("decode_uri", |b| {
+    b.iter_batched(
+      || Vec::with_capacity(string.len()),
+      |mut bytes| black_box(decode_uri(string.as_bytes(), &mut bytes)),
+      BatchSize::SmallInput,
+    )
+  });
+  group.bench_function("decode_uri_component", |b| {
+    b.iter_batched(
+      || Vec::with_capacity(string.len()),
+      |mut bytes| black_box(decode_uri_component(string.as_bytes(), &mut bytes)),
+      BatchSize::SmallInput,
+    )
+  });
+  group.bench_function("decode_uri_component scalar", |b| {
+    b.iter_batched(
+      || Vec::with_capacity(string.len()),
+      |mut bytes| black_box(decode_uri_component_scalar(string.as_bytes(), &mut bytes)),
+      BatchSize::SmallInput,
+    )
+  });
+
+  group.finish();
+}
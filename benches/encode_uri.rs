@@ -1,13 +1,14 @@
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
 
-use draco_utilities::globals::encode_uri;
+use draco_utilities::globals::utils::{byte_to_hex, decode_code_point};
+use draco_utilities::globals::{encode_uri, encode_uri_component, AsciiSet};
 
-criterion_group!(benches, decode_group);
+criterion_group!(benches, encode_group, encode_long_group);
 criterion_main!(benches);
 
 static ENCODED_STRING: &str = "https://developer.mozilla.org/ru/docs/JavaScript_шеллы";
 
-fn decode_group(c: &mut Criterion) {
+fn encode_group(c: &mut Criterion) {
   let mut group = c.benchmark_group("encode string");
 
   group.throughput(Throughput::Bytes(ENCODED_STRING.len() as u64));
@@ -21,3 +22,76 @@ fn decode_group(c: &mut Criterion) {
 
   group.finish();
 }
+
+/// A long URL that's mostly made of characters that aren't encoded.
+fn long_string() -> String {
+  "https://example.com/articles/2021/some-long-article-title_with-many-words?page=2&sort=asc/"
+    .repeat(24)
+    + "ünïcödé"
+}
+
+const COMPONENT_SET: AsciiSet =
+  AsciiSet::from_bytes(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!'()*-._~")
+    .complement();
+
+/// The scalar baseline of `encode_uri_component`, which validates and classifies one byte at a
+/// time instead of skipping runs of bytes with SIMD.
+fn encode_uri_component_scalar(bytes: &[u8], encoded: &mut Vec<u8>) -> bool {
+  let mut idx = 0;
+
+  while idx < bytes.len() {
+    let byte = bytes[idx];
+    let length = if byte < 0x80 {
+      1
+    } else {
+      match decode_code_point(&bytes[idx..], false) {
+        Ok((_, length)) => length,
+        Err(_) => return false,
+      }
+    };
+
+    for &byte in &bytes[idx..idx + length] {
+      if byte < 0x80 && !COMPONENT_SET.contains(byte) {
+        encoded.push(byte);
+      } else {
+        let [one, two] = byte_to_hex(byte);
+
+        encoded.extend_from_slice(&[b'%', one, two]);
+      }
+    }
+
+    idx += length;
+  }
+
+  true
+}
+
+fn encode_long_group(c: &mut Criterion) {
+  let string = long_string();
+  let mut group = c.benchmark_group("encode long string");
+
+  group.throughput(Throughput::Bytes(string.len() as u64));
+  group.bench_function("encode_uri", |b| {
+    b.iter_batched(
+      || Vec::with_capacity(string.len() * 2),
+      |mut bytes| black_box(encode_uri(string.as_bytes(), &mut bytes)),
+      BatchSize::SmallInput,
+    )
+  });
+  group.bench_function("encode_uri_component", |b| {
+    b.iter_batched(
+      || Vec::with_capacity(string.len() * 2),
+      |mut bytes| black_box(encode_uri_component(string.as_bytes(), &mut bytes)),
+      BatchSize::SmallInput,
+    )
+  });
+  group.bench_function("encode_uri_component scalar", |b| {
+    b.iter_batched(
+      || Vec::with_capacity(string.len() * 2),
+      |mut bytes| black_box(encode_uri_component_scalar(string.as_bytes(), &mut bytes)),
+      BatchSize::SmallInput,
+    )
+  });
+
+  group.finish();
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::unit_arg, clippy::excessive_precision)]
+
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
 
 use draco_utilities::number;
@@ -26,7 +26,7 @@ fn gen_rng_group(c: &mut Criterion) {
 
   group.bench_function("gen u128", |b| {
     b.iter_batched(
-      || Rng::new(),
+      Rng::new,
       |mut rng| {
         for _ in 0..AMOUNT {
           black_box(rng.gen_u128());
@@ -37,7 +37,7 @@ fn gen_rng_group(c: &mut Criterion) {
   });
   group.bench_function("gen u64", |b| {
     b.iter_batched(
-      || Rng::new(),
+      Rng::new,
       |mut rng| {
         for _ in 0..AMOUNT {
           black_box(rng.gen_u64());
@@ -48,7 +48,7 @@ fn gen_rng_group(c: &mut Criterion) {
   });
   group.bench_function("gen u32", |b| {
     b.iter_batched(
-      || Rng::new(),
+      Rng::new,
       |mut rng| {
         for _ in 0..AMOUNT {
           black_box(rng.gen_u32());
@@ -59,7 +59,7 @@ fn gen_rng_group(c: &mut Criterion) {
   });
   group.bench_function("gen u32 [0, n)", |b| {
     b.iter_batched(
-      || Rng::new(),
+      Rng::new,
       |mut rng| {
         for n in 0..AMOUNT {
           black_box(rng.gen_u32_in_range(0..n as u32));
@@ -70,7 +70,7 @@ fn gen_rng_group(c: &mut Criterion) {
   });
   group.bench_function("gen f32", |b| {
     b.iter_batched(
-      || Rng::new(),
+      Rng::new,
       |mut rng| {
         for _ in 0..AMOUNT {
           black_box(rng.gen_f32());
@@ -81,7 +81,7 @@ fn gen_rng_group(c: &mut Criterion) {
   });
   group.bench_function("gen f64", |b| {
     b.iter_batched(
-      || Rng::new(),
+      Rng::new,
       |mut rng| {
         for _ in 0..AMOUNT {
           black_box(rng.gen_f64());
@@ -1,3 +1,5 @@
+#![allow(clippy::unit_arg)]
+
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
 
 use draco_utilities::globals::unescape;
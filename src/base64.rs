@@ -27,20 +27,98 @@
 // NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
 // SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use crate::sink::{push_ascii, ByteSink, Sink};
+
+use std::fmt;
+
 /// An error when decoding a base64 encoded string.
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
-pub enum DecodeError {
+pub struct DecodeError {
+  kind: DecodeErrorKind,
+  index: usize,
+  sequence: [u8; 4],
+  sequence_length: u8,
+}
+
+/// The kind of a [`DecodeError`].
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum DecodeErrorKind {
   /// Not enough bytes were provided or it wasn't a multiple of 4.
   InvalidLength,
-  /// An invalid content was provided.
-  InvalidContent,
+  /// A character outside of the alphabet was encountered.
+  InvalidCharacter,
+  /// The unused bits of the last chunk weren't zero.
+  TrailingBits,
+}
+
+impl DecodeError {
+  /// Creates an error of the given kind for the sequence found at `index`.
+  ///
+  /// # Notes
+  /// Only the first 4 bytes of the sequence are kept.
+  pub fn new(kind: DecodeErrorKind, index: usize, sequence: &[u8]) -> Self {
+    let length = sequence.len().min(4);
+    let mut buffer = [0; 4];
+
+    buffer[..length].copy_from_slice(&sequence[..length]);
+
+    DecodeError {
+      kind,
+      index,
+      sequence: buffer,
+      sequence_length: length as u8,
+    }
+  }
+
+  /// The kind of error.
+  #[inline]
+  pub const fn kind(&self) -> DecodeErrorKind {
+    self.kind
+  }
+
+  /// The byte index of the offending sequence in the input.
+  #[inline]
+  pub const fn index(&self) -> usize {
+    self.index
+  }
+
+  /// The offending sequence, which is the invalid character, the last chunk or the bytes left
+  /// over after the last whole chunk.
+  #[inline]
+  pub fn sequence(&self) -> &[u8] {
+    &self.sequence[..self.sequence_length as usize]
+  }
+}
+
+impl fmt::Display for DecodeErrorKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      DecodeErrorKind::InvalidLength => "invalid length",
+      DecodeErrorKind::InvalidCharacter => "invalid character",
+      DecodeErrorKind::TrailingBits => "non-zero trailing bits",
+    })
+  }
+}
+
+impl fmt::Display for DecodeError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} `{}` at index {}",
+      self.kind,
+      self.sequence().escape_ascii(),
+      self.index
+    )
+  }
 }
 
+impl std::error::Error for DecodeError {}
+
 const PADDING_CHAR: u8 = b'=';
 const INVALID_CHAR: u32 = 0x01FFFFFF;
 
 /// Encodes a base64 string.
-pub fn encode_base64(bytes: &[u8], encoded: &mut Vec<u8>) {
+pub fn encode_base64<S: Sink + ?Sized>(bytes: &[u8], encoded: &mut S) {
   let length = bytes.len();
   let mut idx = 0;
 
@@ -53,12 +131,15 @@ pub fn encode_base64(bytes: &[u8], encoded: &mut Vec<u8>) {
           _ => std::hint::unreachable_unchecked(),
         };
 
-        encoded.extend_from_slice(&[
-          E0[one as usize],
-          E1[(((one & 0x03) << 4) | ((two >> 4) & 0x0F)) as usize],
-          E1[(((two & 0x0F) << 2) | ((three >> 6) & 0x03)) as usize],
-          E2[three as usize],
-        ]);
+        push_ascii(
+          encoded,
+          &[
+            E0[one as usize],
+            E1[(((one & 0x03) << 4) | ((two >> 4) & 0x0F)) as usize],
+            E1[(((two & 0x0F) << 2) | ((three >> 6) & 0x03)) as usize],
+            E2[three as usize],
+          ],
+        );
 
         idx += 3;
       }
@@ -69,23 +150,29 @@ pub fn encode_base64(bytes: &[u8], encoded: &mut Vec<u8>) {
       1 => {
         let one = *bytes.get_unchecked(idx);
 
-        encoded.extend_from_slice(&[
-          E0[one as usize],
-          E1[((one & 0x03) << 4) as usize],
-          PADDING_CHAR,
-          PADDING_CHAR,
-        ]);
+        push_ascii(
+          encoded,
+          &[
+            E0[one as usize],
+            E1[((one & 0x03) << 4) as usize],
+            PADDING_CHAR,
+            PADDING_CHAR,
+          ],
+        );
       }
       2 => {
         let one = *bytes.get_unchecked(idx);
         let two = *bytes.get_unchecked(idx + 1);
 
-        encoded.extend_from_slice(&[
-          E0[one as usize],
-          E1[(((one & 0x03) << 4) | ((two >> 4) & 0x0F)) as usize],
-          E2[((two & 0x0F) << 2) as usize],
-          PADDING_CHAR,
-        ]);
+        push_ascii(
+          encoded,
+          &[
+            E0[one as usize],
+            E1[(((one & 0x03) << 4) | ((two >> 4) & 0x0F)) as usize],
+            E2[((two & 0x0F) << 2) as usize],
+            PADDING_CHAR,
+          ],
+        );
       }
       // SAFETY: Other arms would've been reached by now.
       _ => std::hint::unreachable_unchecked(),
@@ -94,11 +181,14 @@ pub fn encode_base64(bytes: &[u8], encoded: &mut Vec<u8>) {
 }
 
 /// Decodes a base64 encoded string.
-pub fn decode_base64(bytes: &[u8], decoded: &mut Vec<u8>) -> Result<(), DecodeError> {
+pub fn decode_base64<S: ByteSink + ?Sized>(
+  bytes: &[u8],
+  decoded: &mut S,
+) -> Result<(), DecodeError> {
   let mut length = bytes.len();
 
-  if length < 4 || length % 4 != 0 {
-    return Err(DecodeError::InvalidLength);
+  if length < 4 || !length.is_multiple_of(4) {
+    return Err(invalid_length(bytes));
   }
 
   if bytes[length - 1] == PADDING_CHAR {
@@ -122,10 +212,10 @@ pub fn decode_base64(bytes: &[u8], decoded: &mut Vec<u8>) -> Result<(), DecodeEr
       let total = D0[one as usize] | D1[two as usize] | D2[three as usize] | D3[four as usize];
 
       if total >= INVALID_CHAR {
-        return Err(DecodeError::InvalidContent);
+        return Err(invalid_character(bytes, idx));
       }
 
-      decoded.extend_from_slice(&[
+      decoded.push_bytes(&[
         (total & 0xFF) as u8,
         ((total & 0xFF00) >> 8) as u8,
         ((total & 0xFF0000) >> 16) as u8,
@@ -138,13 +228,13 @@ pub fn decode_base64(bytes: &[u8], decoded: &mut Vec<u8>) -> Result<(), DecodeEr
       0 => return Ok(()),
       1 => {
         let total = D0[*bytes.get_unchecked(idx) as usize];
-        decoded.push(total as u8);
+        decoded.push_bytes(&[total as u8]);
         total
       }
       2 => {
         let total =
           D0[*bytes.get_unchecked(idx) as usize] | D1[*bytes.get_unchecked(idx + 1) as usize];
-        decoded.push(total as u8);
+        decoded.push_bytes(&[total as u8]);
         total
       }
       3 => {
@@ -152,7 +242,7 @@ pub fn decode_base64(bytes: &[u8], decoded: &mut Vec<u8>) -> Result<(), DecodeEr
           | D1[*bytes.get_unchecked(idx + 1) as usize]
           | D2[*bytes.get_unchecked(idx + 2) as usize];
 
-        decoded.extend_from_slice(&[(total & 0xFF) as u8, ((total & 0xFF00) >> 8) as u8]);
+        decoded.push_bytes(&[(total & 0xFF) as u8, ((total & 0xFF00) >> 8) as u8]);
         total
       }
       // SAFETY: `rem % 4` would've reached the other arms.
@@ -160,13 +250,246 @@ pub fn decode_base64(bytes: &[u8], decoded: &mut Vec<u8>) -> Result<(), DecodeEr
     };
 
     if total >= INVALID_CHAR {
-      return Err(DecodeError::InvalidContent);
+      return Err(invalid_character(bytes, idx));
     }
   }
 
   Ok(())
 }
 
+/// A base64 alphabet, mapping 6 bit values to characters and back.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Alphabet {
+  symbols: [u8; 64],
+  values: [u8; 256],
+}
+
+/// The standard base64 alphabet, as defined in
+/// [RFC 4648 §4](https://datatracker.ietf.org/doc/html/rfc4648#section-4).
+pub const STANDARD: Alphabet =
+  Alphabet::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/");
+
+/// The URL and filename safe base64 alphabet, as defined in
+/// [RFC 4648 §5](https://datatracker.ietf.org/doc/html/rfc4648#section-5).
+pub const URL_SAFE: Alphabet =
+  Alphabet::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_");
+
+/// The modified base64 alphabet used for IMAP mailbox names, as defined in
+/// [RFC 3501 §5.1.3](https://datatracker.ietf.org/doc/html/rfc3501#section-5.1.3).
+pub const IMAP: Alphabet =
+  Alphabet::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+,");
+
+const INVALID_VALUE: u8 = 0xFF;
+
+impl Alphabet {
+  /// Creates an alphabet from 64 characters, ordered by the value they represent.
+  ///
+  /// # Panics
+  /// This function panics if a character isn't ASCII, if a character is repeated or if the padding
+  /// character, `=`, is used.
+  pub const fn new(symbols: &[u8; 64]) -> Self {
+    let mut values = [INVALID_VALUE; 256];
+    let mut idx = 0;
+
+    while idx < symbols.len() {
+      let symbol = symbols[idx];
+
+      assert!(symbol.is_ascii(), "alphabet characters must be ASCII");
+      assert!(
+        symbol != PADDING_CHAR,
+        "the padding character can't be in an alphabet"
+      );
+      assert!(
+        values[symbol as usize] == INVALID_VALUE,
+        "alphabet characters must be unique"
+      );
+
+      values[symbol as usize] = idx as u8;
+      idx += 1;
+    }
+
+    Alphabet {
+      symbols: *symbols,
+      values,
+    }
+  }
+
+  /// Returns the characters of the alphabet, ordered by the value they represent.
+  #[inline]
+  pub const fn symbols(&self) -> &[u8; 64] {
+    &self.symbols
+  }
+
+  /// Returns the 6 bit value of a character, or [`None`] if it's not part of the alphabet.
+  #[inline]
+  pub const fn value(&self, byte: u8) -> Option<u8> {
+    match self.values[byte as usize] {
+      INVALID_VALUE => None,
+      value => Some(value),
+    }
+  }
+}
+
+/// Encodes a base64 string using the given alphabet.
+///
+/// # Notes
+/// When `padding` is `false`, the trailing `=` characters are omitted.
+pub fn encode_base64_with<S: Sink + ?Sized>(
+  bytes: &[u8],
+  alphabet: &Alphabet,
+  padding: bool,
+  encoded: &mut S,
+) {
+  let symbols = &alphabet.symbols;
+  let mut chunks = bytes.chunks_exact(3);
+
+  for chunk in &mut chunks {
+    let total = (chunk[0] as u32) << 16 | (chunk[1] as u32) << 8 | chunk[2] as u32;
+
+    push_ascii(
+      encoded,
+      &[
+        symbols[(total >> 18) as usize & 0x3F],
+        symbols[(total >> 12) as usize & 0x3F],
+        symbols[(total >> 6) as usize & 0x3F],
+        symbols[total as usize & 0x3F],
+      ],
+    );
+  }
+
+  match *chunks.remainder() {
+    [] => {}
+    [one] => {
+      push_ascii(
+        encoded,
+        &[
+          symbols[(one >> 2) as usize],
+          symbols[((one & 0x03) << 4) as usize],
+        ],
+      );
+
+      if padding {
+        push_ascii(encoded, &[PADDING_CHAR, PADDING_CHAR]);
+      }
+    }
+    [one, two] => {
+      push_ascii(
+        encoded,
+        &[
+          symbols[(one >> 2) as usize],
+          symbols[(((one & 0x03) << 4) | (two >> 4)) as usize],
+          symbols[((two & 0x0F) << 2) as usize],
+        ],
+      );
+
+      if padding {
+        push_ascii(encoded, &[PADDING_CHAR]);
+      }
+    }
+    _ => unreachable!(),
+  }
+}
+
+/// Decodes a base64 encoded string using the given alphabet.
+///
+/// # Notes
+/// When `padding` is `true`, the input must be a multiple of 4 bytes long, using `=` to pad the
+/// last chunk. Otherwise, `=` is rejected and the last chunk may be 2 or 3 characters long.
+///
+/// The unused bits of the last chunk must be zero, so that every byte sequence has exactly one
+/// valid encoding.
+pub fn decode_base64_with<S: ByteSink + ?Sized>(
+  bytes: &[u8],
+  alphabet: &Alphabet,
+  padding: bool,
+  decoded: &mut S,
+) -> Result<(), DecodeError> {
+  let mut length = bytes.len();
+
+  if padding {
+    if !length.is_multiple_of(4) {
+      return Err(invalid_length(bytes));
+    }
+
+    if length > 0 && bytes[length - 1] == PADDING_CHAR {
+      length -= 1;
+
+      if bytes[length - 1] == PADDING_CHAR {
+        length -= 1;
+      }
+    }
+  }
+
+  if length % 4 == 1 {
+    return Err(invalid_length(&bytes[..length]));
+  }
+
+  let mut total = 0u32;
+
+  for (idx, &byte) in bytes[..length].iter().enumerate() {
+    let value = match alphabet.values[byte as usize] {
+      INVALID_VALUE => {
+        return Err(DecodeError::new(
+          DecodeErrorKind::InvalidCharacter,
+          idx,
+          &[byte],
+        ))
+      }
+      value => value,
+    };
+
+    total = (total << 6) | value as u32;
+
+    if idx % 4 == 3 {
+      decoded.push_bytes(&[(total >> 16) as u8, (total >> 8) as u8, total as u8]);
+      total = 0;
+    }
+  }
+
+  // The leftover bits of a partial chunk must be zero.
+  match length % 4 {
+    2 if total & 0x0F == 0 => decoded.push_bytes(&[(total >> 4) as u8]),
+    3 if total & 0x03 == 0 => decoded.push_bytes(&[(total >> 10) as u8, (total >> 2) as u8]),
+    0 => {}
+    _ => {
+      let start = length & !3;
+
+      return Err(DecodeError::new(
+        DecodeErrorKind::TrailingBits,
+        start,
+        &bytes[start..length],
+      ));
+    }
+  }
+
+  Ok(())
+}
+
+/// Creates the error for an input whose length isn't valid, keeping the bytes after the last
+/// whole chunk.
+#[cold]
+fn invalid_length(bytes: &[u8]) -> DecodeError {
+  let start = bytes.len() & !3;
+
+  DecodeError::new(DecodeErrorKind::InvalidLength, start, &bytes[start..])
+}
+
+/// Creates the error for the first character outside of the standard alphabet, starting at
+/// `idx`.
+#[cold]
+fn invalid_character(bytes: &[u8], idx: usize) -> DecodeError {
+  let offset = bytes[idx..]
+    .iter()
+    .position(|&byte| D0[byte as usize] >= INVALID_CHAR)
+    .unwrap_or(0);
+
+  DecodeError::new(
+    DecodeErrorKind::InvalidCharacter,
+    idx + offset,
+    &bytes[idx + offset..idx + offset + 1],
+  )
+}
+
 const D0: [u32; 256] = [
   0x01ffffff, 0x01ffffff, 0x01ffffff, 0x01ffffff, 0x01ffffff, 0x01ffffff, 0x01ffffff, 0x01ffffff,
   0x01ffffff, 0x01ffffff, 0x01ffffff, 0x01ffffff, 0x01ffffff, 0x01ffffff, 0x01ffffff, 0x01ffffff,
@@ -0,0 +1,432 @@
+use crate::cookie::TOKEN_SET;
+use crate::globals::utils::hex_value;
+use crate::globals::{encode_str, percent_decode, ATTR_CHAR_SET};
+use crate::sink::Sink;
+
+use std::fmt;
+
+/// An error when decoding an ext-value.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ExtValueError {
+  /// The ext-value wasn't a charset, a language and a value separated by `'`, or the value
+  /// contained a character that isn't an `attr-char` or an escape.
+  InvalidFormat,
+  /// The charset was neither `UTF-8` nor `ISO-8859-1`.
+  UnsupportedCharset,
+  /// The decoded value wasn't valid UTF-8.
+  InvalidUtf8,
+}
+
+impl fmt::Display for ExtValueError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ExtValueError::InvalidFormat => f.write_str("invalid ext-value"),
+      ExtValueError::UnsupportedCharset => f.write_str("unsupported ext-value charset"),
+      ExtValueError::InvalidUtf8 => f.write_str("invalid UTF-8 in ext-value"),
+    }
+  }
+}
+
+impl std::error::Error for ExtValueError {}
+
+/// Encodes a string as a UTF-8 ext-value without a language, such as `UTF-8''%E2%82%AC.txt`.
+///
+/// # Notes
+/// This function follows [RFC 8187 §3.2](https://datatracker.ietf.org/doc/html/rfc8187#section-3.2),
+/// where every character that isn't an `attr-char` is percent encoded with [`ATTR_CHAR_SET`].
+pub fn encode_ext_value<S: Sink + ?Sized>(value: &str, encoded: &mut S) {
+  encoded.push_str("UTF-8''");
+  encode_str(value, &ATTR_CHAR_SET, encoded);
+}
+
+/// Decodes an ext-value, such as `UTF-8''%E2%82%AC.txt`.
+///
+/// # Notes
+/// This function follows [RFC 8187 §3.2](https://datatracker.ietf.org/doc/html/rfc8187#section-3.2),
+/// where the charset is `UTF-8` or `ISO-8859-1`, compared case-insensitively, and the language is
+/// ignored. Nothing is appended to `decoded` when an error is returned.
+pub fn decode_ext_value<S: Sink + ?Sized>(
+  ext_value: &str,
+  decoded: &mut S,
+) -> Result<(), ExtValueError> {
+  let mut parts = ext_value.splitn(3, '\'');
+  let (charset, language, value) = match (parts.next(), parts.next(), parts.next()) {
+    (Some(charset), Some(language), Some(value)) => (charset, language, value),
+    _ => return Err(ExtValueError::InvalidFormat),
+  };
+
+  let is_language = |byte: u8| byte.is_ascii_alphanumeric() || byte == b'-';
+
+  if charset.is_empty() || !language.bytes().all(is_language) {
+    return Err(ExtValueError::InvalidFormat);
+  }
+
+  if !is_value_chars(value.as_bytes()) {
+    return Err(ExtValueError::InvalidFormat);
+  }
+
+  let mut bytes = Vec::with_capacity(value.len());
+
+  percent_decode(value.as_bytes(), &mut bytes);
+
+  if charset.eq_ignore_ascii_case("UTF-8") {
+    let string = String::from_utf8(bytes).map_err(|_| ExtValueError::InvalidUtf8)?;
+
+    decoded.push_str(&string);
+  } else if charset.eq_ignore_ascii_case("ISO-8859-1") {
+    // The code points of ISO-8859-1 are the same as its bytes
+    let string = bytes.iter().map(|&byte| byte as char).collect::<String>();
+
+    decoded.push_str(&string);
+  } else {
+    return Err(ExtValueError::UnsupportedCharset);
+  }
+
+  Ok(())
+}
+
+/// Whether bytes are `value-chars`, where every `%` starts an escape and every other byte is an
+/// `attr-char`.
+fn is_value_chars(bytes: &[u8]) -> bool {
+  let mut idx = 0;
+
+  while idx < bytes.len() {
+    if bytes[idx] != b'%' {
+      if ATTR_CHAR_SET.contains(bytes[idx]) {
+        return false;
+      }
+
+      idx += 1;
+      continue;
+    }
+
+    match bytes.get(idx + 1..idx + 3) {
+      Some(&[one, two]) if hex_value(one, two) <= u8::MAX as u32 => idx += 3,
+      _ => return false,
+    }
+  }
+
+  true
+}
+
+/// An error when parsing a `Content-Disposition` header or adding a parameter to one.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ContentDispositionError {
+  /// The disposition type wasn't a `token`.
+  InvalidType,
+  /// A parameter wasn't a `token` name and a `token` or quoted string value.
+  InvalidParameter,
+  /// The ext-value of a parameter like `filename*` couldn't be decoded.
+  InvalidExtValue(ExtValueError),
+}
+
+impl fmt::Display for ContentDispositionError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ContentDispositionError::InvalidType => f.write_str("invalid disposition type"),
+      ContentDispositionError::InvalidParameter => f.write_str("invalid disposition parameter"),
+      ContentDispositionError::InvalidExtValue(error) => error.fmt(f),
+    }
+  }
+}
+
+impl std::error::Error for ContentDispositionError {}
+
+/// The value of a `Content-Disposition` header, such as
+/// `attachment; filename="file.txt"`, which is serialized by its [`fmt::Display`] implementation.
+///
+/// # Notes
+/// The disposition type and the parameter names are lowercased. Parameter values are stored
+/// decoded, where a value that can't be sent as ASCII is serialized as both a sanitized ASCII
+/// fallback and an ext-value, such as `filename="_.txt"; filename*=UTF-8''%E2%82%AC.txt`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ContentDisposition {
+  disposition_type: String,
+  parameters: Vec<(String, String)>,
+}
+
+impl ContentDisposition {
+  /// Creates a disposition without parameters, such as `inline` or `attachment`.
+  pub fn new(disposition_type: &str) -> Result<ContentDisposition, ContentDispositionError> {
+    if !is_token(disposition_type) {
+      return Err(ContentDispositionError::InvalidType);
+    }
+
+    Ok(ContentDisposition {
+      disposition_type: disposition_type.to_ascii_lowercase(),
+      parameters: Vec::new(),
+    })
+  }
+
+  /// Creates an `attachment` disposition with a filename.
+  pub fn attachment(filename: &str) -> ContentDisposition {
+    ContentDisposition {
+      disposition_type: "attachment".to_owned(),
+      parameters: vec![("filename".to_owned(), filename.to_owned())],
+    }
+  }
+
+  /// Parses the value of a `Content-Disposition` header.
+  ///
+  /// # Notes
+  /// This function follows the grammar of
+  /// [RFC 6266 §4.1](https://datatracker.ietf.org/doc/html/rfc6266#section-4.1), where a parameter
+  /// whose name ends with `*`, such as `filename*`, is an ext-value that takes precedence over the
+  /// parameter without the `*`. Otherwise, the first of repeated parameters is used.
+  pub fn parse(header: &str) -> Result<ContentDisposition, ContentDispositionError> {
+    let mut parser = Parser {
+      bytes: header.as_bytes(),
+      idx: 0,
+    };
+
+    parser.skip_whitespace();
+
+    let disposition_type = parser.token();
+    let mut disposition = ContentDisposition::new(disposition_type)?;
+    // Whether each parameter came from an ext-value
+    let mut is_extended: Vec<bool> = Vec::new();
+
+    loop {
+      parser.skip_whitespace();
+
+      if parser.is_empty() {
+        break;
+      }
+
+      if !parser.eat(b';') {
+        return Err(ContentDispositionError::InvalidParameter);
+      }
+
+      parser.skip_whitespace();
+
+      // A trailing `;` is allowed
+      if parser.is_empty() {
+        break;
+      }
+
+      let name = parser.token().to_ascii_lowercase();
+
+      parser.skip_whitespace();
+
+      if name.is_empty() || !parser.eat(b'=') {
+        return Err(ContentDispositionError::InvalidParameter);
+      }
+
+      parser.skip_whitespace();
+
+      let (name, value, extended) = match name.strip_suffix('*') {
+        Some(name) => {
+          let mut value = String::new();
+
+          decode_ext_value(parser.ext_value(), &mut value)
+            .map_err(ContentDispositionError::InvalidExtValue)?;
+
+          (name.to_owned(), value, true)
+        }
+        None => (name, parser.value()?, false),
+      };
+
+      match disposition.parameters.iter().position(|(n, _)| *n == name) {
+        Some(idx) if extended && !is_extended[idx] => {
+          disposition.parameters[idx].1 = value;
+          is_extended[idx] = true;
+        }
+        Some(_) => {}
+        None => {
+          disposition.parameters.push((name, value));
+          is_extended.push(extended);
+        }
+      }
+    }
+
+    Ok(disposition)
+  }
+
+  /// Returns the disposition type, such as `attachment`.
+  pub fn disposition_type(&self) -> &str {
+    &self.disposition_type
+  }
+
+  /// Returns the value of the `filename` parameter.
+  pub fn filename(&self) -> Option<&str> {
+    self.parameter("filename")
+  }
+
+  /// Returns the value of a parameter, where the name is compared case-insensitively.
+  pub fn parameter(&self, name: &str) -> Option<&str> {
+    self
+      .parameters
+      .iter()
+      .find(|(n, _)| n.eq_ignore_ascii_case(name))
+      .map(|(_, v)| v.as_str())
+  }
+
+  /// Returns an iterator over the parameters, in the order they were added.
+  pub fn parameters(&self) -> impl Iterator<Item = (&str, &str)> {
+    self
+      .parameters
+      .iter()
+      .map(|(n, v)| (n.as_str(), v.as_str()))
+  }
+
+  /// Sets the value of a parameter, adding it when there's none with the name.
+  ///
+  /// # Notes
+  /// The name must be a `token` that doesn't end with `*`, since ext-values are chosen when the
+  /// disposition is serialized.
+  pub fn set_parameter(&mut self, name: &str, value: &str) -> Result<(), ContentDispositionError> {
+    if !is_token(name) || name.ends_with('*') {
+      return Err(ContentDispositionError::InvalidParameter);
+    }
+
+    let name = name.to_ascii_lowercase();
+
+    match self.parameters.iter_mut().find(|(n, _)| *n == name) {
+      Some((_, v)) => *v = value.to_owned(),
+      None => self.parameters.push((name, value.to_owned())),
+    }
+
+    Ok(())
+  }
+}
+
+impl fmt::Display for ContentDisposition {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.disposition_type)?;
+
+    for (name, value) in &self.parameters {
+      let needs_ext_value = needs_ext_value(value);
+
+      if is_token(value) && !needs_ext_value {
+        write!(f, "; {}={}", name, value)?;
+        continue;
+      }
+
+      write!(f, "; {}=\"", name)?;
+
+      for ch in value.chars() {
+        match ch {
+          '"' | '\\' if !needs_ext_value => write!(f, "\\{}", ch)?,
+          // Some browsers don't unescape quoted strings, so the fallback avoids escapes
+          '"' | '\\' => f.write_str("_")?,
+          ' '..='~' => write!(f, "{}", ch)?,
+          _ => f.write_str("_")?,
+        }
+      }
+
+      f.write_str("\"")?;
+
+      if needs_ext_value {
+        let mut ext_value = String::new();
+
+        encode_ext_value(value, &mut ext_value);
+        write!(f, "; {}*={}", name, ext_value)?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+fn is_token(string: &str) -> bool {
+  !string.is_empty() && string.bytes().all(|byte| TOKEN_SET.contains(byte))
+}
+
+/// Whether a value can't be sent as a quoted string that every browser reads the same, because it
+/// has non-ASCII or control characters, or escapes that some browsers decode.
+fn needs_ext_value(value: &str) -> bool {
+  let bytes = value.as_bytes();
+
+  bytes.iter().enumerate().any(|(idx, &byte)| match byte {
+    b' '..=b'~' if byte != b'%' => false,
+    b'%' => match bytes.get(idx + 1..idx + 3) {
+      Some(&[one, two]) => hex_value(one, two) <= u8::MAX as u32,
+      _ => false,
+    },
+    _ => true,
+  })
+}
+
+struct Parser<'a> {
+  bytes: &'a [u8],
+  idx: usize,
+}
+
+impl<'a> Parser<'a> {
+  fn is_empty(&self) -> bool {
+    self.idx == self.bytes.len()
+  }
+
+  fn eat(&mut self, byte: u8) -> bool {
+    if self.bytes.get(self.idx) == Some(&byte) {
+      self.idx += 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn skip_whitespace(&mut self) {
+    while self.eat(b' ') || self.eat(b'\t') {}
+  }
+
+  fn take_while(&mut self, predicate: impl Fn(u8) -> bool) -> &'a str {
+    let start = self.idx;
+
+    while self
+      .bytes
+      .get(self.idx)
+      .is_some_and(|&byte| predicate(byte))
+    {
+      self.idx += 1;
+    }
+
+    // The predicates only take ASCII, so this is on a character boundary
+    std::str::from_utf8(&self.bytes[start..self.idx]).unwrap_or_default()
+  }
+
+  fn token(&mut self) -> &'a str {
+    self.take_while(|byte| TOKEN_SET.contains(byte))
+  }
+
+  /// Reads an ext-value, which is validated when it's decoded.
+  fn ext_value(&mut self) -> &'a str {
+    self.take_while(|byte| byte.is_ascii_graphic() && byte != b';')
+  }
+
+  /// Reads a `token` or a quoted string, unescaping the latter.
+  fn value(&mut self) -> Result<String, ContentDispositionError> {
+    if !self.eat(b'"') {
+      let token = self.token();
+
+      if token.is_empty() {
+        return Err(ContentDispositionError::InvalidParameter);
+      }
+
+      return Ok(token.to_owned());
+    }
+
+    let mut value = Vec::new();
+
+    loop {
+      match self.bytes.get(self.idx) {
+        Some(b'"') => {
+          self.idx += 1;
+          break;
+        }
+        Some(b'\\') if self.idx + 1 < self.bytes.len() => {
+          value.push(self.bytes[self.idx + 1]);
+          self.idx += 2;
+        }
+        Some(&byte) if byte == b'\t' || (byte >= b' ' && byte != 0x7F) => {
+          value.push(byte);
+          self.idx += 1;
+        }
+        _ => return Err(ContentDispositionError::InvalidParameter),
+      }
+    }
+
+    // Browsers send filenames as raw UTF-8, which the header was already decoded as
+    String::from_utf8(value).map_err(|_| ContentDispositionError::InvalidParameter)
+  }
+}
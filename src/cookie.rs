@@ -0,0 +1,339 @@
+use crate::globals::{decode_uri_component_lossy, AsciiSet};
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The characters of a `token`, as defined in
+/// [RFC 9110 §5.6.2](https://datatracker.ietf.org/doc/html/rfc9110#section-5.6.2).
+pub(crate) const TOKEN_SET: AsciiSet = AsciiSet::from_range(b'A', b'Z')
+  .union(&AsciiSet::from_range(b'a', b'z'))
+  .union(&AsciiSet::from_range(b'0', b'9'))
+  .union(&AsciiSet::from_bytes(b"!#$%&'*+-.^_`|~"));
+
+/// The characters of a `cookie-octet`, which are the printable characters except the space, `"`,
+/// `,`, `;` and `\`.
+const COOKIE_OCTET_SET: AsciiSet = AsciiSet::from_range(0x21, 0x7E)
+  .remove(b'"')
+  .remove(b',')
+  .remove(b';')
+  .remove(b'\\');
+
+/// The characters of an `av-octet`, which are the printable characters and the space except `;`.
+const AV_OCTET_SET: AsciiSet = AsciiSet::from_range(0x20, 0x7E).remove(b';');
+
+const DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = [
+  "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// An error when a cookie can't be serialized.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum CookieError {
+  /// The name was empty or wasn't a `token`.
+  InvalidName,
+  /// The value contained a character that isn't a `cookie-octet`.
+  InvalidValue,
+  /// The value of the `Domain` or `Path` attribute contained a control character or `;`.
+  InvalidAttributeValue,
+}
+
+impl fmt::Display for CookieError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      CookieError::InvalidName => f.write_str("invalid cookie name"),
+      CookieError::InvalidValue => f.write_str("invalid cookie value"),
+      CookieError::InvalidAttributeValue => f.write_str("invalid cookie attribute value"),
+    }
+  }
+}
+
+impl std::error::Error for CookieError {}
+
+/// Whether a string is a valid `cookie-name`, which is a non-empty `token`.
+pub fn is_valid_cookie_name(name: &str) -> bool {
+  !name.is_empty() && name.bytes().all(|byte| TOKEN_SET.contains(byte))
+}
+
+/// Whether a string is a valid `cookie-value`, which is `cookie-octet`s that may be wrapped in
+/// double quotes.
+pub fn is_valid_cookie_value(value: &str) -> bool {
+  let value = match value.strip_prefix('"') {
+    Some(quoted) => match quoted.strip_suffix('"') {
+      Some(inner) => inner,
+      None => return false,
+    },
+    None => value,
+  };
+
+  value.bytes().all(|byte| COOKIE_OCTET_SET.contains(byte))
+}
+
+/// Parses a `Cookie` header to a list of name-value pairs, in the order they're sent.
+///
+/// # Notes
+/// This function follows the `cookie-string` grammar of
+/// [RFC 6265 §4.2.1](https://datatracker.ietf.org/doc/html/rfc6265#section-4.2.1), while being as
+/// lenient as servers need to be with what browsers send. Whitespace around names and values is
+/// trimmed, pairs without a `=` or a name are skipped, and a value wrapped in double quotes is
+/// unwrapped. Values are decoded with [`decode_uri_component_lossy`], so malformed escapes are
+/// kept and invalid UTF-8 is replaced with U+FFFD.
+///
+/// A name can be sent more than once, in which case browsers send the cookie with the longest path
+/// first.
+pub fn parse_cookie(header: &str) -> Vec<(String, String)> {
+  header
+    .split(';')
+    .filter_map(|pair| {
+      let (name, value) = pair.split_once('=')?;
+      let name = name.trim_matches(is_whitespace);
+      let value = value.trim_matches(is_whitespace);
+
+      if name.is_empty() {
+        return None;
+      }
+
+      let value = match value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+      {
+        Some(unquoted) => unquoted,
+        None => value,
+      };
+      let mut decoded = String::with_capacity(value.len());
+
+      decode_uri_component_lossy(value.as_bytes(), &mut decoded);
+
+      Some((name.to_owned(), decoded))
+    })
+    .collect()
+}
+
+fn is_whitespace(ch: char) -> bool {
+  ch == ' ' || ch == '\t'
+}
+
+/// The value of the `SameSite` attribute.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum SameSite {
+  /// The cookie is only sent with same-site requests.
+  Strict,
+  /// The cookie is also sent with top-level navigations from other sites.
+  Lax,
+  /// The cookie is sent with every request, which browsers only allow for `Secure` cookies.
+  None,
+}
+
+impl fmt::Display for SameSite {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      SameSite::Strict => f.write_str("Strict"),
+      SameSite::Lax => f.write_str("Lax"),
+      SameSite::None => f.write_str("None"),
+    }
+  }
+}
+
+/// A cookie to send in a `Set-Cookie` header, which is serialized by its [`fmt::Display`]
+/// implementation.
+///
+/// # Notes
+/// The serialization follows the `set-cookie-string` grammar of
+/// [RFC 6265 §4.1.1](https://datatracker.ietf.org/doc/html/rfc6265#section-4.1.1), along with the
+/// `SameSite` and `Partitioned` attributes that browsers support. The name and value are validated
+/// as they are, so a value that may contain other characters should be encoded, e.g. with
+/// [`encode_uri_component`](crate::globals::encode_uri_component), first.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct SetCookie {
+  name: String,
+  value: String,
+  expires: Option<SystemTime>,
+  max_age: Option<i64>,
+  domain: Option<String>,
+  path: Option<String>,
+  secure: bool,
+  http_only: bool,
+  same_site: Option<SameSite>,
+  partitioned: bool,
+}
+
+impl SetCookie {
+  /// Creates a cookie without attributes.
+  pub fn new(name: &str, value: &str) -> Result<SetCookie, CookieError> {
+    if !is_valid_cookie_name(name) {
+      return Err(CookieError::InvalidName);
+    }
+
+    if !is_valid_cookie_value(value) {
+      return Err(CookieError::InvalidValue);
+    }
+
+    Ok(SetCookie {
+      name: name.to_owned(),
+      value: value.to_owned(),
+      expires: None,
+      max_age: None,
+      domain: None,
+      path: None,
+      secure: false,
+      http_only: false,
+      same_site: None,
+      partitioned: false,
+    })
+  }
+
+  /// Returns the name.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the value.
+  pub fn value(&self) -> &str {
+    &self.value
+  }
+
+  /// Sets the `Expires` attribute, the time the cookie expires at.
+  pub fn expires(mut self, expires: SystemTime) -> SetCookie {
+    self.expires = Some(expires);
+    self
+  }
+
+  /// Sets the `Max-Age` attribute, the number of seconds until the cookie expires, where zero or
+  /// less expires it right away.
+  pub fn max_age(mut self, max_age: i64) -> SetCookie {
+    self.max_age = Some(max_age);
+    self
+  }
+
+  /// Sets the `Domain` attribute, the host that the cookie is sent to along with its subdomains.
+  pub fn domain(mut self, domain: &str) -> Result<SetCookie, CookieError> {
+    self.domain = Some(attribute_value(domain)?);
+    Ok(self)
+  }
+
+  /// Sets the `Path` attribute, the path that the cookie is sent to along with its subpaths.
+  pub fn path(mut self, path: &str) -> Result<SetCookie, CookieError> {
+    self.path = Some(attribute_value(path)?);
+    Ok(self)
+  }
+
+  /// Sets the `Secure` attribute, which only sends the cookie over secure connections.
+  pub fn secure(mut self, secure: bool) -> SetCookie {
+    self.secure = secure;
+    self
+  }
+
+  /// Sets the `HttpOnly` attribute, which hides the cookie from scripts.
+  pub fn http_only(mut self, http_only: bool) -> SetCookie {
+    self.http_only = http_only;
+    self
+  }
+
+  /// Sets the `SameSite` attribute.
+  pub fn same_site(mut self, same_site: SameSite) -> SetCookie {
+    self.same_site = Some(same_site);
+    self
+  }
+
+  /// Sets the `Partitioned` attribute, which keys the cookie to the top-level site, and which
+  /// browsers only allow for `Secure` cookies.
+  pub fn partitioned(mut self, partitioned: bool) -> SetCookie {
+    self.partitioned = partitioned;
+    self
+  }
+}
+
+fn attribute_value(value: &str) -> Result<String, CookieError> {
+  if value.bytes().all(|byte| AV_OCTET_SET.contains(byte)) {
+    Ok(value.to_owned())
+  } else {
+    Err(CookieError::InvalidAttributeValue)
+  }
+}
+
+impl fmt::Display for SetCookie {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}={}", self.name, self.value)?;
+
+    if let Some(expires) = self.expires {
+      f.write_str("; Expires=")?;
+      write_http_date(f, expires)?;
+    }
+
+    if let Some(max_age) = self.max_age {
+      write!(f, "; Max-Age={}", max_age)?;
+    }
+
+    if let Some(domain) = &self.domain {
+      write!(f, "; Domain={}", domain)?;
+    }
+
+    if let Some(path) = &self.path {
+      write!(f, "; Path={}", path)?;
+    }
+
+    if self.secure {
+      f.write_str("; Secure")?;
+    }
+
+    if self.http_only {
+      f.write_str("; HttpOnly")?;
+    }
+
+    if let Some(same_site) = self.same_site {
+      write!(f, "; SameSite={}", same_site)?;
+    }
+
+    if self.partitioned {
+      f.write_str("; Partitioned")?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Writes a time as an `IMF-fixdate`, such as `Sun, 06 Nov 1994 08:49:37 GMT`.
+fn write_http_date(f: &mut fmt::Formatter<'_>, time: SystemTime) -> fmt::Result {
+  let seconds = match time.duration_since(UNIX_EPOCH) {
+    Ok(duration) => duration.as_secs() as i64,
+    Err(error) => -(error.duration().as_secs() as i64),
+  };
+  let days = seconds.div_euclid(86400);
+  let seconds = seconds.rem_euclid(86400);
+  let (year, month, day) = civil_from_days(days);
+
+  write!(
+    f,
+    "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+    DAYS[(days + 4).rem_euclid(7) as usize],
+    day,
+    MONTHS[month as usize - 1],
+    year,
+    seconds / 3600,
+    seconds / 60 % 60,
+    seconds % 60
+  )
+}
+
+/// Converts days since the Unix epoch to a proleptic Gregorian date.
+///
+/// # Notes
+/// This function uses Howard Hinnant's
+/// [days_from_civil inverse](https://howardhinnant.github.io/date_algorithms.html#civil_from_days).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+  let days = days + 719468;
+  let era = days.div_euclid(146097);
+  let day_of_era = days.rem_euclid(146097);
+  let year_of_era =
+    (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_index = (5 * day_of_year + 2) / 153;
+  let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+  let month = if month_index < 10 {
+    month_index + 3
+  } else {
+    month_index - 9
+  } as u32;
+  let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+  (year, month, day)
+}
@@ -0,0 +1,165 @@
+/// A set of bytes, stored as a 256-bit bitset so that checking whether a byte is in the set is a
+/// single lookup.
+///
+/// # Notes
+/// Sets can be built in constant expressions, for example
+/// `AsciiSet::from_bytes(b"!*").union(&FRAGMENT_SET)`.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct AsciiSet {
+  bits: [u64; 4],
+  // For each low nibble, the bits of the high nibbles of the ASCII bytes in the set, which lets
+  // SIMD code classify bytes with two table lookups.
+  nibbles: [u8; 16],
+}
+
+impl AsciiSet {
+  /// The set containing no bytes.
+  pub const EMPTY: AsciiSet = AsciiSet {
+    bits: [0; 4],
+    nibbles: [0; 16],
+  };
+
+  /// Creates a set containing the given bytes.
+  pub const fn from_bytes(bytes: &[u8]) -> AsciiSet {
+    let mut set = AsciiSet::EMPTY;
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+      set = set.add(bytes[idx]);
+      idx += 1;
+    }
+
+    set
+  }
+
+  /// Creates a set containing the bytes in the inclusive range `start..=end`.
+  pub const fn from_range(start: u8, end: u8) -> AsciiSet {
+    let mut set = AsciiSet::EMPTY;
+    let mut byte = start as usize;
+
+    while byte <= end as usize {
+      set = set.add(byte as u8);
+      byte += 1;
+    }
+
+    set
+  }
+
+  /// Whether the set contains the byte.
+  #[inline]
+  pub const fn contains(&self, byte: u8) -> bool {
+    self.bits[(byte >> 6) as usize] & (1 << (byte & 63)) != 0
+  }
+
+  /// Returns the set with the byte added.
+  pub const fn add(mut self, byte: u8) -> AsciiSet {
+    self.bits[(byte >> 6) as usize] |= 1 << (byte & 63);
+
+    if byte < 0x80 {
+      self.nibbles[(byte & 0x0F) as usize] |= 1 << (byte >> 4);
+    }
+
+    self
+  }
+
+  /// Returns the set with the byte removed.
+  pub const fn remove(mut self, byte: u8) -> AsciiSet {
+    self.bits[(byte >> 6) as usize] &= !(1 << (byte & 63));
+
+    if byte < 0x80 {
+      self.nibbles[(byte & 0x0F) as usize] &= !(1 << (byte >> 4));
+    }
+
+    self
+  }
+
+  /// Returns the set of bytes in either set.
+  pub const fn union(&self, other: &AsciiSet) -> AsciiSet {
+    let mut set = *self;
+    let mut idx = 0;
+
+    while idx < set.bits.len() {
+      set.bits[idx] |= other.bits[idx];
+      idx += 1;
+    }
+
+    idx = 0;
+
+    while idx < set.nibbles.len() {
+      set.nibbles[idx] |= other.nibbles[idx];
+      idx += 1;
+    }
+
+    set
+  }
+
+  /// Returns the set of bytes that aren't in this set.
+  pub const fn complement(&self) -> AsciiSet {
+    let mut set = *self;
+    let mut idx = 0;
+
+    while idx < set.bits.len() {
+      set.bits[idx] = !set.bits[idx];
+      idx += 1;
+    }
+
+    idx = 0;
+
+    while idx < set.nibbles.len() {
+      set.nibbles[idx] = !set.nibbles[idx];
+      idx += 1;
+    }
+
+    set
+  }
+
+  /// For each low nibble, the bits of the high nibbles of the ASCII bytes in the set.
+  #[inline]
+  pub(crate) const fn nibbles(&self) -> &[u8; 16] {
+    &self.nibbles
+  }
+}
+
+/// The [C0 control percent-encode set](https://url.spec.whatwg.org/#c0-control-percent-encode-set),
+/// which is the C0 controls and every byte above `~`.
+pub const C0_CONTROL_SET: AsciiSet =
+  AsciiSet::from_range(0x00, 0x1F).union(&AsciiSet::from_range(0x7F, 0xFF));
+
+/// The [fragment percent-encode set](https://url.spec.whatwg.org/#fragment-percent-encode-set).
+pub const FRAGMENT_SET: AsciiSet = C0_CONTROL_SET.union(&AsciiSet::from_bytes(b" \"<>`"));
+
+/// The [query percent-encode set](https://url.spec.whatwg.org/#query-percent-encode-set).
+pub const QUERY_SET: AsciiSet = C0_CONTROL_SET.union(&AsciiSet::from_bytes(b" \"#<>"));
+
+/// The [special-query percent-encode set](https://url.spec.whatwg.org/#special-query-percent-encode-set).
+pub const SPECIAL_QUERY_SET: AsciiSet = QUERY_SET.add(b'\'');
+
+/// The [path percent-encode set](https://url.spec.whatwg.org/#path-percent-encode-set).
+pub const PATH_SET: AsciiSet = QUERY_SET.union(&AsciiSet::from_bytes(b"?`{}"));
+
+/// The [userinfo percent-encode set](https://url.spec.whatwg.org/#userinfo-percent-encode-set).
+pub const USERINFO_SET: AsciiSet = PATH_SET.union(&AsciiSet::from_bytes(b"/:;=@[\\]^|"));
+
+/// The [component percent-encode set](https://url.spec.whatwg.org/#component-percent-encode-set).
+pub const COMPONENT_SET: AsciiSet = USERINFO_SET.union(&AsciiSet::from_bytes(b"$%&+,"));
+
+/// The [application/x-www-form-urlencoded percent-encode set](https://url.spec.whatwg.org/#application-x-www-form-urlencoded-percent-encode-set).
+pub const FORM_URLENCODED_SET: AsciiSet = COMPONENT_SET.union(&AsciiSet::from_bytes(b"!'()~"));
+
+/// The percent-encode set of every byte except the
+/// [unreserved characters](https://datatracker.ietf.org/doc/html/rfc3986#section-2.3) of RFC 3986,
+/// which are `A-Z`, `a-z`, `0-9`, `-`, `.`, `_` and `~`.
+pub const UNRESERVED_SET: AsciiSet = AsciiSet::from_range(b'A', b'Z')
+  .union(&AsciiSet::from_range(b'a', b'z'))
+  .union(&AsciiSet::from_range(b'0', b'9'))
+  .union(&AsciiSet::from_bytes(b"-._~"))
+  .complement();
+
+/// The percent-encode set of every byte except the `attr-char`s of
+/// [RFC 8187](https://datatracker.ietf.org/doc/html/rfc8187#section-3.2.1), which are `A-Z`,
+/// `a-z`, `0-9` and ``!#$&+-.^_`|~``.
+pub const ATTR_CHAR_SET: AsciiSet = AsciiSet::from_range(b'A', b'Z')
+  .union(&AsciiSet::from_range(b'a', b'z'))
+  .union(&AsciiSet::from_range(b'0', b'9'))
+  .union(&AsciiSet::from_bytes(b"!#$&+-.^_`|~"))
+  .complement();
@@ -1,15 +1,30 @@
-use crate::globals::utils::{decode_two_octets, hex_value};
-use crate::globals::UriError;
+use crate::globals::simd::literal_len;
+use crate::globals::utils::{decode_code_point, encode_code_point, hex_value};
+use crate::globals::{utf8, AsciiSet, UriError, UriErrorKind};
+use crate::sink::{push_ascii, ByteSink, Sink, SliceSink};
+
+use std::borrow::Cow;
+
+/// Where, and in which encoding, a decoded URI is written to.
+#[derive(Debug)]
+pub enum DecodeOutput<'a> {
+  /// UTF-8 bytes. The unescaped characters of the input must be valid UTF-8.
+  Utf8(&'a mut Vec<u8>),
+  /// [WTF-8](https://simonsapin.github.io/wtf-8/) bytes. The unescaped characters of the input
+  /// may contain unpaired surrogates, which are kept as is.
+  Wtf8(&'a mut Vec<u8>),
+  /// UTF-16 code units. The unescaped characters of the input are read as WTF-8, so that unpaired
+  /// surrogates are kept as is.
+  Utf16(&'a mut Vec<u16>),
+}
 
 /// Decodes a UTF-8 encoded URI, reserving any character in the set `#$&+,/:;=?@`.
 ///
 /// # Notes
 /// This function functionally behaves the same as
 /// [JavaScript's decodeURI](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI).
-pub fn decode_uri(bytes: &[u8], decoded: &mut Vec<u8>) -> Result<(), UriError> {
-  const RESERVED: &[u8] = b"#$&+,/:;=?@";
-
-  decode_uri_inner(bytes, RESERVED, decoded)
+pub fn decode_uri<S: Sink + ?Sized>(bytes: &[u8], decoded: &mut S) -> Result<(), UriError> {
+  decode_uri_inner(bytes, &DECODE_URI_RESERVED, decoded)
 }
 
 /// Decodes a UTF-8 encoded URI.
@@ -17,115 +32,396 @@ pub fn decode_uri(bytes: &[u8], decoded: &mut Vec<u8>) -> Result<(), UriError> {
 /// # Notes
 /// This function functionally behaves the same as
 /// [JavaScript's decodeURIComponent](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURIComponent).
-pub fn decode_uri_component(bytes: &[u8], decoded: &mut Vec<u8>) -> Result<(), UriError> {
-  decode_uri_inner(bytes, &[], decoded)
+pub fn decode_uri_component<S: Sink + ?Sized>(
+  bytes: &[u8],
+  decoded: &mut S,
+) -> Result<(), UriError> {
+  decode_uri_inner(bytes, &AsciiSet::EMPTY, decoded)
+}
+
+/// Decodes a WTF-8 encoded URI to UTF-16 code units, reserving any character in the set
+/// `#$&+,/:;=?@`.
+///
+/// # Notes
+/// This is the same as [`decode_uri`], but writes the UTF-16 code units that a JavaScript engine
+/// would produce.
+pub fn decode_uri_utf16(bytes: &[u8], decoded: &mut Vec<u16>) -> Result<(), UriError> {
+  decode_uri_to(bytes, &DECODE_URI_RESERVED, DecodeOutput::Utf16(decoded))
+}
+
+/// Decodes a WTF-8 encoded URI component to UTF-16 code units.
+///
+/// # Notes
+/// This is the same as [`decode_uri_component`], but writes the UTF-16 code units that a
+/// JavaScript engine would produce.
+pub fn decode_uri_component_utf16(bytes: &[u8], decoded: &mut Vec<u16>) -> Result<(), UriError> {
+  decode_uri_to(bytes, &AsciiSet::EMPTY, DecodeOutput::Utf16(decoded))
+}
+
+/// Decodes a URI, reserving any character in the set `#$&+,/:;=?@`.
+///
+/// # Notes
+/// This is the same as [`decode_uri`], but borrows the input when nothing needs decoding, and
+/// only allocates from the first escape that does otherwise.
+pub fn decode_uri_cow(string: &str) -> Result<Cow<'_, str>, UriError> {
+  decode_cow(string, &DECODE_URI_RESERVED)
+}
+
+/// Decodes a URI component.
+///
+/// # Notes
+/// This is the same as [`decode_uri_component`], but borrows the input when nothing needs
+/// decoding, and only allocates from the first escape that does otherwise.
+pub fn decode_uri_component_cow(string: &str) -> Result<Cow<'_, str>, UriError> {
+  decode_cow(string, &AsciiSet::EMPTY)
+}
+
+/// Decodes a URI without failing, reserving any character in the set `#$&+,/:;=?@`. Returns
+/// whether any invalid UTF-8 was replaced.
+///
+/// # Notes
+/// This is the same as [`decode_uri_component_lossy`], but escapes of reserved characters are
+/// kept as is.
+pub fn decode_uri_lossy<S: Sink + ?Sized>(bytes: &[u8], decoded: &mut S) -> bool {
+  decode_lossy(bytes, &DECODE_URI_RESERVED, decoded)
+}
+
+/// Decodes a URI component without failing. Returns whether any invalid UTF-8 was replaced.
+///
+/// # Notes
+/// This function follows the [percent-decode](https://url.spec.whatwg.org/#percent-decode)
+/// algorithm of the URL Standard, where malformed escapes are kept as is, followed by
+/// [UTF-8 decode without BOM](https://encoding.spec.whatwg.org/#utf-8-decode-without-bom), where
+/// each maximal invalid sequence, escaped or not, is replaced with U+FFFD.
+pub fn decode_uri_component_lossy<S: Sink + ?Sized>(bytes: &[u8], decoded: &mut S) -> bool {
+  decode_lossy(bytes, &AsciiSet::EMPTY, decoded)
+}
+
+/// Percent decodes bytes without failing, keeping every `%` that isn't followed by 2 hexadecimal
+/// digits as is.
+///
+/// # Notes
+/// This function follows the [percent-decode](https://url.spec.whatwg.org/#percent-decode)
+/// algorithm of the URL Standard. The decoded bytes aren't validated, so they may not be UTF-8.
+pub fn percent_decode<S: ByteSink + ?Sized>(bytes: &[u8], decoded: &mut S) {
+  percent_decode_reserving(bytes, &AsciiSet::EMPTY, decoded);
 }
 
 /// Fast UTF-8 uri decode function with multi-byte and reserved character support.
 ///
 /// This function follows the [EMCA-262 spec](https://262.ecma-international.org/11.0/#sec-decode)
-/// for decoding URIs.
-pub fn decode_uri_inner(
+/// for decoding URIs, where escapes of the ASCII characters in `reserved` are kept as is.
+pub fn decode_uri_inner<S: Sink + ?Sized>(
+  bytes: &[u8],
+  reserved: &AsciiSet,
+  decoded: &mut S,
+) -> Result<(), UriError> {
+  decode(bytes, reserved, decoded, None)
+}
+
+/// Decodes a URI, writing the result in the encoding of `output`.
+///
+/// This function follows the [EMCA-262 spec](https://262.ecma-international.org/11.0/#sec-decode)
+/// for decoding URIs, where escapes of the ASCII characters in `reserved` are kept as is.
+pub fn decode_uri_to(
   bytes: &[u8],
-  reserved: &[u8],
-  decoded: &mut Vec<u8>,
+  reserved: &AsciiSet,
+  output: DecodeOutput<'_>,
 ) -> Result<(), UriError> {
-  const UTF8_SURROGATE: u32 = 0x10000;
-  // In a 4 byte utf-8 series, 10 bits are reserved for determining the number of octets there
-  // actually are.
-  const RESERVED_OCTETS_BITS: u32 = 10;
+  match output {
+    DecodeOutput::Utf8(decoded) => decode(bytes, reserved, decoded, None),
+    DecodeOutput::Wtf8(decoded) => decode(bytes, reserved, decoded, Some(push_wtf8_surrogate)),
+    DecodeOutput::Utf16(decoded) => decode(
+      bytes,
+      reserved,
+      &mut Utf16(decoded),
+      Some(Utf16::push_surrogate),
+    ),
+  }
+}
+
+/// Decodes a UTF-8 encoded URI to the start of `decoded`, where escapes of the ASCII characters
+/// in `reserved` are kept as is. Returns the number of bytes written.
+///
+/// # Notes
+/// This is the same as [`decode_uri_inner`], but writes to a buffer that's at least
+/// [`decoded_uri_len`] bytes long, through a [`SliceSink`].
+///
+/// # Panics
+/// This function panics if `decoded` is shorter than [`decoded_uri_len`].
+pub fn decode_uri_to_slice(
+  bytes: &[u8],
+  reserved: &AsciiSet,
+  decoded: &mut [u8],
+) -> Result<usize, UriError> {
+  assert!(
+    decoded.len() >= decoded_uri_len(bytes, reserved),
+    "the buffer is too short for the decoded URI"
+  );
+
+  let mut sink = SliceSink::new(decoded);
+
+  decode(bytes, reserved, &mut sink, None)?;
 
+  Ok(sink.len())
+}
+
+/// Returns the length of a UTF-8 encoded URI once it's decoded, where escapes of the ASCII
+/// characters in `reserved` are kept as is.
+///
+/// # Notes
+/// The length is exact when decoding the URI succeeds.
+pub fn decoded_uri_len(bytes: &[u8], reserved: &AsciiSet) -> usize {
+  let mut length = bytes.len();
   let mut idx = 0;
 
   while idx < bytes.len() {
-    // SAFETY: We'd have at least a single byte here.
-    let byte = unsafe { *bytes.get_unchecked(idx) };
+    // SAFETY: Guaranteed to be valid because of `idx < len`.
+    idx += literal_len(unsafe { bytes.get_unchecked(idx..) }, false);
 
-    if byte == b'%' {
-      const NEXT: usize = 2;
-
-      if idx + NEXT >= bytes.len() {
-        return Err(UriError::InvalidUri);
+    match escaped_byte(bytes, idx) {
+      Ok(byte) if byte >= 0x80 || !reserved.contains(byte) => {
+        length -= 2;
+        idx += 3;
       }
+      Ok(_) => idx += 3,
+      Err(_) => idx += 1,
+    }
+  }
 
-      // SAFETY: We error if there's not enough bytes remaining
-      let next_byte = unsafe {
-        let first = *bytes.get_unchecked(idx + 1);
-        let second = *bytes.get_unchecked(idx + 2);
-
-        hex_value(first, second)
-      };
-
-      idx += NEXT;
-
-      // Gets the number of byte pairs
-      let (bytes_needed, mask) = match next_byte {
-        // One byte - We check for one byte here for perf reasons (avoids extra branching)
-        // For one byte we can use the byte itself as the mask because the bits are the same
-        x if x < 0x80 => (0, x),
-        // Two bytes
-        x if x < 0xE0 => (3, 0x1F),
-        // Three bytes
-        x if x < 0xF0 => (6, 0x0F),
-        // Four bytes
-        x if x < 0xF8 => (9, 0x07),
-        // Invalid
-        _ => return Err(UriError::InvalidUtf8Character),
-      };
-
-      if idx + bytes_needed >= bytes.len() {
-        return Err(UriError::InvalidUri);
-      }
+  length
+}
 
-      const CHUNKS: usize = 3;
+pub(crate) const DECODE_URI_RESERVED: AsciiSet = AsciiSet::from_bytes(b"#$&+,/:;=?@");
 
-      let mut total = next_byte & mask;
+fn decode_cow<'a>(string: &'a str, reserved: &AsciiSet) -> Result<Cow<'a, str>, UriError> {
+  let bytes = string.as_bytes();
+  let mut unchanged = 0;
 
-      for i in 0..bytes_needed / CHUNKS {
-        // SAFETY: We check if we have enough bytes beforehand which also allows to
-        // avoid branching here
-        let first = unsafe { *bytes.get_unchecked(idx + (i * CHUNKS + NEXT)) };
-        let second = unsafe { *bytes.get_unchecked(idx + (i * CHUNKS + NEXT + 1)) };
-        let upcoming_byte = hex_value(first, second);
+  // Skips literals and the escapes that are kept as is
+  loop {
+    unchanged += literal_len(&bytes[unchanged..], false);
 
-        if upcoming_byte & 0xC0 != 0x80 {
-          return Err(UriError::InvalidUtf8Character);
-        }
+    match escaped_byte(bytes, unchanged) {
+      Ok(byte) if byte < 0x80 && reserved.contains(byte) => unchanged += 3,
+      _ => break,
+    }
+  }
+
+  if unchanged == bytes.len() {
+    return Ok(Cow::Borrowed(string));
+  }
 
-        total = (total << 6) + (upcoming_byte & 0x3F)
+  let mut decoded = Vec::with_capacity(bytes.len());
+
+  decoded.extend_from_slice(&bytes[..unchanged]);
+  decode(&bytes[unchanged..], reserved, &mut decoded, None).map_err(|mut error| {
+    error.index += unchanged;
+    error
+  })?;
+
+  // SAFETY: The input is valid UTF-8, and only escapes of valid UTF-8 are decoded.
+  Ok(Cow::Owned(unsafe { String::from_utf8_unchecked(decoded) }))
+}
+
+fn decode_lossy<S: Sink + ?Sized>(bytes: &[u8], reserved: &AsciiSet, output: &mut S) -> bool {
+  let mut decoded = Vec::with_capacity(bytes.len());
+
+  percent_decode_reserving(bytes, reserved, &mut decoded);
+
+  match String::from_utf8_lossy(&decoded) {
+    Cow::Borrowed(decoded) => {
+      output.push_str(decoded);
+      false
+    }
+    Cow::Owned(decoded) => {
+      output.push_str(&decoded);
+      true
+    }
+  }
+}
+
+/// Percent decodes bytes, where escapes of the ASCII characters in `reserved` are kept as is.
+fn percent_decode_reserving<S: ByteSink + ?Sized>(
+  bytes: &[u8],
+  reserved: &AsciiSet,
+  decoded: &mut S,
+) {
+  let mut idx = 0;
+
+  while idx < bytes.len() {
+    // SAFETY: Guaranteed to be valid because of `idx < len`.
+    let rest = unsafe { bytes.get_unchecked(idx..) };
+    let length = literal_len(rest, false);
+
+    // SAFETY: The length is at most the length of `rest`.
+    decoded.push_bytes(unsafe { rest.get_unchecked(..length) });
+    idx += length;
+
+    match escaped_byte(bytes, idx) {
+      Ok(byte) if byte >= 0x80 || !reserved.contains(byte) => {
+        decoded.push_bytes(&[byte]);
+        idx += 3;
       }
+      Ok(_) => {
+        // SAFETY: `escaped_byte` checked that there are 3 bytes.
+        decoded.push_bytes(unsafe { bytes.get_unchecked(idx..idx + 3) });
+        idx += 3;
+      }
+      // The `%` of a malformed escape is kept as is
+      Err(_) if idx < bytes.len() => {
+        decoded.push_bytes(b"%");
+        idx += 1;
+      }
+      Err(_) => {}
+    }
+  }
+}
+
+/// Writes an unpaired surrogate as WTF-8.
+pub(crate) fn push_wtf8_surrogate(decoded: &mut Vec<u8>, surrogate: u32) {
+  let (encoded, length) = encode_code_point(surrogate);
+
+  decoded.extend_from_slice(&encoded[..length]);
+}
 
-      // Invalid unicode scalar value
-      // Either a high or low surrogate or not a valid unicode character
-      if total > 0xD7FF && total < 0xE000 || total > 0x10FFFF {
-        return Err(UriError::InvalidUtf8Character);
+/// A [`Sink`] that writes UTF-16 code units, which can also hold unpaired surrogates.
+pub(crate) struct Utf16<'a>(pub(crate) &'a mut Vec<u16>);
+
+impl Utf16<'_> {
+  /// Writes an unpaired surrogate as is.
+  pub(crate) fn push_surrogate(&mut self, surrogate: u32) {
+    self.0.push(surrogate as u16);
+  }
+}
+
+impl Sink for Utf16<'_> {
+  #[inline]
+  fn push_str(&mut self, string: &str) {
+    self.0.extend(string.encode_utf16());
+  }
+
+  #[inline]
+  fn reserve(&mut self, additional: usize) {
+    self.0.reserve(additional);
+  }
+}
+
+/// Decodes a URI, where unescaped surrogates are only allowed when there's a `push_surrogate` to
+/// write them with.
+fn decode<S: Sink + ?Sized>(
+  bytes: &[u8],
+  reserved: &AsciiSet,
+  decoded: &mut S,
+  push_surrogate: Option<fn(&mut S, u32)>,
+) -> Result<(), UriError> {
+  // The unescaped characters are validated up front, so that they're decoded without checks up to
+  // the first invalid one. An error of an escape before it still comes first.
+  let (valid, result) = match utf8::validate(bytes, push_surrogate.is_some()) {
+    Ok(()) => (bytes.len(), Ok(())),
+    Err(error) => (error.index(), Err(error)),
+  };
+  let mut idx = 0;
+
+  while idx < valid {
+    // SAFETY: We'd have at least a single byte here.
+    let byte = unsafe { *bytes.get_unchecked(idx) };
+
+    if byte != b'%' {
+      if byte < 0x80 {
+        // SAFETY: We'd have at least a single byte here.
+        let rest = unsafe { bytes.get_unchecked(idx..) };
+        let length = literal_len(rest, true);
+
+        // SAFETY: The length is at most the length of `rest`.
+        push_ascii(decoded, unsafe { rest.get_unchecked(..length) });
+        idx += length;
+        continue;
       }
 
-      idx += bytes_needed;
+      let (code_point, length) = utf8::decode_valid(&bytes[idx..]);
 
-      // Get the high and low bits if needed
-      if total < UTF8_SURROGATE {
-        if reserved.iter().any(|&x| x as u32 == total) {
-          decoded.extend_from_slice(&[b'%', unsafe { *bytes.get_unchecked(idx - 1) }, unsafe {
-            *bytes.get_unchecked(idx)
-          }]);
-        } else {
-          decoded.extend_from_slice(&decode_two_octets(total as u16));
+      match push_surrogate {
+        Some(push_surrogate) if (0xD800..=0xDFFF).contains(&code_point) => {
+          push_surrogate(decoded, code_point);
         }
+        // SAFETY: The bytes were validated, and they aren't a surrogate.
+        _ => decoded.push_str(unsafe { std::str::from_utf8_unchecked(&bytes[idx..idx + length]) }),
+      }
+
+      idx += length;
+      continue;
+    }
+
+    let start = idx;
+    let error = |kind, end: usize| UriError::new(kind, start, &bytes[start..end.min(bytes.len())]);
+    let lead = escaped_byte(bytes, idx).map_err(|kind| error(kind, idx + 3))?;
+
+    // Single byte
+    if lead < 0x80 {
+      if reserved.contains(lead) {
+        // SAFETY: `escaped_byte` checked that there are 3 bytes.
+        push_ascii(decoded, unsafe { bytes.get_unchecked(idx..idx + 3) });
       } else {
-        let low = ((total - UTF8_SURROGATE) & 0x3FF) + 0xDC00;
-        let high = (((total - UTF8_SURROGATE) >> RESERVED_OCTETS_BITS) & 0x3FF) + 0xD800;
-        let [hi_0, hi_1] = decode_two_octets(high as u16);
-        let [lo_0, lo_1] = decode_two_octets(low as u16);
+        push_ascii(decoded, &[lead]);
+      }
+
+      idx += 3;
+      continue;
+    }
+
+    // Gets the number of octets from the lead byte
+    let length = match lead {
+      0xC0..=0xDF => 2,
+      0xE0..=0xEF => 3,
+      0xF0..=0xF7 => 4,
+      // A continuation byte or an invalid byte
+      _ => return Err(error(UriErrorKind::InvalidLeadByte, idx + 3)),
+    };
+
+    let end = idx + length * 3;
+
+    if end > bytes.len() {
+      return Err(error(UriErrorKind::Truncated, end));
+    }
+
+    let mut octets = [lead, 0, 0, 0];
+
+    for (octet, continuation) in octets[1..length].iter_mut().enumerate() {
+      let octet_idx = idx + (octet + 1) * 3;
+
+      *continuation = escaped_byte(bytes, octet_idx).map_err(|kind| error(kind, octet_idx + 3))?;
 
-        decoded.extend_from_slice(&[hi_0, hi_1, lo_0, lo_1]);
+      if *continuation & 0xC0 != 0x80 {
+        return Err(error(UriErrorKind::InvalidContinuation, octet_idx + 3));
       }
-    } else {
-      decoded.push(byte);
     }
 
-    idx += 1;
+    // Either an overlong encoding, a surrogate or a code point above U+10FFFF, which are invalid
+    // even when unescaped surrogates are allowed
+    decode_code_point(&octets[..length], false).map_err(|(kind, _)| error(kind, end))?;
+
+    // SAFETY: The octets were just validated.
+    decoded.push_str(unsafe { std::str::from_utf8_unchecked(&octets[..length]) });
+    idx = end;
   }
 
-  Ok(())
+  result
+}
+
+/// Reads the byte escaped as `%XX` at `idx`.
+#[inline]
+fn escaped_byte(bytes: &[u8], idx: usize) -> Result<u8, UriErrorKind> {
+  match bytes.get(idx..idx + 3) {
+    Some(&[b'%', one, two]) => {
+      let value = hex_value(one, two);
+
+      if value > u8::MAX as u32 {
+        return Err(UriErrorKind::InvalidHexDigit);
+      }
+
+      Ok(value as u8)
+    }
+    _ => Err(UriErrorKind::Truncated),
+  }
 }
@@ -1,5 +1,9 @@
-use crate::globals::utils::byte_to_hex;
-use crate::globals::UriError;
+use crate::globals::simd::unencoded_len;
+use crate::globals::utils::{byte_to_hex, encode_code_point};
+use crate::globals::{validate_utf8, AsciiSet, UriError, UriErrorKind};
+use crate::sink::{push_ascii, Sink, SliceSink};
+
+use std::borrow::Cow;
 
 /// Encodes a UTF-8 URI, reserving any character in the set
 /// `ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!#$&'()*+,-./:;=?@_~`.
@@ -7,11 +11,8 @@ use crate::globals::UriError;
 /// # Notes
 /// This function functionally behaves the same as
 /// [JavaScript's encodeURI](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI).
-pub fn encode_uri(bytes: &[u8], encoded: &mut Vec<u8>) -> Result<(), UriError> {
-  const RESERVED: &[u8] =
-    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!#$&'()*+,-./:;=?@_~";
-
-  encode_inner(bytes, RESERVED, encoded)
+pub fn encode_uri<S: Sink + ?Sized>(bytes: &[u8], encoded: &mut S) -> Result<(), UriError> {
+  encode_inner(bytes, &ENCODE_URI_SET, encoded)
 }
 
 /// Encodes a UTF-8 URI, reserving any character in the set
@@ -20,65 +21,215 @@ pub fn encode_uri(bytes: &[u8], encoded: &mut Vec<u8>) -> Result<(), UriError> {
 /// # Notes
 /// This function functionally behaves the same as
 /// [JavaScript's encodeURI](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURIComponent).
-pub fn encode_uri_component(bytes: &[u8], encoded: &mut Vec<u8>) -> Result<(), UriError> {
-  const RESERVED: &[u8] =
-    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!'()*-._~";
+pub fn encode_uri_component<S: Sink + ?Sized>(
+  bytes: &[u8],
+  encoded: &mut S,
+) -> Result<(), UriError> {
+  encode_inner(bytes, &ENCODE_URI_COMPONENT_SET, encoded)
+}
+
+/// Encodes a UTF-16 URI, reserving any character in the set
+/// `ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!#$&'()*+,-./:;=?@_~`.
+///
+/// # Notes
+/// This is the same as [`encode_uri`], but reads the UTF-16 code units of a JavaScript string,
+/// erroring on unpaired surrogates.
+pub fn encode_uri_utf16<S: Sink + ?Sized>(units: &[u16], encoded: &mut S) -> Result<(), UriError> {
+  encode_inner_utf16(units, &ENCODE_URI_SET, encoded)
+}
+
+/// Encodes a UTF-16 URI component, reserving any character in the set
+/// `ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!'()*-._~`.
+///
+/// # Notes
+/// This is the same as [`encode_uri_component`], but reads the UTF-16 code units of a JavaScript
+/// string, erroring on unpaired surrogates.
+pub fn encode_uri_component_utf16<S: Sink + ?Sized>(
+  units: &[u16],
+  encoded: &mut S,
+) -> Result<(), UriError> {
+  encode_inner_utf16(units, &ENCODE_URI_COMPONENT_SET, encoded)
+}
+
+/// Encodes a URI, reserving any character in the set
+/// `ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!#$&'()*+,-./:;=?@_~`.
+///
+/// # Notes
+/// This is the same as [`encode_uri`], but borrows the input when nothing needs encoding, and
+/// only allocates from the first character that does otherwise.
+pub fn encode_uri_cow(string: &str) -> Cow<'_, str> {
+  encode_cow(string, &ENCODE_URI_SET)
+}
 
-  encode_inner(bytes, RESERVED, encoded)
+/// Encodes a URI component, reserving any character in the set
+/// `ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!'()*-._~`.
+///
+/// # Notes
+/// This is the same as [`encode_uri_component`], but borrows the input when nothing needs
+/// encoding, and only allocates from the first character that does otherwise.
+pub fn encode_uri_component_cow(string: &str) -> Cow<'_, str> {
+  encode_cow(string, &ENCODE_URI_COMPONENT_SET)
 }
 
+pub(crate) const ENCODE_URI_SET: AsciiSet = AsciiSet::from_bytes(
+  b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!#$&'()*+,-./:;=?@_~",
+)
+.complement();
+pub(crate) const ENCODE_URI_COMPONENT_SET: AsciiSet =
+  AsciiSet::from_bytes(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!'()*-._~")
+    .complement();
+
 /// Fast UTF-8 uri encode function with multi-byte and reserved character support.
 ///
 /// This function follows the [EMCA-262 spec](https://tc39.es/ecma262/#sec-encode)
-/// for encoding URIs.
-pub fn encode_inner(bytes: &[u8], reserved: &[u8], encoded: &mut Vec<u8>) -> Result<(), UriError> {
+/// for encoding URIs, percent encoding the ASCII characters in `set`. Non-ASCII characters are
+/// always percent encoded, and the input is validated like [`validate_utf8`] does.
+pub fn encode_inner<S: Sink + ?Sized>(
+  bytes: &[u8],
+  set: &AsciiSet,
+  encoded: &mut S,
+) -> Result<(), UriError> {
+  encode(bytes, set, encoded)
+}
+
+/// Percent encodes a string, escaping the ASCII characters in `set` and every non-ASCII character.
+///
+/// # Notes
+/// This is the same as [`encode_inner`], but a `str` is always valid UTF-8, so it isn't validated
+/// again and encoding can't fail.
+pub fn encode_str<S: Sink + ?Sized>(string: &str, set: &AsciiSet, encoded: &mut S) {
+  encode_valid(string.as_bytes(), set, encoded);
+}
+
+/// Encodes a UTF-8 URI to the start of `encoded`, percent encoding the ASCII characters in `set`
+/// and every non-ASCII character. Returns the number of bytes written.
+///
+/// # Notes
+/// This is the same as [`encode_inner`], but writes to a buffer that's at least
+/// [`encoded_uri_len`] bytes long, through a [`SliceSink`].
+///
+/// # Panics
+/// This function panics if `encoded` is shorter than [`encoded_uri_len`].
+pub fn encode_inner_to_slice(
+  bytes: &[u8],
+  set: &AsciiSet,
+  encoded: &mut [u8],
+) -> Result<usize, UriError> {
+  assert!(
+    encoded.len() >= encoded_uri_len(bytes, set),
+    "the buffer is too short for the encoded URI"
+  );
+
+  let mut sink = SliceSink::new(encoded);
+
+  encode(bytes, set, &mut sink)?;
+
+  Ok(sink.len())
+}
+
+/// Returns the length of a UTF-8 URI once the ASCII characters in `set`, and every non-ASCII
+/// character, are percent encoded.
+///
+/// # Notes
+/// The length is exact when encoding the URI succeeds.
+pub fn encoded_uri_len(bytes: &[u8], set: &AsciiSet) -> usize {
+  let mut length = 0;
   let mut index = 0;
 
   while index < bytes.len() {
     // SAFETY: Guaranteed to be valid because of `index < len`.
-    let current = unsafe { *bytes.get_unchecked(index) };
+    let rest = unsafe { bytes.get_unchecked(index..) };
+    let unencoded = unencoded_len(rest, set);
+
+    length += unencoded;
+    index += unencoded;
 
-    if reserved.contains(&current) {
-      encoded.push(current);
+    // Every other byte is escaped
+    if index < bytes.len() {
+      length += 3;
       index += 1;
-      continue;
     }
+  }
 
-    let bytes_needed = match current {
-      // One octet
-      x if x < 0x80 => {
-        // ASCII fast path
-        encoded.extend_from_slice(&percent_hex(x));
-        index += 1;
-        continue;
-      }
-      // Two octets
-      x if x < 0xE0 => 1,
-      // Three octets
-      x if x < 0xF0 => 2,
-      // Four octets
-      x if x < 0xF8 => 3,
-      // Invalid
-      _ => return Err(UriError::InvalidUtf8Character),
-    };
+  length
+}
+
+fn encode<S: Sink + ?Sized>(bytes: &[u8], set: &AsciiSet, encoded: &mut S) -> Result<(), UriError> {
+  // Everything up to the first invalid sequence is encoded before its error is returned
+  let (bytes, result) = match validate_utf8(bytes) {
+    Ok(()) => (bytes, Ok(())),
+    Err(error) => (&bytes[..error.index()], Err(error)),
+  };
+
+  encode_valid(bytes, set, encoded);
+
+  result
+}
+
+/// Encodes bytes that are valid UTF-8.
+fn encode_valid<S: Sink + ?Sized>(bytes: &[u8], set: &AsciiSet, encoded: &mut S) {
+  let mut index = 0;
 
-    if index + bytes_needed >= bytes.len() {
-      return Err(UriError::InvalidUri);
+  while index < bytes.len() {
+    // SAFETY: Guaranteed to be valid because of `index < len`.
+    let current = unsafe { *bytes.get_unchecked(index) };
+
+    if current < 0x80 && !set.contains(current) {
+      // SAFETY: Guaranteed to be valid because of `index < len`.
+      let rest = unsafe { bytes.get_unchecked(index..) };
+      let length = unencoded_len(rest, set);
+
+      // SAFETY: The length is at most the length of `rest`.
+      push_ascii(encoded, unsafe { rest.get_unchecked(..length) });
+      index += length;
+      continue;
     }
 
-    encoded.extend_from_slice(&percent_hex(current));
+    // The input is valid, so every byte of a multi-byte character is escaped on its own
+    push_ascii(encoded, &percent_hex(current));
+    index += 1;
+  }
+}
 
-    for i in 0..bytes_needed {
-      // SAFETY: We check if we have enough bytes beforehand.
-      let byte = unsafe { *bytes.get_unchecked(index + i + 1) };
+/// UTF-16 uri encode function with surrogate pair and reserved character support.
+///
+/// This function follows the [EMCA-262 spec](https://tc39.es/ecma262/#sec-encode)
+/// for encoding URIs, which errors on unpaired surrogates. The ASCII characters in `set` are
+/// percent encoded, along with every non-ASCII character.
+pub fn encode_inner_utf16<S: Sink + ?Sized>(
+  units: &[u16],
+  set: &AsciiSet,
+  encoded: &mut S,
+) -> Result<(), UriError> {
+  let mut index = 0;
 
-      // It should be a continuation byte
-      if byte & 0xC0 != 0x80 {
-        return Err(UriError::InvalidUtf8Character);
-      }
+  while index < units.len() {
+    let current = units[index];
 
-      encoded.extend_from_slice(&percent_hex(byte));
+    if current < 0x80 && !set.contains(current as u8) {
+      push_ascii(encoded, &[current as u8]);
       index += 1;
+      continue;
+    }
+
+    let code_point = match current {
+      // Lead surrogate, which must be followed by a trail surrogate
+      0xD800..=0xDBFF => match units.get(index + 1) {
+        Some(&trail @ 0xDC00..=0xDFFF) => {
+          index += 1;
+          0x10000 + (((current as u32) - 0xD800) << 10) + (trail as u32 - 0xDC00)
+        }
+        _ => return Err(unpaired_surrogate(current, index)),
+      },
+      // Unpaired trail surrogate
+      0xDC00..=0xDFFF => return Err(unpaired_surrogate(current, index)),
+      _ => current as u32,
+    };
+
+    let (octets, length) = encode_code_point(code_point);
+
+    for &octet in &octets[..length] {
+      push_ascii(encoded, &percent_hex(octet));
     }
 
     index += 1;
@@ -87,6 +238,32 @@ pub fn encode_inner(bytes: &[u8], reserved: &[u8], encoded: &mut Vec<u8>) -> Res
   Ok(())
 }
 
+fn encode_cow<'a>(string: &'a str, set: &AsciiSet) -> Cow<'a, str> {
+  let bytes = string.as_bytes();
+  let unchanged = unencoded_len(bytes, set);
+
+  if unchanged == bytes.len() {
+    return Cow::Borrowed(string);
+  }
+
+  let mut encoded = Vec::with_capacity(bytes.len() + 16);
+
+  encoded.extend_from_slice(&bytes[..unchanged]);
+
+  encode_str(&string[unchanged..], set, &mut encoded);
+
+  // SAFETY: Every byte written is ASCII.
+  Cow::Owned(unsafe { String::from_utf8_unchecked(encoded) })
+}
+
+/// Creates the error for an unpaired surrogate, keeping it as WTF-8.
+#[cold]
+fn unpaired_surrogate(unit: u16, index: usize) -> UriError {
+  let (encoded, length) = encode_code_point(unit as u32);
+
+  UriError::new(UriErrorKind::Surrogate, index, &encoded[..length])
+}
+
 #[inline]
 fn percent_hex(byte: u8) -> [u8; 3] {
   let [one, two] = byte_to_hex(byte);
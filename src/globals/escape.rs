@@ -1,4 +1,13 @@
-use crate::globals::utils::byte_to_hex;
+use crate::globals::simd::unencoded_len;
+use crate::globals::utils::{byte_to_hex, decode_code_point};
+use crate::globals::AsciiSet;
+use crate::sink::{push_ascii, Sink};
+
+use std::borrow::Cow;
+
+const RESERVED: AsciiSet =
+  AsciiSet::from_bytes(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789@*_+-./");
+const ESCAPED: AsciiSet = RESERVED.complement();
 
 /// Escapes a string.
 ///
@@ -9,48 +18,96 @@ use crate::globals::utils::byte_to_hex;
 /// [`escape`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/escape)
 ///
 /// [`unescape`]: crate::polyfills::globals::unescape
-pub fn escape(bytes: &[u8], escaped: &mut Vec<u8>) {
-  const RESERVED: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789@*_+-./";
-
+pub fn escape<S: Sink + ?Sized>(bytes: &[u8], escaped: &mut S) {
   let mut idx = 0;
 
   while idx < bytes.len() {
     // SAFETY: `idx < bytes.len()` condition guarantees that this is valid.
-    let mut current = unsafe { *bytes.get_unchecked(idx) };
+    let current = unsafe { *bytes.get_unchecked(idx) };
+
+    if RESERVED.contains(current) {
+      // SAFETY: `idx < bytes.len()` condition guarantees that this is valid.
+      let rest = unsafe { bytes.get_unchecked(idx..) };
+      let length = unencoded_len(rest, &ESCAPED);
 
-    if RESERVED.contains(&current) {
-      escaped.push(current);
-      idx += 1;
+      // SAFETY: The length is at most the length of `rest`.
+      push_ascii(escaped, unsafe { rest.get_unchecked(..length) });
+      idx += length;
       continue;
     }
 
-    if current >= 0x80 && idx + 1 < bytes.len() {
-      // SAFETY: There would be another byte due to the branch's condition.
-      let next = unsafe { *bytes.get_unchecked(idx + 1) };
-
-      // Continuation byte
-      if next & 0xC0 == 0x80 {
-        let val = ((current as u16 & 0x1F) << 6) | (next as u16 & 0x3F);
-
-        if val < u8::MAX as u16 {
-          // Get the LSBs only
-          current = (val & 0xFF) as u8;
-          idx += 1;
+    if current >= 0x80 {
+      // Surrogates are accepted so that WTF-8 escapes like a JavaScript string would
+      if let Ok((code_point, length)) = decode_code_point(&bytes[idx..], true) {
+        if code_point < 0x10000 {
+          escape_unit(code_point as u16, escaped);
         } else {
-          let [one, two] = byte_to_hex((val >> 8) as u8);
-          let [three, four] = byte_to_hex((val & 0xFF) as u8);
+          // Astral characters are escaped as a surrogate pair
+          let offset = code_point - 0x10000;
 
-          escaped.extend_from_slice(&[b'%', b'u', one, two, three, four]);
-          idx += 2;
-
-          continue;
+          escape_unit((0xD800 + (offset >> 10)) as u16, escaped);
+          escape_unit((0xDC00 + (offset & 0x3FF)) as u16, escaped);
         }
+
+        idx += length;
+        continue;
       }
     }
 
-    let [one, two] = byte_to_hex(current);
-
-    escaped.extend_from_slice(&[b'%', one, two]);
+    // ASCII, or a byte that isn't part of a valid character
+    escape_unit(current as u16, escaped);
     idx += 1;
   }
 }
+
+/// Escapes a UTF-16 string.
+///
+/// # Notes
+/// This is the same as [`escape`], but reads the UTF-16 code units of a JavaScript string, where
+/// every code unit is escaped on its own.
+pub fn escape_utf16<S: Sink + ?Sized>(units: &[u16], escaped: &mut S) {
+  for &unit in units {
+    if unit < 0x80 && RESERVED.contains(unit as u8) {
+      push_ascii(escaped, &[unit as u8]);
+    } else {
+      escape_unit(unit, escaped);
+    }
+  }
+}
+
+/// Escapes a string.
+///
+/// # Notes
+/// This is the same as [`escape`], but borrows the input when nothing needs escaping, and only
+/// allocates from the first character that does otherwise.
+pub fn escape_cow(string: &str) -> Cow<'_, str> {
+  let bytes = string.as_bytes();
+  let unchanged = unencoded_len(bytes, &ESCAPED);
+
+  if unchanged == bytes.len() {
+    return Cow::Borrowed(string);
+  }
+
+  let mut escaped = Vec::with_capacity(bytes.len() + 16);
+
+  escaped.extend_from_slice(&bytes[..unchanged]);
+  escape(&bytes[unchanged..], &mut escaped);
+
+  // SAFETY: Every byte written is ASCII.
+  Cow::Owned(unsafe { String::from_utf8_unchecked(escaped) })
+}
+
+/// Escapes a code unit as `%XX` when it fits in a byte, and as `%uXXXX` otherwise.
+#[inline]
+fn escape_unit<S: Sink + ?Sized>(unit: u16, escaped: &mut S) {
+  if unit <= u8::MAX as u16 {
+    let [one, two] = byte_to_hex(unit as u8);
+
+    push_ascii(escaped, &[b'%', one, two]);
+  } else {
+    let [one, two] = byte_to_hex((unit >> 8) as u8);
+    let [three, four] = byte_to_hex((unit & 0xFF) as u8);
+
+    push_ascii(escaped, &[b'%', b'u', one, two, three, four]);
+  }
+}
@@ -1,21 +1,127 @@
+mod ascii_set;
 mod decode_uri;
 mod encode_uri;
 mod escape;
+mod simd;
+mod stream;
 mod unescape;
+mod utf8;
 
 /// Global utilities.
 pub mod utils;
 
+pub use ascii_set::*;
 pub use decode_uri::*;
 pub use encode_uri::*;
-pub use escape::escape;
-pub use unescape::unescape;
+pub use escape::{escape, escape_cow, escape_utf16};
+pub use stream::*;
+pub use unescape::{unescape, unescape_cow, unescape_to, unescape_utf16};
+pub use utf8::validate_utf8;
+
+use std::fmt;
+
+/// The longest sequence kept by a [`UriError`], which is a 4 byte character where every byte is
+/// percent encoded.
+const MAX_SEQUENCE_LENGTH: usize = 12;
 
 /// An error when encoding or decoding a URI.
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum UriError {
-  /// The provided URI was malformed.
-  InvalidUri,
-  /// An unexpected character was encountered.
-  InvalidUtf8Character,
+pub struct UriError {
+  kind: UriErrorKind,
+  index: usize,
+  sequence: [u8; MAX_SEQUENCE_LENGTH],
+  sequence_length: u8,
+}
+
+/// The kind of a [`UriError`].
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum UriErrorKind {
+  /// An escape sequence or a multi-byte character ended early.
+  Truncated,
+  /// An escape sequence contained a character that isn't a hexadecimal digit.
+  InvalidHexDigit,
+  /// A byte that can't start a UTF-8 character was encountered.
+  InvalidLeadByte,
+  /// A byte that should've continued a UTF-8 character didn't.
+  InvalidContinuation,
+  /// A character was encoded with more bytes than necessary.
+  Overlong,
+  /// A surrogate was encoded in UTF-8, or was unpaired in UTF-16.
+  Surrogate,
+  /// A code point above U+10FFFF was encoded.
+  OutOfRange,
 }
+
+impl UriError {
+  /// Creates an error of the given kind for the sequence found at `index`.
+  ///
+  /// # Notes
+  /// Only the first 12 bytes of the sequence are kept.
+  pub fn new(kind: UriErrorKind, index: usize, sequence: &[u8]) -> Self {
+    let length = sequence.len().min(MAX_SEQUENCE_LENGTH);
+    let mut buffer = [0; MAX_SEQUENCE_LENGTH];
+
+    buffer[..length].copy_from_slice(&sequence[..length]);
+
+    UriError {
+      kind,
+      index,
+      sequence: buffer,
+      sequence_length: length as u8,
+    }
+  }
+
+  /// The kind of error.
+  #[inline]
+  pub const fn kind(&self) -> UriErrorKind {
+    self.kind
+  }
+
+  /// The index of the offending sequence in the input.
+  ///
+  /// # Notes
+  /// This is a byte index, except for the functions reading UTF-16, where it's an index of code
+  /// units.
+  #[inline]
+  pub const fn index(&self) -> usize {
+    self.index
+  }
+
+  /// The offending sequence, as found in the input.
+  ///
+  /// # Notes
+  /// Unpaired surrogates read from UTF-16 are encoded as
+  /// [WTF-8](https://simonsapin.github.io/wtf-8/).
+  #[inline]
+  pub fn sequence(&self) -> &[u8] {
+    &self.sequence[..self.sequence_length as usize]
+  }
+}
+
+impl fmt::Display for UriErrorKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      UriErrorKind::Truncated => "truncated sequence",
+      UriErrorKind::InvalidHexDigit => "invalid hexadecimal digit",
+      UriErrorKind::InvalidLeadByte => "invalid UTF-8 lead byte",
+      UriErrorKind::InvalidContinuation => "invalid UTF-8 continuation byte",
+      UriErrorKind::Overlong => "overlong UTF-8 encoding",
+      UriErrorKind::Surrogate => "surrogate code point",
+      UriErrorKind::OutOfRange => "code point above U+10FFFF",
+    })
+  }
+}
+
+impl fmt::Display for UriError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} `{}` at index {}",
+      self.kind,
+      self.sequence().escape_ascii(),
+      self.index
+    )
+  }
+}
+
+impl std::error::Error for UriError {}
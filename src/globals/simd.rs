@@ -0,0 +1,424 @@
+use crate::globals::AsciiSet;
+
+/// Returns the length of the run of ASCII bytes, at the start of `bytes`, that aren't in `set`.
+///
+/// # Notes
+/// On x86-64, the bytes are classified 32 at a time with AVX2, or 16 at a time with SSSE3, when
+/// the CPU supports either.
+#[inline]
+pub(crate) fn unencoded_len(bytes: &[u8], set: &AsciiSet) -> usize {
+  #[cfg(target_arch = "x86_64")]
+  {
+    if bytes.len() >= 32 && is_x86_feature_detected!("avx2") {
+      // SAFETY: The CPU supports AVX2.
+      return unsafe { x86::unencoded_len_avx2(bytes, set) };
+    }
+
+    if bytes.len() >= 16 && is_x86_feature_detected!("ssse3") {
+      // SAFETY: The CPU supports SSSE3.
+      return unsafe { x86::unencoded_len_ssse3(bytes, set) };
+    }
+  }
+
+  unencoded_len_scalar(bytes, set)
+}
+
+/// Returns the length of the run of bytes, at the start of `bytes`, that aren't `%`, stopping at
+/// non-ASCII bytes as well when `stop_at_non_ascii` is set.
+///
+/// # Notes
+/// On x86-64, the bytes are scanned 32 at a time with AVX2 when the CPU supports it, and 16 at a
+/// time with SSE2 otherwise.
+#[inline]
+pub(crate) fn literal_len(bytes: &[u8], stop_at_non_ascii: bool) -> usize {
+  #[cfg(target_arch = "x86_64")]
+  {
+    if bytes.len() >= 32 && is_x86_feature_detected!("avx2") {
+      // SAFETY: The CPU supports AVX2.
+      return unsafe { x86::literal_len_avx2(bytes, stop_at_non_ascii) };
+    }
+
+    if bytes.len() >= 16 {
+      // SAFETY: SSE2 is part of x86-64.
+      return unsafe { x86::literal_len_sse2(bytes, stop_at_non_ascii) };
+    }
+  }
+
+  literal_len_scalar(bytes, stop_at_non_ascii)
+}
+
+/// Returns the length of a prefix of `bytes` that's valid UTF-8 and ends at a character boundary.
+/// The prefix stops before the first invalid sequence or surrogate, but may stop earlier.
+///
+/// # Notes
+/// On x86-64, the bytes are checked 32 at a time with AVX2, or 16 at a time with SSSE3, when the
+/// CPU supports either. Only the run of ASCII bytes is skipped otherwise.
+#[inline]
+pub(crate) fn valid_utf8_len(bytes: &[u8]) -> usize {
+  #[cfg(target_arch = "x86_64")]
+  {
+    if bytes.len() >= 32 && is_x86_feature_detected!("avx2") {
+      // SAFETY: The CPU supports AVX2.
+      return unsafe { x86::valid_utf8_len_avx2(bytes) };
+    }
+
+    if bytes.len() >= 16 && is_x86_feature_detected!("ssse3") {
+      // SAFETY: The CPU supports SSSE3.
+      return unsafe { x86::valid_utf8_len_ssse3(bytes) };
+    }
+  }
+
+  ascii_len_scalar(bytes)
+}
+
+#[inline]
+fn unencoded_len_scalar(bytes: &[u8], set: &AsciiSet) -> usize {
+  bytes
+    .iter()
+    .position(|&byte| byte >= 0x80 || set.contains(byte))
+    .unwrap_or(bytes.len())
+}
+
+#[inline]
+fn literal_len_scalar(bytes: &[u8], stop_at_non_ascii: bool) -> usize {
+  bytes
+    .iter()
+    .position(|&byte| byte == b'%' || (stop_at_non_ascii && byte >= 0x80))
+    .unwrap_or(bytes.len())
+}
+
+#[inline]
+fn ascii_len_scalar(bytes: &[u8]) -> usize {
+  bytes
+    .iter()
+    .position(|&byte| byte >= 0x80)
+    .unwrap_or(bytes.len())
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+  use super::{literal_len_scalar, unencoded_len_scalar};
+  use crate::globals::AsciiSet;
+
+  use std::arch::x86_64::*;
+
+  // The bit of each high nibble of an ASCII byte, where non-ASCII bytes have no bit.
+  const HIGH_NIBBLE_BITS: [i8; 16] = [1, 2, 4, 8, 16, 32, 64, -128, 0, 0, 0, 0, 0, 0, 0, 0];
+
+  /// Classifies 32 bytes at a time, by looking up the low nibble of each byte in
+  /// [`AsciiSet::nibbles`] and checking for the bit of its high nibble.
+  #[target_feature(enable = "avx2")]
+  pub(super) unsafe fn unencoded_len_avx2(bytes: &[u8], set: &AsciiSet) -> usize {
+    let nibbles = _mm_loadu_si128(set.nibbles().as_ptr() as *const __m128i);
+    let nibbles = _mm256_broadcastsi128_si256(nibbles);
+    let high_bits = _mm_loadu_si128(HIGH_NIBBLE_BITS.as_ptr() as *const __m128i);
+    let high_bits = _mm256_broadcastsi128_si256(high_bits);
+    let low_mask = _mm256_set1_epi8(0x0F);
+    let mut idx = 0;
+
+    while idx + 32 <= bytes.len() {
+      let chunk = _mm256_loadu_si256(bytes.as_ptr().add(idx) as *const __m256i);
+      let low = _mm256_and_si256(chunk, low_mask);
+      let high = _mm256_and_si256(_mm256_srli_epi16(chunk, 4), low_mask);
+      let in_set = _mm256_and_si256(
+        _mm256_shuffle_epi8(nibbles, low),
+        _mm256_shuffle_epi8(high_bits, high),
+      );
+      let not_in_set = _mm256_movemask_epi8(_mm256_cmpeq_epi8(in_set, _mm256_setzero_si256()));
+      // Non-ASCII bytes have their sign bit set
+      let unencoded = not_in_set as u32 & !(_mm256_movemask_epi8(chunk) as u32);
+
+      if unencoded != u32::MAX {
+        return idx + (!unencoded).trailing_zeros() as usize;
+      }
+
+      idx += 32;
+    }
+
+    idx + unencoded_len_scalar(&bytes[idx..], set)
+  }
+
+  /// The same as [`unencoded_len_avx2`], but classifies 16 bytes at a time.
+  #[target_feature(enable = "ssse3")]
+  pub(super) unsafe fn unencoded_len_ssse3(bytes: &[u8], set: &AsciiSet) -> usize {
+    let nibbles = _mm_loadu_si128(set.nibbles().as_ptr() as *const __m128i);
+    let high_bits = _mm_loadu_si128(HIGH_NIBBLE_BITS.as_ptr() as *const __m128i);
+    let low_mask = _mm_set1_epi8(0x0F);
+    let mut idx = 0;
+
+    while idx + 16 <= bytes.len() {
+      let chunk = _mm_loadu_si128(bytes.as_ptr().add(idx) as *const __m128i);
+      let low = _mm_and_si128(chunk, low_mask);
+      let high = _mm_and_si128(_mm_srli_epi16(chunk, 4), low_mask);
+      let in_set = _mm_and_si128(
+        _mm_shuffle_epi8(nibbles, low),
+        _mm_shuffle_epi8(high_bits, high),
+      );
+      let not_in_set = _mm_movemask_epi8(_mm_cmpeq_epi8(in_set, _mm_setzero_si128()));
+      let unencoded = not_in_set as u32 & !(_mm_movemask_epi8(chunk) as u32) & 0xFFFF;
+
+      if unencoded != 0xFFFF {
+        return idx + (!unencoded).trailing_zeros() as usize;
+      }
+
+      idx += 16;
+    }
+
+    idx + unencoded_len_scalar(&bytes[idx..], set)
+  }
+
+  #[target_feature(enable = "avx2")]
+  pub(super) unsafe fn literal_len_avx2(bytes: &[u8], stop_at_non_ascii: bool) -> usize {
+    let percent = _mm256_set1_epi8(b'%' as i8);
+    let non_ascii = if stop_at_non_ascii { u32::MAX } else { 0 };
+    let mut idx = 0;
+
+    while idx + 32 <= bytes.len() {
+      let chunk = _mm256_loadu_si256(bytes.as_ptr().add(idx) as *const __m256i);
+      let stops = _mm256_movemask_epi8(_mm256_cmpeq_epi8(chunk, percent)) as u32
+        | (_mm256_movemask_epi8(chunk) as u32 & non_ascii);
+
+      if stops != 0 {
+        return idx + stops.trailing_zeros() as usize;
+      }
+
+      idx += 32;
+    }
+
+    idx + literal_len_scalar(&bytes[idx..], stop_at_non_ascii)
+  }
+
+  #[target_feature(enable = "sse2")]
+  pub(super) unsafe fn literal_len_sse2(bytes: &[u8], stop_at_non_ascii: bool) -> usize {
+    let percent = _mm_set1_epi8(b'%' as i8);
+    let non_ascii = if stop_at_non_ascii { u32::MAX } else { 0 };
+    let mut idx = 0;
+
+    while idx + 16 <= bytes.len() {
+      let chunk = _mm_loadu_si128(bytes.as_ptr().add(idx) as *const __m128i);
+      let stops = _mm_movemask_epi8(_mm_cmpeq_epi8(chunk, percent)) as u32
+        | (_mm_movemask_epi8(chunk) as u32 & non_ascii);
+
+      if stops != 0 {
+        return idx + stops.trailing_zeros() as usize;
+      }
+
+      idx += 16;
+    }
+
+    idx + literal_len_scalar(&bytes[idx..], stop_at_non_ascii)
+  }
+
+  // The errors found by looking at a byte along with the byte before it, following the
+  // "Validating UTF-8 In Less Than One Instruction Per Byte" algorithm by Keiser and Lemire. Each
+  // error is a bit that's set in the entries of all 3 tables only when the pair has that error.
+  /// A lead byte that isn't followed by a continuation byte.
+  const TOO_SHORT: u8 = 1 << 0;
+  /// A continuation byte that doesn't follow a lead byte.
+  const TOO_LONG: u8 = 1 << 1;
+  /// `E0` followed by `80..=9F`.
+  const OVERLONG_3: u8 = 1 << 2;
+  /// `F4` followed by `90..=BF`, or `F5..=FF` followed by `90..=BF`.
+  const TOO_LARGE: u8 = 1 << 3;
+  /// `ED` followed by `A0..=BF`.
+  const SURROGATE: u8 = 1 << 4;
+  /// `C0` or `C1` followed by a continuation byte.
+  const OVERLONG_2: u8 = 1 << 5;
+  /// `F5..=FF` followed by `80..=8F`, where `OVERLONG_4` is `F0` followed by `80..=8F`.
+  const TOO_LARGE_1000: u8 = 1 << 6;
+  const OVERLONG_4: u8 = 1 << 6;
+  /// 2 continuation bytes in a row, which is only valid as the 3rd or 4th byte of a character.
+  const TWO_CONTINUATIONS: u8 = 1 << 7;
+  /// The errors that don't depend on the low nibble of the first byte.
+  const CARRY: u8 = TOO_SHORT | TOO_LONG | TWO_CONTINUATIONS;
+
+  /// The errors of the high nibble of the first byte.
+  #[rustfmt::skip]
+  const BYTE_1_HIGH: [u8; 16] = [
+    // ASCII
+    TOO_LONG, TOO_LONG, TOO_LONG, TOO_LONG, TOO_LONG, TOO_LONG, TOO_LONG, TOO_LONG,
+    // Continuation bytes
+    TWO_CONTINUATIONS, TWO_CONTINUATIONS, TWO_CONTINUATIONS, TWO_CONTINUATIONS,
+    // Lead bytes of 2 byte characters
+    TOO_SHORT | OVERLONG_2,
+    TOO_SHORT,
+    // Lead bytes of 3 byte characters
+    TOO_SHORT | OVERLONG_3 | SURROGATE,
+    // Lead bytes of 4 byte characters
+    TOO_SHORT | TOO_LARGE | TOO_LARGE_1000 | OVERLONG_4,
+  ];
+
+  /// The errors of the low nibble of the first byte.
+  #[rustfmt::skip]
+  const BYTE_1_LOW: [u8; 16] = [
+    CARRY | OVERLONG_3 | OVERLONG_2 | OVERLONG_4,
+    CARRY | OVERLONG_2,
+    CARRY,
+    CARRY,
+    CARRY | TOO_LARGE,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000 | SURROGATE,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+  ];
+
+  /// The errors of the high nibble of the second byte.
+  #[rustfmt::skip]
+  const BYTE_2_HIGH: [u8; 16] = [
+    // ASCII
+    TOO_SHORT, TOO_SHORT, TOO_SHORT, TOO_SHORT, TOO_SHORT, TOO_SHORT, TOO_SHORT, TOO_SHORT,
+    // Continuation bytes `80..=8F`, `90..=9F` and `A0..=BF`
+    TOO_LONG | OVERLONG_2 | TWO_CONTINUATIONS | OVERLONG_3 | TOO_LARGE_1000 | OVERLONG_4,
+    TOO_LONG | OVERLONG_2 | TWO_CONTINUATIONS | OVERLONG_3 | TOO_LARGE,
+    TOO_LONG | OVERLONG_2 | TWO_CONTINUATIONS | SURROGATE | TOO_LARGE,
+    TOO_LONG | OVERLONG_2 | TWO_CONTINUATIONS | SURROGATE | TOO_LARGE,
+    // Lead bytes
+    TOO_SHORT, TOO_SHORT, TOO_SHORT, TOO_SHORT,
+  ];
+
+  /// Returns the start of the character that's cut off at `idx`, or `idx` when there's none, where
+  /// `bytes` is valid UTF-8 up to it.
+  fn char_boundary(bytes: &[u8], idx: usize) -> usize {
+    for start in (idx.saturating_sub(3)..idx).rev() {
+      let length = match bytes[start] {
+        0x80..=0xBF => continue,
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+      };
+
+      return if start + length > idx { start } else { idx };
+    }
+
+    idx
+  }
+
+  /// Checks 32 bytes at a time, by looking up the errors of each byte along with the byte before
+  /// it, and checking that the 3rd and 4th bytes of characters are continuation bytes.
+  #[target_feature(enable = "avx2")]
+  pub(super) unsafe fn valid_utf8_len_avx2(bytes: &[u8]) -> usize {
+    let byte_1_high =
+      _mm256_broadcastsi128_si256(_mm_loadu_si128(BYTE_1_HIGH.as_ptr() as *const __m128i));
+    let byte_1_low =
+      _mm256_broadcastsi128_si256(_mm_loadu_si128(BYTE_1_LOW.as_ptr() as *const __m128i));
+    let byte_2_high =
+      _mm256_broadcastsi128_si256(_mm_loadu_si128(BYTE_2_HIGH.as_ptr() as *const __m128i));
+    let low_mask = _mm256_set1_epi8(0x0F);
+    let mut previous = _mm256_setzero_si256();
+    let mut idx = 0;
+
+    while idx + 32 <= bytes.len() {
+      let chunk = _mm256_loadu_si256(bytes.as_ptr().add(idx) as *const __m256i);
+
+      // ASCII after ASCII is always valid
+      if _mm256_movemask_epi8(_mm256_or_si256(chunk, previous)) == 0 {
+        previous = chunk;
+        idx += 32;
+        continue;
+      }
+
+      // The bytes before each byte, where the ones before the first byte are from `previous`
+      let shifted = _mm256_permute2x128_si256(previous, chunk, 0x21);
+      let previous_1 = _mm256_alignr_epi8(chunk, shifted, 15);
+      let previous_2 = _mm256_alignr_epi8(chunk, shifted, 14);
+      let previous_3 = _mm256_alignr_epi8(chunk, shifted, 13);
+
+      let errors = _mm256_and_si256(
+        _mm256_and_si256(
+          _mm256_shuffle_epi8(
+            byte_1_high,
+            _mm256_and_si256(_mm256_srli_epi16(previous_1, 4), low_mask),
+          ),
+          _mm256_shuffle_epi8(byte_1_low, _mm256_and_si256(previous_1, low_mask)),
+        ),
+        _mm256_shuffle_epi8(
+          byte_2_high,
+          _mm256_and_si256(_mm256_srli_epi16(chunk, 4), low_mask),
+        ),
+      );
+      // Only `E0..=FF` and `F0..=FF` have their sign bit set after these subtractions
+      let must_continue = _mm256_and_si256(
+        _mm256_or_si256(
+          _mm256_subs_epu8(previous_2, _mm256_set1_epi8((0xE0 - 0x80) as i8)),
+          _mm256_subs_epu8(previous_3, _mm256_set1_epi8((0xF0 - 0x80) as i8)),
+        ),
+        _mm256_set1_epi8(0x80_u8 as i8),
+      );
+      // A 3rd or 4th byte has the `TWO_CONTINUATIONS` bit set, unless it wasn't a continuation
+      let errors = _mm256_xor_si256(errors, must_continue);
+
+      if _mm256_movemask_epi8(_mm256_cmpeq_epi8(errors, _mm256_setzero_si256())) != -1 {
+        break;
+      }
+
+      previous = chunk;
+      idx += 32;
+    }
+
+    char_boundary(bytes, idx)
+  }
+
+  /// The same as [`valid_utf8_len_avx2`], but checks 16 bytes at a time.
+  #[target_feature(enable = "ssse3")]
+  pub(super) unsafe fn valid_utf8_len_ssse3(bytes: &[u8]) -> usize {
+    let byte_1_high = _mm_loadu_si128(BYTE_1_HIGH.as_ptr() as *const __m128i);
+    let byte_1_low = _mm_loadu_si128(BYTE_1_LOW.as_ptr() as *const __m128i);
+    let byte_2_high = _mm_loadu_si128(BYTE_2_HIGH.as_ptr() as *const __m128i);
+    let low_mask = _mm_set1_epi8(0x0F);
+    let mut previous = _mm_setzero_si128();
+    let mut idx = 0;
+
+    while idx + 16 <= bytes.len() {
+      let chunk = _mm_loadu_si128(bytes.as_ptr().add(idx) as *const __m128i);
+
+      if _mm_movemask_epi8(_mm_or_si128(chunk, previous)) == 0 {
+        previous = chunk;
+        idx += 16;
+        continue;
+      }
+
+      let previous_1 = _mm_alignr_epi8(chunk, previous, 15);
+      let previous_2 = _mm_alignr_epi8(chunk, previous, 14);
+      let previous_3 = _mm_alignr_epi8(chunk, previous, 13);
+
+      let errors = _mm_and_si128(
+        _mm_and_si128(
+          _mm_shuffle_epi8(
+            byte_1_high,
+            _mm_and_si128(_mm_srli_epi16(previous_1, 4), low_mask),
+          ),
+          _mm_shuffle_epi8(byte_1_low, _mm_and_si128(previous_1, low_mask)),
+        ),
+        _mm_shuffle_epi8(
+          byte_2_high,
+          _mm_and_si128(_mm_srli_epi16(chunk, 4), low_mask),
+        ),
+      );
+      let must_continue = _mm_and_si128(
+        _mm_or_si128(
+          _mm_subs_epu8(previous_2, _mm_set1_epi8((0xE0 - 0x80) as i8)),
+          _mm_subs_epu8(previous_3, _mm_set1_epi8((0xF0 - 0x80) as i8)),
+        ),
+        _mm_set1_epi8(0x80_u8 as i8),
+      );
+      let errors = _mm_xor_si128(errors, must_continue);
+
+      if _mm_movemask_epi8(_mm_cmpeq_epi8(errors, _mm_setzero_si128())) != 0xFFFF {
+        break;
+      }
+
+      previous = chunk;
+      idx += 16;
+    }
+
+    char_boundary(bytes, idx)
+  }
+}
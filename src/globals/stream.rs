@@ -0,0 +1,205 @@
+use crate::globals::{
+  decode_uri_inner, encode_inner, AsciiSet, UriError, UriErrorKind, DECODE_URI_RESERVED,
+  ENCODE_URI_COMPONENT_SET, ENCODE_URI_SET, MAX_SEQUENCE_LENGTH,
+};
+use crate::sink::Sink;
+
+/// An incremental URI decoder, which decodes a URI that's split across chunks.
+///
+/// # Notes
+/// Escapes and multi-byte characters that are split across chunks are kept until the chunk that
+/// completes them, so that the output, and any error, is the same as decoding the whole URI at
+/// once with [`decode_uri_inner`].
+#[derive(Clone, Debug)]
+pub struct UriDecoder {
+  reserved: AsciiSet,
+  chunks: Chunks,
+}
+
+impl UriDecoder {
+  /// Creates a decoder that behaves like [`decode_uri`](crate::globals::decode_uri).
+  pub fn uri() -> Self {
+    UriDecoder::with_reserved(DECODE_URI_RESERVED)
+  }
+
+  /// Creates a decoder that behaves like
+  /// [`decode_uri_component`](crate::globals::decode_uri_component).
+  pub fn component() -> Self {
+    UriDecoder::with_reserved(AsciiSet::EMPTY)
+  }
+
+  /// Creates a decoder where escapes of the ASCII characters in `reserved` are kept as is.
+  pub fn with_reserved(reserved: AsciiSet) -> Self {
+    UriDecoder {
+      reserved,
+      chunks: Chunks::default(),
+    }
+  }
+
+  /// Decodes the next chunk of the URI.
+  ///
+  /// # Notes
+  /// The index of an error is relative to the start of the whole URI.
+  pub fn feed<S: Sink + ?Sized>(&mut self, bytes: &[u8], decoded: &mut S) -> Result<(), UriError> {
+    let reserved = &self.reserved;
+
+    self.chunks.feed(bytes, decoded, |bytes, decoded| {
+      decode_uri_inner(bytes, reserved, decoded)
+    })
+  }
+
+  /// Ends the URI, erroring when it ended within an escape or a multi-byte character.
+  ///
+  /// # Notes
+  /// The decoder is reset afterwards, so that it can decode another URI.
+  pub fn finish(&mut self) -> Result<(), UriError> {
+    let reserved = &self.reserved;
+
+    self
+      .chunks
+      .finish(|bytes, decoded| decode_uri_inner(bytes, reserved, decoded))
+  }
+}
+
+/// An incremental URI encoder, which encodes a UTF-8 URI that's split across chunks.
+///
+/// # Notes
+/// Multi-byte characters that are split across chunks are kept until the chunk that completes
+/// them, so that the output, and any error, is the same as encoding the whole URI at once with
+/// [`encode_inner`].
+#[derive(Clone, Debug)]
+pub struct UriEncoder {
+  set: AsciiSet,
+  chunks: Chunks,
+}
+
+impl UriEncoder {
+  /// Creates an encoder that behaves like [`encode_uri`](crate::globals::encode_uri).
+  pub fn uri() -> Self {
+    UriEncoder::with_set(ENCODE_URI_SET)
+  }
+
+  /// Creates an encoder that behaves like
+  /// [`encode_uri_component`](crate::globals::encode_uri_component).
+  pub fn component() -> Self {
+    UriEncoder::with_set(ENCODE_URI_COMPONENT_SET)
+  }
+
+  /// Creates an encoder that percent encodes the ASCII characters in `set`, along with every
+  /// non-ASCII character.
+  pub fn with_set(set: AsciiSet) -> Self {
+    UriEncoder {
+      set,
+      chunks: Chunks::default(),
+    }
+  }
+
+  /// Encodes the next chunk of the URI.
+  ///
+  /// # Notes
+  /// The index of an error is relative to the start of the whole URI.
+  pub fn feed<S: Sink + ?Sized>(&mut self, bytes: &[u8], encoded: &mut S) -> Result<(), UriError> {
+    let set = &self.set;
+
+    self.chunks.feed(bytes, encoded, |bytes, encoded| {
+      encode_inner(bytes, set, encoded)
+    })
+  }
+
+  /// Ends the URI, erroring when it ended within a multi-byte character.
+  ///
+  /// # Notes
+  /// The encoder is reset afterwards, so that it can encode another URI.
+  pub fn finish(&mut self) -> Result<(), UriError> {
+    let set = &self.set;
+
+    self
+      .chunks
+      .finish(|bytes, encoded| encode_inner(bytes, set, encoded))
+  }
+}
+
+/// The state shared by the incremental encoder and decoder.
+#[derive(Clone, Debug, Default)]
+struct Chunks {
+  /// The start of a sequence that was cut off by the end of the last chunk.
+  pending: Vec<u8>,
+  /// The index of the first byte that wasn't written yet, relative to the start of the input.
+  position: usize,
+}
+
+impl Chunks {
+  fn feed<S, F>(&mut self, mut bytes: &[u8], output: &mut S, mut run: F) -> Result<(), UriError>
+  where
+    S: Sink + ?Sized,
+    F: FnMut(&[u8], &mut S) -> Result<(), UriError>,
+  {
+    if !self.pending.is_empty() {
+      // A sequence is at most 12 bytes long, so this is enough to complete the pending one
+      let taken = bytes.len().min(MAX_SEQUENCE_LENGTH);
+      let pending_length = self.pending.len();
+      let mut buffer = std::mem::take(&mut self.pending);
+
+      buffer.extend_from_slice(&bytes[..taken]);
+
+      let written = self.run(&buffer, output, &mut run)?;
+
+      if written < pending_length {
+        // The pending sequence is still incomplete, which means the whole chunk was taken
+        buffer.drain(..written);
+        self.pending = buffer;
+
+        return Ok(());
+      }
+
+      bytes = &bytes[written - pending_length..];
+    }
+
+    let written = self.run(bytes, output, &mut run)?;
+
+    self.pending.extend_from_slice(&bytes[written..]);
+
+    Ok(())
+  }
+
+  fn finish<F>(&mut self, mut run: F) -> Result<(), UriError>
+  where
+    F: FnMut(&[u8], &mut Vec<u8>) -> Result<(), UriError>,
+  {
+    let pending = std::mem::take(&mut self.pending);
+    let position = std::mem::replace(&mut self.position, 0);
+
+    // Anything pending is incomplete, so this only ever writes the error
+    run(&pending, &mut Vec::new()).map_err(|mut error| {
+      error.index += position;
+      error
+    })
+  }
+
+  /// Runs the encoder or decoder, returning how many bytes were written. A sequence that's cut off
+  /// by the end of `bytes` isn't an error, since the next chunk may complete it.
+  fn run<S, F>(&mut self, bytes: &[u8], output: &mut S, run: &mut F) -> Result<usize, UriError>
+  where
+    S: Sink + ?Sized,
+    F: FnMut(&[u8], &mut S) -> Result<(), UriError>,
+  {
+    let written = match run(bytes, output) {
+      Ok(()) => bytes.len(),
+      Err(error)
+        if error.kind() == UriErrorKind::Truncated
+          && error.index() + error.sequence().len() == bytes.len() =>
+      {
+        error.index()
+      }
+      Err(mut error) => {
+        error.index += self.position;
+
+        return Err(error);
+      }
+    };
+
+    self.position += written;
+
+    Ok(written)
+  }
+}
@@ -1,4 +1,10 @@
-use crate::globals::utils::{decode_two_octets, hex_value};
+use crate::globals::decode_uri::{push_wtf8_surrogate, Utf16};
+use crate::globals::simd::literal_len;
+use crate::globals::utils::{decode_code_point, hex_value};
+use crate::globals::DecodeOutput;
+use crate::sink::{push_ascii, Sink};
+
+use std::borrow::Cow;
 
 /// Unescapes a string.
 ///
@@ -10,7 +16,80 @@ use crate::globals::utils::{decode_two_octets, hex_value};
 /// function.
 ///
 /// [`escape`]: crate::polyfills::globals::escape
-pub fn unescape(bytes: &[u8], unescaped: &mut Vec<u8>) {
+pub fn unescape<S: Sink + ?Sized>(bytes: &[u8], unescaped: &mut S) {
+  unescape_inner(bytes, unescaped, None);
+}
+
+/// Unescapes a string to UTF-16 code units.
+///
+/// # Notes
+/// This is the same as [`unescape`], but writes the UTF-16 code units that a JavaScript engine
+/// would produce, keeping unpaired surrogates as is.
+pub fn unescape_utf16(bytes: &[u8], unescaped: &mut Vec<u16>) {
+  unescape_inner(bytes, &mut Utf16(unescaped), Some(Utf16::push_surrogate));
+}
+
+/// Unescapes a string, writing the result in the encoding of `output`.
+///
+/// # Notes
+/// Unpaired surrogates, which `%uXXXX` escapes can produce, are replaced with U+FFFD when writing
+/// UTF-8, and are kept as is otherwise.
+pub fn unescape_to(bytes: &[u8], output: DecodeOutput<'_>) {
+  match output {
+    DecodeOutput::Utf8(unescaped) => unescape_inner(bytes, unescaped, None),
+    DecodeOutput::Wtf8(unescaped) => unescape_inner(bytes, unescaped, Some(push_wtf8_surrogate)),
+    DecodeOutput::Utf16(unescaped) => {
+      unescape_inner(bytes, &mut Utf16(unescaped), Some(Utf16::push_surrogate))
+    }
+  }
+}
+
+/// Unescapes a string.
+///
+/// # Notes
+/// This is the same as [`unescape`], but borrows the input when nothing needs unescaping, and
+/// only allocates from the first escape that does otherwise.
+///
+/// Unpaired surrogates are replaced with U+FFFD.
+pub fn unescape_cow(string: &str) -> Cow<'_, str> {
+  let bytes = string.as_bytes();
+  let mut unchanged = 0;
+
+  // Skips literals and the `%` of invalid escapes
+  loop {
+    unchanged += literal_len(&bytes[unchanged..], false);
+
+    if unchanged == bytes.len() {
+      return Cow::Borrowed(string);
+    }
+
+    if escape_at(bytes, unchanged).is_some() {
+      break;
+    }
+
+    unchanged += 1;
+  }
+
+  let mut unescaped = Vec::with_capacity(bytes.len());
+
+  unescaped.extend_from_slice(&bytes[..unchanged]);
+  unescape(&bytes[unchanged..], &mut unescaped);
+
+  // SAFETY: The input is valid UTF-8, and unpaired surrogates are replaced.
+  Cow::Owned(unsafe { String::from_utf8_unchecked(unescaped) })
+}
+
+/// Unescapes a string, where unpaired surrogates are kept when there's a `push_surrogate` to write
+/// them with, and replaced with U+FFFD otherwise.
+fn unescape_inner<S: Sink + ?Sized>(
+  bytes: &[u8],
+  unescaped: &mut S,
+  push_surrogate: Option<fn(&mut S, u32)>,
+) {
+  let mut surrogates = Surrogates {
+    lead: None,
+    push_surrogate,
+  };
   let mut idx = 0;
 
   while idx < bytes.len() {
@@ -18,49 +97,139 @@ pub fn unescape(bytes: &[u8], unescaped: &mut Vec<u8>) {
     let current = unsafe { *bytes.get_unchecked(idx) };
 
     if current == b'%' {
-      // SAFETY: We check beforehand that we have enough bytes.
-      unsafe {
-        if idx + 5 < bytes.len() && *bytes.get_unchecked(idx + 1) == b'u' {
-          let [one, two, three, four] = match bytes.get_unchecked(idx + 2..idx + 6) {
-            [one, two, three, four, ..] => [*one, *two, *three, *four],
-            // SAFETY: The first arm would've been reached.
-            _ => std::hint::unreachable_unchecked(),
-          };
-
-          let first_val = hex_value(one, two);
-          let second_val = hex_value(three, four);
-
-          // Only append valid hexadecimal bytes.
-          if first_val <= u8::MAX as u32 && second_val <= u8::MAX as u32 {
-            let combined = ((first_val << 8) | second_val) as u16;
-
-            unescaped.extend_from_slice(&decode_two_octets(combined));
-            idx += 6;
-            continue;
-          }
-        } else if idx + 2 < bytes.len() {
-          let one = *bytes.get_unchecked(idx + 1);
-          let two = *bytes.get_unchecked(idx + 2);
-          let total = hex_value(one, two) as u16;
-
-          // Only append valid hexadecimal bytes.
-          if total <= u8::MAX as u16 {
-            if total < 0x80 {
-              // ASCII
-              unescaped.push(total as u8);
-            } else {
-              // Two octets.
-              unescaped.extend_from_slice(&decode_two_octets(total));
-            }
-
-            idx += 3;
-            continue;
-          }
+      match escape_at(bytes, idx) {
+        Some(Escape::Unicode(value)) => {
+          surrogates.push(value as u32, unescaped);
+          idx += 6;
+        }
+        // Bytes are Latin-1 code points.
+        Some(Escape::Byte(value)) => {
+          surrogates.push(value as u32, unescaped);
+          idx += 3;
+        }
+        // Not a valid escape, so the `%` is kept as is.
+        None => {
+          surrogates.push(current as u32, unescaped);
+          idx += 1;
         }
       }
+
+      continue;
     }
 
-    unescaped.push(current);
-    idx += 1;
+    if current >= 0x80 {
+      // Surrogates encoded in WTF-8 can pair with escaped ones
+      let (code_point, length) =
+        decode_code_point(&bytes[idx..], true).unwrap_or((REPLACEMENT_CHARACTER, 1));
+
+      surrogates.push(code_point, unescaped);
+      idx += length;
+      continue;
+    }
+
+    // SAFETY: This would always be a valid index because of the condition.
+    let rest = unsafe { bytes.get_unchecked(idx..) };
+    let length = literal_len(rest, true);
+
+    surrogates.flush(unescaped);
+    // SAFETY: The length is at most the length of `rest`.
+    push_ascii(unescaped, unsafe { rest.get_unchecked(..length) });
+    idx += length;
+  }
+
+  surrogates.flush(unescaped);
+}
+
+const REPLACEMENT_CHARACTER: u32 = 0xFFFD;
+
+/// Pairs the surrogates that are unescaped one at a time.
+struct Surrogates<S: ?Sized> {
+  /// A lead surrogate that may be followed by a trail surrogate.
+  lead: Option<u32>,
+  /// Writes unpaired surrogates, which are replaced with U+FFFD when there's none.
+  push_surrogate: Option<fn(&mut S, u32)>,
+}
+
+impl<S: Sink + ?Sized> Surrogates<S> {
+  #[inline]
+  fn push(&mut self, code_point: u32, unescaped: &mut S) {
+    if let Some(lead) = self.lead.take() {
+      if (0xDC00..=0xDFFF).contains(&code_point) {
+        push_char(
+          0x10000 + ((lead - 0xD800) << 10) + (code_point - 0xDC00),
+          unescaped,
+        );
+        return;
+      }
+
+      self.push_unpaired(lead, unescaped);
+    }
+
+    match code_point {
+      0xD800..=0xDBFF => self.lead = Some(code_point),
+      0xDC00..=0xDFFF => self.push_unpaired(code_point, unescaped),
+      _ => push_char(code_point, unescaped),
+    }
+  }
+
+  /// Writes the lead surrogate, which won't be followed by a trail surrogate.
+  #[inline]
+  fn flush(&mut self, unescaped: &mut S) {
+    if let Some(lead) = self.lead.take() {
+      self.push_unpaired(lead, unescaped);
+    }
+  }
+
+  #[inline]
+  fn push_unpaired(&self, surrogate: u32, unescaped: &mut S) {
+    match self.push_surrogate {
+      Some(push_surrogate) => push_surrogate(unescaped, surrogate),
+      None => push_char(REPLACEMENT_CHARACTER, unescaped),
+    }
+  }
+}
+
+/// Writes a code point that isn't a surrogate.
+#[inline]
+fn push_char<S: Sink + ?Sized>(code_point: u32, unescaped: &mut S) {
+  let ch = char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER);
+
+  unescaped.push_str(ch.encode_utf8(&mut [0; 4]));
+}
+
+/// A valid escape sequence.
+enum Escape {
+  /// A `%uXXXX` escape.
+  Unicode(u16),
+  /// A `%XX` escape.
+  Byte(u8),
+}
+
+/// Reads the escape sequence starting with the `%` at `idx`.
+#[inline]
+fn escape_at(bytes: &[u8], idx: usize) -> Option<Escape> {
+  match bytes.get(idx + 1..) {
+    Some(&[b'u', one, two, three, four, ..]) => {
+      let first_val = hex_value(one, two);
+      let second_val = hex_value(three, four);
+
+      // Only valid hexadecimal bytes.
+      if first_val <= u8::MAX as u32 && second_val <= u8::MAX as u32 {
+        return Some(Escape::Unicode(((first_val << 8) | second_val) as u16));
+      }
+
+      None
+    }
+    Some(&[one, two, ..]) => {
+      let total = hex_value(one, two);
+
+      // Only valid hexadecimal bytes.
+      if total <= u8::MAX as u32 {
+        return Some(Escape::Byte(total as u8));
+      }
+
+      None
+    }
+    _ => None,
   }
 }
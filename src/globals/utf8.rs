@@ -0,0 +1,86 @@
+use crate::globals::simd::valid_utf8_len;
+use crate::globals::utils::decode_code_point;
+use crate::globals::{UriError, UriErrorKind};
+
+/// How many bytes are checked one character at a time once the SIMD path stops, before it's tried
+/// again.
+const SCALAR_LEN: usize = 64;
+
+/// Validates UTF-8, returning the error of the first invalid sequence.
+///
+/// # Notes
+/// A sequence is valid when it's the shortest encoding of a code point up to U+10FFFF that isn't a
+/// surrogate, which are the well-formed sequences of the Unicode Standard that the ECMAScript
+/// [Decode](https://tc39.es/ecma262/#sec-decode) operation requires. The encoders and decoders of
+/// this module validate their input the same way.
+///
+/// On x86-64, long inputs are checked 32 bytes at a time with AVX2, or 16 at a time with SSSE3,
+/// when the CPU supports either.
+pub fn validate_utf8(bytes: &[u8]) -> Result<(), UriError> {
+  validate(bytes, false)
+}
+
+/// Validates UTF-8, or [WTF-8](https://simonsapin.github.io/wtf-8/) when `allow_surrogates` is
+/// set, where a lead surrogate can't be followed by a trail surrogate.
+pub(crate) fn validate(bytes: &[u8], allow_surrogates: bool) -> Result<(), UriError> {
+  let mut after_lead_surrogate = false;
+  let mut idx = 0;
+
+  while idx < bytes.len() {
+    // SAFETY: Guaranteed to be valid because of `idx < len`.
+    let valid = valid_utf8_len(unsafe { bytes.get_unchecked(idx..) });
+
+    if valid > 0 {
+      after_lead_surrogate = false;
+      idx += valid;
+    }
+
+    // The SIMD path stopped at an invalid sequence, a surrogate or the end of the input
+    let end = bytes.len().min(idx + SCALAR_LEN);
+
+    while idx < end {
+      // SAFETY: Guaranteed to be valid because of `idx < end`.
+      if unsafe { *bytes.get_unchecked(idx) } < 0x80 {
+        after_lead_surrogate = false;
+        idx += 1;
+        continue;
+      }
+
+      let (code_point, length) = decode_code_point(&bytes[idx..], allow_surrogates)
+        .map_err(|(kind, length)| UriError::new(kind, idx, &bytes[idx..idx + length]))?;
+
+      if after_lead_surrogate && (0xDC00..=0xDFFF).contains(&code_point) {
+        return Err(UriError::new(
+          UriErrorKind::Surrogate,
+          idx,
+          &bytes[idx..idx + length],
+        ));
+      }
+
+      after_lead_surrogate = (0xD800..=0xDBFF).contains(&code_point);
+      idx += length;
+    }
+  }
+
+  Ok(())
+}
+
+/// Decodes the code point at the start of `bytes`, which were already validated.
+///
+/// Returns the code point along with the number of bytes it spans.
+#[inline]
+pub(crate) fn decode_valid(bytes: &[u8]) -> (u32, usize) {
+  let lead = bytes[0];
+  let (length, mut code_point) = match lead {
+    0x00..=0x7F => return (lead as u32, 1),
+    0xC0..=0xDF => (2, lead as u32 & 0x1F),
+    0xE0..=0xEF => (3, lead as u32 & 0x0F),
+    _ => (4, lead as u32 & 0x07),
+  };
+
+  for &byte in &bytes[1..length] {
+    code_point = (code_point << 6) | (byte & 0x3F) as u32;
+  }
+
+  (code_point, length)
+}
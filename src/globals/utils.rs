@@ -1,3 +1,4 @@
+use crate::globals::UriErrorKind;
 use crate::number::radii::HEXADECIMAL_RADIX;
 use crate::number::BASE_36_LUT;
 
@@ -31,6 +32,92 @@ pub fn decode_two_octets(val: u16) -> [u8; 2] {
   [((val >> 6) | 0xC0) as u8, ((val & 0x3F) | 0x80) as u8]
 }
 
+/// Encodes a code point, which may be a surrogate, to UTF-8.
+///
+/// Returns the encoded bytes along with how many of them are used. Surrogates are encoded as 3
+/// bytes, like [WTF-8](https://simonsapin.github.io/wtf-8/) does.
+#[inline]
+pub fn encode_code_point(code_point: u32) -> ([u8; 4], usize) {
+  match code_point {
+    0..=0x7F => ([code_point as u8, 0, 0, 0], 1),
+    0x80..=0x7FF => (
+      [
+        (code_point >> 6) as u8 | 0xC0,
+        (code_point & 0x3F) as u8 | 0x80,
+        0,
+        0,
+      ],
+      2,
+    ),
+    0x800..=0xFFFF => (
+      [
+        (code_point >> 12) as u8 | 0xE0,
+        ((code_point >> 6) & 0x3F) as u8 | 0x80,
+        (code_point & 0x3F) as u8 | 0x80,
+        0,
+      ],
+      3,
+    ),
+    _ => (
+      [
+        (code_point >> 18) as u8 | 0xF0,
+        ((code_point >> 12) & 0x3F) as u8 | 0x80,
+        ((code_point >> 6) & 0x3F) as u8 | 0x80,
+        (code_point & 0x3F) as u8 | 0x80,
+      ],
+      4,
+    ),
+  }
+}
+
+/// Decodes the UTF-8 encoded code point at the start of `bytes`.
+///
+/// Returns the code point along with the number of bytes it spans. When `bytes` doesn't start with
+/// the shortest encoding of a code point up to U+10FFFF, the kind of error is returned along with
+/// the length of the offending sequence. Surrogates, encoded as 3 bytes like
+/// [WTF-8](https://simonsapin.github.io/wtf-8/) does, are only accepted when `allow_surrogates`
+/// is set.
+#[inline]
+pub fn decode_code_point(
+  bytes: &[u8],
+  allow_surrogates: bool,
+) -> Result<(u32, usize), (UriErrorKind, usize)> {
+  let lead = match bytes.first() {
+    Some(&lead) => lead,
+    None => return Err((UriErrorKind::Truncated, 0)),
+  };
+
+  let (length, min, mut code_point) = match lead {
+    0x00..=0x7F => return Ok((lead as u32, 1)),
+    0xC0..=0xDF => (2, 0x80, lead as u32 & 0x1F),
+    0xE0..=0xEF => (3, 0x800, lead as u32 & 0x0F),
+    0xF0..=0xF7 => (4, 0x10000, lead as u32 & 0x07),
+    _ => return Err((UriErrorKind::InvalidLeadByte, 1)),
+  };
+
+  for idx in 1..length {
+    match bytes.get(idx) {
+      Some(&byte) if byte & 0xC0 == 0x80 => code_point = (code_point << 6) | (byte & 0x3F) as u32,
+      Some(_) => return Err((UriErrorKind::InvalidContinuation, idx + 1)),
+      None => return Err((UriErrorKind::Truncated, idx)),
+    }
+  }
+
+  if code_point < min {
+    return Err((UriErrorKind::Overlong, length));
+  }
+
+  if code_point > 0x10FFFF {
+    return Err((UriErrorKind::OutOfRange, length));
+  }
+
+  if (0xD800..=0xDFFF).contains(&code_point) && !allow_surrogates {
+    return Err((UriErrorKind::Surrogate, length));
+  }
+
+  Ok((code_point, length))
+}
+
 /// Converts two bytes into a hex value.
 ///
 /// For performance reasons, this function does no validation on the provided input. However,
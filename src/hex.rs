@@ -0,0 +1,126 @@
+use crate::globals::utils::hex_value;
+use crate::sink::{push_ascii, ByteSink, Sink};
+
+use std::fmt;
+
+/// An error when decoding a hexadecimal encoded string.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct DecodeError {
+  kind: DecodeErrorKind,
+  index: usize,
+  byte: u8,
+}
+
+/// The kind of a [`DecodeError`].
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum DecodeErrorKind {
+  /// The provided string wasn't a multiple of 2.
+  InvalidLength,
+  /// A non-hexadecimal digit was encountered.
+  InvalidCharacter,
+}
+
+impl DecodeError {
+  /// Creates an error of the given kind for the byte found at `index`.
+  pub const fn new(kind: DecodeErrorKind, index: usize, byte: u8) -> Self {
+    DecodeError { kind, index, byte }
+  }
+
+  /// The kind of error.
+  #[inline]
+  pub const fn kind(&self) -> DecodeErrorKind {
+    self.kind
+  }
+
+  /// The byte index of the offending byte in the input.
+  #[inline]
+  pub const fn index(&self) -> usize {
+    self.index
+  }
+
+  /// The offending byte, which is the invalid digit or the one left over after the last pair.
+  #[inline]
+  pub const fn byte(&self) -> u8 {
+    self.byte
+  }
+}
+
+impl fmt::Display for DecodeErrorKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      DecodeErrorKind::InvalidLength => "invalid length",
+      DecodeErrorKind::InvalidCharacter => "invalid hexadecimal digit",
+    })
+  }
+}
+
+impl fmt::Display for DecodeError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} `{}` at index {}",
+      self.kind,
+      self.byte.escape_ascii(),
+      self.index
+    )
+  }
+}
+
+impl std::error::Error for DecodeError {}
+
+const LOWERCASE_LUT: &[u8; 16] = b"0123456789abcdef";
+
+/// Encodes bytes as lowercase hexadecimal digits.
+pub fn encode_hex<S: Sink + ?Sized>(bytes: &[u8], encoded: &mut S) {
+  encoded.reserve(bytes.len() * 2);
+
+  for &byte in bytes {
+    push_ascii(
+      encoded,
+      &[
+        LOWERCASE_LUT[(byte >> 4) as usize],
+        LOWERCASE_LUT[(byte & 0x0F) as usize],
+      ],
+    );
+  }
+}
+
+/// Decodes a string of case insensitive hexadecimal digits.
+///
+/// # Notes
+/// The whole string is validated first, so nothing is written to `decoded` when an error is
+/// returned.
+pub fn decode_hex<S: ByteSink + ?Sized>(bytes: &[u8], decoded: &mut S) -> Result<(), DecodeError> {
+  if let Some(&last) = bytes.last().filter(|_| !bytes.len().is_multiple_of(2)) {
+    return Err(DecodeError::new(
+      DecodeErrorKind::InvalidLength,
+      bytes.len() - 1,
+      last,
+    ));
+  }
+
+  if let Some(idx) = bytes.iter().position(|byte| !byte.is_ascii_hexdigit()) {
+    return Err(DecodeError::new(
+      DecodeErrorKind::InvalidCharacter,
+      idx,
+      bytes[idx],
+    ));
+  }
+
+  decoded.reserve(bytes.len() / 2);
+
+  // Decodes to a small buffer, so that sinks aren't written to one byte at a time
+  let mut buffer = [0; 64];
+
+  for chunk in bytes.chunks(buffer.len() * 2) {
+    let values = &mut buffer[..chunk.len() / 2];
+
+    for (value, pair) in values.iter_mut().zip(chunk.chunks_exact(2)) {
+      *value = hex_value(pair[0], pair[1]) as u8;
+    }
+
+    decoded.push_bytes(values);
+  }
+
+  Ok(())
+}
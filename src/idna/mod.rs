@@ -0,0 +1,392 @@
+mod normalize;
+pub(crate) mod properties;
+mod punycode;
+mod tables;
+
+pub use punycode::*;
+
+use normalize::{is_nfc, nfc};
+use properties::{
+  bidi_class, combining_class, is_mark, joining_type, mapped, status, BidiClass, JoiningType,
+  Status, VIRAMA,
+};
+
+use std::fmt;
+
+const ZERO_WIDTH_NON_JOINER: char = '\u{200C}';
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// The options of [UTS #46](https://www.unicode.org/reports/tr46/#Processing) processing.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Config {
+  /// Whether characters that are disallowed by STD3, such as `_` and `/`, are errors.
+  pub use_std3_ascii_rules: bool,
+  /// Whether labels can't start or end with `-`, or have `--` as their third and fourth
+  /// characters.
+  pub check_hyphens: bool,
+  /// Whether labels must follow the bidi rule of
+  /// [RFC 5893](https://datatracker.ietf.org/doc/html/rfc5893#section-2), when the domain has any
+  /// right-to-left characters.
+  pub check_bidi: bool,
+  /// Whether zero width joiners and non-joiners must follow the contextual rules of
+  /// [RFC 5892](https://datatracker.ietf.org/doc/html/rfc5892#appendix-A).
+  pub check_joiners: bool,
+  /// Whether deviation characters, such as `ß`, are mapped instead of kept.
+  pub transitional_processing: bool,
+  /// Whether [`domain_to_ascii`] checks the lengths of the domain and its labels for DNS.
+  pub verify_dns_length: bool,
+}
+
+impl Config {
+  /// The options that the [URL Standard](https://url.spec.whatwg.org/#idna) uses for hosts.
+  pub const URL: Config = Config {
+    use_std3_ascii_rules: false,
+    check_hyphens: false,
+    check_bidi: true,
+    check_joiners: true,
+    transitional_processing: false,
+    verify_dns_length: false,
+  };
+}
+
+impl Default for Config {
+  /// Returns the strictest options, besides transitional processing.
+  fn default() -> Config {
+    Config {
+      use_std3_ascii_rules: true,
+      check_hyphens: true,
+      check_bidi: true,
+      check_joiners: true,
+      transitional_processing: false,
+      verify_dns_length: true,
+    }
+  }
+}
+
+/// An error when processing a domain.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum IdnaError {
+  /// A character was disallowed, or wasn't valid in a label that was decoded from Punycode.
+  DisallowedCharacter,
+  /// A label starting with `xn--` wasn't valid Punycode, or a label couldn't be encoded.
+  InvalidPunycode,
+  /// A label that was decoded from Punycode wasn't in Normalization Form C.
+  NotNormalized,
+  /// A label started or ended with `-`, or had `--` as its third and fourth characters.
+  InvalidHyphen,
+  /// A label started with a combining mark.
+  LeadingCombiningMark,
+  /// A zero width joiner or non-joiner wasn't in a context that allows it.
+  InvalidJoiner,
+  /// A label didn't follow the bidi rule.
+  InvalidBidi,
+  /// The domain or one of its labels was empty or too long for DNS.
+  InvalidLength,
+}
+
+impl fmt::Display for IdnaError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      IdnaError::DisallowedCharacter => "disallowed character",
+      IdnaError::InvalidPunycode => "invalid punycode",
+      IdnaError::NotNormalized => "label isn't in normalization form C",
+      IdnaError::InvalidHyphen => "invalid hyphen",
+      IdnaError::LeadingCombiningMark => "leading combining mark",
+      IdnaError::InvalidJoiner => "invalid joiner",
+      IdnaError::InvalidBidi => "invalid bidi label",
+      IdnaError::InvalidLength => "invalid length",
+    })
+  }
+}
+
+impl std::error::Error for IdnaError {}
+
+/// Converts a domain to ASCII.
+///
+/// # Notes
+/// This function follows the [ToASCII](https://www.unicode.org/reports/tr46/#ToASCII) operation
+/// of UTS #46, where the domain is processed and each label with characters that aren't ASCII is
+/// encoded as Punycode with an `xn--` prefix.
+///
+/// The mapping and validity of characters follow version 13.0.0 of the IDNA mapping table. A label
+/// starting with `xn--` must decode to a label that isn't empty and isn't all ASCII.
+pub fn domain_to_ascii(domain: &str, config: &Config) -> Result<String, IdnaError> {
+  let labels = process(domain, config)?;
+  let mut ascii = String::with_capacity(domain.len());
+
+  for (idx, label) in labels.iter().enumerate() {
+    if idx != 0 {
+      ascii.push('.');
+    }
+
+    if label.iter().all(char::is_ascii) {
+      ascii.extend(label.iter());
+    } else {
+      let label = label.iter().collect::<String>();
+
+      ascii.push_str("xn--");
+      encode_punycode(&label, &mut ascii).map_err(|_| IdnaError::InvalidPunycode)?;
+    }
+  }
+
+  if config.verify_dns_length {
+    verify_dns_length(&ascii)?;
+  }
+
+  Ok(ascii)
+}
+
+/// Converts a domain to Unicode.
+///
+/// # Notes
+/// This function follows the [ToUnicode](https://www.unicode.org/reports/tr46/#ToUnicode)
+/// operation of UTS #46, where the domain is processed and each label starting with `xn--` is
+/// decoded from Punycode. The lengths of the labels aren't checked.
+pub fn domain_to_unicode(domain: &str, config: &Config) -> Result<String, IdnaError> {
+  let labels = process(domain, config)?;
+  let mut unicode = String::with_capacity(domain.len());
+
+  for (idx, label) in labels.iter().enumerate() {
+    if idx != 0 {
+      unicode.push('.');
+    }
+
+    unicode.extend(label.iter());
+  }
+
+  Ok(unicode)
+}
+
+/// Maps, normalizes and splits a domain into labels, then decodes and validates each label.
+fn process(domain: &str, config: &Config) -> Result<Vec<Vec<char>>, IdnaError> {
+  let mut error = None;
+  let mut mapped_chars = Vec::with_capacity(domain.len());
+
+  for ch in domain.chars() {
+    match status(ch) {
+      Status::Valid => mapped_chars.push(ch),
+      Status::Ignored => {}
+      Status::Mapped(offset, len) => mapped_chars.extend(mapped(offset, len).chars()),
+      Status::Deviation(offset, len) if config.transitional_processing => {
+        mapped_chars.extend(mapped(offset, len).chars())
+      }
+      Status::Deviation(..) => mapped_chars.push(ch),
+      Status::DisallowedStd3Valid if !config.use_std3_ascii_rules => mapped_chars.push(ch),
+      Status::DisallowedStd3Mapped(offset, len) if !config.use_std3_ascii_rules => {
+        mapped_chars.extend(mapped(offset, len).chars())
+      }
+      Status::Disallowed | Status::DisallowedStd3Valid | Status::DisallowedStd3Mapped(..) => {
+        // The character is kept, so that processing carries on
+        error.get_or_insert(IdnaError::DisallowedCharacter);
+        mapped_chars.push(ch);
+      }
+    }
+  }
+
+  let normalized = nfc(&mapped_chars);
+  let mut labels = Vec::new();
+
+  for label in normalized.split(|&ch| ch == '.') {
+    let result = match label.strip_prefix(&['x', 'n', '-', '-']) {
+      Some(encoded) => decode_label(encoded).and_then(|decoded| {
+        validate_label(&decoded, config, false)?;
+        Ok(decoded)
+      }),
+      None => validate_label(label, config, config.transitional_processing).map(|_| label.to_vec()),
+    };
+
+    match result {
+      Ok(label) => labels.push(label),
+      Err(label_error) => {
+        error.get_or_insert(label_error);
+        labels.push(label.to_vec());
+      }
+    }
+  }
+
+  if config.check_bidi
+    && is_bidi_domain(&labels)
+    && !labels.iter().all(|label| is_bidi_label(label))
+  {
+    error.get_or_insert(IdnaError::InvalidBidi);
+  }
+
+  match error {
+    Some(error) => Err(error),
+    None => Ok(labels),
+  }
+}
+
+/// Decodes the Punycode of a label after its `xn--` prefix.
+fn decode_label(encoded: &[char]) -> Result<Vec<char>, IdnaError> {
+  let encoded = encoded.iter().collect::<String>();
+  let mut decoded = String::with_capacity(encoded.len());
+
+  if !encoded.is_ascii() || decode_punycode(&encoded, &mut decoded).is_err() {
+    return Err(IdnaError::InvalidPunycode);
+  }
+
+  // Such labels would've been written without Punycode
+  if decoded.is_empty() || decoded.is_ascii() {
+    return Err(IdnaError::InvalidPunycode);
+  }
+
+  Ok(decoded.chars().collect())
+}
+
+/// Checks the [validity criteria](https://www.unicode.org/reports/tr46/#Validity_Criteria) of a
+/// label, besides the bidi rule which depends on the whole domain.
+fn validate_label(label: &[char], config: &Config, transitional: bool) -> Result<(), IdnaError> {
+  if !is_nfc(label) {
+    return Err(IdnaError::NotNormalized);
+  }
+
+  if config.check_hyphens {
+    if label.get(2..4) == Some(&['-', '-']) {
+      return Err(IdnaError::InvalidHyphen);
+    }
+
+    if label.first() == Some(&'-') || label.last() == Some(&'-') {
+      return Err(IdnaError::InvalidHyphen);
+    }
+  } else if label.starts_with(&['x', 'n', '-', '-']) {
+    return Err(IdnaError::InvalidPunycode);
+  }
+
+  if label.first().is_some_and(|&ch| is_mark(ch)) {
+    return Err(IdnaError::LeadingCombiningMark);
+  }
+
+  for &ch in label {
+    let is_valid = match status(ch) {
+      Status::Valid => ch != '.',
+      Status::Deviation(..) => !transitional,
+      Status::DisallowedStd3Valid => !config.use_std3_ascii_rules,
+      _ => false,
+    };
+
+    if !is_valid {
+      return Err(IdnaError::DisallowedCharacter);
+    }
+  }
+
+  if config.check_joiners && !is_valid_joiners(label) {
+    return Err(IdnaError::InvalidJoiner);
+  }
+
+  Ok(())
+}
+
+/// Whether every zero width joiner and non-joiner follows the
+/// [CONTEXTJ rules](https://datatracker.ietf.org/doc/html/rfc5892#appendix-A.1) of RFC 5892.
+fn is_valid_joiners(label: &[char]) -> bool {
+  for (idx, &ch) in label.iter().enumerate() {
+    if ch != ZERO_WIDTH_NON_JOINER && ch != ZERO_WIDTH_JOINER {
+      continue;
+    }
+
+    if idx > 0 && combining_class(label[idx - 1]) == VIRAMA {
+      continue;
+    }
+
+    if ch == ZERO_WIDTH_JOINER {
+      return false;
+    }
+
+    // A non-joiner must be between a left or dual joining character and a right or dual joining
+    // character, where transparent characters are skipped
+    let before = label[..idx]
+      .iter()
+      .rev()
+      .map(|&ch| joining_type(ch))
+      .find(|&joining| joining != Some(JoiningType::T));
+    let after = label[idx + 1..]
+      .iter()
+      .map(|&ch| joining_type(ch))
+      .find(|&joining| joining != Some(JoiningType::T));
+
+    let is_left = matches!(
+      before,
+      Some(Some(JoiningType::L)) | Some(Some(JoiningType::D))
+    );
+    let is_right = matches!(
+      after,
+      Some(Some(JoiningType::R)) | Some(Some(JoiningType::D))
+    );
+
+    if !is_left || !is_right {
+      return false;
+    }
+  }
+
+  true
+}
+
+/// Whether the domain has any right-to-left characters.
+fn is_bidi_domain(labels: &[Vec<char>]) -> bool {
+  labels
+    .iter()
+    .flatten()
+    .any(|&ch| matches!(bidi_class(ch), BidiClass::R | BidiClass::AL | BidiClass::AN))
+}
+
+/// Whether a label follows the [bidi rule](https://datatracker.ietf.org/doc/html/rfc5893#section-2)
+/// of RFC 5893.
+fn is_bidi_label(label: &[char]) -> bool {
+  use BidiClass::*;
+
+  let first = match label.first() {
+    Some(&ch) => bidi_class(ch),
+    None => return true,
+  };
+  // The class of the last character that isn't a nonspacing mark
+  let last = label
+    .iter()
+    .rev()
+    .map(|&ch| bidi_class(ch))
+    .find(|&class| class != NSM);
+
+  match first {
+    R | AL => {
+      let mut has_en = false;
+      let mut has_an = false;
+
+      for &ch in label {
+        match bidi_class(ch) {
+          EN => has_en = true,
+          AN => has_an = true,
+          R | AL | ES | CS | ET | ON | BN | NSM => {}
+          _ => return false,
+        }
+      }
+
+      matches!(last, Some(R) | Some(AL) | Some(EN) | Some(AN)) && !(has_en && has_an)
+    }
+    L => {
+      label
+        .iter()
+        .all(|&ch| matches!(bidi_class(ch), L | EN | ES | CS | ET | ON | BN | NSM))
+        && matches!(last, Some(L) | Some(EN))
+    }
+    _ => false,
+  }
+}
+
+/// Checks that the domain, without a trailing dot, is from 1 to 253 characters long, and that
+/// each label is from 1 to 63 characters long.
+fn verify_dns_length(ascii: &str) -> Result<(), IdnaError> {
+  let domain = ascii.strip_suffix('.').unwrap_or(ascii);
+
+  if domain.is_empty() || domain.len() > 253 {
+    return Err(IdnaError::InvalidLength);
+  }
+
+  if domain
+    .split('.')
+    .any(|label| label.is_empty() || label.len() > 63)
+  {
+    return Err(IdnaError::InvalidLength);
+  }
+
+  Ok(())
+}
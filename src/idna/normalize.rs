@@ -0,0 +1,135 @@
+use crate::idna::properties::{combining_class, composition, decomposition};
+
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+/// Returns the characters in Normalization Form C.
+///
+/// # Notes
+/// This function follows the
+/// [normalization algorithm](https://www.unicode.org/reports/tr15/#Description_Norm) of UAX #15,
+/// where the characters are canonically decomposed, combining marks are put in canonical order and
+/// the result is canonically composed.
+pub(crate) fn nfc(chars: &[char]) -> Vec<char> {
+  let mut decomposed = Vec::with_capacity(chars.len());
+
+  for &ch in chars {
+    decompose(ch, &mut decomposed);
+  }
+
+  reorder(&mut decomposed);
+  compose(&decomposed)
+}
+
+/// Whether the characters are in Normalization Form C.
+pub(crate) fn is_nfc(chars: &[char]) -> bool {
+  nfc(chars) == chars
+}
+
+fn decompose(ch: char, decomposed: &mut Vec<char>) {
+  let s_index = (ch as u32).wrapping_sub(HANGUL_S_BASE);
+
+  if s_index < HANGUL_S_COUNT {
+    let l = HANGUL_L_BASE + s_index / HANGUL_N_COUNT;
+    let v = HANGUL_V_BASE + (s_index % HANGUL_N_COUNT) / HANGUL_T_COUNT;
+    let t = HANGUL_T_BASE + s_index % HANGUL_T_COUNT;
+
+    // Jamo are never surrogates
+    decomposed.extend(char::from_u32(l));
+    decomposed.extend(char::from_u32(v));
+
+    if t != HANGUL_T_BASE {
+      decomposed.extend(char::from_u32(t));
+    }
+
+    return;
+  }
+
+  match decomposition(ch) {
+    Some(chars) => decomposed.extend(chars.chars()),
+    None => decomposed.push(ch),
+  }
+}
+
+/// Sorts each run of combining marks by their canonical combining class, keeping the order of
+/// marks with the same class.
+fn reorder(chars: &mut [char]) {
+  let mut start = 0;
+
+  while start < chars.len() {
+    if combining_class(chars[start]) == 0 {
+      start += 1;
+      continue;
+    }
+
+    let mut end = start + 1;
+
+    while end < chars.len() && combining_class(chars[end]) != 0 {
+      end += 1;
+    }
+
+    chars[start..end].sort_by_key(|&ch| combining_class(ch));
+    start = end;
+  }
+}
+
+fn compose(chars: &[char]) -> Vec<char> {
+  let mut composed: Vec<char> = Vec::with_capacity(chars.len());
+  let mut starter = None;
+  let mut last_class = 0;
+
+  for &ch in chars {
+    let class = combining_class(ch);
+
+    if let Some(idx) = starter {
+      // A character is blocked from the starter by a character in between with a class of zero or
+      // a class that's at least its own
+      let is_adjacent = idx + 1 == composed.len();
+
+      if is_adjacent || (last_class != 0 && last_class < class) {
+        if let Some(primary) = compose_pair(composed[idx], ch) {
+          composed[idx] = primary;
+          continue;
+        }
+      }
+    }
+
+    if class == 0 {
+      starter = Some(composed.len());
+    }
+
+    last_class = class;
+    composed.push(ch);
+  }
+
+  composed
+}
+
+fn compose_pair(first: char, second: char) -> Option<char> {
+  let l_index = (first as u32).wrapping_sub(HANGUL_L_BASE);
+  let v_index = (second as u32).wrapping_sub(HANGUL_V_BASE);
+
+  if l_index < HANGUL_L_COUNT && v_index < HANGUL_V_COUNT {
+    return char::from_u32(HANGUL_S_BASE + (l_index * HANGUL_V_COUNT + v_index) * HANGUL_T_COUNT);
+  }
+
+  let s_index = (first as u32).wrapping_sub(HANGUL_S_BASE);
+  let t_index = (second as u32).wrapping_sub(HANGUL_T_BASE);
+
+  if s_index < HANGUL_S_COUNT
+    && s_index.is_multiple_of(HANGUL_T_COUNT)
+    && t_index > 0
+    && t_index < HANGUL_T_COUNT
+  {
+    return char::from_u32(first as u32 + t_index);
+  }
+
+  composition(first, second)
+}
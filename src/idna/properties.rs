@@ -0,0 +1,129 @@
+use crate::idna::tables::{
+  BIDI_CLASS, COMBINING_CLASS, COMPOSITION, DECOMPOSED, DECOMPOSITION, JOINING_TYPE, MAPPED,
+  MAPPING, MARKS,
+};
+
+use std::cmp::Ordering;
+
+/// The IDNA status of a code point, where mappings are an offset and a length in `MAPPED`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Status {
+  Valid,
+  Ignored,
+  Mapped(u16, u8),
+  Deviation(u16, u8),
+  Disallowed,
+  DisallowedStd3Valid,
+  DisallowedStd3Mapped(u16, u8),
+}
+
+/// The bidi classes that the bidi rule distinguishes, where every other class is `Other`.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum BidiClass {
+  L,
+  R,
+  AL,
+  AN,
+  EN,
+  ES,
+  CS,
+  ET,
+  ON,
+  BN,
+  NSM,
+  Other,
+}
+
+/// The joining types that the contextual rules distinguish, where `U` is left out.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum JoiningType {
+  D,
+  C,
+  L,
+  R,
+  T,
+}
+
+/// The canonical combining class of a virama.
+pub(crate) const VIRAMA: u8 = 9;
+
+/// Returns the IDNA status of a character.
+pub(crate) fn status(ch: char) -> Status {
+  let idx = MAPPING.partition_point(|&(start, _)| start <= ch as u32);
+
+  // The table starts at `U+0000`, so there's always a range
+  MAPPING[idx - 1].1
+}
+
+/// Returns the string that a mapped character is mapped to.
+pub(crate) fn mapped(offset: u16, len: u8) -> &'static str {
+  &MAPPED[offset as usize..offset as usize + len as usize]
+}
+
+/// Returns the canonical combining class of a character.
+pub(crate) fn combining_class(ch: char) -> u8 {
+  lookup(COMBINING_CLASS, ch).unwrap_or(0)
+}
+
+/// Returns the full canonical decomposition of a character, if it has one.
+///
+/// # Notes
+/// Hangul syllables are decomposed algorithmically instead.
+pub(crate) fn decomposition(ch: char) -> Option<&'static str> {
+  DECOMPOSITION
+    .binary_search_by_key(&(ch as u32), |&(code_point, _, _)| code_point)
+    .ok()
+    .map(|idx| {
+      let (_, offset, len) = DECOMPOSITION[idx];
+
+      &DECOMPOSED[offset as usize..offset as usize + len as usize]
+    })
+}
+
+/// Returns the primary composite of two characters, if there is one.
+///
+/// # Notes
+/// Hangul syllables are composed algorithmically instead.
+pub(crate) fn composition(first: char, second: char) -> Option<char> {
+  COMPOSITION
+    .binary_search_by_key(&(first as u32, second as u32), |&(one, two, _)| (one, two))
+    .ok()
+    .and_then(|idx| char::from_u32(COMPOSITION[idx].2))
+}
+
+/// Whether a character has a `Mark` general category.
+pub(crate) fn is_mark(ch: char) -> bool {
+  MARKS
+    .binary_search_by(|&(start, end)| compare_range(start, end, ch))
+    .is_ok()
+}
+
+/// Returns the bidi class of a character.
+pub(crate) fn bidi_class(ch: char) -> BidiClass {
+  lookup(BIDI_CLASS, ch).unwrap_or(BidiClass::L)
+}
+
+/// Returns the joining type of a character, or `None` when it's non-joining.
+pub(crate) fn joining_type(ch: char) -> Option<JoiningType> {
+  lookup(JOINING_TYPE, ch)
+}
+
+/// Looks up the value of the range that contains a character.
+fn lookup<T: Copy>(table: &[(u32, u32, T)], ch: char) -> Option<T> {
+  table
+    .binary_search_by(|&(start, end, _)| compare_range(start, end, ch))
+    .ok()
+    .map(|idx| table[idx].2)
+}
+
+/// Compares an inclusive range of code points to a character.
+fn compare_range(start: u32, end: u32, ch: char) -> Ordering {
+  if end < ch as u32 {
+    Ordering::Less
+  } else if start > ch as u32 {
+    Ordering::Greater
+  } else {
+    Ordering::Equal
+  }
+}
@@ -0,0 +1,228 @@
+use std::fmt;
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+const DELIMITER: char = '-';
+
+/// An error when encoding or decoding Punycode.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum PunycodeError {
+  /// A basic code point wasn't ASCII, or a digit wasn't a letter or a number.
+  InvalidCharacter,
+  /// The digits ended in the middle of a variable-length integer.
+  Truncated,
+  /// A delta didn't fit in 32 bits.
+  Overflow,
+  /// A decoded code point was a surrogate or was greater than `U+10FFFF`.
+  InvalidCodePoint,
+}
+
+impl fmt::Display for PunycodeError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      PunycodeError::InvalidCharacter => "invalid character",
+      PunycodeError::Truncated => "truncated variable-length integer",
+      PunycodeError::Overflow => "overflow",
+      PunycodeError::InvalidCodePoint => "invalid code point",
+    })
+  }
+}
+
+impl std::error::Error for PunycodeError {}
+
+/// Encodes a string to Punycode.
+///
+/// # Notes
+/// This function follows [RFC 3492](https://datatracker.ietf.org/doc/html/rfc3492), where the
+/// ASCII characters are copied, followed by `-` when there are any, and the other characters are
+/// encoded as deltas. The case of ASCII characters is kept. Strings that would overflow the
+/// deltas fail with [`PunycodeError::Overflow`], in which case nothing is written.
+pub fn encode_punycode(string: &str, encoded: &mut String) -> Result<(), PunycodeError> {
+  let start = encoded.len();
+  let result = encode(string, encoded);
+
+  if result.is_err() {
+    encoded.truncate(start);
+  }
+
+  result
+}
+
+fn encode(string: &str, encoded: &mut String) -> Result<(), PunycodeError> {
+  let code_points = string.chars().map(u32::from).collect::<Vec<_>>();
+  let basic = string.chars().filter(char::is_ascii).count() as u32;
+
+  encoded.extend(string.chars().filter(char::is_ascii));
+
+  if basic > 0 {
+    encoded.push(DELIMITER);
+  }
+
+  let mut n = INITIAL_N;
+  let mut delta = 0u32;
+  let mut bias = INITIAL_BIAS;
+  let mut handled = basic;
+
+  while (handled as usize) < code_points.len() {
+    // The smallest code point that hasn't been handled yet
+    let m = code_points
+      .iter()
+      .copied()
+      .filter(|&code_point| code_point >= n)
+      .min()
+      .unwrap();
+
+    delta = (m - n)
+      .checked_mul(handled + 1)
+      .and_then(|increase| delta.checked_add(increase))
+      .ok_or(PunycodeError::Overflow)?;
+    n = m;
+
+    for &code_point in code_points.iter() {
+      if code_point < n {
+        delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+      }
+
+      if code_point == n {
+        let mut q = delta;
+        let mut k = BASE;
+
+        loop {
+          let t = threshold(k, bias);
+
+          if q < t {
+            break;
+          }
+
+          encoded.push(digit_to_char(t + (q - t) % (BASE - t)));
+          q = (q - t) / (BASE - t);
+          k += BASE;
+        }
+
+        encoded.push(digit_to_char(q));
+        bias = adapt(delta, handled + 1, handled == basic);
+        delta = 0;
+        handled += 1;
+      }
+    }
+
+    delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+    n += 1;
+  }
+
+  Ok(())
+}
+
+/// Decodes a Punycode encoded string.
+///
+/// # Notes
+/// This function follows [RFC 3492](https://datatracker.ietf.org/doc/html/rfc3492), where the
+/// characters before the last `-` are copied and the ones after it are decoded as deltas. Digits
+/// are case-insensitive. Nothing is written when decoding fails.
+pub fn decode_punycode(string: &str, decoded: &mut String) -> Result<(), PunycodeError> {
+  let (basic, digits) = match string.rfind(DELIMITER) {
+    Some(idx) => (&string[..idx], &string[idx + 1..]),
+    None => ("", string),
+  };
+
+  if !basic.is_ascii() {
+    return Err(PunycodeError::InvalidCharacter);
+  }
+
+  let mut output = basic.chars().collect::<Vec<_>>();
+  let mut digits = digits.bytes();
+  let mut n = INITIAL_N;
+  let mut i = 0u32;
+  let mut bias = INITIAL_BIAS;
+
+  while digits.len() > 0 {
+    let old_i = i;
+    let mut weight = 1u32;
+    let mut k = BASE;
+
+    loop {
+      let digit = match digits.next() {
+        Some(byte) => char_to_digit(byte).ok_or(PunycodeError::InvalidCharacter)?,
+        None => return Err(PunycodeError::Truncated),
+      };
+
+      i = digit
+        .checked_mul(weight)
+        .and_then(|increase| i.checked_add(increase))
+        .ok_or(PunycodeError::Overflow)?;
+
+      let t = threshold(k, bias);
+
+      if digit < t {
+        break;
+      }
+
+      weight = weight
+        .checked_mul(BASE - t)
+        .ok_or(PunycodeError::Overflow)?;
+      k += BASE;
+    }
+
+    let length = output.len() as u32 + 1;
+
+    bias = adapt(i - old_i, length, old_i == 0);
+    n = n.checked_add(i / length).ok_or(PunycodeError::Overflow)?;
+    i %= length;
+
+    let ch = char::from_u32(n).ok_or(PunycodeError::InvalidCodePoint)?;
+
+    output.insert(i as usize, ch);
+    i += 1;
+  }
+
+  decoded.extend(output);
+
+  Ok(())
+}
+
+/// Returns the threshold of the digit at position `k`.
+fn threshold(k: u32, bias: u32) -> u32 {
+  if k <= bias {
+    T_MIN
+  } else if k >= bias + T_MAX {
+    T_MAX
+  } else {
+    k - bias
+  }
+}
+
+/// Adapts the bias after a delta.
+fn adapt(mut delta: u32, length: u32, is_first: bool) -> u32 {
+  delta /= if is_first { DAMP } else { 2 };
+  delta += delta / length;
+
+  let mut k = 0;
+
+  while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+    delta /= BASE - T_MIN;
+    k += BASE;
+  }
+
+  k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+fn digit_to_char(digit: u32) -> char {
+  match digit {
+    0..=25 => (b'a' + digit as u8) as char,
+    _ => (b'0' + (digit - 26) as u8) as char,
+  }
+}
+
+fn char_to_digit(byte: u8) -> Option<u32> {
+  match byte {
+    b'a'..=b'z' => Some((byte - b'a') as u32),
+    b'A'..=b'Z' => Some((byte - b'A') as u32),
+    b'0'..=b'9' => Some((byte - b'0') as u32 + 26),
+    _ => None,
+  }
+}
@@ -0,0 +1,18534 @@
+// This file is generated by tools/idna_tables.py from IdnaMappingTable.txt 13.0.0 and version
+// 13.0.0 of the Unicode Character Database. Do not edit it by hand.
+
+use crate::idna::properties::BidiClass::{self, *};
+use crate::idna::properties::JoiningType;
+use crate::idna::properties::Status::{self, *};
+
+/// The status of each range of code points, which ends where the next one starts.
+pub(crate) const MAPPING: &[(u32, Status)] = &[
+  (0x0000, DisallowedStd3Valid),
+  (0x002D, Valid),
+  (0x002F, DisallowedStd3Valid),
+  (0x0030, Valid),
+  (0x003A, DisallowedStd3Valid),
+  (0x0041, Mapped(0, 1)),
+  (0x0042, Mapped(1, 1)),
+  (0x0043, Mapped(2, 1)),
+  (0x0044, Mapped(3, 1)),
+  (0x0045, Mapped(4, 1)),
+  (0x0046, Mapped(5, 1)),
+  (0x0047, Mapped(6, 1)),
+  (0x0048, Mapped(7, 1)),
+  (0x0049, Mapped(8, 1)),
+  (0x004A, Mapped(9, 1)),
+  (0x004B, Mapped(10, 1)),
+  (0x004C, Mapped(11, 1)),
+  (0x004D, Mapped(12, 1)),
+  (0x004E, Mapped(13, 1)),
+  (0x004F, Mapped(14, 1)),
+  (0x0050, Mapped(15, 1)),
+  (0x0051, Mapped(16, 1)),
+  (0x0052, Mapped(17, 1)),
+  (0x0053, Mapped(18, 1)),
+  (0x0054, Mapped(19, 1)),
+  (0x0055, Mapped(20, 1)),
+  (0x0056, Mapped(21, 1)),
+  (0x0057, Mapped(22, 1)),
+  (0x0058, Mapped(23, 1)),
+  (0x0059, Mapped(24, 1)),
+  (0x005A, Mapped(25, 1)),
+  (0x005B, DisallowedStd3Valid),
+  (0x0061, Valid),
+  (0x007B, DisallowedStd3Valid),
+  (0x0080, Disallowed),
+  (0x00A0, DisallowedStd3Mapped(26, 1)),
+  (0x00A1, Valid),
+  (0x00A8, DisallowedStd3Mapped(27, 3)),
+  (0x00A9, Valid),
+  (0x00AA, Mapped(0, 1)),
+  (0x00AB, Valid),
+  (0x00AD, Ignored),
+  (0x00AE, Valid),
+  (0x00AF, DisallowedStd3Mapped(30, 3)),
+  (0x00B0, Valid),
+  (0x00B2, Mapped(33, 1)),
+  (0x00B3, Mapped(34, 1)),
+  (0x00B4, DisallowedStd3Mapped(35, 3)),
+  (0x00B5, Mapped(38, 2)),
+  (0x00B6, Valid),
+  (0x00B8, DisallowedStd3Mapped(40, 3)),
+  (0x00B9, Mapped(43, 1)),
+  (0x00BA, Mapped(14, 1)),
+  (0x00BB, Valid),
+  (0x00BC, Mapped(44, 5)),
+  (0x00BD, Mapped(49, 5)),
+  (0x00BE, Mapped(54, 5)),
+  (0x00BF, Valid),
+  (0x00C0, Mapped(59, 2)),
+  (0x00C1, Mapped(61, 2)),
+  (0x00C2, Mapped(63, 2)),
+  (0x00C3, Mapped(65, 2)),
+  (0x00C4, Mapped(67, 2)),
+  (0x00C5, Mapped(69, 2)),
+  (0x00C6, Mapped(71, 2)),
+  (0x00C7, Mapped(73, 2)),
+  (0x00C8, Mapped(75, 2)),
+  (0x00C9, Mapped(77, 2)),
+  (0x00CA, Mapped(79, 2)),
+  (0x00CB, Mapped(81, 2)),
+  (0x00CC, Mapped(83, 2)),
+  (0x00CD, Mapped(85, 2)),
+  (0x00CE, Mapped(87, 2)),
+  (0x00CF, Mapped(89, 2)),
+  (0x00D0, Mapped(91, 2)),
+  (0x00D1, Mapped(93, 2)),
+  (0x00D2, Mapped(95, 2)),
+  (0x00D3, Mapped(97, 2)),
+  (0x00D4, Mapped(99, 2)),
+  (0x00D5, Mapped(101, 2)),
+  (0x00D6, Mapped(103, 2)),
+  (0x00D7, Valid),
+  (0x00D8, Mapped(105, 2)),
+  (0x00D9, Mapped(107, 2)),
+  (0x00DA, Mapped(109, 2)),
+  (0x00DB, Mapped(111, 2)),
+  (0x00DC, Mapped(113, 2)),
+  (0x00DD, Mapped(115, 2)),
+  (0x00DE, Mapped(117, 2)),
+  (0x00DF, Deviation(119, 2)),
+  (0x00E0, Valid),
+  (0x0100, Mapped(121, 2)),
+  (0x0101, Valid),
+  (0x0102, Mapped(123, 2)),
+  (0x0103, Valid),
+  (0x0104, Mapped(125, 2)),
+  (0x0105, Valid),
+  (0x0106, Mapped(127, 2)),
+  (0x0107, Valid),
+  (0x0108, Mapped(129, 2)),
+  (0x0109, Valid),
+  (0x010A, Mapped(131, 2)),
+  (0x010B, Valid),
+  (0x010C, Mapped(133, 2)),
+  (0x010D, Valid),
+  (0x010E, Mapped(135, 2)),
+  (0x010F, Valid),
+  (0x0110, Mapped(137, 2)),
+  (0x0111, Valid),
+  (0x0112, Mapped(139, 2)),
+  (0x0113, Valid),
+  (0x0114, Mapped(141, 2)),
+  (0x0115, Valid),
+  (0x0116, Mapped(143, 2)),
+  (0x0117, Valid),
+  (0x0118, Mapped(145, 2)),
+  (0x0119, Valid),
+  (0x011A, Mapped(147, 2)),
+  (0x011B, Valid),
+  (0x011C, Mapped(149, 2)),
+  (0x011D, Valid),
+  (0x011E, Mapped(151, 2)),
+  (0x011F, Valid),
+  (0x0120, Mapped(153, 2)),
+  (0x0121, Valid),
+  (0x0122, Mapped(155, 2)),
+  (0x0123, Valid),
+  (0x0124, Mapped(157, 2)),
+  (0x0125, Valid),
+  (0x0126, Mapped(159, 2)),
+  (0x0127, Valid),
+  (0x0128, Mapped(161, 2)),
+  (0x0129, Valid),
+  (0x012A, Mapped(163, 2)),
+  (0x012B, Valid),
+  (0x012C, Mapped(165, 2)),
+  (0x012D, Valid),
+  (0x012E, Mapped(167, 2)),
+  (0x012F, Valid),
+  (0x0130, Mapped(169, 3)),
+  (0x0131, Valid),
+  (0x0132, Mapped(172, 2)),
+  (0x0134, Mapped(174, 2)),
+  (0x0135, Valid),
+  (0x0136, Mapped(176, 2)),
+  (0x0137, Valid),
+  (0x0139, Mapped(178, 2)),
+  (0x013A, Valid),
+  (0x013B, Mapped(180, 2)),
+  (0x013C, Valid),
+  (0x013D, Mapped(182, 2)),
+  (0x013E, Valid),
+  (0x013F, Mapped(184, 3)),
+  (0x0141, Mapped(187, 2)),
+  (0x0142, Valid),
+  (0x0143, Mapped(189, 2)),
+  (0x0144, Valid),
+  (0x0145, Mapped(191, 2)),
+  (0x0146, Valid),
+  (0x0147, Mapped(193, 2)),
+  (0x0148, Valid),
+  (0x0149, Mapped(195, 3)),
+  (0x014A, Mapped(198, 2)),
+  (0x014B, Valid),
+  (0x014C, Mapped(200, 2)),
+  (0x014D, Valid),
+  (0x014E, Mapped(202, 2)),
+  (0x014F, Valid),
+  (0x0150, Mapped(204, 2)),
+  (0x0151, Valid),
+  (0x0152, Mapped(206, 2)),
+  (0x0153, Valid),
+  (0x0154, Mapped(208, 2)),
+  (0x0155, Valid),
+  (0x0156, Mapped(210, 2)),
+  (0x0157, Valid),
+  (0x0158, Mapped(212, 2)),
+  (0x0159, Valid),
+  (0x015A, Mapped(214, 2)),
+  (0x015B, Valid),
+  (0x015C, Mapped(216, 2)),
+  (0x015D, Valid),
+  (0x015E, Mapped(218, 2)),
+  (0x015F, Valid),
+  (0x0160, Mapped(220, 2)),
+  (0x0161, Valid),
+  (0x0162, Mapped(222, 2)),
+  (0x0163, Valid),
+  (0x0164, Mapped(224, 2)),
+  (0x0165, Valid),
+  (0x0166, Mapped(226, 2)),
+  (0x0167, Valid),
+  (0x0168, Mapped(228, 2)),
+  (0x0169, Valid),
+  (0x016A, Mapped(230, 2)),
+  (0x016B, Valid),
+  (0x016C, Mapped(232, 2)),
+  (0x016D, Valid),
+  (0x016E, Mapped(234, 2)),
+  (0x016F, Valid),
+  (0x0170, Mapped(236, 2)),
+  (0x0171, Valid),
+  (0x0172, Mapped(238, 2)),
+  (0x0173, Valid),
+  (0x0174, Mapped(240, 2)),
+  (0x0175, Valid),
+  (0x0176, Mapped(242, 2)),
+  (0x0177, Valid),
+  (0x0178, Mapped(244, 2)),
+  (0x0179, Mapped(246, 2)),
+  (0x017A, Valid),
+  (0x017B, Mapped(248, 2)),
+  (0x017C, Valid),
+  (0x017D, Mapped(250, 2)),
+  (0x017E, Valid),
+  (0x017F, Mapped(18, 1)),
+  (0x0180, Valid),
+  (0x0181, Mapped(252, 2)),
+  (0x0182, Mapped(254, 2)),
+  (0x0183, Valid),
+  (0x0184, Mapped(256, 2)),
+  (0x0185, Valid),
+  (0x0186, Mapped(258, 2)),
+  (0x0187, Mapped(260, 2)),
+  (0x0188, Valid),
+  (0x0189, Mapped(262, 2)),
+  (0x018A, Mapped(264, 2)),
+  (0x018B, Mapped(266, 2)),
+  (0x018C, Valid),
+  (0x018E, Mapped(268, 2)),
+  (0x018F, Mapped(270, 2)),
+  (0x0190, Mapped(272, 2)),
+  (0x0191, Mapped(274, 2)),
+  (0x0192, Valid),
+  (0x0193, Mapped(276, 2)),
+  (0x0194, Mapped(278, 2)),
+  (0x0195, Valid),
+  (0x0196, Mapped(280, 2)),
+  (0x0197, Mapped(282, 2)),
+  (0x0198, Mapped(284, 2)),
+  (0x0199, Valid),
+  (0x019C, Mapped(286, 2)),
+  (0x019D, Mapped(288, 2)),
+  (0x019E, Valid),
+  (0x019F, Mapped(290, 2)),
+  (0x01A0, Mapped(292, 2)),
+  (0x01A1, Valid),
+  (0x01A2, Mapped(294, 2)),
+  (0x01A3, Valid),
+  (0x01A4, Mapped(296, 2)),
+  (0x01A5, Valid),
+  (0x01A6, Mapped(298, 2)),
+  (0x01A7, Mapped(300, 2)),
+  (0x01A8, Valid),
+  (0x01A9, Mapped(302, 2)),
+  (0x01AA, Valid),
+  (0x01AC, Mapped(304, 2)),
+  (0x01AD, Valid),
+  (0x01AE, Mapped(306, 2)),
+  (0x01AF, Mapped(308, 2)),
+  (0x01B0, Valid),
+  (0x01B1, Mapped(310, 2)),
+  (0x01B2, Mapped(312, 2)),
+  (0x01B3, Mapped(314, 2)),
+  (0x01B4, Valid),
+  (0x01B5, Mapped(316, 2)),
+  (0x01B6, Valid),
+  (0x01B7, Mapped(318, 2)),
+  (0x01B8, Mapped(320, 2)),
+  (0x01B9, Valid),
+  (0x01BC, Mapped(322, 2)),
+  (0x01BD, Valid),
+  (0x01C4, Mapped(324, 3)),
+  (0x01C7, Mapped(327, 2)),
+  (0x01CA, Mapped(329, 2)),
+  (0x01CD, Mapped(331, 2)),
+  (0x01CE, Valid),
+  (0x01CF, Mapped(333, 2)),
+  (0x01D0, Valid),
+  (0x01D1, Mapped(335, 2)),
+  (0x01D2, Valid),
+  (0x01D3, Mapped(337, 2)),
+  (0x01D4, Valid),
+  (0x01D5, Mapped(339, 2)),
+  (0x01D6, Valid),
+  (0x01D7, Mapped(341, 2)),
+  (0x01D8, Valid),
+  (0x01D9, Mapped(343, 2)),
+  (0x01DA, Valid),
+  (0x01DB, Mapped(345, 2)),
+  (0x01DC, Valid),
+  (0x01DE, Mapped(347, 2)),
+  (0x01DF, Valid),
+  (0x01E0, Mapped(349, 2)),
+  (0x01E1, Valid),
+  (0x01E2, Mapped(351, 2)),
+  (0x01E3, Valid),
+  (0x01E4, Mapped(353, 2)),
+  (0x01E5, Valid),
+  (0x01E6, Mapped(355, 2)),
+  (0x01E7, Valid),
+  (0x01E8, Mapped(357, 2)),
+  (0x01E9, Valid),
+  (0x01EA, Mapped(359, 2)),
+  (0x01EB, Valid),
+  (0x01EC, Mapped(361, 2)),
+  (0x01ED, Valid),
+  (0x01EE, Mapped(363, 2)),
+  (0x01EF, Valid),
+  (0x01F1, Mapped(365, 2)),
+  (0x01F4, Mapped(367, 2)),
+  (0x01F5, Valid),
+  (0x01F6, Mapped(369, 2)),
+  (0x01F7, Mapped(371, 2)),
+  (0x01F8, Mapped(373, 2)),
+  (0x01F9, Valid),
+  (0x01FA, Mapped(375, 2)),
+  (0x01FB, Valid),
+  (0x01FC, Mapped(377, 2)),
+  (0x01FD, Valid),
+  (0x01FE, Mapped(379, 2)),
+  (0x01FF, Valid),
+  (0x0200, Mapped(381, 2)),
+  (0x0201, Valid),
+  (0x0202, Mapped(383, 2)),
+  (0x0203, Valid),
+  (0x0204, Mapped(385, 2)),
+  (0x0205, Valid),
+  (0x0206, Mapped(387, 2)),
+  (0x0207, Valid),
+  (0x0208, Mapped(389, 2)),
+  (0x0209, Valid),
+  (0x020A, Mapped(391, 2)),
+  (0x020B, Valid),
+  (0x020C, Mapped(393, 2)),
+  (0x020D, Valid),
+  (0x020E, Mapped(395, 2)),
+  (0x020F, Valid),
+  (0x0210, Mapped(397, 2)),
+  (0x0211, Valid),
+  (0x0212, Mapped(399, 2)),
+  (0x0213, Valid),
+  (0x0214, Mapped(401, 2)),
+  (0x0215, Valid),
+  (0x0216, Mapped(403, 2)),
+  (0x0217, Valid),
+  (0x0218, Mapped(405, 2)),
+  (0x0219, Valid),
+  (0x021A, Mapped(407, 2)),
+  (0x021B, Valid),
+  (0x021C, Mapped(409, 2)),
+  (0x021D, Valid),
+  (0x021E, Mapped(411, 2)),
+  (0x021F, Valid),
+  (0x0220, Mapped(413, 2)),
+  (0x0221, Valid),
+  (0x0222, Mapped(415, 2)),
+  (0x0223, Valid),
+  (0x0224, Mapped(417, 2)),
+  (0x0225, Valid),
+  (0x0226, Mapped(419, 2)),
+  (0x0227, Valid),
+  (0x0228, Mapped(421, 2)),
+  (0x0229, Valid),
+  (0x022A, Mapped(423, 2)),
+  (0x022B, Valid),
+  (0x022C, Mapped(425, 2)),
+  (0x022D, Valid),
+  (0x022E, Mapped(427, 2)),
+  (0x022F, Valid),
+  (0x0230, Mapped(429, 2)),
+  (0x0231, Valid),
+  (0x0232, Mapped(431, 2)),
+  (0x0233, Valid),
+  (0x023A, Mapped(433, 3)),
+  (0x023B, Mapped(436, 2)),
+  (0x023C, Valid),
+  (0x023D, Mapped(438, 2)),
+  (0x023E, Mapped(440, 3)),
+  (0x023F, Valid),
+  (0x0241, Mapped(443, 2)),
+  (0x0242, Valid),
+  (0x0243, Mapped(445, 2)),
+  (0x0244, Mapped(447, 2)),
+  (0x0245, Mapped(449, 2)),
+  (0x0246, Mapped(451, 2)),
+  (0x0247, Valid),
+  (0x0248, Mapped(453, 2)),
+  (0x0249, Valid),
+  (0x024A, Mapped(455, 2)),
+  (0x024B, Valid),
+  (0x024C, Mapped(457, 2)),
+  (0x024D, Valid),
+  (0x024E, Mapped(459, 2)),
+  (0x024F, Valid),
+  (0x02B0, Mapped(7, 1)),
+  (0x02B1, Mapped(461, 2)),
+  (0x02B2, Mapped(9, 1)),
+  (0x02B3, Mapped(17, 1)),
+  (0x02B4, Mapped(463, 2)),
+  (0x02B5, Mapped(465, 2)),
+  (0x02B6, Mapped(467, 2)),
+  (0x02B7, Mapped(22, 1)),
+  (0x02B8, Mapped(24, 1)),
+  (0x02B9, Valid),
+  (0x02D8, DisallowedStd3Mapped(469, 3)),
+  (0x02D9, DisallowedStd3Mapped(472, 3)),
+  (0x02DA, DisallowedStd3Mapped(475, 3)),
+  (0x02DB, DisallowedStd3Mapped(478, 3)),
+  (0x02DC, DisallowedStd3Mapped(481, 3)),
+  (0x02DD, DisallowedStd3Mapped(484, 3)),
+  (0x02DE, Valid),
+  (0x02E0, Mapped(278, 2)),
+  (0x02E1, Mapped(11, 1)),
+  (0x02E2, Mapped(18, 1)),
+  (0x02E3, Mapped(23, 1)),
+  (0x02E4, Mapped(487, 2)),
+  (0x02E5, Valid),
+  (0x0340, Mapped(489, 2)),
+  (0x0341, Mapped(491, 2)),
+  (0x0342, Valid),
+  (0x0343, Mapped(493, 2)),
+  (0x0344, Mapped(495, 4)),
+  (0x0345, Mapped(499, 2)),
+  (0x0346, Valid),
+  (0x034F, Ignored),
+  (0x0350, Valid),
+  (0x0370, Mapped(501, 2)),
+  (0x0371, Valid),
+  (0x0372, Mapped(503, 2)),
+  (0x0373, Valid),
+  (0x0374, Mapped(505, 2)),
+  (0x0375, Valid),
+  (0x0376, Mapped(507, 2)),
+  (0x0377, Valid),
+  (0x0378, Disallowed),
+  (0x037A, DisallowedStd3Mapped(509, 3)),
+  (0x037B, Valid),
+  (0x037E, DisallowedStd3Mapped(512, 1)),
+  (0x037F, Mapped(513, 2)),
+  (0x0380, Disallowed),
+  (0x0384, DisallowedStd3Mapped(35, 3)),
+  (0x0385, DisallowedStd3Mapped(515, 5)),
+  (0x0386, Mapped(520, 2)),
+  (0x0387, Mapped(522, 2)),
+  (0x0388, Mapped(524, 2)),
+  (0x0389, Mapped(526, 2)),
+  (0x038A, Mapped(528, 2)),
+  (0x038B, Disallowed),
+  (0x038C, Mapped(530, 2)),
+  (0x038D, Disallowed),
+  (0x038E, Mapped(532, 2)),
+  (0x038F, Mapped(534, 2)),
+  (0x0390, Valid),
+  (0x0391, Mapped(536, 2)),
+  (0x0392, Mapped(538, 2)),
+  (0x0393, Mapped(540, 2)),
+  (0x0394, Mapped(542, 2)),
+  (0x0395, Mapped(544, 2)),
+  (0x0396, Mapped(546, 2)),
+  (0x0397, Mapped(548, 2)),
+  (0x0398, Mapped(550, 2)),
+  (0x0399, Mapped(499, 2)),
+  (0x039A, Mapped(552, 2)),
+  (0x039B, Mapped(554, 2)),
+  (0x039C, Mapped(38, 2)),
+  (0x039D, Mapped(556, 2)),
+  (0x039E, Mapped(558, 2)),
+  (0x039F, Mapped(560, 2)),
+  (0x03A0, Mapped(562, 2)),
+  (0x03A1, Mapped(564, 2)),
+  (0x03A2, Disallowed),
+  (0x03A3, Mapped(566, 2)),
+  (0x03A4, Mapped(568, 2)),
+  (0x03A5, Mapped(570, 2)),
+  (0x03A6, Mapped(572, 2)),
+  (0x03A7, Mapped(574, 2)),
+  (0x03A8, Mapped(576, 2)),
+  (0x03A9, Mapped(578, 2)),
+  (0x03AA, Mapped(580, 2)),
+  (0x03AB, Mapped(582, 2)),
+  (0x03AC, Valid),
+  (0x03C2, Deviation(566, 2)),
+  (0x03C3, Valid),
+  (0x03CF, Mapped(584, 2)),
+  (0x03D0, Mapped(538, 2)),
+  (0x03D1, Mapped(550, 2)),
+  (0x03D2, Mapped(570, 2)),
+  (0x03D3, Mapped(532, 2)),
+  (0x03D4, Mapped(582, 2)),
+  (0x03D5, Mapped(572, 2)),
+  (0x03D6, Mapped(562, 2)),
+  (0x03D7, Valid),
+  (0x03D8, Mapped(586, 2)),
+  (0x03D9, Valid),
+  (0x03DA, Mapped(588, 2)),
+  (0x03DB, Valid),
+  (0x03DC, Mapped(590, 2)),
+  (0x03DD, Valid),
+  (0x03DE, Mapped(592, 2)),
+  (0x03DF, Valid),
+  (0x03E0, Mapped(594, 2)),
+  (0x03E1, Valid),
+  (0x03E2, Mapped(596, 2)),
+  (0x03E3, Valid),
+  (0x03E4, Mapped(598, 2)),
+  (0x03E5, Valid),
+  (0x03E6, Mapped(600, 2)),
+  (0x03E7, Valid),
+  (0x03E8, Mapped(602, 2)),
+  (0x03E9, Valid),
+  (0x03EA, Mapped(604, 2)),
+  (0x03EB, Valid),
+  (0x03EC, Mapped(606, 2)),
+  (0x03ED, Valid),
+  (0x03EE, Mapped(608, 2)),
+  (0x03EF, Valid),
+  (0x03F0, Mapped(552, 2)),
+  (0x03F1, Mapped(564, 2)),
+  (0x03F2, Mapped(566, 2)),
+  (0x03F3, Valid),
+  (0x03F4, Mapped(550, 2)),
+  (0x03F5, Mapped(544, 2)),
+  (0x03F6, Valid),
+  (0x03F7, Mapped(610, 2)),
+  (0x03F8, Valid),
+  (0x03F9, Mapped(566, 2)),
+  (0x03FA, Mapped(612, 2)),
+  (0x03FB, Valid),
+  (0x03FD, Mapped(614, 2)),
+  (0x03FE, Mapped(616, 2)),
+  (0x03FF, Mapped(618, 2)),
+  (0x0400, Mapped(620, 2)),
+  (0x0401, Mapped(622, 2)),
+  (0x0402, Mapped(624, 2)),
+  (0x0403, Mapped(626, 2)),
+  (0x0404, Mapped(628, 2)),
+  (0x0405, Mapped(630, 2)),
+  (0x0406, Mapped(632, 2)),
+  (0x0407, Mapped(634, 2)),
+  (0x0408, Mapped(636, 2)),
+  (0x0409, Mapped(638, 2)),
+  (0x040A, Mapped(640, 2)),
+  (0x040B, Mapped(642, 2)),
+  (0x040C, Mapped(644, 2)),
+  (0x040D, Mapped(646, 2)),
+  (0x040E, Mapped(648, 2)),
+  (0x040F, Mapped(650, 2)),
+  (0x0410, Mapped(652, 2)),
+  (0x0411, Mapped(654, 2)),
+  (0x0412, Mapped(656, 2)),
+  (0x0413, Mapped(658, 2)),
+  (0x0414, Mapped(660, 2)),
+  (0x0415, Mapped(662, 2)),
+  (0x0416, Mapped(664, 2)),
+  (0x0417, Mapped(666, 2)),
+  (0x0418, Mapped(668, 2)),
+  (0x0419, Mapped(670, 2)),
+  (0x041A, Mapped(672, 2)),
+  (0x041B, Mapped(674, 2)),
+  (0x041C, Mapped(676, 2)),
+  (0x041D, Mapped(678, 2)),
+  (0x041E, Mapped(680, 2)),
+  (0x041F, Mapped(682, 2)),
+  (0x0420, Mapped(684, 2)),
+  (0x0421, Mapped(686, 2)),
+  (0x0422, Mapped(688, 2)),
+  (0x0423, Mapped(690, 2)),
+  (0x0424, Mapped(692, 2)),
+  (0x0425, Mapped(694, 2)),
+  (0x0426, Mapped(696, 2)),
+  (0x0427, Mapped(698, 2)),
+  (0x0428, Mapped(700, 2)),
+  (0x0429, Mapped(702, 2)),
+  (0x042A, Mapped(704, 2)),
+  (0x042B, Mapped(706, 2)),
+  (0x042C, Mapped(708, 2)),
+  (0x042D, Mapped(710, 2)),
+  (0x042E, Mapped(712, 2)),
+  (0x042F, Mapped(714, 2)),
+  (0x0430, Valid),
+  (0x0460, Mapped(716, 2)),
+  (0x0461, Valid),
+  (0x0462, Mapped(718, 2)),
+  (0x0463, Valid),
+  (0x0464, Mapped(720, 2)),
+  (0x0465, Valid),
+  (0x0466, Mapped(722, 2)),
+  (0x0467, Valid),
+  (0x0468, Mapped(724, 2)),
+  (0x0469, Valid),
+  (0x046A, Mapped(726, 2)),
+  (0x046B, Valid),
+  (0x046C, Mapped(728, 2)),
+  (0x046D, Valid),
+  (0x046E, Mapped(730, 2)),
+  (0x046F, Valid),
+  (0x0470, Mapped(732, 2)),
+  (0x0471, Valid),
+  (0x0472, Mapped(734, 2)),
+  (0x0473, Valid),
+  (0x0474, Mapped(736, 2)),
+  (0x0475, Valid),
+  (0x0476, Mapped(738, 2)),
+  (0x0477, Valid),
+  (0x0478, Mapped(740, 2)),
+  (0x0479, Valid),
+  (0x047A, Mapped(742, 2)),
+  (0x047B, Valid),
+  (0x047C, Mapped(744, 2)),
+  (0x047D, Valid),
+  (0x047E, Mapped(746, 2)),
+  (0x047F, Valid),
+  (0x0480, Mapped(748, 2)),
+  (0x0481, Valid),
+  (0x048A, Mapped(750, 2)),
+  (0x048B, Valid),
+  (0x048C, Mapped(752, 2)),
+  (0x048D, Valid),
+  (0x048E, Mapped(754, 2)),
+  (0x048F, Valid),
+  (0x0490, Mapped(756, 2)),
+  (0x0491, Valid),
+  (0x0492, Mapped(758, 2)),
+  (0x0493, Valid),
+  (0x0494, Mapped(760, 2)),
+  (0x0495, Valid),
+  (0x0496, Mapped(762, 2)),
+  (0x0497, Valid),
+  (0x0498, Mapped(764, 2)),
+  (0x0499, Valid),
+  (0x049A, Mapped(766, 2)),
+  (0x049B, Valid),
+  (0x049C, Mapped(768, 2)),
+  (0x049D, Valid),
+  (0x049E, Mapped(770, 2)),
+  (0x049F, Valid),
+  (0x04A0, Mapped(772, 2)),
+  (0x04A1, Valid),
+  (0x04A2, Mapped(774, 2)),
+  (0x04A3, Valid),
+  (0x04A4, Mapped(776, 2)),
+  (0x04A5, Valid),
+  (0x04A6, Mapped(778, 2)),
+  (0x04A7, Valid),
+  (0x04A8, Mapped(780, 2)),
+  (0x04A9, Valid),
+  (0x04AA, Mapped(782, 2)),
+  (0x04AB, Valid),
+  (0x04AC, Mapped(784, 2)),
+  (0x04AD, Valid),
+  (0x04AE, Mapped(786, 2)),
+  (0x04AF, Valid),
+  (0x04B0, Mapped(788, 2)),
+  (0x04B1, Valid),
+  (0x04B2, Mapped(790, 2)),
+  (0x04B3, Valid),
+  (0x04B4, Mapped(792, 2)),
+  (0x04B5, Valid),
+  (0x04B6, Mapped(794, 2)),
+  (0x04B7, Valid),
+  (0x04B8, Mapped(796, 2)),
+  (0x04B9, Valid),
+  (0x04BA, Mapped(798, 2)),
+  (0x04BB, Valid),
+  (0x04BC, Mapped(800, 2)),
+  (0x04BD, Valid),
+  (0x04BE, Mapped(802, 2)),
+  (0x04BF, Valid),
+  (0x04C0, Disallowed),
+  (0x04C1, Mapped(804, 2)),
+  (0x04C2, Valid),
+  (0x04C3, Mapped(806, 2)),
+  (0x04C4, Valid),
+  (0x04C5, Mapped(808, 2)),
+  (0x04C6, Valid),
+  (0x04C7, Mapped(810, 2)),
+  (0x04C8, Valid),
+  (0x04C9, Mapped(812, 2)),
+  (0x04CA, Valid),
+  (0x04CB, Mapped(814, 2)),
+  (0x04CC, Valid),
+  (0x04CD, Mapped(816, 2)),
+  (0x04CE, Valid),
+  (0x04D0, Mapped(818, 2)),
+  (0x04D1, Valid),
+  (0x04D2, Mapped(820, 2)),
+  (0x04D3, Valid),
+  (0x04D4, Mapped(822, 2)),
+  (0x04D5, Valid),
+  (0x04D6, Mapped(824, 2)),
+  (0x04D7, Valid),
+  (0x04D8, Mapped(826, 2)),
+  (0x04D9, Valid),
+  (0x04DA, Mapped(828, 2)),
+  (0x04DB, Valid),
+  (0x04DC, Mapped(830, 2)),
+  (0x04DD, Valid),
+  (0x04DE, Mapped(832, 2)),
+  (0x04DF, Valid),
+  (0x04E0, Mapped(834, 2)),
+  (0x04E1, Valid),
+  (0x04E2, Mapped(836, 2)),
+  (0x04E3, Valid),
+  (0x04E4, Mapped(838, 2)),
+  (0x04E5, Valid),
+  (0x04E6, Mapped(840, 2)),
+  (0x04E7, Valid),
+  (0x04E8, Mapped(842, 2)),
+  (0x04E9, Valid),
+  (0x04EA, Mapped(844, 2)),
+  (0x04EB, Valid),
+  (0x04EC, Mapped(846, 2)),
+  (0x04ED, Valid),
+  (0x04EE, Mapped(848, 2)),
+  (0x04EF, Valid),
+  (0x04F0, Mapped(850, 2)),
+  (0x04F1, Valid),
+  (0x04F2, Mapped(852, 2)),
+  (0x04F3, Valid),
+  (0x04F4, Mapped(854, 2)),
+  (0x04F5, Valid),
+  (0x04F6, Mapped(856, 2)),
+  (0x04F7, Valid),
+  (0x04F8, Mapped(858, 2)),
+  (0x04F9, Valid),
+  (0x04FA, Mapped(860, 2)),
+  (0x04FB, Valid),
+  (0x04FC, Mapped(862, 2)),
+  (0x04FD, Valid),
+  (0x04FE, Mapped(864, 2)),
+  (0x04FF, Valid),
+  (0x0500, Mapped(866, 2)),
+  (0x0501, Valid),
+  (0x0502, Mapped(868, 2)),
+  (0x0503, Valid),
+  (0x0504, Mapped(870, 2)),
+  (0x0505, Valid),
+  (0x0506, Mapped(872, 2)),
+  (0x0507, Valid),
+  (0x0508, Mapped(874, 2)),
+  (0x0509, Valid),
+  (0x050A, Mapped(876, 2)),
+  (0x050B, Valid),
+  (0x050C, Mapped(878, 2)),
+  (0x050D, Valid),
+  (0x050E, Mapped(880, 2)),
+  (0x050F, Valid),
+  (0x0510, Mapped(882, 2)),
+  (0x0511, Valid),
+  (0x0512, Mapped(884, 2)),
+  (0x0513, Valid),
+  (0x0514, Mapped(886, 2)),
+  (0x0515, Valid),
+  (0x0516, Mapped(888, 2)),
+  (0x0517, Valid),
+  (0x0518, Mapped(890, 2)),
+  (0x0519, Valid),
+  (0x051A, Mapped(892, 2)),
+  (0x051B, Valid),
+  (0x051C, Mapped(894, 2)),
+  (0x051D, Valid),
+  (0x051E, Mapped(896, 2)),
+  (0x051F, Valid),
+  (0x0520, Mapped(898, 2)),
+  (0x0521, Valid),
+  (0x0522, Mapped(900, 2)),
+  (0x0523, Valid),
+  (0x0524, Mapped(902, 2)),
+  (0x0525, Valid),
+  (0x0526, Mapped(904, 2)),
+  (0x0527, Valid),
+  (0x0528, Mapped(906, 2)),
+  (0x0529, Valid),
+  (0x052A, Mapped(908, 2)),
+  (0x052B, Valid),
+  (0x052C, Mapped(910, 2)),
+  (0x052D, Valid),
+  (0x052E, Mapped(912, 2)),
+  (0x052F, Valid),
+  (0x0530, Disallowed),
+  (0x0531, Mapped(914, 2)),
+  (0x0532, Mapped(916, 2)),
+  (0x0533, Mapped(918, 2)),
+  (0x0534, Mapped(920, 2)),
+  (0x0535, Mapped(922, 2)),
+  (0x0536, Mapped(924, 2)),
+  (0x0537, Mapped(926, 2)),
+  (0x0538, Mapped(928, 2)),
+  (0x0539, Mapped(930, 2)),
+  (0x053A, Mapped(932, 2)),
+  (0x053B, Mapped(934, 2)),
+  (0x053C, Mapped(936, 2)),
+  (0x053D, Mapped(938, 2)),
+  (0x053E, Mapped(940, 2)),
+  (0x053F, Mapped(942, 2)),
+  (0x0540, Mapped(944, 2)),
+  (0x0541, Mapped(946, 2)),
+  (0x0542, Mapped(948, 2)),
+  (0x0543, Mapped(950, 2)),
+  (0x0544, Mapped(952, 2)),
+  (0x0545, Mapped(954, 2)),
+  (0x0546, Mapped(956, 2)),
+  (0x0547, Mapped(958, 2)),
+  (0x0548, Mapped(960, 2)),
+  (0x0549, Mapped(962, 2)),
+  (0x054A, Mapped(964, 2)),
+  (0x054B, Mapped(966, 2)),
+  (0x054C, Mapped(968, 2)),
+  (0x054D, Mapped(970, 2)),
+  (0x054E, Mapped(972, 2)),
+  (0x054F, Mapped(974, 2)),
+  (0x0550, Mapped(976, 2)),
+  (0x0551, Mapped(978, 2)),
+  (0x0552, Mapped(980, 2)),
+  (0x0553, Mapped(982, 2)),
+  (0x0554, Mapped(984, 2)),
+  (0x0555, Mapped(986, 2)),
+  (0x0556, Mapped(988, 2)),
+  (0x0557, Disallowed),
+  (0x0559, Valid),
+  (0x0587, Mapped(990, 4)),
+  (0x0588, Valid),
+  (0x058B, Disallowed),
+  (0x058D, Valid),
+  (0x0590, Disallowed),
+  (0x0591, Valid),
+  (0x05C8, Disallowed),
+  (0x05D0, Valid),
+  (0x05EB, Disallowed),
+  (0x05EF, Valid),
+  (0x05F5, Disallowed),
+  (0x0606, Valid),
+  (0x061C, Disallowed),
+  (0x061E, Valid),
+  (0x0675, Mapped(994, 4)),
+  (0x0676, Mapped(998, 4)),
+  (0x0677, Mapped(1002, 4)),
+  (0x0678, Mapped(1006, 4)),
+  (0x0679, Valid),
+  (0x06DD, Disallowed),
+  (0x06DE, Valid),
+  (0x070E, Disallowed),
+  (0x0710, Valid),
+  (0x074B, Disallowed),
+  (0x074D, Valid),
+  (0x07B2, Disallowed),
+  (0x07C0, Valid),
+  (0x07FB, Disallowed),
+  (0x07FD, Valid),
+  (0x082E, Disallowed),
+  (0x0830, Valid),
+  (0x083F, Disallowed),
+  (0x0840, Valid),
+  (0x085C, Disallowed),
+  (0x085E, Valid),
+  (0x085F, Disallowed),
+  (0x0860, Valid),
+  (0x086B, Disallowed),
+  (0x08A0, Valid),
+  (0x08B5, Disallowed),
+  (0x08B6, Valid),
+  (0x08C8, Disallowed),
+  (0x08D3, Valid),
+  (0x08E2, Disallowed),
+  (0x08E3, Valid),
+  (0x0958, Mapped(1010, 6)),
+  (0x0959, Mapped(1016, 6)),
+  (0x095A, Mapped(1022, 6)),
+  (0x095B, Mapped(1028, 6)),
+  (0x095C, Mapped(1034, 6)),
+  (0x095D, Mapped(1040, 6)),
+  (0x095E, Mapped(1046, 6)),
+  (0x095F, Mapped(1052, 6)),
+  (0x0960, Valid),
+  (0x0984, Disallowed),
+  (0x0985, Valid),
+  (0x098D, Disallowed),
+  (0x098F, Valid),
+  (0x0991, Disallowed),
+  (0x0993, Valid),
+  (0x09A9, Disallowed),
+  (0x09AA, Valid),
+  (0x09B1, Disallowed),
+  (0x09B2, Valid),
+  (0x09B3, Disallowed),
+  (0x09B6, Valid),
+  (0x09BA, Disallowed),
+  (0x09BC, Valid),
+  (0x09C5, Disallowed),
+  (0x09C7, Valid),
+  (0x09C9, Disallowed),
+  (0x09CB, Valid),
+  (0x09CF, Disallowed),
+  (0x09D7, Valid),
+  (0x09D8, Disallowed),
+  (0x09DC, Mapped(1058, 6)),
+  (0x09DD, Mapped(1064, 6)),
+  (0x09DE, Disallowed),
+  (0x09DF, Mapped(1070, 6)),
+  (0x09E0, Valid),
+  (0x09E4, Disallowed),
+  (0x09E6, Valid),
+  (0x09FF, Disallowed),
+  (0x0A01, Valid),
+  (0x0A04, Disallowed),
+  (0x0A05, Valid),
+  (0x0A0B, Disallowed),
+  (0x0A0F, Valid),
+  (0x0A11, Disallowed),
+  (0x0A13, Valid),
+  (0x0A29, Disallowed),
+  (0x0A2A, Valid),
+  (0x0A31, Disallowed),
+  (0x0A32, Valid),
+  (0x0A33, Mapped(1076, 6)),
+  (0x0A34, Disallowed),
+  (0x0A35, Valid),
+  (0x0A36, Mapped(1082, 6)),
+  (0x0A37, Disallowed),
+  (0x0A38, Valid),
+  (0x0A3A, Disallowed),
+  (0x0A3C, Valid),
+  (0x0A3D, Disallowed),
+  (0x0A3E, Valid),
+  (0x0A43, Disallowed),
+  (0x0A47, Valid),
+  (0x0A49, Disallowed),
+  (0x0A4B, Valid),
+  (0x0A4E, Disallowed),
+  (0x0A51, Valid),
+  (0x0A52, Disallowed),
+  (0x0A59, Mapped(1088, 6)),
+  (0x0A5A, Mapped(1094, 6)),
+  (0x0A5B, Mapped(1100, 6)),
+  (0x0A5C, Valid),
+  (0x0A5D, Disallowed),
+  (0x0A5E, Mapped(1106, 6)),
+  (0x0A5F, Disallowed),
+  (0x0A66, Valid),
+  (0x0A77, Disallowed),
+  (0x0A81, Valid),
+  (0x0A84, Disallowed),
+  (0x0A85, Valid),
+  (0x0A8E, Disallowed),
+  (0x0A8F, Valid),
+  (0x0A92, Disallowed),
+  (0x0A93, Valid),
+  (0x0AA9, Disallowed),
+  (0x0AAA, Valid),
+  (0x0AB1, Disallowed),
+  (0x0AB2, Valid),
+  (0x0AB4, Disallowed),
+  (0x0AB5, Valid),
+  (0x0ABA, Disallowed),
+  (0x0ABC, Valid),
+  (0x0AC6, Disallowed),
+  (0x0AC7, Valid),
+  (0x0ACA, Disallowed),
+  (0x0ACB, Valid),
+  (0x0ACE, Disallowed),
+  (0x0AD0, Valid),
+  (0x0AD1, Disallowed),
+  (0x0AE0, Valid),
+  (0x0AE4, Disallowed),
+  (0x0AE6, Valid),
+  (0x0AF2, Disallowed),
+  (0x0AF9, Valid),
+  (0x0B00, Disallowed),
+  (0x0B01, Valid),
+  (0x0B04, Disallowed),
+  (0x0B05, Valid),
+  (0x0B0D, Disallowed),
+  (0x0B0F, Valid),
+  (0x0B11, Disallowed),
+  (0x0B13, Valid),
+  (0x0B29, Disallowed),
+  (0x0B2A, Valid),
+  (0x0B31, Disallowed),
+  (0x0B32, Valid),
+  (0x0B34, Disallowed),
+  (0x0B35, Valid),
+  (0x0B3A, Disallowed),
+  (0x0B3C, Valid),
+  (0x0B45, Disallowed),
+  (0x0B47, Valid),
+  (0x0B49, Disallowed),
+  (0x0B4B, Valid),
+  (0x0B4E, Disallowed),
+  (0x0B55, Valid),
+  (0x0B58, Disallowed),
+  (0x0B5C, Mapped(1112, 6)),
+  (0x0B5D, Mapped(1118, 6)),
+  (0x0B5E, Disallowed),
+  (0x0B5F, Valid),
+  (0x0B64, Disallowed),
+  (0x0B66, Valid),
+  (0x0B78, Disallowed),
+  (0x0B82, Valid),
+  (0x0B84, Disallowed),
+  (0x0B85, Valid),
+  (0x0B8B, Disallowed),
+  (0x0B8E, Valid),
+  (0x0B91, Disallowed),
+  (0x0B92, Valid),
+  (0x0B96, Disallowed),
+  (0x0B99, Valid),
+  (0x0B9B, Disallowed),
+  (0x0B9C, Valid),
+  (0x0B9D, Disallowed),
+  (0x0B9E, Valid),
+  (0x0BA0, Disallowed),
+  (0x0BA3, Valid),
+  (0x0BA5, Disallowed),
+  (0x0BA8, Valid),
+  (0x0BAB, Disallowed),
+  (0x0BAE, Valid),
+  (0x0BBA, Disallowed),
+  (0x0BBE, Valid),
+  (0x0BC3, Disallowed),
+  (0x0BC6, Valid),
+  (0x0BC9, Disallowed),
+  (0x0BCA, Valid),
+  (0x0BCE, Disallowed),
+  (0x0BD0, Valid),
+  (0x0BD1, Disallowed),
+  (0x0BD7, Valid),
+  (0x0BD8, Disallowed),
+  (0x0BE6, Valid),
+  (0x0BFB, Disallowed),
+  (0x0C00, Valid),
+  (0x0C0D, Disallowed),
+  (0x0C0E, Valid),
+  (0x0C11, Disallowed),
+  (0x0C12, Valid),
+  (0x0C29, Disallowed),
+  (0x0C2A, Valid),
+  (0x0C3A, Disallowed),
+  (0x0C3D, Valid),
+  (0x0C45, Disallowed),
+  (0x0C46, Valid),
+  (0x0C49, Disallowed),
+  (0x0C4A, Valid),
+  (0x0C4E, Disallowed),
+  (0x0C55, Valid),
+  (0x0C57, Disallowed),
+  (0x0C58, Valid),
+  (0x0C5B, Disallowed),
+  (0x0C60, Valid),
+  (0x0C64, Disallowed),
+  (0x0C66, Valid),
+  (0x0C70, Disallowed),
+  (0x0C77, Valid),
+  (0x0C8D, Disallowed),
+  (0x0C8E, Valid),
+  (0x0C91, Disallowed),
+  (0x0C92, Valid),
+  (0x0CA9, Disallowed),
+  (0x0CAA, Valid),
+  (0x0CB4, Disallowed),
+  (0x0CB5, Valid),
+  (0x0CBA, Disallowed),
+  (0x0CBC, Valid),
+  (0x0CC5, Disallowed),
+  (0x0CC6, Valid),
+  (0x0CC9, Disallowed),
+  (0x0CCA, Valid),
+  (0x0CCE, Disallowed),
+  (0x0CD5, Valid),
+  (0x0CD7, Disallowed),
+  (0x0CDE, Valid),
+  (0x0CDF, Disallowed),
+  (0x0CE0, Valid),
+  (0x0CE4, Disallowed),
+  (0x0CE6, Valid),
+  (0x0CF0, Disallowed),
+  (0x0CF1, Valid),
+  (0x0CF3, Disallowed),
+  (0x0D00, Valid),
+  (0x0D0D, Disallowed),
+  (0x0D0E, Valid),
+  (0x0D11, Disallowed),
+  (0x0D12, Valid),
+  (0x0D45, Disallowed),
+  (0x0D46, Valid),
+  (0x0D49, Disallowed),
+  (0x0D4A, Valid),
+  (0x0D50, Disallowed),
+  (0x0D54, Valid),
+  (0x0D64, Disallowed),
+  (0x0D66, Valid),
+  (0x0D80, Disallowed),
+  (0x0D81, Valid),
+  (0x0D84, Disallowed),
+  (0x0D85, Valid),
+  (0x0D97, Disallowed),
+  (0x0D9A, Valid),
+  (0x0DB2, Disallowed),
+  (0x0DB3, Valid),
+  (0x0DBC, Disallowed),
+  (0x0DBD, Valid),
+  (0x0DBE, Disallowed),
+  (0x0DC0, Valid),
+  (0x0DC7, Disallowed),
+  (0x0DCA, Valid),
+  (0x0DCB, Disallowed),
+  (0x0DCF, Valid),
+  (0x0DD5, Disallowed),
+  (0x0DD6, Valid),
+  (0x0DD7, Disallowed),
+  (0x0DD8, Valid),
+  (0x0DE0, Disallowed),
+  (0x0DE6, Valid),
+  (0x0DF0, Disallowed),
+  (0x0DF2, Valid),
+  (0x0DF5, Disallowed),
+  (0x0E01, Valid),
+  (0x0E33, Mapped(1124, 6)),
+  (0x0E34, Valid),
+  (0x0E3B, Disallowed),
+  (0x0E3F, Valid),
+  (0x0E5C, Disallowed),
+  (0x0E81, Valid),
+  (0x0E83, Disallowed),
+  (0x0E84, Valid),
+  (0x0E85, Disallowed),
+  (0x0E86, Valid),
+  (0x0E8B, Disallowed),
+  (0x0E8C, Valid),
+  (0x0EA4, Disallowed),
+  (0x0EA5, Valid),
+  (0x0EA6, Disallowed),
+  (0x0EA7, Valid),
+  (0x0EB3, Mapped(1130, 6)),
+  (0x0EB4, Valid),
+  (0x0EBE, Disallowed),
+  (0x0EC0, Valid),
+  (0x0EC5, Disallowed),
+  (0x0EC6, Valid),
+  (0x0EC7, Disallowed),
+  (0x0EC8, Valid),
+  (0x0ECE, Disallowed),
+  (0x0ED0, Valid),
+  (0x0EDA, Disallowed),
+  (0x0EDC, Mapped(1136, 6)),
+  (0x0EDD, Mapped(1142, 6)),
+  (0x0EDE, Valid),
+  (0x0EE0, Disallowed),
+  (0x0F00, Valid),
+  (0x0F0C, Mapped(1148, 3)),
+  (0x0F0D, Valid),
+  (0x0F43, Mapped(1151, 6)),
+  (0x0F44, Valid),
+  (0x0F48, Disallowed),
+  (0x0F49, Valid),
+  (0x0F4D, Mapped(1157, 6)),
+  (0x0F4E, Valid),
+  (0x0F52, Mapped(1163, 6)),
+  (0x0F53, Valid),
+  (0x0F57, Mapped(1169, 6)),
+  (0x0F58, Valid),
+  (0x0F5C, Mapped(1175, 6)),
+  (0x0F5D, Valid),
+  (0x0F69, Mapped(1181, 6)),
+  (0x0F6A, Valid),
+  (0x0F6D, Disallowed),
+  (0x0F71, Valid),
+  (0x0F73, Mapped(1187, 6)),
+  (0x0F74, Valid),
+  (0x0F75, Mapped(1193, 6)),
+  (0x0F76, Mapped(1199, 6)),
+  (0x0F77, Mapped(1205, 9)),
+  (0x0F78, Mapped(1214, 6)),
+  (0x0F79, Mapped(1220, 9)),
+  (0x0F7A, Valid),
+  (0x0F81, Mapped(1229, 6)),
+  (0x0F82, Valid),
+  (0x0F93, Mapped(1235, 6)),
+  (0x0F94, Valid),
+  (0x0F98, Disallowed),
+  (0x0F99, Valid),
+  (0x0F9D, Mapped(1241, 6)),
+  (0x0F9E, Valid),
+  (0x0FA2, Mapped(1247, 6)),
+  (0x0FA3, Valid),
+  (0x0FA7, Mapped(1253, 6)),
+  (0x0FA8, Valid),
+  (0x0FAC, Mapped(1259, 6)),
+  (0x0FAD, Valid),
+  (0x0FB9, Mapped(1265, 6)),
+  (0x0FBA, Valid),
+  (0x0FBD, Disallowed),
+  (0x0FBE, Valid),
+  (0x0FCD, Disallowed),
+  (0x0FCE, Valid),
+  (0x0FDB, Disallowed),
+  (0x1000, Valid),
+  (0x10A0, Disallowed),
+  (0x10C7, Mapped(1271, 3)),
+  (0x10C8, Disallowed),
+  (0x10CD, Mapped(1274, 3)),
+  (0x10CE, Disallowed),
+  (0x10D0, Valid),
+  (0x10FC, Mapped(1277, 3)),
+  (0x10FD, Valid),
+  (0x115F, Disallowed),
+  (0x1161, Valid),
+  (0x1249, Disallowed),
+  (0x124A, Valid),
+  (0x124E, Disallowed),
+  (0x1250, Valid),
+  (0x1257, Disallowed),
+  (0x1258, Valid),
+  (0x1259, Disallowed),
+  (0x125A, Valid),
+  (0x125E, Disallowed),
+  (0x1260, Valid),
+  (0x1289, Disallowed),
+  (0x128A, Valid),
+  (0x128E, Disallowed),
+  (0x1290, Valid),
+  (0x12B1, Disallowed),
+  (0x12B2, Valid),
+  (0x12B6, Disallowed),
+  (0x12B8, Valid),
+  (0x12BF, Disallowed),
+  (0x12C0, Valid),
+  (0x12C1, Disallowed),
+  (0x12C2, Valid),
+  (0x12C6, Disallowed),
+  (0x12C8, Valid),
+  (0x12D7, Disallowed),
+  (0x12D8, Valid),
+  (0x1311, Disallowed),
+  (0x1312, Valid),
+  (0x1316, Disallowed),
+  (0x1318, Valid),
+  (0x135B, Disallowed),
+  (0x135D, Valid),
+  (0x137D, Disallowed),
+  (0x1380, Valid),
+  (0x139A, Disallowed),
+  (0x13A0, Valid),
+  (0x13F6, Disallowed),
+  (0x13F8, Mapped(1280, 3)),
+  (0x13F9, Mapped(1283, 3)),
+  (0x13FA, Mapped(1286, 3)),
+  (0x13FB, Mapped(1289, 3)),
+  (0x13FC, Mapped(1292, 3)),
+  (0x13FD, Mapped(1295, 3)),
+  (0x13FE, Disallowed),
+  (0x1400, Valid),
+  (0x1680, Disallowed),
+  (0x1681, Valid),
+  (0x169D, Disallowed),
+  (0x16A0, Valid),
+  (0x16F9, Disallowed),
+  (0x1700, Valid),
+  (0x170D, Disallowed),
+  (0x170E, Valid),
+  (0x1715, Disallowed),
+  (0x1720, Valid),
+  (0x1737, Disallowed),
+  (0x1740, Valid),
+  (0x1754, Disallowed),
+  (0x1760, Valid),
+  (0x176D, Disallowed),
+  (0x176E, Valid),
+  (0x1771, Disallowed),
+  (0x1772, Valid),
+  (0x1774, Disallowed),
+  (0x1780, Valid),
+  (0x17B4, Disallowed),
+  (0x17B6, Valid),
+  (0x17DE, Disallowed),
+  (0x17E0, Valid),
+  (0x17EA, Disallowed),
+  (0x17F0, Valid),
+  (0x17FA, Disallowed),
+  (0x1800, Valid),
+  (0x1806, Disallowed),
+  (0x1807, Valid),
+  (0x180B, Ignored),
+  (0x180E, Disallowed),
+  (0x1810, Valid),
+  (0x181A, Disallowed),
+  (0x1820, Valid),
+  (0x1879, Disallowed),
+  (0x1880, Valid),
+  (0x18AB, Disallowed),
+  (0x18B0, Valid),
+  (0x18F6, Disallowed),
+  (0x1900, Valid),
+  (0x191F, Disallowed),
+  (0x1920, Valid),
+  (0x192C, Disallowed),
+  (0x1930, Valid),
+  (0x193C, Disallowed),
+  (0x1940, Valid),
+  (0x1941, Disallowed),
+  (0x1944, Valid),
+  (0x196E, Disallowed),
+  (0x1970, Valid),
+  (0x1975, Disallowed),
+  (0x1980, Valid),
+  (0x19AC, Disallowed),
+  (0x19B0, Valid),
+  (0x19CA, Disallowed),
+  (0x19D0, Valid),
+  (0x19DB, Disallowed),
+  (0x19DE, Valid),
+  (0x1A1C, Disallowed),
+  (0x1A1E, Valid),
+  (0x1A5F, Disallowed),
+  (0x1A60, Valid),
+  (0x1A7D, Disallowed),
+  (0x1A7F, Valid),
+  (0x1A8A, Disallowed),
+  (0x1A90, Valid),
+  (0x1A9A, Disallowed),
+  (0x1AA0, Valid),
+  (0x1AAE, Disallowed),
+  (0x1AB0, Valid),
+  (0x1AC1, Disallowed),
+  (0x1B00, Valid),
+  (0x1B4C, Disallowed),
+  (0x1B50, Valid),
+  (0x1B7D, Disallowed),
+  (0x1B80, Valid),
+  (0x1BF4, Disallowed),
+  (0x1BFC, Valid),
+  (0x1C38, Disallowed),
+  (0x1C3B, Valid),
+  (0x1C4A, Disallowed),
+  (0x1C4D, Valid),
+  (0x1C80, Mapped(656, 2)),
+  (0x1C81, Mapped(660, 2)),
+  (0x1C82, Mapped(680, 2)),
+  (0x1C83, Mapped(686, 2)),
+  (0x1C84, Mapped(688, 2)),
+  (0x1C86, Mapped(704, 2)),
+  (0x1C87, Mapped(718, 2)),
+  (0x1C88, Mapped(1298, 3)),
+  (0x1C89, Disallowed),
+  (0x1C90, Mapped(1301, 3)),
+  (0x1C91, Mapped(1304, 3)),
+  (0x1C92, Mapped(1307, 3)),
+  (0x1C93, Mapped(1310, 3)),
+  (0x1C94, Mapped(1313, 3)),
+  (0x1C95, Mapped(1316, 3)),
+  (0x1C96, Mapped(1319, 3)),
+  (0x1C97, Mapped(1322, 3)),
+  (0x1C98, Mapped(1325, 3)),
+  (0x1C99, Mapped(1328, 3)),
+  (0x1C9A, Mapped(1331, 3)),
+  (0x1C9B, Mapped(1334, 3)),
+  (0x1C9C, Mapped(1277, 3)),
+  (0x1C9D, Mapped(1337, 3)),
+  (0x1C9E, Mapped(1340, 3)),
+  (0x1C9F, Mapped(1343, 3)),
+  (0x1CA0, Mapped(1346, 3)),
+  (0x1CA1, Mapped(1349, 3)),
+  (0x1CA2, Mapped(1352, 3)),
+  (0x1CA3, Mapped(1355, 3)),
+  (0x1CA4, Mapped(1358, 3)),
+  (0x1CA5, Mapped(1361, 3)),
+  (0x1CA6, Mapped(1364, 3)),
+  (0x1CA7, Mapped(1367, 3)),
+  (0x1CA8, Mapped(1370, 3)),
+  (0x1CA9, Mapped(1373, 3)),
+  (0x1CAA, Mapped(1376, 3)),
+  (0x1CAB, Mapped(1379, 3)),
+  (0x1CAC, Mapped(1382, 3)),
+  (0x1CAD, Mapped(1385, 3)),
+  (0x1CAE, Mapped(1388, 3)),
+  (0x1CAF, Mapped(1391, 3)),
+  (0x1CB0, Mapped(1394, 3)),
+  (0x1CB1, Mapped(1397, 3)),
+  (0x1CB2, Mapped(1400, 3)),
+  (0x1CB3, Mapped(1403, 3)),
+  (0x1CB4, Mapped(1406, 3)),
+  (0x1CB5, Mapped(1409, 3)),
+  (0x1CB6, Mapped(1412, 3)),
+  (0x1CB7, Mapped(1415, 3)),
+  (0x1CB8, Mapped(1418, 3)),
+  (0x1CB9, Mapped(1421, 3)),
+  (0x1CBA, Mapped(1424, 3)),
+  (0x1CBB, Disallowed),
+  (0x1CBD, Mapped(1427, 3)),
+  (0x1CBE, Mapped(1430, 3)),
+  (0x1CBF, Mapped(1433, 3)),
+  (0x1CC0, Valid),
+  (0x1CC8, Disallowed),
+  (0x1CD0, Valid),
+  (0x1CFB, Disallowed),
+  (0x1D00, Valid),
+  (0x1D2C, Mapped(0, 1)),
+  (0x1D2D, Mapped(71, 2)),
+  (0x1D2E, Mapped(1, 1)),
+  (0x1D2F, Valid),
+  (0x1D30, Mapped(3, 1)),
+  (0x1D31, Mapped(4, 1)),
+  (0x1D32, Mapped(268, 2)),
+  (0x1D33, Mapped(6, 1)),
+  (0x1D34, Mapped(7, 1)),
+  (0x1D35, Mapped(8, 1)),
+  (0x1D36, Mapped(9, 1)),
+  (0x1D37, Mapped(10, 1)),
+  (0x1D38, Mapped(11, 1)),
+  (0x1D39, Mapped(12, 1)),
+  (0x1D3A, Mapped(13, 1)),
+  (0x1D3B, Valid),
+  (0x1D3C, Mapped(14, 1)),
+  (0x1D3D, Mapped(415, 2)),
+  (0x1D3E, Mapped(15, 1)),
+  (0x1D3F, Mapped(17, 1)),
+  (0x1D40, Mapped(19, 1)),
+  (0x1D41, Mapped(20, 1)),
+  (0x1D42, Mapped(22, 1)),
+  (0x1D43, Mapped(0, 1)),
+  (0x1D44, Mapped(1436, 2)),
+  (0x1D45, Mapped(1438, 2)),
+  (0x1D46, Mapped(1440, 3)),
+  (0x1D47, Mapped(1, 1)),
+  (0x1D48, Mapped(3, 1)),
+  (0x1D49, Mapped(4, 1)),
+  (0x1D4A, Mapped(270, 2)),
+  (0x1D4B, Mapped(272, 2)),
+  (0x1D4C, Mapped(1443, 2)),
+  (0x1D4D, Mapped(6, 1)),
+  (0x1D4E, Valid),
+  (0x1D4F, Mapped(10, 1)),
+  (0x1D50, Mapped(12, 1)),
+  (0x1D51, Mapped(198, 2)),
+  (0x1D52, Mapped(14, 1)),
+  (0x1D53, Mapped(258, 2)),
+  (0x1D54, Mapped(1445, 3)),
+  (0x1D55, Mapped(1448, 3)),
+  (0x1D56, Mapped(15, 1)),
+  (0x1D57, Mapped(19, 1)),
+  (0x1D58, Mapped(20, 1)),
+  (0x1D59, Mapped(1451, 3)),
+  (0x1D5A, Mapped(286, 2)),
+  (0x1D5B, Mapped(21, 1)),
+  (0x1D5C, Mapped(1454, 3)),
+  (0x1D5D, Mapped(538, 2)),
+  (0x1D5E, Mapped(540, 2)),
+  (0x1D5F, Mapped(542, 2)),
+  (0x1D60, Mapped(572, 2)),
+  (0x1D61, Mapped(574, 2)),
+  (0x1D62, Mapped(8, 1)),
+  (0x1D63, Mapped(17, 1)),
+  (0x1D64, Mapped(20, 1)),
+  (0x1D65, Mapped(21, 1)),
+  (0x1D66, Mapped(538, 2)),
+  (0x1D67, Mapped(540, 2)),
+  (0x1D68, Mapped(564, 2)),
+  (0x1D69, Mapped(572, 2)),
+  (0x1D6A, Mapped(574, 2)),
+  (0x1D6B, Valid),
+  (0x1D78, Mapped(678, 2)),
+  (0x1D79, Valid),
+  (0x1D9B, Mapped(1457, 2)),
+  (0x1D9C, Mapped(2, 1)),
+  (0x1D9D, Mapped(1459, 2)),
+  (0x1D9E, Mapped(91, 2)),
+  (0x1D9F, Mapped(1443, 2)),
+  (0x1DA0, Mapped(5, 1)),
+  (0x1DA1, Mapped(1461, 2)),
+  (0x1DA2, Mapped(1463, 2)),
+  (0x1DA3, Mapped(1465, 2)),
+  (0x1DA4, Mapped(282, 2)),
+  (0x1DA5, Mapped(280, 2)),
+  (0x1DA6, Mapped(1467, 2)),
+  (0x1DA7, Mapped(1469, 3)),
+  (0x1DA8, Mapped(1472, 2)),
+  (0x1DA9, Mapped(1474, 2)),
+  (0x1DAA, Mapped(1476, 3)),
+  (0x1DAB, Mapped(1479, 2)),
+  (0x1DAC, Mapped(1481, 2)),
+  (0x1DAD, Mapped(1483, 2)),
+  (0x1DAE, Mapped(288, 2)),
+  (0x1DAF, Mapped(1485, 2)),
+  (0x1DB0, Mapped(1487, 2)),
+  (0x1DB1, Mapped(290, 2)),
+  (0x1DB2, Mapped(1489, 2)),
+  (0x1DB3, Mapped(1491, 2)),
+  (0x1DB4, Mapped(302, 2)),
+  (0x1DB5, Mapped(1493, 2)),
+  (0x1DB6, Mapped(447, 2)),
+  (0x1DB7, Mapped(310, 2)),
+  (0x1DB8, Mapped(1495, 3)),
+  (0x1DB9, Mapped(312, 2)),
+  (0x1DBA, Mapped(449, 2)),
+  (0x1DBB, Mapped(25, 1)),
+  (0x1DBC, Mapped(1498, 2)),
+  (0x1DBD, Mapped(1500, 2)),
+  (0x1DBE, Mapped(318, 2)),
+  (0x1DBF, Mapped(550, 2)),
+  (0x1DC0, Valid),
+  (0x1DFA, Disallowed),
+  (0x1DFB, Valid),
+  (0x1E00, Mapped(1502, 3)),
+  (0x1E01, Valid),
+  (0x1E02, Mapped(1505, 3)),
+  (0x1E03, Valid),
+  (0x1E04, Mapped(1508, 3)),
+  (0x1E05, Valid),
+  (0x1E06, Mapped(1511, 3)),
+  (0x1E07, Valid),
+  (0x1E08, Mapped(1514, 3)),
+  (0x1E09, Valid),
+  (0x1E0A, Mapped(1517, 3)),
+  (0x1E0B, Valid),
+  (0x1E0C, Mapped(1520, 3)),
+  (0x1E0D, Valid),
+  (0x1E0E, Mapped(1523, 3)),
+  (0x1E0F, Valid),
+  (0x1E10, Mapped(1526, 3)),
+  (0x1E11, Valid),
+  (0x1E12, Mapped(1529, 3)),
+  (0x1E13, Valid),
+  (0x1E14, Mapped(1532, 3)),
+  (0x1E15, Valid),
+  (0x1E16, Mapped(1535, 3)),
+  (0x1E17, Valid),
+  (0x1E18, Mapped(1538, 3)),
+  (0x1E19, Valid),
+  (0x1E1A, Mapped(1541, 3)),
+  (0x1E1B, Valid),
+  (0x1E1C, Mapped(1544, 3)),
+  (0x1E1D, Valid),
+  (0x1E1E, Mapped(1547, 3)),
+  (0x1E1F, Valid),
+  (0x1E20, Mapped(1550, 3)),
+  (0x1E21, Valid),
+  (0x1E22, Mapped(1553, 3)),
+  (0x1E23, Valid),
+  (0x1E24, Mapped(1556, 3)),
+  (0x1E25, Valid),
+  (0x1E26, Mapped(1559, 3)),
+  (0x1E27, Valid),
+  (0x1E28, Mapped(1562, 3)),
+  (0x1E29, Valid),
+  (0x1E2A, Mapped(1565, 3)),
+  (0x1E2B, Valid),
+  (0x1E2C, Mapped(1568, 3)),
+  (0x1E2D, Valid),
+  (0x1E2E, Mapped(1571, 3)),
+  (0x1E2F, Valid),
+  (0x1E30, Mapped(1574, 3)),
+  (0x1E31, Valid),
+  (0x1E32, Mapped(1577, 3)),
+  (0x1E33, Valid),
+  (0x1E34, Mapped(1580, 3)),
+  (0x1E35, Valid),
+  (0x1E36, Mapped(1583, 3)),
+  (0x1E37, Valid),
+  (0x1E38, Mapped(1586, 3)),
+  (0x1E39, Valid),
+  (0x1E3A, Mapped(1589, 3)),
+  (0x1E3B, Valid),
+  (0x1E3C, Mapped(1592, 3)),
+  (0x1E3D, Valid),
+  (0x1E3E, Mapped(1595, 3)),
+  (0x1E3F, Valid),
+  (0x1E40, Mapped(1598, 3)),
+  (0x1E41, Valid),
+  (0x1E42, Mapped(1601, 3)),
+  (0x1E43, Valid),
+  (0x1E44, Mapped(1604, 3)),
+  (0x1E45, Valid),
+  (0x1E46, Mapped(1607, 3)),
+  (0x1E47, Valid),
+  (0x1E48, Mapped(1610, 3)),
+  (0x1E49, Valid),
+  (0x1E4A, Mapped(1613, 3)),
+  (0x1E4B, Valid),
+  (0x1E4C, Mapped(1616, 3)),
+  (0x1E4D, Valid),
+  (0x1E4E, Mapped(1619, 3)),
+  (0x1E4F, Valid),
+  (0x1E50, Mapped(1622, 3)),
+  (0x1E51, Valid),
+  (0x1E52, Mapped(1625, 3)),
+  (0x1E53, Valid),
+  (0x1E54, Mapped(1628, 3)),
+  (0x1E55, Valid),
+  (0x1E56, Mapped(1631, 3)),
+  (0x1E57, Valid),
+  (0x1E58, Mapped(1634, 3)),
+  (0x1E59, Valid),
+  (0x1E5A, Mapped(1637, 3)),
+  (0x1E5B, Valid),
+  (0x1E5C, Mapped(1640, 3)),
+  (0x1E5D, Valid),
+  (0x1E5E, Mapped(1643, 3)),
+  (0x1E5F, Valid),
+  (0x1E60, Mapped(1646, 3)),
+  (0x1E61, Valid),
+  (0x1E62, Mapped(1649, 3)),
+  (0x1E63, Valid),
+  (0x1E64, Mapped(1652, 3)),
+  (0x1E65, Valid),
+  (0x1E66, Mapped(1655, 3)),
+  (0x1E67, Valid),
+  (0x1E68, Mapped(1658, 3)),
+  (0x1E69, Valid),
+  (0x1E6A, Mapped(1661, 3)),
+  (0x1E6B, Valid),
+  (0x1E6C, Mapped(1664, 3)),
+  (0x1E6D, Valid),
+  (0x1E6E, Mapped(1667, 3)),
+  (0x1E6F, Valid),
+  (0x1E70, Mapped(1670, 3)),
+  (0x1E71, Valid),
+  (0x1E72, Mapped(1673, 3)),
+  (0x1E73, Valid),
+  (0x1E74, Mapped(1676, 3)),
+  (0x1E75, Valid),
+  (0x1E76, Mapped(1679, 3)),
+  (0x1E77, Valid),
+  (0x1E78, Mapped(1682, 3)),
+  (0x1E79, Valid),
+  (0x1E7A, Mapped(1685, 3)),
+  (0x1E7B, Valid),
+  (0x1E7C, Mapped(1688, 3)),
+  (0x1E7D, Valid),
+  (0x1E7E, Mapped(1691, 3)),
+  (0x1E7F, Valid),
+  (0x1E80, Mapped(1694, 3)),
+  (0x1E81, Valid),
+  (0x1E82, Mapped(1697, 3)),
+  (0x1E83, Valid),
+  (0x1E84, Mapped(1700, 3)),
+  (0x1E85, Valid),
+  (0x1E86, Mapped(1703, 3)),
+  (0x1E87, Valid),
+  (0x1E88, Mapped(1706, 3)),
+  (0x1E89, Valid),
+  (0x1E8A, Mapped(1709, 3)),
+  (0x1E8B, Valid),
+  (0x1E8C, Mapped(1712, 3)),
+  (0x1E8D, Valid),
+  (0x1E8E, Mapped(1715, 3)),
+  (0x1E8F, Valid),
+  (0x1E90, Mapped(1718, 3)),
+  (0x1E91, Valid),
+  (0x1E92, Mapped(1721, 3)),
+  (0x1E93, Valid),
+  (0x1E94, Mapped(1724, 3)),
+  (0x1E95, Valid),
+  (0x1E9A, Mapped(1727, 3)),
+  (0x1E9B, Mapped(1646, 3)),
+  (0x1E9C, Valid),
+  (0x1E9E, Mapped(119, 2)),
+  (0x1E9F, Valid),
+  (0x1EA0, Mapped(1730, 3)),
+  (0x1EA1, Valid),
+  (0x1EA2, Mapped(1733, 3)),
+  (0x1EA3, Valid),
+  (0x1EA4, Mapped(1736, 3)),
+  (0x1EA5, Valid),
+  (0x1EA6, Mapped(1739, 3)),
+  (0x1EA7, Valid),
+  (0x1EA8, Mapped(1742, 3)),
+  (0x1EA9, Valid),
+  (0x1EAA, Mapped(1745, 3)),
+  (0x1EAB, Valid),
+  (0x1EAC, Mapped(1748, 3)),
+  (0x1EAD, Valid),
+  (0x1EAE, Mapped(1751, 3)),
+  (0x1EAF, Valid),
+  (0x1EB0, Mapped(1754, 3)),
+  (0x1EB1, Valid),
+  (0x1EB2, Mapped(1757, 3)),
+  (0x1EB3, Valid),
+  (0x1EB4, Mapped(1760, 3)),
+  (0x1EB5, Valid),
+  (0x1EB6, Mapped(1763, 3)),
+  (0x1EB7, Valid),
+  (0x1EB8, Mapped(1766, 3)),
+  (0x1EB9, Valid),
+  (0x1EBA, Mapped(1769, 3)),
+  (0x1EBB, Valid),
+  (0x1EBC, Mapped(1772, 3)),
+  (0x1EBD, Valid),
+  (0x1EBE, Mapped(1775, 3)),
+  (0x1EBF, Valid),
+  (0x1EC0, Mapped(1778, 3)),
+  (0x1EC1, Valid),
+  (0x1EC2, Mapped(1781, 3)),
+  (0x1EC3, Valid),
+  (0x1EC4, Mapped(1784, 3)),
+  (0x1EC5, Valid),
+  (0x1EC6, Mapped(1787, 3)),
+  (0x1EC7, Valid),
+  (0x1EC8, Mapped(1790, 3)),
+  (0x1EC9, Valid),
+  (0x1ECA, Mapped(1793, 3)),
+  (0x1ECB, Valid),
+  (0x1ECC, Mapped(1796, 3)),
+  (0x1ECD, Valid),
+  (0x1ECE, Mapped(1799, 3)),
+  (0x1ECF, Valid),
+  (0x1ED0, Mapped(1802, 3)),
+  (0x1ED1, Valid),
+  (0x1ED2, Mapped(1805, 3)),
+  (0x1ED3, Valid),
+  (0x1ED4, Mapped(1808, 3)),
+  (0x1ED5, Valid),
+  (0x1ED6, Mapped(1811, 3)),
+  (0x1ED7, Valid),
+  (0x1ED8, Mapped(1814, 3)),
+  (0x1ED9, Valid),
+  (0x1EDA, Mapped(1817, 3)),
+  (0x1EDB, Valid),
+  (0x1EDC, Mapped(1820, 3)),
+  (0x1EDD, Valid),
+  (0x1EDE, Mapped(1823, 3)),
+  (0x1EDF, Valid),
+  (0x1EE0, Mapped(1826, 3)),
+  (0x1EE1, Valid),
+  (0x1EE2, Mapped(1829, 3)),
+  (0x1EE3, Valid),
+  (0x1EE4, Mapped(1832, 3)),
+  (0x1EE5, Valid),
+  (0x1EE6, Mapped(1835, 3)),
+  (0x1EE7, Valid),
+  (0x1EE8, Mapped(1838, 3)),
+  (0x1EE9, Valid),
+  (0x1EEA, Mapped(1841, 3)),
+  (0x1EEB, Valid),
+  (0x1EEC, Mapped(1844, 3)),
+  (0x1EED, Valid),
+  (0x1EEE, Mapped(1847, 3)),
+  (0x1EEF, Valid),
+  (0x1EF0, Mapped(1850, 3)),
+  (0x1EF1, Valid),
+  (0x1EF2, Mapped(1853, 3)),
+  (0x1EF3, Valid),
+  (0x1EF4, Mapped(1856, 3)),
+  (0x1EF5, Valid),
+  (0x1EF6, Mapped(1859, 3)),
+  (0x1EF7, Valid),
+  (0x1EF8, Mapped(1862, 3)),
+  (0x1EF9, Valid),
+  (0x1EFA, Mapped(1865, 3)),
+  (0x1EFB, Valid),
+  (0x1EFC, Mapped(1868, 3)),
+  (0x1EFD, Valid),
+  (0x1EFE, Mapped(1871, 3)),
+  (0x1EFF, Valid),
+  (0x1F08, Mapped(1874, 3)),
+  (0x1F09, Mapped(1877, 3)),
+  (0x1F0A, Mapped(1880, 3)),
+  (0x1F0B, Mapped(1883, 3)),
+  (0x1F0C, Mapped(1886, 3)),
+  (0x1F0D, Mapped(1889, 3)),
+  (0x1F0E, Mapped(1892, 3)),
+  (0x1F0F, Mapped(1895, 3)),
+  (0x1F10, Valid),
+  (0x1F16, Disallowed),
+  (0x1F18, Mapped(1898, 3)),
+  (0x1F19, Mapped(1901, 3)),
+  (0x1F1A, Mapped(1904, 3)),
+  (0x1F1B, Mapped(1907, 3)),
+  (0x1F1C, Mapped(1910, 3)),
+  (0x1F1D, Mapped(1913, 3)),
+  (0x1F1E, Disallowed),
+  (0x1F20, Valid),
+  (0x1F28, Mapped(1916, 3)),
+  (0x1F29, Mapped(1919, 3)),
+  (0x1F2A, Mapped(1922, 3)),
+  (0x1F2B, Mapped(1925, 3)),
+  (0x1F2C, Mapped(1928, 3)),
+  (0x1F2D, Mapped(1931, 3)),
+  (0x1F2E, Mapped(1934, 3)),
+  (0x1F2F, Mapped(1937, 3)),
+  (0x1F30, Valid),
+  (0x1F38, Mapped(1940, 3)),
+  (0x1F39, Mapped(1943, 3)),
+  (0x1F3A, Mapped(1946, 3)),
+  (0x1F3B, Mapped(1949, 3)),
+  (0x1F3C, Mapped(1952, 3)),
+  (0x1F3D, Mapped(1955, 3)),
+  (0x1F3E, Mapped(1958, 3)),
+  (0x1F3F, Mapped(1961, 3)),
+  (0x1F40, Valid),
+  (0x1F46, Disallowed),
+  (0x1F48, Mapped(1964, 3)),
+  (0x1F49, Mapped(1967, 3)),
+  (0x1F4A, Mapped(1970, 3)),
+  (0x1F4B, Mapped(1973, 3)),
+  (0x1F4C, Mapped(1976, 3)),
+  (0x1F4D, Mapped(1979, 3)),
+  (0x1F4E, Disallowed),
+  (0x1F50, Valid),
+  (0x1F58, Disallowed),
+  (0x1F59, Mapped(1982, 3)),
+  (0x1F5A, Disallowed),
+  (0x1F5B, Mapped(1985, 3)),
+  (0x1F5C, Disallowed),
+  (0x1F5D, Mapped(1988, 3)),
+  (0x1F5E, Disallowed),
+  (0x1F5F, Mapped(1991, 3)),
+  (0x1F60, Valid),
+  (0x1F68, Mapped(1994, 3)),
+  (0x1F69, Mapped(1997, 3)),
+  (0x1F6A, Mapped(2000, 3)),
+  (0x1F6B, Mapped(2003, 3)),
+  (0x1F6C, Mapped(2006, 3)),
+  (0x1F6D, Mapped(2009, 3)),
+  (0x1F6E, Mapped(2012, 3)),
+  (0x1F6F, Mapped(2015, 3)),
+  (0x1F70, Valid),
+  (0x1F71, Mapped(520, 2)),
+  (0x1F72, Valid),
+  (0x1F73, Mapped(524, 2)),
+  (0x1F74, Valid),
+  (0x1F75, Mapped(526, 2)),
+  (0x1F76, Valid),
+  (0x1F77, Mapped(528, 2)),
+  (0x1F78, Valid),
+  (0x1F79, Mapped(530, 2)),
+  (0x1F7A, Valid),
+  (0x1F7B, Mapped(532, 2)),
+  (0x1F7C, Valid),
+  (0x1F7D, Mapped(534, 2)),
+  (0x1F7E, Disallowed),
+  (0x1F80, Mapped(2018, 5)),
+  (0x1F81, Mapped(2023, 5)),
+  (0x1F82, Mapped(2028, 5)),
+  (0x1F83, Mapped(2033, 5)),
+  (0x1F84, Mapped(2038, 5)),
+  (0x1F85, Mapped(2043, 5)),
+  (0x1F86, Mapped(2048, 5)),
+  (0x1F87, Mapped(2053, 5)),
+  (0x1F88, Mapped(2018, 5)),
+  (0x1F89, Mapped(2023, 5)),
+  (0x1F8A, Mapped(2028, 5)),
+  (0x1F8B, Mapped(2033, 5)),
+  (0x1F8C, Mapped(2038, 5)),
+  (0x1F8D, Mapped(2043, 5)),
+  (0x1F8E, Mapped(2048, 5)),
+  (0x1F8F, Mapped(2053, 5)),
+  (0x1F90, Mapped(2058, 5)),
+  (0x1F91, Mapped(2063, 5)),
+  (0x1F92, Mapped(2068, 5)),
+  (0x1F93, Mapped(2073, 5)),
+  (0x1F94, Mapped(2078, 5)),
+  (0x1F95, Mapped(2083, 5)),
+  (0x1F96, Mapped(2088, 5)),
+  (0x1F97, Mapped(2093, 5)),
+  (0x1F98, Mapped(2058, 5)),
+  (0x1F99, Mapped(2063, 5)),
+  (0x1F9A, Mapped(2068, 5)),
+  (0x1F9B, Mapped(2073, 5)),
+  (0x1F9C, Mapped(2078, 5)),
+  (0x1F9D, Mapped(2083, 5)),
+  (0x1F9E, Mapped(2088, 5)),
+  (0x1F9F, Mapped(2093, 5)),
+  (0x1FA0, Mapped(2098, 5)),
+  (0x1FA1, Mapped(2103, 5)),
+  (0x1FA2, Mapped(2108, 5)),
+  (0x1FA3, Mapped(2113, 5)),
+  (0x1FA4, Mapped(2118, 5)),
+  (0x1FA5, Mapped(2123, 5)),
+  (0x1FA6, Mapped(2128, 5)),
+  (0x1FA7, Mapped(2133, 5)),
+  (0x1FA8, Mapped(2098, 5)),
+  (0x1FA9, Mapped(2103, 5)),
+  (0x1FAA, Mapped(2108, 5)),
+  (0x1FAB, Mapped(2113, 5)),
+  (0x1FAC, Mapped(2118, 5)),
+  (0x1FAD, Mapped(2123, 5)),
+  (0x1FAE, Mapped(2128, 5)),
+  (0x1FAF, Mapped(2133, 5)),
+  (0x1FB0, Valid),
+  (0x1FB2, Mapped(2138, 5)),
+  (0x1FB3, Mapped(2143, 4)),
+  (0x1FB4, Mapped(2147, 4)),
+  (0x1FB5, Disallowed),
+  (0x1FB6, Valid),
+  (0x1FB7, Mapped(2151, 5)),
+  (0x1FB8, Mapped(2156, 3)),
+  (0x1FB9, Mapped(2159, 3)),
+  (0x1FBA, Mapped(2162, 3)),
+  (0x1FBB, Mapped(520, 2)),
+  (0x1FBC, Mapped(2143, 4)),
+  (0x1FBD, DisallowedStd3Mapped(2165, 3)),
+  (0x1FBE, Mapped(499, 2)),
+  (0x1FBF, DisallowedStd3Mapped(2165, 3)),
+  (0x1FC0, DisallowedStd3Mapped(2168, 3)),
+  (0x1FC1, DisallowedStd3Mapped(2171, 5)),
+  (0x1FC2, Mapped(2176, 5)),
+  (0x1FC3, Mapped(2181, 4)),
+  (0x1FC4, Mapped(2185, 4)),
+  (0x1FC5, Disallowed),
+  (0x1FC6, Valid),
+  (0x1FC7, Mapped(2189, 5)),
+  (0x1FC8, Mapped(2194, 3)),
+  (0x1FC9, Mapped(524, 2)),
+  (0x1FCA, Mapped(2197, 3)),
+  (0x1FCB, Mapped(526, 2)),
+  (0x1FCC, Mapped(2181, 4)),
+  (0x1FCD, DisallowedStd3Mapped(2200, 5)),
+  (0x1FCE, DisallowedStd3Mapped(2205, 5)),
+  (0x1FCF, DisallowedStd3Mapped(2210, 5)),
+  (0x1FD0, Valid),
+  (0x1FD3, Mapped(2215, 2)),
+  (0x1FD4, Disallowed),
+  (0x1FD6, Valid),
+  (0x1FD8, Mapped(2217, 3)),
+  (0x1FD9, Mapped(2220, 3)),
+  (0x1FDA, Mapped(2223, 3)),
+  (0x1FDB, Mapped(528, 2)),
+  (0x1FDC, Disallowed),
+  (0x1FDD, DisallowedStd3Mapped(2226, 5)),
+  (0x1FDE, DisallowedStd3Mapped(2231, 5)),
+  (0x1FDF, DisallowedStd3Mapped(2236, 5)),
+  (0x1FE0, Valid),
+  (0x1FE3, Mapped(2241, 2)),
+  (0x1FE4, Valid),
+  (0x1FE8, Mapped(2243, 3)),
+  (0x1FE9, Mapped(2246, 3)),
+  (0x1FEA, Mapped(2249, 3)),
+  (0x1FEB, Mapped(532, 2)),
+  (0x1FEC, Mapped(2252, 3)),
+  (0x1FED, DisallowedStd3Mapped(2255, 5)),
+  (0x1FEE, DisallowedStd3Mapped(515, 5)),
+  (0x1FEF, DisallowedStd3Mapped(2260, 1)),
+  (0x1FF0, Disallowed),
+  (0x1FF2, Mapped(2261, 5)),
+  (0x1FF3, Mapped(2266, 4)),
+  (0x1FF4, Mapped(2270, 4)),
+  (0x1FF5, Disallowed),
+  (0x1FF6, Valid),
+  (0x1FF7, Mapped(2274, 5)),
+  (0x1FF8, Mapped(2279, 3)),
+  (0x1FF9, Mapped(530, 2)),
+  (0x1FFA, Mapped(2282, 3)),
+  (0x1FFB, Mapped(534, 2)),
+  (0x1FFC, Mapped(2266, 4)),
+  (0x1FFD, DisallowedStd3Mapped(35, 3)),
+  (0x1FFE, DisallowedStd3Mapped(2285, 3)),
+  (0x1FFF, Disallowed),
+  (0x2000, DisallowedStd3Mapped(26, 1)),
+  (0x200B, Ignored),
+  (0x200C, Deviation(2288, 0)),
+  (0x200E, Disallowed),
+  (0x2010, Valid),
+  (0x2011, Mapped(2288, 3)),
+  (0x2012, Valid),
+  (0x2017, DisallowedStd3Mapped(2291, 3)),
+  (0x2018, Valid),
+  (0x2024, Disallowed),
+  (0x2027, Valid),
+  (0x2028, Disallowed),
+  (0x202F, DisallowedStd3Mapped(26, 1)),
+  (0x2030, Valid),
+  (0x2033, Mapped(2294, 6)),
+  (0x2034, Mapped(2300, 9)),
+  (0x2035, Valid),
+  (0x2036, Mapped(2309, 6)),
+  (0x2037, Mapped(2315, 9)),
+  (0x2038, Valid),
+  (0x203C, DisallowedStd3Mapped(2324, 2)),
+  (0x203D, Valid),
+  (0x203E, DisallowedStd3Mapped(2326, 3)),
+  (0x203F, Valid),
+  (0x2047, DisallowedStd3Mapped(2329, 2)),
+  (0x2048, DisallowedStd3Mapped(2331, 2)),
+  (0x2049, DisallowedStd3Mapped(2333, 2)),
+  (0x204A, Valid),
+  (0x2057, Mapped(2335, 12)),
+  (0x2058, Valid),
+  (0x205F, DisallowedStd3Mapped(26, 1)),
+  (0x2060, Ignored),
+  (0x2061, Disallowed),
+  (0x2064, Ignored),
+  (0x2065, Disallowed),
+  (0x2070, Mapped(2347, 1)),
+  (0x2071, Mapped(8, 1)),
+  (0x2072, Disallowed),
+  (0x2074, Mapped(2348, 1)),
+  (0x2075, Mapped(2349, 1)),
+  (0x2076, Mapped(2350, 1)),
+  (0x2077, Mapped(2351, 1)),
+  (0x2078, Mapped(2352, 1)),
+  (0x2079, Mapped(2353, 1)),
+  (0x207A, DisallowedStd3Mapped(2354, 1)),
+  (0x207B, Mapped(2355, 3)),
+  (0x207C, DisallowedStd3Mapped(2358, 1)),
+  (0x207D, DisallowedStd3Mapped(2359, 1)),
+  (0x207E, DisallowedStd3Mapped(2360, 1)),
+  (0x207F, Mapped(13, 1)),
+  (0x2080, Mapped(2347, 1)),
+  (0x2081, Mapped(43, 1)),
+  (0x2082, Mapped(33, 1)),
+  (0x2083, Mapped(34, 1)),
+  (0x2084, Mapped(2348, 1)),
+  (0x2085, Mapped(2349, 1)),
+  (0x2086, Mapped(2350, 1)),
+  (0x2087, Mapped(2351, 1)),
+  (0x2088, Mapped(2352, 1)),
+  (0x2089, Mapped(2353, 1)),
+  (0x208A, DisallowedStd3Mapped(2354, 1)),
+  (0x208B, Mapped(2355, 3)),
+  (0x208C, DisallowedStd3Mapped(2358, 1)),
+  (0x208D, DisallowedStd3Mapped(2359, 1)),
+  (0x208E, DisallowedStd3Mapped(2360, 1)),
+  (0x208F, Disallowed),
+  (0x2090, Mapped(0, 1)),
+  (0x2091, Mapped(4, 1)),
+  (0x2092, Mapped(14, 1)),
+  (0x2093, Mapped(23, 1)),
+  (0x2094, Mapped(270, 2)),
+  (0x2095, Mapped(7, 1)),
+  (0x2096, Mapped(10, 1)),
+  (0x2097, Mapped(11, 1)),
+  (0x2098, Mapped(12, 1)),
+  (0x2099, Mapped(13, 1)),
+  (0x209A, Mapped(15, 1)),
+  (0x209B, Mapped(18, 1)),
+  (0x209C, Mapped(19, 1)),
+  (0x209D, Disallowed),
+  (0x20A0, Valid),
+  (0x20A8, Mapped(2361, 2)),
+  (0x20A9, Valid),
+  (0x20C0, Disallowed),
+  (0x20D0, Valid),
+  (0x20F1, Disallowed),
+  (0x2100, DisallowedStd3Mapped(2363, 3)),
+  (0x2101, DisallowedStd3Mapped(2366, 3)),
+  (0x2102, Mapped(2, 1)),
+  (0x2103, Mapped(2369, 3)),
+  (0x2104, Valid),
+  (0x2105, DisallowedStd3Mapped(2372, 3)),
+  (0x2106, DisallowedStd3Mapped(2375, 3)),
+  (0x2107, Mapped(272, 2)),
+  (0x2108, Valid),
+  (0x2109, Mapped(2378, 3)),
+  (0x210A, Mapped(6, 1)),
+  (0x210B, Mapped(7, 1)),
+  (0x210F, Mapped(159, 2)),
+  (0x2110, Mapped(8, 1)),
+  (0x2112, Mapped(11, 1)),
+  (0x2114, Valid),
+  (0x2115, Mapped(13, 1)),
+  (0x2116, Mapped(2381, 2)),
+  (0x2117, Valid),
+  (0x2119, Mapped(15, 1)),
+  (0x211A, Mapped(16, 1)),
+  (0x211B, Mapped(17, 1)),
+  (0x211E, Valid),
+  (0x2120, Mapped(2383, 2)),
+  (0x2121, Mapped(2385, 3)),
+  (0x2122, Mapped(2388, 2)),
+  (0x2123, Valid),
+  (0x2124, Mapped(25, 1)),
+  (0x2125, Valid),
+  (0x2126, Mapped(578, 2)),
+  (0x2127, Valid),
+  (0x2128, Mapped(25, 1)),
+  (0x2129, Valid),
+  (0x212A, Mapped(10, 1)),
+  (0x212B, Mapped(69, 2)),
+  (0x212C, Mapped(1, 1)),
+  (0x212D, Mapped(2, 1)),
+  (0x212E, Valid),
+  (0x212F, Mapped(4, 1)),
+  (0x2131, Mapped(5, 1)),
+  (0x2132, Disallowed),
+  (0x2133, Mapped(12, 1)),
+  (0x2134, Mapped(14, 1)),
+  (0x2135, Mapped(2390, 2)),
+  (0x2136, Mapped(2392, 2)),
+  (0x2137, Mapped(2394, 2)),
+  (0x2138, Mapped(2396, 2)),
+  (0x2139, Mapped(8, 1)),
+  (0x213A, Valid),
+  (0x213B, Mapped(2398, 3)),
+  (0x213C, Mapped(562, 2)),
+  (0x213D, Mapped(540, 2)),
+  (0x213F, Mapped(562, 2)),
+  (0x2140, Mapped(2401, 3)),
+  (0x2141, Valid),
+  (0x2145, Mapped(3, 1)),
+  (0x2147, Mapped(4, 1)),
+  (0x2148, Mapped(8, 1)),
+  (0x2149, Mapped(9, 1)),
+  (0x214A, Valid),
+  (0x2150, Mapped(2404, 5)),
+  (0x2151, Mapped(2409, 5)),
+  (0x2152, Mapped(2414, 6)),
+  (0x2153, Mapped(2420, 5)),
+  (0x2154, Mapped(2425, 5)),
+  (0x2155, Mapped(2430, 5)),
+  (0x2156, Mapped(2435, 5)),
+  (0x2157, Mapped(2440, 5)),
+  (0x2158, Mapped(2445, 5)),
+  (0x2159, Mapped(2450, 5)),
+  (0x215A, Mapped(2455, 5)),
+  (0x215B, Mapped(2460, 5)),
+  (0x215C, Mapped(2465, 5)),
+  (0x215D, Mapped(2470, 5)),
+  (0x215E, Mapped(2475, 5)),
+  (0x215F, Mapped(2480, 4)),
+  (0x2160, Mapped(8, 1)),
+  (0x2161, Mapped(2484, 2)),
+  (0x2162, Mapped(2486, 3)),
+  (0x2163, Mapped(2489, 2)),
+  (0x2164, Mapped(21, 1)),
+  (0x2165, Mapped(2491, 2)),
+  (0x2166, Mapped(2493, 3)),
+  (0x2167, Mapped(2496, 4)),
+  (0x2168, Mapped(2500, 2)),
+  (0x2169, Mapped(23, 1)),
+  (0x216A, Mapped(2502, 2)),
+  (0x216B, Mapped(2504, 3)),
+  (0x216C, Mapped(11, 1)),
+  (0x216D, Mapped(2, 1)),
+  (0x216E, Mapped(3, 1)),
+  (0x216F, Mapped(12, 1)),
+  (0x2170, Mapped(8, 1)),
+  (0x2171, Mapped(2484, 2)),
+  (0x2172, Mapped(2486, 3)),
+  (0x2173, Mapped(2489, 2)),
+  (0x2174, Mapped(21, 1)),
+  (0x2175, Mapped(2491, 2)),
+  (0x2176, Mapped(2493, 3)),
+  (0x2177, Mapped(2496, 4)),
+  (0x2178, Mapped(2500, 2)),
+  (0x2179, Mapped(23, 1)),
+  (0x217A, Mapped(2502, 2)),
+  (0x217B, Mapped(2504, 3)),
+  (0x217C, Mapped(11, 1)),
+  (0x217D, Mapped(2, 1)),
+  (0x217E, Mapped(3, 1)),
+  (0x217F, Mapped(12, 1)),
+  (0x2180, Valid),
+  (0x2183, Disallowed),
+  (0x2184, Valid),
+  (0x2189, Mapped(2507, 5)),
+  (0x218A, Valid),
+  (0x218C, Disallowed),
+  (0x2190, Valid),
+  (0x222C, Mapped(2512, 6)),
+  (0x222D, Mapped(2518, 9)),
+  (0x222E, Valid),
+  (0x222F, Mapped(2527, 6)),
+  (0x2230, Mapped(2533, 9)),
+  (0x2231, Valid),
+  (0x2260, DisallowedStd3Valid),
+  (0x2261, Valid),
+  (0x226E, DisallowedStd3Valid),
+  (0x2270, Valid),
+  (0x2329, Mapped(2542, 3)),
+  (0x232A, Mapped(2545, 3)),
+  (0x232B, Valid),
+  (0x2427, Disallowed),
+  (0x2440, Valid),
+  (0x244B, Disallowed),
+  (0x2460, Mapped(43, 1)),
+  (0x2461, Mapped(33, 1)),
+  (0x2462, Mapped(34, 1)),
+  (0x2463, Mapped(2348, 1)),
+  (0x2464, Mapped(2349, 1)),
+  (0x2465, Mapped(2350, 1)),
+  (0x2466, Mapped(2351, 1)),
+  (0x2467, Mapped(2352, 1)),
+  (0x2468, Mapped(2353, 1)),
+  (0x2469, Mapped(2548, 2)),
+  (0x246A, Mapped(2550, 2)),
+  (0x246B, Mapped(2552, 2)),
+  (0x246C, Mapped(2554, 2)),
+  (0x246D, Mapped(2556, 2)),
+  (0x246E, Mapped(2558, 2)),
+  (0x246F, Mapped(2560, 2)),
+  (0x2470, Mapped(2562, 2)),
+  (0x2471, Mapped(2564, 2)),
+  (0x2472, Mapped(2566, 2)),
+  (0x2473, Mapped(2568, 2)),
+  (0x2474, DisallowedStd3Mapped(2570, 3)),
+  (0x2475, DisallowedStd3Mapped(2573, 3)),
+  (0x2476, DisallowedStd3Mapped(2576, 3)),
+  (0x2477, DisallowedStd3Mapped(2579, 3)),
+  (0x2478, DisallowedStd3Mapped(2582, 3)),
+  (0x2479, DisallowedStd3Mapped(2585, 3)),
+  (0x247A, DisallowedStd3Mapped(2588, 3)),
+  (0x247B, DisallowedStd3Mapped(2591, 3)),
+  (0x247C, DisallowedStd3Mapped(2594, 3)),
+  (0x247D, DisallowedStd3Mapped(2597, 4)),
+  (0x247E, DisallowedStd3Mapped(2601, 4)),
+  (0x247F, DisallowedStd3Mapped(2605, 4)),
+  (0x2480, DisallowedStd3Mapped(2609, 4)),
+  (0x2481, DisallowedStd3Mapped(2613, 4)),
+  (0x2482, DisallowedStd3Mapped(2617, 4)),
+  (0x2483, DisallowedStd3Mapped(2621, 4)),
+  (0x2484, DisallowedStd3Mapped(2625, 4)),
+  (0x2485, DisallowedStd3Mapped(2629, 4)),
+  (0x2486, DisallowedStd3Mapped(2633, 4)),
+  (0x2487, DisallowedStd3Mapped(2637, 4)),
+  (0x2488, Disallowed),
+  (0x249C, DisallowedStd3Mapped(2641, 3)),
+  (0x249D, DisallowedStd3Mapped(2644, 3)),
+  (0x249E, DisallowedStd3Mapped(2647, 3)),
+  (0x249F, DisallowedStd3Mapped(2650, 3)),
+  (0x24A0, DisallowedStd3Mapped(2653, 3)),
+  (0x24A1, DisallowedStd3Mapped(2656, 3)),
+  (0x24A2, DisallowedStd3Mapped(2659, 3)),
+  (0x24A3, DisallowedStd3Mapped(2662, 3)),
+  (0x24A4, DisallowedStd3Mapped(2665, 3)),
+  (0x24A5, DisallowedStd3Mapped(2668, 3)),
+  (0x24A6, DisallowedStd3Mapped(2671, 3)),
+  (0x24A7, DisallowedStd3Mapped(2674, 3)),
+  (0x24A8, DisallowedStd3Mapped(2677, 3)),
+  (0x24A9, DisallowedStd3Mapped(2680, 3)),
+  (0x24AA, DisallowedStd3Mapped(2683, 3)),
+  (0x24AB, DisallowedStd3Mapped(2686, 3)),
+  (0x24AC, DisallowedStd3Mapped(2689, 3)),
+  (0x24AD, DisallowedStd3Mapped(2692, 3)),
+  (0x24AE, DisallowedStd3Mapped(2695, 3)),
+  (0x24AF, DisallowedStd3Mapped(2698, 3)),
+  (0x24B0, DisallowedStd3Mapped(2701, 3)),
+  (0x24B1, DisallowedStd3Mapped(2704, 3)),
+  (0x24B2, DisallowedStd3Mapped(2707, 3)),
+  (0x24B3, DisallowedStd3Mapped(2710, 3)),
+  (0x24B4, DisallowedStd3Mapped(2713, 3)),
+  (0x24B5, DisallowedStd3Mapped(2716, 3)),
+  (0x24B6, Mapped(0, 1)),
+  (0x24B7, Mapped(1, 1)),
+  (0x24B8, Mapped(2, 1)),
+  (0x24B9, Mapped(3, 1)),
+  (0x24BA, Mapped(4, 1)),
+  (0x24BB, Mapped(5, 1)),
+  (0x24BC, Mapped(6, 1)),
+  (0x24BD, Mapped(7, 1)),
+  (0x24BE, Mapped(8, 1)),
+  (0x24BF, Mapped(9, 1)),
+  (0x24C0, Mapped(10, 1)),
+  (0x24C1, Mapped(11, 1)),
+  (0x24C2, Mapped(12, 1)),
+  (0x24C3, Mapped(13, 1)),
+  (0x24C4, Mapped(14, 1)),
+  (0x24C5, Mapped(15, 1)),
+  (0x24C6, Mapped(16, 1)),
+  (0x24C7, Mapped(17, 1)),
+  (0x24C8, Mapped(18, 1)),
+  (0x24C9, Mapped(19, 1)),
+  (0x24CA, Mapped(20, 1)),
+  (0x24CB, Mapped(21, 1)),
+  (0x24CC, Mapped(22, 1)),
+  (0x24CD, Mapped(23, 1)),
+  (0x24CE, Mapped(24, 1)),
+  (0x24CF, Mapped(25, 1)),
+  (0x24D0, Mapped(0, 1)),
+  (0x24D1, Mapped(1, 1)),
+  (0x24D2, Mapped(2, 1)),
+  (0x24D3, Mapped(3, 1)),
+  (0x24D4, Mapped(4, 1)),
+  (0x24D5, Mapped(5, 1)),
+  (0x24D6, Mapped(6, 1)),
+  (0x24D7, Mapped(7, 1)),
+  (0x24D8, Mapped(8, 1)),
+  (0x24D9, Mapped(9, 1)),
+  (0x24DA, Mapped(10, 1)),
+  (0x24DB, Mapped(11, 1)),
+  (0x24DC, Mapped(12, 1)),
+  (0x24DD, Mapped(13, 1)),
+  (0x24DE, Mapped(14, 1)),
+  (0x24DF, Mapped(15, 1)),
+  (0x24E0, Mapped(16, 1)),
+  (0x24E1, Mapped(17, 1)),
+  (0x24E2, Mapped(18, 1)),
+  (0x24E3, Mapped(19, 1)),
+  (0x24E4, Mapped(20, 1)),
+  (0x24E5, Mapped(21, 1)),
+  (0x24E6, Mapped(22, 1)),
+  (0x24E7, Mapped(23, 1)),
+  (0x24E8, Mapped(24, 1)),
+  (0x24E9, Mapped(25, 1)),
+  (0x24EA, Mapped(2347, 1)),
+  (0x24EB, Valid),
+  (0x2A0C, Mapped(2719, 12)),
+  (0x2A0D, Valid),
+  (0x2A74, DisallowedStd3Mapped(2731, 3)),
+  (0x2A75, DisallowedStd3Mapped(2734, 2)),
+  (0x2A76, DisallowedStd3Mapped(2736, 3)),
+  (0x2A77, Valid),
+  (0x2ADC, Mapped(2739, 5)),
+  (0x2ADD, Valid),
+  (0x2B74, Disallowed),
+  (0x2B76, Valid),
+  (0x2B96, Disallowed),
+  (0x2B97, Valid),
+  (0x2C00, Mapped(2744, 3)),
+  (0x2C01, Mapped(2747, 3)),
+  (0x2C02, Mapped(2750, 3)),
+  (0x2C03, Mapped(2753, 3)),
+  (0x2C04, Mapped(2756, 3)),
+  (0x2C05, Mapped(2759, 3)),
+  (0x2C06, Mapped(2762, 3)),
+  (0x2C07, Mapped(2765, 3)),
+  (0x2C08, Mapped(2768, 3)),
+  (0x2C09, Mapped(2771, 3)),
+  (0x2C0A, Mapped(2774, 3)),
+  (0x2C0B, Mapped(2777, 3)),
+  (0x2C0C, Mapped(2780, 3)),
+  (0x2C0D, Mapped(2783, 3)),
+  (0x2C0E, Mapped(2786, 3)),
+  (0x2C0F, Mapped(2789, 3)),
+  (0x2C10, Mapped(2792, 3)),
+  (0x2C11, Mapped(2795, 3)),
+  (0x2C12, Mapped(2798, 3)),
+  (0x2C13, Mapped(2801, 3)),
+  (0x2C14, Mapped(2804, 3)),
+  (0x2C15, Mapped(2807, 3)),
+  (0x2C16, Mapped(2810, 3)),
+  (0x2C17, Mapped(2813, 3)),
+  (0x2C18, Mapped(2816, 3)),
+  (0x2C19, Mapped(2819, 3)),
+  (0x2C1A, Mapped(2822, 3)),
+  (0x2C1B, Mapped(2825, 3)),
+  (0x2C1C, Mapped(2828, 3)),
+  (0x2C1D, Mapped(2831, 3)),
+  (0x2C1E, Mapped(2834, 3)),
+  (0x2C1F, Mapped(2837, 3)),
+  (0x2C20, Mapped(2840, 3)),
+  (0x2C21, Mapped(2843, 3)),
+  (0x2C22, Mapped(2846, 3)),
+  (0x2C23, Mapped(2849, 3)),
+  (0x2C24, Mapped(2852, 3)),
+  (0x2C25, Mapped(2855, 3)),
+  (0x2C26, Mapped(2858, 3)),
+  (0x2C27, Mapped(2861, 3)),
+  (0x2C28, Mapped(2864, 3)),
+  (0x2C29, Mapped(2867, 3)),
+  (0x2C2A, Mapped(2870, 3)),
+  (0x2C2B, Mapped(2873, 3)),
+  (0x2C2C, Mapped(2876, 3)),
+  (0x2C2D, Mapped(2879, 3)),
+  (0x2C2E, Mapped(2882, 3)),
+  (0x2C2F, Disallowed),
+  (0x2C30, Valid),
+  (0x2C5F, Disallowed),
+  (0x2C60, Mapped(2885, 3)),
+  (0x2C61, Valid),
+  (0x2C62, Mapped(2888, 2)),
+  (0x2C63, Mapped(2890, 3)),
+  (0x2C64, Mapped(2893, 2)),
+  (0x2C65, Valid),
+  (0x2C67, Mapped(2895, 3)),
+  (0x2C68, Valid),
+  (0x2C69, Mapped(2898, 3)),
+  (0x2C6A, Valid),
+  (0x2C6B, Mapped(2901, 3)),
+  (0x2C6C, Valid),
+  (0x2C6D, Mapped(1438, 2)),
+  (0x2C6E, Mapped(1481, 2)),
+  (0x2C6F, Mapped(1436, 2)),
+  (0x2C70, Mapped(1457, 2)),
+  (0x2C71, Valid),
+  (0x2C72, Mapped(2904, 3)),
+  (0x2C73, Valid),
+  (0x2C75, Mapped(2907, 3)),
+  (0x2C76, Valid),
+  (0x2C7C, Mapped(9, 1)),
+  (0x2C7D, Mapped(21, 1)),
+  (0x2C7E, Mapped(2910, 2)),
+  (0x2C7F, Mapped(2912, 2)),
+  (0x2C80, Mapped(2914, 3)),
+  (0x2C81, Valid),
+  (0x2C82, Mapped(2917, 3)),
+  (0x2C83, Valid),
+  (0x2C84, Mapped(2920, 3)),
+  (0x2C85, Valid),
+  (0x2C86, Mapped(2923, 3)),
+  (0x2C87, Valid),
+  (0x2C88, Mapped(2926, 3)),
+  (0x2C89, Valid),
+  (0x2C8A, Mapped(2929, 3)),
+  (0x2C8B, Valid),
+  (0x2C8C, Mapped(2932, 3)),
+  (0x2C8D, Valid),
+  (0x2C8E, Mapped(2935, 3)),
+  (0x2C8F, Valid),
+  (0x2C90, Mapped(2938, 3)),
+  (0x2C91, Valid),
+  (0x2C92, Mapped(2941, 3)),
+  (0x2C93, Valid),
+  (0x2C94, Mapped(2944, 3)),
+  (0x2C95, Valid),
+  (0x2C96, Mapped(2947, 3)),
+  (0x2C97, Valid),
+  (0x2C98, Mapped(2950, 3)),
+  (0x2C99, Valid),
+  (0x2C9A, Mapped(2953, 3)),
+  (0x2C9B, Valid),
+  (0x2C9C, Mapped(2956, 3)),
+  (0x2C9D, Valid),
+  (0x2C9E, Mapped(2959, 3)),
+  (0x2C9F, Valid),
+  (0x2CA0, Mapped(2962, 3)),
+  (0x2CA1, Valid),
+  (0x2CA2, Mapped(2965, 3)),
+  (0x2CA3, Valid),
+  (0x2CA4, Mapped(2968, 3)),
+  (0x2CA5, Valid),
+  (0x2CA6, Mapped(2971, 3)),
+  (0x2CA7, Valid),
+  (0x2CA8, Mapped(2974, 3)),
+  (0x2CA9, Valid),
+  (0x2CAA, Mapped(2977, 3)),
+  (0x2CAB, Valid),
+  (0x2CAC, Mapped(2980, 3)),
+  (0x2CAD, Valid),
+  (0x2CAE, Mapped(2983, 3)),
+  (0x2CAF, Valid),
+  (0x2CB0, Mapped(2986, 3)),
+  (0x2CB1, Valid),
+  (0x2CB2, Mapped(2989, 3)),
+  (0x2CB3, Valid),
+  (0x2CB4, Mapped(2992, 3)),
+  (0x2CB5, Valid),
+  (0x2CB6, Mapped(2995, 3)),
+  (0x2CB7, Valid),
+  (0x2CB8, Mapped(2998, 3)),
+  (0x2CB9, Valid),
+  (0x2CBA, Mapped(3001, 3)),
+  (0x2CBB, Valid),
+  (0x2CBC, Mapped(3004, 3)),
+  (0x2CBD, Valid),
+  (0x2CBE, Mapped(3007, 3)),
+  (0x2CBF, Valid),
+  (0x2CC0, Mapped(3010, 3)),
+  (0x2CC1, Valid),
+  (0x2CC2, Mapped(3013, 3)),
+  (0x2CC3, Valid),
+  (0x2CC4, Mapped(3016, 3)),
+  (0x2CC5, Valid),
+  (0x2CC6, Mapped(3019, 3)),
+  (0x2CC7, Valid),
+  (0x2CC8, Mapped(3022, 3)),
+  (0x2CC9, Valid),
+  (0x2CCA, Mapped(3025, 3)),
+  (0x2CCB, Valid),
+  (0x2CCC, Mapped(3028, 3)),
+  (0x2CCD, Valid),
+  (0x2CCE, Mapped(3031, 3)),
+  (0x2CCF, Valid),
+  (0x2CD0, Mapped(3034, 3)),
+  (0x2CD1, Valid),
+  (0x2CD2, Mapped(3037, 3)),
+  (0x2CD3, Valid),
+  (0x2CD4, Mapped(3040, 3)),
+  (0x2CD5, Valid),
+  (0x2CD6, Mapped(3043, 3)),
+  (0x2CD7, Valid),
+  (0x2CD8, Mapped(3046, 3)),
+  (0x2CD9, Valid),
+  (0x2CDA, Mapped(3049, 3)),
+  (0x2CDB, Valid),
+  (0x2CDC, Mapped(3052, 3)),
+  (0x2CDD, Valid),
+  (0x2CDE, Mapped(3055, 3)),
+  (0x2CDF, Valid),
+  (0x2CE0, Mapped(3058, 3)),
+  (0x2CE1, Valid),
+  (0x2CE2, Mapped(3061, 3)),
+  (0x2CE3, Valid),
+  (0x2CEB, Mapped(3064, 3)),
+  (0x2CEC, Valid),
+  (0x2CED, Mapped(3067, 3)),
+  (0x2CEE, Valid),
+  (0x2CF2, Mapped(3070, 3)),
+  (0x2CF3, Valid),
+  (0x2CF4, Disallowed),
+  (0x2CF9, Valid),
+  (0x2D26, Disallowed),
+  (0x2D27, Valid),
+  (0x2D28, Disallowed),
+  (0x2D2D, Valid),
+  (0x2D2E, Disallowed),
+  (0x2D30, Valid),
+  (0x2D68, Disallowed),
+  (0x2D6F, Mapped(3073, 3)),
+  (0x2D70, Valid),
+  (0x2D71, Disallowed),
+  (0x2D7F, Valid),
+  (0x2D97, Disallowed),
+  (0x2DA0, Valid),
+  (0x2DA7, Disallowed),
+  (0x2DA8, Valid),
+  (0x2DAF, Disallowed),
+  (0x2DB0, Valid),
+  (0x2DB7, Disallowed),
+  (0x2DB8, Valid),
+  (0x2DBF, Disallowed),
+  (0x2DC0, Valid),
+  (0x2DC7, Disallowed),
+  (0x2DC8, Valid),
+  (0x2DCF, Disallowed),
+  (0x2DD0, Valid),
+  (0x2DD7, Disallowed),
+  (0x2DD8, Valid),
+  (0x2DDF, Disallowed),
+  (0x2DE0, Valid),
+  (0x2E53, Disallowed),
+  (0x2E80, Valid),
+  (0x2E9A, Disallowed),
+  (0x2E9B, Valid),
+  (0x2E9F, Mapped(3076, 3)),
+  (0x2EA0, Valid),
+  (0x2EF3, Mapped(3079, 3)),
+  (0x2EF4, Disallowed),
+  (0x2F00, Mapped(3082, 3)),
+  (0x2F01, Mapped(3085, 3)),
+  (0x2F02, Mapped(3088, 3)),
+  (0x2F03, Mapped(3091, 3)),
+  (0x2F04, Mapped(3094, 3)),
+  (0x2F05, Mapped(3097, 3)),
+  (0x2F06, Mapped(3100, 3)),
+  (0x2F07, Mapped(3103, 3)),
+  (0x2F08, Mapped(3106, 3)),
+  (0x2F09, Mapped(3109, 3)),
+  (0x2F0A, Mapped(3112, 3)),
+  (0x2F0B, Mapped(3115, 3)),
+  (0x2F0C, Mapped(3118, 3)),
+  (0x2F0D, Mapped(3121, 3)),
+  (0x2F0E, Mapped(3124, 3)),
+  (0x2F0F, Mapped(3127, 3)),
+  (0x2F10, Mapped(3130, 3)),
+  (0x2F11, Mapped(3133, 3)),
+  (0x2F12, Mapped(3136, 3)),
+  (0x2F13, Mapped(3139, 3)),
+  (0x2F14, Mapped(3142, 3)),
+  (0x2F15, Mapped(3145, 3)),
+  (0x2F16, Mapped(3148, 3)),
+  (0x2F17, Mapped(3151, 3)),
+  (0x2F18, Mapped(3154, 3)),
+  (0x2F19, Mapped(3157, 3)),
+  (0x2F1A, Mapped(3160, 3)),
+  (0x2F1B, Mapped(3163, 3)),
+  (0x2F1C, Mapped(3166, 3)),
+  (0x2F1D, Mapped(3169, 3)),
+  (0x2F1E, Mapped(3172, 3)),
+  (0x2F1F, Mapped(3175, 3)),
+  (0x2F20, Mapped(3178, 3)),
+  (0x2F21, Mapped(3181, 3)),
+  (0x2F22, Mapped(3184, 3)),
+  (0x2F23, Mapped(3187, 3)),
+  (0x2F24, Mapped(3190, 3)),
+  (0x2F25, Mapped(3193, 3)),
+  (0x2F26, Mapped(3196, 3)),
+  (0x2F27, Mapped(3199, 3)),
+  (0x2F28, Mapped(3202, 3)),
+  (0x2F29, Mapped(3205, 3)),
+  (0x2F2A, Mapped(3208, 3)),
+  (0x2F2B, Mapped(3211, 3)),
+  (0x2F2C, Mapped(3214, 3)),
+  (0x2F2D, Mapped(3217, 3)),
+  (0x2F2E, Mapped(3220, 3)),
+  (0x2F2F, Mapped(3223, 3)),
+  (0x2F30, Mapped(3226, 3)),
+  (0x2F31, Mapped(3229, 3)),
+  (0x2F32, Mapped(3232, 3)),
+  (0x2F33, Mapped(3235, 3)),
+  (0x2F34, Mapped(3238, 3)),
+  (0x2F35, Mapped(3241, 3)),
+  (0x2F36, Mapped(3244, 3)),
+  (0x2F37, Mapped(3247, 3)),
+  (0x2F38, Mapped(3250, 3)),
+  (0x2F39, Mapped(3253, 3)),
+  (0x2F3A, Mapped(3256, 3)),
+  (0x2F3B, Mapped(3259, 3)),
+  (0x2F3C, Mapped(3262, 3)),
+  (0x2F3D, Mapped(3265, 3)),
+  (0x2F3E, Mapped(3268, 3)),
+  (0x2F3F, Mapped(3271, 3)),
+  (0x2F40, Mapped(3274, 3)),
+  (0x2F41, Mapped(3277, 3)),
+  (0x2F42, Mapped(3280, 3)),
+  (0x2F43, Mapped(3283, 3)),
+  (0x2F44, Mapped(3286, 3)),
+  (0x2F45, Mapped(3289, 3)),
+  (0x2F46, Mapped(3292, 3)),
+  (0x2F47, Mapped(3295, 3)),
+  (0x2F48, Mapped(3298, 3)),
+  (0x2F49, Mapped(3301, 3)),
+  (0x2F4A, Mapped(3304, 3)),
+  (0x2F4B, Mapped(3307, 3)),
+  (0x2F4C, Mapped(3310, 3)),
+  (0x2F4D, Mapped(3313, 3)),
+  (0x2F4E, Mapped(3316, 3)),
+  (0x2F4F, Mapped(3319, 3)),
+  (0x2F50, Mapped(3322, 3)),
+  (0x2F51, Mapped(3325, 3)),
+  (0x2F52, Mapped(3328, 3)),
+  (0x2F53, Mapped(3331, 3)),
+  (0x2F54, Mapped(3334, 3)),
+  (0x2F55, Mapped(3337, 3)),
+  (0x2F56, Mapped(3340, 3)),
+  (0x2F57, Mapped(3343, 3)),
+  (0x2F58, Mapped(3346, 3)),
+  (0x2F59, Mapped(3349, 3)),
+  (0x2F5A, Mapped(3352, 3)),
+  (0x2F5B, Mapped(3355, 3)),
+  (0x2F5C, Mapped(3358, 3)),
+  (0x2F5D, Mapped(3361, 3)),
+  (0x2F5E, Mapped(3364, 3)),
+  (0x2F5F, Mapped(3367, 3)),
+  (0x2F60, Mapped(3370, 3)),
+  (0x2F61, Mapped(3373, 3)),
+  (0x2F62, Mapped(3376, 3)),
+  (0x2F63, Mapped(3379, 3)),
+  (0x2F64, Mapped(3382, 3)),
+  (0x2F65, Mapped(3385, 3)),
+  (0x2F66, Mapped(3388, 3)),
+  (0x2F67, Mapped(3391, 3)),
+  (0x2F68, Mapped(3394, 3)),
+  (0x2F69, Mapped(3397, 3)),
+  (0x2F6A, Mapped(3400, 3)),
+  (0x2F6B, Mapped(3403, 3)),
+  (0x2F6C, Mapped(3406, 3)),
+  (0x2F6D, Mapped(3409, 3)),
+  (0x2F6E, Mapped(3412, 3)),
+  (0x2F6F, Mapped(3415, 3)),
+  (0x2F70, Mapped(3418, 3)),
+  (0x2F71, Mapped(3421, 3)),
+  (0x2F72, Mapped(3424, 3)),
+  (0x2F73, Mapped(3427, 3)),
+  (0x2F74, Mapped(3430, 3)),
+  (0x2F75, Mapped(3433, 3)),
+  (0x2F76, Mapped(3436, 3)),
+  (0x2F77, Mapped(3439, 3)),
+  (0x2F78, Mapped(3442, 3)),
+  (0x2F79, Mapped(3445, 3)),
+  (0x2F7A, Mapped(3448, 3)),
+  (0x2F7B, Mapped(3451, 3)),
+  (0x2F7C, Mapped(3454, 3)),
+  (0x2F7D, Mapped(3457, 3)),
+  (0x2F7E, Mapped(3460, 3)),
+  (0x2F7F, Mapped(3463, 3)),
+  (0x2F80, Mapped(3466, 3)),
+  (0x2F81, Mapped(3469, 3)),
+  (0x2F82, Mapped(3472, 3)),
+  (0x2F83, Mapped(3475, 3)),
+  (0x2F84, Mapped(3478, 3)),
+  (0x2F85, Mapped(3481, 3)),
+  (0x2F86, Mapped(3484, 3)),
+  (0x2F87, Mapped(3487, 3)),
+  (0x2F88, Mapped(3490, 3)),
+  (0x2F89, Mapped(3493, 3)),
+  (0x2F8A, Mapped(3496, 3)),
+  (0x2F8B, Mapped(3499, 3)),
+  (0x2F8C, Mapped(3502, 3)),
+  (0x2F8D, Mapped(3505, 3)),
+  (0x2F8E, Mapped(3508, 3)),
+  (0x2F8F, Mapped(3511, 3)),
+  (0x2F90, Mapped(3514, 3)),
+  (0x2F91, Mapped(3517, 3)),
+  (0x2F92, Mapped(3520, 3)),
+  (0x2F93, Mapped(3523, 3)),
+  (0x2F94, Mapped(3526, 3)),
+  (0x2F95, Mapped(3529, 3)),
+  (0x2F96, Mapped(3532, 3)),
+  (0x2F97, Mapped(3535, 3)),
+  (0x2F98, Mapped(3538, 3)),
+  (0x2F99, Mapped(3541, 3)),
+  (0x2F9A, Mapped(3544, 3)),
+  (0x2F9B, Mapped(3547, 3)),
+  (0x2F9C, Mapped(3550, 3)),
+  (0x2F9D, Mapped(3553, 3)),
+  (0x2F9E, Mapped(3556, 3)),
+  (0x2F9F, Mapped(3559, 3)),
+  (0x2FA0, Mapped(3562, 3)),
+  (0x2FA1, Mapped(3565, 3)),
+  (0x2FA2, Mapped(3568, 3)),
+  (0x2FA3, Mapped(3571, 3)),
+  (0x2FA4, Mapped(3574, 3)),
+  (0x2FA5, Mapped(3577, 3)),
+  (0x2FA6, Mapped(3580, 3)),
+  (0x2FA7, Mapped(3583, 3)),
+  (0x2FA8, Mapped(3586, 3)),
+  (0x2FA9, Mapped(3589, 3)),
+  (0x2FAA, Mapped(3592, 3)),
+  (0x2FAB, Mapped(3595, 3)),
+  (0x2FAC, Mapped(3598, 3)),
+  (0x2FAD, Mapped(3601, 3)),
+  (0x2FAE, Mapped(3604, 3)),
+  (0x2FAF, Mapped(3607, 3)),
+  (0x2FB0, Mapped(3610, 3)),
+  (0x2FB1, Mapped(3613, 3)),
+  (0x2FB2, Mapped(3616, 3)),
+  (0x2FB3, Mapped(3619, 3)),
+  (0x2FB4, Mapped(3622, 3)),
+  (0x2FB5, Mapped(3625, 3)),
+  (0x2FB6, Mapped(3628, 3)),
+  (0x2FB7, Mapped(3631, 3)),
+  (0x2FB8, Mapped(3634, 3)),
+  (0x2FB9, Mapped(3637, 3)),
+  (0x2FBA, Mapped(3640, 3)),
+  (0x2FBB, Mapped(3643, 3)),
+  (0x2FBC, Mapped(3646, 3)),
+  (0x2FBD, Mapped(3649, 3)),
+  (0x2FBE, Mapped(3652, 3)),
+  (0x2FBF, Mapped(3655, 3)),
+  (0x2FC0, Mapped(3658, 3)),
+  (0x2FC1, Mapped(3661, 3)),
+  (0x2FC2, Mapped(3664, 3)),
+  (0x2FC3, Mapped(3667, 3)),
+  (0x2FC4, Mapped(3670, 3)),
+  (0x2FC5, Mapped(3673, 3)),
+  (0x2FC6, Mapped(3676, 3)),
+  (0x2FC7, Mapped(3679, 3)),
+  (0x2FC8, Mapped(3682, 3)),
+  (0x2FC9, Mapped(3685, 3)),
+  (0x2FCA, Mapped(3688, 3)),
+  (0x2FCB, Mapped(3691, 3)),
+  (0x2FCC, Mapped(3694, 3)),
+  (0x2FCD, Mapped(3697, 3)),
+  (0x2FCE, Mapped(3700, 3)),
+  (0x2FCF, Mapped(3703, 3)),
+  (0x2FD0, Mapped(3706, 3)),
+  (0x2FD1, Mapped(3709, 3)),
+  (0x2FD2, Mapped(3712, 3)),
+  (0x2FD3, Mapped(3715, 3)),
+  (0x2FD4, Mapped(3718, 3)),
+  (0x2FD5, Mapped(3721, 3)),
+  (0x2FD6, Disallowed),
+  (0x3000, DisallowedStd3Mapped(26, 1)),
+  (0x3001, Valid),
+  (0x3002, Mapped(3724, 1)),
+  (0x3003, Valid),
+  (0x3036, Mapped(3725, 3)),
+  (0x3037, Valid),
+  (0x3038, Mapped(3151, 3)),
+  (0x3039, Mapped(3728, 3)),
+  (0x303A, Mapped(3731, 3)),
+  (0x303B, Valid),
+  (0x3040, Disallowed),
+  (0x3041, Valid),
+  (0x3097, Disallowed),
+  (0x3099, Valid),
+  (0x309B, DisallowedStd3Mapped(3734, 4)),
+  (0x309C, DisallowedStd3Mapped(3738, 4)),
+  (0x309D, Valid),
+  (0x309F, Mapped(3742, 6)),
+  (0x30A0, Valid),
+  (0x30FF, Mapped(3748, 6)),
+  (0x3100, Disallowed),
+  (0x3105, Valid),
+  (0x3130, Disallowed),
+  (0x3131, Mapped(3754, 3)),
+  (0x3132, Mapped(3757, 3)),
+  (0x3133, Mapped(3760, 3)),
+  (0x3134, Mapped(3763, 3)),
+  (0x3135, Mapped(3766, 3)),
+  (0x3136, Mapped(3769, 3)),
+  (0x3137, Mapped(3772, 3)),
+  (0x3138, Mapped(3775, 3)),
+  (0x3139, Mapped(3778, 3)),
+  (0x313A, Mapped(3781, 3)),
+  (0x313B, Mapped(3784, 3)),
+  (0x313C, Mapped(3787, 3)),
+  (0x313D, Mapped(3790, 3)),
+  (0x313E, Mapped(3793, 3)),
+  (0x313F, Mapped(3796, 3)),
+  (0x3140, Mapped(3799, 3)),
+  (0x3141, Mapped(3802, 3)),
+  (0x3142, Mapped(3805, 3)),
+  (0x3143, Mapped(3808, 3)),
+  (0x3144, Mapped(3811, 3)),
+  (0x3145, Mapped(3814, 3)),
+  (0x3146, Mapped(3817, 3)),
+  (0x3147, Mapped(3820, 3)),
+  (0x3148, Mapped(3823, 3)),
+  (0x3149, Mapped(3826, 3)),
+  (0x314A, Mapped(3829, 3)),
+  (0x314B, Mapped(3832, 3)),
+  (0x314C, Mapped(3835, 3)),
+  (0x314D, Mapped(3838, 3)),
+  (0x314E, Mapped(3841, 3)),
+  (0x314F, Mapped(3844, 3)),
+  (0x3150, Mapped(3847, 3)),
+  (0x3151, Mapped(3850, 3)),
+  (0x3152, Mapped(3853, 3)),
+  (0x3153, Mapped(3856, 3)),
+  (0x3154, Mapped(3859, 3)),
+  (0x3155, Mapped(3862, 3)),
+  (0x3156, Mapped(3865, 3)),
+  (0x3157, Mapped(3868, 3)),
+  (0x3158, Mapped(3871, 3)),
+  (0x3159, Mapped(3874, 3)),
+  (0x315A, Mapped(3877, 3)),
+  (0x315B, Mapped(3880, 3)),
+  (0x315C, Mapped(3883, 3)),
+  (0x315D, Mapped(3886, 3)),
+  (0x315E, Mapped(3889, 3)),
+  (0x315F, Mapped(3892, 3)),
+  (0x3160, Mapped(3895, 3)),
+  (0x3161, Mapped(3898, 3)),
+  (0x3162, Mapped(3901, 3)),
+  (0x3163, Mapped(3904, 3)),
+  (0x3164, Disallowed),
+  (0x3165, Mapped(3907, 3)),
+  (0x3166, Mapped(3910, 3)),
+  (0x3167, Mapped(3913, 3)),
+  (0x3168, Mapped(3916, 3)),
+  (0x3169, Mapped(3919, 3)),
+  (0x316A, Mapped(3922, 3)),
+  (0x316B, Mapped(3925, 3)),
+  (0x316C, Mapped(3928, 3)),
+  (0x316D, Mapped(3931, 3)),
+  (0x316E, Mapped(3934, 3)),
+  (0x316F, Mapped(3937, 3)),
+  (0x3170, Mapped(3940, 3)),
+  (0x3171, Mapped(3943, 3)),
+  (0x3172, Mapped(3946, 3)),
+  (0x3173, Mapped(3949, 3)),
+  (0x3174, Mapped(3952, 3)),
+  (0x3175, Mapped(3955, 3)),
+  (0x3176, Mapped(3958, 3)),
+  (0x3177, Mapped(3961, 3)),
+  (0x3178, Mapped(3964, 3)),
+  (0x3179, Mapped(3967, 3)),
+  (0x317A, Mapped(3970, 3)),
+  (0x317B, Mapped(3973, 3)),
+  (0x317C, Mapped(3976, 3)),
+  (0x317D, Mapped(3979, 3)),
+  (0x317E, Mapped(3982, 3)),
+  (0x317F, Mapped(3985, 3)),
+  (0x3180, Mapped(3988, 3)),
+  (0x3181, Mapped(3991, 3)),
+  (0x3182, Mapped(3994, 3)),
+  (0x3183, Mapped(3997, 3)),
+  (0x3184, Mapped(4000, 3)),
+  (0x3185, Mapped(4003, 3)),
+  (0x3186, Mapped(4006, 3)),
+  (0x3187, Mapped(4009, 3)),
+  (0x3188, Mapped(4012, 3)),
+  (0x3189, Mapped(4015, 3)),
+  (0x318A, Mapped(4018, 3)),
+  (0x318B, Mapped(4021, 3)),
+  (0x318C, Mapped(4024, 3)),
+  (0x318D, Mapped(4027, 3)),
+  (0x318E, Mapped(4030, 3)),
+  (0x318F, Disallowed),
+  (0x3190, Valid),
+  (0x3192, Mapped(3082, 3)),
+  (0x3193, Mapped(3100, 3)),
+  (0x3194, Mapped(4033, 3)),
+  (0x3195, Mapped(4036, 3)),
+  (0x3196, Mapped(4039, 3)),
+  (0x3197, Mapped(4042, 3)),
+  (0x3198, Mapped(4045, 3)),
+  (0x3199, Mapped(4048, 3)),
+  (0x319A, Mapped(3094, 3)),
+  (0x319B, Mapped(4051, 3)),
+  (0x319C, Mapped(4054, 3)),
+  (0x319D, Mapped(4057, 3)),
+  (0x319E, Mapped(4060, 3)),
+  (0x319F, Mapped(3106, 3)),
+  (0x31A0, Valid),
+  (0x31E4, Disallowed),
+  (0x31F0, Valid),
+  (0x3200, DisallowedStd3Mapped(4063, 5)),
+  (0x3201, DisallowedStd3Mapped(4068, 5)),
+  (0x3202, DisallowedStd3Mapped(4073, 5)),
+  (0x3203, DisallowedStd3Mapped(4078, 5)),
+  (0x3204, DisallowedStd3Mapped(4083, 5)),
+  (0x3205, DisallowedStd3Mapped(4088, 5)),
+  (0x3206, DisallowedStd3Mapped(4093, 5)),
+  (0x3207, DisallowedStd3Mapped(4098, 5)),
+  (0x3208, DisallowedStd3Mapped(4103, 5)),
+  (0x3209, DisallowedStd3Mapped(4108, 5)),
+  (0x320A, DisallowedStd3Mapped(4113, 5)),
+  (0x320B, DisallowedStd3Mapped(4118, 5)),
+  (0x320C, DisallowedStd3Mapped(4123, 5)),
+  (0x320D, DisallowedStd3Mapped(4128, 5)),
+  (0x320E, DisallowedStd3Mapped(4133, 5)),
+  (0x320F, DisallowedStd3Mapped(4138, 5)),
+  (0x3210, DisallowedStd3Mapped(4143, 5)),
+  (0x3211, DisallowedStd3Mapped(4148, 5)),
+  (0x3212, DisallowedStd3Mapped(4153, 5)),
+  (0x3213, DisallowedStd3Mapped(4158, 5)),
+  (0x3214, DisallowedStd3Mapped(4163, 5)),
+  (0x3215, DisallowedStd3Mapped(4168, 5)),
+  (0x3216, DisallowedStd3Mapped(4173, 5)),
+  (0x3217, DisallowedStd3Mapped(4178, 5)),
+  (0x3218, DisallowedStd3Mapped(4183, 5)),
+  (0x3219, DisallowedStd3Mapped(4188, 5)),
+  (0x321A, DisallowedStd3Mapped(4193, 5)),
+  (0x321B, DisallowedStd3Mapped(4198, 5)),
+  (0x321C, DisallowedStd3Mapped(4203, 5)),
+  (0x321D, DisallowedStd3Mapped(4208, 8)),
+  (0x321E, DisallowedStd3Mapped(4216, 8)),
+  (0x321F, Disallowed),
+  (0x3220, DisallowedStd3Mapped(4224, 5)),
+  (0x3221, DisallowedStd3Mapped(4229, 5)),
+  (0x3222, DisallowedStd3Mapped(4234, 5)),
+  (0x3223, DisallowedStd3Mapped(4239, 5)),
+  (0x3224, DisallowedStd3Mapped(4244, 5)),
+  (0x3225, DisallowedStd3Mapped(4249, 5)),
+  (0x3226, DisallowedStd3Mapped(4254, 5)),
+  (0x3227, DisallowedStd3Mapped(4259, 5)),
+  (0x3228, DisallowedStd3Mapped(4264, 5)),
+  (0x3229, DisallowedStd3Mapped(4269, 5)),
+  (0x322A, DisallowedStd3Mapped(4274, 5)),
+  (0x322B, DisallowedStd3Mapped(4279, 5)),
+  (0x322C, DisallowedStd3Mapped(4284, 5)),
+  (0x322D, DisallowedStd3Mapped(4289, 5)),
+  (0x322E, DisallowedStd3Mapped(4294, 5)),
+  (0x322F, DisallowedStd3Mapped(4299, 5)),
+  (0x3230, DisallowedStd3Mapped(4304, 5)),
+  (0x3231, DisallowedStd3Mapped(4309, 5)),
+  (0x3232, DisallowedStd3Mapped(4314, 5)),
+  (0x3233, DisallowedStd3Mapped(4319, 5)),
+  (0x3234, DisallowedStd3Mapped(4324, 5)),
+  (0x3235, DisallowedStd3Mapped(4329, 5)),
+  (0x3236, DisallowedStd3Mapped(4334, 5)),
+  (0x3237, DisallowedStd3Mapped(4339, 5)),
+  (0x3238, DisallowedStd3Mapped(4344, 5)),
+  (0x3239, DisallowedStd3Mapped(4349, 5)),
+  (0x323A, DisallowedStd3Mapped(4354, 5)),
+  (0x323B, DisallowedStd3Mapped(4359, 5)),
+  (0x323C, DisallowedStd3Mapped(4364, 5)),
+  (0x323D, DisallowedStd3Mapped(4369, 5)),
+  (0x323E, DisallowedStd3Mapped(4374, 5)),
+  (0x323F, DisallowedStd3Mapped(4379, 5)),
+  (0x3240, DisallowedStd3Mapped(4384, 5)),
+  (0x3241, DisallowedStd3Mapped(4389, 5)),
+  (0x3242, DisallowedStd3Mapped(4394, 5)),
+  (0x3243, DisallowedStd3Mapped(4399, 5)),
+  (0x3244, Mapped(4404, 3)),
+  (0x3245, Mapped(4407, 3)),
+  (0x3246, Mapped(3280, 3)),
+  (0x3247, Mapped(4410, 3)),
+  (0x3248, Valid),
+  (0x3250, Mapped(4413, 3)),
+  (0x3251, Mapped(4416, 2)),
+  (0x3252, Mapped(4418, 2)),
+  (0x3253, Mapped(4420, 2)),
+  (0x3254, Mapped(4422, 2)),
+  (0x3255, Mapped(4424, 2)),
+  (0x3256, Mapped(4426, 2)),
+  (0x3257, Mapped(4428, 2)),
+  (0x3258, Mapped(4430, 2)),
+  (0x3259, Mapped(4432, 2)),
+  (0x325A, Mapped(4434, 2)),
+  (0x325B, Mapped(4436, 2)),
+  (0x325C, Mapped(4438, 2)),
+  (0x325D, Mapped(4440, 2)),
+  (0x325E, Mapped(4442, 2)),
+  (0x325F, Mapped(4444, 2)),
+  (0x3260, Mapped(3754, 3)),
+  (0x3261, Mapped(3763, 3)),
+  (0x3262, Mapped(3772, 3)),
+  (0x3263, Mapped(3778, 3)),
+  (0x3264, Mapped(3802, 3)),
+  (0x3265, Mapped(3805, 3)),
+  (0x3266, Mapped(3814, 3)),
+  (0x3267, Mapped(3820, 3)),
+  (0x3268, Mapped(3823, 3)),
+  (0x3269, Mapped(3829, 3)),
+  (0x326A, Mapped(3832, 3)),
+  (0x326B, Mapped(3835, 3)),
+  (0x326C, Mapped(3838, 3)),
+  (0x326D, Mapped(3841, 3)),
+  (0x326E, Mapped(4446, 3)),
+  (0x326F, Mapped(4449, 3)),
+  (0x3270, Mapped(4452, 3)),
+  (0x3271, Mapped(4455, 3)),
+  (0x3272, Mapped(4458, 3)),
+  (0x3273, Mapped(4461, 3)),
+  (0x3274, Mapped(4464, 3)),
+  (0x3275, Mapped(4467, 3)),
+  (0x3276, Mapped(4470, 3)),
+  (0x3277, Mapped(4473, 3)),
+  (0x3278, Mapped(4476, 3)),
+  (0x3279, Mapped(4479, 3)),
+  (0x327A, Mapped(4482, 3)),
+  (0x327B, Mapped(4485, 3)),
+  (0x327C, Mapped(4488, 6)),
+  (0x327D, Mapped(4494, 6)),
+  (0x327E, Mapped(4500, 3)),
+  (0x327F, Valid),
+  (0x3280, Mapped(3082, 3)),
+  (0x3281, Mapped(3100, 3)),
+  (0x3282, Mapped(4033, 3)),
+  (0x3283, Mapped(4036, 3)),
+  (0x3284, Mapped(4503, 3)),
+  (0x3285, Mapped(4506, 3)),
+  (0x3286, Mapped(4509, 3)),
+  (0x3287, Mapped(3115, 3)),
+  (0x3288, Mapped(4512, 3)),
+  (0x3289, Mapped(3151, 3)),
+  (0x328A, Mapped(3301, 3)),
+  (0x328B, Mapped(3337, 3)),
+  (0x328C, Mapped(3334, 3)),
+  (0x328D, Mapped(3304, 3)),
+  (0x328E, Mapped(3580, 3)),
+  (0x328F, Mapped(3175, 3)),
+  (0x3290, Mapped(3295, 3)),
+  (0x3291, Mapped(4515, 3)),
+  (0x3292, Mapped(4518, 3)),
+  (0x3293, Mapped(4521, 3)),
+  (0x3294, Mapped(4524, 3)),
+  (0x3295, Mapped(4527, 3)),
+  (0x3296, Mapped(4530, 3)),
+  (0x3297, Mapped(4533, 3)),
+  (0x3298, Mapped(4536, 3)),
+  (0x3299, Mapped(4539, 3)),
+  (0x329A, Mapped(4542, 3)),
+  (0x329B, Mapped(3193, 3)),
+  (0x329C, Mapped(4545, 3)),
+  (0x329D, Mapped(4548, 3)),
+  (0x329E, Mapped(4551, 3)),
+  (0x329F, Mapped(4554, 3)),
+  (0x32A0, Mapped(4557, 3)),
+  (0x32A1, Mapped(4560, 3)),
+  (0x32A2, Mapped(4563, 3)),
+  (0x32A3, Mapped(4566, 3)),
+  (0x32A4, Mapped(4039, 3)),
+  (0x32A5, Mapped(4042, 3)),
+  (0x32A6, Mapped(4045, 3)),
+  (0x32A7, Mapped(4569, 3)),
+  (0x32A8, Mapped(4572, 3)),
+  (0x32A9, Mapped(4575, 3)),
+  (0x32AA, Mapped(4578, 3)),
+  (0x32AB, Mapped(4581, 3)),
+  (0x32AC, Mapped(4584, 3)),
+  (0x32AD, Mapped(4587, 3)),
+  (0x32AE, Mapped(4590, 3)),
+  (0x32AF, Mapped(4593, 3)),
+  (0x32B0, Mapped(4596, 3)),
+  (0x32B1, Mapped(4599, 2)),
+  (0x32B2, Mapped(4601, 2)),
+  (0x32B3, Mapped(4603, 2)),
+  (0x32B4, Mapped(4605, 2)),
+  (0x32B5, Mapped(4607, 2)),
+  (0x32B6, Mapped(4609, 2)),
+  (0x32B7, Mapped(4611, 2)),
+  (0x32B8, Mapped(4613, 2)),
+  (0x32B9, Mapped(4615, 2)),
+  (0x32BA, Mapped(4617, 2)),
+  (0x32BB, Mapped(4619, 2)),
+  (0x32BC, Mapped(4621, 2)),
+  (0x32BD, Mapped(4623, 2)),
+  (0x32BE, Mapped(4625, 2)),
+  (0x32BF, Mapped(4627, 2)),
+  (0x32C0, Mapped(4629, 4)),
+  (0x32C1, Mapped(4633, 4)),
+  (0x32C2, Mapped(4637, 4)),
+  (0x32C3, Mapped(4641, 4)),
+  (0x32C4, Mapped(4645, 4)),
+  (0x32C5, Mapped(4649, 4)),
+  (0x32C6, Mapped(4653, 4)),
+  (0x32C7, Mapped(4657, 4)),
+  (0x32C8, Mapped(4661, 4)),
+  (0x32C9, Mapped(4665, 5)),
+  (0x32CA, Mapped(4670, 5)),
+  (0x32CB, Mapped(4675, 5)),
+  (0x32CC, Mapped(4680, 2)),
+  (0x32CD, Mapped(4682, 3)),
+  (0x32CE, Mapped(4685, 2)),
+  (0x32CF, Mapped(4687, 3)),
+  (0x32D0, Mapped(4690, 3)),
+  (0x32D1, Mapped(4693, 3)),
+  (0x32D2, Mapped(4696, 3)),
+  (0x32D3, Mapped(4699, 3)),
+  (0x32D4, Mapped(4702, 3)),
+  (0x32D5, Mapped(4705, 3)),
+  (0x32D6, Mapped(4708, 3)),
+  (0x32D7, Mapped(4711, 3)),
+  (0x32D8, Mapped(4714, 3)),
+  (0x32D9, Mapped(4717, 3)),
+  (0x32DA, Mapped(4720, 3)),
+  (0x32DB, Mapped(4723, 3)),
+  (0x32DC, Mapped(4726, 3)),
+  (0x32DD, Mapped(4729, 3)),
+  (0x32DE, Mapped(4732, 3)),
+  (0x32DF, Mapped(4735, 3)),
+  (0x32E0, Mapped(4738, 3)),
+  (0x32E1, Mapped(4741, 3)),
+  (0x32E2, Mapped(4744, 3)),
+  (0x32E3, Mapped(4747, 3)),
+  (0x32E4, Mapped(4750, 3)),
+  (0x32E5, Mapped(4753, 3)),
+  (0x32E6, Mapped(4756, 3)),
+  (0x32E7, Mapped(4759, 3)),
+  (0x32E8, Mapped(4762, 3)),
+  (0x32E9, Mapped(4765, 3)),
+  (0x32EA, Mapped(4768, 3)),
+  (0x32EB, Mapped(4771, 3)),
+  (0x32EC, Mapped(4774, 3)),
+  (0x32ED, Mapped(4777, 3)),
+  (0x32EE, Mapped(4780, 3)),
+  (0x32EF, Mapped(4783, 3)),
+  (0x32F0, Mapped(4786, 3)),
+  (0x32F1, Mapped(4789, 3)),
+  (0x32F2, Mapped(4792, 3)),
+  (0x32F3, Mapped(4795, 3)),
+  (0x32F4, Mapped(4798, 3)),
+  (0x32F5, Mapped(4801, 3)),
+  (0x32F6, Mapped(4804, 3)),
+  (0x32F7, Mapped(4807, 3)),
+  (0x32F8, Mapped(4810, 3)),
+  (0x32F9, Mapped(4813, 3)),
+  (0x32FA, Mapped(4816, 3)),
+  (0x32FB, Mapped(4819, 3)),
+  (0x32FC, Mapped(4822, 3)),
+  (0x32FD, Mapped(4825, 3)),
+  (0x32FE, Mapped(4828, 3)),
+  (0x32FF, Mapped(4831, 6)),
+  (0x3300, Mapped(4837, 12)),
+  (0x3301, Mapped(4849, 12)),
+  (0x3302, Mapped(4861, 12)),
+  (0x3303, Mapped(4873, 9)),
+  (0x3304, Mapped(4882, 12)),
+  (0x3305, Mapped(4894, 9)),
+  (0x3306, Mapped(4903, 9)),
+  (0x3307, Mapped(4912, 15)),
+  (0x3308, Mapped(4927, 12)),
+  (0x3309, Mapped(4939, 9)),
+  (0x330A, Mapped(4948, 9)),
+  (0x330B, Mapped(4957, 9)),
+  (0x330C, Mapped(4966, 12)),
+  (0x330D, Mapped(4978, 12)),
+  (0x330E, Mapped(4990, 9)),
+  (0x330F, Mapped(4999, 9)),
+  (0x3310, Mapped(5008, 6)),
+  (0x3311, Mapped(5014, 9)),
+  (0x3312, Mapped(5023, 12)),
+  (0x3313, Mapped(5035, 12)),
+  (0x3314, Mapped(5047, 6)),
+  (0x3315, Mapped(5053, 15)),
+  (0x3316, Mapped(5068, 18)),
+  (0x3317, Mapped(5086, 15)),
+  (0x3318, Mapped(5101, 9)),
+  (0x3319, Mapped(5110, 15)),
+  (0x331A, Mapped(5125, 15)),
+  (0x331B, Mapped(5140, 12)),
+  (0x331C, Mapped(5152, 9)),
+  (0x331D, Mapped(5161, 9)),
+  (0x331E, Mapped(5170, 9)),
+  (0x331F, Mapped(5179, 12)),
+  (0x3320, Mapped(5191, 15)),
+  (0x3321, Mapped(5206, 12)),
+  (0x3322, Mapped(5218, 9)),
+  (0x3323, Mapped(5227, 9)),
+  (0x3324, Mapped(5236, 9)),
+  (0x3325, Mapped(5245, 6)),
+  (0x3326, Mapped(5251, 6)),
+  (0x3327, Mapped(5257, 6)),
+  (0x3328, Mapped(5263, 6)),
+  (0x3329, Mapped(5269, 9)),
+  (0x332A, Mapped(5278, 9)),
+  (0x332B, Mapped(5287, 15)),
+  (0x332C, Mapped(5302, 9)),
+  (0x332D, Mapped(5311, 12)),
+  (0x332E, Mapped(5323, 15)),
+  (0x332F, Mapped(5338, 9)),
+  (0x3330, Mapped(5347, 6)),
+  (0x3331, Mapped(5353, 6)),
+  (0x3332, Mapped(5359, 15)),
+  (0x3333, Mapped(5374, 12)),
+  (0x3334, Mapped(5386, 15)),
+  (0x3335, Mapped(5401, 9)),
+  (0x3336, Mapped(5410, 15)),
+  (0x3337, Mapped(5425, 6)),
+  (0x3338, Mapped(5431, 9)),
+  (0x3339, Mapped(5440, 9)),
+  (0x333A, Mapped(5449, 9)),
+  (0x333B, Mapped(5458, 9)),
+  (0x333C, Mapped(5467, 9)),
+  (0x333D, Mapped(5476, 12)),
+  (0x333E, Mapped(5488, 9)),
+  (0x333F, Mapped(5497, 6)),
+  (0x3340, Mapped(5503, 9)),
+  (0x3341, Mapped(5512, 9)),
+  (0x3342, Mapped(5521, 9)),
+  (0x3343, Mapped(5530, 12)),
+  (0x3344, Mapped(5542, 9)),
+  (0x3345, Mapped(5551, 9)),
+  (0x3346, Mapped(5560, 9)),
+  (0x3347, Mapped(5569, 15)),
+  (0x3348, Mapped(5584, 12)),
+  (0x3349, Mapped(5596, 6)),
+  (0x334A, Mapped(5602, 15)),
+  (0x334B, Mapped(5617, 6)),
+  (0x334C, Mapped(5623, 12)),
+  (0x334D, Mapped(5635, 12)),
+  (0x334E, Mapped(5647, 9)),
+  (0x334F, Mapped(5656, 9)),
+  (0x3350, Mapped(5665, 9)),
+  (0x3351, Mapped(5674, 12)),
+  (0x3352, Mapped(5686, 6)),
+  (0x3353, Mapped(5692, 9)),
+  (0x3354, Mapped(5701, 12)),
+  (0x3355, Mapped(5713, 6)),
+  (0x3356, Mapped(5719, 15)),
+  (0x3357, Mapped(5734, 9)),
+  (0x3358, Mapped(5743, 4)),
+  (0x3359, Mapped(5747, 4)),
+  (0x335A, Mapped(5751, 4)),
+  (0x335B, Mapped(5755, 4)),
+  (0x335C, Mapped(5759, 4)),
+  (0x335D, Mapped(5763, 4)),
+  (0x335E, Mapped(5767, 4)),
+  (0x335F, Mapped(5771, 4)),
+  (0x3360, Mapped(5775, 4)),
+  (0x3361, Mapped(5779, 4)),
+  (0x3362, Mapped(5783, 5)),
+  (0x3363, Mapped(5788, 5)),
+  (0x3364, Mapped(5793, 5)),
+  (0x3365, Mapped(5798, 5)),
+  (0x3366, Mapped(5803, 5)),
+  (0x3367, Mapped(5808, 5)),
+  (0x3368, Mapped(5813, 5)),
+  (0x3369, Mapped(5818, 5)),
+  (0x336A, Mapped(5823, 5)),
+  (0x336B, Mapped(5828, 5)),
+  (0x336C, Mapped(5833, 5)),
+  (0x336D, Mapped(5838, 5)),
+  (0x336E, Mapped(5843, 5)),
+  (0x336F, Mapped(5848, 5)),
+  (0x3370, Mapped(5853, 5)),
+  (0x3371, Mapped(5858, 3)),
+  (0x3372, Mapped(5861, 2)),
+  (0x3373, Mapped(5863, 2)),
+  (0x3374, Mapped(5865, 3)),
+  (0x3375, Mapped(5868, 2)),
+  (0x3376, Mapped(5870, 2)),
+  (0x3377, Mapped(5872, 2)),
+  (0x3378, Mapped(5874, 3)),
+  (0x3379, Mapped(5877, 3)),
+  (0x337A, Mapped(5880, 2)),
+  (0x337B, Mapped(5882, 6)),
+  (0x337C, Mapped(5888, 6)),
+  (0x337D, Mapped(5894, 6)),
+  (0x337E, Mapped(5900, 6)),
+  (0x337F, Mapped(5906, 12)),
+  (0x3380, Mapped(5918, 2)),
+  (0x3381, Mapped(5920, 2)),
+  (0x3382, Mapped(5922, 3)),
+  (0x3383, Mapped(5925, 2)),
+  (0x3384, Mapped(5927, 2)),
+  (0x3385, Mapped(5929, 2)),
+  (0x3386, Mapped(5931, 2)),
+  (0x3387, Mapped(5933, 2)),
+  (0x3388, Mapped(5935, 3)),
+  (0x3389, Mapped(5938, 4)),
+  (0x338A, Mapped(5942, 2)),
+  (0x338B, Mapped(5944, 2)),
+  (0x338C, Mapped(5946, 3)),
+  (0x338D, Mapped(5949, 3)),
+  (0x338E, Mapped(5952, 2)),
+  (0x338F, Mapped(5954, 2)),
+  (0x3390, Mapped(5956, 2)),
+  (0x3391, Mapped(5958, 3)),
+  (0x3392, Mapped(5961, 3)),
+  (0x3393, Mapped(5964, 3)),
+  (0x3394, Mapped(5967, 3)),
+  (0x3395, Mapped(5970, 3)),
+  (0x3396, Mapped(5973, 2)),
+  (0x3397, Mapped(5975, 2)),
+  (0x3398, Mapped(5977, 2)),
+  (0x3399, Mapped(5979, 2)),
+  (0x339A, Mapped(5981, 2)),
+  (0x339B, Mapped(5983, 3)),
+  (0x339C, Mapped(5986, 2)),
+  (0x339D, Mapped(5988, 2)),
+  (0x339E, Mapped(5990, 2)),
+  (0x339F, Mapped(5992, 3)),
+  (0x33A0, Mapped(5995, 3)),
+  (0x33A1, Mapped(5998, 2)),
+  (0x33A2, Mapped(6000, 3)),
+  (0x33A3, Mapped(6003, 3)),
+  (0x33A4, Mapped(6006, 3)),
+  (0x33A5, Mapped(6009, 2)),
+  (0x33A6, Mapped(6011, 3)),
+  (0x33A7, Mapped(6014, 5)),
+  (0x33A8, Mapped(6019, 6)),
+  (0x33A9, Mapped(5918, 2)),
+  (0x33AA, Mapped(6025, 3)),
+  (0x33AB, Mapped(6028, 3)),
+  (0x33AC, Mapped(6031, 3)),
+  (0x33AD, Mapped(6034, 3)),
+  (0x33AE, Mapped(6037, 7)),
+  (0x33AF, Mapped(6044, 8)),
+  (0x33B0, Mapped(6052, 2)),
+  (0x33B1, Mapped(6054, 2)),
+  (0x33B2, Mapped(6056, 3)),
+  (0x33B3, Mapped(6059, 2)),
+  (0x33B4, Mapped(6061, 2)),
+  (0x33B5, Mapped(6063, 2)),
+  (0x33B6, Mapped(6065, 3)),
+  (0x33B7, Mapped(6068, 2)),
+  (0x33B8, Mapped(6070, 2)),
+  (0x33B9, Mapped(6068, 2)),
+  (0x33BA, Mapped(6072, 2)),
+  (0x33BB, Mapped(6074, 2)),
+  (0x33BC, Mapped(6076, 3)),
+  (0x33BD, Mapped(6079, 2)),
+  (0x33BE, Mapped(6081, 2)),
+  (0x33BF, Mapped(6079, 2)),
+  (0x33C0, Mapped(6083, 3)),
+  (0x33C1, Mapped(6086, 3)),
+  (0x33C2, Disallowed),
+  (0x33C3, Mapped(6089, 2)),
+  (0x33C4, Mapped(6091, 2)),
+  (0x33C5, Mapped(6093, 2)),
+  (0x33C6, Mapped(6095, 6)),
+  (0x33C7, Disallowed),
+  (0x33C8, Mapped(6101, 2)),
+  (0x33C9, Mapped(6103, 2)),
+  (0x33CA, Mapped(6105, 2)),
+  (0x33CB, Mapped(6107, 2)),
+  (0x33CC, Mapped(6109, 2)),
+  (0x33CD, Mapped(6111, 2)),
+  (0x33CE, Mapped(5990, 2)),
+  (0x33CF, Mapped(6113, 2)),
+  (0x33D0, Mapped(6115, 2)),
+  (0x33D1, Mapped(6117, 2)),
+  (0x33D2, Mapped(6119, 3)),
+  (0x33D3, Mapped(6122, 2)),
+  (0x33D4, Mapped(5931, 2)),
+  (0x33D5, Mapped(6124, 3)),
+  (0x33D6, Mapped(6127, 3)),
+  (0x33D7, Mapped(6130, 2)),
+  (0x33D8, Disallowed),
+  (0x33D9, Mapped(6132, 3)),
+  (0x33DA, Mapped(6135, 2)),
+  (0x33DB, Mapped(6137, 2)),
+  (0x33DC, Mapped(6139, 2)),
+  (0x33DD, Mapped(6141, 2)),
+  (0x33DE, Mapped(6143, 5)),
+  (0x33DF, Mapped(6148, 5)),
+  (0x33E0, Mapped(6153, 4)),
+  (0x33E1, Mapped(6157, 4)),
+  (0x33E2, Mapped(6161, 4)),
+  (0x33E3, Mapped(6165, 4)),
+  (0x33E4, Mapped(6169, 4)),
+  (0x33E5, Mapped(6173, 4)),
+  (0x33E6, Mapped(6177, 4)),
+  (0x33E7, Mapped(6181, 4)),
+  (0x33E8, Mapped(6185, 4)),
+  (0x33E9, Mapped(6189, 5)),
+  (0x33EA, Mapped(6194, 5)),
+  (0x33EB, Mapped(6199, 5)),
+  (0x33EC, Mapped(6204, 5)),
+  (0x33ED, Mapped(6209, 5)),
+  (0x33EE, Mapped(6214, 5)),
+  (0x33EF, Mapped(6219, 5)),
+  (0x33F0, Mapped(6224, 5)),
+  (0x33F1, Mapped(6229, 5)),
+  (0x33F2, Mapped(6234, 5)),
+  (0x33F3, Mapped(6239, 5)),
+  (0x33F4, Mapped(6244, 5)),
+  (0x33F5, Mapped(6249, 5)),
+  (0x33F6, Mapped(6254, 5)),
+  (0x33F7, Mapped(6259, 5)),
+  (0x33F8, Mapped(6264, 5)),
+  (0x33F9, Mapped(6269, 5)),
+  (0x33FA, Mapped(6274, 5)),
+  (0x33FB, Mapped(6279, 5)),
+  (0x33FC, Mapped(6284, 5)),
+  (0x33FD, Mapped(6289, 5)),
+  (0x33FE, Mapped(6294, 5)),
+  (0x33FF, Mapped(6299, 3)),
+  (0x3400, Valid),
+  (0x9FFD, Disallowed),
+  (0xA000, Valid),
+  (0xA48D, Disallowed),
+  (0xA490, Valid),
+  (0xA4C7, Disallowed),
+  (0xA4D0, Valid),
+  (0xA62C, Disallowed),
+  (0xA640, Mapped(6302, 3)),
+  (0xA641, Valid),
+  (0xA642, Mapped(6305, 3)),
+  (0xA643, Valid),
+  (0xA644, Mapped(6308, 3)),
+  (0xA645, Valid),
+  (0xA646, Mapped(6311, 3)),
+  (0xA647, Valid),
+  (0xA648, Mapped(6314, 3)),
+  (0xA649, Valid),
+  (0xA64A, Mapped(1298, 3)),
+  (0xA64B, Valid),
+  (0xA64C, Mapped(6317, 3)),
+  (0xA64D, Valid),
+  (0xA64E, Mapped(6320, 3)),
+  (0xA64F, Valid),
+  (0xA650, Mapped(6323, 3)),
+  (0xA651, Valid),
+  (0xA652, Mapped(6326, 3)),
+  (0xA653, Valid),
+  (0xA654, Mapped(6329, 3)),
+  (0xA655, Valid),
+  (0xA656, Mapped(6332, 3)),
+  (0xA657, Valid),
+  (0xA658, Mapped(6335, 3)),
+  (0xA659, Valid),
+  (0xA65A, Mapped(6338, 3)),
+  (0xA65B, Valid),
+  (0xA65C, Mapped(6341, 3)),
+  (0xA65D, Valid),
+  (0xA65E, Mapped(6344, 3)),
+  (0xA65F, Valid),
+  (0xA660, Mapped(6347, 3)),
+  (0xA661, Valid),
+  (0xA662, Mapped(6350, 3)),
+  (0xA663, Valid),
+  (0xA664, Mapped(6353, 3)),
+  (0xA665, Valid),
+  (0xA666, Mapped(6356, 3)),
+  (0xA667, Valid),
+  (0xA668, Mapped(6359, 3)),
+  (0xA669, Valid),
+  (0xA66A, Mapped(6362, 3)),
+  (0xA66B, Valid),
+  (0xA66C, Mapped(6365, 3)),
+  (0xA66D, Valid),
+  (0xA680, Mapped(6368, 3)),
+  (0xA681, Valid),
+  (0xA682, Mapped(6371, 3)),
+  (0xA683, Valid),
+  (0xA684, Mapped(6374, 3)),
+  (0xA685, Valid),
+  (0xA686, Mapped(6377, 3)),
+  (0xA687, Valid),
+  (0xA688, Mapped(6380, 3)),
+  (0xA689, Valid),
+  (0xA68A, Mapped(6383, 3)),
+  (0xA68B, Valid),
+  (0xA68C, Mapped(6386, 3)),
+  (0xA68D, Valid),
+  (0xA68E, Mapped(6389, 3)),
+  (0xA68F, Valid),
+  (0xA690, Mapped(6392, 3)),
+  (0xA691, Valid),
+  (0xA692, Mapped(6395, 3)),
+  (0xA693, Valid),
+  (0xA694, Mapped(6398, 3)),
+  (0xA695, Valid),
+  (0xA696, Mapped(6401, 3)),
+  (0xA697, Valid),
+  (0xA698, Mapped(6404, 3)),
+  (0xA699, Valid),
+  (0xA69A, Mapped(6407, 3)),
+  (0xA69B, Valid),
+  (0xA69C, Mapped(704, 2)),
+  (0xA69D, Mapped(708, 2)),
+  (0xA69E, Valid),
+  (0xA6F8, Disallowed),
+  (0xA700, Valid),
+  (0xA722, Mapped(6410, 3)),
+  (0xA723, Valid),
+  (0xA724, Mapped(6413, 3)),
+  (0xA725, Valid),
+  (0xA726, Mapped(6416, 3)),
+  (0xA727, Valid),
+  (0xA728, Mapped(6419, 3)),
+  (0xA729, Valid),
+  (0xA72A, Mapped(6422, 3)),
+  (0xA72B, Valid),
+  (0xA72C, Mapped(6425, 3)),
+  (0xA72D, Valid),
+  (0xA72E, Mapped(6428, 3)),
+  (0xA72F, Valid),
+  (0xA732, Mapped(6431, 3)),
+  (0xA733, Valid),
+  (0xA734, Mapped(6434, 3)),
+  (0xA735, Valid),
+  (0xA736, Mapped(6437, 3)),
+  (0xA737, Valid),
+  (0xA738, Mapped(6440, 3)),
+  (0xA739, Valid),
+  (0xA73A, Mapped(6443, 3)),
+  (0xA73B, Valid),
+  (0xA73C, Mapped(6446, 3)),
+  (0xA73D, Valid),
+  (0xA73E, Mapped(6449, 3)),
+  (0xA73F, Valid),
+  (0xA740, Mapped(6452, 3)),
+  (0xA741, Valid),
+  (0xA742, Mapped(6455, 3)),
+  (0xA743, Valid),
+  (0xA744, Mapped(6458, 3)),
+  (0xA745, Valid),
+  (0xA746, Mapped(6461, 3)),
+  (0xA747, Valid),
+  (0xA748, Mapped(6464, 3)),
+  (0xA749, Valid),
+  (0xA74A, Mapped(6467, 3)),
+  (0xA74B, Valid),
+  (0xA74C, Mapped(6470, 3)),
+  (0xA74D, Valid),
+  (0xA74E, Mapped(6473, 3)),
+  (0xA74F, Valid),
+  (0xA750, Mapped(6476, 3)),
+  (0xA751, Valid),
+  (0xA752, Mapped(6479, 3)),
+  (0xA753, Valid),
+  (0xA754, Mapped(6482, 3)),
+  (0xA755, Valid),
+  (0xA756, Mapped(6485, 3)),
+  (0xA757, Valid),
+  (0xA758, Mapped(6488, 3)),
+  (0xA759, Valid),
+  (0xA75A, Mapped(6491, 3)),
+  (0xA75B, Valid),
+  (0xA75C, Mapped(6494, 3)),
+  (0xA75D, Valid),
+  (0xA75E, Mapped(6497, 3)),
+  (0xA75F, Valid),
+  (0xA760, Mapped(6500, 3)),
+  (0xA761, Valid),
+  (0xA762, Mapped(6503, 3)),
+  (0xA763, Valid),
+  (0xA764, Mapped(6506, 3)),
+  (0xA765, Valid),
+  (0xA766, Mapped(6509, 3)),
+  (0xA767, Valid),
+  (0xA768, Mapped(6512, 3)),
+  (0xA769, Valid),
+  (0xA76A, Mapped(6515, 3)),
+  (0xA76B, Valid),
+  (0xA76C, Mapped(6518, 3)),
+  (0xA76D, Valid),
+  (0xA76E, Mapped(6521, 3)),
+  (0xA76F, Valid),
+  (0xA770, Mapped(6521, 3)),
+  (0xA771, Valid),
+  (0xA779, Mapped(6524, 3)),
+  (0xA77A, Valid),
+  (0xA77B, Mapped(6527, 3)),
+  (0xA77C, Valid),
+  (0xA77D, Mapped(6530, 3)),
+  (0xA77E, Mapped(6533, 3)),
+  (0xA77F, Valid),
+  (0xA780, Mapped(6536, 3)),
+  (0xA781, Valid),
+  (0xA782, Mapped(6539, 3)),
+  (0xA783, Valid),
+  (0xA784, Mapped(6542, 3)),
+  (0xA785, Valid),
+  (0xA786, Mapped(6545, 3)),
+  (0xA787, Valid),
+  (0xA78B, Mapped(6548, 3)),
+  (0xA78C, Valid),
+  (0xA78D, Mapped(1465, 2)),
+  (0xA78E, Valid),
+  (0xA790, Mapped(6551, 3)),
+  (0xA791, Valid),
+  (0xA792, Mapped(6554, 3)),
+  (0xA793, Valid),
+  (0xA796, Mapped(6557, 3)),
+  (0xA797, Valid),
+  (0xA798, Mapped(6560, 3)),
+  (0xA799, Valid),
+  (0xA79A, Mapped(6563, 3)),
+  (0xA79B, Valid),
+  (0xA79C, Mapped(6566, 3)),
+  (0xA79D, Valid),
+  (0xA79E, Mapped(6569, 3)),
+  (0xA79F, Valid),
+  (0xA7A0, Mapped(6572, 3)),
+  (0xA7A1, Valid),
+  (0xA7A2, Mapped(6575, 3)),
+  (0xA7A3, Valid),
+  (0xA7A4, Mapped(6578, 3)),
+  (0xA7A5, Valid),
+  (0xA7A6, Mapped(6581, 3)),
+  (0xA7A7, Valid),
+  (0xA7A8, Mapped(6584, 3)),
+  (0xA7A9, Valid),
+  (0xA7AA, Mapped(461, 2)),
+  (0xA7AB, Mapped(1443, 2)),
+  (0xA7AC, Mapped(1463, 2)),
+  (0xA7AD, Mapped(6587, 2)),
+  (0xA7AE, Mapped(1467, 2)),
+  (0xA7AF, Valid),
+  (0xA7B0, Mapped(6589, 2)),
+  (0xA7B1, Mapped(6591, 2)),
+  (0xA7B2, Mapped(1472, 2)),
+  (0xA7B3, Mapped(6593, 3)),
+  (0xA7B4, Mapped(6596, 3)),
+  (0xA7B5, Valid),
+  (0xA7B6, Mapped(6599, 3)),
+  (0xA7B7, Valid),
+  (0xA7B8, Mapped(6602, 3)),
+  (0xA7B9, Valid),
+  (0xA7BA, Mapped(6605, 3)),
+  (0xA7BB, Valid),
+  (0xA7BC, Mapped(6608, 3)),
+  (0xA7BD, Valid),
+  (0xA7BE, Mapped(6611, 3)),
+  (0xA7BF, Valid),
+  (0xA7C0, Disallowed),
+  (0xA7C2, Mapped(6614, 3)),
+  (0xA7C3, Valid),
+  (0xA7C4, Mapped(6617, 3)),
+  (0xA7C5, Mapped(1491, 2)),
+  (0xA7C6, Mapped(6620, 3)),
+  (0xA7C7, Mapped(6623, 3)),
+  (0xA7C8, Valid),
+  (0xA7C9, Mapped(6626, 3)),
+  (0xA7CA, Valid),
+  (0xA7CB, Disallowed),
+  (0xA7F5, Mapped(6629, 3)),
+  (0xA7F6, Valid),
+  (0xA7F8, Mapped(159, 2)),
+  (0xA7F9, Mapped(206, 2)),
+  (0xA7FA, Valid),
+  (0xA82D, Disallowed),
+  (0xA830, Valid),
+  (0xA83A, Disallowed),
+  (0xA840, Valid),
+  (0xA878, Disallowed),
+  (0xA880, Valid),
+  (0xA8C6, Disallowed),
+  (0xA8CE, Valid),
+  (0xA8DA, Disallowed),
+  (0xA8E0, Valid),
+  (0xA954, Disallowed),
+  (0xA95F, Valid),
+  (0xA97D, Disallowed),
+  (0xA980, Valid),
+  (0xA9CE, Disallowed),
+  (0xA9CF, Valid),
+  (0xA9DA, Disallowed),
+  (0xA9DE, Valid),
+  (0xA9FF, Disallowed),
+  (0xAA00, Valid),
+  (0xAA37, Disallowed),
+  (0xAA40, Valid),
+  (0xAA4E, Disallowed),
+  (0xAA50, Valid),
+  (0xAA5A, Disallowed),
+  (0xAA5C, Valid),
+  (0xAAC3, Disallowed),
+  (0xAADB, Valid),
+  (0xAAF7, Disallowed),
+  (0xAB01, Valid),
+  (0xAB07, Disallowed),
+  (0xAB09, Valid),
+  (0xAB0F, Disallowed),
+  (0xAB11, Valid),
+  (0xAB17, Disallowed),
+  (0xAB20, Valid),
+  (0xAB27, Disallowed),
+  (0xAB28, Valid),
+  (0xAB2F, Disallowed),
+  (0xAB30, Valid),
+  (0xAB5C, Mapped(6416, 3)),
+  (0xAB5D, Mapped(6632, 3)),
+  (0xAB5E, Mapped(2888, 2)),
+  (0xAB5F, Mapped(6635, 3)),
+  (0xAB60, Valid),
+  (0xAB69, Mapped(6638, 2)),
+  (0xAB6A, Valid),
+  (0xAB6C, Disallowed),
+  (0xAB70, Mapped(6640, 3)),
+  (0xAB71, Mapped(6643, 3)),
+  (0xAB72, Mapped(6646, 3)),
+  (0xAB73, Mapped(6649, 3)),
+  (0xAB74, Mapped(6652, 3)),
+  (0xAB75, Mapped(6655, 3)),
+  (0xAB76, Mapped(6658, 3)),
+  (0xAB77, Mapped(6661, 3)),
+  (0xAB78, Mapped(6664, 3)),
+  (0xAB79, Mapped(6667, 3)),
+  (0xAB7A, Mapped(6670, 3)),
+  (0xAB7B, Mapped(6673, 3)),
+  (0xAB7C, Mapped(6676, 3)),
+  (0xAB7D, Mapped(6679, 3)),
+  (0xAB7E, Mapped(6682, 3)),
+  (0xAB7F, Mapped(6685, 3)),
+  (0xAB80, Mapped(6688, 3)),
+  (0xAB81, Mapped(6691, 3)),
+  (0xAB82, Mapped(6694, 3)),
+  (0xAB83, Mapped(6697, 3)),
+  (0xAB84, Mapped(6700, 3)),
+  (0xAB85, Mapped(6703, 3)),
+  (0xAB86, Mapped(6706, 3)),
+  (0xAB87, Mapped(6709, 3)),
+  (0xAB88, Mapped(6712, 3)),
+  (0xAB89, Mapped(6715, 3)),
+  (0xAB8A, Mapped(6718, 3)),
+  (0xAB8B, Mapped(6721, 3)),
+  (0xAB8C, Mapped(6724, 3)),
+  (0xAB8D, Mapped(6727, 3)),
+  (0xAB8E, Mapped(6730, 3)),
+  (0xAB8F, Mapped(6733, 3)),
+  (0xAB90, Mapped(6736, 3)),
+  (0xAB91, Mapped(6739, 3)),
+  (0xAB92, Mapped(6742, 3)),
+  (0xAB93, Mapped(6745, 3)),
+  (0xAB94, Mapped(6748, 3)),
+  (0xAB95, Mapped(6751, 3)),
+  (0xAB96, Mapped(6754, 3)),
+  (0xAB97, Mapped(6757, 3)),
+  (0xAB98, Mapped(6760, 3)),
+  (0xAB99, Mapped(6763, 3)),
+  (0xAB9A, Mapped(6766, 3)),
+  (0xAB9B, Mapped(6769, 3)),
+  (0xAB9C, Mapped(6772, 3)),
+  (0xAB9D, Mapped(6775, 3)),
+  (0xAB9E, Mapped(6778, 3)),
+  (0xAB9F, Mapped(6781, 3)),
+  (0xABA0, Mapped(6784, 3)),
+  (0xABA1, Mapped(6787, 3)),
+  (0xABA2, Mapped(6790, 3)),
+  (0xABA3, Mapped(6793, 3)),
+  (0xABA4, Mapped(6796, 3)),
+  (0xABA5, Mapped(6799, 3)),
+  (0xABA6, Mapped(6802, 3)),
+  (0xABA7, Mapped(6805, 3)),
+  (0xABA8, Mapped(6808, 3)),
+  (0xABA9, Mapped(6811, 3)),
+  (0xABAA, Mapped(6814, 3)),
+  (0xABAB, Mapped(6817, 3)),
+  (0xABAC, Mapped(6820, 3)),
+  (0xABAD, Mapped(6823, 3)),
+  (0xABAE, Mapped(6826, 3)),
+  (0xABAF, Mapped(6829, 3)),
+  (0xABB0, Mapped(6832, 3)),
+  (0xABB1, Mapped(6835, 3)),
+  (0xABB2, Mapped(6838, 3)),
+  (0xABB3, Mapped(6841, 3)),
+  (0xABB4, Mapped(6844, 3)),
+  (0xABB5, Mapped(6847, 3)),
+  (0xABB6, Mapped(6850, 3)),
+  (0xABB7, Mapped(6853, 3)),
+  (0xABB8, Mapped(6856, 3)),
+  (0xABB9, Mapped(6859, 3)),
+  (0xABBA, Mapped(6862, 3)),
+  (0xABBB, Mapped(6865, 3)),
+  (0xABBC, Mapped(6868, 3)),
+  (0xABBD, Mapped(6871, 3)),
+  (0xABBE, Mapped(6874, 3)),
+  (0xABBF, Mapped(6877, 3)),
+  (0xABC0, Valid),
+  (0xABEE, Disallowed),
+  (0xABF0, Valid),
+  (0xABFA, Disallowed),
+  (0xAC00, Valid),
+  (0xD7A4, Disallowed),
+  (0xD7B0, Valid),
+  (0xD7C7, Disallowed),
+  (0xD7CB, Valid),
+  (0xD7FC, Disallowed),
+  (0xF900, Mapped(6880, 3)),
+  (0xF901, Mapped(6883, 3)),
+  (0xF902, Mapped(3556, 3)),
+  (0xF903, Mapped(6886, 3)),
+  (0xF904, Mapped(6889, 3)),
+  (0xF905, Mapped(6892, 3)),
+  (0xF906, Mapped(6895, 3)),
+  (0xF907, Mapped(3718, 3)),
+  (0xF909, Mapped(6898, 3)),
+  (0xF90A, Mapped(3580, 3)),
+  (0xF90B, Mapped(6901, 3)),
+  (0xF90C, Mapped(6904, 3)),
+  (0xF90D, Mapped(6907, 3)),
+  (0xF90E, Mapped(6910, 3)),
+  (0xF90F, Mapped(6913, 3)),
+  (0xF910, Mapped(6916, 3)),
+  (0xF911, Mapped(6919, 3)),
+  (0xF912, Mapped(6922, 3)),
+  (0xF913, Mapped(6925, 3)),
+  (0xF914, Mapped(6928, 3)),
+  (0xF915, Mapped(6931, 3)),
+  (0xF916, Mapped(6934, 3)),
+  (0xF917, Mapped(6937, 3)),
+  (0xF918, Mapped(6940, 3)),
+  (0xF919, Mapped(6943, 3)),
+  (0xF91A, Mapped(6946, 3)),
+  (0xF91B, Mapped(6949, 3)),
+  (0xF91C, Mapped(6952, 3)),
+  (0xF91D, Mapped(6955, 3)),
+  (0xF91E, Mapped(6958, 3)),
+  (0xF91F, Mapped(6961, 3)),
+  (0xF920, Mapped(6964, 3)),
+  (0xF921, Mapped(6967, 3)),
+  (0xF922, Mapped(6970, 3)),
+  (0xF923, Mapped(6973, 3)),
+  (0xF924, Mapped(6976, 3)),
+  (0xF925, Mapped(6979, 3)),
+  (0xF926, Mapped(6982, 3)),
+  (0xF927, Mapped(6985, 3)),
+  (0xF928, Mapped(6988, 3)),
+  (0xF929, Mapped(6991, 3)),
+  (0xF92A, Mapped(6994, 3)),
+  (0xF92B, Mapped(6997, 3)),
+  (0xF92C, Mapped(7000, 3)),
+  (0xF92D, Mapped(7003, 3)),
+  (0xF92E, Mapped(7006, 3)),
+  (0xF92F, Mapped(7009, 3)),
+  (0xF930, Mapped(7012, 3)),
+  (0xF931, Mapped(7015, 3)),
+  (0xF932, Mapped(7018, 3)),
+  (0xF933, Mapped(7021, 3)),
+  (0xF934, Mapped(3454, 3)),
+  (0xF935, Mapped(7024, 3)),
+  (0xF936, Mapped(7027, 3)),
+  (0xF937, Mapped(7030, 3)),
+  (0xF938, Mapped(7033, 3)),
+  (0xF939, Mapped(7036, 3)),
+  (0xF93A, Mapped(7039, 3)),
+  (0xF93B, Mapped(7042, 3)),
+  (0xF93C, Mapped(7045, 3)),
+  (0xF93D, Mapped(7048, 3)),
+  (0xF93E, Mapped(7051, 3)),
+  (0xF93F, Mapped(7054, 3)),
+  (0xF940, Mapped(3673, 3)),
+  (0xF941, Mapped(7057, 3)),
+  (0xF942, Mapped(7060, 3)),
+  (0xF943, Mapped(7063, 3)),
+  (0xF944, Mapped(7066, 3)),
+  (0xF945, Mapped(7069, 3)),
+  (0xF946, Mapped(7072, 3)),
+  (0xF947, Mapped(7075, 3)),
+  (0xF948, Mapped(7078, 3)),
+  (0xF949, Mapped(7081, 3)),
+  (0xF94A, Mapped(7084, 3)),
+  (0xF94B, Mapped(7087, 3)),
+  (0xF94C, Mapped(7090, 3)),
+  (0xF94D, Mapped(7093, 3)),
+  (0xF94E, Mapped(7096, 3)),
+  (0xF94F, Mapped(7099, 3)),
+  (0xF950, Mapped(7102, 3)),
+  (0xF951, Mapped(7105, 3)),
+  (0xF952, Mapped(7108, 3)),
+  (0xF953, Mapped(7111, 3)),
+  (0xF954, Mapped(7114, 3)),
+  (0xF955, Mapped(7117, 3)),
+  (0xF956, Mapped(7120, 3)),
+  (0xF957, Mapped(7123, 3)),
+  (0xF958, Mapped(7126, 3)),
+  (0xF959, Mapped(7129, 3)),
+  (0xF95A, Mapped(7132, 3)),
+  (0xF95B, Mapped(7135, 3)),
+  (0xF95C, Mapped(6928, 3)),
+  (0xF95D, Mapped(7138, 3)),
+  (0xF95E, Mapped(7141, 3)),
+  (0xF95F, Mapped(7144, 3)),
+  (0xF960, Mapped(7147, 3)),
+  (0xF961, Mapped(7150, 3)),
+  (0xF962, Mapped(7153, 3)),
+  (0xF963, Mapped(7156, 3)),
+  (0xF964, Mapped(7159, 3)),
+  (0xF965, Mapped(7162, 3)),
+  (0xF966, Mapped(7165, 3)),
+  (0xF967, Mapped(7168, 3)),
+  (0xF968, Mapped(7171, 3)),
+  (0xF969, Mapped(7174, 3)),
+  (0xF96A, Mapped(7177, 3)),
+  (0xF96B, Mapped(7180, 3)),
+  (0xF96C, Mapped(7183, 3)),
+  (0xF96D, Mapped(7186, 3)),
+  (0xF96E, Mapped(7189, 3)),
+  (0xF96F, Mapped(7192, 3)),
+  (0xF970, Mapped(7195, 3)),
+  (0xF971, Mapped(3562, 3)),
+  (0xF972, Mapped(7198, 3)),
+  (0xF973, Mapped(7201, 3)),
+  (0xF974, Mapped(7204, 3)),
+  (0xF975, Mapped(7207, 3)),
+  (0xF976, Mapped(7210, 3)),
+  (0xF977, Mapped(7213, 3)),
+  (0xF978, Mapped(7216, 3)),
+  (0xF979, Mapped(7219, 3)),
+  (0xF97A, Mapped(7222, 3)),
+  (0xF97B, Mapped(7225, 3)),
+  (0xF97C, Mapped(7228, 3)),
+  (0xF97D, Mapped(7231, 3)),
+  (0xF97E, Mapped(7234, 3)),
+  (0xF97F, Mapped(7237, 3)),
+  (0xF980, Mapped(7240, 3)),
+  (0xF981, Mapped(3193, 3)),
+  (0xF982, Mapped(7243, 3)),
+  (0xF983, Mapped(7246, 3)),
+  (0xF984, Mapped(7249, 3)),
+  (0xF985, Mapped(7252, 3)),
+  (0xF986, Mapped(7255, 3)),
+  (0xF987, Mapped(7258, 3)),
+  (0xF988, Mapped(7261, 3)),
+  (0xF989, Mapped(7264, 3)),
+  (0xF98A, Mapped(3136, 3)),
+  (0xF98B, Mapped(7267, 3)),
+  (0xF98C, Mapped(7270, 3)),
+  (0xF98D, Mapped(7273, 3)),
+  (0xF98E, Mapped(7276, 3)),
+  (0xF98F, Mapped(7279, 3)),
+  (0xF990, Mapped(7282, 3)),
+  (0xF991, Mapped(7285, 3)),
+  (0xF992, Mapped(7288, 3)),
+  (0xF993, Mapped(7291, 3)),
+  (0xF994, Mapped(7294, 3)),
+  (0xF995, Mapped(7297, 3)),
+  (0xF996, Mapped(7300, 3)),
+  (0xF997, Mapped(7303, 3)),
+  (0xF998, Mapped(7306, 3)),
+  (0xF999, Mapped(7309, 3)),
+  (0xF99A, Mapped(7312, 3)),
+  (0xF99B, Mapped(7315, 3)),
+  (0xF99C, Mapped(7318, 3)),
+  (0xF99D, Mapped(7321, 3)),
+  (0xF99E, Mapped(7324, 3)),
+  (0xF99F, Mapped(7327, 3)),
+  (0xF9A0, Mapped(7330, 3)),
+  (0xF9A1, Mapped(7192, 3)),
+  (0xF9A2, Mapped(7333, 3)),
+  (0xF9A3, Mapped(7336, 3)),
+  (0xF9A4, Mapped(7339, 3)),
+  (0xF9A5, Mapped(7342, 3)),
+  (0xF9A6, Mapped(7345, 3)),
+  (0xF9A7, Mapped(7348, 3)),
+  (0xF9A8, Mapped(7351, 3)),
+  (0xF9A9, Mapped(7354, 3)),
+  (0xF9AA, Mapped(7144, 3)),
+  (0xF9AB, Mapped(7357, 3)),
+  (0xF9AC, Mapped(7360, 3)),
+  (0xF9AD, Mapped(7363, 3)),
+  (0xF9AE, Mapped(7366, 3)),
+  (0xF9AF, Mapped(7369, 3)),
+  (0xF9B0, Mapped(7372, 3)),
+  (0xF9B1, Mapped(7375, 3)),
+  (0xF9B2, Mapped(7378, 3)),
+  (0xF9B3, Mapped(7381, 3)),
+  (0xF9B4, Mapped(7384, 3)),
+  (0xF9B5, Mapped(7387, 3)),
+  (0xF9B6, Mapped(7390, 3)),
+  (0xF9B7, Mapped(7393, 3)),
+  (0xF9B8, Mapped(7396, 3)),
+  (0xF9B9, Mapped(7399, 3)),
+  (0xF9BA, Mapped(7402, 3)),
+  (0xF9BB, Mapped(7405, 3)),
+  (0xF9BC, Mapped(7408, 3)),
+  (0xF9BD, Mapped(7411, 3)),
+  (0xF9BE, Mapped(7414, 3)),
+  (0xF9BF, Mapped(6928, 3)),
+  (0xF9C0, Mapped(7417, 3)),
+  (0xF9C1, Mapped(7420, 3)),
+  (0xF9C2, Mapped(7423, 3)),
+  (0xF9C3, Mapped(7426, 3)),
+  (0xF9C4, Mapped(3715, 3)),
+  (0xF9C5, Mapped(7429, 3)),
+  (0xF9C6, Mapped(7432, 3)),
+  (0xF9C7, Mapped(7435, 3)),
+  (0xF9C8, Mapped(7438, 3)),
+  (0xF9C9, Mapped(7441, 3)),
+  (0xF9CA, Mapped(7444, 3)),
+  (0xF9CB, Mapped(7447, 3)),
+  (0xF9CC, Mapped(7450, 3)),
+  (0xF9CD, Mapped(7453, 3)),
+  (0xF9CE, Mapped(7456, 3)),
+  (0xF9CF, Mapped(7459, 3)),
+  (0xF9D0, Mapped(7462, 3)),
+  (0xF9D1, Mapped(4506, 3)),
+  (0xF9D2, Mapped(7465, 3)),
+  (0xF9D3, Mapped(7468, 3)),
+  (0xF9D4, Mapped(7471, 3)),
+  (0xF9D5, Mapped(7474, 3)),
+  (0xF9D6, Mapped(7477, 3)),
+  (0xF9D7, Mapped(7480, 3)),
+  (0xF9D8, Mapped(7483, 3)),
+  (0xF9D9, Mapped(7486, 3)),
+  (0xF9DA, Mapped(7489, 3)),
+  (0xF9DB, Mapped(7150, 3)),
+  (0xF9DC, Mapped(7492, 3)),
+  (0xF9DD, Mapped(7495, 3)),
+  (0xF9DE, Mapped(7498, 3)),
+  (0xF9DF, Mapped(7501, 3)),
+  (0xF9E0, Mapped(7504, 3)),
+  (0xF9E1, Mapped(7507, 3)),
+  (0xF9E2, Mapped(7510, 3)),
+  (0xF9E3, Mapped(7513, 3)),
+  (0xF9E4, Mapped(7516, 3)),
+  (0xF9E5, Mapped(7519, 3)),
+  (0xF9E6, Mapped(7522, 3)),
+  (0xF9E7, Mapped(7525, 3)),
+  (0xF9E8, Mapped(7528, 3)),
+  (0xF9E9, Mapped(3577, 3)),
+  (0xF9EA, Mapped(7531, 3)),
+  (0xF9EB, Mapped(7534, 3)),
+  (0xF9EC, Mapped(7537, 3)),
+  (0xF9ED, Mapped(7540, 3)),
+  (0xF9EE, Mapped(7543, 3)),
+  (0xF9EF, Mapped(7546, 3)),
+  (0xF9F0, Mapped(7549, 3)),
+  (0xF9F1, Mapped(7552, 3)),
+  (0xF9F2, Mapped(7555, 3)),
+  (0xF9F3, Mapped(7558, 3)),
+  (0xF9F4, Mapped(7561, 3)),
+  (0xF9F5, Mapped(7564, 3)),
+  (0xF9F6, Mapped(7567, 3)),
+  (0xF9F7, Mapped(3430, 3)),
+  (0xF9F8, Mapped(7570, 3)),
+  (0xF9F9, Mapped(7573, 3)),
+  (0xF9FA, Mapped(7576, 3)),
+  (0xF9FB, Mapped(7579, 3)),
+  (0xF9FC, Mapped(7582, 3)),
+  (0xF9FD, Mapped(7585, 3)),
+  (0xF9FE, Mapped(7588, 3)),
+  (0xF9FF, Mapped(7591, 3)),
+  (0xFA00, Mapped(7594, 3)),
+  (0xFA01, Mapped(7597, 3)),
+  (0xFA02, Mapped(7600, 3)),
+  (0xFA03, Mapped(7603, 3)),
+  (0xFA04, Mapped(7606, 3)),
+  (0xFA05, Mapped(7609, 3)),
+  (0xFA06, Mapped(7612, 3)),
+  (0xFA07, Mapped(7615, 3)),
+  (0xFA08, Mapped(3511, 3)),
+  (0xFA09, Mapped(7618, 3)),
+  (0xFA0A, Mapped(3520, 3)),
+  (0xFA0B, Mapped(7621, 3)),
+  (0xFA0C, Mapped(7624, 3)),
+  (0xFA0D, Mapped(7627, 3)),
+  (0xFA0E, Valid),
+  (0xFA10, Mapped(7630, 3)),
+  (0xFA11, Valid),
+  (0xFA12, Mapped(7633, 3)),
+  (0xFA13, Valid),
+  (0xFA15, Mapped(7636, 3)),
+  (0xFA16, Mapped(7639, 3)),
+  (0xFA17, Mapped(7642, 3)),
+  (0xFA18, Mapped(7645, 3)),
+  (0xFA19, Mapped(7648, 3)),
+  (0xFA1A, Mapped(7651, 3)),
+  (0xFA1B, Mapped(7654, 3)),
+  (0xFA1C, Mapped(7657, 3)),
+  (0xFA1D, Mapped(7660, 3)),
+  (0xFA1E, Mapped(3451, 3)),
+  (0xFA1F, Valid),
+  (0xFA20, Mapped(7663, 3)),
+  (0xFA21, Valid),
+  (0xFA22, Mapped(7666, 3)),
+  (0xFA23, Valid),
+  (0xFA25, Mapped(7669, 3)),
+  (0xFA26, Mapped(7672, 3)),
+  (0xFA27, Valid),
+  (0xFA2A, Mapped(7675, 3)),
+  (0xFA2B, Mapped(7678, 3)),
+  (0xFA2C, Mapped(7681, 3)),
+  (0xFA2D, Mapped(7684, 3)),
+  (0xFA2E, Mapped(7687, 3)),
+  (0xFA2F, Mapped(7690, 3)),
+  (0xFA30, Mapped(7693, 3)),
+  (0xFA31, Mapped(7696, 3)),
+  (0xFA32, Mapped(7699, 3)),
+  (0xFA33, Mapped(7702, 3)),
+  (0xFA34, Mapped(7705, 3)),
+  (0xFA35, Mapped(7708, 3)),
+  (0xFA36, Mapped(7711, 3)),
+  (0xFA37, Mapped(7714, 3)),
+  (0xFA38, Mapped(7717, 3)),
+  (0xFA39, Mapped(7720, 3)),
+  (0xFA3A, Mapped(7723, 3)),
+  (0xFA3B, Mapped(7726, 3)),
+  (0xFA3C, Mapped(3214, 3)),
+  (0xFA3D, Mapped(7729, 3)),
+  (0xFA3E, Mapped(7732, 3)),
+  (0xFA3F, Mapped(7735, 3)),
+  (0xFA40, Mapped(7738, 3)),
+  (0xFA41, Mapped(7741, 3)),
+  (0xFA42, Mapped(7744, 3)),
+  (0xFA43, Mapped(7747, 3)),
+  (0xFA44, Mapped(7750, 3)),
+  (0xFA45, Mapped(7753, 3)),
+  (0xFA46, Mapped(7756, 3)),
+  (0xFA47, Mapped(7759, 3)),
+  (0xFA48, Mapped(7762, 3)),
+  (0xFA49, Mapped(7765, 3)),
+  (0xFA4A, Mapped(7768, 3)),
+  (0xFA4B, Mapped(7771, 3)),
+  (0xFA4C, Mapped(4521, 3)),
+  (0xFA4D, Mapped(7774, 3)),
+  (0xFA4E, Mapped(7777, 3)),
+  (0xFA4F, Mapped(7780, 3)),
+  (0xFA50, Mapped(7783, 3)),
+  (0xFA51, Mapped(4533, 3)),
+  (0xFA52, Mapped(7786, 3)),
+  (0xFA53, Mapped(7789, 3)),
+  (0xFA54, Mapped(7792, 3)),
+  (0xFA55, Mapped(7795, 3)),
+  (0xFA56, Mapped(7798, 3)),
+  (0xFA57, Mapped(7300, 3)),
+  (0xFA58, Mapped(7801, 3)),
+  (0xFA59, Mapped(7804, 3)),
+  (0xFA5A, Mapped(7807, 3)),
+  (0xFA5B, Mapped(7810, 3)),
+  (0xFA5C, Mapped(7813, 3)),
+  (0xFA5D, Mapped(7816, 3)),
+  (0xFA5F, Mapped(7819, 3)),
+  (0xFA60, Mapped(7822, 3)),
+  (0xFA61, Mapped(7825, 3)),
+  (0xFA62, Mapped(7828, 3)),
+  (0xFA63, Mapped(7831, 3)),
+  (0xFA64, Mapped(7834, 3)),
+  (0xFA65, Mapped(7837, 3)),
+  (0xFA66, Mapped(7840, 3)),
+  (0xFA67, Mapped(7669, 3)),
+  (0xFA68, Mapped(7843, 3)),
+  (0xFA69, Mapped(7846, 3)),
+  (0xFA6A, Mapped(7849, 3)),
+  (0xFA6B, Mapped(7852, 3)),
+  (0xFA6C, Mapped(7855, 4)),
+  (0xFA6D, Mapped(7859, 3)),
+  (0xFA6E, Disallowed),
+  (0xFA70, Mapped(7862, 3)),
+  (0xFA71, Mapped(7865, 3)),
+  (0xFA72, Mapped(7868, 3)),
+  (0xFA73, Mapped(7871, 3)),
+  (0xFA74, Mapped(7874, 3)),
+  (0xFA75, Mapped(7877, 3)),
+  (0xFA76, Mapped(7880, 3)),
+  (0xFA77, Mapped(7883, 3)),
+  (0xFA78, Mapped(7711, 3)),
+  (0xFA79, Mapped(7886, 3)),
+  (0xFA7A, Mapped(7889, 3)),
+  (0xFA7B, Mapped(7892, 3)),
+  (0xFA7C, Mapped(7630, 3)),
+  (0xFA7D, Mapped(7895, 3)),
+  (0xFA7E, Mapped(7898, 3)),
+  (0xFA7F, Mapped(7901, 3)),
+  (0xFA80, Mapped(7904, 3)),
+  (0xFA81, Mapped(7907, 3)),
+  (0xFA82, Mapped(7910, 3)),
+  (0xFA83, Mapped(7913, 3)),
+  (0xFA84, Mapped(7916, 3)),
+  (0xFA85, Mapped(7919, 3)),
+  (0xFA86, Mapped(7922, 3)),
+  (0xFA87, Mapped(7925, 3)),
+  (0xFA88, Mapped(7928, 3)),
+  (0xFA89, Mapped(7735, 3)),
+  (0xFA8A, Mapped(7931, 3)),
+  (0xFA8B, Mapped(7738, 3)),
+  (0xFA8C, Mapped(7934, 3)),
+  (0xFA8D, Mapped(7937, 3)),
+  (0xFA8E, Mapped(7940, 3)),
+  (0xFA8F, Mapped(7943, 3)),
+  (0xFA90, Mapped(7946, 3)),
+  (0xFA91, Mapped(7633, 3)),
+  (0xFA92, Mapped(6991, 3)),
+  (0xFA93, Mapped(7949, 3)),
+  (0xFA94, Mapped(7952, 3)),
+  (0xFA95, Mapped(3313, 3)),
+  (0xFA96, Mapped(7195, 3)),
+  (0xFA97, Mapped(7444, 3)),
+  (0xFA98, Mapped(7955, 3)),
+  (0xFA99, Mapped(7958, 3)),
+  (0xFA9A, Mapped(7759, 3)),
+  (0xFA9B, Mapped(7961, 3)),
+  (0xFA9C, Mapped(7762, 3)),
+  (0xFA9D, Mapped(7964, 3)),
+  (0xFA9E, Mapped(7967, 3)),
+  (0xFA9F, Mapped(7970, 3)),
+  (0xFAA0, Mapped(7639, 3)),
+  (0xFAA1, Mapped(7973, 3)),
+  (0xFAA2, Mapped(7976, 3)),
+  (0xFAA3, Mapped(7979, 3)),
+  (0xFAA4, Mapped(7982, 3)),
+  (0xFAA5, Mapped(7985, 3)),
+  (0xFAA6, Mapped(7642, 3)),
+  (0xFAA7, Mapped(7988, 3)),
+  (0xFAA8, Mapped(7991, 3)),
+  (0xFAA9, Mapped(7994, 3)),
+  (0xFAAA, Mapped(7997, 3)),
+  (0xFAAB, Mapped(8000, 3)),
+  (0xFAAC, Mapped(8003, 3)),
+  (0xFAAD, Mapped(7798, 3)),
+  (0xFAAE, Mapped(8006, 3)),
+  (0xFAAF, Mapped(8009, 3)),
+  (0xFAB0, Mapped(7300, 3)),
+  (0xFAB1, Mapped(8012, 3)),
+  (0xFAB2, Mapped(7810, 3)),
+  (0xFAB3, Mapped(8015, 3)),
+  (0xFAB4, Mapped(8018, 3)),
+  (0xFAB5, Mapped(8021, 3)),
+  (0xFAB6, Mapped(8024, 3)),
+  (0xFAB7, Mapped(8027, 3)),
+  (0xFAB8, Mapped(7825, 3)),
+  (0xFAB9, Mapped(8030, 3)),
+  (0xFABA, Mapped(7666, 3)),
+  (0xFABB, Mapped(8033, 3)),
+  (0xFABC, Mapped(7828, 3)),
+  (0xFABD, Mapped(7138, 3)),
+  (0xFABE, Mapped(8036, 3)),
+  (0xFABF, Mapped(7831, 3)),
+  (0xFAC0, Mapped(8039, 3)),
+  (0xFAC1, Mapped(7837, 3)),
+  (0xFAC2, Mapped(8042, 3)),
+  (0xFAC3, Mapped(8045, 3)),
+  (0xFAC4, Mapped(8048, 3)),
+  (0xFAC5, Mapped(8051, 3)),
+  (0xFAC6, Mapped(8054, 3)),
+  (0xFAC7, Mapped(7843, 3)),
+  (0xFAC8, Mapped(7657, 3)),
+  (0xFAC9, Mapped(8057, 3)),
+  (0xFACA, Mapped(7846, 3)),
+  (0xFACB, Mapped(8060, 3)),
+  (0xFACC, Mapped(7849, 3)),
+  (0xFACD, Mapped(8063, 3)),
+  (0xFACE, Mapped(3718, 3)),
+  (0xFACF, Mapped(8066, 4)),
+  (0xFAD0, Mapped(8070, 4)),
+  (0xFAD1, Mapped(8074, 4)),
+  (0xFAD2, Mapped(8078, 3)),
+  (0xFAD3, Mapped(8081, 3)),
+  (0xFAD4, Mapped(8084, 3)),
+  (0xFAD5, Mapped(8087, 4)),
+  (0xFAD6, Mapped(8091, 4)),
+  (0xFAD7, Mapped(8095, 4)),
+  (0xFAD8, Mapped(8099, 3)),
+  (0xFAD9, Mapped(8102, 3)),
+  (0xFADA, Disallowed),
+  (0xFB00, Mapped(8105, 2)),
+  (0xFB01, Mapped(8107, 2)),
+  (0xFB02, Mapped(8109, 2)),
+  (0xFB03, Mapped(8111, 3)),
+  (0xFB04, Mapped(8114, 3)),
+  (0xFB05, Mapped(8117, 2)),
+  (0xFB07, Disallowed),
+  (0xFB13, Mapped(8119, 4)),
+  (0xFB14, Mapped(8123, 4)),
+  (0xFB15, Mapped(8127, 4)),
+  (0xFB16, Mapped(8131, 4)),
+  (0xFB17, Mapped(8135, 4)),
+  (0xFB18, Disallowed),
+  (0xFB1D, Mapped(8139, 4)),
+  (0xFB1E, Valid),
+  (0xFB1F, Mapped(8143, 4)),
+  (0xFB20, Mapped(8147, 2)),
+  (0xFB21, Mapped(2390, 2)),
+  (0xFB22, Mapped(2396, 2)),
+  (0xFB23, Mapped(8149, 2)),
+  (0xFB24, Mapped(8151, 2)),
+  (0xFB25, Mapped(8153, 2)),
+  (0xFB26, Mapped(8155, 2)),
+  (0xFB27, Mapped(8157, 2)),
+  (0xFB28, Mapped(8159, 2)),
+  (0xFB29, DisallowedStd3Mapped(2354, 1)),
+  (0xFB2A, Mapped(8161, 4)),
+  (0xFB2B, Mapped(8165, 4)),
+  (0xFB2C, Mapped(8169, 6)),
+  (0xFB2D, Mapped(8175, 6)),
+  (0xFB2E, Mapped(8181, 4)),
+  (0xFB2F, Mapped(8185, 4)),
+  (0xFB30, Mapped(8189, 4)),
+  (0xFB31, Mapped(8193, 4)),
+  (0xFB32, Mapped(8197, 4)),
+  (0xFB33, Mapped(8201, 4)),
+  (0xFB34, Mapped(8205, 4)),
+  (0xFB35, Mapped(8209, 4)),
+  (0xFB36, Mapped(8213, 4)),
+  (0xFB37, Disallowed),
+  (0xFB38, Mapped(8217, 4)),
+  (0xFB39, Mapped(8221, 4)),
+  (0xFB3A, Mapped(8225, 4)),
+  (0xFB3B, Mapped(8229, 4)),
+  (0xFB3C, Mapped(8233, 4)),
+  (0xFB3D, Disallowed),
+  (0xFB3E, Mapped(8237, 4)),
+  (0xFB3F, Disallowed),
+  (0xFB40, Mapped(8241, 4)),
+  (0xFB41, Mapped(8245, 4)),
+  (0xFB42, Disallowed),
+  (0xFB43, Mapped(8249, 4)),
+  (0xFB44, Mapped(8253, 4)),
+  (0xFB45, Disallowed),
+  (0xFB46, Mapped(8257, 4)),
+  (0xFB47, Mapped(8261, 4)),
+  (0xFB48, Mapped(8265, 4)),
+  (0xFB49, Mapped(8269, 4)),
+  (0xFB4A, Mapped(8273, 4)),
+  (0xFB4B, Mapped(8277, 4)),
+  (0xFB4C, Mapped(8281, 4)),
+  (0xFB4D, Mapped(8285, 4)),
+  (0xFB4E, Mapped(8289, 4)),
+  (0xFB4F, Mapped(8293, 4)),
+  (0xFB50, Mapped(8297, 2)),
+  (0xFB52, Mapped(8299, 2)),
+  (0xFB56, Mapped(8301, 2)),
+  (0xFB5A, Mapped(8303, 2)),
+  (0xFB5E, Mapped(8305, 2)),
+  (0xFB62, Mapped(8307, 2)),
+  (0xFB66, Mapped(8309, 2)),
+  (0xFB6A, Mapped(8311, 2)),
+  (0xFB6E, Mapped(8313, 2)),
+  (0xFB72, Mapped(8315, 2)),
+  (0xFB76, Mapped(8317, 2)),
+  (0xFB7A, Mapped(8319, 2)),
+  (0xFB7E, Mapped(8321, 2)),
+  (0xFB82, Mapped(8323, 2)),
+  (0xFB84, Mapped(8325, 2)),
+  (0xFB86, Mapped(8327, 2)),
+  (0xFB88, Mapped(8329, 2)),
+  (0xFB8A, Mapped(8331, 2)),
+  (0xFB8C, Mapped(8333, 2)),
+  (0xFB8E, Mapped(8335, 2)),
+  (0xFB92, Mapped(8337, 2)),
+  (0xFB96, Mapped(8339, 2)),
+  (0xFB9A, Mapped(8341, 2)),
+  (0xFB9E, Mapped(8343, 2)),
+  (0xFBA0, Mapped(8345, 2)),
+  (0xFBA4, Mapped(8347, 2)),
+  (0xFBA6, Mapped(8349, 2)),
+  (0xFBAA, Mapped(8351, 2)),
+  (0xFBAE, Mapped(8353, 2)),
+  (0xFBB0, Mapped(8355, 2)),
+  (0xFBB2, Valid),
+  (0xFBC2, Disallowed),
+  (0xFBD3, Mapped(8357, 2)),
+  (0xFBD7, Mapped(8359, 2)),
+  (0xFBD9, Mapped(8361, 2)),
+  (0xFBDB, Mapped(8363, 2)),
+  (0xFBDD, Mapped(1002, 4)),
+  (0xFBDE, Mapped(8365, 2)),
+  (0xFBE0, Mapped(8367, 2)),
+  (0xFBE2, Mapped(8369, 2)),
+  (0xFBE4, Mapped(8371, 2)),
+  (0xFBE8, Mapped(8373, 2)),
+  (0xFBEA, Mapped(8375, 4)),
+  (0xFBEC, Mapped(8379, 4)),
+  (0xFBEE, Mapped(8383, 4)),
+  (0xFBF0, Mapped(8387, 4)),
+  (0xFBF2, Mapped(8391, 4)),
+  (0xFBF4, Mapped(8395, 4)),
+  (0xFBF6, Mapped(8399, 4)),
+  (0xFBF9, Mapped(8403, 4)),
+  (0xFBFC, Mapped(8407, 2)),
+  (0xFC00, Mapped(8409, 4)),
+  (0xFC01, Mapped(8413, 4)),
+  (0xFC02, Mapped(8417, 4)),
+  (0xFC03, Mapped(8403, 4)),
+  (0xFC04, Mapped(8421, 4)),
+  (0xFC05, Mapped(8425, 4)),
+  (0xFC06, Mapped(8429, 4)),
+  (0xFC07, Mapped(8433, 4)),
+  (0xFC08, Mapped(8437, 4)),
+  (0xFC09, Mapped(8441, 4)),
+  (0xFC0A, Mapped(8445, 4)),
+  (0xFC0B, Mapped(8449, 4)),
+  (0xFC0C, Mapped(8453, 4)),
+  (0xFC0D, Mapped(8457, 4)),
+  (0xFC0E, Mapped(8461, 4)),
+  (0xFC0F, Mapped(8465, 4)),
+  (0xFC10, Mapped(8469, 4)),
+  (0xFC11, Mapped(8473, 4)),
+  (0xFC12, Mapped(8477, 4)),
+  (0xFC13, Mapped(8481, 4)),
+  (0xFC14, Mapped(8485, 4)),
+  (0xFC15, Mapped(8489, 4)),
+  (0xFC16, Mapped(8493, 4)),
+  (0xFC17, Mapped(8497, 4)),
+  (0xFC18, Mapped(8501, 4)),
+  (0xFC19, Mapped(8505, 4)),
+  (0xFC1A, Mapped(8509, 4)),
+  (0xFC1B, Mapped(8513, 4)),
+  (0xFC1C, Mapped(8517, 4)),
+  (0xFC1D, Mapped(8521, 4)),
+  (0xFC1E, Mapped(8525, 4)),
+  (0xFC1F, Mapped(8529, 4)),
+  (0xFC20, Mapped(8533, 4)),
+  (0xFC21, Mapped(8537, 4)),
+  (0xFC22, Mapped(8541, 4)),
+  (0xFC23, Mapped(8545, 4)),
+  (0xFC24, Mapped(8549, 4)),
+  (0xFC25, Mapped(8553, 4)),
+  (0xFC26, Mapped(8557, 4)),
+  (0xFC27, Mapped(8561, 4)),
+  (0xFC28, Mapped(8565, 4)),
+  (0xFC29, Mapped(8569, 4)),
+  (0xFC2A, Mapped(8573, 4)),
+  (0xFC2B, Mapped(8577, 4)),
+  (0xFC2C, Mapped(8581, 4)),
+  (0xFC2D, Mapped(8585, 4)),
+  (0xFC2E, Mapped(8589, 4)),
+  (0xFC2F, Mapped(8593, 4)),
+  (0xFC30, Mapped(8597, 4)),
+  (0xFC31, Mapped(8601, 4)),
+  (0xFC32, Mapped(8605, 4)),
+  (0xFC33, Mapped(8609, 4)),
+  (0xFC34, Mapped(8613, 4)),
+  (0xFC35, Mapped(8617, 4)),
+  (0xFC36, Mapped(8621, 4)),
+  (0xFC37, Mapped(8625, 4)),
+  (0xFC38, Mapped(8629, 4)),
+  (0xFC39, Mapped(8633, 4)),
+  (0xFC3A, Mapped(8637, 4)),
+  (0xFC3B, Mapped(8641, 4)),
+  (0xFC3C, Mapped(8645, 4)),
+  (0xFC3D, Mapped(8649, 4)),
+  (0xFC3E, Mapped(8653, 4)),
+  (0xFC3F, Mapped(8657, 4)),
+  (0xFC40, Mapped(8661, 4)),
+  (0xFC41, Mapped(8665, 4)),
+  (0xFC42, Mapped(8669, 4)),
+  (0xFC43, Mapped(8673, 4)),
+  (0xFC44, Mapped(8677, 4)),
+  (0xFC45, Mapped(8681, 4)),
+  (0xFC46, Mapped(8685, 4)),
+  (0xFC47, Mapped(8689, 4)),
+  (0xFC48, Mapped(8693, 4)),
+  (0xFC49, Mapped(8697, 4)),
+  (0xFC4A, Mapped(8701, 4)),
+  (0xFC4B, Mapped(8705, 4)),
+  (0xFC4C, Mapped(8709, 4)),
+  (0xFC4D, Mapped(8713, 4)),
+  (0xFC4E, Mapped(8717, 4)),
+  (0xFC4F, Mapped(8721, 4)),
+  (0xFC50, Mapped(8725, 4)),
+  (0xFC51, Mapped(8729, 4)),
+  (0xFC52, Mapped(8733, 4)),
+  (0xFC53, Mapped(8737, 4)),
+  (0xFC54, Mapped(8741, 4)),
+  (0xFC55, Mapped(8745, 4)),
+  (0xFC56, Mapped(8749, 4)),
+  (0xFC57, Mapped(8753, 4)),
+  (0xFC58, Mapped(8757, 4)),
+  (0xFC59, Mapped(8761, 4)),
+  (0xFC5A, Mapped(8765, 4)),
+  (0xFC5B, Mapped(8769, 4)),
+  (0xFC5C, Mapped(8773, 4)),
+  (0xFC5D, Mapped(8777, 4)),
+  (0xFC5E, DisallowedStd3Mapped(8781, 5)),
+  (0xFC5F, DisallowedStd3Mapped(8786, 5)),
+  (0xFC60, DisallowedStd3Mapped(8791, 5)),
+  (0xFC61, DisallowedStd3Mapped(8796, 5)),
+  (0xFC62, DisallowedStd3Mapped(8801, 5)),
+  (0xFC63, DisallowedStd3Mapped(8806, 5)),
+  (0xFC64, Mapped(8811, 4)),
+  (0xFC65, Mapped(8815, 4)),
+  (0xFC66, Mapped(8417, 4)),
+  (0xFC67, Mapped(8819, 4)),
+  (0xFC68, Mapped(8403, 4)),
+  (0xFC69, Mapped(8421, 4)),
+  (0xFC6A, Mapped(8823, 4)),
+  (0xFC6B, Mapped(8827, 4)),
+  (0xFC6C, Mapped(8437, 4)),
+  (0xFC6D, Mapped(8831, 4)),
+  (0xFC6E, Mapped(8441, 4)),
+  (0xFC6F, Mapped(8445, 4)),
+  (0xFC70, Mapped(8835, 4)),
+  (0xFC71, Mapped(8839, 4)),
+  (0xFC72, Mapped(8461, 4)),
+  (0xFC73, Mapped(8843, 4)),
+  (0xFC74, Mapped(8465, 4)),
+  (0xFC75, Mapped(8469, 4)),
+  (0xFC76, Mapped(8847, 4)),
+  (0xFC77, Mapped(8851, 4)),
+  (0xFC78, Mapped(8477, 4)),
+  (0xFC79, Mapped(8855, 4)),
+  (0xFC7A, Mapped(8481, 4)),
+  (0xFC7B, Mapped(8485, 4)),
+  (0xFC7C, Mapped(8601, 4)),
+  (0xFC7D, Mapped(8605, 4)),
+  (0xFC7E, Mapped(8617, 4)),
+  (0xFC7F, Mapped(8621, 4)),
+  (0xFC80, Mapped(8625, 4)),
+  (0xFC81, Mapped(8641, 4)),
+  (0xFC82, Mapped(8645, 4)),
+  (0xFC83, Mapped(8649, 4)),
+  (0xFC84, Mapped(8653, 4)),
+  (0xFC85, Mapped(8669, 4)),
+  (0xFC86, Mapped(8673, 4)),
+  (0xFC87, Mapped(8677, 4)),
+  (0xFC88, Mapped(8859, 4)),
+  (0xFC89, Mapped(8693, 4)),
+  (0xFC8A, Mapped(8863, 4)),
+  (0xFC8B, Mapped(8867, 4)),
+  (0xFC8C, Mapped(8717, 4)),
+  (0xFC8D, Mapped(8871, 4)),
+  (0xFC8E, Mapped(8721, 4)),
+  (0xFC8F, Mapped(8725, 4)),
+  (0xFC90, Mapped(8777, 4)),
+  (0xFC91, Mapped(8875, 4)),
+  (0xFC92, Mapped(8879, 4)),
+  (0xFC93, Mapped(8757, 4)),
+  (0xFC94, Mapped(8883, 4)),
+  (0xFC95, Mapped(8761, 4)),
+  (0xFC96, Mapped(8765, 4)),
+  (0xFC97, Mapped(8409, 4)),
+  (0xFC98, Mapped(8413, 4)),
+  (0xFC99, Mapped(8887, 4)),
+  (0xFC9A, Mapped(8417, 4)),
+  (0xFC9B, Mapped(8891, 4)),
+  (0xFC9C, Mapped(8425, 4)),
+  (0xFC9D, Mapped(8429, 4)),
+  (0xFC9E, Mapped(8433, 4)),
+  (0xFC9F, Mapped(8437, 4)),
+  (0xFCA0, Mapped(8895, 4)),
+  (0xFCA1, Mapped(8449, 4)),
+  (0xFCA2, Mapped(8453, 4)),
+  (0xFCA3, Mapped(8457, 4)),
+  (0xFCA4, Mapped(8461, 4)),
+  (0xFCA5, Mapped(8899, 4)),
+  (0xFCA6, Mapped(8477, 4)),
+  (0xFCA7, Mapped(8489, 4)),
+  (0xFCA8, Mapped(8493, 4)),
+  (0xFCA9, Mapped(8497, 4)),
+  (0xFCAA, Mapped(8501, 4)),
+  (0xFCAB, Mapped(8505, 4)),
+  (0xFCAC, Mapped(8513, 4)),
+  (0xFCAD, Mapped(8517, 4)),
+  (0xFCAE, Mapped(8521, 4)),
+  (0xFCAF, Mapped(8525, 4)),
+  (0xFCB0, Mapped(8529, 4)),
+  (0xFCB1, Mapped(8533, 4)),
+  (0xFCB2, Mapped(8903, 4)),
+  (0xFCB3, Mapped(8537, 4)),
+  (0xFCB4, Mapped(8541, 4)),
+  (0xFCB5, Mapped(8545, 4)),
+  (0xFCB6, Mapped(8549, 4)),
+  (0xFCB7, Mapped(8553, 4)),
+  (0xFCB8, Mapped(8557, 4)),
+  (0xFCB9, Mapped(8565, 4)),
+  (0xFCBA, Mapped(8569, 4)),
+  (0xFCBB, Mapped(8573, 4)),
+  (0xFCBC, Mapped(8577, 4)),
+  (0xFCBD, Mapped(8581, 4)),
+  (0xFCBE, Mapped(8585, 4)),
+  (0xFCBF, Mapped(8589, 4)),
+  (0xFCC0, Mapped(8593, 4)),
+  (0xFCC1, Mapped(8597, 4)),
+  (0xFCC2, Mapped(8609, 4)),
+  (0xFCC3, Mapped(8613, 4)),
+  (0xFCC4, Mapped(8629, 4)),
+  (0xFCC5, Mapped(8633, 4)),
+  (0xFCC6, Mapped(8637, 4)),
+  (0xFCC7, Mapped(8641, 4)),
+  (0xFCC8, Mapped(8645, 4)),
+  (0xFCC9, Mapped(8657, 4)),
+  (0xFCCA, Mapped(8661, 4)),
+  (0xFCCB, Mapped(8665, 4)),
+  (0xFCCC, Mapped(8669, 4)),
+  (0xFCCD, Mapped(8907, 4)),
+  (0xFCCE, Mapped(8681, 4)),
+  (0xFCCF, Mapped(8685, 4)),
+  (0xFCD0, Mapped(8689, 4)),
+  (0xFCD1, Mapped(8693, 4)),
+  (0xFCD2, Mapped(8705, 4)),
+  (0xFCD3, Mapped(8709, 4)),
+  (0xFCD4, Mapped(8713, 4)),
+  (0xFCD5, Mapped(8717, 4)),
+  (0xFCD6, Mapped(8911, 4)),
+  (0xFCD7, Mapped(8729, 4)),
+  (0xFCD8, Mapped(8733, 4)),
+  (0xFCD9, Mapped(8915, 4)),
+  (0xFCDA, Mapped(8745, 4)),
+  (0xFCDB, Mapped(8749, 4)),
+  (0xFCDC, Mapped(8753, 4)),
+  (0xFCDD, Mapped(8757, 4)),
+  (0xFCDE, Mapped(8919, 4)),
+  (0xFCDF, Mapped(8417, 4)),
+  (0xFCE0, Mapped(8891, 4)),
+  (0xFCE1, Mapped(8437, 4)),
+  (0xFCE2, Mapped(8895, 4)),
+  (0xFCE3, Mapped(8461, 4)),
+  (0xFCE4, Mapped(8899, 4)),
+  (0xFCE5, Mapped(8477, 4)),
+  (0xFCE6, Mapped(8923, 4)),
+  (0xFCE7, Mapped(8529, 4)),
+  (0xFCE8, Mapped(8927, 4)),
+  (0xFCE9, Mapped(8931, 4)),
+  (0xFCEA, Mapped(8935, 4)),
+  (0xFCEB, Mapped(8641, 4)),
+  (0xFCEC, Mapped(8645, 4)),
+  (0xFCED, Mapped(8669, 4)),
+  (0xFCEE, Mapped(8717, 4)),
+  (0xFCEF, Mapped(8911, 4)),
+  (0xFCF0, Mapped(8757, 4)),
+  (0xFCF1, Mapped(8919, 4)),
+  (0xFCF2, Mapped(8939, 6)),
+  (0xFCF3, Mapped(8945, 6)),
+  (0xFCF4, Mapped(8951, 6)),
+  (0xFCF5, Mapped(8957, 4)),
+  (0xFCF6, Mapped(8961, 4)),
+  (0xFCF7, Mapped(8965, 4)),
+  (0xFCF8, Mapped(8969, 4)),
+  (0xFCF9, Mapped(8973, 4)),
+  (0xFCFA, Mapped(8977, 4)),
+  (0xFCFB, Mapped(8981, 4)),
+  (0xFCFC, Mapped(8985, 4)),
+  (0xFCFD, Mapped(8989, 4)),
+  (0xFCFE, Mapped(8993, 4)),
+  (0xFCFF, Mapped(8997, 4)),
+  (0xFD00, Mapped(9001, 4)),
+  (0xFD01, Mapped(9005, 4)),
+  (0xFD02, Mapped(9009, 4)),
+  (0xFD03, Mapped(9013, 4)),
+  (0xFD04, Mapped(9017, 4)),
+  (0xFD05, Mapped(9021, 4)),
+  (0xFD06, Mapped(9025, 4)),
+  (0xFD07, Mapped(9029, 4)),
+  (0xFD08, Mapped(9033, 4)),
+  (0xFD09, Mapped(9037, 4)),
+  (0xFD0A, Mapped(9041, 4)),
+  (0xFD0B, Mapped(9045, 4)),
+  (0xFD0C, Mapped(8931, 4)),
+  (0xFD0D, Mapped(9049, 4)),
+  (0xFD0E, Mapped(9053, 4)),
+  (0xFD0F, Mapped(9057, 4)),
+  (0xFD10, Mapped(9061, 4)),
+  (0xFD11, Mapped(8957, 4)),
+  (0xFD12, Mapped(8961, 4)),
+  (0xFD13, Mapped(8965, 4)),
+  (0xFD14, Mapped(8969, 4)),
+  (0xFD15, Mapped(8973, 4)),
+  (0xFD16, Mapped(8977, 4)),
+  (0xFD17, Mapped(8981, 4)),
+  (0xFD18, Mapped(8985, 4)),
+  (0xFD19, Mapped(8989, 4)),
+  (0xFD1A, Mapped(8993, 4)),
+  (0xFD1B, Mapped(8997, 4)),
+  (0xFD1C, Mapped(9001, 4)),
+  (0xFD1D, Mapped(9005, 4)),
+  (0xFD1E, Mapped(9009, 4)),
+  (0xFD1F, Mapped(9013, 4)),
+  (0xFD20, Mapped(9017, 4)),
+  (0xFD21, Mapped(9021, 4)),
+  (0xFD22, Mapped(9025, 4)),
+  (0xFD23, Mapped(9029, 4)),
+  (0xFD24, Mapped(9033, 4)),
+  (0xFD25, Mapped(9037, 4)),
+  (0xFD26, Mapped(9041, 4)),
+  (0xFD27, Mapped(9045, 4)),
+  (0xFD28, Mapped(8931, 4)),
+  (0xFD29, Mapped(9049, 4)),
+  (0xFD2A, Mapped(9053, 4)),
+  (0xFD2B, Mapped(9057, 4)),
+  (0xFD2C, Mapped(9061, 4)),
+  (0xFD2D, Mapped(9037, 4)),
+  (0xFD2E, Mapped(9041, 4)),
+  (0xFD2F, Mapped(9045, 4)),
+  (0xFD30, Mapped(8931, 4)),
+  (0xFD31, Mapped(8927, 4)),
+  (0xFD32, Mapped(8935, 4)),
+  (0xFD33, Mapped(8561, 4)),
+  (0xFD34, Mapped(8517, 4)),
+  (0xFD35, Mapped(8521, 4)),
+  (0xFD36, Mapped(8525, 4)),
+  (0xFD37, Mapped(9037, 4)),
+  (0xFD38, Mapped(9041, 4)),
+  (0xFD39, Mapped(9045, 4)),
+  (0xFD3A, Mapped(8561, 4)),
+  (0xFD3B, Mapped(8565, 4)),
+  (0xFD3C, Mapped(9065, 4)),
+  (0xFD3E, Valid),
+  (0xFD40, Disallowed),
+  (0xFD50, Mapped(9069, 6)),
+  (0xFD51, Mapped(9075, 6)),
+  (0xFD53, Mapped(9081, 6)),
+  (0xFD54, Mapped(9087, 6)),
+  (0xFD55, Mapped(9093, 6)),
+  (0xFD56, Mapped(9099, 6)),
+  (0xFD57, Mapped(9105, 6)),
+  (0xFD58, Mapped(9111, 6)),
+  (0xFD5A, Mapped(9117, 6)),
+  (0xFD5B, Mapped(9123, 6)),
+  (0xFD5C, Mapped(9129, 6)),
+  (0xFD5D, Mapped(9135, 6)),
+  (0xFD5E, Mapped(9141, 6)),
+  (0xFD5F, Mapped(9147, 6)),
+  (0xFD61, Mapped(9153, 6)),
+  (0xFD62, Mapped(9159, 6)),
+  (0xFD64, Mapped(9165, 6)),
+  (0xFD66, Mapped(9171, 6)),
+  (0xFD67, Mapped(9177, 6)),
+  (0xFD69, Mapped(9183, 6)),
+  (0xFD6A, Mapped(9189, 6)),
+  (0xFD6C, Mapped(9195, 6)),
+  (0xFD6E, Mapped(9201, 6)),
+  (0xFD6F, Mapped(9207, 6)),
+  (0xFD71, Mapped(9213, 6)),
+  (0xFD73, Mapped(9219, 6)),
+  (0xFD74, Mapped(9225, 6)),
+  (0xFD75, Mapped(9231, 6)),
+  (0xFD76, Mapped(9237, 6)),
+  (0xFD78, Mapped(9243, 6)),
+  (0xFD79, Mapped(9249, 6)),
+  (0xFD7A, Mapped(9255, 6)),
+  (0xFD7B, Mapped(9261, 6)),
+  (0xFD7C, Mapped(9267, 6)),
+  (0xFD7E, Mapped(9273, 6)),
+  (0xFD7F, Mapped(9279, 6)),
+  (0xFD80, Mapped(9285, 6)),
+  (0xFD81, Mapped(9291, 6)),
+  (0xFD82, Mapped(9297, 6)),
+  (0xFD83, Mapped(9303, 6)),
+  (0xFD85, Mapped(9309, 6)),
+  (0xFD87, Mapped(9315, 6)),
+  (0xFD89, Mapped(9321, 6)),
+  (0xFD8A, Mapped(9327, 6)),
+  (0xFD8B, Mapped(9333, 6)),
+  (0xFD8C, Mapped(9339, 6)),
+  (0xFD8D, Mapped(9345, 6)),
+  (0xFD8E, Mapped(9351, 6)),
+  (0xFD8F, Mapped(9357, 6)),
+  (0xFD90, Disallowed),
+  (0xFD92, Mapped(9363, 6)),
+  (0xFD93, Mapped(9369, 6)),
+  (0xFD94, Mapped(9375, 6)),
+  (0xFD95, Mapped(9381, 6)),
+  (0xFD96, Mapped(9387, 6)),
+  (0xFD97, Mapped(9393, 6)),
+  (0xFD99, Mapped(9399, 6)),
+  (0xFD9A, Mapped(9405, 6)),
+  (0xFD9B, Mapped(9411, 6)),
+  (0xFD9C, Mapped(9417, 6)),
+  (0xFD9E, Mapped(9423, 6)),
+  (0xFD9F, Mapped(9429, 6)),
+  (0xFDA0, Mapped(9435, 6)),
+  (0xFDA1, Mapped(9441, 6)),
+  (0xFDA2, Mapped(9447, 6)),
+  (0xFDA3, Mapped(9453, 6)),
+  (0xFDA4, Mapped(9459, 6)),
+  (0xFDA5, Mapped(9465, 6)),
+  (0xFDA6, Mapped(9471, 6)),
+  (0xFDA7, Mapped(9477, 6)),
+  (0xFDA8, Mapped(9483, 6)),
+  (0xFDA9, Mapped(9489, 6)),
+  (0xFDAA, Mapped(9495, 6)),
+  (0xFDAB, Mapped(9501, 6)),
+  (0xFDAC, Mapped(9507, 6)),
+  (0xFDAD, Mapped(9513, 6)),
+  (0xFDAE, Mapped(9519, 6)),
+  (0xFDAF, Mapped(9525, 6)),
+  (0xFDB0, Mapped(9531, 6)),
+  (0xFDB1, Mapped(9537, 6)),
+  (0xFDB2, Mapped(9543, 6)),
+  (0xFDB3, Mapped(9549, 6)),
+  (0xFDB4, Mapped(9273, 6)),
+  (0xFDB5, Mapped(9285, 6)),
+  (0xFDB6, Mapped(9555, 6)),
+  (0xFDB7, Mapped(9561, 6)),
+  (0xFDB8, Mapped(9567, 6)),
+  (0xFDB9, Mapped(9573, 6)),
+  (0xFDBA, Mapped(9579, 6)),
+  (0xFDBB, Mapped(9585, 6)),
+  (0xFDBC, Mapped(9579, 6)),
+  (0xFDBD, Mapped(9567, 6)),
+  (0xFDBE, Mapped(9591, 6)),
+  (0xFDBF, Mapped(9597, 6)),
+  (0xFDC0, Mapped(9603, 6)),
+  (0xFDC1, Mapped(9609, 6)),
+  (0xFDC2, Mapped(9615, 6)),
+  (0xFDC3, Mapped(9585, 6)),
+  (0xFDC4, Mapped(9231, 6)),
+  (0xFDC5, Mapped(9171, 6)),
+  (0xFDC6, Mapped(9621, 6)),
+  (0xFDC7, Mapped(9627, 6)),
+  (0xFDC8, Disallowed),
+  (0xFDF0, Mapped(9633, 6)),
+  (0xFDF1, Mapped(9639, 6)),
+  (0xFDF2, Mapped(9645, 8)),
+  (0xFDF3, Mapped(9653, 8)),
+  (0xFDF4, Mapped(9661, 8)),
+  (0xFDF5, Mapped(9669, 8)),
+  (0xFDF6, Mapped(9677, 8)),
+  (0xFDF7, Mapped(9685, 8)),
+  (0xFDF8, Mapped(9693, 8)),
+  (0xFDF9, Mapped(9701, 6)),
+  (0xFDFA, DisallowedStd3Mapped(9707, 33)),
+  (0xFDFB, DisallowedStd3Mapped(9740, 15)),
+  (0xFDFC, Mapped(9755, 8)),
+  (0xFDFD, Valid),
+  (0xFDFE, Disallowed),
+  (0xFE00, Ignored),
+  (0xFE10, DisallowedStd3Mapped(9763, 1)),
+  (0xFE11, Mapped(9764, 3)),
+  (0xFE12, Disallowed),
+  (0xFE13, DisallowedStd3Mapped(9767, 1)),
+  (0xFE14, DisallowedStd3Mapped(512, 1)),
+  (0xFE15, DisallowedStd3Mapped(9768, 1)),
+  (0xFE16, DisallowedStd3Mapped(9769, 1)),
+  (0xFE17, Mapped(9770, 3)),
+  (0xFE18, Mapped(9773, 3)),
+  (0xFE19, Disallowed),
+  (0xFE20, Valid),
+  (0xFE30, Disallowed),
+  (0xFE31, Mapped(9776, 3)),
+  (0xFE32, Mapped(9779, 3)),
+  (0xFE33, DisallowedStd3Mapped(9782, 1)),
+  (0xFE35, DisallowedStd3Mapped(2359, 1)),
+  (0xFE36, DisallowedStd3Mapped(2360, 1)),
+  (0xFE37, DisallowedStd3Mapped(9783, 1)),
+  (0xFE38, DisallowedStd3Mapped(9784, 1)),
+  (0xFE39, Mapped(9785, 3)),
+  (0xFE3A, Mapped(9788, 3)),
+  (0xFE3B, Mapped(9791, 3)),
+  (0xFE3C, Mapped(9794, 3)),
+  (0xFE3D, Mapped(9797, 3)),
+  (0xFE3E, Mapped(9800, 3)),
+  (0xFE3F, Mapped(2542, 3)),
+  (0xFE40, Mapped(2545, 3)),
+  (0xFE41, Mapped(9803, 3)),
+  (0xFE42, Mapped(9806, 3)),
+  (0xFE43, Mapped(9809, 3)),
+  (0xFE44, Mapped(9812, 3)),
+  (0xFE45, Valid),
+  (0xFE47, DisallowedStd3Mapped(9815, 1)),
+  (0xFE48, DisallowedStd3Mapped(9816, 1)),
+  (0xFE49, DisallowedStd3Mapped(2326, 3)),
+  (0xFE4D, DisallowedStd3Mapped(9782, 1)),
+  (0xFE50, DisallowedStd3Mapped(9763, 1)),
+  (0xFE51, Mapped(9764, 3)),
+  (0xFE52, Disallowed),
+  (0xFE54, DisallowedStd3Mapped(512, 1)),
+  (0xFE55, DisallowedStd3Mapped(9767, 1)),
+  (0xFE56, DisallowedStd3Mapped(9769, 1)),
+  (0xFE57, DisallowedStd3Mapped(9768, 1)),
+  (0xFE58, Mapped(9776, 3)),
+  (0xFE59, DisallowedStd3Mapped(2359, 1)),
+  (0xFE5A, DisallowedStd3Mapped(2360, 1)),
+  (0xFE5B, DisallowedStd3Mapped(9783, 1)),
+  (0xFE5C, DisallowedStd3Mapped(9784, 1)),
+  (0xFE5D, Mapped(9785, 3)),
+  (0xFE5E, Mapped(9788, 3)),
+  (0xFE5F, DisallowedStd3Mapped(9817, 1)),
+  (0xFE60, DisallowedStd3Mapped(9818, 1)),
+  (0xFE61, DisallowedStd3Mapped(9819, 1)),
+  (0xFE62, DisallowedStd3Mapped(2354, 1)),
+  (0xFE63, Mapped(9820, 1)),
+  (0xFE64, DisallowedStd3Mapped(9821, 1)),
+  (0xFE65, DisallowedStd3Mapped(9822, 1)),
+  (0xFE66, DisallowedStd3Mapped(2358, 1)),
+  (0xFE67, Disallowed),
+  (0xFE68, DisallowedStd3Mapped(9823, 1)),
+  (0xFE69, DisallowedStd3Mapped(9824, 1)),
+  (0xFE6A, DisallowedStd3Mapped(9825, 1)),
+  (0xFE6B, DisallowedStd3Mapped(9826, 1)),
+  (0xFE6C, Disallowed),
+  (0xFE70, DisallowedStd3Mapped(9827, 3)),
+  (0xFE71, Mapped(9830, 4)),
+  (0xFE72, DisallowedStd3Mapped(9834, 3)),
+  (0xFE73, Valid),
+  (0xFE74, DisallowedStd3Mapped(9837, 3)),
+  (0xFE75, Disallowed),
+  (0xFE76, DisallowedStd3Mapped(9840, 3)),
+  (0xFE77, Mapped(9843, 4)),
+  (0xFE78, DisallowedStd3Mapped(9847, 3)),
+  (0xFE79, Mapped(9850, 4)),
+  (0xFE7A, DisallowedStd3Mapped(9854, 3)),
+  (0xFE7B, Mapped(9857, 4)),
+  (0xFE7C, DisallowedStd3Mapped(9861, 3)),
+  (0xFE7D, Mapped(9864, 4)),
+  (0xFE7E, DisallowedStd3Mapped(9868, 3)),
+  (0xFE7F, Mapped(9871, 4)),
+  (0xFE80, Mapped(9875, 2)),
+  (0xFE81, Mapped(9877, 2)),
+  (0xFE83, Mapped(9879, 2)),
+  (0xFE85, Mapped(9881, 2)),
+  (0xFE87, Mapped(9883, 2)),
+  (0xFE89, Mapped(9885, 2)),
+  (0xFE8D, Mapped(9887, 2)),
+  (0xFE8F, Mapped(9889, 2)),
+  (0xFE93, Mapped(9891, 2)),
+  (0xFE95, Mapped(9893, 2)),
+  (0xFE99, Mapped(9895, 2)),
+  (0xFE9D, Mapped(9897, 2)),
+  (0xFEA1, Mapped(9899, 2)),
+  (0xFEA5, Mapped(9901, 2)),
+  (0xFEA9, Mapped(9903, 2)),
+  (0xFEAB, Mapped(9905, 2)),
+  (0xFEAD, Mapped(9907, 2)),
+  (0xFEAF, Mapped(9909, 2)),
+  (0xFEB1, Mapped(9911, 2)),
+  (0xFEB5, Mapped(9913, 2)),
+  (0xFEB9, Mapped(9915, 2)),
+  (0xFEBD, Mapped(9917, 2)),
+  (0xFEC1, Mapped(9919, 2)),
+  (0xFEC5, Mapped(9921, 2)),
+  (0xFEC9, Mapped(9923, 2)),
+  (0xFECD, Mapped(9925, 2)),
+  (0xFED1, Mapped(9927, 2)),
+  (0xFED5, Mapped(9929, 2)),
+  (0xFED9, Mapped(9931, 2)),
+  (0xFEDD, Mapped(9933, 2)),
+  (0xFEE1, Mapped(9935, 2)),
+  (0xFEE5, Mapped(9937, 2)),
+  (0xFEE9, Mapped(9939, 2)),
+  (0xFEED, Mapped(9941, 2)),
+  (0xFEEF, Mapped(8373, 2)),
+  (0xFEF1, Mapped(9943, 2)),
+  (0xFEF5, Mapped(9945, 4)),
+  (0xFEF7, Mapped(9949, 4)),
+  (0xFEF9, Mapped(9953, 4)),
+  (0xFEFB, Mapped(9957, 4)),
+  (0xFEFD, Disallowed),
+  (0xFEFF, Ignored),
+  (0xFF00, Disallowed),
+  (0xFF01, DisallowedStd3Mapped(9768, 1)),
+  (0xFF02, DisallowedStd3Mapped(9961, 1)),
+  (0xFF03, DisallowedStd3Mapped(9817, 1)),
+  (0xFF04, DisallowedStd3Mapped(9824, 1)),
+  (0xFF05, DisallowedStd3Mapped(9825, 1)),
+  (0xFF06, DisallowedStd3Mapped(9818, 1)),
+  (0xFF07, DisallowedStd3Mapped(9962, 1)),
+  (0xFF08, DisallowedStd3Mapped(2359, 1)),
+  (0xFF09, DisallowedStd3Mapped(2360, 1)),
+  (0xFF0A, DisallowedStd3Mapped(9819, 1)),
+  (0xFF0B, DisallowedStd3Mapped(2354, 1)),
+  (0xFF0C, DisallowedStd3Mapped(9763, 1)),
+  (0xFF0D, Mapped(9820, 1)),
+  (0xFF0E, Mapped(3724, 1)),
+  (0xFF0F, DisallowedStd3Mapped(9963, 1)),
+  (0xFF10, Mapped(2347, 1)),
+  (0xFF11, Mapped(43, 1)),
+  (0xFF12, Mapped(33, 1)),
+  (0xFF13, Mapped(34, 1)),
+  (0xFF14, Mapped(2348, 1)),
+  (0xFF15, Mapped(2349, 1)),
+  (0xFF16, Mapped(2350, 1)),
+  (0xFF17, Mapped(2351, 1)),
+  (0xFF18, Mapped(2352, 1)),
+  (0xFF19, Mapped(2353, 1)),
+  (0xFF1A, DisallowedStd3Mapped(9767, 1)),
+  (0xFF1B, DisallowedStd3Mapped(512, 1)),
+  (0xFF1C, DisallowedStd3Mapped(9821, 1)),
+  (0xFF1D, DisallowedStd3Mapped(2358, 1)),
+  (0xFF1E, DisallowedStd3Mapped(9822, 1)),
+  (0xFF1F, DisallowedStd3Mapped(9769, 1)),
+  (0xFF20, DisallowedStd3Mapped(9826, 1)),
+  (0xFF21, Mapped(0, 1)),
+  (0xFF22, Mapped(1, 1)),
+  (0xFF23, Mapped(2, 1)),
+  (0xFF24, Mapped(3, 1)),
+  (0xFF25, Mapped(4, 1)),
+  (0xFF26, Mapped(5, 1)),
+  (0xFF27, Mapped(6, 1)),
+  (0xFF28, Mapped(7, 1)),
+  (0xFF29, Mapped(8, 1)),
+  (0xFF2A, Mapped(9, 1)),
+  (0xFF2B, Mapped(10, 1)),
+  (0xFF2C, Mapped(11, 1)),
+  (0xFF2D, Mapped(12, 1)),
+  (0xFF2E, Mapped(13, 1)),
+  (0xFF2F, Mapped(14, 1)),
+  (0xFF30, Mapped(15, 1)),
+  (0xFF31, Mapped(16, 1)),
+  (0xFF32, Mapped(17, 1)),
+  (0xFF33, Mapped(18, 1)),
+  (0xFF34, Mapped(19, 1)),
+  (0xFF35, Mapped(20, 1)),
+  (0xFF36, Mapped(21, 1)),
+  (0xFF37, Mapped(22, 1)),
+  (0xFF38, Mapped(23, 1)),
+  (0xFF39, Mapped(24, 1)),
+  (0xFF3A, Mapped(25, 1)),
+  (0xFF3B, DisallowedStd3Mapped(9815, 1)),
+  (0xFF3C, DisallowedStd3Mapped(9823, 1)),
+  (0xFF3D, DisallowedStd3Mapped(9816, 1)),
+  (0xFF3E, DisallowedStd3Mapped(9964, 1)),
+  (0xFF3F, DisallowedStd3Mapped(9782, 1)),
+  (0xFF40, DisallowedStd3Mapped(2260, 1)),
+  (0xFF41, Mapped(0, 1)),
+  (0xFF42, Mapped(1, 1)),
+  (0xFF43, Mapped(2, 1)),
+  (0xFF44, Mapped(3, 1)),
+  (0xFF45, Mapped(4, 1)),
+  (0xFF46, Mapped(5, 1)),
+  (0xFF47, Mapped(6, 1)),
+  (0xFF48, Mapped(7, 1)),
+  (0xFF49, Mapped(8, 1)),
+  (0xFF4A, Mapped(9, 1)),
+  (0xFF4B, Mapped(10, 1)),
+  (0xFF4C, Mapped(11, 1)),
+  (0xFF4D, Mapped(12, 1)),
+  (0xFF4E, Mapped(13, 1)),
+  (0xFF4F, Mapped(14, 1)),
+  (0xFF50, Mapped(15, 1)),
+  (0xFF51, Mapped(16, 1)),
+  (0xFF52, Mapped(17, 1)),
+  (0xFF53, Mapped(18, 1)),
+  (0xFF54, Mapped(19, 1)),
+  (0xFF55, Mapped(20, 1)),
+  (0xFF56, Mapped(21, 1)),
+  (0xFF57, Mapped(22, 1)),
+  (0xFF58, Mapped(23, 1)),
+  (0xFF59, Mapped(24, 1)),
+  (0xFF5A, Mapped(25, 1)),
+  (0xFF5B, DisallowedStd3Mapped(9783, 1)),
+  (0xFF5C, DisallowedStd3Mapped(9965, 1)),
+  (0xFF5D, DisallowedStd3Mapped(9784, 1)),
+  (0xFF5E, DisallowedStd3Mapped(9966, 1)),
+  (0xFF5F, Mapped(9967, 3)),
+  (0xFF60, Mapped(9970, 3)),
+  (0xFF61, Mapped(3724, 1)),
+  (0xFF62, Mapped(9803, 3)),
+  (0xFF63, Mapped(9806, 3)),
+  (0xFF64, Mapped(9764, 3)),
+  (0xFF65, Mapped(9973, 3)),
+  (0xFF66, Mapped(4828, 3)),
+  (0xFF67, Mapped(9976, 3)),
+  (0xFF68, Mapped(9979, 3)),
+  (0xFF69, Mapped(9982, 3)),
+  (0xFF6A, Mapped(9985, 3)),
+  (0xFF6B, Mapped(9988, 3)),
+  (0xFF6C, Mapped(9991, 3)),
+  (0xFF6D, Mapped(9994, 3)),
+  (0xFF6E, Mapped(9997, 3)),
+  (0xFF6F, Mapped(10000, 3)),
+  (0xFF70, Mapped(10003, 3)),
+  (0xFF71, Mapped(4690, 3)),
+  (0xFF72, Mapped(4693, 3)),
+  (0xFF73, Mapped(4696, 3)),
+  (0xFF74, Mapped(4699, 3)),
+  (0xFF75, Mapped(4702, 3)),
+  (0xFF76, Mapped(4705, 3)),
+  (0xFF77, Mapped(4708, 3)),
+  (0xFF78, Mapped(4711, 3)),
+  (0xFF79, Mapped(4714, 3)),
+  (0xFF7A, Mapped(4717, 3)),
+  (0xFF7B, Mapped(4720, 3)),
+  (0xFF7C, Mapped(4723, 3)),
+  (0xFF7D, Mapped(4726, 3)),
+  (0xFF7E, Mapped(4729, 3)),
+  (0xFF7F, Mapped(4732, 3)),
+  (0xFF80, Mapped(4735, 3)),
+  (0xFF81, Mapped(4738, 3)),
+  (0xFF82, Mapped(4741, 3)),
+  (0xFF83, Mapped(4744, 3)),
+  (0xFF84, Mapped(4747, 3)),
+  (0xFF85, Mapped(4750, 3)),
+  (0xFF86, Mapped(4753, 3)),
+  (0xFF87, Mapped(4756, 3)),
+  (0xFF88, Mapped(4759, 3)),
+  (0xFF89, Mapped(4762, 3)),
+  (0xFF8A, Mapped(4765, 3)),
+  (0xFF8B, Mapped(4768, 3)),
+  (0xFF8C, Mapped(4771, 3)),
+  (0xFF8D, Mapped(4774, 3)),
+  (0xFF8E, Mapped(4777, 3)),
+  (0xFF8F, Mapped(4780, 3)),
+  (0xFF90, Mapped(4783, 3)),
+  (0xFF91, Mapped(4786, 3)),
+  (0xFF92, Mapped(4789, 3)),
+  (0xFF93, Mapped(4792, 3)),
+  (0xFF94, Mapped(4795, 3)),
+  (0xFF95, Mapped(4798, 3)),
+  (0xFF96, Mapped(4801, 3)),
+  (0xFF97, Mapped(4804, 3)),
+  (0xFF98, Mapped(4807, 3)),
+  (0xFF99, Mapped(4810, 3)),
+  (0xFF9A, Mapped(4813, 3)),
+  (0xFF9B, Mapped(4816, 3)),
+  (0xFF9C, Mapped(4819, 3)),
+  (0xFF9D, Mapped(10006, 3)),
+  (0xFF9E, Mapped(10009, 3)),
+  (0xFF9F, Mapped(10012, 3)),
+  (0xFFA0, Disallowed),
+  (0xFFA1, Mapped(3754, 3)),
+  (0xFFA2, Mapped(3757, 3)),
+  (0xFFA3, Mapped(3760, 3)),
+  (0xFFA4, Mapped(3763, 3)),
+  (0xFFA5, Mapped(3766, 3)),
+  (0xFFA6, Mapped(3769, 3)),
+  (0xFFA7, Mapped(3772, 3)),
+  (0xFFA8, Mapped(3775, 3)),
+  (0xFFA9, Mapped(3778, 3)),
+  (0xFFAA, Mapped(3781, 3)),
+  (0xFFAB, Mapped(3784, 3)),
+  (0xFFAC, Mapped(3787, 3)),
+  (0xFFAD, Mapped(3790, 3)),
+  (0xFFAE, Mapped(3793, 3)),
+  (0xFFAF, Mapped(3796, 3)),
+  (0xFFB0, Mapped(3799, 3)),
+  (0xFFB1, Mapped(3802, 3)),
+  (0xFFB2, Mapped(3805, 3)),
+  (0xFFB3, Mapped(3808, 3)),
+  (0xFFB4, Mapped(3811, 3)),
+  (0xFFB5, Mapped(3814, 3)),
+  (0xFFB6, Mapped(3817, 3)),
+  (0xFFB7, Mapped(3820, 3)),
+  (0xFFB8, Mapped(3823, 3)),
+  (0xFFB9, Mapped(3826, 3)),
+  (0xFFBA, Mapped(3829, 3)),
+  (0xFFBB, Mapped(3832, 3)),
+  (0xFFBC, Mapped(3835, 3)),
+  (0xFFBD, Mapped(3838, 3)),
+  (0xFFBE, Mapped(3841, 3)),
+  (0xFFBF, Disallowed),
+  (0xFFC2, Mapped(3844, 3)),
+  (0xFFC3, Mapped(3847, 3)),
+  (0xFFC4, Mapped(3850, 3)),
+  (0xFFC5, Mapped(3853, 3)),
+  (0xFFC6, Mapped(3856, 3)),
+  (0xFFC7, Mapped(3859, 3)),
+  (0xFFC8, Disallowed),
+  (0xFFCA, Mapped(3862, 3)),
+  (0xFFCB, Mapped(3865, 3)),
+  (0xFFCC, Mapped(3868, 3)),
+  (0xFFCD, Mapped(3871, 3)),
+  (0xFFCE, Mapped(3874, 3)),
+  (0xFFCF, Mapped(3877, 3)),
+  (0xFFD0, Disallowed),
+  (0xFFD2, Mapped(3880, 3)),
+  (0xFFD3, Mapped(3883, 3)),
+  (0xFFD4, Mapped(3886, 3)),
+  (0xFFD5, Mapped(3889, 3)),
+  (0xFFD6, Mapped(3892, 3)),
+  (0xFFD7, Mapped(3895, 3)),
+  (0xFFD8, Disallowed),
+  (0xFFDA, Mapped(3898, 3)),
+  (0xFFDB, Mapped(3901, 3)),
+  (0xFFDC, Mapped(3904, 3)),
+  (0xFFDD, Disallowed),
+  (0xFFE0, Mapped(10015, 2)),
+  (0xFFE1, Mapped(10017, 2)),
+  (0xFFE2, Mapped(10019, 2)),
+  (0xFFE3, DisallowedStd3Mapped(30, 3)),
+  (0xFFE4, Mapped(10021, 2)),
+  (0xFFE5, Mapped(10023, 2)),
+  (0xFFE6, Mapped(10025, 3)),
+  (0xFFE7, Disallowed),
+  (0xFFE8, Mapped(10028, 3)),
+  (0xFFE9, Mapped(10031, 3)),
+  (0xFFEA, Mapped(10034, 3)),
+  (0xFFEB, Mapped(10037, 3)),
+  (0xFFEC, Mapped(10040, 3)),
+  (0xFFED, Mapped(10043, 3)),
+  (0xFFEE, Mapped(10046, 3)),
+  (0xFFEF, Disallowed),
+  (0x10000, Valid),
+  (0x1000C, Disallowed),
+  (0x1000D, Valid),
+  (0x10027, Disallowed),
+  (0x10028, Valid),
+  (0x1003B, Disallowed),
+  (0x1003C, Valid),
+  (0x1003E, Disallowed),
+  (0x1003F, Valid),
+  (0x1004E, Disallowed),
+  (0x10050, Valid),
+  (0x1005E, Disallowed),
+  (0x10080, Valid),
+  (0x100FB, Disallowed),
+  (0x10100, Valid),
+  (0x10103, Disallowed),
+  (0x10107, Valid),
+  (0x10134, Disallowed),
+  (0x10137, Valid),
+  (0x1018F, Disallowed),
+  (0x10190, Valid),
+  (0x1019D, Disallowed),
+  (0x101A0, Valid),
+  (0x101A1, Disallowed),
+  (0x101D0, Valid),
+  (0x101FE, Disallowed),
+  (0x10280, Valid),
+  (0x1029D, Disallowed),
+  (0x102A0, Valid),
+  (0x102D1, Disallowed),
+  (0x102E0, Valid),
+  (0x102FC, Disallowed),
+  (0x10300, Valid),
+  (0x10324, Disallowed),
+  (0x1032D, Valid),
+  (0x1034B, Disallowed),
+  (0x10350, Valid),
+  (0x1037B, Disallowed),
+  (0x10380, Valid),
+  (0x1039E, Disallowed),
+  (0x1039F, Valid),
+  (0x103C4, Disallowed),
+  (0x103C8, Valid),
+  (0x103D6, Disallowed),
+  (0x10400, Mapped(10049, 4)),
+  (0x10401, Mapped(10053, 4)),
+  (0x10402, Mapped(10057, 4)),
+  (0x10403, Mapped(10061, 4)),
+  (0x10404, Mapped(10065, 4)),
+  (0x10405, Mapped(10069, 4)),
+  (0x10406, Mapped(10073, 4)),
+  (0x10407, Mapped(10077, 4)),
+  (0x10408, Mapped(10081, 4)),
+  (0x10409, Mapped(10085, 4)),
+  (0x1040A, Mapped(10089, 4)),
+  (0x1040B, Mapped(10093, 4)),
+  (0x1040C, Mapped(10097, 4)),
+  (0x1040D, Mapped(10101, 4)),
+  (0x1040E, Mapped(10105, 4)),
+  (0x1040F, Mapped(10109, 4)),
+  (0x10410, Mapped(10113, 4)),
+  (0x10411, Mapped(10117, 4)),
+  (0x10412, Mapped(10121, 4)),
+  (0x10413, Mapped(10125, 4)),
+  (0x10414, Mapped(10129, 4)),
+  (0x10415, Mapped(10133, 4)),
+  (0x10416, Mapped(10137, 4)),
+  (0x10417, Mapped(10141, 4)),
+  (0x10418, Mapped(10145, 4)),
+  (0x10419, Mapped(10149, 4)),
+  (0x1041A, Mapped(10153, 4)),
+  (0x1041B, Mapped(10157, 4)),
+  (0x1041C, Mapped(10161, 4)),
+  (0x1041D, Mapped(10165, 4)),
+  (0x1041E, Mapped(10169, 4)),
+  (0x1041F, Mapped(10173, 4)),
+  (0x10420, Mapped(10177, 4)),
+  (0x10421, Mapped(10181, 4)),
+  (0x10422, Mapped(10185, 4)),
+  (0x10423, Mapped(10189, 4)),
+  (0x10424, Mapped(10193, 4)),
+  (0x10425, Mapped(10197, 4)),
+  (0x10426, Mapped(10201, 4)),
+  (0x10427, Mapped(10205, 4)),
+  (0x10428, Valid),
+  (0x1049E, Disallowed),
+  (0x104A0, Valid),
+  (0x104AA, Disallowed),
+  (0x104B0, Mapped(10209, 4)),
+  (0x104B1, Mapped(10213, 4)),
+  (0x104B2, Mapped(10217, 4)),
+  (0x104B3, Mapped(10221, 4)),
+  (0x104B4, Mapped(10225, 4)),
+  (0x104B5, Mapped(10229, 4)),
+  (0x104B6, Mapped(10233, 4)),
+  (0x104B7, Mapped(10237, 4)),
+  (0x104B8, Mapped(10241, 4)),
+  (0x104B9, Mapped(10245, 4)),
+  (0x104BA, Mapped(10249, 4)),
+  (0x104BB, Mapped(10253, 4)),
+  (0x104BC, Mapped(10257, 4)),
+  (0x104BD, Mapped(10261, 4)),
+  (0x104BE, Mapped(10265, 4)),
+  (0x104BF, Mapped(10269, 4)),
+  (0x104C0, Mapped(10273, 4)),
+  (0x104C1, Mapped(10277, 4)),
+  (0x104C2, Mapped(10281, 4)),
+  (0x104C3, Mapped(10285, 4)),
+  (0x104C4, Mapped(10289, 4)),
+  (0x104C5, Mapped(10293, 4)),
+  (0x104C6, Mapped(10297, 4)),
+  (0x104C7, Mapped(10301, 4)),
+  (0x104C8, Mapped(10305, 4)),
+  (0x104C9, Mapped(10309, 4)),
+  (0x104CA, Mapped(10313, 4)),
+  (0x104CB, Mapped(10317, 4)),
+  (0x104CC, Mapped(10321, 4)),
+  (0x104CD, Mapped(10325, 4)),
+  (0x104CE, Mapped(10329, 4)),
+  (0x104CF, Mapped(10333, 4)),
+  (0x104D0, Mapped(10337, 4)),
+  (0x104D1, Mapped(10341, 4)),
+  (0x104D2, Mapped(10345, 4)),
+  (0x104D3, Mapped(10349, 4)),
+  (0x104D4, Disallowed),
+  (0x104D8, Valid),
+  (0x104FC, Disallowed),
+  (0x10500, Valid),
+  (0x10528, Disallowed),
+  (0x10530, Valid),
+  (0x10564, Disallowed),
+  (0x1056F, Valid),
+  (0x10570, Disallowed),
+  (0x10600, Valid),
+  (0x10737, Disallowed),
+  (0x10740, Valid),
+  (0x10756, Disallowed),
+  (0x10760, Valid),
+  (0x10768, Disallowed),
+  (0x10800, Valid),
+  (0x10806, Disallowed),
+  (0x10808, Valid),
+  (0x10809, Disallowed),
+  (0x1080A, Valid),
+  (0x10836, Disallowed),
+  (0x10837, Valid),
+  (0x10839, Disallowed),
+  (0x1083C, Valid),
+  (0x1083D, Disallowed),
+  (0x1083F, Valid),
+  (0x10856, Disallowed),
+  (0x10857, Valid),
+  (0x1089F, Disallowed),
+  (0x108A7, Valid),
+  (0x108B0, Disallowed),
+  (0x108E0, Valid),
+  (0x108F3, Disallowed),
+  (0x108F4, Valid),
+  (0x108F6, Disallowed),
+  (0x108FB, Valid),
+  (0x1091C, Disallowed),
+  (0x1091F, Valid),
+  (0x1093A, Disallowed),
+  (0x1093F, Valid),
+  (0x10940, Disallowed),
+  (0x10980, Valid),
+  (0x109B8, Disallowed),
+  (0x109BC, Valid),
+  (0x109D0, Disallowed),
+  (0x109D2, Valid),
+  (0x10A04, Disallowed),
+  (0x10A05, Valid),
+  (0x10A07, Disallowed),
+  (0x10A0C, Valid),
+  (0x10A14, Disallowed),
+  (0x10A15, Valid),
+  (0x10A18, Disallowed),
+  (0x10A19, Valid),
+  (0x10A36, Disallowed),
+  (0x10A38, Valid),
+  (0x10A3B, Disallowed),
+  (0x10A3F, Valid),
+  (0x10A49, Disallowed),
+  (0x10A50, Valid),
+  (0x10A59, Disallowed),
+  (0x10A60, Valid),
+  (0x10AA0, Disallowed),
+  (0x10AC0, Valid),
+  (0x10AE7, Disallowed),
+  (0x10AEB, Valid),
+  (0x10AF7, Disallowed),
+  (0x10B00, Valid),
+  (0x10B36, Disallowed),
+  (0x10B39, Valid),
+  (0x10B56, Disallowed),
+  (0x10B58, Valid),
+  (0x10B73, Disallowed),
+  (0x10B78, Valid),
+  (0x10B92, Disallowed),
+  (0x10B99, Valid),
+  (0x10B9D, Disallowed),
+  (0x10BA9, Valid),
+  (0x10BB0, Disallowed),
+  (0x10C00, Valid),
+  (0x10C49, Disallowed),
+  (0x10C80, Mapped(10353, 4)),
+  (0x10C81, Mapped(10357, 4)),
+  (0x10C82, Mapped(10361, 4)),
+  (0x10C83, Mapped(10365, 4)),
+  (0x10C84, Mapped(10369, 4)),
+  (0x10C85, Mapped(10373, 4)),
+  (0x10C86, Mapped(10377, 4)),
+  (0x10C87, Mapped(10381, 4)),
+  (0x10C88, Mapped(10385, 4)),
+  (0x10C89, Mapped(10389, 4)),
+  (0x10C8A, Mapped(10393, 4)),
+  (0x10C8B, Mapped(10397, 4)),
+  (0x10C8C, Mapped(10401, 4)),
+  (0x10C8D, Mapped(10405, 4)),
+  (0x10C8E, Mapped(10409, 4)),
+  (0x10C8F, Mapped(10413, 4)),
+  (0x10C90, Mapped(10417, 4)),
+  (0x10C91, Mapped(10421, 4)),
+  (0x10C92, Mapped(10425, 4)),
+  (0x10C93, Mapped(10429, 4)),
+  (0x10C94, Mapped(10433, 4)),
+  (0x10C95, Mapped(10437, 4)),
+  (0x10C96, Mapped(10441, 4)),
+  (0x10C97, Mapped(10445, 4)),
+  (0x10C98, Mapped(10449, 4)),
+  (0x10C99, Mapped(10453, 4)),
+  (0x10C9A, Mapped(10457, 4)),
+  (0x10C9B, Mapped(10461, 4)),
+  (0x10C9C, Mapped(10465, 4)),
+  (0x10C9D, Mapped(10469, 4)),
+  (0x10C9E, Mapped(10473, 4)),
+  (0x10C9F, Mapped(10477, 4)),
+  (0x10CA0, Mapped(10481, 4)),
+  (0x10CA1, Mapped(10485, 4)),
+  (0x10CA2, Mapped(10489, 4)),
+  (0x10CA3, Mapped(10493, 4)),
+  (0x10CA4, Mapped(10497, 4)),
+  (0x10CA5, Mapped(10501, 4)),
+  (0x10CA6, Mapped(10505, 4)),
+  (0x10CA7, Mapped(10509, 4)),
+  (0x10CA8, Mapped(10513, 4)),
+  (0x10CA9, Mapped(10517, 4)),
+  (0x10CAA, Mapped(10521, 4)),
+  (0x10CAB, Mapped(10525, 4)),
+  (0x10CAC, Mapped(10529, 4)),
+  (0x10CAD, Mapped(10533, 4)),
+  (0x10CAE, Mapped(10537, 4)),
+  (0x10CAF, Mapped(10541, 4)),
+  (0x10CB0, Mapped(10545, 4)),
+  (0x10CB1, Mapped(10549, 4)),
+  (0x10CB2, Mapped(10553, 4)),
+  (0x10CB3, Disallowed),
+  (0x10CC0, Valid),
+  (0x10CF3, Disallowed),
+  (0x10CFA, Valid),
+  (0x10D28, Disallowed),
+  (0x10D30, Valid),
+  (0x10D3A, Disallowed),
+  (0x10E60, Valid),
+  (0x10E7F, Disallowed),
+  (0x10E80, Valid),
+  (0x10EAA, Disallowed),
+  (0x10EAB, Valid),
+  (0x10EAE, Disallowed),
+  (0x10EB0, Valid),
+  (0x10EB2, Disallowed),
+  (0x10F00, Valid),
+  (0x10F28, Disallowed),
+  (0x10F30, Valid),
+  (0x10F5A, Disallowed),
+  (0x10FB0, Valid),
+  (0x10FCC, Disallowed),
+  (0x10FE0, Valid),
+  (0x10FF7, Disallowed),
+  (0x11000, Valid),
+  (0x1104E, Disallowed),
+  (0x11052, Valid),
+  (0x11070, Disallowed),
+  (0x1107F, Valid),
+  (0x110BD, Disallowed),
+  (0x110BE, Valid),
+  (0x110C2, Disallowed),
+  (0x110D0, Valid),
+  (0x110E9, Disallowed),
+  (0x110F0, Valid),
+  (0x110FA, Disallowed),
+  (0x11100, Valid),
+  (0x11135, Disallowed),
+  (0x11136, Valid),
+  (0x11148, Disallowed),
+  (0x11150, Valid),
+  (0x11177, Disallowed),
+  (0x11180, Valid),
+  (0x111E0, Disallowed),
+  (0x111E1, Valid),
+  (0x111F5, Disallowed),
+  (0x11200, Valid),
+  (0x11212, Disallowed),
+  (0x11213, Valid),
+  (0x1123F, Disallowed),
+  (0x11280, Valid),
+  (0x11287, Disallowed),
+  (0x11288, Valid),
+  (0x11289, Disallowed),
+  (0x1128A, Valid),
+  (0x1128E, Disallowed),
+  (0x1128F, Valid),
+  (0x1129E, Disallowed),
+  (0x1129F, Valid),
+  (0x112AA, Disallowed),
+  (0x112B0, Valid),
+  (0x112EB, Disallowed),
+  (0x112F0, Valid),
+  (0x112FA, Disallowed),
+  (0x11300, Valid),
+  (0x11304, Disallowed),
+  (0x11305, Valid),
+  (0x1130D, Disallowed),
+  (0x1130F, Valid),
+  (0x11311, Disallowed),
+  (0x11313, Valid),
+  (0x11329, Disallowed),
+  (0x1132A, Valid),
+  (0x11331, Disallowed),
+  (0x11332, Valid),
+  (0x11334, Disallowed),
+  (0x11335, Valid),
+  (0x1133A, Disallowed),
+  (0x1133B, Valid),
+  (0x11345, Disallowed),
+  (0x11347, Valid),
+  (0x11349, Disallowed),
+  (0x1134B, Valid),
+  (0x1134E, Disallowed),
+  (0x11350, Valid),
+  (0x11351, Disallowed),
+  (0x11357, Valid),
+  (0x11358, Disallowed),
+  (0x1135D, Valid),
+  (0x11364, Disallowed),
+  (0x11366, Valid),
+  (0x1136D, Disallowed),
+  (0x11370, Valid),
+  (0x11375, Disallowed),
+  (0x11400, Valid),
+  (0x1145C, Disallowed),
+  (0x1145D, Valid),
+  (0x11462, Disallowed),
+  (0x11480, Valid),
+  (0x114C8, Disallowed),
+  (0x114D0, Valid),
+  (0x114DA, Disallowed),
+  (0x11580, Valid),
+  (0x115B6, Disallowed),
+  (0x115B8, Valid),
+  (0x115DE, Disallowed),
+  (0x11600, Valid),
+  (0x11645, Disallowed),
+  (0x11650, Valid),
+  (0x1165A, Disallowed),
+  (0x11660, Valid),
+  (0x1166D, Disallowed),
+  (0x11680, Valid),
+  (0x116B9, Disallowed),
+  (0x116C0, Valid),
+  (0x116CA, Disallowed),
+  (0x11700, Valid),
+  (0x1171B, Disallowed),
+  (0x1171D, Valid),
+  (0x1172C, Disallowed),
+  (0x11730, Valid),
+  (0x11740, Disallowed),
+  (0x11800, Valid),
+  (0x1183C, Disallowed),
+  (0x118A0, Mapped(10557, 4)),
+  (0x118A1, Mapped(10561, 4)),
+  (0x118A2, Mapped(10565, 4)),
+  (0x118A3, Mapped(10569, 4)),
+  (0x118A4, Mapped(10573, 4)),
+  (0x118A5, Mapped(10577, 4)),
+  (0x118A6, Mapped(10581, 4)),
+  (0x118A7, Mapped(10585, 4)),
+  (0x118A8, Mapped(10589, 4)),
+  (0x118A9, Mapped(10593, 4)),
+  (0x118AA, Mapped(10597, 4)),
+  (0x118AB, Mapped(10601, 4)),
+  (0x118AC, Mapped(10605, 4)),
+  (0x118AD, Mapped(10609, 4)),
+  (0x118AE, Mapped(10613, 4)),
+  (0x118AF, Mapped(10617, 4)),
+  (0x118B0, Mapped(10621, 4)),
+  (0x118B1, Mapped(10625, 4)),
+  (0x118B2, Mapped(10629, 4)),
+  (0x118B3, Mapped(10633, 4)),
+  (0x118B4, Mapped(10637, 4)),
+  (0x118B5, Mapped(10641, 4)),
+  (0x118B6, Mapped(10645, 4)),
+  (0x118B7, Mapped(10649, 4)),
+  (0x118B8, Mapped(10653, 4)),
+  (0x118B9, Mapped(10657, 4)),
+  (0x118BA, Mapped(10661, 4)),
+  (0x118BB, Mapped(10665, 4)),
+  (0x118BC, Mapped(10669, 4)),
+  (0x118BD, Mapped(10673, 4)),
+  (0x118BE, Mapped(10677, 4)),
+  (0x118BF, Mapped(10681, 4)),
+  (0x118C0, Valid),
+  (0x118F3, Disallowed),
+  (0x118FF, Valid),
+  (0x11907, Disallowed),
+  (0x11909, Valid),
+  (0x1190A, Disallowed),
+  (0x1190C, Valid),
+  (0x11914, Disallowed),
+  (0x11915, Valid),
+  (0x11917, Disallowed),
+  (0x11918, Valid),
+  (0x11936, Disallowed),
+  (0x11937, Valid),
+  (0x11939, Disallowed),
+  (0x1193B, Valid),
+  (0x11947, Disallowed),
+  (0x11950, Valid),
+  (0x1195A, Disallowed),
+  (0x119A0, Valid),
+  (0x119A8, Disallowed),
+  (0x119AA, Valid),
+  (0x119D8, Disallowed),
+  (0x119DA, Valid),
+  (0x119E5, Disallowed),
+  (0x11A00, Valid),
+  (0x11A48, Disallowed),
+  (0x11A50, Valid),
+  (0x11AA3, Disallowed),
+  (0x11AC0, Valid),
+  (0x11AF9, Disallowed),
+  (0x11C00, Valid),
+  (0x11C09, Disallowed),
+  (0x11C0A, Valid),
+  (0x11C37, Disallowed),
+  (0x11C38, Valid),
+  (0x11C46, Disallowed),
+  (0x11C50, Valid),
+  (0x11C6D, Disallowed),
+  (0x11C70, Valid),
+  (0x11C90, Disallowed),
+  (0x11C92, Valid),
+  (0x11CA8, Disallowed),
+  (0x11CA9, Valid),
+  (0x11CB7, Disallowed),
+  (0x11D00, Valid),
+  (0x11D07, Disallowed),
+  (0x11D08, Valid),
+  (0x11D0A, Disallowed),
+  (0x11D0B, Valid),
+  (0x11D37, Disallowed),
+  (0x11D3A, Valid),
+  (0x11D3B, Disallowed),
+  (0x11D3C, Valid),
+  (0x11D3E, Disallowed),
+  (0x11D3F, Valid),
+  (0x11D48, Disallowed),
+  (0x11D50, Valid),
+  (0x11D5A, Disallowed),
+  (0x11D60, Valid),
+  (0x11D66, Disallowed),
+  (0x11D67, Valid),
+  (0x11D69, Disallowed),
+  (0x11D6A, Valid),
+  (0x11D8F, Disallowed),
+  (0x11D90, Valid),
+  (0x11D92, Disallowed),
+  (0x11D93, Valid),
+  (0x11D99, Disallowed),
+  (0x11DA0, Valid),
+  (0x11DAA, Disallowed),
+  (0x11EE0, Valid),
+  (0x11EF9, Disallowed),
+  (0x11FB0, Valid),
+  (0x11FB1, Disallowed),
+  (0x11FC0, Valid),
+  (0x11FF2, Disallowed),
+  (0x11FFF, Valid),
+  (0x1239A, Disallowed),
+  (0x12400, Valid),
+  (0x1246F, Disallowed),
+  (0x12470, Valid),
+  (0x12475, Disallowed),
+  (0x12480, Valid),
+  (0x12544, Disallowed),
+  (0x13000, Valid),
+  (0x1342F, Disallowed),
+  (0x14400, Valid),
+  (0x14647, Disallowed),
+  (0x16800, Valid),
+  (0x16A39, Disallowed),
+  (0x16A40, Valid),
+  (0x16A5F, Disallowed),
+  (0x16A60, Valid),
+  (0x16A6A, Disallowed),
+  (0x16A6E, Valid),
+  (0x16A70, Disallowed),
+  (0x16AD0, Valid),
+  (0x16AEE, Disallowed),
+  (0x16AF0, Valid),
+  (0x16AF6, Disallowed),
+  (0x16B00, Valid),
+  (0x16B46, Disallowed),
+  (0x16B50, Valid),
+  (0x16B5A, Disallowed),
+  (0x16B5B, Valid),
+  (0x16B62, Disallowed),
+  (0x16B63, Valid),
+  (0x16B78, Disallowed),
+  (0x16B7D, Valid),
+  (0x16B90, Disallowed),
+  (0x16E40, Mapped(10685, 4)),
+  (0x16E41, Mapped(10689, 4)),
+  (0x16E42, Mapped(10693, 4)),
+  (0x16E43, Mapped(10697, 4)),
+  (0x16E44, Mapped(10701, 4)),
+  (0x16E45, Mapped(10705, 4)),
+  (0x16E46, Mapped(10709, 4)),
+  (0x16E47, Mapped(10713, 4)),
+  (0x16E48, Mapped(10717, 4)),
+  (0x16E49, Mapped(10721, 4)),
+  (0x16E4A, Mapped(10725, 4)),
+  (0x16E4B, Mapped(10729, 4)),
+  (0x16E4C, Mapped(10733, 4)),
+  (0x16E4D, Mapped(10737, 4)),
+  (0x16E4E, Mapped(10741, 4)),
+  (0x16E4F, Mapped(10745, 4)),
+  (0x16E50, Mapped(10749, 4)),
+  (0x16E51, Mapped(10753, 4)),
+  (0x16E52, Mapped(10757, 4)),
+  (0x16E53, Mapped(10761, 4)),
+  (0x16E54, Mapped(10765, 4)),
+  (0x16E55, Mapped(10769, 4)),
+  (0x16E56, Mapped(10773, 4)),
+  (0x16E57, Mapped(10777, 4)),
+  (0x16E58, Mapped(10781, 4)),
+  (0x16E59, Mapped(10785, 4)),
+  (0x16E5A, Mapped(10789, 4)),
+  (0x16E5B, Mapped(10793, 4)),
+  (0x16E5C, Mapped(10797, 4)),
+  (0x16E5D, Mapped(10801, 4)),
+  (0x16E5E, Mapped(10805, 4)),
+  (0x16E5F, Mapped(10809, 4)),
+  (0x16E60, Valid),
+  (0x16E9B, Disallowed),
+  (0x16F00, Valid),
+  (0x16F4B, Disallowed),
+  (0x16F4F, Valid),
+  (0x16F88, Disallowed),
+  (0x16F8F, Valid),
+  (0x16FA0, Disallowed),
+  (0x16FE0, Valid),
+  (0x16FE5, Disallowed),
+  (0x16FF0, Valid),
+  (0x16FF2, Disallowed),
+  (0x17000, Valid),
+  (0x187F8, Disallowed),
+  (0x18800, Valid),
+  (0x18CD6, Disallowed),
+  (0x18D00, Valid),
+  (0x18D09, Disallowed),
+  (0x1B000, Valid),
+  (0x1B11F, Disallowed),
+  (0x1B150, Valid),
+  (0x1B153, Disallowed),
+  (0x1B164, Valid),
+  (0x1B168, Disallowed),
+  (0x1B170, Valid),
+  (0x1B2FC, Disallowed),
+  (0x1BC00, Valid),
+  (0x1BC6B, Disallowed),
+  (0x1BC70, Valid),
+  (0x1BC7D, Disallowed),
+  (0x1BC80, Valid),
+  (0x1BC89, Disallowed),
+  (0x1BC90, Valid),
+  (0x1BC9A, Disallowed),
+  (0x1BC9C, Valid),
+  (0x1BCA0, Ignored),
+  (0x1BCA4, Disallowed),
+  (0x1D000, Valid),
+  (0x1D0F6, Disallowed),
+  (0x1D100, Valid),
+  (0x1D127, Disallowed),
+  (0x1D129, Valid),
+  (0x1D15E, Mapped(10813, 8)),
+  (0x1D15F, Mapped(10821, 8)),
+  (0x1D160, Mapped(10829, 12)),
+  (0x1D161, Mapped(10841, 12)),
+  (0x1D162, Mapped(10853, 12)),
+  (0x1D163, Mapped(10865, 12)),
+  (0x1D164, Mapped(10877, 12)),
+  (0x1D165, Valid),
+  (0x1D173, Disallowed),
+  (0x1D17B, Valid),
+  (0x1D1BB, Mapped(10889, 8)),
+  (0x1D1BC, Mapped(10897, 8)),
+  (0x1D1BD, Mapped(10905, 12)),
+  (0x1D1BE, Mapped(10917, 12)),
+  (0x1D1BF, Mapped(10929, 12)),
+  (0x1D1C0, Mapped(10941, 12)),
+  (0x1D1C1, Valid),
+  (0x1D1E9, Disallowed),
+  (0x1D200, Valid),
+  (0x1D246, Disallowed),
+  (0x1D2E0, Valid),
+  (0x1D2F4, Disallowed),
+  (0x1D300, Valid),
+  (0x1D357, Disallowed),
+  (0x1D360, Valid),
+  (0x1D379, Disallowed),
+  (0x1D400, Mapped(0, 1)),
+  (0x1D401, Mapped(1, 1)),
+  (0x1D402, Mapped(2, 1)),
+  (0x1D403, Mapped(3, 1)),
+  (0x1D404, Mapped(4, 1)),
+  (0x1D405, Mapped(5, 1)),
+  (0x1D406, Mapped(6, 1)),
+  (0x1D407, Mapped(7, 1)),
+  (0x1D408, Mapped(8, 1)),
+  (0x1D409, Mapped(9, 1)),
+  (0x1D40A, Mapped(10, 1)),
+  (0x1D40B, Mapped(11, 1)),
+  (0x1D40C, Mapped(12, 1)),
+  (0x1D40D, Mapped(13, 1)),
+  (0x1D40E, Mapped(14, 1)),
+  (0x1D40F, Mapped(15, 1)),
+  (0x1D410, Mapped(16, 1)),
+  (0x1D411, Mapped(17, 1)),
+  (0x1D412, Mapped(18, 1)),
+  (0x1D413, Mapped(19, 1)),
+  (0x1D414, Mapped(20, 1)),
+  (0x1D415, Mapped(21, 1)),
+  (0x1D416, Mapped(22, 1)),
+  (0x1D417, Mapped(23, 1)),
+  (0x1D418, Mapped(24, 1)),
+  (0x1D419, Mapped(25, 1)),
+  (0x1D41A, Mapped(0, 1)),
+  (0x1D41B, Mapped(1, 1)),
+  (0x1D41C, Mapped(2, 1)),
+  (0x1D41D, Mapped(3, 1)),
+  (0x1D41E, Mapped(4, 1)),
+  (0x1D41F, Mapped(5, 1)),
+  (0x1D420, Mapped(6, 1)),
+  (0x1D421, Mapped(7, 1)),
+  (0x1D422, Mapped(8, 1)),
+  (0x1D423, Mapped(9, 1)),
+  (0x1D424, Mapped(10, 1)),
+  (0x1D425, Mapped(11, 1)),
+  (0x1D426, Mapped(12, 1)),
+  (0x1D427, Mapped(13, 1)),
+  (0x1D428, Mapped(14, 1)),
+  (0x1D429, Mapped(15, 1)),
+  (0x1D42A, Mapped(16, 1)),
+  (0x1D42B, Mapped(17, 1)),
+  (0x1D42C, Mapped(18, 1)),
+  (0x1D42D, Mapped(19, 1)),
+  (0x1D42E, Mapped(20, 1)),
+  (0x1D42F, Mapped(21, 1)),
+  (0x1D430, Mapped(22, 1)),
+  (0x1D431, Mapped(23, 1)),
+  (0x1D432, Mapped(24, 1)),
+  (0x1D433, Mapped(25, 1)),
+  (0x1D434, Mapped(0, 1)),
+  (0x1D435, Mapped(1, 1)),
+  (0x1D436, Mapped(2, 1)),
+  (0x1D437, Mapped(3, 1)),
+  (0x1D438, Mapped(4, 1)),
+  (0x1D439, Mapped(5, 1)),
+  (0x1D43A, Mapped(6, 1)),
+  (0x1D43B, Mapped(7, 1)),
+  (0x1D43C, Mapped(8, 1)),
+  (0x1D43D, Mapped(9, 1)),
+  (0x1D43E, Mapped(10, 1)),
+  (0x1D43F, Mapped(11, 1)),
+  (0x1D440, Mapped(12, 1)),
+  (0x1D441, Mapped(13, 1)),
+  (0x1D442, Mapped(14, 1)),
+  (0x1D443, Mapped(15, 1)),
+  (0x1D444, Mapped(16, 1)),
+  (0x1D445, Mapped(17, 1)),
+  (0x1D446, Mapped(18, 1)),
+  (0x1D447, Mapped(19, 1)),
+  (0x1D448, Mapped(20, 1)),
+  (0x1D449, Mapped(21, 1)),
+  (0x1D44A, Mapped(22, 1)),
+  (0x1D44B, Mapped(23, 1)),
+  (0x1D44C, Mapped(24, 1)),
+  (0x1D44D, Mapped(25, 1)),
+  (0x1D44E, Mapped(0, 1)),
+  (0x1D44F, Mapped(1, 1)),
+  (0x1D450, Mapped(2, 1)),
+  (0x1D451, Mapped(3, 1)),
+  (0x1D452, Mapped(4, 1)),
+  (0x1D453, Mapped(5, 1)),
+  (0x1D454, Mapped(6, 1)),
+  (0x1D455, Disallowed),
+  (0x1D456, Mapped(8, 1)),
+  (0x1D457, Mapped(9, 1)),
+  (0x1D458, Mapped(10, 1)),
+  (0x1D459, Mapped(11, 1)),
+  (0x1D45A, Mapped(12, 1)),
+  (0x1D45B, Mapped(13, 1)),
+  (0x1D45C, Mapped(14, 1)),
+  (0x1D45D, Mapped(15, 1)),
+  (0x1D45E, Mapped(16, 1)),
+  (0x1D45F, Mapped(17, 1)),
+  (0x1D460, Mapped(18, 1)),
+  (0x1D461, Mapped(19, 1)),
+  (0x1D462, Mapped(20, 1)),
+  (0x1D463, Mapped(21, 1)),
+  (0x1D464, Mapped(22, 1)),
+  (0x1D465, Mapped(23, 1)),
+  (0x1D466, Mapped(24, 1)),
+  (0x1D467, Mapped(25, 1)),
+  (0x1D468, Mapped(0, 1)),
+  (0x1D469, Mapped(1, 1)),
+  (0x1D46A, Mapped(2, 1)),
+  (0x1D46B, Mapped(3, 1)),
+  (0x1D46C, Mapped(4, 1)),
+  (0x1D46D, Mapped(5, 1)),
+  (0x1D46E, Mapped(6, 1)),
+  (0x1D46F, Mapped(7, 1)),
+  (0x1D470, Mapped(8, 1)),
+  (0x1D471, Mapped(9, 1)),
+  (0x1D472, Mapped(10, 1)),
+  (0x1D473, Mapped(11, 1)),
+  (0x1D474, Mapped(12, 1)),
+  (0x1D475, Mapped(13, 1)),
+  (0x1D476, Mapped(14, 1)),
+  (0x1D477, Mapped(15, 1)),
+  (0x1D478, Mapped(16, 1)),
+  (0x1D479, Mapped(17, 1)),
+  (0x1D47A, Mapped(18, 1)),
+  (0x1D47B, Mapped(19, 1)),
+  (0x1D47C, Mapped(20, 1)),
+  (0x1D47D, Mapped(21, 1)),
+  (0x1D47E, Mapped(22, 1)),
+  (0x1D47F, Mapped(23, 1)),
+  (0x1D480, Mapped(24, 1)),
+  (0x1D481, Mapped(25, 1)),
+  (0x1D482, Mapped(0, 1)),
+  (0x1D483, Mapped(1, 1)),
+  (0x1D484, Mapped(2, 1)),
+  (0x1D485, Mapped(3, 1)),
+  (0x1D486, Mapped(4, 1)),
+  (0x1D487, Mapped(5, 1)),
+  (0x1D488, Mapped(6, 1)),
+  (0x1D489, Mapped(7, 1)),
+  (0x1D48A, Mapped(8, 1)),
+  (0x1D48B, Mapped(9, 1)),
+  (0x1D48C, Mapped(10, 1)),
+  (0x1D48D, Mapped(11, 1)),
+  (0x1D48E, Mapped(12, 1)),
+  (0x1D48F, Mapped(13, 1)),
+  (0x1D490, Mapped(14, 1)),
+  (0x1D491, Mapped(15, 1)),
+  (0x1D492, Mapped(16, 1)),
+  (0x1D493, Mapped(17, 1)),
+  (0x1D494, Mapped(18, 1)),
+  (0x1D495, Mapped(19, 1)),
+  (0x1D496, Mapped(20, 1)),
+  (0x1D497, Mapped(21, 1)),
+  (0x1D498, Mapped(22, 1)),
+  (0x1D499, Mapped(23, 1)),
+  (0x1D49A, Mapped(24, 1)),
+  (0x1D49B, Mapped(25, 1)),
+  (0x1D49C, Mapped(0, 1)),
+  (0x1D49D, Disallowed),
+  (0x1D49E, Mapped(2, 1)),
+  (0x1D49F, Mapped(3, 1)),
+  (0x1D4A0, Disallowed),
+  (0x1D4A2, Mapped(6, 1)),
+  (0x1D4A3, Disallowed),
+  (0x1D4A5, Mapped(9, 1)),
+  (0x1D4A6, Mapped(10, 1)),
+  (0x1D4A7, Disallowed),
+  (0x1D4A9, Mapped(13, 1)),
+  (0x1D4AA, Mapped(14, 1)),
+  (0x1D4AB, Mapped(15, 1)),
+  (0x1D4AC, Mapped(16, 1)),
+  (0x1D4AD, Disallowed),
+  (0x1D4AE, Mapped(18, 1)),
+  (0x1D4AF, Mapped(19, 1)),
+  (0x1D4B0, Mapped(20, 1)),
+  (0x1D4B1, Mapped(21, 1)),
+  (0x1D4B2, Mapped(22, 1)),
+  (0x1D4B3, Mapped(23, 1)),
+  (0x1D4B4, Mapped(24, 1)),
+  (0x1D4B5, Mapped(25, 1)),
+  (0x1D4B6, Mapped(0, 1)),
+  (0x1D4B7, Mapped(1, 1)),
+  (0x1D4B8, Mapped(2, 1)),
+  (0x1D4B9, Mapped(3, 1)),
+  (0x1D4BA, Disallowed),
+  (0x1D4BB, Mapped(5, 1)),
+  (0x1D4BC, Disallowed),
+  (0x1D4BD, Mapped(7, 1)),
+  (0x1D4BE, Mapped(8, 1)),
+  (0x1D4BF, Mapped(9, 1)),
+  (0x1D4C0, Mapped(10, 1)),
+  (0x1D4C1, Mapped(11, 1)),
+  (0x1D4C2, Mapped(12, 1)),
+  (0x1D4C3, Mapped(13, 1)),
+  (0x1D4C4, Disallowed),
+  (0x1D4C5, Mapped(15, 1)),
+  (0x1D4C6, Mapped(16, 1)),
+  (0x1D4C7, Mapped(17, 1)),
+  (0x1D4C8, Mapped(18, 1)),
+  (0x1D4C9, Mapped(19, 1)),
+  (0x1D4CA, Mapped(20, 1)),
+  (0x1D4CB, Mapped(21, 1)),
+  (0x1D4CC, Mapped(22, 1)),
+  (0x1D4CD, Mapped(23, 1)),
+  (0x1D4CE, Mapped(24, 1)),
+  (0x1D4CF, Mapped(25, 1)),
+  (0x1D4D0, Mapped(0, 1)),
+  (0x1D4D1, Mapped(1, 1)),
+  (0x1D4D2, Mapped(2, 1)),
+  (0x1D4D3, Mapped(3, 1)),
+  (0x1D4D4, Mapped(4, 1)),
+  (0x1D4D5, Mapped(5, 1)),
+  (0x1D4D6, Mapped(6, 1)),
+  (0x1D4D7, Mapped(7, 1)),
+  (0x1D4D8, Mapped(8, 1)),
+  (0x1D4D9, Mapped(9, 1)),
+  (0x1D4DA, Mapped(10, 1)),
+  (0x1D4DB, Mapped(11, 1)),
+  (0x1D4DC, Mapped(12, 1)),
+  (0x1D4DD, Mapped(13, 1)),
+  (0x1D4DE, Mapped(14, 1)),
+  (0x1D4DF, Mapped(15, 1)),
+  (0x1D4E0, Mapped(16, 1)),
+  (0x1D4E1, Mapped(17, 1)),
+  (0x1D4E2, Mapped(18, 1)),
+  (0x1D4E3, Mapped(19, 1)),
+  (0x1D4E4, Mapped(20, 1)),
+  (0x1D4E5, Mapped(21, 1)),
+  (0x1D4E6, Mapped(22, 1)),
+  (0x1D4E7, Mapped(23, 1)),
+  (0x1D4E8, Mapped(24, 1)),
+  (0x1D4E9, Mapped(25, 1)),
+  (0x1D4EA, Mapped(0, 1)),
+  (0x1D4EB, Mapped(1, 1)),
+  (0x1D4EC, Mapped(2, 1)),
+  (0x1D4ED, Mapped(3, 1)),
+  (0x1D4EE, Mapped(4, 1)),
+  (0x1D4EF, Mapped(5, 1)),
+  (0x1D4F0, Mapped(6, 1)),
+  (0x1D4F1, Mapped(7, 1)),
+  (0x1D4F2, Mapped(8, 1)),
+  (0x1D4F3, Mapped(9, 1)),
+  (0x1D4F4, Mapped(10, 1)),
+  (0x1D4F5, Mapped(11, 1)),
+  (0x1D4F6, Mapped(12, 1)),
+  (0x1D4F7, Mapped(13, 1)),
+  (0x1D4F8, Mapped(14, 1)),
+  (0x1D4F9, Mapped(15, 1)),
+  (0x1D4FA, Mapped(16, 1)),
+  (0x1D4FB, Mapped(17, 1)),
+  (0x1D4FC, Mapped(18, 1)),
+  (0x1D4FD, Mapped(19, 1)),
+  (0x1D4FE, Mapped(20, 1)),
+  (0x1D4FF, Mapped(21, 1)),
+  (0x1D500, Mapped(22, 1)),
+  (0x1D501, Mapped(23, 1)),
+  (0x1D502, Mapped(24, 1)),
+  (0x1D503, Mapped(25, 1)),
+  (0x1D504, Mapped(0, 1)),
+  (0x1D505, Mapped(1, 1)),
+  (0x1D506, Disallowed),
+  (0x1D507, Mapped(3, 1)),
+  (0x1D508, Mapped(4, 1)),
+  (0x1D509, Mapped(5, 1)),
+  (0x1D50A, Mapped(6, 1)),
+  (0x1D50B, Disallowed),
+  (0x1D50D, Mapped(9, 1)),
+  (0x1D50E, Mapped(10, 1)),
+  (0x1D50F, Mapped(11, 1)),
+  (0x1D510, Mapped(12, 1)),
+  (0x1D511, Mapped(13, 1)),
+  (0x1D512, Mapped(14, 1)),
+  (0x1D513, Mapped(15, 1)),
+  (0x1D514, Mapped(16, 1)),
+  (0x1D515, Disallowed),
+  (0x1D516, Mapped(18, 1)),
+  (0x1D517, Mapped(19, 1)),
+  (0x1D518, Mapped(20, 1)),
+  (0x1D519, Mapped(21, 1)),
+  (0x1D51A, Mapped(22, 1)),
+  (0x1D51B, Mapped(23, 1)),
+  (0x1D51C, Mapped(24, 1)),
+  (0x1D51D, Disallowed),
+  (0x1D51E, Mapped(0, 1)),
+  (0x1D51F, Mapped(1, 1)),
+  (0x1D520, Mapped(2, 1)),
+  (0x1D521, Mapped(3, 1)),
+  (0x1D522, Mapped(4, 1)),
+  (0x1D523, Mapped(5, 1)),
+  (0x1D524, Mapped(6, 1)),
+  (0x1D525, Mapped(7, 1)),
+  (0x1D526, Mapped(8, 1)),
+  (0x1D527, Mapped(9, 1)),
+  (0x1D528, Mapped(10, 1)),
+  (0x1D529, Mapped(11, 1)),
+  (0x1D52A, Mapped(12, 1)),
+  (0x1D52B, Mapped(13, 1)),
+  (0x1D52C, Mapped(14, 1)),
+  (0x1D52D, Mapped(15, 1)),
+  (0x1D52E, Mapped(16, 1)),
+  (0x1D52F, Mapped(17, 1)),
+  (0x1D530, Mapped(18, 1)),
+  (0x1D531, Mapped(19, 1)),
+  (0x1D532, Mapped(20, 1)),
+  (0x1D533, Mapped(21, 1)),
+  (0x1D534, Mapped(22, 1)),
+  (0x1D535, Mapped(23, 1)),
+  (0x1D536, Mapped(24, 1)),
+  (0x1D537, Mapped(25, 1)),
+  (0x1D538, Mapped(0, 1)),
+  (0x1D539, Mapped(1, 1)),
+  (0x1D53A, Disallowed),
+  (0x1D53B, Mapped(3, 1)),
+  (0x1D53C, Mapped(4, 1)),
+  (0x1D53D, Mapped(5, 1)),
+  (0x1D53E, Mapped(6, 1)),
+  (0x1D53F, Disallowed),
+  (0x1D540, Mapped(8, 1)),
+  (0x1D541, Mapped(9, 1)),
+  (0x1D542, Mapped(10, 1)),
+  (0x1D543, Mapped(11, 1)),
+  (0x1D544, Mapped(12, 1)),
+  (0x1D545, Disallowed),
+  (0x1D546, Mapped(14, 1)),
+  (0x1D547, Disallowed),
+  (0x1D54A, Mapped(18, 1)),
+  (0x1D54B, Mapped(19, 1)),
+  (0x1D54C, Mapped(20, 1)),
+  (0x1D54D, Mapped(21, 1)),
+  (0x1D54E, Mapped(22, 1)),
+  (0x1D54F, Mapped(23, 1)),
+  (0x1D550, Mapped(24, 1)),
+  (0x1D551, Disallowed),
+  (0x1D552, Mapped(0, 1)),
+  (0x1D553, Mapped(1, 1)),
+  (0x1D554, Mapped(2, 1)),
+  (0x1D555, Mapped(3, 1)),
+  (0x1D556, Mapped(4, 1)),
+  (0x1D557, Mapped(5, 1)),
+  (0x1D558, Mapped(6, 1)),
+  (0x1D559, Mapped(7, 1)),
+  (0x1D55A, Mapped(8, 1)),
+  (0x1D55B, Mapped(9, 1)),
+  (0x1D55C, Mapped(10, 1)),
+  (0x1D55D, Mapped(11, 1)),
+  (0x1D55E, Mapped(12, 1)),
+  (0x1D55F, Mapped(13, 1)),
+  (0x1D560, Mapped(14, 1)),
+  (0x1D561, Mapped(15, 1)),
+  (0x1D562, Mapped(16, 1)),
+  (0x1D563, Mapped(17, 1)),
+  (0x1D564, Mapped(18, 1)),
+  (0x1D565, Mapped(19, 1)),
+  (0x1D566, Mapped(20, 1)),
+  (0x1D567, Mapped(21, 1)),
+  (0x1D568, Mapped(22, 1)),
+  (0x1D569, Mapped(23, 1)),
+  (0x1D56A, Mapped(24, 1)),
+  (0x1D56B, Mapped(25, 1)),
+  (0x1D56C, Mapped(0, 1)),
+  (0x1D56D, Mapped(1, 1)),
+  (0x1D56E, Mapped(2, 1)),
+  (0x1D56F, Mapped(3, 1)),
+  (0x1D570, Mapped(4, 1)),
+  (0x1D571, Mapped(5, 1)),
+  (0x1D572, Mapped(6, 1)),
+  (0x1D573, Mapped(7, 1)),
+  (0x1D574, Mapped(8, 1)),
+  (0x1D575, Mapped(9, 1)),
+  (0x1D576, Mapped(10, 1)),
+  (0x1D577, Mapped(11, 1)),
+  (0x1D578, Mapped(12, 1)),
+  (0x1D579, Mapped(13, 1)),
+  (0x1D57A, Mapped(14, 1)),
+  (0x1D57B, Mapped(15, 1)),
+  (0x1D57C, Mapped(16, 1)),
+  (0x1D57D, Mapped(17, 1)),
+  (0x1D57E, Mapped(18, 1)),
+  (0x1D57F, Mapped(19, 1)),
+  (0x1D580, Mapped(20, 1)),
+  (0x1D581, Mapped(21, 1)),
+  (0x1D582, Mapped(22, 1)),
+  (0x1D583, Mapped(23, 1)),
+  (0x1D584, Mapped(24, 1)),
+  (0x1D585, Mapped(25, 1)),
+  (0x1D586, Mapped(0, 1)),
+  (0x1D587, Mapped(1, 1)),
+  (0x1D588, Mapped(2, 1)),
+  (0x1D589, Mapped(3, 1)),
+  (0x1D58A, Mapped(4, 1)),
+  (0x1D58B, Mapped(5, 1)),
+  (0x1D58C, Mapped(6, 1)),
+  (0x1D58D, Mapped(7, 1)),
+  (0x1D58E, Mapped(8, 1)),
+  (0x1D58F, Mapped(9, 1)),
+  (0x1D590, Mapped(10, 1)),
+  (0x1D591, Mapped(11, 1)),
+  (0x1D592, Mapped(12, 1)),
+  (0x1D593, Mapped(13, 1)),
+  (0x1D594, Mapped(14, 1)),
+  (0x1D595, Mapped(15, 1)),
+  (0x1D596, Mapped(16, 1)),
+  (0x1D597, Mapped(17, 1)),
+  (0x1D598, Mapped(18, 1)),
+  (0x1D599, Mapped(19, 1)),
+  (0x1D59A, Mapped(20, 1)),
+  (0x1D59B, Mapped(21, 1)),
+  (0x1D59C, Mapped(22, 1)),
+  (0x1D59D, Mapped(23, 1)),
+  (0x1D59E, Mapped(24, 1)),
+  (0x1D59F, Mapped(25, 1)),
+  (0x1D5A0, Mapped(0, 1)),
+  (0x1D5A1, Mapped(1, 1)),
+  (0x1D5A2, Mapped(2, 1)),
+  (0x1D5A3, Mapped(3, 1)),
+  (0x1D5A4, Mapped(4, 1)),
+  (0x1D5A5, Mapped(5, 1)),
+  (0x1D5A6, Mapped(6, 1)),
+  (0x1D5A7, Mapped(7, 1)),
+  (0x1D5A8, Mapped(8, 1)),
+  (0x1D5A9, Mapped(9, 1)),
+  (0x1D5AA, Mapped(10, 1)),
+  (0x1D5AB, Mapped(11, 1)),
+  (0x1D5AC, Mapped(12, 1)),
+  (0x1D5AD, Mapped(13, 1)),
+  (0x1D5AE, Mapped(14, 1)),
+  (0x1D5AF, Mapped(15, 1)),
+  (0x1D5B0, Mapped(16, 1)),
+  (0x1D5B1, Mapped(17, 1)),
+  (0x1D5B2, Mapped(18, 1)),
+  (0x1D5B3, Mapped(19, 1)),
+  (0x1D5B4, Mapped(20, 1)),
+  (0x1D5B5, Mapped(21, 1)),
+  (0x1D5B6, Mapped(22, 1)),
+  (0x1D5B7, Mapped(23, 1)),
+  (0x1D5B8, Mapped(24, 1)),
+  (0x1D5B9, Mapped(25, 1)),
+  (0x1D5BA, Mapped(0, 1)),
+  (0x1D5BB, Mapped(1, 1)),
+  (0x1D5BC, Mapped(2, 1)),
+  (0x1D5BD, Mapped(3, 1)),
+  (0x1D5BE, Mapped(4, 1)),
+  (0x1D5BF, Mapped(5, 1)),
+  (0x1D5C0, Mapped(6, 1)),
+  (0x1D5C1, Mapped(7, 1)),
+  (0x1D5C2, Mapped(8, 1)),
+  (0x1D5C3, Mapped(9, 1)),
+  (0x1D5C4, Mapped(10, 1)),
+  (0x1D5C5, Mapped(11, 1)),
+  (0x1D5C6, Mapped(12, 1)),
+  (0x1D5C7, Mapped(13, 1)),
+  (0x1D5C8, Mapped(14, 1)),
+  (0x1D5C9, Mapped(15, 1)),
+  (0x1D5CA, Mapped(16, 1)),
+  (0x1D5CB, Mapped(17, 1)),
+  (0x1D5CC, Mapped(18, 1)),
+  (0x1D5CD, Mapped(19, 1)),
+  (0x1D5CE, Mapped(20, 1)),
+  (0x1D5CF, Mapped(21, 1)),
+  (0x1D5D0, Mapped(22, 1)),
+  (0x1D5D1, Mapped(23, 1)),
+  (0x1D5D2, Mapped(24, 1)),
+  (0x1D5D3, Mapped(25, 1)),
+  (0x1D5D4, Mapped(0, 1)),
+  (0x1D5D5, Mapped(1, 1)),
+  (0x1D5D6, Mapped(2, 1)),
+  (0x1D5D7, Mapped(3, 1)),
+  (0x1D5D8, Mapped(4, 1)),
+  (0x1D5D9, Mapped(5, 1)),
+  (0x1D5DA, Mapped(6, 1)),
+  (0x1D5DB, Mapped(7, 1)),
+  (0x1D5DC, Mapped(8, 1)),
+  (0x1D5DD, Mapped(9, 1)),
+  (0x1D5DE, Mapped(10, 1)),
+  (0x1D5DF, Mapped(11, 1)),
+  (0x1D5E0, Mapped(12, 1)),
+  (0x1D5E1, Mapped(13, 1)),
+  (0x1D5E2, Mapped(14, 1)),
+  (0x1D5E3, Mapped(15, 1)),
+  (0x1D5E4, Mapped(16, 1)),
+  (0x1D5E5, Mapped(17, 1)),
+  (0x1D5E6, Mapped(18, 1)),
+  (0x1D5E7, Mapped(19, 1)),
+  (0x1D5E8, Mapped(20, 1)),
+  (0x1D5E9, Mapped(21, 1)),
+  (0x1D5EA, Mapped(22, 1)),
+  (0x1D5EB, Mapped(23, 1)),
+  (0x1D5EC, Mapped(24, 1)),
+  (0x1D5ED, Mapped(25, 1)),
+  (0x1D5EE, Mapped(0, 1)),
+  (0x1D5EF, Mapped(1, 1)),
+  (0x1D5F0, Mapped(2, 1)),
+  (0x1D5F1, Mapped(3, 1)),
+  (0x1D5F2, Mapped(4, 1)),
+  (0x1D5F3, Mapped(5, 1)),
+  (0x1D5F4, Mapped(6, 1)),
+  (0x1D5F5, Mapped(7, 1)),
+  (0x1D5F6, Mapped(8, 1)),
+  (0x1D5F7, Mapped(9, 1)),
+  (0x1D5F8, Mapped(10, 1)),
+  (0x1D5F9, Mapped(11, 1)),
+  (0x1D5FA, Mapped(12, 1)),
+  (0x1D5FB, Mapped(13, 1)),
+  (0x1D5FC, Mapped(14, 1)),
+  (0x1D5FD, Mapped(15, 1)),
+  (0x1D5FE, Mapped(16, 1)),
+  (0x1D5FF, Mapped(17, 1)),
+  (0x1D600, Mapped(18, 1)),
+  (0x1D601, Mapped(19, 1)),
+  (0x1D602, Mapped(20, 1)),
+  (0x1D603, Mapped(21, 1)),
+  (0x1D604, Mapped(22, 1)),
+  (0x1D605, Mapped(23, 1)),
+  (0x1D606, Mapped(24, 1)),
+  (0x1D607, Mapped(25, 1)),
+  (0x1D608, Mapped(0, 1)),
+  (0x1D609, Mapped(1, 1)),
+  (0x1D60A, Mapped(2, 1)),
+  (0x1D60B, Mapped(3, 1)),
+  (0x1D60C, Mapped(4, 1)),
+  (0x1D60D, Mapped(5, 1)),
+  (0x1D60E, Mapped(6, 1)),
+  (0x1D60F, Mapped(7, 1)),
+  (0x1D610, Mapped(8, 1)),
+  (0x1D611, Mapped(9, 1)),
+  (0x1D612, Mapped(10, 1)),
+  (0x1D613, Mapped(11, 1)),
+  (0x1D614, Mapped(12, 1)),
+  (0x1D615, Mapped(13, 1)),
+  (0x1D616, Mapped(14, 1)),
+  (0x1D617, Mapped(15, 1)),
+  (0x1D618, Mapped(16, 1)),
+  (0x1D619, Mapped(17, 1)),
+  (0x1D61A, Mapped(18, 1)),
+  (0x1D61B, Mapped(19, 1)),
+  (0x1D61C, Mapped(20, 1)),
+  (0x1D61D, Mapped(21, 1)),
+  (0x1D61E, Mapped(22, 1)),
+  (0x1D61F, Mapped(23, 1)),
+  (0x1D620, Mapped(24, 1)),
+  (0x1D621, Mapped(25, 1)),
+  (0x1D622, Mapped(0, 1)),
+  (0x1D623, Mapped(1, 1)),
+  (0x1D624, Mapped(2, 1)),
+  (0x1D625, Mapped(3, 1)),
+  (0x1D626, Mapped(4, 1)),
+  (0x1D627, Mapped(5, 1)),
+  (0x1D628, Mapped(6, 1)),
+  (0x1D629, Mapped(7, 1)),
+  (0x1D62A, Mapped(8, 1)),
+  (0x1D62B, Mapped(9, 1)),
+  (0x1D62C, Mapped(10, 1)),
+  (0x1D62D, Mapped(11, 1)),
+  (0x1D62E, Mapped(12, 1)),
+  (0x1D62F, Mapped(13, 1)),
+  (0x1D630, Mapped(14, 1)),
+  (0x1D631, Mapped(15, 1)),
+  (0x1D632, Mapped(16, 1)),
+  (0x1D633, Mapped(17, 1)),
+  (0x1D634, Mapped(18, 1)),
+  (0x1D635, Mapped(19, 1)),
+  (0x1D636, Mapped(20, 1)),
+  (0x1D637, Mapped(21, 1)),
+  (0x1D638, Mapped(22, 1)),
+  (0x1D639, Mapped(23, 1)),
+  (0x1D63A, Mapped(24, 1)),
+  (0x1D63B, Mapped(25, 1)),
+  (0x1D63C, Mapped(0, 1)),
+  (0x1D63D, Mapped(1, 1)),
+  (0x1D63E, Mapped(2, 1)),
+  (0x1D63F, Mapped(3, 1)),
+  (0x1D640, Mapped(4, 1)),
+  (0x1D641, Mapped(5, 1)),
+  (0x1D642, Mapped(6, 1)),
+  (0x1D643, Mapped(7, 1)),
+  (0x1D644, Mapped(8, 1)),
+  (0x1D645, Mapped(9, 1)),
+  (0x1D646, Mapped(10, 1)),
+  (0x1D647, Mapped(11, 1)),
+  (0x1D648, Mapped(12, 1)),
+  (0x1D649, Mapped(13, 1)),
+  (0x1D64A, Mapped(14, 1)),
+  (0x1D64B, Mapped(15, 1)),
+  (0x1D64C, Mapped(16, 1)),
+  (0x1D64D, Mapped(17, 1)),
+  (0x1D64E, Mapped(18, 1)),
+  (0x1D64F, Mapped(19, 1)),
+  (0x1D650, Mapped(20, 1)),
+  (0x1D651, Mapped(21, 1)),
+  (0x1D652, Mapped(22, 1)),
+  (0x1D653, Mapped(23, 1)),
+  (0x1D654, Mapped(24, 1)),
+  (0x1D655, Mapped(25, 1)),
+  (0x1D656, Mapped(0, 1)),
+  (0x1D657, Mapped(1, 1)),
+  (0x1D658, Mapped(2, 1)),
+  (0x1D659, Mapped(3, 1)),
+  (0x1D65A, Mapped(4, 1)),
+  (0x1D65B, Mapped(5, 1)),
+  (0x1D65C, Mapped(6, 1)),
+  (0x1D65D, Mapped(7, 1)),
+  (0x1D65E, Mapped(8, 1)),
+  (0x1D65F, Mapped(9, 1)),
+  (0x1D660, Mapped(10, 1)),
+  (0x1D661, Mapped(11, 1)),
+  (0x1D662, Mapped(12, 1)),
+  (0x1D663, Mapped(13, 1)),
+  (0x1D664, Mapped(14, 1)),
+  (0x1D665, Mapped(15, 1)),
+  (0x1D666, Mapped(16, 1)),
+  (0x1D667, Mapped(17, 1)),
+  (0x1D668, Mapped(18, 1)),
+  (0x1D669, Mapped(19, 1)),
+  (0x1D66A, Mapped(20, 1)),
+  (0x1D66B, Mapped(21, 1)),
+  (0x1D66C, Mapped(22, 1)),
+  (0x1D66D, Mapped(23, 1)),
+  (0x1D66E, Mapped(24, 1)),
+  (0x1D66F, Mapped(25, 1)),
+  (0x1D670, Mapped(0, 1)),
+  (0x1D671, Mapped(1, 1)),
+  (0x1D672, Mapped(2, 1)),
+  (0x1D673, Mapped(3, 1)),
+  (0x1D674, Mapped(4, 1)),
+  (0x1D675, Mapped(5, 1)),
+  (0x1D676, Mapped(6, 1)),
+  (0x1D677, Mapped(7, 1)),
+  (0x1D678, Mapped(8, 1)),
+  (0x1D679, Mapped(9, 1)),
+  (0x1D67A, Mapped(10, 1)),
+  (0x1D67B, Mapped(11, 1)),
+  (0x1D67C, Mapped(12, 1)),
+  (0x1D67D, Mapped(13, 1)),
+  (0x1D67E, Mapped(14, 1)),
+  (0x1D67F, Mapped(15, 1)),
+  (0x1D680, Mapped(16, 1)),
+  (0x1D681, Mapped(17, 1)),
+  (0x1D682, Mapped(18, 1)),
+  (0x1D683, Mapped(19, 1)),
+  (0x1D684, Mapped(20, 1)),
+  (0x1D685, Mapped(21, 1)),
+  (0x1D686, Mapped(22, 1)),
+  (0x1D687, Mapped(23, 1)),
+  (0x1D688, Mapped(24, 1)),
+  (0x1D689, Mapped(25, 1)),
+  (0x1D68A, Mapped(0, 1)),
+  (0x1D68B, Mapped(1, 1)),
+  (0x1D68C, Mapped(2, 1)),
+  (0x1D68D, Mapped(3, 1)),
+  (0x1D68E, Mapped(4, 1)),
+  (0x1D68F, Mapped(5, 1)),
+  (0x1D690, Mapped(6, 1)),
+  (0x1D691, Mapped(7, 1)),
+  (0x1D692, Mapped(8, 1)),
+  (0x1D693, Mapped(9, 1)),
+  (0x1D694, Mapped(10, 1)),
+  (0x1D695, Mapped(11, 1)),
+  (0x1D696, Mapped(12, 1)),
+  (0x1D697, Mapped(13, 1)),
+  (0x1D698, Mapped(14, 1)),
+  (0x1D699, Mapped(15, 1)),
+  (0x1D69A, Mapped(16, 1)),
+  (0x1D69B, Mapped(17, 1)),
+  (0x1D69C, Mapped(18, 1)),
+  (0x1D69D, Mapped(19, 1)),
+  (0x1D69E, Mapped(20, 1)),
+  (0x1D69F, Mapped(21, 1)),
+  (0x1D6A0, Mapped(22, 1)),
+  (0x1D6A1, Mapped(23, 1)),
+  (0x1D6A2, Mapped(24, 1)),
+  (0x1D6A3, Mapped(25, 1)),
+  (0x1D6A4, Mapped(10953, 2)),
+  (0x1D6A5, Mapped(10955, 2)),
+  (0x1D6A6, Disallowed),
+  (0x1D6A8, Mapped(536, 2)),
+  (0x1D6A9, Mapped(538, 2)),
+  (0x1D6AA, Mapped(540, 2)),
+  (0x1D6AB, Mapped(542, 2)),
+  (0x1D6AC, Mapped(544, 2)),
+  (0x1D6AD, Mapped(546, 2)),
+  (0x1D6AE, Mapped(548, 2)),
+  (0x1D6AF, Mapped(550, 2)),
+  (0x1D6B0, Mapped(499, 2)),
+  (0x1D6B1, Mapped(552, 2)),
+  (0x1D6B2, Mapped(554, 2)),
+  (0x1D6B3, Mapped(38, 2)),
+  (0x1D6B4, Mapped(556, 2)),
+  (0x1D6B5, Mapped(558, 2)),
+  (0x1D6B6, Mapped(560, 2)),
+  (0x1D6B7, Mapped(562, 2)),
+  (0x1D6B8, Mapped(564, 2)),
+  (0x1D6B9, Mapped(550, 2)),
+  (0x1D6BA, Mapped(566, 2)),
+  (0x1D6BB, Mapped(568, 2)),
+  (0x1D6BC, Mapped(570, 2)),
+  (0x1D6BD, Mapped(572, 2)),
+  (0x1D6BE, Mapped(574, 2)),
+  (0x1D6BF, Mapped(576, 2)),
+  (0x1D6C0, Mapped(578, 2)),
+  (0x1D6C1, Mapped(10957, 3)),
+  (0x1D6C2, Mapped(536, 2)),
+  (0x1D6C3, Mapped(538, 2)),
+  (0x1D6C4, Mapped(540, 2)),
+  (0x1D6C5, Mapped(542, 2)),
+  (0x1D6C6, Mapped(544, 2)),
+  (0x1D6C7, Mapped(546, 2)),
+  (0x1D6C8, Mapped(548, 2)),
+  (0x1D6C9, Mapped(550, 2)),
+  (0x1D6CA, Mapped(499, 2)),
+  (0x1D6CB, Mapped(552, 2)),
+  (0x1D6CC, Mapped(554, 2)),
+  (0x1D6CD, Mapped(38, 2)),
+  (0x1D6CE, Mapped(556, 2)),
+  (0x1D6CF, Mapped(558, 2)),
+  (0x1D6D0, Mapped(560, 2)),
+  (0x1D6D1, Mapped(562, 2)),
+  (0x1D6D2, Mapped(564, 2)),
+  (0x1D6D3, Mapped(566, 2)),
+  (0x1D6D5, Mapped(568, 2)),
+  (0x1D6D6, Mapped(570, 2)),
+  (0x1D6D7, Mapped(572, 2)),
+  (0x1D6D8, Mapped(574, 2)),
+  (0x1D6D9, Mapped(576, 2)),
+  (0x1D6DA, Mapped(578, 2)),
+  (0x1D6DB, Mapped(10960, 3)),
+  (0x1D6DC, Mapped(544, 2)),
+  (0x1D6DD, Mapped(550, 2)),
+  (0x1D6DE, Mapped(552, 2)),
+  (0x1D6DF, Mapped(572, 2)),
+  (0x1D6E0, Mapped(564, 2)),
+  (0x1D6E1, Mapped(562, 2)),
+  (0x1D6E2, Mapped(536, 2)),
+  (0x1D6E3, Mapped(538, 2)),
+  (0x1D6E4, Mapped(540, 2)),
+  (0x1D6E5, Mapped(542, 2)),
+  (0x1D6E6, Mapped(544, 2)),
+  (0x1D6E7, Mapped(546, 2)),
+  (0x1D6E8, Mapped(548, 2)),
+  (0x1D6E9, Mapped(550, 2)),
+  (0x1D6EA, Mapped(499, 2)),
+  (0x1D6EB, Mapped(552, 2)),
+  (0x1D6EC, Mapped(554, 2)),
+  (0x1D6ED, Mapped(38, 2)),
+  (0x1D6EE, Mapped(556, 2)),
+  (0x1D6EF, Mapped(558, 2)),
+  (0x1D6F0, Mapped(560, 2)),
+  (0x1D6F1, Mapped(562, 2)),
+  (0x1D6F2, Mapped(564, 2)),
+  (0x1D6F3, Mapped(550, 2)),
+  (0x1D6F4, Mapped(566, 2)),
+  (0x1D6F5, Mapped(568, 2)),
+  (0x1D6F6, Mapped(570, 2)),
+  (0x1D6F7, Mapped(572, 2)),
+  (0x1D6F8, Mapped(574, 2)),
+  (0x1D6F9, Mapped(576, 2)),
+  (0x1D6FA, Mapped(578, 2)),
+  (0x1D6FB, Mapped(10957, 3)),
+  (0x1D6FC, Mapped(536, 2)),
+  (0x1D6FD, Mapped(538, 2)),
+  (0x1D6FE, Mapped(540, 2)),
+  (0x1D6FF, Mapped(542, 2)),
+  (0x1D700, Mapped(544, 2)),
+  (0x1D701, Mapped(546, 2)),
+  (0x1D702, Mapped(548, 2)),
+  (0x1D703, Mapped(550, 2)),
+  (0x1D704, Mapped(499, 2)),
+  (0x1D705, Mapped(552, 2)),
+  (0x1D706, Mapped(554, 2)),
+  (0x1D707, Mapped(38, 2)),
+  (0x1D708, Mapped(556, 2)),
+  (0x1D709, Mapped(558, 2)),
+  (0x1D70A, Mapped(560, 2)),
+  (0x1D70B, Mapped(562, 2)),
+  (0x1D70C, Mapped(564, 2)),
+  (0x1D70D, Mapped(566, 2)),
+  (0x1D70F, Mapped(568, 2)),
+  (0x1D710, Mapped(570, 2)),
+  (0x1D711, Mapped(572, 2)),
+  (0x1D712, Mapped(574, 2)),
+  (0x1D713, Mapped(576, 2)),
+  (0x1D714, Mapped(578, 2)),
+  (0x1D715, Mapped(10960, 3)),
+  (0x1D716, Mapped(544, 2)),
+  (0x1D717, Mapped(550, 2)),
+  (0x1D718, Mapped(552, 2)),
+  (0x1D719, Mapped(572, 2)),
+  (0x1D71A, Mapped(564, 2)),
+  (0x1D71B, Mapped(562, 2)),
+  (0x1D71C, Mapped(536, 2)),
+  (0x1D71D, Mapped(538, 2)),
+  (0x1D71E, Mapped(540, 2)),
+  (0x1D71F, Mapped(542, 2)),
+  (0x1D720, Mapped(544, 2)),
+  (0x1D721, Mapped(546, 2)),
+  (0x1D722, Mapped(548, 2)),
+  (0x1D723, Mapped(550, 2)),
+  (0x1D724, Mapped(499, 2)),
+  (0x1D725, Mapped(552, 2)),
+  (0x1D726, Mapped(554, 2)),
+  (0x1D727, Mapped(38, 2)),
+  (0x1D728, Mapped(556, 2)),
+  (0x1D729, Mapped(558, 2)),
+  (0x1D72A, Mapped(560, 2)),
+  (0x1D72B, Mapped(562, 2)),
+  (0x1D72C, Mapped(564, 2)),
+  (0x1D72D, Mapped(550, 2)),
+  (0x1D72E, Mapped(566, 2)),
+  (0x1D72F, Mapped(568, 2)),
+  (0x1D730, Mapped(570, 2)),
+  (0x1D731, Mapped(572, 2)),
+  (0x1D732, Mapped(574, 2)),
+  (0x1D733, Mapped(576, 2)),
+  (0x1D734, Mapped(578, 2)),
+  (0x1D735, Mapped(10957, 3)),
+  (0x1D736, Mapped(536, 2)),
+  (0x1D737, Mapped(538, 2)),
+  (0x1D738, Mapped(540, 2)),
+  (0x1D739, Mapped(542, 2)),
+  (0x1D73A, Mapped(544, 2)),
+  (0x1D73B, Mapped(546, 2)),
+  (0x1D73C, Mapped(548, 2)),
+  (0x1D73D, Mapped(550, 2)),
+  (0x1D73E, Mapped(499, 2)),
+  (0x1D73F, Mapped(552, 2)),
+  (0x1D740, Mapped(554, 2)),
+  (0x1D741, Mapped(38, 2)),
+  (0x1D742, Mapped(556, 2)),
+  (0x1D743, Mapped(558, 2)),
+  (0x1D744, Mapped(560, 2)),
+  (0x1D745, Mapped(562, 2)),
+  (0x1D746, Mapped(564, 2)),
+  (0x1D747, Mapped(566, 2)),
+  (0x1D749, Mapped(568, 2)),
+  (0x1D74A, Mapped(570, 2)),
+  (0x1D74B, Mapped(572, 2)),
+  (0x1D74C, Mapped(574, 2)),
+  (0x1D74D, Mapped(576, 2)),
+  (0x1D74E, Mapped(578, 2)),
+  (0x1D74F, Mapped(10960, 3)),
+  (0x1D750, Mapped(544, 2)),
+  (0x1D751, Mapped(550, 2)),
+  (0x1D752, Mapped(552, 2)),
+  (0x1D753, Mapped(572, 2)),
+  (0x1D754, Mapped(564, 2)),
+  (0x1D755, Mapped(562, 2)),
+  (0x1D756, Mapped(536, 2)),
+  (0x1D757, Mapped(538, 2)),
+  (0x1D758, Mapped(540, 2)),
+  (0x1D759, Mapped(542, 2)),
+  (0x1D75A, Mapped(544, 2)),
+  (0x1D75B, Mapped(546, 2)),
+  (0x1D75C, Mapped(548, 2)),
+  (0x1D75D, Mapped(550, 2)),
+  (0x1D75E, Mapped(499, 2)),
+  (0x1D75F, Mapped(552, 2)),
+  (0x1D760, Mapped(554, 2)),
+  (0x1D761, Mapped(38, 2)),
+  (0x1D762, Mapped(556, 2)),
+  (0x1D763, Mapped(558, 2)),
+  (0x1D764, Mapped(560, 2)),
+  (0x1D765, Mapped(562, 2)),
+  (0x1D766, Mapped(564, 2)),
+  (0x1D767, Mapped(550, 2)),
+  (0x1D768, Mapped(566, 2)),
+  (0x1D769, Mapped(568, 2)),
+  (0x1D76A, Mapped(570, 2)),
+  (0x1D76B, Mapped(572, 2)),
+  (0x1D76C, Mapped(574, 2)),
+  (0x1D76D, Mapped(576, 2)),
+  (0x1D76E, Mapped(578, 2)),
+  (0x1D76F, Mapped(10957, 3)),
+  (0x1D770, Mapped(536, 2)),
+  (0x1D771, Mapped(538, 2)),
+  (0x1D772, Mapped(540, 2)),
+  (0x1D773, Mapped(542, 2)),
+  (0x1D774, Mapped(544, 2)),
+  (0x1D775, Mapped(546, 2)),
+  (0x1D776, Mapped(548, 2)),
+  (0x1D777, Mapped(550, 2)),
+  (0x1D778, Mapped(499, 2)),
+  (0x1D779, Mapped(552, 2)),
+  (0x1D77A, Mapped(554, 2)),
+  (0x1D77B, Mapped(38, 2)),
+  (0x1D77C, Mapped(556, 2)),
+  (0x1D77D, Mapped(558, 2)),
+  (0x1D77E, Mapped(560, 2)),
+  (0x1D77F, Mapped(562, 2)),
+  (0x1D780, Mapped(564, 2)),
+  (0x1D781, Mapped(566, 2)),
+  (0x1D783, Mapped(568, 2)),
+  (0x1D784, Mapped(570, 2)),
+  (0x1D785, Mapped(572, 2)),
+  (0x1D786, Mapped(574, 2)),
+  (0x1D787, Mapped(576, 2)),
+  (0x1D788, Mapped(578, 2)),
+  (0x1D789, Mapped(10960, 3)),
+  (0x1D78A, Mapped(544, 2)),
+  (0x1D78B, Mapped(550, 2)),
+  (0x1D78C, Mapped(552, 2)),
+  (0x1D78D, Mapped(572, 2)),
+  (0x1D78E, Mapped(564, 2)),
+  (0x1D78F, Mapped(562, 2)),
+  (0x1D790, Mapped(536, 2)),
+  (0x1D791, Mapped(538, 2)),
+  (0x1D792, Mapped(540, 2)),
+  (0x1D793, Mapped(542, 2)),
+  (0x1D794, Mapped(544, 2)),
+  (0x1D795, Mapped(546, 2)),
+  (0x1D796, Mapped(548, 2)),
+  (0x1D797, Mapped(550, 2)),
+  (0x1D798, Mapped(499, 2)),
+  (0x1D799, Mapped(552, 2)),
+  (0x1D79A, Mapped(554, 2)),
+  (0x1D79B, Mapped(38, 2)),
+  (0x1D79C, Mapped(556, 2)),
+  (0x1D79D, Mapped(558, 2)),
+  (0x1D79E, Mapped(560, 2)),
+  (0x1D79F, Mapped(562, 2)),
+  (0x1D7A0, Mapped(564, 2)),
+  (0x1D7A1, Mapped(550, 2)),
+  (0x1D7A2, Mapped(566, 2)),
+  (0x1D7A3, Mapped(568, 2)),
+  (0x1D7A4, Mapped(570, 2)),
+  (0x1D7A5, Mapped(572, 2)),
+  (0x1D7A6, Mapped(574, 2)),
+  (0x1D7A7, Mapped(576, 2)),
+  (0x1D7A8, Mapped(578, 2)),
+  (0x1D7A9, Mapped(10957, 3)),
+  (0x1D7AA, Mapped(536, 2)),
+  (0x1D7AB, Mapped(538, 2)),
+  (0x1D7AC, Mapped(540, 2)),
+  (0x1D7AD, Mapped(542, 2)),
+  (0x1D7AE, Mapped(544, 2)),
+  (0x1D7AF, Mapped(546, 2)),
+  (0x1D7B0, Mapped(548, 2)),
+  (0x1D7B1, Mapped(550, 2)),
+  (0x1D7B2, Mapped(499, 2)),
+  (0x1D7B3, Mapped(552, 2)),
+  (0x1D7B4, Mapped(554, 2)),
+  (0x1D7B5, Mapped(38, 2)),
+  (0x1D7B6, Mapped(556, 2)),
+  (0x1D7B7, Mapped(558, 2)),
+  (0x1D7B8, Mapped(560, 2)),
+  (0x1D7B9, Mapped(562, 2)),
+  (0x1D7BA, Mapped(564, 2)),
+  (0x1D7BB, Mapped(566, 2)),
+  (0x1D7BD, Mapped(568, 2)),
+  (0x1D7BE, Mapped(570, 2)),
+  (0x1D7BF, Mapped(572, 2)),
+  (0x1D7C0, Mapped(574, 2)),
+  (0x1D7C1, Mapped(576, 2)),
+  (0x1D7C2, Mapped(578, 2)),
+  (0x1D7C3, Mapped(10960, 3)),
+  (0x1D7C4, Mapped(544, 2)),
+  (0x1D7C5, Mapped(550, 2)),
+  (0x1D7C6, Mapped(552, 2)),
+  (0x1D7C7, Mapped(572, 2)),
+  (0x1D7C8, Mapped(564, 2)),
+  (0x1D7C9, Mapped(562, 2)),
+  (0x1D7CA, Mapped(590, 2)),
+  (0x1D7CC, Disallowed),
+  (0x1D7CE, Mapped(2347, 1)),
+  (0x1D7CF, Mapped(43, 1)),
+  (0x1D7D0, Mapped(33, 1)),
+  (0x1D7D1, Mapped(34, 1)),
+  (0x1D7D2, Mapped(2348, 1)),
+  (0x1D7D3, Mapped(2349, 1)),
+  (0x1D7D4, Mapped(2350, 1)),
+  (0x1D7D5, Mapped(2351, 1)),
+  (0x1D7D6, Mapped(2352, 1)),
+  (0x1D7D7, Mapped(2353, 1)),
+  (0x1D7D8, Mapped(2347, 1)),
+  (0x1D7D9, Mapped(43, 1)),
+  (0x1D7DA, Mapped(33, 1)),
+  (0x1D7DB, Mapped(34, 1)),
+  (0x1D7DC, Mapped(2348, 1)),
+  (0x1D7DD, Mapped(2349, 1)),
+  (0x1D7DE, Mapped(2350, 1)),
+  (0x1D7DF, Mapped(2351, 1)),
+  (0x1D7E0, Mapped(2352, 1)),
+  (0x1D7E1, Mapped(2353, 1)),
+  (0x1D7E2, Mapped(2347, 1)),
+  (0x1D7E3, Mapped(43, 1)),
+  (0x1D7E4, Mapped(33, 1)),
+  (0x1D7E5, Mapped(34, 1)),
+  (0x1D7E6, Mapped(2348, 1)),
+  (0x1D7E7, Mapped(2349, 1)),
+  (0x1D7E8, Mapped(2350, 1)),
+  (0x1D7E9, Mapped(2351, 1)),
+  (0x1D7EA, Mapped(2352, 1)),
+  (0x1D7EB, Mapped(2353, 1)),
+  (0x1D7EC, Mapped(2347, 1)),
+  (0x1D7ED, Mapped(43, 1)),
+  (0x1D7EE, Mapped(33, 1)),
+  (0x1D7EF, Mapped(34, 1)),
+  (0x1D7F0, Mapped(2348, 1)),
+  (0x1D7F1, Mapped(2349, 1)),
+  (0x1D7F2, Mapped(2350, 1)),
+  (0x1D7F3, Mapped(2351, 1)),
+  (0x1D7F4, Mapped(2352, 1)),
+  (0x1D7F5, Mapped(2353, 1)),
+  (0x1D7F6, Mapped(2347, 1)),
+  (0x1D7F7, Mapped(43, 1)),
+  (0x1D7F8, Mapped(33, 1)),
+  (0x1D7F9, Mapped(34, 1)),
+  (0x1D7FA, Mapped(2348, 1)),
+  (0x1D7FB, Mapped(2349, 1)),
+  (0x1D7FC, Mapped(2350, 1)),
+  (0x1D7FD, Mapped(2351, 1)),
+  (0x1D7FE, Mapped(2352, 1)),
+  (0x1D7FF, Mapped(2353, 1)),
+  (0x1D800, Valid),
+  (0x1DA8C, Disallowed),
+  (0x1DA9B, Valid),
+  (0x1DAA0, Disallowed),
+  (0x1DAA1, Valid),
+  (0x1DAB0, Disallowed),
+  (0x1E000, Valid),
+  (0x1E007, Disallowed),
+  (0x1E008, Valid),
+  (0x1E019, Disallowed),
+  (0x1E01B, Valid),
+  (0x1E022, Disallowed),
+  (0x1E023, Valid),
+  (0x1E025, Disallowed),
+  (0x1E026, Valid),
+  (0x1E02B, Disallowed),
+  (0x1E100, Valid),
+  (0x1E12D, Disallowed),
+  (0x1E130, Valid),
+  (0x1E13E, Disallowed),
+  (0x1E140, Valid),
+  (0x1E14A, Disallowed),
+  (0x1E14E, Valid),
+  (0x1E150, Disallowed),
+  (0x1E2C0, Valid),
+  (0x1E2FA, Disallowed),
+  (0x1E2FF, Valid),
+  (0x1E300, Disallowed),
+  (0x1E800, Valid),
+  (0x1E8C5, Disallowed),
+  (0x1E8C7, Valid),
+  (0x1E8D7, Disallowed),
+  (0x1E900, Mapped(10963, 4)),
+  (0x1E901, Mapped(10967, 4)),
+  (0x1E902, Mapped(10971, 4)),
+  (0x1E903, Mapped(10975, 4)),
+  (0x1E904, Mapped(10979, 4)),
+  (0x1E905, Mapped(10983, 4)),
+  (0x1E906, Mapped(10987, 4)),
+  (0x1E907, Mapped(10991, 4)),
+  (0x1E908, Mapped(10995, 4)),
+  (0x1E909, Mapped(10999, 4)),
+  (0x1E90A, Mapped(11003, 4)),
+  (0x1E90B, Mapped(11007, 4)),
+  (0x1E90C, Mapped(11011, 4)),
+  (0x1E90D, Mapped(11015, 4)),
+  (0x1E90E, Mapped(11019, 4)),
+  (0x1E90F, Mapped(11023, 4)),
+  (0x1E910, Mapped(11027, 4)),
+  (0x1E911, Mapped(11031, 4)),
+  (0x1E912, Mapped(11035, 4)),
+  (0x1E913, Mapped(11039, 4)),
+  (0x1E914, Mapped(11043, 4)),
+  (0x1E915, Mapped(11047, 4)),
+  (0x1E916, Mapped(11051, 4)),
+  (0x1E917, Mapped(11055, 4)),
+  (0x1E918, Mapped(11059, 4)),
+  (0x1E919, Mapped(11063, 4)),
+  (0x1E91A, Mapped(11067, 4)),
+  (0x1E91B, Mapped(11071, 4)),
+  (0x1E91C, Mapped(11075, 4)),
+  (0x1E91D, Mapped(11079, 4)),
+  (0x1E91E, Mapped(11083, 4)),
+  (0x1E91F, Mapped(11087, 4)),
+  (0x1E920, Mapped(11091, 4)),
+  (0x1E921, Mapped(11095, 4)),
+  (0x1E922, Valid),
+  (0x1E94C, Disallowed),
+  (0x1E950, Valid),
+  (0x1E95A, Disallowed),
+  (0x1E95E, Valid),
+  (0x1E960, Disallowed),
+  (0x1EC71, Valid),
+  (0x1ECB5, Disallowed),
+  (0x1ED01, Valid),
+  (0x1ED3E, Disallowed),
+  (0x1EE00, Mapped(9887, 2)),
+  (0x1EE01, Mapped(9889, 2)),
+  (0x1EE02, Mapped(9897, 2)),
+  (0x1EE03, Mapped(9903, 2)),
+  (0x1EE04, Disallowed),
+  (0x1EE05, Mapped(9941, 2)),
+  (0x1EE06, Mapped(9909, 2)),
+  (0x1EE07, Mapped(9899, 2)),
+  (0x1EE08, Mapped(9919, 2)),
+  (0x1EE09, Mapped(9943, 2)),
+  (0x1EE0A, Mapped(9931, 2)),
+  (0x1EE0B, Mapped(9933, 2)),
+  (0x1EE0C, Mapped(9935, 2)),
+  (0x1EE0D, Mapped(9937, 2)),
+  (0x1EE0E, Mapped(9911, 2)),
+  (0x1EE0F, Mapped(9923, 2)),
+  (0x1EE10, Mapped(9927, 2)),
+  (0x1EE11, Mapped(9915, 2)),
+  (0x1EE12, Mapped(9929, 2)),
+  (0x1EE13, Mapped(9907, 2)),
+  (0x1EE14, Mapped(9913, 2)),
+  (0x1EE15, Mapped(9893, 2)),
+  (0x1EE16, Mapped(9895, 2)),
+  (0x1EE17, Mapped(9901, 2)),
+  (0x1EE18, Mapped(9905, 2)),
+  (0x1EE19, Mapped(9917, 2)),
+  (0x1EE1A, Mapped(9921, 2)),
+  (0x1EE1B, Mapped(9925, 2)),
+  (0x1EE1C, Mapped(11099, 2)),
+  (0x1EE1D, Mapped(8343, 2)),
+  (0x1EE1E, Mapped(11101, 2)),
+  (0x1EE1F, Mapped(11103, 2)),
+  (0x1EE20, Disallowed),
+  (0x1EE21, Mapped(9889, 2)),
+  (0x1EE22, Mapped(9897, 2)),
+  (0x1EE23, Disallowed),
+  (0x1EE24, Mapped(9939, 2)),
+  (0x1EE25, Disallowed),
+  (0x1EE27, Mapped(9899, 2)),
+  (0x1EE28, Disallowed),
+  (0x1EE29, Mapped(9943, 2)),
+  (0x1EE2A, Mapped(9931, 2)),
+  (0x1EE2B, Mapped(9933, 2)),
+  (0x1EE2C, Mapped(9935, 2)),
+  (0x1EE2D, Mapped(9937, 2)),
+  (0x1EE2E, Mapped(9911, 2)),
+  (0x1EE2F, Mapped(9923, 2)),
+  (0x1EE30, Mapped(9927, 2)),
+  (0x1EE31, Mapped(9915, 2)),
+  (0x1EE32, Mapped(9929, 2)),
+  (0x1EE33, Disallowed),
+  (0x1EE34, Mapped(9913, 2)),
+  (0x1EE35, Mapped(9893, 2)),
+  (0x1EE36, Mapped(9895, 2)),
+  (0x1EE37, Mapped(9901, 2)),
+  (0x1EE38, Disallowed),
+  (0x1EE39, Mapped(9917, 2)),
+  (0x1EE3A, Disallowed),
+  (0x1EE3B, Mapped(9925, 2)),
+  (0x1EE3C, Disallowed),
+  (0x1EE42, Mapped(9897, 2)),
+  (0x1EE43, Disallowed),
+  (0x1EE47, Mapped(9899, 2)),
+  (0x1EE48, Disallowed),
+  (0x1EE49, Mapped(9943, 2)),
+  (0x1EE4A, Disallowed),
+  (0x1EE4B, Mapped(9933, 2)),
+  (0x1EE4C, Disallowed),
+  (0x1EE4D, Mapped(9937, 2)),
+  (0x1EE4E, Mapped(9911, 2)),
+  (0x1EE4F, Mapped(9923, 2)),
+  (0x1EE50, Disallowed),
+  (0x1EE51, Mapped(9915, 2)),
+  (0x1EE52, Mapped(9929, 2)),
+  (0x1EE53, Disallowed),
+  (0x1EE54, Mapped(9913, 2)),
+  (0x1EE55, Disallowed),
+  (0x1EE57, Mapped(9901, 2)),
+  (0x1EE58, Disallowed),
+  (0x1EE59, Mapped(9917, 2)),
+  (0x1EE5A, Disallowed),
+  (0x1EE5B, Mapped(9925, 2)),
+  (0x1EE5C, Disallowed),
+  (0x1EE5D, Mapped(8343, 2)),
+  (0x1EE5E, Disallowed),
+  (0x1EE5F, Mapped(11103, 2)),
+  (0x1EE60, Disallowed),
+  (0x1EE61, Mapped(9889, 2)),
+  (0x1EE62, Mapped(9897, 2)),
+  (0x1EE63, Disallowed),
+  (0x1EE64, Mapped(9939, 2)),
+  (0x1EE65, Disallowed),
+  (0x1EE67, Mapped(9899, 2)),
+  (0x1EE68, Mapped(9919, 2)),
+  (0x1EE69, Mapped(9943, 2)),
+  (0x1EE6A, Mapped(9931, 2)),
+  (0x1EE6B, Disallowed),
+  (0x1EE6C, Mapped(9935, 2)),
+  (0x1EE6D, Mapped(9937, 2)),
+  (0x1EE6E, Mapped(9911, 2)),
+  (0x1EE6F, Mapped(9923, 2)),
+  (0x1EE70, Mapped(9927, 2)),
+  (0x1EE71, Mapped(9915, 2)),
+  (0x1EE72, Mapped(9929, 2)),
+  (0x1EE73, Disallowed),
+  (0x1EE74, Mapped(9913, 2)),
+  (0x1EE75, Mapped(9893, 2)),
+  (0x1EE76, Mapped(9895, 2)),
+  (0x1EE77, Mapped(9901, 2)),
+  (0x1EE78, Disallowed),
+  (0x1EE79, Mapped(9917, 2)),
+  (0x1EE7A, Mapped(9921, 2)),
+  (0x1EE7B, Mapped(9925, 2)),
+  (0x1EE7C, Mapped(11099, 2)),
+  (0x1EE7D, Disallowed),
+  (0x1EE7E, Mapped(11101, 2)),
+  (0x1EE7F, Disallowed),
+  (0x1EE80, Mapped(9887, 2)),
+  (0x1EE81, Mapped(9889, 2)),
+  (0x1EE82, Mapped(9897, 2)),
+  (0x1EE83, Mapped(9903, 2)),
+  (0x1EE84, Mapped(9939, 2)),
+  (0x1EE85, Mapped(9941, 2)),
+  (0x1EE86, Mapped(9909, 2)),
+  (0x1EE87, Mapped(9899, 2)),
+  (0x1EE88, Mapped(9919, 2)),
+  (0x1EE89, Mapped(9943, 2)),
+  (0x1EE8A, Disallowed),
+  (0x1EE8B, Mapped(9933, 2)),
+  (0x1EE8C, Mapped(9935, 2)),
+  (0x1EE8D, Mapped(9937, 2)),
+  (0x1EE8E, Mapped(9911, 2)),
+  (0x1EE8F, Mapped(9923, 2)),
+  (0x1EE90, Mapped(9927, 2)),
+  (0x1EE91, Mapped(9915, 2)),
+  (0x1EE92, Mapped(9929, 2)),
+  (0x1EE93, Mapped(9907, 2)),
+  (0x1EE94, Mapped(9913, 2)),
+  (0x1EE95, Mapped(9893, 2)),
+  (0x1EE96, Mapped(9895, 2)),
+  (0x1EE97, Mapped(9901, 2)),
+  (0x1EE98, Mapped(9905, 2)),
+  (0x1EE99, Mapped(9917, 2)),
+  (0x1EE9A, Mapped(9921, 2)),
+  (0x1EE9B, Mapped(9925, 2)),
+  (0x1EE9C, Disallowed),
+  (0x1EEA1, Mapped(9889, 2)),
+  (0x1EEA2, Mapped(9897, 2)),
+  (0x1EEA3, Mapped(9903, 2)),
+  (0x1EEA4, Disallowed),
+  (0x1EEA5, Mapped(9941, 2)),
+  (0x1EEA6, Mapped(9909, 2)),
+  (0x1EEA7, Mapped(9899, 2)),
+  (0x1EEA8, Mapped(9919, 2)),
+  (0x1EEA9, Mapped(9943, 2)),
+  (0x1EEAA, Disallowed),
+  (0x1EEAB, Mapped(9933, 2)),
+  (0x1EEAC, Mapped(9935, 2)),
+  (0x1EEAD, Mapped(9937, 2)),
+  (0x1EEAE, Mapped(9911, 2)),
+  (0x1EEAF, Mapped(9923, 2)),
+  (0x1EEB0, Mapped(9927, 2)),
+  (0x1EEB1, Mapped(9915, 2)),
+  (0x1EEB2, Mapped(9929, 2)),
+  (0x1EEB3, Mapped(9907, 2)),
+  (0x1EEB4, Mapped(9913, 2)),
+  (0x1EEB5, Mapped(9893, 2)),
+  (0x1EEB6, Mapped(9895, 2)),
+  (0x1EEB7, Mapped(9901, 2)),
+  (0x1EEB8, Mapped(9905, 2)),
+  (0x1EEB9, Mapped(9917, 2)),
+  (0x1EEBA, Mapped(9921, 2)),
+  (0x1EEBB, Mapped(9925, 2)),
+  (0x1EEBC, Disallowed),
+  (0x1EEF0, Valid),
+  (0x1EEF2, Disallowed),
+  (0x1F000, Valid),
+  (0x1F02C, Disallowed),
+  (0x1F030, Valid),
+  (0x1F094, Disallowed),
+  (0x1F0A0, Valid),
+  (0x1F0AF, Disallowed),
+  (0x1F0B1, Valid),
+  (0x1F0C0, Disallowed),
+  (0x1F0C1, Valid),
+  (0x1F0D0, Disallowed),
+  (0x1F0D1, Valid),
+  (0x1F0F6, Disallowed),
+  (0x1F101, DisallowedStd3Mapped(11105, 2)),
+  (0x1F102, DisallowedStd3Mapped(11107, 2)),
+  (0x1F103, DisallowedStd3Mapped(11109, 2)),
+  (0x1F104, DisallowedStd3Mapped(11111, 2)),
+  (0x1F105, DisallowedStd3Mapped(11113, 2)),
+  (0x1F106, DisallowedStd3Mapped(11115, 2)),
+  (0x1F107, DisallowedStd3Mapped(11117, 2)),
+  (0x1F108, DisallowedStd3Mapped(11119, 2)),
+  (0x1F109, DisallowedStd3Mapped(11121, 2)),
+  (0x1F10A, DisallowedStd3Mapped(11123, 2)),
+  (0x1F10B, Valid),
+  (0x1F110, DisallowedStd3Mapped(2641, 3)),
+  (0x1F111, DisallowedStd3Mapped(2644, 3)),
+  (0x1F112, DisallowedStd3Mapped(2647, 3)),
+  (0x1F113, DisallowedStd3Mapped(2650, 3)),
+  (0x1F114, DisallowedStd3Mapped(2653, 3)),
+  (0x1F115, DisallowedStd3Mapped(2656, 3)),
+  (0x1F116, DisallowedStd3Mapped(2659, 3)),
+  (0x1F117, DisallowedStd3Mapped(2662, 3)),
+  (0x1F118, DisallowedStd3Mapped(2665, 3)),
+  (0x1F119, DisallowedStd3Mapped(2668, 3)),
+  (0x1F11A, DisallowedStd3Mapped(2671, 3)),
+  (0x1F11B, DisallowedStd3Mapped(2674, 3)),
+  (0x1F11C, DisallowedStd3Mapped(2677, 3)),
+  (0x1F11D, DisallowedStd3Mapped(2680, 3)),
+  (0x1F11E, DisallowedStd3Mapped(2683, 3)),
+  (0x1F11F, DisallowedStd3Mapped(2686, 3)),
+  (0x1F120, DisallowedStd3Mapped(2689, 3)),
+  (0x1F121, DisallowedStd3Mapped(2692, 3)),
+  (0x1F122, DisallowedStd3Mapped(2695, 3)),
+  (0x1F123, DisallowedStd3Mapped(2698, 3)),
+  (0x1F124, DisallowedStd3Mapped(2701, 3)),
+  (0x1F125, DisallowedStd3Mapped(2704, 3)),
+  (0x1F126, DisallowedStd3Mapped(2707, 3)),
+  (0x1F127, DisallowedStd3Mapped(2710, 3)),
+  (0x1F128, DisallowedStd3Mapped(2713, 3)),
+  (0x1F129, DisallowedStd3Mapped(2716, 3)),
+  (0x1F12A, Mapped(11125, 7)),
+  (0x1F12B, Mapped(2, 1)),
+  (0x1F12C, Mapped(17, 1)),
+  (0x1F12D, Mapped(6093, 2)),
+  (0x1F12E, Mapped(11132, 2)),
+  (0x1F12F, Valid),
+  (0x1F130, Mapped(0, 1)),
+  (0x1F131, Mapped(1, 1)),
+  (0x1F132, Mapped(2, 1)),
+  (0x1F133, Mapped(3, 1)),
+  (0x1F134, Mapped(4, 1)),
+  (0x1F135, Mapped(5, 1)),
+  (0x1F136, Mapped(6, 1)),
+  (0x1F137, Mapped(7, 1)),
+  (0x1F138, Mapped(8, 1)),
+  (0x1F139, Mapped(9, 1)),
+  (0x1F13A, Mapped(10, 1)),
+  (0x1F13B, Mapped(11, 1)),
+  (0x1F13C, Mapped(12, 1)),
+  (0x1F13D, Mapped(13, 1)),
+  (0x1F13E, Mapped(14, 1)),
+  (0x1F13F, Mapped(15, 1)),
+  (0x1F140, Mapped(16, 1)),
+  (0x1F141, Mapped(17, 1)),
+  (0x1F142, Mapped(18, 1)),
+  (0x1F143, Mapped(19, 1)),
+  (0x1F144, Mapped(20, 1)),
+  (0x1F145, Mapped(21, 1)),
+  (0x1F146, Mapped(22, 1)),
+  (0x1F147, Mapped(23, 1)),
+  (0x1F148, Mapped(24, 1)),
+  (0x1F149, Mapped(25, 1)),
+  (0x1F14A, Mapped(11134, 2)),
+  (0x1F14B, Mapped(6068, 2)),
+  (0x1F14C, Mapped(11136, 2)),
+  (0x1F14D, Mapped(119, 2)),
+  (0x1F14E, Mapped(11138, 3)),
+  (0x1F14F, Mapped(11141, 2)),
+  (0x1F150, Valid),
+  (0x1F16A, Mapped(11143, 2)),
+  (0x1F16B, Mapped(11145, 2)),
+  (0x1F16C, Mapped(11147, 2)),
+  (0x1F16D, Valid),
+  (0x1F190, Mapped(11149, 2)),
+  (0x1F191, Valid),
+  (0x1F1AE, Disallowed),
+  (0x1F1E6, Valid),
+  (0x1F200, Mapped(11151, 6)),
+  (0x1F201, Mapped(11157, 6)),
+  (0x1F202, Mapped(4720, 3)),
+  (0x1F203, Disallowed),
+  (0x1F210, Mapped(3271, 3)),
+  (0x1F211, Mapped(11163, 3)),
+  (0x1F212, Mapped(11166, 3)),
+  (0x1F213, Mapped(11169, 3)),
+  (0x1F214, Mapped(3100, 3)),
+  (0x1F215, Mapped(11172, 3)),
+  (0x1F216, Mapped(11175, 3)),
+  (0x1F217, Mapped(4057, 3)),
+  (0x1F218, Mapped(11178, 3)),
+  (0x1F219, Mapped(11181, 3)),
+  (0x1F21A, Mapped(11184, 3)),
+  (0x1F21B, Mapped(7414, 3)),
+  (0x1F21C, Mapped(11187, 3)),
+  (0x1F21D, Mapped(11190, 3)),
+  (0x1F21E, Mapped(11193, 3)),
+  (0x1F21F, Mapped(11196, 3)),
+  (0x1F220, Mapped(11199, 3)),
+  (0x1F221, Mapped(11202, 3)),
+  (0x1F222, Mapped(3379, 3)),
+  (0x1F223, Mapped(11205, 3)),
+  (0x1F224, Mapped(11208, 3)),
+  (0x1F225, Mapped(11211, 3)),
+  (0x1F226, Mapped(11214, 3)),
+  (0x1F227, Mapped(11217, 3)),
+  (0x1F228, Mapped(11220, 3)),
+  (0x1F229, Mapped(3082, 3)),
+  (0x1F22A, Mapped(4033, 3)),
+  (0x1F22B, Mapped(11223, 3)),
+  (0x1F22C, Mapped(4569, 3)),
+  (0x1F22D, Mapped(4042, 3)),
+  (0x1F22E, Mapped(4572, 3)),
+  (0x1F22F, Mapped(11226, 3)),
+  (0x1F230, Mapped(3547, 3)),
+  (0x1F231, Mapped(11229, 3)),
+  (0x1F232, Mapped(11232, 3)),
+  (0x1F233, Mapped(11235, 3)),
+  (0x1F234, Mapped(11238, 3)),
+  (0x1F235, Mapped(11241, 3)),
+  (0x1F236, Mapped(4518, 3)),
+  (0x1F237, Mapped(3301, 3)),
+  (0x1F238, Mapped(11244, 3)),
+  (0x1F239, Mapped(11247, 3)),
+  (0x1F23A, Mapped(11250, 3)),
+  (0x1F23B, Mapped(11253, 3)),
+  (0x1F23C, Disallowed),
+  (0x1F240, Mapped(11256, 9)),
+  (0x1F241, Mapped(11265, 9)),
+  (0x1F242, Mapped(11274, 9)),
+  (0x1F243, Mapped(11283, 9)),
+  (0x1F244, Mapped(11292, 9)),
+  (0x1F245, Mapped(11301, 9)),
+  (0x1F246, Mapped(11310, 9)),
+  (0x1F247, Mapped(11319, 9)),
+  (0x1F248, Mapped(11328, 9)),
+  (0x1F249, Disallowed),
+  (0x1F250, Mapped(11337, 3)),
+  (0x1F251, Mapped(11340, 3)),
+  (0x1F252, Disallowed),
+  (0x1F260, Valid),
+  (0x1F266, Disallowed),
+  (0x1F300, Valid),
+  (0x1F6D8, Disallowed),
+  (0x1F6E0, Valid),
+  (0x1F6ED, Disallowed),
+  (0x1F6F0, Valid),
+  (0x1F6FD, Disallowed),
+  (0x1F700, Valid),
+  (0x1F774, Disallowed),
+  (0x1F780, Valid),
+  (0x1F7D9, Disallowed),
+  (0x1F7E0, Valid),
+  (0x1F7EC, Disallowed),
+  (0x1F800, Valid),
+  (0x1F80C, Disallowed),
+  (0x1F810, Valid),
+  (0x1F848, Disallowed),
+  (0x1F850, Valid),
+  (0x1F85A, Disallowed),
+  (0x1F860, Valid),
+  (0x1F888, Disallowed),
+  (0x1F890, Valid),
+  (0x1F8AE, Disallowed),
+  (0x1F8B0, Valid),
+  (0x1F8B2, Disallowed),
+  (0x1F900, Valid),
+  (0x1F979, Disallowed),
+  (0x1F97A, Valid),
+  (0x1F9CC, Disallowed),
+  (0x1F9CD, Valid),
+  (0x1FA54, Disallowed),
+  (0x1FA60, Valid),
+  (0x1FA6E, Disallowed),
+  (0x1FA70, Valid),
+  (0x1FA75, Disallowed),
+  (0x1FA78, Valid),
+  (0x1FA7B, Disallowed),
+  (0x1FA80, Valid),
+  (0x1FA87, Disallowed),
+  (0x1FA90, Valid),
+  (0x1FAA9, Disallowed),
+  (0x1FAB0, Valid),
+  (0x1FAB7, Disallowed),
+  (0x1FAC0, Valid),
+  (0x1FAC3, Disallowed),
+  (0x1FAD0, Valid),
+  (0x1FAD7, Disallowed),
+  (0x1FB00, Valid),
+  (0x1FB93, Disallowed),
+  (0x1FB94, Valid),
+  (0x1FBCB, Disallowed),
+  (0x1FBF0, Mapped(2347, 1)),
+  (0x1FBF1, Mapped(43, 1)),
+  (0x1FBF2, Mapped(33, 1)),
+  (0x1FBF3, Mapped(34, 1)),
+  (0x1FBF4, Mapped(2348, 1)),
+  (0x1FBF5, Mapped(2349, 1)),
+  (0x1FBF6, Mapped(2350, 1)),
+  (0x1FBF7, Mapped(2351, 1)),
+  (0x1FBF8, Mapped(2352, 1)),
+  (0x1FBF9, Mapped(2353, 1)),
+  (0x1FBFA, Disallowed),
+  (0x20000, Valid),
+  (0x2A6DE, Disallowed),
+  (0x2A700, Valid),
+  (0x2B735, Disallowed),
+  (0x2B740, Valid),
+  (0x2B81E, Disallowed),
+  (0x2B820, Valid),
+  (0x2CEA2, Disallowed),
+  (0x2CEB0, Valid),
+  (0x2EBE1, Disallowed),
+  (0x2F800, Mapped(11343, 3)),
+  (0x2F801, Mapped(11346, 3)),
+  (0x2F802, Mapped(11349, 3)),
+  (0x2F803, Mapped(11352, 4)),
+  (0x2F804, Mapped(11356, 3)),
+  (0x2F805, Mapped(7693, 3)),
+  (0x2F806, Mapped(11359, 3)),
+  (0x2F807, Mapped(11362, 3)),
+  (0x2F808, Mapped(11365, 3)),
+  (0x2F809, Mapped(11368, 3)),
+  (0x2F80A, Mapped(7696, 3)),
+  (0x2F80B, Mapped(11371, 3)),
+  (0x2F80C, Mapped(11374, 3)),
+  (0x2F80D, Mapped(11377, 4)),
+  (0x2F80E, Mapped(7699, 3)),
+  (0x2F80F, Mapped(11381, 3)),
+  (0x2F810, Mapped(11384, 3)),
+  (0x2F811, Mapped(11387, 3)),
+  (0x2F812, Mapped(11390, 4)),
+  (0x2F813, Mapped(11394, 3)),
+  (0x2F814, Mapped(11397, 3)),
+  (0x2F815, Mapped(11193, 3)),
+  (0x2F816, Mapped(11400, 4)),
+  (0x2F817, Mapped(11404, 3)),
+  (0x2F818, Mapped(11407, 3)),
+  (0x2F819, Mapped(11410, 3)),
+  (0x2F81A, Mapped(11413, 3)),
+  (0x2F81B, Mapped(7865, 3)),
+  (0x2F81C, Mapped(11416, 4)),
+  (0x2F81D, Mapped(3130, 3)),
+  (0x2F81E, Mapped(11420, 3)),
+  (0x2F81F, Mapped(11423, 3)),
+  (0x2F820, Mapped(11426, 3)),
+  (0x2F821, Mapped(11429, 3)),
+  (0x2F822, Mapped(11247, 3)),
+  (0x2F823, Mapped(11432, 3)),
+  (0x2F824, Mapped(11435, 3)),
+  (0x2F825, Mapped(7880, 3)),
+  (0x2F826, Mapped(7702, 3)),
+  (0x2F827, Mapped(7705, 3)),
+  (0x2F828, Mapped(7883, 3)),
+  (0x2F829, Mapped(11438, 3)),
+  (0x2F82A, Mapped(11441, 3)),
+  (0x2F82B, Mapped(7156, 3)),
+  (0x2F82C, Mapped(11444, 3)),
+  (0x2F82D, Mapped(7708, 3)),
+  (0x2F82E, Mapped(11447, 3)),
+  (0x2F82F, Mapped(11450, 3)),
+  (0x2F830, Mapped(11453, 3)),
+  (0x2F831, Mapped(11456, 3)),
+  (0x2F834, Mapped(11459, 4)),
+  (0x2F835, Mapped(11463, 3)),
+  (0x2F836, Mapped(11466, 3)),
+  (0x2F837, Mapped(11469, 3)),
+  (0x2F838, Mapped(11472, 4)),
+  (0x2F839, Mapped(11476, 3)),
+  (0x2F83A, Mapped(11479, 3)),
+  (0x2F83B, Mapped(11482, 3)),
+  (0x2F83C, Mapped(11485, 3)),
+  (0x2F83D, Mapped(11488, 3)),
+  (0x2F83E, Mapped(11491, 3)),
+  (0x2F83F, Mapped(11494, 3)),
+  (0x2F840, Mapped(11497, 3)),
+  (0x2F841, Mapped(11500, 3)),
+  (0x2F842, Mapped(11503, 3)),
+  (0x2F843, Mapped(11506, 3)),
+  (0x2F844, Mapped(11509, 3)),
+  (0x2F845, Mapped(11512, 3)),
+  (0x2F847, Mapped(7889, 3)),
+  (0x2F848, Mapped(11515, 3)),
+  (0x2F849, Mapped(11518, 3)),
+  (0x2F84A, Mapped(11521, 3)),
+  (0x2F84B, Mapped(11524, 3)),
+  (0x2F84C, Mapped(7714, 3)),
+  (0x2F84D, Mapped(11527, 3)),
+  (0x2F84E, Mapped(11530, 3)),
+  (0x2F84F, Mapped(11533, 3)),
+  (0x2F850, Mapped(7594, 3)),
+  (0x2F851, Mapped(11536, 3)),
+  (0x2F852, Mapped(11539, 3)),
+  (0x2F853, Mapped(11542, 3)),
+  (0x2F854, Mapped(11545, 3)),
+  (0x2F855, Mapped(11548, 3)),
+  (0x2F856, Mapped(11551, 3)),
+  (0x2F857, Mapped(11554, 3)),
+  (0x2F858, Mapped(11557, 3)),
+  (0x2F859, Mapped(11560, 4)),
+  (0x2F85A, Mapped(11564, 3)),
+  (0x2F85B, Mapped(11567, 3)),
+  (0x2F85C, Mapped(11570, 3)),
+  (0x2F85D, Mapped(11172, 3)),
+  (0x2F85E, Mapped(11573, 3)),
+  (0x2F85F, Mapped(11576, 3)),
+  (0x2F860, Mapped(11579, 4)),
+  (0x2F861, Mapped(11583, 4)),
+  (0x2F862, Mapped(11587, 3)),
+  (0x2F863, Mapped(11590, 3)),
+  (0x2F864, Mapped(11593, 3)),
+  (0x2F865, Mapped(11596, 3)),
+  (0x2F866, Mapped(11599, 3)),
+  (0x2F867, Mapped(11602, 3)),
+  (0x2F868, Disallowed),
+  (0x2F869, Mapped(11605, 3)),
+  (0x2F86A, Mapped(11608, 3)),
+  (0x2F86C, Mapped(11611, 4)),
+  (0x2F86D, Mapped(11615, 3)),
+  (0x2F86E, Mapped(11618, 3)),
+  (0x2F86F, Mapped(7144, 3)),
+  (0x2F870, Mapped(11621, 3)),
+  (0x2F871, Mapped(11624, 4)),
+  (0x2F872, Mapped(11628, 3)),
+  (0x2F873, Mapped(11631, 3)),
+  (0x2F874, Disallowed),
+  (0x2F875, Mapped(3208, 3)),
+  (0x2F876, Mapped(11634, 3)),
+  (0x2F877, Mapped(11637, 3)),
+  (0x2F878, Mapped(3214, 3)),
+  (0x2F879, Mapped(11640, 3)),
+  (0x2F87A, Mapped(11643, 3)),
+  (0x2F87B, Mapped(11646, 4)),
+  (0x2F87C, Mapped(11650, 3)),
+  (0x2F87D, Mapped(11653, 4)),
+  (0x2F87E, Mapped(11657, 3)),
+  (0x2F87F, Mapped(11660, 3)),
+  (0x2F880, Mapped(11663, 3)),
+  (0x2F881, Mapped(11666, 3)),
+  (0x2F882, Mapped(11669, 3)),
+  (0x2F883, Mapped(11672, 3)),
+  (0x2F884, Mapped(11675, 3)),
+  (0x2F885, Mapped(11678, 3)),
+  (0x2F886, Mapped(11681, 3)),
+  (0x2F887, Mapped(11684, 3)),
+  (0x2F888, Mapped(11687, 3)),
+  (0x2F889, Mapped(11690, 4)),
+  (0x2F88A, Mapped(11694, 3)),
+  (0x2F88B, Mapped(11697, 3)),
+  (0x2F88C, Mapped(11700, 3)),
+  (0x2F88D, Mapped(11703, 3)),
+  (0x2F88E, Mapped(6988, 3)),
+  (0x2F88F, Mapped(11706, 4)),
+  (0x2F890, Mapped(3244, 3)),
+  (0x2F891, Mapped(11710, 4)),
+  (0x2F893, Mapped(11714, 3)),
+  (0x2F894, Mapped(11717, 3)),
+  (0x2F896, Mapped(11720, 3)),
+  (0x2F897, Mapped(11723, 4)),
+  (0x2F898, Mapped(11727, 4)),
+  (0x2F899, Mapped(11731, 3)),
+  (0x2F89A, Mapped(11734, 3)),
+  (0x2F89B, Mapped(11737, 3)),
+  (0x2F89C, Mapped(11740, 3)),
+  (0x2F89D, Mapped(11743, 3)),
+  (0x2F89E, Mapped(11746, 3)),
+  (0x2F89F, Mapped(11749, 3)),
+  (0x2F8A0, Mapped(11752, 3)),
+  (0x2F8A1, Mapped(11755, 3)),
+  (0x2F8A2, Mapped(11758, 3)),
+  (0x2F8A3, Mapped(7729, 3)),
+  (0x2F8A4, Mapped(11761, 4)),
+  (0x2F8A5, Mapped(11765, 3)),
+  (0x2F8A6, Mapped(11768, 3)),
+  (0x2F8A7, Mapped(11771, 3)),
+  (0x2F8A8, Mapped(7925, 3)),
+  (0x2F8A9, Mapped(11771, 3)),
+  (0x2F8AA, Mapped(11774, 3)),
+  (0x2F8AB, Mapped(7735, 3)),
+  (0x2F8AC, Mapped(11777, 3)),
+  (0x2F8AD, Mapped(11780, 3)),
+  (0x2F8AE, Mapped(11783, 3)),
+  (0x2F8AF, Mapped(11786, 3)),
+  (0x2F8B0, Mapped(7738, 3)),
+  (0x2F8B1, Mapped(6907, 3)),
+  (0x2F8B2, Mapped(11789, 3)),
+  (0x2F8B3, Mapped(11792, 3)),
+  (0x2F8B4, Mapped(11795, 3)),
+  (0x2F8B5, Mapped(11798, 3)),
+  (0x2F8B6, Mapped(11801, 3)),
+  (0x2F8B7, Mapped(11804, 3)),
+  (0x2F8B8, Mapped(11807, 4)),
+  (0x2F8B9, Mapped(11811, 3)),
+  (0x2F8BA, Mapped(11814, 3)),
+  (0x2F8BB, Mapped(11817, 3)),
+  (0x2F8BC, Mapped(11820, 3)),
+  (0x2F8BD, Mapped(11823, 3)),
+  (0x2F8BE, Mapped(11826, 4)),
+  (0x2F8BF, Mapped(11830, 3)),
+  (0x2F8C0, Mapped(11833, 3)),
+  (0x2F8C1, Mapped(11836, 3)),
+  (0x2F8C2, Mapped(11839, 3)),
+  (0x2F8C3, Mapped(11842, 3)),
+  (0x2F8C4, Mapped(11845, 3)),
+  (0x2F8C5, Mapped(11848, 3)),
+  (0x2F8C6, Mapped(11851, 3)),
+  (0x2F8C7, Mapped(11854, 3)),
+  (0x2F8C8, Mapped(7741, 3)),
+  (0x2F8C9, Mapped(11857, 3)),
+  (0x2F8CA, Mapped(11860, 4)),
+  (0x2F8CB, Mapped(11864, 3)),
+  (0x2F8CC, Mapped(11867, 3)),
+  (0x2F8CD, Mapped(11870, 3)),
+  (0x2F8CE, Mapped(11873, 3)),
+  (0x2F8CF, Mapped(7747, 3)),
+  (0x2F8D0, Mapped(11876, 3)),
+  (0x2F8D1, Mapped(11879, 3)),
+  (0x2F8D2, Mapped(11882, 3)),
+  (0x2F8D3, Mapped(11885, 3)),
+  (0x2F8D4, Mapped(11888, 3)),
+  (0x2F8D5, Mapped(11891, 3)),
+  (0x2F8D6, Mapped(11894, 3)),
+  (0x2F8D7, Mapped(11897, 3)),
+  (0x2F8D8, Mapped(6991, 3)),
+  (0x2F8D9, Mapped(7949, 3)),
+  (0x2F8DA, Mapped(11900, 3)),
+  (0x2F8DB, Mapped(11903, 3)),
+  (0x2F8DC, Mapped(11906, 3)),
+  (0x2F8DD, Mapped(11909, 4)),
+  (0x2F8DE, Mapped(11913, 3)),
+  (0x2F8DF, Mapped(11916, 3)),
+  (0x2F8E0, Mapped(11919, 3)),
+  (0x2F8E1, Mapped(11922, 3)),
+  (0x2F8E2, Mapped(7750, 3)),
+  (0x2F8E3, Mapped(11925, 4)),
+  (0x2F8E4, Mapped(11929, 3)),
+  (0x2F8E5, Mapped(11932, 3)),
+  (0x2F8E6, Mapped(11935, 3)),
+  (0x2F8E7, Mapped(8078, 3)),
+  (0x2F8E8, Mapped(11938, 3)),
+  (0x2F8E9, Mapped(11941, 3)),
+  (0x2F8EA, Mapped(11944, 3)),
+  (0x2F8EB, Mapped(11947, 3)),
+  (0x2F8EC, Mapped(11950, 4)),
+  (0x2F8ED, Mapped(11954, 3)),
+  (0x2F8EE, Mapped(11957, 3)),
+  (0x2F8EF, Mapped(11960, 3)),
+  (0x2F8F0, Mapped(11963, 4)),
+  (0x2F8F1, Mapped(11967, 3)),
+  (0x2F8F2, Mapped(11970, 3)),
+  (0x2F8F3, Mapped(11973, 3)),
+  (0x2F8F4, Mapped(11976, 3)),
+  (0x2F8F5, Mapped(7195, 3)),
+  (0x2F8F6, Mapped(11979, 3)),
+  (0x2F8F7, Mapped(11982, 4)),
+  (0x2F8F8, Mapped(11986, 4)),
+  (0x2F8F9, Mapped(11990, 4)),
+  (0x2F8FA, Mapped(11994, 3)),
+  (0x2F8FB, Mapped(11997, 4)),
+  (0x2F8FC, Mapped(12001, 3)),
+  (0x2F8FD, Mapped(12004, 3)),
+  (0x2F8FE, Mapped(12007, 3)),
+  (0x2F8FF, Mapped(12010, 3)),
+  (0x2F900, Mapped(12013, 3)),
+  (0x2F901, Mapped(7753, 3)),
+  (0x2F902, Mapped(7444, 3)),
+  (0x2F903, Mapped(12016, 3)),
+  (0x2F904, Mapped(12019, 3)),
+  (0x2F905, Mapped(12022, 3)),
+  (0x2F906, Mapped(12025, 4)),
+  (0x2F907, Mapped(12029, 3)),
+  (0x2F908, Mapped(12032, 3)),
+  (0x2F909, Mapped(12035, 3)),
+  (0x2F90A, Mapped(12038, 3)),
+  (0x2F90B, Mapped(7958, 3)),
+  (0x2F90C, Mapped(12041, 3)),
+  (0x2F90D, Mapped(12044, 4)),
+  (0x2F90E, Mapped(12048, 3)),
+  (0x2F90F, Mapped(12051, 3)),
+  (0x2F910, Mapped(12054, 4)),
+  (0x2F911, Mapped(12058, 4)),
+  (0x2F912, Mapped(12062, 3)),
+  (0x2F913, Mapped(12065, 3)),
+  (0x2F914, Mapped(7961, 3)),
+  (0x2F915, Mapped(12068, 3)),
+  (0x2F916, Mapped(12071, 3)),
+  (0x2F917, Mapped(12074, 3)),
+  (0x2F918, Mapped(12077, 3)),
+  (0x2F919, Mapped(12080, 3)),
+  (0x2F91A, Mapped(12083, 3)),
+  (0x2F91B, Mapped(12086, 4)),
+  (0x2F91C, Mapped(12090, 3)),
+  (0x2F91D, Mapped(12093, 4)),
+  (0x2F91E, Mapped(12097, 3)),
+  (0x2F91F, Disallowed),
+  (0x2F920, Mapped(12100, 3)),
+  (0x2F921, Mapped(7967, 3)),
+  (0x2F922, Mapped(12103, 3)),
+  (0x2F923, Mapped(12106, 4)),
+  (0x2F924, Mapped(12110, 3)),
+  (0x2F925, Mapped(12113, 3)),
+  (0x2F926, Mapped(12116, 4)),
+  (0x2F927, Mapped(12120, 4)),
+  (0x2F928, Mapped(12124, 3)),
+  (0x2F929, Mapped(12127, 3)),
+  (0x2F92A, Mapped(12130, 3)),
+  (0x2F92B, Mapped(12133, 3)),
+  (0x2F92C, Mapped(12136, 3)),
+  (0x2F92E, Mapped(12139, 3)),
+  (0x2F92F, Mapped(12142, 3)),
+  (0x2F930, Mapped(7973, 3)),
+  (0x2F931, Mapped(12145, 3)),
+  (0x2F932, Mapped(12148, 3)),
+  (0x2F933, Mapped(12151, 3)),
+  (0x2F934, Mapped(12154, 3)),
+  (0x2F935, Mapped(12157, 4)),
+  (0x2F936, Mapped(12161, 3)),
+  (0x2F937, Mapped(12164, 4)),
+  (0x2F938, Mapped(7153, 3)),
+  (0x2F939, Mapped(12168, 4)),
+  (0x2F93A, Mapped(12172, 3)),
+  (0x2F93B, Mapped(12175, 4)),
+  (0x2F93C, Mapped(12179, 4)),
+  (0x2F93D, Mapped(12183, 4)),
+  (0x2F93E, Mapped(12187, 3)),
+  (0x2F93F, Mapped(12190, 3)),
+  (0x2F940, Mapped(7991, 3)),
+  (0x2F941, Mapped(12193, 4)),
+  (0x2F942, Mapped(12197, 4)),
+  (0x2F943, Mapped(12201, 4)),
+  (0x2F944, Mapped(12205, 4)),
+  (0x2F945, Mapped(12209, 3)),
+  (0x2F946, Mapped(12212, 3)),
+  (0x2F948, Mapped(7994, 3)),
+  (0x2F949, Mapped(8084, 3)),
+  (0x2F94A, Mapped(12215, 3)),
+  (0x2F94B, Mapped(12218, 3)),
+  (0x2F94C, Mapped(12221, 3)),
+  (0x2F94D, Mapped(12224, 4)),
+  (0x2F94E, Mapped(12228, 3)),
+  (0x2F94F, Mapped(7042, 3)),
+  (0x2F950, Mapped(8000, 3)),
+  (0x2F951, Mapped(12231, 3)),
+  (0x2F952, Mapped(12234, 4)),
+  (0x2F953, Mapped(7783, 3)),
+  (0x2F954, Mapped(12238, 4)),
+  (0x2F955, Mapped(12242, 4)),
+  (0x2F956, Mapped(7654, 3)),
+  (0x2F957, Mapped(12246, 3)),
+  (0x2F958, Mapped(12249, 3)),
+  (0x2F959, Mapped(7792, 3)),
+  (0x2F95A, Mapped(12252, 3)),
+  (0x2F95B, Mapped(12255, 3)),
+  (0x2F95C, Mapped(12258, 4)),
+  (0x2F95D, Mapped(12262, 4)),
+  (0x2F95F, Disallowed),
+  (0x2F960, Mapped(12266, 3)),
+  (0x2F961, Mapped(12269, 4)),
+  (0x2F962, Mapped(12273, 3)),
+  (0x2F963, Mapped(12276, 3)),
+  (0x2F964, Mapped(12279, 3)),
+  (0x2F965, Mapped(12282, 4)),
+  (0x2F966, Mapped(12286, 3)),
+  (0x2F967, Mapped(12289, 3)),
+  (0x2F968, Mapped(12292, 3)),
+  (0x2F969, Mapped(12295, 3)),
+  (0x2F96A, Mapped(12298, 3)),
+  (0x2F96B, Mapped(12301, 4)),
+  (0x2F96C, Mapped(12305, 3)),
+  (0x2F96D, Mapped(12308, 3)),
+  (0x2F96E, Mapped(12311, 3)),
+  (0x2F96F, Mapped(12314, 3)),
+  (0x2F970, Mapped(12317, 3)),
+  (0x2F971, Mapped(12320, 3)),
+  (0x2F972, Mapped(12323, 4)),
+  (0x2F973, Mapped(12327, 4)),
+  (0x2F974, Mapped(12331, 3)),
+  (0x2F975, Mapped(12334, 4)),
+  (0x2F976, Mapped(12338, 3)),
+  (0x2F977, Mapped(12341, 4)),
+  (0x2F978, Mapped(12345, 3)),
+  (0x2F979, Mapped(12348, 3)),
+  (0x2F97A, Mapped(7810, 3)),
+  (0x2F97B, Mapped(12351, 4)),
+  (0x2F97C, Mapped(12355, 4)),
+  (0x2F97D, Mapped(12359, 3)),
+  (0x2F97E, Mapped(12362, 4)),
+  (0x2F97F, Mapped(12366, 3)),
+  (0x2F980, Mapped(12369, 4)),
+  (0x2F981, Mapped(12373, 3)),
+  (0x2F982, Mapped(12376, 3)),
+  (0x2F983, Mapped(12379, 3)),
+  (0x2F984, Mapped(12382, 3)),
+  (0x2F985, Mapped(12385, 3)),
+  (0x2F986, Mapped(12388, 3)),
+  (0x2F987, Mapped(12391, 4)),
+  (0x2F988, Mapped(12395, 4)),
+  (0x2F989, Mapped(12399, 4)),
+  (0x2F98A, Mapped(12403, 4)),
+  (0x2F98B, Mapped(11714, 3)),
+  (0x2F98C, Mapped(12407, 3)),
+  (0x2F98D, Mapped(12410, 3)),
+  (0x2F98E, Mapped(12413, 3)),
+  (0x2F98F, Mapped(12416, 3)),
+  (0x2F990, Mapped(12419, 3)),
+  (0x2F991, Mapped(12422, 3)),
+  (0x2F992, Mapped(12425, 3)),
+  (0x2F993, Mapped(12428, 3)),
+  (0x2F994, Mapped(12431, 3)),
+  (0x2F995, Mapped(12434, 3)),
+  (0x2F996, Mapped(12437, 3)),
+  (0x2F997, Mapped(12440, 4)),
+  (0x2F998, Mapped(7204, 3)),
+  (0x2F999, Mapped(12444, 3)),
+  (0x2F99A, Mapped(12447, 3)),
+  (0x2F99B, Mapped(12450, 3)),
+  (0x2F99C, Mapped(12453, 3)),
+  (0x2F99D, Mapped(12456, 3)),
+  (0x2F99E, Mapped(12459, 3)),
+  (0x2F99F, Mapped(7819, 3)),
+  (0x2F9A0, Mapped(12462, 3)),
+  (0x2F9A1, Mapped(12465, 3)),
+  (0x2F9A2, Mapped(12468, 3)),
+  (0x2F9A3, Mapped(12471, 3)),
+  (0x2F9A4, Mapped(12474, 4)),
+  (0x2F9A5, Mapped(12478, 4)),
+  (0x2F9A6, Mapped(12482, 4)),
+  (0x2F9A7, Mapped(12486, 3)),
+  (0x2F9A8, Mapped(12489, 3)),
+  (0x2F9A9, Mapped(12492, 3)),
+  (0x2F9AA, Mapped(12495, 3)),
+  (0x2F9AB, Mapped(12498, 4)),
+  (0x2F9AC, Mapped(12502, 3)),
+  (0x2F9AD, Mapped(12505, 4)),
+  (0x2F9AE, Mapped(12509, 3)),
+  (0x2F9AF, Mapped(12512, 3)),
+  (0x2F9B0, Mapped(12515, 4)),
+  (0x2F9B1, Mapped(12519, 4)),
+  (0x2F9B2, Mapped(12523, 3)),
+  (0x2F9B3, Mapped(12526, 3)),
+  (0x2F9B4, Mapped(7027, 3)),
+  (0x2F9B5, Mapped(12529, 3)),
+  (0x2F9B6, Mapped(12532, 3)),
+  (0x2F9B7, Mapped(12535, 3)),
+  (0x2F9B8, Mapped(12538, 3)),
+  (0x2F9B9, Mapped(12541, 3)),
+  (0x2F9BA, Mapped(12544, 3)),
+  (0x2F9BB, Mapped(8021, 3)),
+  (0x2F9BC, Mapped(12547, 3)),
+  (0x2F9BD, Mapped(12550, 3)),
+  (0x2F9BE, Mapped(12553, 3)),
+  (0x2F9BF, Disallowed),
+  (0x2F9C0, Mapped(12556, 3)),
+  (0x2F9C1, Mapped(12559, 3)),
+  (0x2F9C2, Mapped(12562, 3)),
+  (0x2F9C3, Mapped(12565, 3)),
+  (0x2F9C4, Mapped(3514, 3)),
+  (0x2F9C5, Mapped(12568, 4)),
+  (0x2F9C6, Mapped(12572, 3)),
+  (0x2F9C7, Mapped(12575, 3)),
+  (0x2F9C8, Mapped(12578, 3)),
+  (0x2F9C9, Mapped(12581, 3)),
+  (0x2F9CA, Mapped(12584, 3)),
+  (0x2F9CB, Mapped(12587, 4)),
+  (0x2F9CC, Mapped(12591, 4)),
+  (0x2F9CD, Mapped(12595, 3)),
+  (0x2F9CE, Mapped(12598, 3)),
+  (0x2F9CF, Mapped(12601, 3)),
+  (0x2F9D0, Mapped(8036, 3)),
+  (0x2F9D1, Mapped(8039, 3)),
+  (0x2F9D2, Mapped(3535, 3)),
+  (0x2F9D3, Mapped(12604, 4)),
+  (0x2F9D4, Mapped(12608, 3)),
+  (0x2F9D5, Mapped(12611, 3)),
+  (0x2F9D6, Mapped(12614, 3)),
+  (0x2F9D7, Mapped(12617, 3)),
+  (0x2F9D8, Mapped(12620, 4)),
+  (0x2F9D9, Mapped(12624, 4)),
+  (0x2F9DA, Mapped(12628, 3)),
+  (0x2F9DB, Mapped(12631, 3)),
+  (0x2F9DC, Mapped(12634, 3)),
+  (0x2F9DD, Mapped(12637, 4)),
+  (0x2F9DE, Mapped(12641, 3)),
+  (0x2F9DF, Mapped(8042, 3)),
+  (0x2F9E0, Mapped(12644, 4)),
+  (0x2F9E1, Mapped(12648, 4)),
+  (0x2F9E2, Mapped(12652, 3)),
+  (0x2F9E3, Mapped(12655, 3)),
+  (0x2F9E4, Mapped(12658, 3)),
+  (0x2F9E5, Mapped(12661, 4)),
+  (0x2F9E6, Mapped(12665, 3)),
+  (0x2F9E7, Mapped(12668, 3)),
+  (0x2F9E8, Mapped(12671, 3)),
+  (0x2F9E9, Mapped(12674, 3)),
+  (0x2F9EA, Mapped(12677, 3)),
+  (0x2F9EB, Mapped(12680, 3)),
+  (0x2F9EC, Mapped(12683, 3)),
+  (0x2F9ED, Mapped(12686, 4)),
+  (0x2F9EE, Mapped(12690, 3)),
+  (0x2F9EF, Mapped(12693, 3)),
+  (0x2F9F0, Mapped(12696, 3)),
+  (0x2F9F1, Mapped(12699, 4)),
+  (0x2F9F2, Mapped(12703, 3)),
+  (0x2F9F3, Mapped(12706, 3)),
+  (0x2F9F4, Mapped(12709, 3)),
+  (0x2F9F5, Mapped(12712, 3)),
+  (0x2F9F6, Mapped(12715, 4)),
+  (0x2F9F7, Mapped(12719, 4)),
+  (0x2F9F8, Mapped(12723, 3)),
+  (0x2F9F9, Mapped(12726, 3)),
+  (0x2F9FA, Mapped(12729, 3)),
+  (0x2F9FB, Mapped(12732, 4)),
+  (0x2F9FC, Mapped(12736, 3)),
+  (0x2F9FD, Mapped(12739, 4)),
+  (0x2F9FE, Mapped(8060, 3)),
+  (0x2FA00, Mapped(12743, 3)),
+  (0x2FA01, Mapped(12746, 4)),
+  (0x2FA02, Mapped(12750, 3)),
+  (0x2FA03, Mapped(12753, 3)),
+  (0x2FA04, Mapped(12756, 3)),
+  (0x2FA05, Mapped(12759, 3)),
+  (0x2FA06, Mapped(12762, 3)),
+  (0x2FA07, Mapped(12765, 3)),
+  (0x2FA08, Mapped(12768, 3)),
+  (0x2FA09, Mapped(12771, 4)),
+  (0x2FA0A, Mapped(8063, 3)),
+  (0x2FA0B, Mapped(12775, 3)),
+  (0x2FA0C, Mapped(12778, 3)),
+  (0x2FA0D, Mapped(12781, 3)),
+  (0x2FA0E, Mapped(12784, 3)),
+  (0x2FA0F, Mapped(12787, 3)),
+  (0x2FA10, Mapped(12790, 4)),
+  (0x2FA11, Mapped(12794, 3)),
+  (0x2FA12, Mapped(12797, 4)),
+  (0x2FA13, Mapped(12801, 4)),
+  (0x2FA14, Mapped(12805, 4)),
+  (0x2FA15, Mapped(3679, 3)),
+  (0x2FA16, Mapped(12809, 3)),
+  (0x2FA17, Mapped(3691, 3)),
+  (0x2FA18, Mapped(12812, 3)),
+  (0x2FA19, Mapped(12815, 3)),
+  (0x2FA1A, Mapped(12818, 3)),
+  (0x2FA1B, Mapped(12821, 3)),
+  (0x2FA1C, Mapped(3706, 3)),
+  (0x2FA1D, Mapped(12824, 4)),
+  (0x2FA1E, Disallowed),
+  (0x30000, Valid),
+  (0x3134B, Disallowed),
+  (0xE0100, Ignored),
+  (0xE01F0, Disallowed),
+];
+
+/// The strings that code points are mapped to.
+pub(crate) const MAPPED: &str = concat!(
+  "a",
+  "b",
+  "c",
+  "d",
+  "e",
+  "f",
+  "g",
+  "h",
+  "i",
+  "j",
+  "k",
+  "l",
+  "m",
+  "n",
+  "o",
+  "p",
+  "q",
+  "r",
+  "s",
+  "t",
+  "u",
+  "v",
+  "w",
+  "x",
+  "y",
+  "z",
+  " ",
+  " \u{308}",
+  " \u{304}",
+  "2",
+  "3",
+  " \u{301}",
+  "μ",
+  " \u{327}",
+  "1",
+  "1⁄4",
+  "1⁄2",
+  "3⁄4",
+  "à",
+  "á",
+  "â",
+  "ã",
+  "ä",
+  "å",
+  "æ",
+  "ç",
+  "è",
+  "é",
+  "ê",
+  "ë",
+  "ì",
+  "í",
+  "î",
+  "ï",
+  "ð",
+  "ñ",
+  "ò",
+  "ó",
+  "ô",
+  "õ",
+  "ö",
+  "ø",
+  "ù",
+  "ú",
+  "û",
+  "ü",
+  "ý",
+  "þ",
+  "ss",
+  "ā",
+  "ă",
+  "ą",
+  "ć",
+  "ĉ",
+  "ċ",
+  "č",
+  "ď",
+  "đ",
+  "ē",
+  "ĕ",
+  "ė",
+  "ę",
+  "ě",
+  "ĝ",
+  "ğ",
+  "ġ",
+  "ģ",
+  "ĥ",
+  "ħ",
+  "ĩ",
+  "ī",
+  "ĭ",
+  "į",
+  "i\u{307}",
+  "ij",
+  "ĵ",
+  "ķ",
+  "ĺ",
+  "ļ",
+  "ľ",
+  "l·",
+  "ł",
+  "ń",
+  "ņ",
+  "ň",
+  "ʼn",
+  "ŋ",
+  "ō",
+  "ŏ",
+  "ő",
+  "œ",
+  "ŕ",
+  "ŗ",
+  "ř",
+  "ś",
+  "ŝ",
+  "ş",
+  "š",
+  "ţ",
+  "ť",
+  "ŧ",
+  "ũ",
+  "ū",
+  "ŭ",
+  "ů",
+  "ű",
+  "ų",
+  "ŵ",
+  "ŷ",
+  "ÿ",
+  "ź",
+  "ż",
+  "ž",
+  "ɓ",
+  "ƃ",
+  "ƅ",
+  "ɔ",
+  "ƈ",
+  "ɖ",
+  "ɗ",
+  "ƌ",
+  "ǝ",
+  "ə",
+  "ɛ",
+  "ƒ",
+  "ɠ",
+  "ɣ",
+  "ɩ",
+  "ɨ",
+  "ƙ",
+  "ɯ",
+  "ɲ",
+  "ɵ",
+  "ơ",
+  "ƣ",
+  "ƥ",
+  "ʀ",
+  "ƨ",
+  "ʃ",
+  "ƭ",
+  "ʈ",
+  "ư",
+  "ʊ",
+  "ʋ",
+  "ƴ",
+  "ƶ",
+  "ʒ",
+  "ƹ",
+  "ƽ",
+  "dž",
+  "lj",
+  "nj",
+  "ǎ",
+  "ǐ",
+  "ǒ",
+  "ǔ",
+  "ǖ",
+  "ǘ",
+  "ǚ",
+  "ǜ",
+  "ǟ",
+  "ǡ",
+  "ǣ",
+  "ǥ",
+  "ǧ",
+  "ǩ",
+  "ǫ",
+  "ǭ",
+  "ǯ",
+  "dz",
+  "ǵ",
+  "ƕ",
+  "ƿ",
+  "ǹ",
+  "ǻ",
+  "ǽ",
+  "ǿ",
+  "ȁ",
+  "ȃ",
+  "ȅ",
+  "ȇ",
+  "ȉ",
+  "ȋ",
+  "ȍ",
+  "ȏ",
+  "ȑ",
+  "ȓ",
+  "ȕ",
+  "ȗ",
+  "ș",
+  "ț",
+  "ȝ",
+  "ȟ",
+  "ƞ",
+  "ȣ",
+  "ȥ",
+  "ȧ",
+  "ȩ",
+  "ȫ",
+  "ȭ",
+  "ȯ",
+  "ȱ",
+  "ȳ",
+  "ⱥ",
+  "ȼ",
+  "ƚ",
+  "ⱦ",
+  "ɂ",
+  "ƀ",
+  "ʉ",
+  "ʌ",
+  "ɇ",
+  "ɉ",
+  "ɋ",
+  "ɍ",
+  "ɏ",
+  "ɦ",
+  "ɹ",
+  "ɻ",
+  "ʁ",
+  " \u{306}",
+  " \u{307}",
+  " \u{30A}",
+  " \u{328}",
+  " \u{303}",
+  " \u{30B}",
+  "ʕ",
+  "\u{300}",
+  "\u{301}",
+  "\u{313}",
+  "\u{308}\u{301}",
+  "ι",
+  "ͱ",
+  "ͳ",
+  "ʹ",
+  "ͷ",
+  " ι",
+  ";",
+  "ϳ",
+  " \u{308}\u{301}",
+  "ά",
+  "·",
+  "έ",
+  "ή",
+  "ί",
+  "ό",
+  "ύ",
+  "ώ",
+  "α",
+  "β",
+  "γ",
+  "δ",
+  "ε",
+  "ζ",
+  "η",
+  "θ",
+  "κ",
+  "λ",
+  "ν",
+  "ξ",
+  "ο",
+  "π",
+  "ρ",
+  "σ",
+  "τ",
+  "υ",
+  "φ",
+  "χ",
+  "ψ",
+  "ω",
+  "ϊ",
+  "ϋ",
+  "ϗ",
+  "ϙ",
+  "ϛ",
+  "ϝ",
+  "ϟ",
+  "ϡ",
+  "ϣ",
+  "ϥ",
+  "ϧ",
+  "ϩ",
+  "ϫ",
+  "ϭ",
+  "ϯ",
+  "ϸ",
+  "ϻ",
+  "ͻ",
+  "ͼ",
+  "ͽ",
+  "ѐ",
+  "ё",
+  "ђ",
+  "ѓ",
+  "є",
+  "ѕ",
+  "і",
+  "ї",
+  "ј",
+  "љ",
+  "њ",
+  "ћ",
+  "ќ",
+  "ѝ",
+  "ў",
+  "џ",
+  "а",
+  "б",
+  "в",
+  "г",
+  "д",
+  "е",
+  "ж",
+  "з",
+  "и",
+  "й",
+  "к",
+  "л",
+  "м",
+  "н",
+  "о",
+  "п",
+  "р",
+  "с",
+  "т",
+  "у",
+  "ф",
+  "х",
+  "ц",
+  "ч",
+  "ш",
+  "щ",
+  "ъ",
+  "ы",
+  "ь",
+  "э",
+  "ю",
+  "я",
+  "ѡ",
+  "ѣ",
+  "ѥ",
+  "ѧ",
+  "ѩ",
+  "ѫ",
+  "ѭ",
+  "ѯ",
+  "ѱ",
+  "ѳ",
+  "ѵ",
+  "ѷ",
+  "ѹ",
+  "ѻ",
+  "ѽ",
+  "ѿ",
+  "ҁ",
+  "ҋ",
+  "ҍ",
+  "ҏ",
+  "ґ",
+  "ғ",
+  "ҕ",
+  "җ",
+  "ҙ",
+  "қ",
+  "ҝ",
+  "ҟ",
+  "ҡ",
+  "ң",
+  "ҥ",
+  "ҧ",
+  "ҩ",
+  "ҫ",
+  "ҭ",
+  "ү",
+  "ұ",
+  "ҳ",
+  "ҵ",
+  "ҷ",
+  "ҹ",
+  "һ",
+  "ҽ",
+  "ҿ",
+  "ӂ",
+  "ӄ",
+  "ӆ",
+  "ӈ",
+  "ӊ",
+  "ӌ",
+  "ӎ",
+  "ӑ",
+  "ӓ",
+  "ӕ",
+  "ӗ",
+  "ә",
+  "ӛ",
+  "ӝ",
+  "ӟ",
+  "ӡ",
+  "ӣ",
+  "ӥ",
+  "ӧ",
+  "ө",
+  "ӫ",
+  "ӭ",
+  "ӯ",
+  "ӱ",
+  "ӳ",
+  "ӵ",
+  "ӷ",
+  "ӹ",
+  "ӻ",
+  "ӽ",
+  "ӿ",
+  "ԁ",
+  "ԃ",
+  "ԅ",
+  "ԇ",
+  "ԉ",
+  "ԋ",
+  "ԍ",
+  "ԏ",
+  "ԑ",
+  "ԓ",
+  "ԕ",
+  "ԗ",
+  "ԙ",
+  "ԛ",
+  "ԝ",
+  "ԟ",
+  "ԡ",
+  "ԣ",
+  "ԥ",
+  "ԧ",
+  "ԩ",
+  "ԫ",
+  "ԭ",
+  "ԯ",
+  "ա",
+  "բ",
+  "գ",
+  "դ",
+  "ե",
+  "զ",
+  "է",
+  "ը",
+  "թ",
+  "ժ",
+  "ի",
+  "լ",
+  "խ",
+  "ծ",
+  "կ",
+  "հ",
+  "ձ",
+  "ղ",
+  "ճ",
+  "մ",
+  "յ",
+  "ն",
+  "շ",
+  "ո",
+  "չ",
+  "պ",
+  "ջ",
+  "ռ",
+  "ս",
+  "վ",
+  "տ",
+  "ր",
+  "ց",
+  "ւ",
+  "փ",
+  "ք",
+  "օ",
+  "ֆ",
+  "եւ",
+  "اٴ",
+  "وٴ",
+  "ۇٴ",
+  "يٴ",
+  "क\u{93C}",
+  "ख\u{93C}",
+  "ग\u{93C}",
+  "ज\u{93C}",
+  "ड\u{93C}",
+  "ढ\u{93C}",
+  "फ\u{93C}",
+  "य\u{93C}",
+  "ড\u{9BC}",
+  "ঢ\u{9BC}",
+  "য\u{9BC}",
+  "ਲ\u{A3C}",
+  "ਸ\u{A3C}",
+  "ਖ\u{A3C}",
+  "ਗ\u{A3C}",
+  "ਜ\u{A3C}",
+  "ਫ\u{A3C}",
+  "ଡ\u{B3C}",
+  "ଢ\u{B3C}",
+  "\u{E4D}า",
+  "\u{ECD}າ",
+  "ຫນ",
+  "ຫມ",
+  "་",
+  "ག\u{FB7}",
+  "ཌ\u{FB7}",
+  "ད\u{FB7}",
+  "བ\u{FB7}",
+  "ཛ\u{FB7}",
+  "ཀ\u{FB5}",
+  "\u{F71}\u{F72}",
+  "\u{F71}\u{F74}",
+  "\u{FB2}\u{F80}",
+  "\u{FB2}\u{F71}\u{F80}",
+  "\u{FB3}\u{F80}",
+  "\u{FB3}\u{F71}\u{F80}",
+  "\u{F71}\u{F80}",
+  "\u{F92}\u{FB7}",
+  "\u{F9C}\u{FB7}",
+  "\u{FA1}\u{FB7}",
+  "\u{FA6}\u{FB7}",
+  "\u{FAB}\u{FB7}",
+  "\u{F90}\u{FB5}",
+  "ⴧ",
+  "ⴭ",
+  "ნ",
+  "Ᏸ",
+  "Ᏹ",
+  "Ᏺ",
+  "Ᏻ",
+  "Ᏼ",
+  "Ᏽ",
+  "ꙋ",
+  "ა",
+  "ბ",
+  "გ",
+  "დ",
+  "ე",
+  "ვ",
+  "ზ",
+  "თ",
+  "ი",
+  "კ",
+  "ლ",
+  "მ",
+  "ო",
+  "პ",
+  "ჟ",
+  "რ",
+  "ს",
+  "ტ",
+  "უ",
+  "ფ",
+  "ქ",
+  "ღ",
+  "ყ",
+  "შ",
+  "ჩ",
+  "ც",
+  "ძ",
+  "წ",
+  "ჭ",
+  "ხ",
+  "ჯ",
+  "ჰ",
+  "ჱ",
+  "ჲ",
+  "ჳ",
+  "ჴ",
+  "ჵ",
+  "ჶ",
+  "ჷ",
+  "ჸ",
+  "ჹ",
+  "ჺ",
+  "ჽ",
+  "ჾ",
+  "ჿ",
+  "ɐ",
+  "ɑ",
+  "ᴂ",
+  "ɜ",
+  "ᴖ",
+  "ᴗ",
+  "ᴝ",
+  "ᴥ",
+  "ɒ",
+  "ɕ",
+  "ɟ",
+  "ɡ",
+  "ɥ",
+  "ɪ",
+  "ᵻ",
+  "ʝ",
+  "ɭ",
+  "ᶅ",
+  "ʟ",
+  "ɱ",
+  "ɰ",
+  "ɳ",
+  "ɴ",
+  "ɸ",
+  "ʂ",
+  "ƫ",
+  "ᴜ",
+  "ʐ",
+  "ʑ",
+  "ḁ",
+  "ḃ",
+  "ḅ",
+  "ḇ",
+  "ḉ",
+  "ḋ",
+  "ḍ",
+  "ḏ",
+  "ḑ",
+  "ḓ",
+  "ḕ",
+  "ḗ",
+  "ḙ",
+  "ḛ",
+  "ḝ",
+  "ḟ",
+  "ḡ",
+  "ḣ",
+  "ḥ",
+  "ḧ",
+  "ḩ",
+  "ḫ",
+  "ḭ",
+  "ḯ",
+  "ḱ",
+  "ḳ",
+  "ḵ",
+  "ḷ",
+  "ḹ",
+  "ḻ",
+  "ḽ",
+  "ḿ",
+  "ṁ",
+  "ṃ",
+  "ṅ",
+  "ṇ",
+  "ṉ",
+  "ṋ",
+  "ṍ",
+  "ṏ",
+  "ṑ",
+  "ṓ",
+  "ṕ",
+  "ṗ",
+  "ṙ",
+  "ṛ",
+  "ṝ",
+  "ṟ",
+  "ṡ",
+  "ṣ",
+  "ṥ",
+  "ṧ",
+  "ṩ",
+  "ṫ",
+  "ṭ",
+  "ṯ",
+  "ṱ",
+  "ṳ",
+  "ṵ",
+  "ṷ",
+  "ṹ",
+  "ṻ",
+  "ṽ",
+  "ṿ",
+  "ẁ",
+  "ẃ",
+  "ẅ",
+  "ẇ",
+  "ẉ",
+  "ẋ",
+  "ẍ",
+  "ẏ",
+  "ẑ",
+  "ẓ",
+  "ẕ",
+  "aʾ",
+  "ạ",
+  "ả",
+  "ấ",
+  "ầ",
+  "ẩ",
+  "ẫ",
+  "ậ",
+  "ắ",
+  "ằ",
+  "ẳ",
+  "ẵ",
+  "ặ",
+  "ẹ",
+  "ẻ",
+  "ẽ",
+  "ế",
+  "ề",
+  "ể",
+  "ễ",
+  "ệ",
+  "ỉ",
+  "ị",
+  "ọ",
+  "ỏ",
+  "ố",
+  "ồ",
+  "ổ",
+  "ỗ",
+  "ộ",
+  "ớ",
+  "ờ",
+  "ở",
+  "ỡ",
+  "ợ",
+  "ụ",
+  "ủ",
+  "ứ",
+  "ừ",
+  "ử",
+  "ữ",
+  "ự",
+  "ỳ",
+  "ỵ",
+  "ỷ",
+  "ỹ",
+  "ỻ",
+  "ỽ",
+  "ỿ",
+  "ἀ",
+  "ἁ",
+  "ἂ",
+  "ἃ",
+  "ἄ",
+  "ἅ",
+  "ἆ",
+  "ἇ",
+  "ἐ",
+  "ἑ",
+  "ἒ",
+  "ἓ",
+  "ἔ",
+  "ἕ",
+  "ἠ",
+  "ἡ",
+  "ἢ",
+  "ἣ",
+  "ἤ",
+  "ἥ",
+  "ἦ",
+  "ἧ",
+  "ἰ",
+  "ἱ",
+  "ἲ",
+  "ἳ",
+  "ἴ",
+  "ἵ",
+  "ἶ",
+  "ἷ",
+  "ὀ",
+  "ὁ",
+  "ὂ",
+  "ὃ",
+  "ὄ",
+  "ὅ",
+  "ὑ",
+  "ὓ",
+  "ὕ",
+  "ὗ",
+  "ὠ",
+  "ὡ",
+  "ὢ",
+  "ὣ",
+  "ὤ",
+  "ὥ",
+  "ὦ",
+  "ὧ",
+  "ἀι",
+  "ἁι",
+  "ἂι",
+  "ἃι",
+  "ἄι",
+  "ἅι",
+  "ἆι",
+  "ἇι",
+  "ἠι",
+  "ἡι",
+  "ἢι",
+  "ἣι",
+  "ἤι",
+  "ἥι",
+  "ἦι",
+  "ἧι",
+  "ὠι",
+  "ὡι",
+  "ὢι",
+  "ὣι",
+  "ὤι",
+  "ὥι",
+  "ὦι",
+  "ὧι",
+  "ὰι",
+  "αι",
+  "άι",
+  "ᾶι",
+  "ᾰ",
+  "ᾱ",
+  "ὰ",
+  " \u{313}",
+  " \u{342}",
+  " \u{308}\u{342}",
+  "ὴι",
+  "ηι",
+  "ήι",
+  "ῆι",
+  "ὲ",
+  "ὴ",
+  " \u{313}\u{300}",
+  " \u{313}\u{301}",
+  " \u{313}\u{342}",
+  "ΐ",
+  "ῐ",
+  "ῑ",
+  "ὶ",
+  " \u{314}\u{300}",
+  " \u{314}\u{301}",
+  " \u{314}\u{342}",
+  "ΰ",
+  "ῠ",
+  "ῡ",
+  "ὺ",
+  "ῥ",
+  " \u{308}\u{300}",
+  "`",
+  "ὼι",
+  "ωι",
+  "ώι",
+  "ῶι",
+  "ὸ",
+  "ὼ",
+  " \u{314}",
+  "",
+  "‐",
+  " \u{333}",
+  "′′",
+  "′′′",
+  "‵‵",
+  "‵‵‵",
+  "!!",
+  " \u{305}",
+  "??",
+  "?!",
+  "!?",
+  "′′′′",
+  "0",
+  "4",
+  "5",
+  "6",
+  "7",
+  "8",
+  "9",
+  "+",
+  "−",
+  "=",
+  "(",
+  ")",
+  "rs",
+  "a/c",
+  "a/s",
+  "°c",
+  "c/o",
+  "c/u",
+  "°f",
+  "no",
+  "sm",
+  "tel",
+  "tm",
+  "א",
+  "ב",
+  "ג",
+  "ד",
+  "fax",
+  "∑",
+  "1⁄7",
+  "1⁄9",
+  "1⁄10",
+  "1⁄3",
+  "2⁄3",
+  "1⁄5",
+  "2⁄5",
+  "3⁄5",
+  "4⁄5",
+  "1⁄6",
+  "5⁄6",
+  "1⁄8",
+  "3⁄8",
+  "5⁄8",
+  "7⁄8",
+  "1⁄",
+  "ii",
+  "iii",
+  "iv",
+  "vi",
+  "vii",
+  "viii",
+  "ix",
+  "xi",
+  "xii",
+  "0⁄3",
+  "∫∫",
+  "∫∫∫",
+  "∮∮",
+  "∮∮∮",
+  "〈",
+  "〉",
+  "10",
+  "11",
+  "12",
+  "13",
+  "14",
+  "15",
+  "16",
+  "17",
+  "18",
+  "19",
+  "20",
+  "(1)",
+  "(2)",
+  "(3)",
+  "(4)",
+  "(5)",
+  "(6)",
+  "(7)",
+  "(8)",
+  "(9)",
+  "(10)",
+  "(11)",
+  "(12)",
+  "(13)",
+  "(14)",
+  "(15)",
+  "(16)",
+  "(17)",
+  "(18)",
+  "(19)",
+  "(20)",
+  "(a)",
+  "(b)",
+  "(c)",
+  "(d)",
+  "(e)",
+  "(f)",
+  "(g)",
+  "(h)",
+  "(i)",
+  "(j)",
+  "(k)",
+  "(l)",
+  "(m)",
+  "(n)",
+  "(o)",
+  "(p)",
+  "(q)",
+  "(r)",
+  "(s)",
+  "(t)",
+  "(u)",
+  "(v)",
+  "(w)",
+  "(x)",
+  "(y)",
+  "(z)",
+  "∫∫∫∫",
+  "::=",
+  "==",
+  "===",
+  "⫝\u{338}",
+  "ⰰ",
+  "ⰱ",
+  "ⰲ",
+  "ⰳ",
+  "ⰴ",
+  "ⰵ",
+  "ⰶ",
+  "ⰷ",
+  "ⰸ",
+  "ⰹ",
+  "ⰺ",
+  "ⰻ",
+  "ⰼ",
+  "ⰽ",
+  "ⰾ",
+  "ⰿ",
+  "ⱀ",
+  "ⱁ",
+  "ⱂ",
+  "ⱃ",
+  "ⱄ",
+  "ⱅ",
+  "ⱆ",
+  "ⱇ",
+  "ⱈ",
+  "ⱉ",
+  "ⱊ",
+  "ⱋ",
+  "ⱌ",
+  "ⱍ",
+  "ⱎ",
+  "ⱏ",
+  "ⱐ",
+  "ⱑ",
+  "ⱒ",
+  "ⱓ",
+  "ⱔ",
+  "ⱕ",
+  "ⱖ",
+  "ⱗ",
+  "ⱘ",
+  "ⱙ",
+  "ⱚ",
+  "ⱛ",
+  "ⱜ",
+  "ⱝ",
+  "ⱞ",
+  "ⱡ",
+  "ɫ",
+  "ᵽ",
+  "ɽ",
+  "ⱨ",
+  "ⱪ",
+  "ⱬ",
+  "ⱳ",
+  "ⱶ",
+  "ȿ",
+  "ɀ",
+  "ⲁ",
+  "ⲃ",
+  "ⲅ",
+  "ⲇ",
+  "ⲉ",
+  "ⲋ",
+  "ⲍ",
+  "ⲏ",
+  "ⲑ",
+  "ⲓ",
+  "ⲕ",
+  "ⲗ",
+  "ⲙ",
+  "ⲛ",
+  "ⲝ",
+  "ⲟ",
+  "ⲡ",
+  "ⲣ",
+  "ⲥ",
+  "ⲧ",
+  "ⲩ",
+  "ⲫ",
+  "ⲭ",
+  "ⲯ",
+  "ⲱ",
+  "ⲳ",
+  "ⲵ",
+  "ⲷ",
+  "ⲹ",
+  "ⲻ",
+  "ⲽ",
+  "ⲿ",
+  "ⳁ",
+  "ⳃ",
+  "ⳅ",
+  "ⳇ",
+  "ⳉ",
+  "ⳋ",
+  "ⳍ",
+  "ⳏ",
+  "ⳑ",
+  "ⳓ",
+  "ⳕ",
+  "ⳗ",
+  "ⳙ",
+  "ⳛ",
+  "ⳝ",
+  "ⳟ",
+  "ⳡ",
+  "ⳣ",
+  "ⳬ",
+  "ⳮ",
+  "ⳳ",
+  "ⵡ",
+  "母",
+  "龟",
+  "一",
+  "丨",
+  "丶",
+  "丿",
+  "乙",
+  "亅",
+  "二",
+  "亠",
+  "人",
+  "儿",
+  "入",
+  "八",
+  "冂",
+  "冖",
+  "冫",
+  "几",
+  "凵",
+  "刀",
+  "力",
+  "勹",
+  "匕",
+  "匚",
+  "匸",
+  "十",
+  "卜",
+  "卩",
+  "厂",
+  "厶",
+  "又",
+  "口",
+  "囗",
+  "土",
+  "士",
+  "夂",
+  "夊",
+  "夕",
+  "大",
+  "女",
+  "子",
+  "宀",
+  "寸",
+  "小",
+  "尢",
+  "尸",
+  "屮",
+  "山",
+  "巛",
+  "工",
+  "己",
+  "巾",
+  "干",
+  "幺",
+  "广",
+  "廴",
+  "廾",
+  "弋",
+  "弓",
+  "彐",
+  "彡",
+  "彳",
+  "心",
+  "戈",
+  "戶",
+  "手",
+  "支",
+  "攴",
+  "文",
+  "斗",
+  "斤",
+  "方",
+  "无",
+  "日",
+  "曰",
+  "月",
+  "木",
+  "欠",
+  "止",
+  "歹",
+  "殳",
+  "毋",
+  "比",
+  "毛",
+  "氏",
+  "气",
+  "水",
+  "火",
+  "爪",
+  "父",
+  "爻",
+  "爿",
+  "片",
+  "牙",
+  "牛",
+  "犬",
+  "玄",
+  "玉",
+  "瓜",
+  "瓦",
+  "甘",
+  "生",
+  "用",
+  "田",
+  "疋",
+  "疒",
+  "癶",
+  "白",
+  "皮",
+  "皿",
+  "目",
+  "矛",
+  "矢",
+  "石",
+  "示",
+  "禸",
+  "禾",
+  "穴",
+  "立",
+  "竹",
+  "米",
+  "糸",
+  "缶",
+  "网",
+  "羊",
+  "羽",
+  "老",
+  "而",
+  "耒",
+  "耳",
+  "聿",
+  "肉",
+  "臣",
+  "自",
+  "至",
+  "臼",
+  "舌",
+  "舛",
+  "舟",
+  "艮",
+  "色",
+  "艸",
+  "虍",
+  "虫",
+  "血",
+  "行",
+  "衣",
+  "襾",
+  "見",
+  "角",
+  "言",
+  "谷",
+  "豆",
+  "豕",
+  "豸",
+  "貝",
+  "赤",
+  "走",
+  "足",
+  "身",
+  "車",
+  "辛",
+  "辰",
+  "辵",
+  "邑",
+  "酉",
+  "釆",
+  "里",
+  "金",
+  "長",
+  "門",
+  "阜",
+  "隶",
+  "隹",
+  "雨",
+  "靑",
+  "非",
+  "面",
+  "革",
+  "韋",
+  "韭",
+  "音",
+  "頁",
+  "風",
+  "飛",
+  "食",
+  "首",
+  "香",
+  "馬",
+  "骨",
+  "高",
+  "髟",
+  "鬥",
+  "鬯",
+  "鬲",
+  "鬼",
+  "魚",
+  "鳥",
+  "鹵",
+  "鹿",
+  "麥",
+  "麻",
+  "黃",
+  "黍",
+  "黑",
+  "黹",
+  "黽",
+  "鼎",
+  "鼓",
+  "鼠",
+  "鼻",
+  "齊",
+  "齒",
+  "龍",
+  "龜",
+  "龠",
+  ".",
+  "〒",
+  "卄",
+  "卅",
+  " \u{3099}",
+  " \u{309A}",
+  "より",
+  "コト",
+  "ᄀ",
+  "ᄁ",
+  "ᆪ",
+  "ᄂ",
+  "ᆬ",
+  "ᆭ",
+  "ᄃ",
+  "ᄄ",
+  "ᄅ",
+  "ᆰ",
+  "ᆱ",
+  "ᆲ",
+  "ᆳ",
+  "ᆴ",
+  "ᆵ",
+  "ᄚ",
+  "ᄆ",
+  "ᄇ",
+  "ᄈ",
+  "ᄡ",
+  "ᄉ",
+  "ᄊ",
+  "ᄋ",
+  "ᄌ",
+  "ᄍ",
+  "ᄎ",
+  "ᄏ",
+  "ᄐ",
+  "ᄑ",
+  "ᄒ",
+  "ᅡ",
+  "ᅢ",
+  "ᅣ",
+  "ᅤ",
+  "ᅥ",
+  "ᅦ",
+  "ᅧ",
+  "ᅨ",
+  "ᅩ",
+  "ᅪ",
+  "ᅫ",
+  "ᅬ",
+  "ᅭ",
+  "ᅮ",
+  "ᅯ",
+  "ᅰ",
+  "ᅱ",
+  "ᅲ",
+  "ᅳ",
+  "ᅴ",
+  "ᅵ",
+  "ᄔ",
+  "ᄕ",
+  "ᇇ",
+  "ᇈ",
+  "ᇌ",
+  "ᇎ",
+  "ᇓ",
+  "ᇗ",
+  "ᇙ",
+  "ᄜ",
+  "ᇝ",
+  "ᇟ",
+  "ᄝ",
+  "ᄞ",
+  "ᄠ",
+  "ᄢ",
+  "ᄣ",
+  "ᄧ",
+  "ᄩ",
+  "ᄫ",
+  "ᄬ",
+  "ᄭ",
+  "ᄮ",
+  "ᄯ",
+  "ᄲ",
+  "ᄶ",
+  "ᅀ",
+  "ᅇ",
+  "ᅌ",
+  "ᇱ",
+  "ᇲ",
+  "ᅗ",
+  "ᅘ",
+  "ᅙ",
+  "ᆄ",
+  "ᆅ",
+  "ᆈ",
+  "ᆑ",
+  "ᆒ",
+  "ᆔ",
+  "ᆞ",
+  "ᆡ",
+  "三",
+  "四",
+  "上",
+  "中",
+  "下",
+  "甲",
+  "丙",
+  "丁",
+  "天",
+  "地",
+  "(ᄀ)",
+  "(ᄂ)",
+  "(ᄃ)",
+  "(ᄅ)",
+  "(ᄆ)",
+  "(ᄇ)",
+  "(ᄉ)",
+  "(ᄋ)",
+  "(ᄌ)",
+  "(ᄎ)",
+  "(ᄏ)",
+  "(ᄐ)",
+  "(ᄑ)",
+  "(ᄒ)",
+  "(가)",
+  "(나)",
+  "(다)",
+  "(라)",
+  "(마)",
+  "(바)",
+  "(사)",
+  "(아)",
+  "(자)",
+  "(차)",
+  "(카)",
+  "(타)",
+  "(파)",
+  "(하)",
+  "(주)",
+  "(오전)",
+  "(오후)",
+  "(一)",
+  "(二)",
+  "(三)",
+  "(四)",
+  "(五)",
+  "(六)",
+  "(七)",
+  "(八)",
+  "(九)",
+  "(十)",
+  "(月)",
+  "(火)",
+  "(水)",
+  "(木)",
+  "(金)",
+  "(土)",
+  "(日)",
+  "(株)",
+  "(有)",
+  "(社)",
+  "(名)",
+  "(特)",
+  "(財)",
+  "(祝)",
+  "(労)",
+  "(代)",
+  "(呼)",
+  "(学)",
+  "(監)",
+  "(企)",
+  "(資)",
+  "(協)",
+  "(祭)",
+  "(休)",
+  "(自)",
+  "(至)",
+  "問",
+  "幼",
+  "箏",
+  "pte",
+  "21",
+  "22",
+  "23",
+  "24",
+  "25",
+  "26",
+  "27",
+  "28",
+  "29",
+  "30",
+  "31",
+  "32",
+  "33",
+  "34",
+  "35",
+  "가",
+  "나",
+  "다",
+  "라",
+  "마",
+  "바",
+  "사",
+  "아",
+  "자",
+  "차",
+  "카",
+  "타",
+  "파",
+  "하",
+  "참고",
+  "주의",
+  "우",
+  "五",
+  "六",
+  "七",
+  "九",
+  "株",
+  "有",
+  "社",
+  "名",
+  "特",
+  "財",
+  "祝",
+  "労",
+  "秘",
+  "男",
+  "適",
+  "優",
+  "印",
+  "注",
+  "項",
+  "休",
+  "写",
+  "正",
+  "左",
+  "右",
+  "医",
+  "宗",
+  "学",
+  "監",
+  "企",
+  "資",
+  "協",
+  "夜",
+  "36",
+  "37",
+  "38",
+  "39",
+  "40",
+  "41",
+  "42",
+  "43",
+  "44",
+  "45",
+  "46",
+  "47",
+  "48",
+  "49",
+  "50",
+  "1月",
+  "2月",
+  "3月",
+  "4月",
+  "5月",
+  "6月",
+  "7月",
+  "8月",
+  "9月",
+  "10月",
+  "11月",
+  "12月",
+  "hg",
+  "erg",
+  "ev",
+  "ltd",
+  "ア",
+  "イ",
+  "ウ",
+  "エ",
+  "オ",
+  "カ",
+  "キ",
+  "ク",
+  "ケ",
+  "コ",
+  "サ",
+  "シ",
+  "ス",
+  "セ",
+  "ソ",
+  "タ",
+  "チ",
+  "ツ",
+  "テ",
+  "ト",
+  "ナ",
+  "ニ",
+  "ヌ",
+  "ネ",
+  "ノ",
+  "ハ",
+  "ヒ",
+  "フ",
+  "ヘ",
+  "ホ",
+  "マ",
+  "ミ",
+  "ム",
+  "メ",
+  "モ",
+  "ヤ",
+  "ユ",
+  "ヨ",
+  "ラ",
+  "リ",
+  "ル",
+  "レ",
+  "ロ",
+  "ワ",
+  "ヰ",
+  "ヱ",
+  "ヲ",
+  "令和",
+  "アパート",
+  "アルファ",
+  "アンペア",
+  "アール",
+  "イニング",
+  "インチ",
+  "ウォン",
+  "エスクード",
+  "エーカー",
+  "オンス",
+  "オーム",
+  "カイリ",
+  "カラット",
+  "カロリー",
+  "ガロン",
+  "ガンマ",
+  "ギガ",
+  "ギニー",
+  "キュリー",
+  "ギルダー",
+  "キロ",
+  "キログラム",
+  "キロメートル",
+  "キロワット",
+  "グラム",
+  "グラムトン",
+  "クルゼイロ",
+  "クローネ",
+  "ケース",
+  "コルナ",
+  "コーポ",
+  "サイクル",
+  "サンチーム",
+  "シリング",
+  "センチ",
+  "セント",
+  "ダース",
+  "デシ",
+  "ドル",
+  "トン",
+  "ナノ",
+  "ノット",
+  "ハイツ",
+  "パーセント",
+  "パーツ",
+  "バーレル",
+  "ピアストル",
+  "ピクル",
+  "ピコ",
+  "ビル",
+  "ファラッド",
+  "フィート",
+  "ブッシェル",
+  "フラン",
+  "ヘクタール",
+  "ペソ",
+  "ペニヒ",
+  "ヘルツ",
+  "ペンス",
+  "ページ",
+  "ベータ",
+  "ポイント",
+  "ボルト",
+  "ホン",
+  "ポンド",
+  "ホール",
+  "ホーン",
+  "マイクロ",
+  "マイル",
+  "マッハ",
+  "マルク",
+  "マンション",
+  "ミクロン",
+  "ミリ",
+  "ミリバール",
+  "メガ",
+  "メガトン",
+  "メートル",
+  "ヤード",
+  "ヤール",
+  "ユアン",
+  "リットル",
+  "リラ",
+  "ルピー",
+  "ルーブル",
+  "レム",
+  "レントゲン",
+  "ワット",
+  "0点",
+  "1点",
+  "2点",
+  "3点",
+  "4点",
+  "5点",
+  "6点",
+  "7点",
+  "8点",
+  "9点",
+  "10点",
+  "11点",
+  "12点",
+  "13点",
+  "14点",
+  "15点",
+  "16点",
+  "17点",
+  "18点",
+  "19点",
+  "20点",
+  "21点",
+  "22点",
+  "23点",
+  "24点",
+  "hpa",
+  "da",
+  "au",
+  "bar",
+  "ov",
+  "pc",
+  "dm",
+  "dm2",
+  "dm3",
+  "iu",
+  "平成",
+  "昭和",
+  "大正",
+  "明治",
+  "株式会社",
+  "pa",
+  "na",
+  "μa",
+  "ma",
+  "ka",
+  "kb",
+  "mb",
+  "gb",
+  "cal",
+  "kcal",
+  "pf",
+  "nf",
+  "μf",
+  "μg",
+  "mg",
+  "kg",
+  "hz",
+  "khz",
+  "mhz",
+  "ghz",
+  "thz",
+  "μl",
+  "ml",
+  "dl",
+  "kl",
+  "fm",
+  "nm",
+  "μm",
+  "mm",
+  "cm",
+  "km",
+  "mm2",
+  "cm2",
+  "m2",
+  "km2",
+  "mm3",
+  "cm3",
+  "m3",
+  "km3",
+  "m∕s",
+  "m∕s2",
+  "kpa",
+  "mpa",
+  "gpa",
+  "rad",
+  "rad∕s",
+  "rad∕s2",
+  "ps",
+  "ns",
+  "μs",
+  "ms",
+  "pv",
+  "nv",
+  "μv",
+  "mv",
+  "kv",
+  "pw",
+  "nw",
+  "μw",
+  "mw",
+  "kw",
+  "kω",
+  "mω",
+  "bq",
+  "cc",
+  "cd",
+  "c∕kg",
+  "db",
+  "gy",
+  "ha",
+  "hp",
+  "in",
+  "kk",
+  "kt",
+  "lm",
+  "ln",
+  "log",
+  "lx",
+  "mil",
+  "mol",
+  "ph",
+  "ppm",
+  "pr",
+  "sr",
+  "sv",
+  "wb",
+  "v∕m",
+  "a∕m",
+  "1日",
+  "2日",
+  "3日",
+  "4日",
+  "5日",
+  "6日",
+  "7日",
+  "8日",
+  "9日",
+  "10日",
+  "11日",
+  "12日",
+  "13日",
+  "14日",
+  "15日",
+  "16日",
+  "17日",
+  "18日",
+  "19日",
+  "20日",
+  "21日",
+  "22日",
+  "23日",
+  "24日",
+  "25日",
+  "26日",
+  "27日",
+  "28日",
+  "29日",
+  "30日",
+  "31日",
+  "gal",
+  "ꙁ",
+  "ꙃ",
+  "ꙅ",
+  "ꙇ",
+  "ꙉ",
+  "ꙍ",
+  "ꙏ",
+  "ꙑ",
+  "ꙓ",
+  "ꙕ",
+  "ꙗ",
+  "ꙙ",
+  "ꙛ",
+  "ꙝ",
+  "ꙟ",
+  "ꙡ",
+  "ꙣ",
+  "ꙥ",
+  "ꙧ",
+  "ꙩ",
+  "ꙫ",
+  "ꙭ",
+  "ꚁ",
+  "ꚃ",
+  "ꚅ",
+  "ꚇ",
+  "ꚉ",
+  "ꚋ",
+  "ꚍ",
+  "ꚏ",
+  "ꚑ",
+  "ꚓ",
+  "ꚕ",
+  "ꚗ",
+  "ꚙ",
+  "ꚛ",
+  "ꜣ",
+  "ꜥ",
+  "ꜧ",
+  "ꜩ",
+  "ꜫ",
+  "ꜭ",
+  "ꜯ",
+  "ꜳ",
+  "ꜵ",
+  "ꜷ",
+  "ꜹ",
+  "ꜻ",
+  "ꜽ",
+  "ꜿ",
+  "ꝁ",
+  "ꝃ",
+  "ꝅ",
+  "ꝇ",
+  "ꝉ",
+  "ꝋ",
+  "ꝍ",
+  "ꝏ",
+  "ꝑ",
+  "ꝓ",
+  "ꝕ",
+  "ꝗ",
+  "ꝙ",
+  "ꝛ",
+  "ꝝ",
+  "ꝟ",
+  "ꝡ",
+  "ꝣ",
+  "ꝥ",
+  "ꝧ",
+  "ꝩ",
+  "ꝫ",
+  "ꝭ",
+  "ꝯ",
+  "ꝺ",
+  "ꝼ",
+  "ᵹ",
+  "ꝿ",
+  "ꞁ",
+  "ꞃ",
+  "ꞅ",
+  "ꞇ",
+  "ꞌ",
+  "ꞑ",
+  "ꞓ",
+  "ꞗ",
+  "ꞙ",
+  "ꞛ",
+  "ꞝ",
+  "ꞟ",
+  "ꞡ",
+  "ꞣ",
+  "ꞥ",
+  "ꞧ",
+  "ꞩ",
+  "ɬ",
+  "ʞ",
+  "ʇ",
+  "ꭓ",
+  "ꞵ",
+  "ꞷ",
+  "ꞹ",
+  "ꞻ",
+  "ꞽ",
+  "ꞿ",
+  "ꟃ",
+  "ꞔ",
+  "ᶎ",
+  "ꟈ",
+  "ꟊ",
+  "ꟶ",
+  "ꬷ",
+  "ꭒ",
+  "ʍ",
+  "Ꭰ",
+  "Ꭱ",
+  "Ꭲ",
+  "Ꭳ",
+  "Ꭴ",
+  "Ꭵ",
+  "Ꭶ",
+  "Ꭷ",
+  "Ꭸ",
+  "Ꭹ",
+  "Ꭺ",
+  "Ꭻ",
+  "Ꭼ",
+  "Ꭽ",
+  "Ꭾ",
+  "Ꭿ",
+  "Ꮀ",
+  "Ꮁ",
+  "Ꮂ",
+  "Ꮃ",
+  "Ꮄ",
+  "Ꮅ",
+  "Ꮆ",
+  "Ꮇ",
+  "Ꮈ",
+  "Ꮉ",
+  "Ꮊ",
+  "Ꮋ",
+  "Ꮌ",
+  "Ꮍ",
+  "Ꮎ",
+  "Ꮏ",
+  "Ꮐ",
+  "Ꮑ",
+  "Ꮒ",
+  "Ꮓ",
+  "Ꮔ",
+  "Ꮕ",
+  "Ꮖ",
+  "Ꮗ",
+  "Ꮘ",
+  "Ꮙ",
+  "Ꮚ",
+  "Ꮛ",
+  "Ꮜ",
+  "Ꮝ",
+  "Ꮞ",
+  "Ꮟ",
+  "Ꮠ",
+  "Ꮡ",
+  "Ꮢ",
+  "Ꮣ",
+  "Ꮤ",
+  "Ꮥ",
+  "Ꮦ",
+  "Ꮧ",
+  "Ꮨ",
+  "Ꮩ",
+  "Ꮪ",
+  "Ꮫ",
+  "Ꮬ",
+  "Ꮭ",
+  "Ꮮ",
+  "Ꮯ",
+  "Ꮰ",
+  "Ꮱ",
+  "Ꮲ",
+  "Ꮳ",
+  "Ꮴ",
+  "Ꮵ",
+  "Ꮶ",
+  "Ꮷ",
+  "Ꮸ",
+  "Ꮹ",
+  "Ꮺ",
+  "Ꮻ",
+  "Ꮼ",
+  "Ꮽ",
+  "Ꮾ",
+  "Ꮿ",
+  "豈",
+  "更",
+  "賈",
+  "滑",
+  "串",
+  "句",
+  "契",
+  "喇",
+  "奈",
+  "懶",
+  "癩",
+  "羅",
+  "蘿",
+  "螺",
+  "裸",
+  "邏",
+  "樂",
+  "洛",
+  "烙",
+  "珞",
+  "落",
+  "酪",
+  "駱",
+  "亂",
+  "卵",
+  "欄",
+  "爛",
+  "蘭",
+  "鸞",
+  "嵐",
+  "濫",
+  "藍",
+  "襤",
+  "拉",
+  "臘",
+  "蠟",
+  "廊",
+  "朗",
+  "浪",
+  "狼",
+  "郎",
+  "來",
+  "冷",
+  "勞",
+  "擄",
+  "櫓",
+  "爐",
+  "盧",
+  "蘆",
+  "虜",
+  "路",
+  "露",
+  "魯",
+  "鷺",
+  "碌",
+  "祿",
+  "綠",
+  "菉",
+  "錄",
+  "論",
+  "壟",
+  "弄",
+  "籠",
+  "聾",
+  "牢",
+  "磊",
+  "賂",
+  "雷",
+  "壘",
+  "屢",
+  "樓",
+  "淚",
+  "漏",
+  "累",
+  "縷",
+  "陋",
+  "勒",
+  "肋",
+  "凜",
+  "凌",
+  "稜",
+  "綾",
+  "菱",
+  "陵",
+  "讀",
+  "拏",
+  "諾",
+  "丹",
+  "寧",
+  "怒",
+  "率",
+  "異",
+  "北",
+  "磻",
+  "便",
+  "復",
+  "不",
+  "泌",
+  "數",
+  "索",
+  "參",
+  "塞",
+  "省",
+  "葉",
+  "說",
+  "殺",
+  "沈",
+  "拾",
+  "若",
+  "掠",
+  "略",
+  "亮",
+  "兩",
+  "凉",
+  "梁",
+  "糧",
+  "良",
+  "諒",
+  "量",
+  "勵",
+  "呂",
+  "廬",
+  "旅",
+  "濾",
+  "礪",
+  "閭",
+  "驪",
+  "麗",
+  "黎",
+  "曆",
+  "歷",
+  "轢",
+  "年",
+  "憐",
+  "戀",
+  "撚",
+  "漣",
+  "煉",
+  "璉",
+  "秊",
+  "練",
+  "聯",
+  "輦",
+  "蓮",
+  "連",
+  "鍊",
+  "列",
+  "劣",
+  "咽",
+  "烈",
+  "裂",
+  "廉",
+  "念",
+  "捻",
+  "殮",
+  "簾",
+  "獵",
+  "令",
+  "囹",
+  "嶺",
+  "怜",
+  "玲",
+  "瑩",
+  "羚",
+  "聆",
+  "鈴",
+  "零",
+  "靈",
+  "領",
+  "例",
+  "禮",
+  "醴",
+  "隸",
+  "惡",
+  "了",
+  "僚",
+  "寮",
+  "尿",
+  "料",
+  "燎",
+  "療",
+  "蓼",
+  "遼",
+  "暈",
+  "阮",
+  "劉",
+  "杻",
+  "柳",
+  "流",
+  "溜",
+  "琉",
+  "留",
+  "硫",
+  "紐",
+  "類",
+  "戮",
+  "陸",
+  "倫",
+  "崙",
+  "淪",
+  "輪",
+  "律",
+  "慄",
+  "栗",
+  "隆",
+  "利",
+  "吏",
+  "履",
+  "易",
+  "李",
+  "梨",
+  "泥",
+  "理",
+  "痢",
+  "罹",
+  "裏",
+  "裡",
+  "離",
+  "匿",
+  "溺",
+  "吝",
+  "燐",
+  "璘",
+  "藺",
+  "隣",
+  "鱗",
+  "麟",
+  "林",
+  "淋",
+  "臨",
+  "笠",
+  "粒",
+  "狀",
+  "炙",
+  "識",
+  "什",
+  "茶",
+  "刺",
+  "切",
+  "度",
+  "拓",
+  "糖",
+  "宅",
+  "洞",
+  "暴",
+  "輻",
+  "降",
+  "廓",
+  "兀",
+  "嗀",
+  "塚",
+  "晴",
+  "凞",
+  "猪",
+  "益",
+  "礼",
+  "神",
+  "祥",
+  "福",
+  "靖",
+  "精",
+  "蘒",
+  "諸",
+  "逸",
+  "都",
+  "飯",
+  "飼",
+  "館",
+  "鶴",
+  "郞",
+  "隷",
+  "侮",
+  "僧",
+  "免",
+  "勉",
+  "勤",
+  "卑",
+  "喝",
+  "嘆",
+  "器",
+  "塀",
+  "墨",
+  "層",
+  "悔",
+  "慨",
+  "憎",
+  "懲",
+  "敏",
+  "既",
+  "暑",
+  "梅",
+  "海",
+  "渚",
+  "漢",
+  "煮",
+  "爫",
+  "琢",
+  "碑",
+  "祉",
+  "祈",
+  "祐",
+  "祖",
+  "禍",
+  "禎",
+  "穀",
+  "突",
+  "節",
+  "縉",
+  "繁",
+  "署",
+  "者",
+  "臭",
+  "艹",
+  "著",
+  "褐",
+  "視",
+  "謁",
+  "謹",
+  "賓",
+  "贈",
+  "辶",
+  "難",
+  "響",
+  "頻",
+  "恵",
+  "𤋮",
+  "舘",
+  "並",
+  "况",
+  "全",
+  "侀",
+  "充",
+  "冀",
+  "勇",
+  "勺",
+  "啕",
+  "喙",
+  "嗢",
+  "墳",
+  "奄",
+  "奔",
+  "婢",
+  "嬨",
+  "廒",
+  "廙",
+  "彩",
+  "徭",
+  "惘",
+  "慎",
+  "愈",
+  "慠",
+  "戴",
+  "揄",
+  "搜",
+  "摒",
+  "敖",
+  "望",
+  "杖",
+  "滛",
+  "滋",
+  "瀞",
+  "瞧",
+  "爵",
+  "犯",
+  "瑱",
+  "甆",
+  "画",
+  "瘝",
+  "瘟",
+  "盛",
+  "直",
+  "睊",
+  "着",
+  "磌",
+  "窱",
+  "类",
+  "絛",
+  "缾",
+  "荒",
+  "華",
+  "蝹",
+  "襁",
+  "覆",
+  "調",
+  "請",
+  "諭",
+  "變",
+  "輸",
+  "遲",
+  "醙",
+  "鉶",
+  "陼",
+  "韛",
+  "頋",
+  "鬒",
+  "𢡊",
+  "𢡄",
+  "𣏕",
+  "㮝",
+  "䀘",
+  "䀹",
+  "𥉉",
+  "𥳐",
+  "𧻓",
+  "齃",
+  "龎",
+  "ff",
+  "fi",
+  "fl",
+  "ffi",
+  "ffl",
+  "st",
+  "մն",
+  "մե",
+  "մի",
+  "վն",
+  "մխ",
+  "י\u{5B4}",
+  "ײ\u{5B7}",
+  "ע",
+  "ה",
+  "כ",
+  "ל",
+  "ם",
+  "ר",
+  "ת",
+  "ש\u{5C1}",
+  "ש\u{5C2}",
+  "ש\u{5BC}\u{5C1}",
+  "ש\u{5BC}\u{5C2}",
+  "א\u{5B7}",
+  "א\u{5B8}",
+  "א\u{5BC}",
+  "ב\u{5BC}",
+  "ג\u{5BC}",
+  "ד\u{5BC}",
+  "ה\u{5BC}",
+  "ו\u{5BC}",
+  "ז\u{5BC}",
+  "ט\u{5BC}",
+  "י\u{5BC}",
+  "ך\u{5BC}",
+  "כ\u{5BC}",
+  "ל\u{5BC}",
+  "מ\u{5BC}",
+  "נ\u{5BC}",
+  "ס\u{5BC}",
+  "ף\u{5BC}",
+  "פ\u{5BC}",
+  "צ\u{5BC}",
+  "ק\u{5BC}",
+  "ר\u{5BC}",
+  "ש\u{5BC}",
+  "ת\u{5BC}",
+  "ו\u{5B9}",
+  "ב\u{5BF}",
+  "כ\u{5BF}",
+  "פ\u{5BF}",
+  "אל",
+  "ٱ",
+  "ٻ",
+  "پ",
+  "ڀ",
+  "ٺ",
+  "ٿ",
+  "ٹ",
+  "ڤ",
+  "ڦ",
+  "ڄ",
+  "ڃ",
+  "چ",
+  "ڇ",
+  "ڍ",
+  "ڌ",
+  "ڎ",
+  "ڈ",
+  "ژ",
+  "ڑ",
+  "ک",
+  "گ",
+  "ڳ",
+  "ڱ",
+  "ں",
+  "ڻ",
+  "ۀ",
+  "ہ",
+  "ھ",
+  "ے",
+  "ۓ",
+  "ڭ",
+  "ۇ",
+  "ۆ",
+  "ۈ",
+  "ۋ",
+  "ۅ",
+  "ۉ",
+  "ې",
+  "ى",
+  "ئا",
+  "ئە",
+  "ئو",
+  "ئۇ",
+  "ئۆ",
+  "ئۈ",
+  "ئې",
+  "ئى",
+  "ی",
+  "ئج",
+  "ئح",
+  "ئم",
+  "ئي",
+  "بج",
+  "بح",
+  "بخ",
+  "بم",
+  "بى",
+  "بي",
+  "تج",
+  "تح",
+  "تخ",
+  "تم",
+  "تى",
+  "تي",
+  "ثج",
+  "ثم",
+  "ثى",
+  "ثي",
+  "جح",
+  "جم",
+  "حج",
+  "حم",
+  "خج",
+  "خح",
+  "خم",
+  "سج",
+  "سح",
+  "سخ",
+  "سم",
+  "صح",
+  "صم",
+  "ضج",
+  "ضح",
+  "ضخ",
+  "ضم",
+  "طح",
+  "طم",
+  "ظم",
+  "عج",
+  "عم",
+  "غج",
+  "غم",
+  "فج",
+  "فح",
+  "فخ",
+  "فم",
+  "فى",
+  "في",
+  "قح",
+  "قم",
+  "قى",
+  "قي",
+  "كا",
+  "كج",
+  "كح",
+  "كخ",
+  "كل",
+  "كم",
+  "كى",
+  "كي",
+  "لج",
+  "لح",
+  "لخ",
+  "لم",
+  "لى",
+  "لي",
+  "مج",
+  "مح",
+  "مخ",
+  "مم",
+  "مى",
+  "مي",
+  "نج",
+  "نح",
+  "نخ",
+  "نم",
+  "نى",
+  "ني",
+  "هج",
+  "هم",
+  "هى",
+  "هي",
+  "يج",
+  "يح",
+  "يخ",
+  "يم",
+  "يى",
+  "يي",
+  "ذ\u{670}",
+  "ر\u{670}",
+  "ى\u{670}",
+  " \u{64C}\u{651}",
+  " \u{64D}\u{651}",
+  " \u{64E}\u{651}",
+  " \u{64F}\u{651}",
+  " \u{650}\u{651}",
+  " \u{651}\u{670}",
+  "ئر",
+  "ئز",
+  "ئن",
+  "بر",
+  "بز",
+  "بن",
+  "تر",
+  "تز",
+  "تن",
+  "ثر",
+  "ثز",
+  "ثن",
+  "ما",
+  "نر",
+  "نز",
+  "نن",
+  "ير",
+  "يز",
+  "ين",
+  "ئخ",
+  "ئه",
+  "به",
+  "ته",
+  "صخ",
+  "له",
+  "نه",
+  "ه\u{670}",
+  "يه",
+  "ثه",
+  "سه",
+  "شم",
+  "شه",
+  "ـ\u{64E}\u{651}",
+  "ـ\u{64F}\u{651}",
+  "ـ\u{650}\u{651}",
+  "طى",
+  "طي",
+  "عى",
+  "عي",
+  "غى",
+  "غي",
+  "سى",
+  "سي",
+  "شى",
+  "شي",
+  "حى",
+  "حي",
+  "جى",
+  "جي",
+  "خى",
+  "خي",
+  "صى",
+  "صي",
+  "ضى",
+  "ضي",
+  "شج",
+  "شح",
+  "شخ",
+  "شر",
+  "سر",
+  "صر",
+  "ضر",
+  "ا\u{64B}",
+  "تجم",
+  "تحج",
+  "تحم",
+  "تخم",
+  "تمج",
+  "تمح",
+  "تمخ",
+  "جمح",
+  "حمي",
+  "حمى",
+  "سحج",
+  "سجح",
+  "سجى",
+  "سمح",
+  "سمج",
+  "سمم",
+  "صحح",
+  "صمم",
+  "شحم",
+  "شجي",
+  "شمخ",
+  "شمم",
+  "ضحى",
+  "ضخم",
+  "طمح",
+  "طمم",
+  "طمي",
+  "عجم",
+  "عمم",
+  "عمى",
+  "غمم",
+  "غمي",
+  "غمى",
+  "فخم",
+  "قمح",
+  "قمم",
+  "لحم",
+  "لحي",
+  "لحى",
+  "لجج",
+  "لخم",
+  "لمح",
+  "محج",
+  "محم",
+  "محي",
+  "مجح",
+  "مجم",
+  "مخج",
+  "مخم",
+  "مجخ",
+  "همج",
+  "همم",
+  "نحم",
+  "نحى",
+  "نجم",
+  "نجى",
+  "نمي",
+  "نمى",
+  "يمم",
+  "بخي",
+  "تجي",
+  "تجى",
+  "تخي",
+  "تخى",
+  "تمي",
+  "تمى",
+  "جمي",
+  "جحى",
+  "جمى",
+  "سخى",
+  "صحي",
+  "شحي",
+  "ضحي",
+  "لجي",
+  "لمي",
+  "يحي",
+  "يجي",
+  "يمي",
+  "ممي",
+  "قمي",
+  "نحي",
+  "عمي",
+  "كمي",
+  "نجح",
+  "مخي",
+  "لجم",
+  "كمم",
+  "جحي",
+  "حجي",
+  "مجي",
+  "فمي",
+  "بحي",
+  "سخي",
+  "نجي",
+  "صلے",
+  "قلے",
+  "الله",
+  "اكبر",
+  "محمد",
+  "صلعم",
+  "رسول",
+  "عليه",
+  "وسلم",
+  "صلى",
+  "صلى الله عليه وسلم",
+  "جل جلاله",
+  "ریال",
+  ",",
+  "、",
+  ":",
+  "!",
+  "?",
+  "〖",
+  "〗",
+  "—",
+  "–",
+  "_",
+  "{",
+  "}",
+  "〔",
+  "〕",
+  "【",
+  "】",
+  "《",
+  "》",
+  "「",
+  "」",
+  "『",
+  "』",
+  "[",
+  "]",
+  "#",
+  "&",
+  "*",
+  "-",
+  "<",
+  ">",
+  "\\",
+  "$",
+  "%",
+  "@",
+  " \u{64B}",
+  "ـ\u{64B}",
+  " \u{64C}",
+  " \u{64D}",
+  " \u{64E}",
+  "ـ\u{64E}",
+  " \u{64F}",
+  "ـ\u{64F}",
+  " \u{650}",
+  "ـ\u{650}",
+  " \u{651}",
+  "ـ\u{651}",
+  " \u{652}",
+  "ـ\u{652}",
+  "ء",
+  "آ",
+  "أ",
+  "ؤ",
+  "إ",
+  "ئ",
+  "ا",
+  "ب",
+  "ة",
+  "ت",
+  "ث",
+  "ج",
+  "ح",
+  "خ",
+  "د",
+  "ذ",
+  "ر",
+  "ز",
+  "س",
+  "ش",
+  "ص",
+  "ض",
+  "ط",
+  "ظ",
+  "ع",
+  "غ",
+  "ف",
+  "ق",
+  "ك",
+  "ل",
+  "م",
+  "ن",
+  "ه",
+  "و",
+  "ي",
+  "لآ",
+  "لأ",
+  "لإ",
+  "لا",
+  "\"",
+  "'",
+  "/",
+  "^",
+  "|",
+  "~",
+  "⦅",
+  "⦆",
+  "・",
+  "ァ",
+  "ィ",
+  "ゥ",
+  "ェ",
+  "ォ",
+  "ャ",
+  "ュ",
+  "ョ",
+  "ッ",
+  "ー",
+  "ン",
+  "\u{3099}",
+  "\u{309A}",
+  "¢",
+  "£",
+  "¬",
+  "¦",
+  "¥",
+  "₩",
+  "│",
+  "←",
+  "↑",
+  "→",
+  "↓",
+  "■",
+  "○",
+  "𐐨",
+  "𐐩",
+  "𐐪",
+  "𐐫",
+  "𐐬",
+  "𐐭",
+  "𐐮",
+  "𐐯",
+  "𐐰",
+  "𐐱",
+  "𐐲",
+  "𐐳",
+  "𐐴",
+  "𐐵",
+  "𐐶",
+  "𐐷",
+  "𐐸",
+  "𐐹",
+  "𐐺",
+  "𐐻",
+  "𐐼",
+  "𐐽",
+  "𐐾",
+  "𐐿",
+  "𐑀",
+  "𐑁",
+  "𐑂",
+  "𐑃",
+  "𐑄",
+  "𐑅",
+  "𐑆",
+  "𐑇",
+  "𐑈",
+  "𐑉",
+  "𐑊",
+  "𐑋",
+  "𐑌",
+  "𐑍",
+  "𐑎",
+  "𐑏",
+  "𐓘",
+  "𐓙",
+  "𐓚",
+  "𐓛",
+  "𐓜",
+  "𐓝",
+  "𐓞",
+  "𐓟",
+  "𐓠",
+  "𐓡",
+  "𐓢",
+  "𐓣",
+  "𐓤",
+  "𐓥",
+  "𐓦",
+  "𐓧",
+  "𐓨",
+  "𐓩",
+  "𐓪",
+  "𐓫",
+  "𐓬",
+  "𐓭",
+  "𐓮",
+  "𐓯",
+  "𐓰",
+  "𐓱",
+  "𐓲",
+  "𐓳",
+  "𐓴",
+  "𐓵",
+  "𐓶",
+  "𐓷",
+  "𐓸",
+  "𐓹",
+  "𐓺",
+  "𐓻",
+  "𐳀",
+  "𐳁",
+  "𐳂",
+  "𐳃",
+  "𐳄",
+  "𐳅",
+  "𐳆",
+  "𐳇",
+  "𐳈",
+  "𐳉",
+  "𐳊",
+  "𐳋",
+  "𐳌",
+  "𐳍",
+  "𐳎",
+  "𐳏",
+  "𐳐",
+  "𐳑",
+  "𐳒",
+  "𐳓",
+  "𐳔",
+  "𐳕",
+  "𐳖",
+  "𐳗",
+  "𐳘",
+  "𐳙",
+  "𐳚",
+  "𐳛",
+  "𐳜",
+  "𐳝",
+  "𐳞",
+  "𐳟",
+  "𐳠",
+  "𐳡",
+  "𐳢",
+  "𐳣",
+  "𐳤",
+  "𐳥",
+  "𐳦",
+  "𐳧",
+  "𐳨",
+  "𐳩",
+  "𐳪",
+  "𐳫",
+  "𐳬",
+  "𐳭",
+  "𐳮",
+  "𐳯",
+  "𐳰",
+  "𐳱",
+  "𐳲",
+  "𑣀",
+  "𑣁",
+  "𑣂",
+  "𑣃",
+  "𑣄",
+  "𑣅",
+  "𑣆",
+  "𑣇",
+  "𑣈",
+  "𑣉",
+  "𑣊",
+  "𑣋",
+  "𑣌",
+  "𑣍",
+  "𑣎",
+  "𑣏",
+  "𑣐",
+  "𑣑",
+  "𑣒",
+  "𑣓",
+  "𑣔",
+  "𑣕",
+  "𑣖",
+  "𑣗",
+  "𑣘",
+  "𑣙",
+  "𑣚",
+  "𑣛",
+  "𑣜",
+  "𑣝",
+  "𑣞",
+  "𑣟",
+  "𖹠",
+  "𖹡",
+  "𖹢",
+  "𖹣",
+  "𖹤",
+  "𖹥",
+  "𖹦",
+  "𖹧",
+  "𖹨",
+  "𖹩",
+  "𖹪",
+  "𖹫",
+  "𖹬",
+  "𖹭",
+  "𖹮",
+  "𖹯",
+  "𖹰",
+  "𖹱",
+  "𖹲",
+  "𖹳",
+  "𖹴",
+  "𖹵",
+  "𖹶",
+  "𖹷",
+  "𖹸",
+  "𖹹",
+  "𖹺",
+  "𖹻",
+  "𖹼",
+  "𖹽",
+  "𖹾",
+  "𖹿",
+  "𝅗\u{1D165}",
+  "𝅘\u{1D165}",
+  "𝅘\u{1D165}\u{1D16E}",
+  "𝅘\u{1D165}\u{1D16F}",
+  "𝅘\u{1D165}\u{1D170}",
+  "𝅘\u{1D165}\u{1D171}",
+  "𝅘\u{1D165}\u{1D172}",
+  "𝆹\u{1D165}",
+  "𝆺\u{1D165}",
+  "𝆹\u{1D165}\u{1D16E}",
+  "𝆺\u{1D165}\u{1D16E}",
+  "𝆹\u{1D165}\u{1D16F}",
+  "𝆺\u{1D165}\u{1D16F}",
+  "ı",
+  "ȷ",
+  "∇",
+  "∂",
+  "𞤢",
+  "𞤣",
+  "𞤤",
+  "𞤥",
+  "𞤦",
+  "𞤧",
+  "𞤨",
+  "𞤩",
+  "𞤪",
+  "𞤫",
+  "𞤬",
+  "𞤭",
+  "𞤮",
+  "𞤯",
+  "𞤰",
+  "𞤱",
+  "𞤲",
+  "𞤳",
+  "𞤴",
+  "𞤵",
+  "𞤶",
+  "𞤷",
+  "𞤸",
+  "𞤹",
+  "𞤺",
+  "𞤻",
+  "𞤼",
+  "𞤽",
+  "𞤾",
+  "𞤿",
+  "𞥀",
+  "𞥁",
+  "𞥂",
+  "𞥃",
+  "ٮ",
+  "ڡ",
+  "ٯ",
+  "0,",
+  "1,",
+  "2,",
+  "3,",
+  "4,",
+  "5,",
+  "6,",
+  "7,",
+  "8,",
+  "9,",
+  "〔s〕",
+  "wz",
+  "hv",
+  "sd",
+  "ppv",
+  "wc",
+  "mc",
+  "md",
+  "mr",
+  "dj",
+  "ほか",
+  "ココ",
+  "字",
+  "双",
+  "デ",
+  "多",
+  "解",
+  "交",
+  "映",
+  "無",
+  "前",
+  "後",
+  "再",
+  "新",
+  "初",
+  "終",
+  "販",
+  "声",
+  "吹",
+  "演",
+  "投",
+  "捕",
+  "遊",
+  "指",
+  "打",
+  "禁",
+  "空",
+  "合",
+  "満",
+  "申",
+  "割",
+  "営",
+  "配",
+  "〔本〕",
+  "〔三〕",
+  "〔二〕",
+  "〔安〕",
+  "〔点〕",
+  "〔打〕",
+  "〔盗〕",
+  "〔勝〕",
+  "〔敗〕",
+  "得",
+  "可",
+  "丽",
+  "丸",
+  "乁",
+  "𠄢",
+  "你",
+  "侻",
+  "倂",
+  "偺",
+  "備",
+  "像",
+  "㒞",
+  "𠘺",
+  "兔",
+  "兤",
+  "具",
+  "𠔜",
+  "㒹",
+  "內",
+  "𠕋",
+  "冗",
+  "冤",
+  "仌",
+  "冬",
+  "𩇟",
+  "刃",
+  "㓟",
+  "刻",
+  "剆",
+  "剷",
+  "㔕",
+  "包",
+  "匆",
+  "卉",
+  "博",
+  "即",
+  "卽",
+  "卿",
+  "𠨬",
+  "灰",
+  "及",
+  "叟",
+  "𠭣",
+  "叫",
+  "叱",
+  "吆",
+  "咞",
+  "吸",
+  "呈",
+  "周",
+  "咢",
+  "哶",
+  "唐",
+  "啓",
+  "啣",
+  "善",
+  "喫",
+  "喳",
+  "嗂",
+  "圖",
+  "圗",
+  "噑",
+  "噴",
+  "壮",
+  "城",
+  "埴",
+  "堍",
+  "型",
+  "堲",
+  "報",
+  "墬",
+  "𡓤",
+  "売",
+  "壷",
+  "夆",
+  "夢",
+  "奢",
+  "𡚨",
+  "𡛪",
+  "姬",
+  "娛",
+  "娧",
+  "姘",
+  "婦",
+  "㛮",
+  "嬈",
+  "嬾",
+  "𡧈",
+  "寃",
+  "寘",
+  "寳",
+  "𡬘",
+  "寿",
+  "将",
+  "㞁",
+  "屠",
+  "峀",
+  "岍",
+  "𡷤",
+  "嵃",
+  "𡷦",
+  "嵮",
+  "嵫",
+  "嵼",
+  "巡",
+  "巢",
+  "㠯",
+  "巽",
+  "帨",
+  "帽",
+  "幩",
+  "㡢",
+  "𢆃",
+  "㡼",
+  "庰",
+  "庳",
+  "庶",
+  "𪎒",
+  "𢌱",
+  "舁",
+  "弢",
+  "㣇",
+  "𣊸",
+  "𦇚",
+  "形",
+  "彫",
+  "㣣",
+  "徚",
+  "忍",
+  "志",
+  "忹",
+  "悁",
+  "㤺",
+  "㤜",
+  "𢛔",
+  "惇",
+  "慈",
+  "慌",
+  "慺",
+  "憲",
+  "憤",
+  "憯",
+  "懞",
+  "成",
+  "戛",
+  "扝",
+  "抱",
+  "拔",
+  "捐",
+  "𢬌",
+  "挽",
+  "拼",
+  "捨",
+  "掃",
+  "揤",
+  "𢯱",
+  "搢",
+  "揅",
+  "掩",
+  "㨮",
+  "摩",
+  "摾",
+  "撝",
+  "摷",
+  "㩬",
+  "敬",
+  "𣀊",
+  "旣",
+  "書",
+  "晉",
+  "㬙",
+  "㬈",
+  "㫤",
+  "冒",
+  "冕",
+  "最",
+  "暜",
+  "肭",
+  "䏙",
+  "朡",
+  "杞",
+  "杓",
+  "𣏃",
+  "㭉",
+  "柺",
+  "枅",
+  "桒",
+  "𣑭",
+  "梎",
+  "栟",
+  "椔",
+  "楂",
+  "榣",
+  "槪",
+  "檨",
+  "𣚣",
+  "櫛",
+  "㰘",
+  "次",
+  "𣢧",
+  "歔",
+  "㱎",
+  "歲",
+  "殟",
+  "殻",
+  "𣪍",
+  "𡴋",
+  "𣫺",
+  "汎",
+  "𣲼",
+  "沿",
+  "泍",
+  "汧",
+  "洖",
+  "派",
+  "浩",
+  "浸",
+  "涅",
+  "𣴞",
+  "洴",
+  "港",
+  "湮",
+  "㴳",
+  "滇",
+  "𣻑",
+  "淹",
+  "潮",
+  "𣽞",
+  "𣾎",
+  "濆",
+  "瀹",
+  "瀛",
+  "㶖",
+  "灊",
+  "災",
+  "灷",
+  "炭",
+  "𠔥",
+  "煅",
+  "𤉣",
+  "熜",
+  "爨",
+  "牐",
+  "𤘈",
+  "犀",
+  "犕",
+  "𤜵",
+  "𤠔",
+  "獺",
+  "王",
+  "㺬",
+  "玥",
+  "㺸",
+  "瑇",
+  "瑜",
+  "璅",
+  "瓊",
+  "㼛",
+  "甤",
+  "𤰶",
+  "甾",
+  "𤲒",
+  "𢆟",
+  "瘐",
+  "𤾡",
+  "𤾸",
+  "𥁄",
+  "㿼",
+  "䀈",
+  "𥃳",
+  "𥃲",
+  "𥄙",
+  "𥄳",
+  "眞",
+  "真",
+  "瞋",
+  "䁆",
+  "䂖",
+  "𥐝",
+  "硎",
+  "䃣",
+  "𥘦",
+  "𥚚",
+  "𥛅",
+  "秫",
+  "䄯",
+  "穊",
+  "穏",
+  "𥥼",
+  "𥪧",
+  "䈂",
+  "𥮫",
+  "篆",
+  "築",
+  "䈧",
+  "𥲀",
+  "糒",
+  "䊠",
+  "糨",
+  "糣",
+  "紀",
+  "𥾆",
+  "絣",
+  "䌁",
+  "緇",
+  "縂",
+  "繅",
+  "䌴",
+  "𦈨",
+  "𦉇",
+  "䍙",
+  "𦋙",
+  "罺",
+  "𦌾",
+  "羕",
+  "翺",
+  "𦓚",
+  "𦔣",
+  "聠",
+  "𦖨",
+  "聰",
+  "𣍟",
+  "䏕",
+  "育",
+  "脃",
+  "䐋",
+  "脾",
+  "媵",
+  "𦞧",
+  "𦞵",
+  "𣎓",
+  "𣎜",
+  "舄",
+  "辞",
+  "䑫",
+  "芑",
+  "芋",
+  "芝",
+  "劳",
+  "花",
+  "芳",
+  "芽",
+  "苦",
+  "𦬼",
+  "茝",
+  "荣",
+  "莭",
+  "茣",
+  "莽",
+  "菧",
+  "荓",
+  "菊",
+  "菌",
+  "菜",
+  "𦰶",
+  "𦵫",
+  "𦳕",
+  "䔫",
+  "蓱",
+  "蓳",
+  "蔖",
+  "𧏊",
+  "蕤",
+  "𦼬",
+  "䕝",
+  "䕡",
+  "𦾱",
+  "𧃒",
+  "䕫",
+  "虐",
+  "虧",
+  "虩",
+  "蚩",
+  "蚈",
+  "蜎",
+  "蛢",
+  "蜨",
+  "蝫",
+  "螆",
+  "蟡",
+  "蠁",
+  "䗹",
+  "衠",
+  "𧙧",
+  "裗",
+  "裞",
+  "䘵",
+  "裺",
+  "㒻",
+  "𧢮",
+  "𧥦",
+  "䚾",
+  "䛇",
+  "誠",
+  "𧲨",
+  "貫",
+  "賁",
+  "贛",
+  "起",
+  "𧼯",
+  "𠠄",
+  "跋",
+  "趼",
+  "跰",
+  "𠣞",
+  "軔",
+  "𨗒",
+  "𨗭",
+  "邔",
+  "郱",
+  "鄑",
+  "𨜮",
+  "鄛",
+  "鈸",
+  "鋗",
+  "鋘",
+  "鉼",
+  "鏹",
+  "鐕",
+  "𨯺",
+  "開",
+  "䦕",
+  "閷",
+  "𨵷",
+  "䧦",
+  "雃",
+  "嶲",
+  "霣",
+  "𩅅",
+  "𩈚",
+  "䩮",
+  "䩶",
+  "韠",
+  "𩐊",
+  "䪲",
+  "𩒖",
+  "頩",
+  "𩖶",
+  "飢",
+  "䬳",
+  "餩",
+  "馧",
+  "駂",
+  "駾",
+  "䯎",
+  "𩬰",
+  "鱀",
+  "鳽",
+  "䳎",
+  "䳭",
+  "鵧",
+  "𪃎",
+  "䳸",
+  "𪄅",
+  "𪈎",
+  "𪊑",
+  "䵖",
+  "黾",
+  "鼅",
+  "鼏",
+  "鼖",
+  "𪘀",
+);
+
+/// The ranges of code points with a non-zero canonical combining class.
+pub(crate) const COMBINING_CLASS: &[(u32, u32, u8)] = &[
+  (0x0300, 0x0314, 230),
+  (0x0315, 0x0315, 232),
+  (0x0316, 0x0319, 220),
+  (0x031A, 0x031A, 232),
+  (0x031B, 0x031B, 216),
+  (0x031C, 0x0320, 220),
+  (0x0321, 0x0322, 202),
+  (0x0323, 0x0326, 220),
+  (0x0327, 0x0328, 202),
+  (0x0329, 0x0333, 220),
+  (0x0334, 0x0338, 1),
+  (0x0339, 0x033C, 220),
+  (0x033D, 0x0344, 230),
+  (0x0345, 0x0345, 240),
+  (0x0346, 0x0346, 230),
+  (0x0347, 0x0349, 220),
+  (0x034A, 0x034C, 230),
+  (0x034D, 0x034E, 220),
+  (0x0350, 0x0352, 230),
+  (0x0353, 0x0356, 220),
+  (0x0357, 0x0357, 230),
+  (0x0358, 0x0358, 232),
+  (0x0359, 0x035A, 220),
+  (0x035B, 0x035B, 230),
+  (0x035C, 0x035C, 233),
+  (0x035D, 0x035E, 234),
+  (0x035F, 0x035F, 233),
+  (0x0360, 0x0361, 234),
+  (0x0362, 0x0362, 233),
+  (0x0363, 0x036F, 230),
+  (0x0483, 0x0487, 230),
+  (0x0591, 0x0591, 220),
+  (0x0592, 0x0595, 230),
+  (0x0596, 0x0596, 220),
+  (0x0597, 0x0599, 230),
+  (0x059A, 0x059A, 222),
+  (0x059B, 0x059B, 220),
+  (0x059C, 0x05A1, 230),
+  (0x05A2, 0x05A7, 220),
+  (0x05A8, 0x05A9, 230),
+  (0x05AA, 0x05AA, 220),
+  (0x05AB, 0x05AC, 230),
+  (0x05AD, 0x05AD, 222),
+  (0x05AE, 0x05AE, 228),
+  (0x05AF, 0x05AF, 230),
+  (0x05B0, 0x05B0, 10),
+  (0x05B1, 0x05B1, 11),
+  (0x05B2, 0x05B2, 12),
+  (0x05B3, 0x05B3, 13),
+  (0x05B4, 0x05B4, 14),
+  (0x05B5, 0x05B5, 15),
+  (0x05B6, 0x05B6, 16),
+  (0x05B7, 0x05B7, 17),
+  (0x05B8, 0x05B8, 18),
+  (0x05B9, 0x05BA, 19),
+  (0x05BB, 0x05BB, 20),
+  (0x05BC, 0x05BC, 21),
+  (0x05BD, 0x05BD, 22),
+  (0x05BF, 0x05BF, 23),
+  (0x05C1, 0x05C1, 24),
+  (0x05C2, 0x05C2, 25),
+  (0x05C4, 0x05C4, 230),
+  (0x05C5, 0x05C5, 220),
+  (0x05C7, 0x05C7, 18),
+  (0x0610, 0x0617, 230),
+  (0x0618, 0x0618, 30),
+  (0x0619, 0x0619, 31),
+  (0x061A, 0x061A, 32),
+  (0x064B, 0x064B, 27),
+  (0x064C, 0x064C, 28),
+  (0x064D, 0x064D, 29),
+  (0x064E, 0x064E, 30),
+  (0x064F, 0x064F, 31),
+  (0x0650, 0x0650, 32),
+  (0x0651, 0x0651, 33),
+  (0x0652, 0x0652, 34),
+  (0x0653, 0x0654, 230),
+  (0x0655, 0x0656, 220),
+  (0x0657, 0x065B, 230),
+  (0x065C, 0x065C, 220),
+  (0x065D, 0x065E, 230),
+  (0x065F, 0x065F, 220),
+  (0x0670, 0x0670, 35),
+  (0x06D6, 0x06DC, 230),
+  (0x06DF, 0x06E2, 230),
+  (0x06E3, 0x06E3, 220),
+  (0x06E4, 0x06E4, 230),
+  (0x06E7, 0x06E8, 230),
+  (0x06EA, 0x06EA, 220),
+  (0x06EB, 0x06EC, 230),
+  (0x06ED, 0x06ED, 220),
+  (0x0711, 0x0711, 36),
+  (0x0730, 0x0730, 230),
+  (0x0731, 0x0731, 220),
+  (0x0732, 0x0733, 230),
+  (0x0734, 0x0734, 220),
+  (0x0735, 0x0736, 230),
+  (0x0737, 0x0739, 220),
+  (0x073A, 0x073A, 230),
+  (0x073B, 0x073C, 220),
+  (0x073D, 0x073D, 230),
+  (0x073E, 0x073E, 220),
+  (0x073F, 0x0741, 230),
+  (0x0742, 0x0742, 220),
+  (0x0743, 0x0743, 230),
+  (0x0744, 0x0744, 220),
+  (0x0745, 0x0745, 230),
+  (0x0746, 0x0746, 220),
+  (0x0747, 0x0747, 230),
+  (0x0748, 0x0748, 220),
+  (0x0749, 0x074A, 230),
+  (0x07EB, 0x07F1, 230),
+  (0x07F2, 0x07F2, 220),
+  (0x07F3, 0x07F3, 230),
+  (0x07FD, 0x07FD, 220),
+  (0x0816, 0x0819, 230),
+  (0x081B, 0x0823, 230),
+  (0x0825, 0x0827, 230),
+  (0x0829, 0x082D, 230),
+  (0x0859, 0x085B, 220),
+  (0x08D3, 0x08D3, 220),
+  (0x08D4, 0x08E1, 230),
+  (0x08E3, 0x08E3, 220),
+  (0x08E4, 0x08E5, 230),
+  (0x08E6, 0x08E6, 220),
+  (0x08E7, 0x08E8, 230),
+  (0x08E9, 0x08E9, 220),
+  (0x08EA, 0x08EC, 230),
+  (0x08ED, 0x08EF, 220),
+  (0x08F0, 0x08F0, 27),
+  (0x08F1, 0x08F1, 28),
+  (0x08F2, 0x08F2, 29),
+  (0x08F3, 0x08F5, 230),
+  (0x08F6, 0x08F6, 220),
+  (0x08F7, 0x08F8, 230),
+  (0x08F9, 0x08FA, 220),
+  (0x08FB, 0x08FF, 230),
+  (0x093C, 0x093C, 7),
+  (0x094D, 0x094D, 9),
+  (0x0951, 0x0951, 230),
+  (0x0952, 0x0952, 220),
+  (0x0953, 0x0954, 230),
+  (0x09BC, 0x09BC, 7),
+  (0x09CD, 0x09CD, 9),
+  (0x09FE, 0x09FE, 230),
+  (0x0A3C, 0x0A3C, 7),
+  (0x0A4D, 0x0A4D, 9),
+  (0x0ABC, 0x0ABC, 7),
+  (0x0ACD, 0x0ACD, 9),
+  (0x0B3C, 0x0B3C, 7),
+  (0x0B4D, 0x0B4D, 9),
+  (0x0BCD, 0x0BCD, 9),
+  (0x0C4D, 0x0C4D, 9),
+  (0x0C55, 0x0C55, 84),
+  (0x0C56, 0x0C56, 91),
+  (0x0CBC, 0x0CBC, 7),
+  (0x0CCD, 0x0CCD, 9),
+  (0x0D3B, 0x0D3C, 9),
+  (0x0D4D, 0x0D4D, 9),
+  (0x0DCA, 0x0DCA, 9),
+  (0x0E38, 0x0E39, 103),
+  (0x0E3A, 0x0E3A, 9),
+  (0x0E48, 0x0E4B, 107),
+  (0x0EB8, 0x0EB9, 118),
+  (0x0EBA, 0x0EBA, 9),
+  (0x0EC8, 0x0ECB, 122),
+  (0x0F18, 0x0F19, 220),
+  (0x0F35, 0x0F35, 220),
+  (0x0F37, 0x0F37, 220),
+  (0x0F39, 0x0F39, 216),
+  (0x0F71, 0x0F71, 129),
+  (0x0F72, 0x0F72, 130),
+  (0x0F74, 0x0F74, 132),
+  (0x0F7A, 0x0F7D, 130),
+  (0x0F80, 0x0F80, 130),
+  (0x0F82, 0x0F83, 230),
+  (0x0F84, 0x0F84, 9),
+  (0x0F86, 0x0F87, 230),
+  (0x0FC6, 0x0FC6, 220),
+  (0x1037, 0x1037, 7),
+  (0x1039, 0x103A, 9),
+  (0x108D, 0x108D, 220),
+  (0x135D, 0x135F, 230),
+  (0x1714, 0x1714, 9),
+  (0x1734, 0x1734, 9),
+  (0x17D2, 0x17D2, 9),
+  (0x17DD, 0x17DD, 230),
+  (0x18A9, 0x18A9, 228),
+  (0x1939, 0x1939, 222),
+  (0x193A, 0x193A, 230),
+  (0x193B, 0x193B, 220),
+  (0x1A17, 0x1A17, 230),
+  (0x1A18, 0x1A18, 220),
+  (0x1A60, 0x1A60, 9),
+  (0x1A75, 0x1A7C, 230),
+  (0x1A7F, 0x1A7F, 220),
+  (0x1AB0, 0x1AB4, 230),
+  (0x1AB5, 0x1ABA, 220),
+  (0x1ABB, 0x1ABC, 230),
+  (0x1ABD, 0x1ABD, 220),
+  (0x1ABF, 0x1AC0, 220),
+  (0x1B34, 0x1B34, 7),
+  (0x1B44, 0x1B44, 9),
+  (0x1B6B, 0x1B6B, 230),
+  (0x1B6C, 0x1B6C, 220),
+  (0x1B6D, 0x1B73, 230),
+  (0x1BAA, 0x1BAB, 9),
+  (0x1BE6, 0x1BE6, 7),
+  (0x1BF2, 0x1BF3, 9),
+  (0x1C37, 0x1C37, 7),
+  (0x1CD0, 0x1CD2, 230),
+  (0x1CD4, 0x1CD4, 1),
+  (0x1CD5, 0x1CD9, 220),
+  (0x1CDA, 0x1CDB, 230),
+  (0x1CDC, 0x1CDF, 220),
+  (0x1CE0, 0x1CE0, 230),
+  (0x1CE2, 0x1CE8, 1),
+  (0x1CED, 0x1CED, 220),
+  (0x1CF4, 0x1CF4, 230),
+  (0x1CF8, 0x1CF9, 230),
+  (0x1DC0, 0x1DC1, 230),
+  (0x1DC2, 0x1DC2, 220),
+  (0x1DC3, 0x1DC9, 230),
+  (0x1DCA, 0x1DCA, 220),
+  (0x1DCB, 0x1DCC, 230),
+  (0x1DCD, 0x1DCD, 234),
+  (0x1DCE, 0x1DCE, 214),
+  (0x1DCF, 0x1DCF, 220),
+  (0x1DD0, 0x1DD0, 202),
+  (0x1DD1, 0x1DF5, 230),
+  (0x1DF6, 0x1DF6, 232),
+  (0x1DF7, 0x1DF8, 228),
+  (0x1DF9, 0x1DF9, 220),
+  (0x1DFB, 0x1DFB, 230),
+  (0x1DFC, 0x1DFC, 233),
+  (0x1DFD, 0x1DFD, 220),
+  (0x1DFE, 0x1DFE, 230),
+  (0x1DFF, 0x1DFF, 220),
+  (0x20D0, 0x20D1, 230),
+  (0x20D2, 0x20D3, 1),
+  (0x20D4, 0x20D7, 230),
+  (0x20D8, 0x20DA, 1),
+  (0x20DB, 0x20DC, 230),
+  (0x20E1, 0x20E1, 230),
+  (0x20E5, 0x20E6, 1),
+  (0x20E7, 0x20E7, 230),
+  (0x20E8, 0x20E8, 220),
+  (0x20E9, 0x20E9, 230),
+  (0x20EA, 0x20EB, 1),
+  (0x20EC, 0x20EF, 220),
+  (0x20F0, 0x20F0, 230),
+  (0x2CEF, 0x2CF1, 230),
+  (0x2D7F, 0x2D7F, 9),
+  (0x2DE0, 0x2DFF, 230),
+  (0x302A, 0x302A, 218),
+  (0x302B, 0x302B, 228),
+  (0x302C, 0x302C, 232),
+  (0x302D, 0x302D, 222),
+  (0x302E, 0x302F, 224),
+  (0x3099, 0x309A, 8),
+  (0xA66F, 0xA66F, 230),
+  (0xA674, 0xA67D, 230),
+  (0xA69E, 0xA69F, 230),
+  (0xA6F0, 0xA6F1, 230),
+  (0xA806, 0xA806, 9),
+  (0xA82C, 0xA82C, 9),
+  (0xA8C4, 0xA8C4, 9),
+  (0xA8E0, 0xA8F1, 230),
+  (0xA92B, 0xA92D, 220),
+  (0xA953, 0xA953, 9),
+  (0xA9B3, 0xA9B3, 7),
+  (0xA9C0, 0xA9C0, 9),
+  (0xAAB0, 0xAAB0, 230),
+  (0xAAB2, 0xAAB3, 230),
+  (0xAAB4, 0xAAB4, 220),
+  (0xAAB7, 0xAAB8, 230),
+  (0xAABE, 0xAABF, 230),
+  (0xAAC1, 0xAAC1, 230),
+  (0xAAF6, 0xAAF6, 9),
+  (0xABED, 0xABED, 9),
+  (0xFB1E, 0xFB1E, 26),
+  (0xFE20, 0xFE26, 230),
+  (0xFE27, 0xFE2D, 220),
+  (0xFE2E, 0xFE2F, 230),
+  (0x101FD, 0x101FD, 220),
+  (0x102E0, 0x102E0, 220),
+  (0x10376, 0x1037A, 230),
+  (0x10A0D, 0x10A0D, 220),
+  (0x10A0F, 0x10A0F, 230),
+  (0x10A38, 0x10A38, 230),
+  (0x10A39, 0x10A39, 1),
+  (0x10A3A, 0x10A3A, 220),
+  (0x10A3F, 0x10A3F, 9),
+  (0x10AE5, 0x10AE5, 230),
+  (0x10AE6, 0x10AE6, 220),
+  (0x10D24, 0x10D27, 230),
+  (0x10EAB, 0x10EAC, 230),
+  (0x10F46, 0x10F47, 220),
+  (0x10F48, 0x10F4A, 230),
+  (0x10F4B, 0x10F4B, 220),
+  (0x10F4C, 0x10F4C, 230),
+  (0x10F4D, 0x10F50, 220),
+  (0x11046, 0x11046, 9),
+  (0x1107F, 0x1107F, 9),
+  (0x110B9, 0x110B9, 9),
+  (0x110BA, 0x110BA, 7),
+  (0x11100, 0x11102, 230),
+  (0x11133, 0x11134, 9),
+  (0x11173, 0x11173, 7),
+  (0x111C0, 0x111C0, 9),
+  (0x111CA, 0x111CA, 7),
+  (0x11235, 0x11235, 9),
+  (0x11236, 0x11236, 7),
+  (0x112E9, 0x112E9, 7),
+  (0x112EA, 0x112EA, 9),
+  (0x1133B, 0x1133C, 7),
+  (0x1134D, 0x1134D, 9),
+  (0x11366, 0x1136C, 230),
+  (0x11370, 0x11374, 230),
+  (0x11442, 0x11442, 9),
+  (0x11446, 0x11446, 7),
+  (0x1145E, 0x1145E, 230),
+  (0x114C2, 0x114C2, 9),
+  (0x114C3, 0x114C3, 7),
+  (0x115BF, 0x115BF, 9),
+  (0x115C0, 0x115C0, 7),
+  (0x1163F, 0x1163F, 9),
+  (0x116B6, 0x116B6, 9),
+  (0x116B7, 0x116B7, 7),
+  (0x1172B, 0x1172B, 9),
+  (0x11839, 0x11839, 9),
+  (0x1183A, 0x1183A, 7),
+  (0x1193D, 0x1193E, 9),
+  (0x11943, 0x11943, 7),
+  (0x119E0, 0x119E0, 9),
+  (0x11A34, 0x11A34, 9),
+  (0x11A47, 0x11A47, 9),
+  (0x11A99, 0x11A99, 9),
+  (0x11C3F, 0x11C3F, 9),
+  (0x11D42, 0x11D42, 7),
+  (0x11D44, 0x11D45, 9),
+  (0x11D97, 0x11D97, 9),
+  (0x16AF0, 0x16AF4, 1),
+  (0x16B30, 0x16B36, 230),
+  (0x16FF0, 0x16FF1, 6),
+  (0x1BC9E, 0x1BC9E, 1),
+  (0x1D165, 0x1D166, 216),
+  (0x1D167, 0x1D169, 1),
+  (0x1D16D, 0x1D16D, 226),
+  (0x1D16E, 0x1D172, 216),
+  (0x1D17B, 0x1D182, 220),
+  (0x1D185, 0x1D189, 230),
+  (0x1D18A, 0x1D18B, 220),
+  (0x1D1AA, 0x1D1AD, 230),
+  (0x1D242, 0x1D244, 230),
+  (0x1E000, 0x1E006, 230),
+  (0x1E008, 0x1E018, 230),
+  (0x1E01B, 0x1E021, 230),
+  (0x1E023, 0x1E024, 230),
+  (0x1E026, 0x1E02A, 230),
+  (0x1E130, 0x1E136, 230),
+  (0x1E2EC, 0x1E2EF, 230),
+  (0x1E8D0, 0x1E8D6, 220),
+  (0x1E944, 0x1E949, 230),
+  (0x1E94A, 0x1E94A, 7),
+];
+
+/// The full canonical decomposition of each code point, besides Hangul syllables, as an
+/// offset and a length in `DECOMPOSED`.
+pub(crate) const DECOMPOSITION: &[(u32, u16, u8)] = &[
+  (0x00C0, 0, 3),
+  (0x00C1, 3, 3),
+  (0x00C2, 6, 3),
+  (0x00C3, 9, 3),
+  (0x00C4, 12, 3),
+  (0x00C5, 15, 3),
+  (0x00C7, 18, 3),
+  (0x00C8, 21, 3),
+  (0x00C9, 24, 3),
+  (0x00CA, 27, 3),
+  (0x00CB, 30, 3),
+  (0x00CC, 33, 3),
+  (0x00CD, 36, 3),
+  (0x00CE, 39, 3),
+  (0x00CF, 42, 3),
+  (0x00D1, 45, 3),
+  (0x00D2, 48, 3),
+  (0x00D3, 51, 3),
+  (0x00D4, 54, 3),
+  (0x00D5, 57, 3),
+  (0x00D6, 60, 3),
+  (0x00D9, 63, 3),
+  (0x00DA, 66, 3),
+  (0x00DB, 69, 3),
+  (0x00DC, 72, 3),
+  (0x00DD, 75, 3),
+  (0x00E0, 78, 3),
+  (0x00E1, 81, 3),
+  (0x00E2, 84, 3),
+  (0x00E3, 87, 3),
+  (0x00E4, 90, 3),
+  (0x00E5, 93, 3),
+  (0x00E7, 96, 3),
+  (0x00E8, 99, 3),
+  (0x00E9, 102, 3),
+  (0x00EA, 105, 3),
+  (0x00EB, 108, 3),
+  (0x00EC, 111, 3),
+  (0x00ED, 114, 3),
+  (0x00EE, 117, 3),
+  (0x00EF, 120, 3),
+  (0x00F1, 123, 3),
+  (0x00F2, 126, 3),
+  (0x00F3, 129, 3),
+  (0x00F4, 132, 3),
+  (0x00F5, 135, 3),
+  (0x00F6, 138, 3),
+  (0x00F9, 141, 3),
+  (0x00FA, 144, 3),
+  (0x00FB, 147, 3),
+  (0x00FC, 150, 3),
+  (0x00FD, 153, 3),
+  (0x00FF, 156, 3),
+  (0x0100, 159, 3),
+  (0x0101, 162, 3),
+  (0x0102, 165, 3),
+  (0x0103, 168, 3),
+  (0x0104, 171, 3),
+  (0x0105, 174, 3),
+  (0x0106, 177, 3),
+  (0x0107, 180, 3),
+  (0x0108, 183, 3),
+  (0x0109, 186, 3),
+  (0x010A, 189, 3),
+  (0x010B, 192, 3),
+  (0x010C, 195, 3),
+  (0x010D, 198, 3),
+  (0x010E, 201, 3),
+  (0x010F, 204, 3),
+  (0x0112, 207, 3),
+  (0x0113, 210, 3),
+  (0x0114, 213, 3),
+  (0x0115, 216, 3),
+  (0x0116, 219, 3),
+  (0x0117, 222, 3),
+  (0x0118, 225, 3),
+  (0x0119, 228, 3),
+  (0x011A, 231, 3),
+  (0x011B, 234, 3),
+  (0x011C, 237, 3),
+  (0x011D, 240, 3),
+  (0x011E, 243, 3),
+  (0x011F, 246, 3),
+  (0x0120, 249, 3),
+  (0x0121, 252, 3),
+  (0x0122, 255, 3),
+  (0x0123, 258, 3),
+  (0x0124, 261, 3),
+  (0x0125, 264, 3),
+  (0x0128, 267, 3),
+  (0x0129, 270, 3),
+  (0x012A, 273, 3),
+  (0x012B, 276, 3),
+  (0x012C, 279, 3),
+  (0x012D, 282, 3),
+  (0x012E, 285, 3),
+  (0x012F, 288, 3),
+  (0x0130, 291, 3),
+  (0x0134, 294, 3),
+  (0x0135, 297, 3),
+  (0x0136, 300, 3),
+  (0x0137, 303, 3),
+  (0x0139, 306, 3),
+  (0x013A, 309, 3),
+  (0x013B, 312, 3),
+  (0x013C, 315, 3),
+  (0x013D, 318, 3),
+  (0x013E, 321, 3),
+  (0x0143, 324, 3),
+  (0x0144, 327, 3),
+  (0x0145, 330, 3),
+  (0x0146, 333, 3),
+  (0x0147, 336, 3),
+  (0x0148, 339, 3),
+  (0x014C, 342, 3),
+  (0x014D, 345, 3),
+  (0x014E, 348, 3),
+  (0x014F, 351, 3),
+  (0x0150, 354, 3),
+  (0x0151, 357, 3),
+  (0x0154, 360, 3),
+  (0x0155, 363, 3),
+  (0x0156, 366, 3),
+  (0x0157, 369, 3),
+  (0x0158, 372, 3),
+  (0x0159, 375, 3),
+  (0x015A, 378, 3),
+  (0x015B, 381, 3),
+  (0x015C, 384, 3),
+  (0x015D, 387, 3),
+  (0x015E, 390, 3),
+  (0x015F, 393, 3),
+  (0x0160, 396, 3),
+  (0x0161, 399, 3),
+  (0x0162, 402, 3),
+  (0x0163, 405, 3),
+  (0x0164, 408, 3),
+  (0x0165, 411, 3),
+  (0x0168, 414, 3),
+  (0x0169, 417, 3),
+  (0x016A, 420, 3),
+  (0x016B, 423, 3),
+  (0x016C, 426, 3),
+  (0x016D, 429, 3),
+  (0x016E, 432, 3),
+  (0x016F, 435, 3),
+  (0x0170, 438, 3),
+  (0x0171, 441, 3),
+  (0x0172, 444, 3),
+  (0x0173, 447, 3),
+  (0x0174, 450, 3),
+  (0x0175, 453, 3),
+  (0x0176, 456, 3),
+  (0x0177, 459, 3),
+  (0x0178, 462, 3),
+  (0x0179, 465, 3),
+  (0x017A, 468, 3),
+  (0x017B, 471, 3),
+  (0x017C, 474, 3),
+  (0x017D, 477, 3),
+  (0x017E, 480, 3),
+  (0x01A0, 483, 3),
+  (0x01A1, 486, 3),
+  (0x01AF, 489, 3),
+  (0x01B0, 492, 3),
+  (0x01CD, 495, 3),
+  (0x01CE, 498, 3),
+  (0x01CF, 501, 3),
+  (0x01D0, 504, 3),
+  (0x01D1, 507, 3),
+  (0x01D2, 510, 3),
+  (0x01D3, 513, 3),
+  (0x01D4, 516, 3),
+  (0x01D5, 519, 5),
+  (0x01D6, 524, 5),
+  (0x01D7, 529, 5),
+  (0x01D8, 534, 5),
+  (0x01D9, 539, 5),
+  (0x01DA, 544, 5),
+  (0x01DB, 549, 5),
+  (0x01DC, 554, 5),
+  (0x01DE, 559, 5),
+  (0x01DF, 564, 5),
+  (0x01E0, 569, 5),
+  (0x01E1, 574, 5),
+  (0x01E2, 579, 4),
+  (0x01E3, 583, 4),
+  (0x01E6, 587, 3),
+  (0x01E7, 590, 3),
+  (0x01E8, 593, 3),
+  (0x01E9, 596, 3),
+  (0x01EA, 599, 3),
+  (0x01EB, 602, 3),
+  (0x01EC, 605, 5),
+  (0x01ED, 610, 5),
+  (0x01EE, 615, 4),
+  (0x01EF, 619, 4),
+  (0x01F0, 623, 3),
+  (0x01F4, 626, 3),
+  (0x01F5, 629, 3),
+  (0x01F8, 632, 3),
+  (0x01F9, 635, 3),
+  (0x01FA, 638, 5),
+  (0x01FB, 643, 5),
+  (0x01FC, 648, 4),
+  (0x01FD, 652, 4),
+  (0x01FE, 656, 4),
+  (0x01FF, 660, 4),
+  (0x0200, 664, 3),
+  (0x0201, 667, 3),
+  (0x0202, 670, 3),
+  (0x0203, 673, 3),
+  (0x0204, 676, 3),
+  (0x0205, 679, 3),
+  (0x0206, 682, 3),
+  (0x0207, 685, 3),
+  (0x0208, 688, 3),
+  (0x0209, 691, 3),
+  (0x020A, 694, 3),
+  (0x020B, 697, 3),
+  (0x020C, 700, 3),
+  (0x020D, 703, 3),
+  (0x020E, 706, 3),
+  (0x020F, 709, 3),
+  (0x0210, 712, 3),
+  (0x0211, 715, 3),
+  (0x0212, 718, 3),
+  (0x0213, 721, 3),
+  (0x0214, 724, 3),
+  (0x0215, 727, 3),
+  (0x0216, 730, 3),
+  (0x0217, 733, 3),
+  (0x0218, 736, 3),
+  (0x0219, 739, 3),
+  (0x021A, 742, 3),
+  (0x021B, 745, 3),
+  (0x021E, 748, 3),
+  (0x021F, 751, 3),
+  (0x0226, 754, 3),
+  (0x0227, 757, 3),
+  (0x0228, 760, 3),
+  (0x0229, 763, 3),
+  (0x022A, 766, 5),
+  (0x022B, 771, 5),
+  (0x022C, 776, 5),
+  (0x022D, 781, 5),
+  (0x022E, 786, 3),
+  (0x022F, 789, 3),
+  (0x0230, 792, 5),
+  (0x0231, 797, 5),
+  (0x0232, 802, 3),
+  (0x0233, 805, 3),
+  (0x0340, 808, 2),
+  (0x0341, 810, 2),
+  (0x0343, 812, 2),
+  (0x0344, 814, 4),
+  (0x0374, 818, 2),
+  (0x037E, 820, 1),
+  (0x0385, 821, 4),
+  (0x0386, 825, 4),
+  (0x0387, 829, 2),
+  (0x0388, 831, 4),
+  (0x0389, 835, 4),
+  (0x038A, 839, 4),
+  (0x038C, 843, 4),
+  (0x038E, 847, 4),
+  (0x038F, 851, 4),
+  (0x0390, 855, 6),
+  (0x03AA, 861, 4),
+  (0x03AB, 865, 4),
+  (0x03AC, 869, 4),
+  (0x03AD, 873, 4),
+  (0x03AE, 877, 4),
+  (0x03AF, 881, 4),
+  (0x03B0, 885, 6),
+  (0x03CA, 891, 4),
+  (0x03CB, 895, 4),
+  (0x03CC, 899, 4),
+  (0x03CD, 903, 4),
+  (0x03CE, 907, 4),
+  (0x03D3, 911, 4),
+  (0x03D4, 915, 4),
+  (0x0400, 919, 4),
+  (0x0401, 923, 4),
+  (0x0403, 927, 4),
+  (0x0407, 931, 4),
+  (0x040C, 935, 4),
+  (0x040D, 939, 4),
+  (0x040E, 943, 4),
+  (0x0419, 947, 4),
+  (0x0439, 951, 4),
+  (0x0450, 955, 4),
+  (0x0451, 959, 4),
+  (0x0453, 963, 4),
+  (0x0457, 967, 4),
+  (0x045C, 971, 4),
+  (0x045D, 975, 4),
+  (0x045E, 979, 4),
+  (0x0476, 983, 4),
+  (0x0477, 987, 4),
+  (0x04C1, 991, 4),
+  (0x04C2, 995, 4),
+  (0x04D0, 999, 4),
+  (0x04D1, 1003, 4),
+  (0x04D2, 1007, 4),
+  (0x04D3, 1011, 4),
+  (0x04D6, 1015, 4),
+  (0x04D7, 1019, 4),
+  (0x04DA, 1023, 4),
+  (0x04DB, 1027, 4),
+  (0x04DC, 1031, 4),
+  (0x04DD, 1035, 4),
+  (0x04DE, 1039, 4),
+  (0x04DF, 1043, 4),
+  (0x04E2, 1047, 4),
+  (0x04E3, 1051, 4),
+  (0x04E4, 1055, 4),
+  (0x04E5, 1059, 4),
+  (0x04E6, 1063, 4),
+  (0x04E7, 1067, 4),
+  (0x04EA, 1071, 4),
+  (0x04EB, 1075, 4),
+  (0x04EC, 1079, 4),
+  (0x04ED, 1083, 4),
+  (0x04EE, 1087, 4),
+  (0x04EF, 1091, 4),
+  (0x04F0, 1095, 4),
+  (0x04F1, 1099, 4),
+  (0x04F2, 1103, 4),
+  (0x04F3, 1107, 4),
+  (0x04F4, 1111, 4),
+  (0x04F5, 1115, 4),
+  (0x04F8, 1119, 4),
+  (0x04F9, 1123, 4),
+  (0x0622, 1127, 4),
+  (0x0623, 1131, 4),
+  (0x0624, 1135, 4),
+  (0x0625, 1139, 4),
+  (0x0626, 1143, 4),
+  (0x06C0, 1147, 4),
+  (0x06C2, 1151, 4),
+  (0x06D3, 1155, 4),
+  (0x0929, 1159, 6),
+  (0x0931, 1165, 6),
+  (0x0934, 1171, 6),
+  (0x0958, 1177, 6),
+  (0x0959, 1183, 6),
+  (0x095A, 1189, 6),
+  (0x095B, 1195, 6),
+  (0x095C, 1201, 6),
+  (0x095D, 1207, 6),
+  (0x095E, 1213, 6),
+  (0x095F, 1219, 6),
+  (0x09CB, 1225, 6),
+  (0x09CC, 1231, 6),
+  (0x09DC, 1237, 6),
+  (0x09DD, 1243, 6),
+  (0x09DF, 1249, 6),
+  (0x0A33, 1255, 6),
+  (0x0A36, 1261, 6),
+  (0x0A59, 1267, 6),
+  (0x0A5A, 1273, 6),
+  (0x0A5B, 1279, 6),
+  (0x0A5E, 1285, 6),
+  (0x0B48, 1291, 6),
+  (0x0B4B, 1297, 6),
+  (0x0B4C, 1303, 6),
+  (0x0B5C, 1309, 6),
+  (0x0B5D, 1315, 6),
+  (0x0B94, 1321, 6),
+  (0x0BCA, 1327, 6),
+  (0x0BCB, 1333, 6),
+  (0x0BCC, 1339, 6),
+  (0x0C48, 1345, 6),
+  (0x0CC0, 1351, 6),
+  (0x0CC7, 1357, 6),
+  (0x0CC8, 1363, 6),
+  (0x0CCA, 1369, 6),
+  (0x0CCB, 1375, 9),
+  (0x0D4A, 1384, 6),
+  (0x0D4B, 1390, 6),
+  (0x0D4C, 1396, 6),
+  (0x0DDA, 1402, 6),
+  (0x0DDC, 1408, 6),
+  (0x0DDD, 1414, 9),
+  (0x0DDE, 1423, 6),
+  (0x0F43, 1429, 6),
+  (0x0F4D, 1435, 6),
+  (0x0F52, 1441, 6),
+  (0x0F57, 1447, 6),
+  (0x0F5C, 1453, 6),
+  (0x0F69, 1459, 6),
+  (0x0F73, 1465, 6),
+  (0x0F75, 1471, 6),
+  (0x0F76, 1477, 6),
+  (0x0F78, 1483, 6),
+  (0x0F81, 1489, 6),
+  (0x0F93, 1495, 6),
+  (0x0F9D, 1501, 6),
+  (0x0FA2, 1507, 6),
+  (0x0FA7, 1513, 6),
+  (0x0FAC, 1519, 6),
+  (0x0FB9, 1525, 6),
+  (0x1026, 1531, 6),
+  (0x1B06, 1537, 6),
+  (0x1B08, 1543, 6),
+  (0x1B0A, 1549, 6),
+  (0x1B0C, 1555, 6),
+  (0x1B0E, 1561, 6),
+  (0x1B12, 1567, 6),
+  (0x1B3B, 1573, 6),
+  (0x1B3D, 1579, 6),
+  (0x1B40, 1585, 6),
+  (0x1B41, 1591, 6),
+  (0x1B43, 1597, 6),
+  (0x1E00, 1603, 3),
+  (0x1E01, 1606, 3),
+  (0x1E02, 1609, 3),
+  (0x1E03, 1612, 3),
+  (0x1E04, 1615, 3),
+  (0x1E05, 1618, 3),
+  (0x1E06, 1621, 3),
+  (0x1E07, 1624, 3),
+  (0x1E08, 1627, 5),
+  (0x1E09, 1632, 5),
+  (0x1E0A, 1637, 3),
+  (0x1E0B, 1640, 3),
+  (0x1E0C, 1643, 3),
+  (0x1E0D, 1646, 3),
+  (0x1E0E, 1649, 3),
+  (0x1E0F, 1652, 3),
+  (0x1E10, 1655, 3),
+  (0x1E11, 1658, 3),
+  (0x1E12, 1661, 3),
+  (0x1E13, 1664, 3),
+  (0x1E14, 1667, 5),
+  (0x1E15, 1672, 5),
+  (0x1E16, 1677, 5),
+  (0x1E17, 1682, 5),
+  (0x1E18, 1687, 3),
+  (0x1E19, 1690, 3),
+  (0x1E1A, 1693, 3),
+  (0x1E1B, 1696, 3),
+  (0x1E1C, 1699, 5),
+  (0x1E1D, 1704, 5),
+  (0x1E1E, 1709, 3),
+  (0x1E1F, 1712, 3),
+  (0x1E20, 1715, 3),
+  (0x1E21, 1718, 3),
+  (0x1E22, 1721, 3),
+  (0x1E23, 1724, 3),
+  (0x1E24, 1727, 3),
+  (0x1E25, 1730, 3),
+  (0x1E26, 1733, 3),
+  (0x1E27, 1736, 3),
+  (0x1E28, 1739, 3),
+  (0x1E29, 1742, 3),
+  (0x1E2A, 1745, 3),
+  (0x1E2B, 1748, 3),
+  (0x1E2C, 1751, 3),
+  (0x1E2D, 1754, 3),
+  (0x1E2E, 1757, 5),
+  (0x1E2F, 1762, 5),
+  (0x1E30, 1767, 3),
+  (0x1E31, 1770, 3),
+  (0x1E32, 1773, 3),
+  (0x1E33, 1776, 3),
+  (0x1E34, 1779, 3),
+  (0x1E35, 1782, 3),
+  (0x1E36, 1785, 3),
+  (0x1E37, 1788, 3),
+  (0x1E38, 1791, 5),
+  (0x1E39, 1796, 5),
+  (0x1E3A, 1801, 3),
+  (0x1E3B, 1804, 3),
+  (0x1E3C, 1807, 3),
+  (0x1E3D, 1810, 3),
+  (0x1E3E, 1813, 3),
+  (0x1E3F, 1816, 3),
+  (0x1E40, 1819, 3),
+  (0x1E41, 1822, 3),
+  (0x1E42, 1825, 3),
+  (0x1E43, 1828, 3),
+  (0x1E44, 1831, 3),
+  (0x1E45, 1834, 3),
+  (0x1E46, 1837, 3),
+  (0x1E47, 1840, 3),
+  (0x1E48, 1843, 3),
+  (0x1E49, 1846, 3),
+  (0x1E4A, 1849, 3),
+  (0x1E4B, 1852, 3),
+  (0x1E4C, 1855, 5),
+  (0x1E4D, 1860, 5),
+  (0x1E4E, 1865, 5),
+  (0x1E4F, 1870, 5),
+  (0x1E50, 1875, 5),
+  (0x1E51, 1880, 5),
+  (0x1E52, 1885, 5),
+  (0x1E53, 1890, 5),
+  (0x1E54, 1895, 3),
+  (0x1E55, 1898, 3),
+  (0x1E56, 1901, 3),
+  (0x1E57, 1904, 3),
+  (0x1E58, 1907, 3),
+  (0x1E59, 1910, 3),
+  (0x1E5A, 1913, 3),
+  (0x1E5B, 1916, 3),
+  (0x1E5C, 1919, 5),
+  (0x1E5D, 1924, 5),
+  (0x1E5E, 1929, 3),
+  (0x1E5F, 1932, 3),
+  (0x1E60, 1935, 3),
+  (0x1E61, 1938, 3),
+  (0x1E62, 1941, 3),
+  (0x1E63, 1944, 3),
+  (0x1E64, 1947, 5),
+  (0x1E65, 1952, 5),
+  (0x1E66, 1957, 5),
+  (0x1E67, 1962, 5),
+  (0x1E68, 1967, 5),
+  (0x1E69, 1972, 5),
+  (0x1E6A, 1977, 3),
+  (0x1E6B, 1980, 3),
+  (0x1E6C, 1983, 3),
+  (0x1E6D, 1986, 3),
+  (0x1E6E, 1989, 3),
+  (0x1E6F, 1992, 3),
+  (0x1E70, 1995, 3),
+  (0x1E71, 1998, 3),
+  (0x1E72, 2001, 3),
+  (0x1E73, 2004, 3),
+  (0x1E74, 2007, 3),
+  (0x1E75, 2010, 3),
+  (0x1E76, 2013, 3),
+  (0x1E77, 2016, 3),
+  (0x1E78, 2019, 5),
+  (0x1E79, 2024, 5),
+  (0x1E7A, 2029, 5),
+  (0x1E7B, 2034, 5),
+  (0x1E7C, 2039, 3),
+  (0x1E7D, 2042, 3),
+  (0x1E7E, 2045, 3),
+  (0x1E7F, 2048, 3),
+  (0x1E80, 2051, 3),
+  (0x1E81, 2054, 3),
+  (0x1E82, 2057, 3),
+  (0x1E83, 2060, 3),
+  (0x1E84, 2063, 3),
+  (0x1E85, 2066, 3),
+  (0x1E86, 2069, 3),
+  (0x1E87, 2072, 3),
+  (0x1E88, 2075, 3),
+  (0x1E89, 2078, 3),
+  (0x1E8A, 2081, 3),
+  (0x1E8B, 2084, 3),
+  (0x1E8C, 2087, 3),
+  (0x1E8D, 2090, 3),
+  (0x1E8E, 2093, 3),
+  (0x1E8F, 2096, 3),
+  (0x1E90, 2099, 3),
+  (0x1E91, 2102, 3),
+  (0x1E92, 2105, 3),
+  (0x1E93, 2108, 3),
+  (0x1E94, 2111, 3),
+  (0x1E95, 2114, 3),
+  (0x1E96, 2117, 3),
+  (0x1E97, 2120, 3),
+  (0x1E98, 2123, 3),
+  (0x1E99, 2126, 3),
+  (0x1E9B, 2129, 4),
+  (0x1EA0, 2133, 3),
+  (0x1EA1, 2136, 3),
+  (0x1EA2, 2139, 3),
+  (0x1EA3, 2142, 3),
+  (0x1EA4, 2145, 5),
+  (0x1EA5, 2150, 5),
+  (0x1EA6, 2155, 5),
+  (0x1EA7, 2160, 5),
+  (0x1EA8, 2165, 5),
+  (0x1EA9, 2170, 5),
+  (0x1EAA, 2175, 5),
+  (0x1EAB, 2180, 5),
+  (0x1EAC, 2185, 5),
+  (0x1EAD, 2190, 5),
+  (0x1EAE, 2195, 5),
+  (0x1EAF, 2200, 5),
+  (0x1EB0, 2205, 5),
+  (0x1EB1, 2210, 5),
+  (0x1EB2, 2215, 5),
+  (0x1EB3, 2220, 5),
+  (0x1EB4, 2225, 5),
+  (0x1EB5, 2230, 5),
+  (0x1EB6, 2235, 5),
+  (0x1EB7, 2240, 5),
+  (0x1EB8, 2245, 3),
+  (0x1EB9, 2248, 3),
+  (0x1EBA, 2251, 3),
+  (0x1EBB, 2254, 3),
+  (0x1EBC, 2257, 3),
+  (0x1EBD, 2260, 3),
+  (0x1EBE, 2263, 5),
+  (0x1EBF, 2268, 5),
+  (0x1EC0, 2273, 5),
+  (0x1EC1, 2278, 5),
+  (0x1EC2, 2283, 5),
+  (0x1EC3, 2288, 5),
+  (0x1EC4, 2293, 5),
+  (0x1EC5, 2298, 5),
+  (0x1EC6, 2303, 5),
+  (0x1EC7, 2308, 5),
+  (0x1EC8, 2313, 3),
+  (0x1EC9, 2316, 3),
+  (0x1ECA, 2319, 3),
+  (0x1ECB, 2322, 3),
+  (0x1ECC, 2325, 3),
+  (0x1ECD, 2328, 3),
+  (0x1ECE, 2331, 3),
+  (0x1ECF, 2334, 3),
+  (0x1ED0, 2337, 5),
+  (0x1ED1, 2342, 5),
+  (0x1ED2, 2347, 5),
+  (0x1ED3, 2352, 5),
+  (0x1ED4, 2357, 5),
+  (0x1ED5, 2362, 5),
+  (0x1ED6, 2367, 5),
+  (0x1ED7, 2372, 5),
+  (0x1ED8, 2377, 5),
+  (0x1ED9, 2382, 5),
+  (0x1EDA, 2387, 5),
+  (0x1EDB, 2392, 5),
+  (0x1EDC, 2397, 5),
+  (0x1EDD, 2402, 5),
+  (0x1EDE, 2407, 5),
+  (0x1EDF, 2412, 5),
+  (0x1EE0, 2417, 5),
+  (0x1EE1, 2422, 5),
+  (0x1EE2, 2427, 5),
+  (0x1EE3, 2432, 5),
+  (0x1EE4, 2437, 3),
+  (0x1EE5, 2440, 3),
+  (0x1EE6, 2443, 3),
+  (0x1EE7, 2446, 3),
+  (0x1EE8, 2449, 5),
+  (0x1EE9, 2454, 5),
+  (0x1EEA, 2459, 5),
+  (0x1EEB, 2464, 5),
+  (0x1EEC, 2469, 5),
+  (0x1EED, 2474, 5),
+  (0x1EEE, 2479, 5),
+  (0x1EEF, 2484, 5),
+  (0x1EF0, 2489, 5),
+  (0x1EF1, 2494, 5),
+  (0x1EF2, 2499, 3),
+  (0x1EF3, 2502, 3),
+  (0x1EF4, 2505, 3),
+  (0x1EF5, 2508, 3),
+  (0x1EF6, 2511, 3),
+  (0x1EF7, 2514, 3),
+  (0x1EF8, 2517, 3),
+  (0x1EF9, 2520, 3),
+  (0x1F00, 2523, 4),
+  (0x1F01, 2527, 4),
+  (0x1F02, 2531, 6),
+  (0x1F03, 2537, 6),
+  (0x1F04, 2543, 6),
+  (0x1F05, 2549, 6),
+  (0x1F06, 2555, 6),
+  (0x1F07, 2561, 6),
+  (0x1F08, 2567, 4),
+  (0x1F09, 2571, 4),
+  (0x1F0A, 2575, 6),
+  (0x1F0B, 2581, 6),
+  (0x1F0C, 2587, 6),
+  (0x1F0D, 2593, 6),
+  (0x1F0E, 2599, 6),
+  (0x1F0F, 2605, 6),
+  (0x1F10, 2611, 4),
+  (0x1F11, 2615, 4),
+  (0x1F12, 2619, 6),
+  (0x1F13, 2625, 6),
+  (0x1F14, 2631, 6),
+  (0x1F15, 2637, 6),
+  (0x1F18, 2643, 4),
+  (0x1F19, 2647, 4),
+  (0x1F1A, 2651, 6),
+  (0x1F1B, 2657, 6),
+  (0x1F1C, 2663, 6),
+  (0x1F1D, 2669, 6),
+  (0x1F20, 2675, 4),
+  (0x1F21, 2679, 4),
+  (0x1F22, 2683, 6),
+  (0x1F23, 2689, 6),
+  (0x1F24, 2695, 6),
+  (0x1F25, 2701, 6),
+  (0x1F26, 2707, 6),
+  (0x1F27, 2713, 6),
+  (0x1F28, 2719, 4),
+  (0x1F29, 2723, 4),
+  (0x1F2A, 2727, 6),
+  (0x1F2B, 2733, 6),
+  (0x1F2C, 2739, 6),
+  (0x1F2D, 2745, 6),
+  (0x1F2E, 2751, 6),
+  (0x1F2F, 2757, 6),
+  (0x1F30, 2763, 4),
+  (0x1F31, 2767, 4),
+  (0x1F32, 2771, 6),
+  (0x1F33, 2777, 6),
+  (0x1F34, 2783, 6),
+  (0x1F35, 2789, 6),
+  (0x1F36, 2795, 6),
+  (0x1F37, 2801, 6),
+  (0x1F38, 2807, 4),
+  (0x1F39, 2811, 4),
+  (0x1F3A, 2815, 6),
+  (0x1F3B, 2821, 6),
+  (0x1F3C, 2827, 6),
+  (0x1F3D, 2833, 6),
+  (0x1F3E, 2839, 6),
+  (0x1F3F, 2845, 6),
+  (0x1F40, 2851, 4),
+  (0x1F41, 2855, 4),
+  (0x1F42, 2859, 6),
+  (0x1F43, 2865, 6),
+  (0x1F44, 2871, 6),
+  (0x1F45, 2877, 6),
+  (0x1F48, 2883, 4),
+  (0x1F49, 2887, 4),
+  (0x1F4A, 2891, 6),
+  (0x1F4B, 2897, 6),
+  (0x1F4C, 2903, 6),
+  (0x1F4D, 2909, 6),
+  (0x1F50, 2915, 4),
+  (0x1F51, 2919, 4),
+  (0x1F52, 2923, 6),
+  (0x1F53, 2929, 6),
+  (0x1F54, 2935, 6),
+  (0x1F55, 2941, 6),
+  (0x1F56, 2947, 6),
+  (0x1F57, 2953, 6),
+  (0x1F59, 2959, 4),
+  (0x1F5B, 2963, 6),
+  (0x1F5D, 2969, 6),
+  (0x1F5F, 2975, 6),
+  (0x1F60, 2981, 4),
+  (0x1F61, 2985, 4),
+  (0x1F62, 2989, 6),
+  (0x1F63, 2995, 6),
+  (0x1F64, 3001, 6),
+  (0x1F65, 3007, 6),
+  (0x1F66, 3013, 6),
+  (0x1F67, 3019, 6),
+  (0x1F68, 3025, 4),
+  (0x1F69, 3029, 4),
+  (0x1F6A, 3033, 6),
+  (0x1F6B, 3039, 6),
+  (0x1F6C, 3045, 6),
+  (0x1F6D, 3051, 6),
+  (0x1F6E, 3057, 6),
+  (0x1F6F, 3063, 6),
+  (0x1F70, 3069, 4),
+  (0x1F71, 3073, 4),
+  (0x1F72, 3077, 4),
+  (0x1F73, 3081, 4),
+  (0x1F74, 3085, 4),
+  (0x1F75, 3089, 4),
+  (0x1F76, 3093, 4),
+  (0x1F77, 3097, 4),
+  (0x1F78, 3101, 4),
+  (0x1F79, 3105, 4),
+  (0x1F7A, 3109, 4),
+  (0x1F7B, 3113, 4),
+  (0x1F7C, 3117, 4),
+  (0x1F7D, 3121, 4),
+  (0x1F80, 3125, 6),
+  (0x1F81, 3131, 6),
+  (0x1F82, 3137, 8),
+  (0x1F83, 3145, 8),
+  (0x1F84, 3153, 8),
+  (0x1F85, 3161, 8),
+  (0x1F86, 3169, 8),
+  (0x1F87, 3177, 8),
+  (0x1F88, 3185, 6),
+  (0x1F89, 3191, 6),
+  (0x1F8A, 3197, 8),
+  (0x1F8B, 3205, 8),
+  (0x1F8C, 3213, 8),
+  (0x1F8D, 3221, 8),
+  (0x1F8E, 3229, 8),
+  (0x1F8F, 3237, 8),
+  (0x1F90, 3245, 6),
+  (0x1F91, 3251, 6),
+  (0x1F92, 3257, 8),
+  (0x1F93, 3265, 8),
+  (0x1F94, 3273, 8),
+  (0x1F95, 3281, 8),
+  (0x1F96, 3289, 8),
+  (0x1F97, 3297, 8),
+  (0x1F98, 3305, 6),
+  (0x1F99, 3311, 6),
+  (0x1F9A, 3317, 8),
+  (0x1F9B, 3325, 8),
+  (0x1F9C, 3333, 8),
+  (0x1F9D, 3341, 8),
+  (0x1F9E, 3349, 8),
+  (0x1F9F, 3357, 8),
+  (0x1FA0, 3365, 6),
+  (0x1FA1, 3371, 6),
+  (0x1FA2, 3377, 8),
+  (0x1FA3, 3385, 8),
+  (0x1FA4, 3393, 8),
+  (0x1FA5, 3401, 8),
+  (0x1FA6, 3409, 8),
+  (0x1FA7, 3417, 8),
+  (0x1FA8, 3425, 6),
+  (0x1FA9, 3431, 6),
+  (0x1FAA, 3437, 8),
+  (0x1FAB, 3445, 8),
+  (0x1FAC, 3453, 8),
+  (0x1FAD, 3461, 8),
+  (0x1FAE, 3469, 8),
+  (0x1FAF, 3477, 8),
+  (0x1FB0, 3485, 4),
+  (0x1FB1, 3489, 4),
+  (0x1FB2, 3493, 6),
+  (0x1FB3, 3499, 4),
+  (0x1FB4, 3503, 6),
+  (0x1FB6, 3509, 4),
+  (0x1FB7, 3513, 6),
+  (0x1FB8, 3519, 4),
+  (0x1FB9, 3523, 4),
+  (0x1FBA, 3527, 4),
+  (0x1FBB, 3531, 4),
+  (0x1FBC, 3535, 4),
+  (0x1FBE, 3539, 2),
+  (0x1FC1, 3541, 4),
+  (0x1FC2, 3545, 6),
+  (0x1FC3, 3551, 4),
+  (0x1FC4, 3555, 6),
+  (0x1FC6, 3561, 4),
+  (0x1FC7, 3565, 6),
+  (0x1FC8, 3571, 4),
+  (0x1FC9, 3575, 4),
+  (0x1FCA, 3579, 4),
+  (0x1FCB, 3583, 4),
+  (0x1FCC, 3587, 4),
+  (0x1FCD, 3591, 5),
+  (0x1FCE, 3596, 5),
+  (0x1FCF, 3601, 5),
+  (0x1FD0, 3606, 4),
+  (0x1FD1, 3610, 4),
+  (0x1FD2, 3614, 6),
+  (0x1FD3, 3620, 6),
+  (0x1FD6, 3626, 4),
+  (0x1FD7, 3630, 6),
+  (0x1FD8, 3636, 4),
+  (0x1FD9, 3640, 4),
+  (0x1FDA, 3644, 4),
+  (0x1FDB, 3648, 4),
+  (0x1FDD, 3652, 5),
+  (0x1FDE, 3657, 5),
+  (0x1FDF, 3662, 5),
+  (0x1FE0, 3667, 4),
+  (0x1FE1, 3671, 4),
+  (0x1FE2, 3675, 6),
+  (0x1FE3, 3681, 6),
+  (0x1FE4, 3687, 4),
+  (0x1FE5, 3691, 4),
+  (0x1FE6, 3695, 4),
+  (0x1FE7, 3699, 6),
+  (0x1FE8, 3705, 4),
+  (0x1FE9, 3709, 4),
+  (0x1FEA, 3713, 4),
+  (0x1FEB, 3717, 4),
+  (0x1FEC, 3721, 4),
+  (0x1FED, 3725, 4),
+  (0x1FEE, 3729, 4),
+  (0x1FEF, 3733, 1),
+  (0x1FF2, 3734, 6),
+  (0x1FF3, 3740, 4),
+  (0x1FF4, 3744, 6),
+  (0x1FF6, 3750, 4),
+  (0x1FF7, 3754, 6),
+  (0x1FF8, 3760, 4),
+  (0x1FF9, 3764, 4),
+  (0x1FFA, 3768, 4),
+  (0x1FFB, 3772, 4),
+  (0x1FFC, 3776, 4),
+  (0x1FFD, 3780, 2),
+  (0x2000, 3782, 3),
+  (0x2001, 3785, 3),
+  (0x2126, 3788, 2),
+  (0x212A, 3790, 1),
+  (0x212B, 3791, 3),
+  (0x219A, 3794, 5),
+  (0x219B, 3799, 5),
+  (0x21AE, 3804, 5),
+  (0x21CD, 3809, 5),
+  (0x21CE, 3814, 5),
+  (0x21CF, 3819, 5),
+  (0x2204, 3824, 5),
+  (0x2209, 3829, 5),
+  (0x220C, 3834, 5),
+  (0x2224, 3839, 5),
+  (0x2226, 3844, 5),
+  (0x2241, 3849, 5),
+  (0x2244, 3854, 5),
+  (0x2247, 3859, 5),
+  (0x2249, 3864, 5),
+  (0x2260, 3869, 3),
+  (0x2262, 3872, 5),
+  (0x226D, 3877, 5),
+  (0x226E, 3882, 3),
+  (0x226F, 3885, 3),
+  (0x2270, 3888, 5),
+  (0x2271, 3893, 5),
+  (0x2274, 3898, 5),
+  (0x2275, 3903, 5),
+  (0x2278, 3908, 5),
+  (0x2279, 3913, 5),
+  (0x2280, 3918, 5),
+  (0x2281, 3923, 5),
+  (0x2284, 3928, 5),
+  (0x2285, 3933, 5),
+  (0x2288, 3938, 5),
+  (0x2289, 3943, 5),
+  (0x22AC, 3948, 5),
+  (0x22AD, 3953, 5),
+  (0x22AE, 3958, 5),
+  (0x22AF, 3963, 5),
+  (0x22E0, 3968, 5),
+  (0x22E1, 3973, 5),
+  (0x22E2, 3978, 5),
+  (0x22E3, 3983, 5),
+  (0x22EA, 3988, 5),
+  (0x22EB, 3993, 5),
+  (0x22EC, 3998, 5),
+  (0x22ED, 4003, 5),
+  (0x2329, 4008, 3),
+  (0x232A, 4011, 3),
+  (0x2ADC, 4014, 5),
+  (0x304C, 4019, 6),
+  (0x304E, 4025, 6),
+  (0x3050, 4031, 6),
+  (0x3052, 4037, 6),
+  (0x3054, 4043, 6),
+  (0x3056, 4049, 6),
+  (0x3058, 4055, 6),
+  (0x305A, 4061, 6),
+  (0x305C, 4067, 6),
+  (0x305E, 4073, 6),
+  (0x3060, 4079, 6),
+  (0x3062, 4085, 6),
+  (0x3065, 4091, 6),
+  (0x3067, 4097, 6),
+  (0x3069, 4103, 6),
+  (0x3070, 4109, 6),
+  (0x3071, 4115, 6),
+  (0x3073, 4121, 6),
+  (0x3074, 4127, 6),
+  (0x3076, 4133, 6),
+  (0x3077, 4139, 6),
+  (0x3079, 4145, 6),
+  (0x307A, 4151, 6),
+  (0x307C, 4157, 6),
+  (0x307D, 4163, 6),
+  (0x3094, 4169, 6),
+  (0x309E, 4175, 6),
+  (0x30AC, 4181, 6),
+  (0x30AE, 4187, 6),
+  (0x30B0, 4193, 6),
+  (0x30B2, 4199, 6),
+  (0x30B4, 4205, 6),
+  (0x30B6, 4211, 6),
+  (0x30B8, 4217, 6),
+  (0x30BA, 4223, 6),
+  (0x30BC, 4229, 6),
+  (0x30BE, 4235, 6),
+  (0x30C0, 4241, 6),
+  (0x30C2, 4247, 6),
+  (0x30C5, 4253, 6),
+  (0x30C7, 4259, 6),
+  (0x30C9, 4265, 6),
+  (0x30D0, 4271, 6),
+  (0x30D1, 4277, 6),
+  (0x30D3, 4283, 6),
+  (0x30D4, 4289, 6),
+  (0x30D6, 4295, 6),
+  (0x30D7, 4301, 6),
+  (0x30D9, 4307, 6),
+  (0x30DA, 4313, 6),
+  (0x30DC, 4319, 6),
+  (0x30DD, 4325, 6),
+  (0x30F4, 4331, 6),
+  (0x30F7, 4337, 6),
+  (0x30F8, 4343, 6),
+  (0x30F9, 4349, 6),
+  (0x30FA, 4355, 6),
+  (0x30FE, 4361, 6),
+  (0xF900, 4367, 3),
+  (0xF901, 4370, 3),
+  (0xF902, 4373, 3),
+  (0xF903, 4376, 3),
+  (0xF904, 4379, 3),
+  (0xF905, 4382, 3),
+  (0xF906, 4385, 3),
+  (0xF907, 4388, 3),
+  (0xF908, 4391, 3),
+  (0xF909, 4394, 3),
+  (0xF90A, 4397, 3),
+  (0xF90B, 4400, 3),
+  (0xF90C, 4403, 3),
+  (0xF90D, 4406, 3),
+  (0xF90E, 4409, 3),
+  (0xF90F, 4412, 3),
+  (0xF910, 4415, 3),
+  (0xF911, 4418, 3),
+  (0xF912, 4421, 3),
+  (0xF913, 4424, 3),
+  (0xF914, 4427, 3),
+  (0xF915, 4430, 3),
+  (0xF916, 4433, 3),
+  (0xF917, 4436, 3),
+  (0xF918, 4439, 3),
+  (0xF919, 4442, 3),
+  (0xF91A, 4445, 3),
+  (0xF91B, 4448, 3),
+  (0xF91C, 4451, 3),
+  (0xF91D, 4454, 3),
+  (0xF91E, 4457, 3),
+  (0xF91F, 4460, 3),
+  (0xF920, 4463, 3),
+  (0xF921, 4466, 3),
+  (0xF922, 4469, 3),
+  (0xF923, 4472, 3),
+  (0xF924, 4475, 3),
+  (0xF925, 4478, 3),
+  (0xF926, 4481, 3),
+  (0xF927, 4484, 3),
+  (0xF928, 4487, 3),
+  (0xF929, 4490, 3),
+  (0xF92A, 4493, 3),
+  (0xF92B, 4496, 3),
+  (0xF92C, 4499, 3),
+  (0xF92D, 4502, 3),
+  (0xF92E, 4505, 3),
+  (0xF92F, 4508, 3),
+  (0xF930, 4511, 3),
+  (0xF931, 4514, 3),
+  (0xF932, 4517, 3),
+  (0xF933, 4520, 3),
+  (0xF934, 4523, 3),
+  (0xF935, 4526, 3),
+  (0xF936, 4529, 3),
+  (0xF937, 4532, 3),
+  (0xF938, 4535, 3),
+  (0xF939, 4538, 3),
+  (0xF93A, 4541, 3),
+  (0xF93B, 4544, 3),
+  (0xF93C, 4547, 3),
+  (0xF93D, 4550, 3),
+  (0xF93E, 4553, 3),
+  (0xF93F, 4556, 3),
+  (0xF940, 4559, 3),
+  (0xF941, 4562, 3),
+  (0xF942, 4565, 3),
+  (0xF943, 4568, 3),
+  (0xF944, 4571, 3),
+  (0xF945, 4574, 3),
+  (0xF946, 4577, 3),
+  (0xF947, 4580, 3),
+  (0xF948, 4583, 3),
+  (0xF949, 4586, 3),
+  (0xF94A, 4589, 3),
+  (0xF94B, 4592, 3),
+  (0xF94C, 4595, 3),
+  (0xF94D, 4598, 3),
+  (0xF94E, 4601, 3),
+  (0xF94F, 4604, 3),
+  (0xF950, 4607, 3),
+  (0xF951, 4610, 3),
+  (0xF952, 4613, 3),
+  (0xF953, 4616, 3),
+  (0xF954, 4619, 3),
+  (0xF955, 4622, 3),
+  (0xF956, 4625, 3),
+  (0xF957, 4628, 3),
+  (0xF958, 4631, 3),
+  (0xF959, 4634, 3),
+  (0xF95A, 4637, 3),
+  (0xF95B, 4640, 3),
+  (0xF95C, 4643, 3),
+  (0xF95D, 4646, 3),
+  (0xF95E, 4649, 3),
+  (0xF95F, 4652, 3),
+  (0xF960, 4655, 3),
+  (0xF961, 4658, 3),
+  (0xF962, 4661, 3),
+  (0xF963, 4664, 3),
+  (0xF964, 4667, 3),
+  (0xF965, 4670, 3),
+  (0xF966, 4673, 3),
+  (0xF967, 4676, 3),
+  (0xF968, 4679, 3),
+  (0xF969, 4682, 3),
+  (0xF96A, 4685, 3),
+  (0xF96B, 4688, 3),
+  (0xF96C, 4691, 3),
+  (0xF96D, 4694, 3),
+  (0xF96E, 4697, 3),
+  (0xF96F, 4700, 3),
+  (0xF970, 4703, 3),
+  (0xF971, 4706, 3),
+  (0xF972, 4709, 3),
+  (0xF973, 4712, 3),
+  (0xF974, 4715, 3),
+  (0xF975, 4718, 3),
+  (0xF976, 4721, 3),
+  (0xF977, 4724, 3),
+  (0xF978, 4727, 3),
+  (0xF979, 4730, 3),
+  (0xF97A, 4733, 3),
+  (0xF97B, 4736, 3),
+  (0xF97C, 4739, 3),
+  (0xF97D, 4742, 3),
+  (0xF97E, 4745, 3),
+  (0xF97F, 4748, 3),
+  (0xF980, 4751, 3),
+  (0xF981, 4754, 3),
+  (0xF982, 4757, 3),
+  (0xF983, 4760, 3),
+  (0xF984, 4763, 3),
+  (0xF985, 4766, 3),
+  (0xF986, 4769, 3),
+  (0xF987, 4772, 3),
+  (0xF988, 4775, 3),
+  (0xF989, 4778, 3),
+  (0xF98A, 4781, 3),
+  (0xF98B, 4784, 3),
+  (0xF98C, 4787, 3),
+  (0xF98D, 4790, 3),
+  (0xF98E, 4793, 3),
+  (0xF98F, 4796, 3),
+  (0xF990, 4799, 3),
+  (0xF991, 4802, 3),
+  (0xF992, 4805, 3),
+  (0xF993, 4808, 3),
+  (0xF994, 4811, 3),
+  (0xF995, 4814, 3),
+  (0xF996, 4817, 3),
+  (0xF997, 4820, 3),
+  (0xF998, 4823, 3),
+  (0xF999, 4826, 3),
+  (0xF99A, 4829, 3),
+  (0xF99B, 4832, 3),
+  (0xF99C, 4835, 3),
+  (0xF99D, 4838, 3),
+  (0xF99E, 4841, 3),
+  (0xF99F, 4844, 3),
+  (0xF9A0, 4847, 3),
+  (0xF9A1, 4850, 3),
+  (0xF9A2, 4853, 3),
+  (0xF9A3, 4856, 3),
+  (0xF9A4, 4859, 3),
+  (0xF9A5, 4862, 3),
+  (0xF9A6, 4865, 3),
+  (0xF9A7, 4868, 3),
+  (0xF9A8, 4871, 3),
+  (0xF9A9, 4874, 3),
+  (0xF9AA, 4877, 3),
+  (0xF9AB, 4880, 3),
+  (0xF9AC, 4883, 3),
+  (0xF9AD, 4886, 3),
+  (0xF9AE, 4889, 3),
+  (0xF9AF, 4892, 3),
+  (0xF9B0, 4895, 3),
+  (0xF9B1, 4898, 3),
+  (0xF9B2, 4901, 3),
+  (0xF9B3, 4904, 3),
+  (0xF9B4, 4907, 3),
+  (0xF9B5, 4910, 3),
+  (0xF9B6, 4913, 3),
+  (0xF9B7, 4916, 3),
+  (0xF9B8, 4919, 3),
+  (0xF9B9, 4922, 3),
+  (0xF9BA, 4925, 3),
+  (0xF9BB, 4928, 3),
+  (0xF9BC, 4931, 3),
+  (0xF9BD, 4934, 3),
+  (0xF9BE, 4937, 3),
+  (0xF9BF, 4940, 3),
+  (0xF9C0, 4943, 3),
+  (0xF9C1, 4946, 3),
+  (0xF9C2, 4949, 3),
+  (0xF9C3, 4952, 3),
+  (0xF9C4, 4955, 3),
+  (0xF9C5, 4958, 3),
+  (0xF9C6, 4961, 3),
+  (0xF9C7, 4964, 3),
+  (0xF9C8, 4967, 3),
+  (0xF9C9, 4970, 3),
+  (0xF9CA, 4973, 3),
+  (0xF9CB, 4976, 3),
+  (0xF9CC, 4979, 3),
+  (0xF9CD, 4982, 3),
+  (0xF9CE, 4985, 3),
+  (0xF9CF, 4988, 3),
+  (0xF9D0, 4991, 3),
+  (0xF9D1, 4994, 3),
+  (0xF9D2, 4997, 3),
+  (0xF9D3, 5000, 3),
+  (0xF9D4, 5003, 3),
+  (0xF9D5, 5006, 3),
+  (0xF9D6, 5009, 3),
+  (0xF9D7, 5012, 3),
+  (0xF9D8, 5015, 3),
+  (0xF9D9, 5018, 3),
+  (0xF9DA, 5021, 3),
+  (0xF9DB, 5024, 3),
+  (0xF9DC, 5027, 3),
+  (0xF9DD, 5030, 3),
+  (0xF9DE, 5033, 3),
+  (0xF9DF, 5036, 3),
+  (0xF9E0, 5039, 3),
+  (0xF9E1, 5042, 3),
+  (0xF9E2, 5045, 3),
+  (0xF9E3, 5048, 3),
+  (0xF9E4, 5051, 3),
+  (0xF9E5, 5054, 3),
+  (0xF9E6, 5057, 3),
+  (0xF9E7, 5060, 3),
+  (0xF9E8, 5063, 3),
+  (0xF9E9, 5066, 3),
+  (0xF9EA, 5069, 3),
+  (0xF9EB, 5072, 3),
+  (0xF9EC, 5075, 3),
+  (0xF9ED, 5078, 3),
+  (0xF9EE, 5081, 3),
+  (0xF9EF, 5084, 3),
+  (0xF9F0, 5087, 3),
+  (0xF9F1, 5090, 3),
+  (0xF9F2, 5093, 3),
+  (0xF9F3, 5096, 3),
+  (0xF9F4, 5099, 3),
+  (0xF9F5, 5102, 3),
+  (0xF9F6, 5105, 3),
+  (0xF9F7, 5108, 3),
+  (0xF9F8, 5111, 3),
+  (0xF9F9, 5114, 3),
+  (0xF9FA, 5117, 3),
+  (0xF9FB, 5120, 3),
+  (0xF9FC, 5123, 3),
+  (0xF9FD, 5126, 3),
+  (0xF9FE, 5129, 3),
+  (0xF9FF, 5132, 3),
+  (0xFA00, 5135, 3),
+  (0xFA01, 5138, 3),
+  (0xFA02, 5141, 3),
+  (0xFA03, 5144, 3),
+  (0xFA04, 5147, 3),
+  (0xFA05, 5150, 3),
+  (0xFA06, 5153, 3),
+  (0xFA07, 5156, 3),
+  (0xFA08, 5159, 3),
+  (0xFA09, 5162, 3),
+  (0xFA0A, 5165, 3),
+  (0xFA0B, 5168, 3),
+  (0xFA0C, 5171, 3),
+  (0xFA0D, 5174, 3),
+  (0xFA10, 5177, 3),
+  (0xFA12, 5180, 3),
+  (0xFA15, 5183, 3),
+  (0xFA16, 5186, 3),
+  (0xFA17, 5189, 3),
+  (0xFA18, 5192, 3),
+  (0xFA19, 5195, 3),
+  (0xFA1A, 5198, 3),
+  (0xFA1B, 5201, 3),
+  (0xFA1C, 5204, 3),
+  (0xFA1D, 5207, 3),
+  (0xFA1E, 5210, 3),
+  (0xFA20, 5213, 3),
+  (0xFA22, 5216, 3),
+  (0xFA25, 5219, 3),
+  (0xFA26, 5222, 3),
+  (0xFA2A, 5225, 3),
+  (0xFA2B, 5228, 3),
+  (0xFA2C, 5231, 3),
+  (0xFA2D, 5234, 3),
+  (0xFA2E, 5237, 3),
+  (0xFA2F, 5240, 3),
+  (0xFA30, 5243, 3),
+  (0xFA31, 5246, 3),
+  (0xFA32, 5249, 3),
+  (0xFA33, 5252, 3),
+  (0xFA34, 5255, 3),
+  (0xFA35, 5258, 3),
+  (0xFA36, 5261, 3),
+  (0xFA37, 5264, 3),
+  (0xFA38, 5267, 3),
+  (0xFA39, 5270, 3),
+  (0xFA3A, 5273, 3),
+  (0xFA3B, 5276, 3),
+  (0xFA3C, 5279, 3),
+  (0xFA3D, 5282, 3),
+  (0xFA3E, 5285, 3),
+  (0xFA3F, 5288, 3),
+  (0xFA40, 5291, 3),
+  (0xFA41, 5294, 3),
+  (0xFA42, 5297, 3),
+  (0xFA43, 5300, 3),
+  (0xFA44, 5303, 3),
+  (0xFA45, 5306, 3),
+  (0xFA46, 5309, 3),
+  (0xFA47, 5312, 3),
+  (0xFA48, 5315, 3),
+  (0xFA49, 5318, 3),
+  (0xFA4A, 5321, 3),
+  (0xFA4B, 5324, 3),
+  (0xFA4C, 5327, 3),
+  (0xFA4D, 5330, 3),
+  (0xFA4E, 5333, 3),
+  (0xFA4F, 5336, 3),
+  (0xFA50, 5339, 3),
+  (0xFA51, 5342, 3),
+  (0xFA52, 5345, 3),
+  (0xFA53, 5348, 3),
+  (0xFA54, 5351, 3),
+  (0xFA55, 5354, 3),
+  (0xFA56, 5357, 3),
+  (0xFA57, 5360, 3),
+  (0xFA58, 5363, 3),
+  (0xFA59, 5366, 3),
+  (0xFA5A, 5369, 3),
+  (0xFA5B, 5372, 3),
+  (0xFA5C, 5375, 3),
+  (0xFA5D, 5378, 3),
+  (0xFA5E, 5381, 3),
+  (0xFA5F, 5384, 3),
+  (0xFA60, 5387, 3),
+  (0xFA61, 5390, 3),
+  (0xFA62, 5393, 3),
+  (0xFA63, 5396, 3),
+  (0xFA64, 5399, 3),
+  (0xFA65, 5402, 3),
+  (0xFA66, 5405, 3),
+  (0xFA67, 5408, 3),
+  (0xFA68, 5411, 3),
+  (0xFA69, 5414, 3),
+  (0xFA6A, 5417, 3),
+  (0xFA6B, 5420, 3),
+  (0xFA6C, 5423, 4),
+  (0xFA6D, 5427, 3),
+  (0xFA70, 5430, 3),
+  (0xFA71, 5433, 3),
+  (0xFA72, 5436, 3),
+  (0xFA73, 5439, 3),
+  (0xFA74, 5442, 3),
+  (0xFA75, 5445, 3),
+  (0xFA76, 5448, 3),
+  (0xFA77, 5451, 3),
+  (0xFA78, 5454, 3),
+  (0xFA79, 5457, 3),
+  (0xFA7A, 5460, 3),
+  (0xFA7B, 5463, 3),
+  (0xFA7C, 5466, 3),
+  (0xFA7D, 5469, 3),
+  (0xFA7E, 5472, 3),
+  (0xFA7F, 5475, 3),
+  (0xFA80, 5478, 3),
+  (0xFA81, 5481, 3),
+  (0xFA82, 5484, 3),
+  (0xFA83, 5487, 3),
+  (0xFA84, 5490, 3),
+  (0xFA85, 5493, 3),
+  (0xFA86, 5496, 3),
+  (0xFA87, 5499, 3),
+  (0xFA88, 5502, 3),
+  (0xFA89, 5505, 3),
+  (0xFA8A, 5508, 3),
+  (0xFA8B, 5511, 3),
+  (0xFA8C, 5514, 3),
+  (0xFA8D, 5517, 3),
+  (0xFA8E, 5520, 3),
+  (0xFA8F, 5523, 3),
+  (0xFA90, 5526, 3),
+  (0xFA91, 5529, 3),
+  (0xFA92, 5532, 3),
+  (0xFA93, 5535, 3),
+  (0xFA94, 5538, 3),
+  (0xFA95, 5541, 3),
+  (0xFA96, 5544, 3),
+  (0xFA97, 5547, 3),
+  (0xFA98, 5550, 3),
+  (0xFA99, 5553, 3),
+  (0xFA9A, 5556, 3),
+  (0xFA9B, 5559, 3),
+  (0xFA9C, 5562, 3),
+  (0xFA9D, 5565, 3),
+  (0xFA9E, 5568, 3),
+  (0xFA9F, 5571, 3),
+  (0xFAA0, 5574, 3),
+  (0xFAA1, 5577, 3),
+  (0xFAA2, 5580, 3),
+  (0xFAA3, 5583, 3),
+  (0xFAA4, 5586, 3),
+  (0xFAA5, 5589, 3),
+  (0xFAA6, 5592, 3),
+  (0xFAA7, 5595, 3),
+  (0xFAA8, 5598, 3),
+  (0xFAA9, 5601, 3),
+  (0xFAAA, 5604, 3),
+  (0xFAAB, 5607, 3),
+  (0xFAAC, 5610, 3),
+  (0xFAAD, 5613, 3),
+  (0xFAAE, 5616, 3),
+  (0xFAAF, 5619, 3),
+  (0xFAB0, 5622, 3),
+  (0xFAB1, 5625, 3),
+  (0xFAB2, 5628, 3),
+  (0xFAB3, 5631, 3),
+  (0xFAB4, 5634, 3),
+  (0xFAB5, 5637, 3),
+  (0xFAB6, 5640, 3),
+  (0xFAB7, 5643, 3),
+  (0xFAB8, 5646, 3),
+  (0xFAB9, 5649, 3),
+  (0xFABA, 5652, 3),
+  (0xFABB, 5655, 3),
+  (0xFABC, 5658, 3),
+  (0xFABD, 5661, 3),
+  (0xFABE, 5664, 3),
+  (0xFABF, 5667, 3),
+  (0xFAC0, 5670, 3),
+  (0xFAC1, 5673, 3),
+  (0xFAC2, 5676, 3),
+  (0xFAC3, 5679, 3),
+  (0xFAC4, 5682, 3),
+  (0xFAC5, 5685, 3),
+  (0xFAC6, 5688, 3),
+  (0xFAC7, 5691, 3),
+  (0xFAC8, 5694, 3),
+  (0xFAC9, 5697, 3),
+  (0xFACA, 5700, 3),
+  (0xFACB, 5703, 3),
+  (0xFACC, 5706, 3),
+  (0xFACD, 5709, 3),
+  (0xFACE, 5712, 3),
+  (0xFACF, 5715, 4),
+  (0xFAD0, 5719, 4),
+  (0xFAD1, 5723, 4),
+  (0xFAD2, 5727, 3),
+  (0xFAD3, 5730, 3),
+  (0xFAD4, 5733, 3),
+  (0xFAD5, 5736, 4),
+  (0xFAD6, 5740, 4),
+  (0xFAD7, 5744, 4),
+  (0xFAD8, 5748, 3),
+  (0xFAD9, 5751, 3),
+  (0xFB1D, 5754, 4),
+  (0xFB1F, 5758, 4),
+  (0xFB2A, 5762, 4),
+  (0xFB2B, 5766, 4),
+  (0xFB2C, 5770, 6),
+  (0xFB2D, 5776, 6),
+  (0xFB2E, 5782, 4),
+  (0xFB2F, 5786, 4),
+  (0xFB30, 5790, 4),
+  (0xFB31, 5794, 4),
+  (0xFB32, 5798, 4),
+  (0xFB33, 5802, 4),
+  (0xFB34, 5806, 4),
+  (0xFB35, 5810, 4),
+  (0xFB36, 5814, 4),
+  (0xFB38, 5818, 4),
+  (0xFB39, 5822, 4),
+  (0xFB3A, 5826, 4),
+  (0xFB3B, 5830, 4),
+  (0xFB3C, 5834, 4),
+  (0xFB3E, 5838, 4),
+  (0xFB40, 5842, 4),
+  (0xFB41, 5846, 4),
+  (0xFB43, 5850, 4),
+  (0xFB44, 5854, 4),
+  (0xFB46, 5858, 4),
+  (0xFB47, 5862, 4),
+  (0xFB48, 5866, 4),
+  (0xFB49, 5870, 4),
+  (0xFB4A, 5874, 4),
+  (0xFB4B, 5878, 4),
+  (0xFB4C, 5882, 4),
+  (0xFB4D, 5886, 4),
+  (0xFB4E, 5890, 4),
+  (0x1109A, 5894, 8),
+  (0x1109C, 5902, 8),
+  (0x110AB, 5910, 8),
+  (0x1112E, 5918, 8),
+  (0x1112F, 5926, 8),
+  (0x1134B, 5934, 8),
+  (0x1134C, 5942, 8),
+  (0x114BB, 5950, 8),
+  (0x114BC, 5958, 8),
+  (0x114BE, 5966, 8),
+  (0x115BA, 5974, 8),
+  (0x115BB, 5982, 8),
+  (0x11938, 5990, 8),
+  (0x1D15E, 5998, 8),
+  (0x1D15F, 6006, 8),
+  (0x1D160, 6014, 12),
+  (0x1D161, 6026, 12),
+  (0x1D162, 6038, 12),
+  (0x1D163, 6050, 12),
+  (0x1D164, 6062, 12),
+  (0x1D1BB, 6074, 8),
+  (0x1D1BC, 6082, 8),
+  (0x1D1BD, 6090, 12),
+  (0x1D1BE, 6102, 12),
+  (0x1D1BF, 6114, 12),
+  (0x1D1C0, 6126, 12),
+  (0x2F800, 6138, 3),
+  (0x2F801, 6141, 3),
+  (0x2F802, 6144, 3),
+  (0x2F803, 6147, 4),
+  (0x2F804, 6151, 3),
+  (0x2F805, 6154, 3),
+  (0x2F806, 6157, 3),
+  (0x2F807, 6160, 3),
+  (0x2F808, 6163, 3),
+  (0x2F809, 6166, 3),
+  (0x2F80A, 6169, 3),
+  (0x2F80B, 6172, 3),
+  (0x2F80C, 6175, 3),
+  (0x2F80D, 6178, 4),
+  (0x2F80E, 6182, 3),
+  (0x2F80F, 6185, 3),
+  (0x2F810, 6188, 3),
+  (0x2F811, 6191, 3),
+  (0x2F812, 6194, 4),
+  (0x2F813, 6198, 3),
+  (0x2F814, 6201, 3),
+  (0x2F815, 6204, 3),
+  (0x2F816, 6207, 4),
+  (0x2F817, 6211, 3),
+  (0x2F818, 6214, 3),
+  (0x2F819, 6217, 3),
+  (0x2F81A, 6220, 3),
+  (0x2F81B, 6223, 3),
+  (0x2F81C, 6226, 4),
+  (0x2F81D, 6230, 3),
+  (0x2F81E, 6233, 3),
+  (0x2F81F, 6236, 3),
+  (0x2F820, 6239, 3),
+  (0x2F821, 6242, 3),
+  (0x2F822, 6245, 3),
+  (0x2F823, 6248, 3),
+  (0x2F824, 6251, 3),
+  (0x2F825, 6254, 3),
+  (0x2F826, 6257, 3),
+  (0x2F827, 6260, 3),
+  (0x2F828, 6263, 3),
+  (0x2F829, 6266, 3),
+  (0x2F82A, 6269, 3),
+  (0x2F82B, 6272, 3),
+  (0x2F82C, 6275, 3),
+  (0x2F82D, 6278, 3),
+  (0x2F82E, 6281, 3),
+  (0x2F82F, 6284, 3),
+  (0x2F830, 6287, 3),
+  (0x2F831, 6290, 3),
+  (0x2F832, 6293, 3),
+  (0x2F833, 6296, 3),
+  (0x2F834, 6299, 4),
+  (0x2F835, 6303, 3),
+  (0x2F836, 6306, 3),
+  (0x2F837, 6309, 3),
+  (0x2F838, 6312, 4),
+  (0x2F839, 6316, 3),
+  (0x2F83A, 6319, 3),
+  (0x2F83B, 6322, 3),
+  (0x2F83C, 6325, 3),
+  (0x2F83D, 6328, 3),
+  (0x2F83E, 6331, 3),
+  (0x2F83F, 6334, 3),
+  (0x2F840, 6337, 3),
+  (0x2F841, 6340, 3),
+  (0x2F842, 6343, 3),
+  (0x2F843, 6346, 3),
+  (0x2F844, 6349, 3),
+  (0x2F845, 6352, 3),
+  (0x2F846, 6355, 3),
+  (0x2F847, 6358, 3),
+  (0x2F848, 6361, 3),
+  (0x2F849, 6364, 3),
+  (0x2F84A, 6367, 3),
+  (0x2F84B, 6370, 3),
+  (0x2F84C, 6373, 3),
+  (0x2F84D, 6376, 3),
+  (0x2F84E, 6379, 3),
+  (0x2F84F, 6382, 3),
+  (0x2F850, 6385, 3),
+  (0x2F851, 6388, 3),
+  (0x2F852, 6391, 3),
+  (0x2F853, 6394, 3),
+  (0x2F854, 6397, 3),
+  (0x2F855, 6400, 3),
+  (0x2F856, 6403, 3),
+  (0x2F857, 6406, 3),
+  (0x2F858, 6409, 3),
+  (0x2F859, 6412, 4),
+  (0x2F85A, 6416, 3),
+  (0x2F85B, 6419, 3),
+  (0x2F85C, 6422, 3),
+  (0x2F85D, 6425, 3),
+  (0x2F85E, 6428, 3),
+  (0x2F85F, 6431, 3),
+  (0x2F860, 6434, 4),
+  (0x2F861, 6438, 4),
+  (0x2F862, 6442, 3),
+  (0x2F863, 6445, 3),
+  (0x2F864, 6448, 3),
+  (0x2F865, 6451, 3),
+  (0x2F866, 6454, 3),
+  (0x2F867, 6457, 3),
+  (0x2F868, 6460, 3),
+  (0x2F869, 6463, 3),
+  (0x2F86A, 6466, 3),
+  (0x2F86B, 6469, 3),
+  (0x2F86C, 6472, 4),
+  (0x2F86D, 6476, 3),
+  (0x2F86E, 6479, 3),
+  (0x2F86F, 6482, 3),
+  (0x2F870, 6485, 3),
+  (0x2F871, 6488, 4),
+  (0x2F872, 6492, 3),
+  (0x2F873, 6495, 3),
+  (0x2F874, 6498, 3),
+  (0x2F875, 6501, 3),
+  (0x2F876, 6504, 3),
+  (0x2F877, 6507, 3),
+  (0x2F878, 6510, 3),
+  (0x2F879, 6513, 3),
+  (0x2F87A, 6516, 3),
+  (0x2F87B, 6519, 4),
+  (0x2F87C, 6523, 3),
+  (0x2F87D, 6526, 4),
+  (0x2F87E, 6530, 3),
+  (0x2F87F, 6533, 3),
+  (0x2F880, 6536, 3),
+  (0x2F881, 6539, 3),
+  (0x2F882, 6542, 3),
+  (0x2F883, 6545, 3),
+  (0x2F884, 6548, 3),
+  (0x2F885, 6551, 3),
+  (0x2F886, 6554, 3),
+  (0x2F887, 6557, 3),
+  (0x2F888, 6560, 3),
+  (0x2F889, 6563, 4),
+  (0x2F88A, 6567, 3),
+  (0x2F88B, 6570, 3),
+  (0x2F88C, 6573, 3),
+  (0x2F88D, 6576, 3),
+  (0x2F88E, 6579, 3),
+  (0x2F88F, 6582, 4),
+  (0x2F890, 6586, 3),
+  (0x2F891, 6589, 4),
+  (0x2F892, 6593, 4),
+  (0x2F893, 6597, 3),
+  (0x2F894, 6600, 3),
+  (0x2F895, 6603, 3),
+  (0x2F896, 6606, 3),
+  (0x2F897, 6609, 4),
+  (0x2F898, 6613, 4),
+  (0x2F899, 6617, 3),
+  (0x2F89A, 6620, 3),
+  (0x2F89B, 6623, 3),
+  (0x2F89C, 6626, 3),
+  (0x2F89D, 6629, 3),
+  (0x2F89E, 6632, 3),
+  (0x2F89F, 6635, 3),
+  (0x2F8A0, 6638, 3),
+  (0x2F8A1, 6641, 3),
+  (0x2F8A2, 6644, 3),
+  (0x2F8A3, 6647, 3),
+  (0x2F8A4, 6650, 4),
+  (0x2F8A5, 6654, 3),
+  (0x2F8A6, 6657, 3),
+  (0x2F8A7, 6660, 3),
+  (0x2F8A8, 6663, 3),
+  (0x2F8A9, 6666, 3),
+  (0x2F8AA, 6669, 3),
+  (0x2F8AB, 6672, 3),
+  (0x2F8AC, 6675, 3),
+  (0x2F8AD, 6678, 3),
+  (0x2F8AE, 6681, 3),
+  (0x2F8AF, 6684, 3),
+  (0x2F8B0, 6687, 3),
+  (0x2F8B1, 6690, 3),
+  (0x2F8B2, 6693, 3),
+  (0x2F8B3, 6696, 3),
+  (0x2F8B4, 6699, 3),
+  (0x2F8B5, 6702, 3),
+  (0x2F8B6, 6705, 3),
+  (0x2F8B7, 6708, 3),
+  (0x2F8B8, 6711, 4),
+  (0x2F8B9, 6715, 3),
+  (0x2F8BA, 6718, 3),
+  (0x2F8BB, 6721, 3),
+  (0x2F8BC, 6724, 3),
+  (0x2F8BD, 6727, 3),
+  (0x2F8BE, 6730, 4),
+  (0x2F8BF, 6734, 3),
+  (0x2F8C0, 6737, 3),
+  (0x2F8C1, 6740, 3),
+  (0x2F8C2, 6743, 3),
+  (0x2F8C3, 6746, 3),
+  (0x2F8C4, 6749, 3),
+  (0x2F8C5, 6752, 3),
+  (0x2F8C6, 6755, 3),
+  (0x2F8C7, 6758, 3),
+  (0x2F8C8, 6761, 3),
+  (0x2F8C9, 6764, 3),
+  (0x2F8CA, 6767, 4),
+  (0x2F8CB, 6771, 3),
+  (0x2F8CC, 6774, 3),
+  (0x2F8CD, 6777, 3),
+  (0x2F8CE, 6780, 3),
+  (0x2F8CF, 6783, 3),
+  (0x2F8D0, 6786, 3),
+  (0x2F8D1, 6789, 3),
+  (0x2F8D2, 6792, 3),
+  (0x2F8D3, 6795, 3),
+  (0x2F8D4, 6798, 3),
+  (0x2F8D5, 6801, 3),
+  (0x2F8D6, 6804, 3),
+  (0x2F8D7, 6807, 3),
+  (0x2F8D8, 6810, 3),
+  (0x2F8D9, 6813, 3),
+  (0x2F8DA, 6816, 3),
+  (0x2F8DB, 6819, 3),
+  (0x2F8DC, 6822, 3),
+  (0x2F8DD, 6825, 4),
+  (0x2F8DE, 6829, 3),
+  (0x2F8DF, 6832, 3),
+  (0x2F8E0, 6835, 3),
+  (0x2F8E1, 6838, 3),
+  (0x2F8E2, 6841, 3),
+  (0x2F8E3, 6844, 4),
+  (0x2F8E4, 6848, 3),
+  (0x2F8E5, 6851, 3),
+  (0x2F8E6, 6854, 3),
+  (0x2F8E7, 6857, 3),
+  (0x2F8E8, 6860, 3),
+  (0x2F8E9, 6863, 3),
+  (0x2F8EA, 6866, 3),
+  (0x2F8EB, 6869, 3),
+  (0x2F8EC, 6872, 4),
+  (0x2F8ED, 6876, 3),
+  (0x2F8EE, 6879, 3),
+  (0x2F8EF, 6882, 3),
+  (0x2F8F0, 6885, 4),
+  (0x2F8F1, 6889, 3),
+  (0x2F8F2, 6892, 3),
+  (0x2F8F3, 6895, 3),
+  (0x2F8F4, 6898, 3),
+  (0x2F8F5, 6901, 3),
+  (0x2F8F6, 6904, 3),
+  (0x2F8F7, 6907, 4),
+  (0x2F8F8, 6911, 4),
+  (0x2F8F9, 6915, 4),
+  (0x2F8FA, 6919, 3),
+  (0x2F8FB, 6922, 4),
+  (0x2F8FC, 6926, 3),
+  (0x2F8FD, 6929, 3),
+  (0x2F8FE, 6932, 3),
+  (0x2F8FF, 6935, 3),
+  (0x2F900, 6938, 3),
+  (0x2F901, 6941, 3),
+  (0x2F902, 6944, 3),
+  (0x2F903, 6947, 3),
+  (0x2F904, 6950, 3),
+  (0x2F905, 6953, 3),
+  (0x2F906, 6956, 4),
+  (0x2F907, 6960, 3),
+  (0x2F908, 6963, 3),
+  (0x2F909, 6966, 3),
+  (0x2F90A, 6969, 3),
+  (0x2F90B, 6972, 3),
+  (0x2F90C, 6975, 3),
+  (0x2F90D, 6978, 4),
+  (0x2F90E, 6982, 3),
+  (0x2F90F, 6985, 3),
+  (0x2F910, 6988, 4),
+  (0x2F911, 6992, 4),
+  (0x2F912, 6996, 3),
+  (0x2F913, 6999, 3),
+  (0x2F914, 7002, 3),
+  (0x2F915, 7005, 3),
+  (0x2F916, 7008, 3),
+  (0x2F917, 7011, 3),
+  (0x2F918, 7014, 3),
+  (0x2F919, 7017, 3),
+  (0x2F91A, 7020, 3),
+  (0x2F91B, 7023, 4),
+  (0x2F91C, 7027, 3),
+  (0x2F91D, 7030, 4),
+  (0x2F91E, 7034, 3),
+  (0x2F91F, 7037, 4),
+  (0x2F920, 7041, 3),
+  (0x2F921, 7044, 3),
+  (0x2F922, 7047, 3),
+  (0x2F923, 7050, 4),
+  (0x2F924, 7054, 3),
+  (0x2F925, 7057, 3),
+  (0x2F926, 7060, 4),
+  (0x2F927, 7064, 4),
+  (0x2F928, 7068, 3),
+  (0x2F929, 7071, 3),
+  (0x2F92A, 7074, 3),
+  (0x2F92B, 7077, 3),
+  (0x2F92C, 7080, 3),
+  (0x2F92D, 7083, 3),
+  (0x2F92E, 7086, 3),
+  (0x2F92F, 7089, 3),
+  (0x2F930, 7092, 3),
+  (0x2F931, 7095, 3),
+  (0x2F932, 7098, 3),
+  (0x2F933, 7101, 3),
+  (0x2F934, 7104, 3),
+  (0x2F935, 7107, 4),
+  (0x2F936, 7111, 3),
+  (0x2F937, 7114, 4),
+  (0x2F938, 7118, 3),
+  (0x2F939, 7121, 4),
+  (0x2F93A, 7125, 3),
+  (0x2F93B, 7128, 4),
+  (0x2F93C, 7132, 4),
+  (0x2F93D, 7136, 4),
+  (0x2F93E, 7140, 3),
+  (0x2F93F, 7143, 3),
+  (0x2F940, 7146, 3),
+  (0x2F941, 7149, 4),
+  (0x2F942, 7153, 4),
+  (0x2F943, 7157, 4),
+  (0x2F944, 7161, 4),
+  (0x2F945, 7165, 3),
+  (0x2F946, 7168, 3),
+  (0x2F947, 7171, 3),
+  (0x2F948, 7174, 3),
+  (0x2F949, 7177, 3),
+  (0x2F94A, 7180, 3),
+  (0x2F94B, 7183, 3),
+  (0x2F94C, 7186, 3),
+  (0x2F94D, 7189, 4),
+  (0x2F94E, 7193, 3),
+  (0x2F94F, 7196, 3),
+  (0x2F950, 7199, 3),
+  (0x2F951, 7202, 3),
+  (0x2F952, 7205, 4),
+  (0x2F953, 7209, 3),
+  (0x2F954, 7212, 4),
+  (0x2F955, 7216, 4),
+  (0x2F956, 7220, 3),
+  (0x2F957, 7223, 3),
+  (0x2F958, 7226, 3),
+  (0x2F959, 7229, 3),
+  (0x2F95A, 7232, 3),
+  (0x2F95B, 7235, 3),
+  (0x2F95C, 7238, 4),
+  (0x2F95D, 7242, 4),
+  (0x2F95E, 7246, 4),
+  (0x2F95F, 7250, 3),
+  (0x2F960, 7253, 3),
+  (0x2F961, 7256, 4),
+  (0x2F962, 7260, 3),
+  (0x2F963, 7263, 3),
+  (0x2F964, 7266, 3),
+  (0x2F965, 7269, 4),
+  (0x2F966, 7273, 3),
+  (0x2F967, 7276, 3),
+  (0x2F968, 7279, 3),
+  (0x2F969, 7282, 3),
+  (0x2F96A, 7285, 3),
+  (0x2F96B, 7288, 4),
+  (0x2F96C, 7292, 3),
+  (0x2F96D, 7295, 3),
+  (0x2F96E, 7298, 3),
+  (0x2F96F, 7301, 3),
+  (0x2F970, 7304, 3),
+  (0x2F971, 7307, 3),
+  (0x2F972, 7310, 4),
+  (0x2F973, 7314, 4),
+  (0x2F974, 7318, 3),
+  (0x2F975, 7321, 4),
+  (0x2F976, 7325, 3),
+  (0x2F977, 7328, 4),
+  (0x2F978, 7332, 3),
+  (0x2F979, 7335, 3),
+  (0x2F97A, 7338, 3),
+  (0x2F97B, 7341, 4),
+  (0x2F97C, 7345, 4),
+  (0x2F97D, 7349, 3),
+  (0x2F97E, 7352, 4),
+  (0x2F97F, 7356, 3),
+  (0x2F980, 7359, 4),
+  (0x2F981, 7363, 3),
+  (0x2F982, 7366, 3),
+  (0x2F983, 7369, 3),
+  (0x2F984, 7372, 3),
+  (0x2F985, 7375, 3),
+  (0x2F986, 7378, 3),
+  (0x2F987, 7381, 4),
+  (0x2F988, 7385, 4),
+  (0x2F989, 7389, 4),
+  (0x2F98A, 7393, 4),
+  (0x2F98B, 7397, 3),
+  (0x2F98C, 7400, 3),
+  (0x2F98D, 7403, 3),
+  (0x2F98E, 7406, 3),
+  (0x2F98F, 7409, 3),
+  (0x2F990, 7412, 3),
+  (0x2F991, 7415, 3),
+  (0x2F992, 7418, 3),
+  (0x2F993, 7421, 3),
+  (0x2F994, 7424, 3),
+  (0x2F995, 7427, 3),
+  (0x2F996, 7430, 3),
+  (0x2F997, 7433, 4),
+  (0x2F998, 7437, 3),
+  (0x2F999, 7440, 3),
+  (0x2F99A, 7443, 3),
+  (0x2F99B, 7446, 3),
+  (0x2F99C, 7449, 3),
+  (0x2F99D, 7452, 3),
+  (0x2F99E, 7455, 3),
+  (0x2F99F, 7458, 3),
+  (0x2F9A0, 7461, 3),
+  (0x2F9A1, 7464, 3),
+  (0x2F9A2, 7467, 3),
+  (0x2F9A3, 7470, 3),
+  (0x2F9A4, 7473, 4),
+  (0x2F9A5, 7477, 4),
+  (0x2F9A6, 7481, 4),
+  (0x2F9A7, 7485, 3),
+  (0x2F9A8, 7488, 3),
+  (0x2F9A9, 7491, 3),
+  (0x2F9AA, 7494, 3),
+  (0x2F9AB, 7497, 4),
+  (0x2F9AC, 7501, 3),
+  (0x2F9AD, 7504, 4),
+  (0x2F9AE, 7508, 3),
+  (0x2F9AF, 7511, 3),
+  (0x2F9B0, 7514, 4),
+  (0x2F9B1, 7518, 4),
+  (0x2F9B2, 7522, 3),
+  (0x2F9B3, 7525, 3),
+  (0x2F9B4, 7528, 3),
+  (0x2F9B5, 7531, 3),
+  (0x2F9B6, 7534, 3),
+  (0x2F9B7, 7537, 3),
+  (0x2F9B8, 7540, 3),
+  (0x2F9B9, 7543, 3),
+  (0x2F9BA, 7546, 3),
+  (0x2F9BB, 7549, 3),
+  (0x2F9BC, 7552, 3),
+  (0x2F9BD, 7555, 3),
+  (0x2F9BE, 7558, 3),
+  (0x2F9BF, 7561, 3),
+  (0x2F9C0, 7564, 3),
+  (0x2F9C1, 7567, 3),
+  (0x2F9C2, 7570, 3),
+  (0x2F9C3, 7573, 3),
+  (0x2F9C4, 7576, 3),
+  (0x2F9C5, 7579, 4),
+  (0x2F9C6, 7583, 3),
+  (0x2F9C7, 7586, 3),
+  (0x2F9C8, 7589, 3),
+  (0x2F9C9, 7592, 3),
+  (0x2F9CA, 7595, 3),
+  (0x2F9CB, 7598, 4),
+  (0x2F9CC, 7602, 4),
+  (0x2F9CD, 7606, 3),
+  (0x2F9CE, 7609, 3),
+  (0x2F9CF, 7612, 3),
+  (0x2F9D0, 7615, 3),
+  (0x2F9D1, 7618, 3),
+  (0x2F9D2, 7621, 3),
+  (0x2F9D3, 7624, 4),
+  (0x2F9D4, 7628, 3),
+  (0x2F9D5, 7631, 3),
+  (0x2F9D6, 7634, 3),
+  (0x2F9D7, 7637, 3),
+  (0x2F9D8, 7640, 4),
+  (0x2F9D9, 7644, 4),
+  (0x2F9DA, 7648, 3),
+  (0x2F9DB, 7651, 3),
+  (0x2F9DC, 7654, 3),
+  (0x2F9DD, 7657, 4),
+  (0x2F9DE, 7661, 3),
+  (0x2F9DF, 7664, 3),
+  (0x2F9E0, 7667, 4),
+  (0x2F9E1, 7671, 4),
+  (0x2F9E2, 7675, 3),
+  (0x2F9E3, 7678, 3),
+  (0x2F9E4, 7681, 3),
+  (0x2F9E5, 7684, 4),
+  (0x2F9E6, 7688, 3),
+  (0x2F9E7, 7691, 3),
+  (0x2F9E8, 7694, 3),
+  (0x2F9E9, 7697, 3),
+  (0x2F9EA, 7700, 3),
+  (0x2F9EB, 7703, 3),
+  (0x2F9EC, 7706, 3),
+  (0x2F9ED, 7709, 4),
+  (0x2F9EE, 7713, 3),
+  (0x2F9EF, 7716, 3),
+  (0x2F9F0, 7719, 3),
+  (0x2F9F1, 7722, 4),
+  (0x2F9F2, 7726, 3),
+  (0x2F9F3, 7729, 3),
+  (0x2F9F4, 7732, 3),
+  (0x2F9F5, 7735, 3),
+  (0x2F9F6, 7738, 4),
+  (0x2F9F7, 7742, 4),
+  (0x2F9F8, 7746, 3),
+  (0x2F9F9, 7749, 3),
+  (0x2F9FA, 7752, 3),
+  (0x2F9FB, 7755, 4),
+  (0x2F9FC, 7759, 3),
+  (0x2F9FD, 7762, 4),
+  (0x2F9FE, 7766, 3),
+  (0x2F9FF, 7769, 3),
+  (0x2FA00, 7772, 3),
+  (0x2FA01, 7775, 4),
+  (0x2FA02, 7779, 3),
+  (0x2FA03, 7782, 3),
+  (0x2FA04, 7785, 3),
+  (0x2FA05, 7788, 3),
+  (0x2FA06, 7791, 3),
+  (0x2FA07, 7794, 3),
+  (0x2FA08, 7797, 3),
+  (0x2FA09, 7800, 4),
+  (0x2FA0A, 7804, 3),
+  (0x2FA0B, 7807, 3),
+  (0x2FA0C, 7810, 3),
+  (0x2FA0D, 7813, 3),
+  (0x2FA0E, 7816, 3),
+  (0x2FA0F, 7819, 3),
+  (0x2FA10, 7822, 4),
+  (0x2FA11, 7826, 3),
+  (0x2FA12, 7829, 4),
+  (0x2FA13, 7833, 4),
+  (0x2FA14, 7837, 4),
+  (0x2FA15, 7841, 3),
+  (0x2FA16, 7844, 3),
+  (0x2FA17, 7847, 3),
+  (0x2FA18, 7850, 3),
+  (0x2FA19, 7853, 3),
+  (0x2FA1A, 7856, 3),
+  (0x2FA1B, 7859, 3),
+  (0x2FA1C, 7862, 3),
+  (0x2FA1D, 7865, 4),
+];
+
+/// The canonical decompositions of code points.
+pub(crate) const DECOMPOSED: &str = concat!(
+  "A\u{300}",
+  "A\u{301}",
+  "A\u{302}",
+  "A\u{303}",
+  "A\u{308}",
+  "A\u{30A}",
+  "C\u{327}",
+  "E\u{300}",
+  "E\u{301}",
+  "E\u{302}",
+  "E\u{308}",
+  "I\u{300}",
+  "I\u{301}",
+  "I\u{302}",
+  "I\u{308}",
+  "N\u{303}",
+  "O\u{300}",
+  "O\u{301}",
+  "O\u{302}",
+  "O\u{303}",
+  "O\u{308}",
+  "U\u{300}",
+  "U\u{301}",
+  "U\u{302}",
+  "U\u{308}",
+  "Y\u{301}",
+  "a\u{300}",
+  "a\u{301}",
+  "a\u{302}",
+  "a\u{303}",
+  "a\u{308}",
+  "a\u{30A}",
+  "c\u{327}",
+  "e\u{300}",
+  "e\u{301}",
+  "e\u{302}",
+  "e\u{308}",
+  "i\u{300}",
+  "i\u{301}",
+  "i\u{302}",
+  "i\u{308}",
+  "n\u{303}",
+  "o\u{300}",
+  "o\u{301}",
+  "o\u{302}",
+  "o\u{303}",
+  "o\u{308}",
+  "u\u{300}",
+  "u\u{301}",
+  "u\u{302}",
+  "u\u{308}",
+  "y\u{301}",
+  "y\u{308}",
+  "A\u{304}",
+  "a\u{304}",
+  "A\u{306}",
+  "a\u{306}",
+  "A\u{328}",
+  "a\u{328}",
+  "C\u{301}",
+  "c\u{301}",
+  "C\u{302}",
+  "c\u{302}",
+  "C\u{307}",
+  "c\u{307}",
+  "C\u{30C}",
+  "c\u{30C}",
+  "D\u{30C}",
+  "d\u{30C}",
+  "E\u{304}",
+  "e\u{304}",
+  "E\u{306}",
+  "e\u{306}",
+  "E\u{307}",
+  "e\u{307}",
+  "E\u{328}",
+  "e\u{328}",
+  "E\u{30C}",
+  "e\u{30C}",
+  "G\u{302}",
+  "g\u{302}",
+  "G\u{306}",
+  "g\u{306}",
+  "G\u{307}",
+  "g\u{307}",
+  "G\u{327}",
+  "g\u{327}",
+  "H\u{302}",
+  "h\u{302}",
+  "I\u{303}",
+  "i\u{303}",
+  "I\u{304}",
+  "i\u{304}",
+  "I\u{306}",
+  "i\u{306}",
+  "I\u{328}",
+  "i\u{328}",
+  "I\u{307}",
+  "J\u{302}",
+  "j\u{302}",
+  "K\u{327}",
+  "k\u{327}",
+  "L\u{301}",
+  "l\u{301}",
+  "L\u{327}",
+  "l\u{327}",
+  "L\u{30C}",
+  "l\u{30C}",
+  "N\u{301}",
+  "n\u{301}",
+  "N\u{327}",
+  "n\u{327}",
+  "N\u{30C}",
+  "n\u{30C}",
+  "O\u{304}",
+  "o\u{304}",
+  "O\u{306}",
+  "o\u{306}",
+  "O\u{30B}",
+  "o\u{30B}",
+  "R\u{301}",
+  "r\u{301}",
+  "R\u{327}",
+  "r\u{327}",
+  "R\u{30C}",
+  "r\u{30C}",
+  "S\u{301}",
+  "s\u{301}",
+  "S\u{302}",
+  "s\u{302}",
+  "S\u{327}",
+  "s\u{327}",
+  "S\u{30C}",
+  "s\u{30C}",
+  "T\u{327}",
+  "t\u{327}",
+  "T\u{30C}",
+  "t\u{30C}",
+  "U\u{303}",
+  "u\u{303}",
+  "U\u{304}",
+  "u\u{304}",
+  "U\u{306}",
+  "u\u{306}",
+  "U\u{30A}",
+  "u\u{30A}",
+  "U\u{30B}",
+  "u\u{30B}",
+  "U\u{328}",
+  "u\u{328}",
+  "W\u{302}",
+  "w\u{302}",
+  "Y\u{302}",
+  "y\u{302}",
+  "Y\u{308}",
+  "Z\u{301}",
+  "z\u{301}",
+  "Z\u{307}",
+  "z\u{307}",
+  "Z\u{30C}",
+  "z\u{30C}",
+  "O\u{31B}",
+  "o\u{31B}",
+  "U\u{31B}",
+  "u\u{31B}",
+  "A\u{30C}",
+  "a\u{30C}",
+  "I\u{30C}",
+  "i\u{30C}",
+  "O\u{30C}",
+  "o\u{30C}",
+  "U\u{30C}",
+  "u\u{30C}",
+  "U\u{308}\u{304}",
+  "u\u{308}\u{304}",
+  "U\u{308}\u{301}",
+  "u\u{308}\u{301}",
+  "U\u{308}\u{30C}",
+  "u\u{308}\u{30C}",
+  "U\u{308}\u{300}",
+  "u\u{308}\u{300}",
+  "A\u{308}\u{304}",
+  "a\u{308}\u{304}",
+  "A\u{307}\u{304}",
+  "a\u{307}\u{304}",
+  "Æ\u{304}",
+  "æ\u{304}",
+  "G\u{30C}",
+  "g\u{30C}",
+  "K\u{30C}",
+  "k\u{30C}",
+  "O\u{328}",
+  "o\u{328}",
+  "O\u{328}\u{304}",
+  "o\u{328}\u{304}",
+  "Ʒ\u{30C}",
+  "ʒ\u{30C}",
+  "j\u{30C}",
+  "G\u{301}",
+  "g\u{301}",
+  "N\u{300}",
+  "n\u{300}",
+  "A\u{30A}\u{301}",
+  "a\u{30A}\u{301}",
+  "Æ\u{301}",
+  "æ\u{301}",
+  "Ø\u{301}",
+  "ø\u{301}",
+  "A\u{30F}",
+  "a\u{30F}",
+  "A\u{311}",
+  "a\u{311}",
+  "E\u{30F}",
+  "e\u{30F}",
+  "E\u{311}",
+  "e\u{311}",
+  "I\u{30F}",
+  "i\u{30F}",
+  "I\u{311}",
+  "i\u{311}",
+  "O\u{30F}",
+  "o\u{30F}",
+  "O\u{311}",
+  "o\u{311}",
+  "R\u{30F}",
+  "r\u{30F}",
+  "R\u{311}",
+  "r\u{311}",
+  "U\u{30F}",
+  "u\u{30F}",
+  "U\u{311}",
+  "u\u{311}",
+  "S\u{326}",
+  "s\u{326}",
+  "T\u{326}",
+  "t\u{326}",
+  "H\u{30C}",
+  "h\u{30C}",
+  "A\u{307}",
+  "a\u{307}",
+  "E\u{327}",
+  "e\u{327}",
+  "O\u{308}\u{304}",
+  "o\u{308}\u{304}",
+  "O\u{303}\u{304}",
+  "o\u{303}\u{304}",
+  "O\u{307}",
+  "o\u{307}",
+  "O\u{307}\u{304}",
+  "o\u{307}\u{304}",
+  "Y\u{304}",
+  "y\u{304}",
+  "\u{300}",
+  "\u{301}",
+  "\u{313}",
+  "\u{308}\u{301}",
+  "ʹ",
+  ";",
+  "¨\u{301}",
+  "Α\u{301}",
+  "·",
+  "Ε\u{301}",
+  "Η\u{301}",
+  "Ι\u{301}",
+  "Ο\u{301}",
+  "Υ\u{301}",
+  "Ω\u{301}",
+  "ι\u{308}\u{301}",
+  "Ι\u{308}",
+  "Υ\u{308}",
+  "α\u{301}",
+  "ε\u{301}",
+  "η\u{301}",
+  "ι\u{301}",
+  "υ\u{308}\u{301}",
+  "ι\u{308}",
+  "υ\u{308}",
+  "ο\u{301}",
+  "υ\u{301}",
+  "ω\u{301}",
+  "ϒ\u{301}",
+  "ϒ\u{308}",
+  "Е\u{300}",
+  "Е\u{308}",
+  "Г\u{301}",
+  "І\u{308}",
+  "К\u{301}",
+  "И\u{300}",
+  "У\u{306}",
+  "И\u{306}",
+  "и\u{306}",
+  "е\u{300}",
+  "е\u{308}",
+  "г\u{301}",
+  "і\u{308}",
+  "к\u{301}",
+  "и\u{300}",
+  "у\u{306}",
+  "Ѵ\u{30F}",
+  "ѵ\u{30F}",
+  "Ж\u{306}",
+  "ж\u{306}",
+  "А\u{306}",
+  "а\u{306}",
+  "А\u{308}",
+  "а\u{308}",
+  "Е\u{306}",
+  "е\u{306}",
+  "Ә\u{308}",
+  "ә\u{308}",
+  "Ж\u{308}",
+  "ж\u{308}",
+  "З\u{308}",
+  "з\u{308}",
+  "И\u{304}",
+  "и\u{304}",
+  "И\u{308}",
+  "и\u{308}",
+  "О\u{308}",
+  "о\u{308}",
+  "Ө\u{308}",
+  "ө\u{308}",
+  "Э\u{308}",
+  "э\u{308}",
+  "У\u{304}",
+  "у\u{304}",
+  "У\u{308}",
+  "у\u{308}",
+  "У\u{30B}",
+  "у\u{30B}",
+  "Ч\u{308}",
+  "ч\u{308}",
+  "Ы\u{308}",
+  "ы\u{308}",
+  "ا\u{653}",
+  "ا\u{654}",
+  "و\u{654}",
+  "ا\u{655}",
+  "ي\u{654}",
+  "ە\u{654}",
+  "ہ\u{654}",
+  "ے\u{654}",
+  "न\u{93C}",
+  "र\u{93C}",
+  "ळ\u{93C}",
+  "क\u{93C}",
+  "ख\u{93C}",
+  "ग\u{93C}",
+  "ज\u{93C}",
+  "ड\u{93C}",
+  "ढ\u{93C}",
+  "फ\u{93C}",
+  "य\u{93C}",
+  "\u{9C7}\u{9BE}",
+  "\u{9C7}\u{9D7}",
+  "ড\u{9BC}",
+  "ঢ\u{9BC}",
+  "য\u{9BC}",
+  "ਲ\u{A3C}",
+  "ਸ\u{A3C}",
+  "ਖ\u{A3C}",
+  "ਗ\u{A3C}",
+  "ਜ\u{A3C}",
+  "ਫ\u{A3C}",
+  "\u{B47}\u{B56}",
+  "\u{B47}\u{B3E}",
+  "\u{B47}\u{B57}",
+  "ଡ\u{B3C}",
+  "ଢ\u{B3C}",
+  "ஒ\u{BD7}",
+  "\u{BC6}\u{BBE}",
+  "\u{BC7}\u{BBE}",
+  "\u{BC6}\u{BD7}",
+  "\u{C46}\u{C56}",
+  "\u{CBF}\u{CD5}",
+  "\u{CC6}\u{CD5}",
+  "\u{CC6}\u{CD6}",
+  "\u{CC6}\u{CC2}",
+  "\u{CC6}\u{CC2}\u{CD5}",
+  "\u{D46}\u{D3E}",
+  "\u{D47}\u{D3E}",
+  "\u{D46}\u{D57}",
+  "\u{DD9}\u{DCA}",
+  "\u{DD9}\u{DCF}",
+  "\u{DD9}\u{DCF}\u{DCA}",
+  "\u{DD9}\u{DDF}",
+  "ག\u{FB7}",
+  "ཌ\u{FB7}",
+  "ད\u{FB7}",
+  "བ\u{FB7}",
+  "ཛ\u{FB7}",
+  "ཀ\u{FB5}",
+  "\u{F71}\u{F72}",
+  "\u{F71}\u{F74}",
+  "\u{FB2}\u{F80}",
+  "\u{FB3}\u{F80}",
+  "\u{F71}\u{F80}",
+  "\u{F92}\u{FB7}",
+  "\u{F9C}\u{FB7}",
+  "\u{FA1}\u{FB7}",
+  "\u{FA6}\u{FB7}",
+  "\u{FAB}\u{FB7}",
+  "\u{F90}\u{FB5}",
+  "ဥ\u{102E}",
+  "ᬅ\u{1B35}",
+  "ᬇ\u{1B35}",
+  "ᬉ\u{1B35}",
+  "ᬋ\u{1B35}",
+  "ᬍ\u{1B35}",
+  "ᬑ\u{1B35}",
+  "\u{1B3A}\u{1B35}",
+  "\u{1B3C}\u{1B35}",
+  "\u{1B3E}\u{1B35}",
+  "\u{1B3F}\u{1B35}",
+  "\u{1B42}\u{1B35}",
+  "A\u{325}",
+  "a\u{325}",
+  "B\u{307}",
+  "b\u{307}",
+  "B\u{323}",
+  "b\u{323}",
+  "B\u{331}",
+  "b\u{331}",
+  "C\u{327}\u{301}",
+  "c\u{327}\u{301}",
+  "D\u{307}",
+  "d\u{307}",
+  "D\u{323}",
+  "d\u{323}",
+  "D\u{331}",
+  "d\u{331}",
+  "D\u{327}",
+  "d\u{327}",
+  "D\u{32D}",
+  "d\u{32D}",
+  "E\u{304}\u{300}",
+  "e\u{304}\u{300}",
+  "E\u{304}\u{301}",
+  "e\u{304}\u{301}",
+  "E\u{32D}",
+  "e\u{32D}",
+  "E\u{330}",
+  "e\u{330}",
+  "E\u{327}\u{306}",
+  "e\u{327}\u{306}",
+  "F\u{307}",
+  "f\u{307}",
+  "G\u{304}",
+  "g\u{304}",
+  "H\u{307}",
+  "h\u{307}",
+  "H\u{323}",
+  "h\u{323}",
+  "H\u{308}",
+  "h\u{308}",
+  "H\u{327}",
+  "h\u{327}",
+  "H\u{32E}",
+  "h\u{32E}",
+  "I\u{330}",
+  "i\u{330}",
+  "I\u{308}\u{301}",
+  "i\u{308}\u{301}",
+  "K\u{301}",
+  "k\u{301}",
+  "K\u{323}",
+  "k\u{323}",
+  "K\u{331}",
+  "k\u{331}",
+  "L\u{323}",
+  "l\u{323}",
+  "L\u{323}\u{304}",
+  "l\u{323}\u{304}",
+  "L\u{331}",
+  "l\u{331}",
+  "L\u{32D}",
+  "l\u{32D}",
+  "M\u{301}",
+  "m\u{301}",
+  "M\u{307}",
+  "m\u{307}",
+  "M\u{323}",
+  "m\u{323}",
+  "N\u{307}",
+  "n\u{307}",
+  "N\u{323}",
+  "n\u{323}",
+  "N\u{331}",
+  "n\u{331}",
+  "N\u{32D}",
+  "n\u{32D}",
+  "O\u{303}\u{301}",
+  "o\u{303}\u{301}",
+  "O\u{303}\u{308}",
+  "o\u{303}\u{308}",
+  "O\u{304}\u{300}",
+  "o\u{304}\u{300}",
+  "O\u{304}\u{301}",
+  "o\u{304}\u{301}",
+  "P\u{301}",
+  "p\u{301}",
+  "P\u{307}",
+  "p\u{307}",
+  "R\u{307}",
+  "r\u{307}",
+  "R\u{323}",
+  "r\u{323}",
+  "R\u{323}\u{304}",
+  "r\u{323}\u{304}",
+  "R\u{331}",
+  "r\u{331}",
+  "S\u{307}",
+  "s\u{307}",
+  "S\u{323}",
+  "s\u{323}",
+  "S\u{301}\u{307}",
+  "s\u{301}\u{307}",
+  "S\u{30C}\u{307}",
+  "s\u{30C}\u{307}",
+  "S\u{323}\u{307}",
+  "s\u{323}\u{307}",
+  "T\u{307}",
+  "t\u{307}",
+  "T\u{323}",
+  "t\u{323}",
+  "T\u{331}",
+  "t\u{331}",
+  "T\u{32D}",
+  "t\u{32D}",
+  "U\u{324}",
+  "u\u{324}",
+  "U\u{330}",
+  "u\u{330}",
+  "U\u{32D}",
+  "u\u{32D}",
+  "U\u{303}\u{301}",
+  "u\u{303}\u{301}",
+  "U\u{304}\u{308}",
+  "u\u{304}\u{308}",
+  "V\u{303}",
+  "v\u{303}",
+  "V\u{323}",
+  "v\u{323}",
+  "W\u{300}",
+  "w\u{300}",
+  "W\u{301}",
+  "w\u{301}",
+  "W\u{308}",
+  "w\u{308}",
+  "W\u{307}",
+  "w\u{307}",
+  "W\u{323}",
+  "w\u{323}",
+  "X\u{307}",
+  "x\u{307}",
+  "X\u{308}",
+  "x\u{308}",
+  "Y\u{307}",
+  "y\u{307}",
+  "Z\u{302}",
+  "z\u{302}",
+  "Z\u{323}",
+  "z\u{323}",
+  "Z\u{331}",
+  "z\u{331}",
+  "h\u{331}",
+  "t\u{308}",
+  "w\u{30A}",
+  "y\u{30A}",
+  "ſ\u{307}",
+  "A\u{323}",
+  "a\u{323}",
+  "A\u{309}",
+  "a\u{309}",
+  "A\u{302}\u{301}",
+  "a\u{302}\u{301}",
+  "A\u{302}\u{300}",
+  "a\u{302}\u{300}",
+  "A\u{302}\u{309}",
+  "a\u{302}\u{309}",
+  "A\u{302}\u{303}",
+  "a\u{302}\u{303}",
+  "A\u{323}\u{302}",
+  "a\u{323}\u{302}",
+  "A\u{306}\u{301}",
+  "a\u{306}\u{301}",
+  "A\u{306}\u{300}",
+  "a\u{306}\u{300}",
+  "A\u{306}\u{309}",
+  "a\u{306}\u{309}",
+  "A\u{306}\u{303}",
+  "a\u{306}\u{303}",
+  "A\u{323}\u{306}",
+  "a\u{323}\u{306}",
+  "E\u{323}",
+  "e\u{323}",
+  "E\u{309}",
+  "e\u{309}",
+  "E\u{303}",
+  "e\u{303}",
+  "E\u{302}\u{301}",
+  "e\u{302}\u{301}",
+  "E\u{302}\u{300}",
+  "e\u{302}\u{300}",
+  "E\u{302}\u{309}",
+  "e\u{302}\u{309}",
+  "E\u{302}\u{303}",
+  "e\u{302}\u{303}",
+  "E\u{323}\u{302}",
+  "e\u{323}\u{302}",
+  "I\u{309}",
+  "i\u{309}",
+  "I\u{323}",
+  "i\u{323}",
+  "O\u{323}",
+  "o\u{323}",
+  "O\u{309}",
+  "o\u{309}",
+  "O\u{302}\u{301}",
+  "o\u{302}\u{301}",
+  "O\u{302}\u{300}",
+  "o\u{302}\u{300}",
+  "O\u{302}\u{309}",
+  "o\u{302}\u{309}",
+  "O\u{302}\u{303}",
+  "o\u{302}\u{303}",
+  "O\u{323}\u{302}",
+  "o\u{323}\u{302}",
+  "O\u{31B}\u{301}",
+  "o\u{31B}\u{301}",
+  "O\u{31B}\u{300}",
+  "o\u{31B}\u{300}",
+  "O\u{31B}\u{309}",
+  "o\u{31B}\u{309}",
+  "O\u{31B}\u{303}",
+  "o\u{31B}\u{303}",
+  "O\u{31B}\u{323}",
+  "o\u{31B}\u{323}",
+  "U\u{323}",
+  "u\u{323}",
+  "U\u{309}",
+  "u\u{309}",
+  "U\u{31B}\u{301}",
+  "u\u{31B}\u{301}",
+  "U\u{31B}\u{300}",
+  "u\u{31B}\u{300}",
+  "U\u{31B}\u{309}",
+  "u\u{31B}\u{309}",
+  "U\u{31B}\u{303}",
+  "u\u{31B}\u{303}",
+  "U\u{31B}\u{323}",
+  "u\u{31B}\u{323}",
+  "Y\u{300}",
+  "y\u{300}",
+  "Y\u{323}",
+  "y\u{323}",
+  "Y\u{309}",
+  "y\u{309}",
+  "Y\u{303}",
+  "y\u{303}",
+  "α\u{313}",
+  "α\u{314}",
+  "α\u{313}\u{300}",
+  "α\u{314}\u{300}",
+  "α\u{313}\u{301}",
+  "α\u{314}\u{301}",
+  "α\u{313}\u{342}",
+  "α\u{314}\u{342}",
+  "Α\u{313}",
+  "Α\u{314}",
+  "Α\u{313}\u{300}",
+  "Α\u{314}\u{300}",
+  "Α\u{313}\u{301}",
+  "Α\u{314}\u{301}",
+  "Α\u{313}\u{342}",
+  "Α\u{314}\u{342}",
+  "ε\u{313}",
+  "ε\u{314}",
+  "ε\u{313}\u{300}",
+  "ε\u{314}\u{300}",
+  "ε\u{313}\u{301}",
+  "ε\u{314}\u{301}",
+  "Ε\u{313}",
+  "Ε\u{314}",
+  "Ε\u{313}\u{300}",
+  "Ε\u{314}\u{300}",
+  "Ε\u{313}\u{301}",
+  "Ε\u{314}\u{301}",
+  "η\u{313}",
+  "η\u{314}",
+  "η\u{313}\u{300}",
+  "η\u{314}\u{300}",
+  "η\u{313}\u{301}",
+  "η\u{314}\u{301}",
+  "η\u{313}\u{342}",
+  "η\u{314}\u{342}",
+  "Η\u{313}",
+  "Η\u{314}",
+  "Η\u{313}\u{300}",
+  "Η\u{314}\u{300}",
+  "Η\u{313}\u{301}",
+  "Η\u{314}\u{301}",
+  "Η\u{313}\u{342}",
+  "Η\u{314}\u{342}",
+  "ι\u{313}",
+  "ι\u{314}",
+  "ι\u{313}\u{300}",
+  "ι\u{314}\u{300}",
+  "ι\u{313}\u{301}",
+  "ι\u{314}\u{301}",
+  "ι\u{313}\u{342}",
+  "ι\u{314}\u{342}",
+  "Ι\u{313}",
+  "Ι\u{314}",
+  "Ι\u{313}\u{300}",
+  "Ι\u{314}\u{300}",
+  "Ι\u{313}\u{301}",
+  "Ι\u{314}\u{301}",
+  "Ι\u{313}\u{342}",
+  "Ι\u{314}\u{342}",
+  "ο\u{313}",
+  "ο\u{314}",
+  "ο\u{313}\u{300}",
+  "ο\u{314}\u{300}",
+  "ο\u{313}\u{301}",
+  "ο\u{314}\u{301}",
+  "Ο\u{313}",
+  "Ο\u{314}",
+  "Ο\u{313}\u{300}",
+  "Ο\u{314}\u{300}",
+  "Ο\u{313}\u{301}",
+  "Ο\u{314}\u{301}",
+  "υ\u{313}",
+  "υ\u{314}",
+  "υ\u{313}\u{300}",
+  "υ\u{314}\u{300}",
+  "υ\u{313}\u{301}",
+  "υ\u{314}\u{301}",
+  "υ\u{313}\u{342}",
+  "υ\u{314}\u{342}",
+  "Υ\u{314}",
+  "Υ\u{314}\u{300}",
+  "Υ\u{314}\u{301}",
+  "Υ\u{314}\u{342}",
+  "ω\u{313}",
+  "ω\u{314}",
+  "ω\u{313}\u{300}",
+  "ω\u{314}\u{300}",
+  "ω\u{313}\u{301}",
+  "ω\u{314}\u{301}",
+  "ω\u{313}\u{342}",
+  "ω\u{314}\u{342}",
+  "Ω\u{313}",
+  "Ω\u{314}",
+  "Ω\u{313}\u{300}",
+  "Ω\u{314}\u{300}",
+  "Ω\u{313}\u{301}",
+  "Ω\u{314}\u{301}",
+  "Ω\u{313}\u{342}",
+  "Ω\u{314}\u{342}",
+  "α\u{300}",
+  "α\u{301}",
+  "ε\u{300}",
+  "ε\u{301}",
+  "η\u{300}",
+  "η\u{301}",
+  "ι\u{300}",
+  "ι\u{301}",
+  "ο\u{300}",
+  "ο\u{301}",
+  "υ\u{300}",
+  "υ\u{301}",
+  "ω\u{300}",
+  "ω\u{301}",
+  "α\u{313}\u{345}",
+  "α\u{314}\u{345}",
+  "α\u{313}\u{300}\u{345}",
+  "α\u{314}\u{300}\u{345}",
+  "α\u{313}\u{301}\u{345}",
+  "α\u{314}\u{301}\u{345}",
+  "α\u{313}\u{342}\u{345}",
+  "α\u{314}\u{342}\u{345}",
+  "Α\u{313}\u{345}",
+  "Α\u{314}\u{345}",
+  "Α\u{313}\u{300}\u{345}",
+  "Α\u{314}\u{300}\u{345}",
+  "Α\u{313}\u{301}\u{345}",
+  "Α\u{314}\u{301}\u{345}",
+  "Α\u{313}\u{342}\u{345}",
+  "Α\u{314}\u{342}\u{345}",
+  "η\u{313}\u{345}",
+  "η\u{314}\u{345}",
+  "η\u{313}\u{300}\u{345}",
+  "η\u{314}\u{300}\u{345}",
+  "η\u{313}\u{301}\u{345}",
+  "η\u{314}\u{301}\u{345}",
+  "η\u{313}\u{342}\u{345}",
+  "η\u{314}\u{342}\u{345}",
+  "Η\u{313}\u{345}",
+  "Η\u{314}\u{345}",
+  "Η\u{313}\u{300}\u{345}",
+  "Η\u{314}\u{300}\u{345}",
+  "Η\u{313}\u{301}\u{345}",
+  "Η\u{314}\u{301}\u{345}",
+  "Η\u{313}\u{342}\u{345}",
+  "Η\u{314}\u{342}\u{345}",
+  "ω\u{313}\u{345}",
+  "ω\u{314}\u{345}",
+  "ω\u{313}\u{300}\u{345}",
+  "ω\u{314}\u{300}\u{345}",
+  "ω\u{313}\u{301}\u{345}",
+  "ω\u{314}\u{301}\u{345}",
+  "ω\u{313}\u{342}\u{345}",
+  "ω\u{314}\u{342}\u{345}",
+  "Ω\u{313}\u{345}",
+  "Ω\u{314}\u{345}",
+  "Ω\u{313}\u{300}\u{345}",
+  "Ω\u{314}\u{300}\u{345}",
+  "Ω\u{313}\u{301}\u{345}",
+  "Ω\u{314}\u{301}\u{345}",
+  "Ω\u{313}\u{342}\u{345}",
+  "Ω\u{314}\u{342}\u{345}",
+  "α\u{306}",
+  "α\u{304}",
+  "α\u{300}\u{345}",
+  "α\u{345}",
+  "α\u{301}\u{345}",
+  "α\u{342}",
+  "α\u{342}\u{345}",
+  "Α\u{306}",
+  "Α\u{304}",
+  "Α\u{300}",
+  "Α\u{301}",
+  "Α\u{345}",
+  "ι",
+  "¨\u{342}",
+  "η\u{300}\u{345}",
+  "η\u{345}",
+  "η\u{301}\u{345}",
+  "η\u{342}",
+  "η\u{342}\u{345}",
+  "Ε\u{300}",
+  "Ε\u{301}",
+  "Η\u{300}",
+  "Η\u{301}",
+  "Η\u{345}",
+  "᾿\u{300}",
+  "᾿\u{301}",
+  "᾿\u{342}",
+  "ι\u{306}",
+  "ι\u{304}",
+  "ι\u{308}\u{300}",
+  "ι\u{308}\u{301}",
+  "ι\u{342}",
+  "ι\u{308}\u{342}",
+  "Ι\u{306}",
+  "Ι\u{304}",
+  "Ι\u{300}",
+  "Ι\u{301}",
+  "῾\u{300}",
+  "῾\u{301}",
+  "῾\u{342}",
+  "υ\u{306}",
+  "υ\u{304}",
+  "υ\u{308}\u{300}",
+  "υ\u{308}\u{301}",
+  "ρ\u{313}",
+  "ρ\u{314}",
+  "υ\u{342}",
+  "υ\u{308}\u{342}",
+  "Υ\u{306}",
+  "Υ\u{304}",
+  "Υ\u{300}",
+  "Υ\u{301}",
+  "Ρ\u{314}",
+  "¨\u{300}",
+  "¨\u{301}",
+  "`",
+  "ω\u{300}\u{345}",
+  "ω\u{345}",
+  "ω\u{301}\u{345}",
+  "ω\u{342}",
+  "ω\u{342}\u{345}",
+  "Ο\u{300}",
+  "Ο\u{301}",
+  "Ω\u{300}",
+  "Ω\u{301}",
+  "Ω\u{345}",
+  "´",
+  "\u{2002}",
+  "\u{2003}",
+  "Ω",
+  "K",
+  "A\u{30A}",
+  "←\u{338}",
+  "→\u{338}",
+  "↔\u{338}",
+  "⇐\u{338}",
+  "⇔\u{338}",
+  "⇒\u{338}",
+  "∃\u{338}",
+  "∈\u{338}",
+  "∋\u{338}",
+  "∣\u{338}",
+  "∥\u{338}",
+  "∼\u{338}",
+  "≃\u{338}",
+  "≅\u{338}",
+  "≈\u{338}",
+  "=\u{338}",
+  "≡\u{338}",
+  "≍\u{338}",
+  "<\u{338}",
+  ">\u{338}",
+  "≤\u{338}",
+  "≥\u{338}",
+  "≲\u{338}",
+  "≳\u{338}",
+  "≶\u{338}",
+  "≷\u{338}",
+  "≺\u{338}",
+  "≻\u{338}",
+  "⊂\u{338}",
+  "⊃\u{338}",
+  "⊆\u{338}",
+  "⊇\u{338}",
+  "⊢\u{338}",
+  "⊨\u{338}",
+  "⊩\u{338}",
+  "⊫\u{338}",
+  "≼\u{338}",
+  "≽\u{338}",
+  "⊑\u{338}",
+  "⊒\u{338}",
+  "⊲\u{338}",
+  "⊳\u{338}",
+  "⊴\u{338}",
+  "⊵\u{338}",
+  "〈",
+  "〉",
+  "⫝\u{338}",
+  "か\u{3099}",
+  "き\u{3099}",
+  "く\u{3099}",
+  "け\u{3099}",
+  "こ\u{3099}",
+  "さ\u{3099}",
+  "し\u{3099}",
+  "す\u{3099}",
+  "せ\u{3099}",
+  "そ\u{3099}",
+  "た\u{3099}",
+  "ち\u{3099}",
+  "つ\u{3099}",
+  "て\u{3099}",
+  "と\u{3099}",
+  "は\u{3099}",
+  "は\u{309A}",
+  "ひ\u{3099}",
+  "ひ\u{309A}",
+  "ふ\u{3099}",
+  "ふ\u{309A}",
+  "へ\u{3099}",
+  "へ\u{309A}",
+  "ほ\u{3099}",
+  "ほ\u{309A}",
+  "う\u{3099}",
+  "ゝ\u{3099}",
+  "カ\u{3099}",
+  "キ\u{3099}",
+  "ク\u{3099}",
+  "ケ\u{3099}",
+  "コ\u{3099}",
+  "サ\u{3099}",
+  "シ\u{3099}",
+  "ス\u{3099}",
+  "セ\u{3099}",
+  "ソ\u{3099}",
+  "タ\u{3099}",
+  "チ\u{3099}",
+  "ツ\u{3099}",
+  "テ\u{3099}",
+  "ト\u{3099}",
+  "ハ\u{3099}",
+  "ハ\u{309A}",
+  "ヒ\u{3099}",
+  "ヒ\u{309A}",
+  "フ\u{3099}",
+  "フ\u{309A}",
+  "ヘ\u{3099}",
+  "ヘ\u{309A}",
+  "ホ\u{3099}",
+  "ホ\u{309A}",
+  "ウ\u{3099}",
+  "ワ\u{3099}",
+  "ヰ\u{3099}",
+  "ヱ\u{3099}",
+  "ヲ\u{3099}",
+  "ヽ\u{3099}",
+  "豈",
+  "更",
+  "車",
+  "賈",
+  "滑",
+  "串",
+  "句",
+  "龜",
+  "龜",
+  "契",
+  "金",
+  "喇",
+  "奈",
+  "懶",
+  "癩",
+  "羅",
+  "蘿",
+  "螺",
+  "裸",
+  "邏",
+  "樂",
+  "洛",
+  "烙",
+  "珞",
+  "落",
+  "酪",
+  "駱",
+  "亂",
+  "卵",
+  "欄",
+  "爛",
+  "蘭",
+  "鸞",
+  "嵐",
+  "濫",
+  "藍",
+  "襤",
+  "拉",
+  "臘",
+  "蠟",
+  "廊",
+  "朗",
+  "浪",
+  "狼",
+  "郎",
+  "來",
+  "冷",
+  "勞",
+  "擄",
+  "櫓",
+  "爐",
+  "盧",
+  "老",
+  "蘆",
+  "虜",
+  "路",
+  "露",
+  "魯",
+  "鷺",
+  "碌",
+  "祿",
+  "綠",
+  "菉",
+  "錄",
+  "鹿",
+  "論",
+  "壟",
+  "弄",
+  "籠",
+  "聾",
+  "牢",
+  "磊",
+  "賂",
+  "雷",
+  "壘",
+  "屢",
+  "樓",
+  "淚",
+  "漏",
+  "累",
+  "縷",
+  "陋",
+  "勒",
+  "肋",
+  "凜",
+  "凌",
+  "稜",
+  "綾",
+  "菱",
+  "陵",
+  "讀",
+  "拏",
+  "樂",
+  "諾",
+  "丹",
+  "寧",
+  "怒",
+  "率",
+  "異",
+  "北",
+  "磻",
+  "便",
+  "復",
+  "不",
+  "泌",
+  "數",
+  "索",
+  "參",
+  "塞",
+  "省",
+  "葉",
+  "說",
+  "殺",
+  "辰",
+  "沈",
+  "拾",
+  "若",
+  "掠",
+  "略",
+  "亮",
+  "兩",
+  "凉",
+  "梁",
+  "糧",
+  "良",
+  "諒",
+  "量",
+  "勵",
+  "呂",
+  "女",
+  "廬",
+  "旅",
+  "濾",
+  "礪",
+  "閭",
+  "驪",
+  "麗",
+  "黎",
+  "力",
+  "曆",
+  "歷",
+  "轢",
+  "年",
+  "憐",
+  "戀",
+  "撚",
+  "漣",
+  "煉",
+  "璉",
+  "秊",
+  "練",
+  "聯",
+  "輦",
+  "蓮",
+  "連",
+  "鍊",
+  "列",
+  "劣",
+  "咽",
+  "烈",
+  "裂",
+  "說",
+  "廉",
+  "念",
+  "捻",
+  "殮",
+  "簾",
+  "獵",
+  "令",
+  "囹",
+  "寧",
+  "嶺",
+  "怜",
+  "玲",
+  "瑩",
+  "羚",
+  "聆",
+  "鈴",
+  "零",
+  "靈",
+  "領",
+  "例",
+  "禮",
+  "醴",
+  "隸",
+  "惡",
+  "了",
+  "僚",
+  "寮",
+  "尿",
+  "料",
+  "樂",
+  "燎",
+  "療",
+  "蓼",
+  "遼",
+  "龍",
+  "暈",
+  "阮",
+  "劉",
+  "杻",
+  "柳",
+  "流",
+  "溜",
+  "琉",
+  "留",
+  "硫",
+  "紐",
+  "類",
+  "六",
+  "戮",
+  "陸",
+  "倫",
+  "崙",
+  "淪",
+  "輪",
+  "律",
+  "慄",
+  "栗",
+  "率",
+  "隆",
+  "利",
+  "吏",
+  "履",
+  "易",
+  "李",
+  "梨",
+  "泥",
+  "理",
+  "痢",
+  "罹",
+  "裏",
+  "裡",
+  "里",
+  "離",
+  "匿",
+  "溺",
+  "吝",
+  "燐",
+  "璘",
+  "藺",
+  "隣",
+  "鱗",
+  "麟",
+  "林",
+  "淋",
+  "臨",
+  "立",
+  "笠",
+  "粒",
+  "狀",
+  "炙",
+  "識",
+  "什",
+  "茶",
+  "刺",
+  "切",
+  "度",
+  "拓",
+  "糖",
+  "宅",
+  "洞",
+  "暴",
+  "輻",
+  "行",
+  "降",
+  "見",
+  "廓",
+  "兀",
+  "嗀",
+  "塚",
+  "晴",
+  "凞",
+  "猪",
+  "益",
+  "礼",
+  "神",
+  "祥",
+  "福",
+  "靖",
+  "精",
+  "羽",
+  "蘒",
+  "諸",
+  "逸",
+  "都",
+  "飯",
+  "飼",
+  "館",
+  "鶴",
+  "郞",
+  "隷",
+  "侮",
+  "僧",
+  "免",
+  "勉",
+  "勤",
+  "卑",
+  "喝",
+  "嘆",
+  "器",
+  "塀",
+  "墨",
+  "層",
+  "屮",
+  "悔",
+  "慨",
+  "憎",
+  "懲",
+  "敏",
+  "既",
+  "暑",
+  "梅",
+  "海",
+  "渚",
+  "漢",
+  "煮",
+  "爫",
+  "琢",
+  "碑",
+  "社",
+  "祉",
+  "祈",
+  "祐",
+  "祖",
+  "祝",
+  "禍",
+  "禎",
+  "穀",
+  "突",
+  "節",
+  "練",
+  "縉",
+  "繁",
+  "署",
+  "者",
+  "臭",
+  "艹",
+  "艹",
+  "著",
+  "褐",
+  "視",
+  "謁",
+  "謹",
+  "賓",
+  "贈",
+  "辶",
+  "逸",
+  "難",
+  "響",
+  "頻",
+  "恵",
+  "𤋮",
+  "舘",
+  "並",
+  "况",
+  "全",
+  "侀",
+  "充",
+  "冀",
+  "勇",
+  "勺",
+  "喝",
+  "啕",
+  "喙",
+  "嗢",
+  "塚",
+  "墳",
+  "奄",
+  "奔",
+  "婢",
+  "嬨",
+  "廒",
+  "廙",
+  "彩",
+  "徭",
+  "惘",
+  "慎",
+  "愈",
+  "憎",
+  "慠",
+  "懲",
+  "戴",
+  "揄",
+  "搜",
+  "摒",
+  "敖",
+  "晴",
+  "朗",
+  "望",
+  "杖",
+  "歹",
+  "殺",
+  "流",
+  "滛",
+  "滋",
+  "漢",
+  "瀞",
+  "煮",
+  "瞧",
+  "爵",
+  "犯",
+  "猪",
+  "瑱",
+  "甆",
+  "画",
+  "瘝",
+  "瘟",
+  "益",
+  "盛",
+  "直",
+  "睊",
+  "着",
+  "磌",
+  "窱",
+  "節",
+  "类",
+  "絛",
+  "練",
+  "缾",
+  "者",
+  "荒",
+  "華",
+  "蝹",
+  "襁",
+  "覆",
+  "視",
+  "調",
+  "諸",
+  "請",
+  "謁",
+  "諾",
+  "諭",
+  "謹",
+  "變",
+  "贈",
+  "輸",
+  "遲",
+  "醙",
+  "鉶",
+  "陼",
+  "難",
+  "靖",
+  "韛",
+  "響",
+  "頋",
+  "頻",
+  "鬒",
+  "龜",
+  "𢡊",
+  "𢡄",
+  "𣏕",
+  "㮝",
+  "䀘",
+  "䀹",
+  "𥉉",
+  "𥳐",
+  "𧻓",
+  "齃",
+  "龎",
+  "י\u{5B4}",
+  "ײ\u{5B7}",
+  "ש\u{5C1}",
+  "ש\u{5C2}",
+  "ש\u{5BC}\u{5C1}",
+  "ש\u{5BC}\u{5C2}",
+  "א\u{5B7}",
+  "א\u{5B8}",
+  "א\u{5BC}",
+  "ב\u{5BC}",
+  "ג\u{5BC}",
+  "ד\u{5BC}",
+  "ה\u{5BC}",
+  "ו\u{5BC}",
+  "ז\u{5BC}",
+  "ט\u{5BC}",
+  "י\u{5BC}",
+  "ך\u{5BC}",
+  "כ\u{5BC}",
+  "ל\u{5BC}",
+  "מ\u{5BC}",
+  "נ\u{5BC}",
+  "ס\u{5BC}",
+  "ף\u{5BC}",
+  "פ\u{5BC}",
+  "צ\u{5BC}",
+  "ק\u{5BC}",
+  "ר\u{5BC}",
+  "ש\u{5BC}",
+  "ת\u{5BC}",
+  "ו\u{5B9}",
+  "ב\u{5BF}",
+  "כ\u{5BF}",
+  "פ\u{5BF}",
+  "𑂙\u{110BA}",
+  "𑂛\u{110BA}",
+  "𑂥\u{110BA}",
+  "\u{11131}\u{11127}",
+  "\u{11132}\u{11127}",
+  "\u{11347}\u{1133E}",
+  "\u{11347}\u{11357}",
+  "\u{114B9}\u{114BA}",
+  "\u{114B9}\u{114B0}",
+  "\u{114B9}\u{114BD}",
+  "\u{115B8}\u{115AF}",
+  "\u{115B9}\u{115AF}",
+  "\u{11935}\u{11930}",
+  "𝅗\u{1D165}",
+  "𝅘\u{1D165}",
+  "𝅘\u{1D165}\u{1D16E}",
+  "𝅘\u{1D165}\u{1D16F}",
+  "𝅘\u{1D165}\u{1D170}",
+  "𝅘\u{1D165}\u{1D171}",
+  "𝅘\u{1D165}\u{1D172}",
+  "𝆹\u{1D165}",
+  "𝆺\u{1D165}",
+  "𝆹\u{1D165}\u{1D16E}",
+  "𝆺\u{1D165}\u{1D16E}",
+  "𝆹\u{1D165}\u{1D16F}",
+  "𝆺\u{1D165}\u{1D16F}",
+  "丽",
+  "丸",
+  "乁",
+  "𠄢",
+  "你",
+  "侮",
+  "侻",
+  "倂",
+  "偺",
+  "備",
+  "僧",
+  "像",
+  "㒞",
+  "𠘺",
+  "免",
+  "兔",
+  "兤",
+  "具",
+  "𠔜",
+  "㒹",
+  "內",
+  "再",
+  "𠕋",
+  "冗",
+  "冤",
+  "仌",
+  "冬",
+  "况",
+  "𩇟",
+  "凵",
+  "刃",
+  "㓟",
+  "刻",
+  "剆",
+  "割",
+  "剷",
+  "㔕",
+  "勇",
+  "勉",
+  "勤",
+  "勺",
+  "包",
+  "匆",
+  "北",
+  "卉",
+  "卑",
+  "博",
+  "即",
+  "卽",
+  "卿",
+  "卿",
+  "卿",
+  "𠨬",
+  "灰",
+  "及",
+  "叟",
+  "𠭣",
+  "叫",
+  "叱",
+  "吆",
+  "咞",
+  "吸",
+  "呈",
+  "周",
+  "咢",
+  "哶",
+  "唐",
+  "啓",
+  "啣",
+  "善",
+  "善",
+  "喙",
+  "喫",
+  "喳",
+  "嗂",
+  "圖",
+  "嘆",
+  "圗",
+  "噑",
+  "噴",
+  "切",
+  "壮",
+  "城",
+  "埴",
+  "堍",
+  "型",
+  "堲",
+  "報",
+  "墬",
+  "𡓤",
+  "売",
+  "壷",
+  "夆",
+  "多",
+  "夢",
+  "奢",
+  "𡚨",
+  "𡛪",
+  "姬",
+  "娛",
+  "娧",
+  "姘",
+  "婦",
+  "㛮",
+  "㛼",
+  "嬈",
+  "嬾",
+  "嬾",
+  "𡧈",
+  "寃",
+  "寘",
+  "寧",
+  "寳",
+  "𡬘",
+  "寿",
+  "将",
+  "当",
+  "尢",
+  "㞁",
+  "屠",
+  "屮",
+  "峀",
+  "岍",
+  "𡷤",
+  "嵃",
+  "𡷦",
+  "嵮",
+  "嵫",
+  "嵼",
+  "巡",
+  "巢",
+  "㠯",
+  "巽",
+  "帨",
+  "帽",
+  "幩",
+  "㡢",
+  "𢆃",
+  "㡼",
+  "庰",
+  "庳",
+  "庶",
+  "廊",
+  "𪎒",
+  "廾",
+  "𢌱",
+  "𢌱",
+  "舁",
+  "弢",
+  "弢",
+  "㣇",
+  "𣊸",
+  "𦇚",
+  "形",
+  "彫",
+  "㣣",
+  "徚",
+  "忍",
+  "志",
+  "忹",
+  "悁",
+  "㤺",
+  "㤜",
+  "悔",
+  "𢛔",
+  "惇",
+  "慈",
+  "慌",
+  "慎",
+  "慌",
+  "慺",
+  "憎",
+  "憲",
+  "憤",
+  "憯",
+  "懞",
+  "懲",
+  "懶",
+  "成",
+  "戛",
+  "扝",
+  "抱",
+  "拔",
+  "捐",
+  "𢬌",
+  "挽",
+  "拼",
+  "捨",
+  "掃",
+  "揤",
+  "𢯱",
+  "搢",
+  "揅",
+  "掩",
+  "㨮",
+  "摩",
+  "摾",
+  "撝",
+  "摷",
+  "㩬",
+  "敏",
+  "敬",
+  "𣀊",
+  "旣",
+  "書",
+  "晉",
+  "㬙",
+  "暑",
+  "㬈",
+  "㫤",
+  "冒",
+  "冕",
+  "最",
+  "暜",
+  "肭",
+  "䏙",
+  "朗",
+  "望",
+  "朡",
+  "杞",
+  "杓",
+  "𣏃",
+  "㭉",
+  "柺",
+  "枅",
+  "桒",
+  "梅",
+  "𣑭",
+  "梎",
+  "栟",
+  "椔",
+  "㮝",
+  "楂",
+  "榣",
+  "槪",
+  "檨",
+  "𣚣",
+  "櫛",
+  "㰘",
+  "次",
+  "𣢧",
+  "歔",
+  "㱎",
+  "歲",
+  "殟",
+  "殺",
+  "殻",
+  "𣪍",
+  "𡴋",
+  "𣫺",
+  "汎",
+  "𣲼",
+  "沿",
+  "泍",
+  "汧",
+  "洖",
+  "派",
+  "海",
+  "流",
+  "浩",
+  "浸",
+  "涅",
+  "𣴞",
+  "洴",
+  "港",
+  "湮",
+  "㴳",
+  "滋",
+  "滇",
+  "𣻑",
+  "淹",
+  "潮",
+  "𣽞",
+  "𣾎",
+  "濆",
+  "瀹",
+  "瀞",
+  "瀛",
+  "㶖",
+  "灊",
+  "災",
+  "灷",
+  "炭",
+  "𠔥",
+  "煅",
+  "𤉣",
+  "熜",
+  "𤎫",
+  "爨",
+  "爵",
+  "牐",
+  "𤘈",
+  "犀",
+  "犕",
+  "𤜵",
+  "𤠔",
+  "獺",
+  "王",
+  "㺬",
+  "玥",
+  "㺸",
+  "㺸",
+  "瑇",
+  "瑜",
+  "瑱",
+  "璅",
+  "瓊",
+  "㼛",
+  "甤",
+  "𤰶",
+  "甾",
+  "𤲒",
+  "異",
+  "𢆟",
+  "瘐",
+  "𤾡",
+  "𤾸",
+  "𥁄",
+  "㿼",
+  "䀈",
+  "直",
+  "𥃳",
+  "𥃲",
+  "𥄙",
+  "𥄳",
+  "眞",
+  "真",
+  "真",
+  "睊",
+  "䀹",
+  "瞋",
+  "䁆",
+  "䂖",
+  "𥐝",
+  "硎",
+  "碌",
+  "磌",
+  "䃣",
+  "𥘦",
+  "祖",
+  "𥚚",
+  "𥛅",
+  "福",
+  "秫",
+  "䄯",
+  "穀",
+  "穊",
+  "穏",
+  "𥥼",
+  "𥪧",
+  "𥪧",
+  "竮",
+  "䈂",
+  "𥮫",
+  "篆",
+  "築",
+  "䈧",
+  "𥲀",
+  "糒",
+  "䊠",
+  "糨",
+  "糣",
+  "紀",
+  "𥾆",
+  "絣",
+  "䌁",
+  "緇",
+  "縂",
+  "繅",
+  "䌴",
+  "𦈨",
+  "𦉇",
+  "䍙",
+  "𦋙",
+  "罺",
+  "𦌾",
+  "羕",
+  "翺",
+  "者",
+  "𦓚",
+  "𦔣",
+  "聠",
+  "𦖨",
+  "聰",
+  "𣍟",
+  "䏕",
+  "育",
+  "脃",
+  "䐋",
+  "脾",
+  "媵",
+  "𦞧",
+  "𦞵",
+  "𣎓",
+  "𣎜",
+  "舁",
+  "舄",
+  "辞",
+  "䑫",
+  "芑",
+  "芋",
+  "芝",
+  "劳",
+  "花",
+  "芳",
+  "芽",
+  "苦",
+  "𦬼",
+  "若",
+  "茝",
+  "荣",
+  "莭",
+  "茣",
+  "莽",
+  "菧",
+  "著",
+  "荓",
+  "菊",
+  "菌",
+  "菜",
+  "𦰶",
+  "𦵫",
+  "𦳕",
+  "䔫",
+  "蓱",
+  "蓳",
+  "蔖",
+  "𧏊",
+  "蕤",
+  "𦼬",
+  "䕝",
+  "䕡",
+  "𦾱",
+  "𧃒",
+  "䕫",
+  "虐",
+  "虜",
+  "虧",
+  "虩",
+  "蚩",
+  "蚈",
+  "蜎",
+  "蛢",
+  "蝹",
+  "蜨",
+  "蝫",
+  "螆",
+  "䗗",
+  "蟡",
+  "蠁",
+  "䗹",
+  "衠",
+  "衣",
+  "𧙧",
+  "裗",
+  "裞",
+  "䘵",
+  "裺",
+  "㒻",
+  "𧢮",
+  "𧥦",
+  "䚾",
+  "䛇",
+  "誠",
+  "諭",
+  "變",
+  "豕",
+  "𧲨",
+  "貫",
+  "賁",
+  "贛",
+  "起",
+  "𧼯",
+  "𠠄",
+  "跋",
+  "趼",
+  "跰",
+  "𠣞",
+  "軔",
+  "輸",
+  "𨗒",
+  "𨗭",
+  "邔",
+  "郱",
+  "鄑",
+  "𨜮",
+  "鄛",
+  "鈸",
+  "鋗",
+  "鋘",
+  "鉼",
+  "鏹",
+  "鐕",
+  "𨯺",
+  "開",
+  "䦕",
+  "閷",
+  "𨵷",
+  "䧦",
+  "雃",
+  "嶲",
+  "霣",
+  "𩅅",
+  "𩈚",
+  "䩮",
+  "䩶",
+  "韠",
+  "𩐊",
+  "䪲",
+  "𩒖",
+  "頋",
+  "頋",
+  "頩",
+  "𩖶",
+  "飢",
+  "䬳",
+  "餩",
+  "馧",
+  "駂",
+  "駾",
+  "䯎",
+  "𩬰",
+  "鬒",
+  "鱀",
+  "鳽",
+  "䳎",
+  "䳭",
+  "鵧",
+  "𪃎",
+  "䳸",
+  "𪄅",
+  "𪈎",
+  "𪊑",
+  "麻",
+  "䵖",
+  "黹",
+  "黾",
+  "鼅",
+  "鼏",
+  "鼖",
+  "鼻",
+  "𪘀",
+);
+
+/// The primary composite of each pair of code points, besides Hangul syllables.
+pub(crate) const COMPOSITION: &[(u32, u32, u32)] = &[
+  (0x003C, 0x0338, 0x226E),
+  (0x003D, 0x0338, 0x2260),
+  (0x003E, 0x0338, 0x226F),
+  (0x0041, 0x0300, 0x00C0),
+  (0x0041, 0x0301, 0x00C1),
+  (0x0041, 0x0302, 0x00C2),
+  (0x0041, 0x0303, 0x00C3),
+  (0x0041, 0x0304, 0x0100),
+  (0x0041, 0x0306, 0x0102),
+  (0x0041, 0x0307, 0x0226),
+  (0x0041, 0x0308, 0x00C4),
+  (0x0041, 0x0309, 0x1EA2),
+  (0x0041, 0x030A, 0x00C5),
+  (0x0041, 0x030C, 0x01CD),
+  (0x0041, 0x030F, 0x0200),
+  (0x0041, 0x0311, 0x0202),
+  (0x0041, 0x0323, 0x1EA0),
+  (0x0041, 0x0325, 0x1E00),
+  (0x0041, 0x0328, 0x0104),
+  (0x0042, 0x0307, 0x1E02),
+  (0x0042, 0x0323, 0x1E04),
+  (0x0042, 0x0331, 0x1E06),
+  (0x0043, 0x0301, 0x0106),
+  (0x0043, 0x0302, 0x0108),
+  (0x0043, 0x0307, 0x010A),
+  (0x0043, 0x030C, 0x010C),
+  (0x0043, 0x0327, 0x00C7),
+  (0x0044, 0x0307, 0x1E0A),
+  (0x0044, 0x030C, 0x010E),
+  (0x0044, 0x0323, 0x1E0C),
+  (0x0044, 0x0327, 0x1E10),
+  (0x0044, 0x032D, 0x1E12),
+  (0x0044, 0x0331, 0x1E0E),
+  (0x0045, 0x0300, 0x00C8),
+  (0x0045, 0x0301, 0x00C9),
+  (0x0045, 0x0302, 0x00CA),
+  (0x0045, 0x0303, 0x1EBC),
+  (0x0045, 0x0304, 0x0112),
+  (0x0045, 0x0306, 0x0114),
+  (0x0045, 0x0307, 0x0116),
+  (0x0045, 0x0308, 0x00CB),
+  (0x0045, 0x0309, 0x1EBA),
+  (0x0045, 0x030C, 0x011A),
+  (0x0045, 0x030F, 0x0204),
+  (0x0045, 0x0311, 0x0206),
+  (0x0045, 0x0323, 0x1EB8),
+  (0x0045, 0x0327, 0x0228),
+  (0x0045, 0x0328, 0x0118),
+  (0x0045, 0x032D, 0x1E18),
+  (0x0045, 0x0330, 0x1E1A),
+  (0x0046, 0x0307, 0x1E1E),
+  (0x0047, 0x0301, 0x01F4),
+  (0x0047, 0x0302, 0x011C),
+  (0x0047, 0x0304, 0x1E20),
+  (0x0047, 0x0306, 0x011E),
+  (0x0047, 0x0307, 0x0120),
+  (0x0047, 0x030C, 0x01E6),
+  (0x0047, 0x0327, 0x0122),
+  (0x0048, 0x0302, 0x0124),
+  (0x0048, 0x0307, 0x1E22),
+  (0x0048, 0x0308, 0x1E26),
+  (0x0048, 0x030C, 0x021E),
+  (0x0048, 0x0323, 0x1E24),
+  (0x0048, 0x0327, 0x1E28),
+  (0x0048, 0x032E, 0x1E2A),
+  (0x0049, 0x0300, 0x00CC),
+  (0x0049, 0x0301, 0x00CD),
+  (0x0049, 0x0302, 0x00CE),
+  (0x0049, 0x0303, 0x0128),
+  (0x0049, 0x0304, 0x012A),
+  (0x0049, 0x0306, 0x012C),
+  (0x0049, 0x0307, 0x0130),
+  (0x0049, 0x0308, 0x00CF),
+  (0x0049, 0x0309, 0x1EC8),
+  (0x0049, 0x030C, 0x01CF),
+  (0x0049, 0x030F, 0x0208),
+  (0x0049, 0x0311, 0x020A),
+  (0x0049, 0x0323, 0x1ECA),
+  (0x0049, 0x0328, 0x012E),
+  (0x0049, 0x0330, 0x1E2C),
+  (0x004A, 0x0302, 0x0134),
+  (0x004B, 0x0301, 0x1E30),
+  (0x004B, 0x030C, 0x01E8),
+  (0x004B, 0x0323, 0x1E32),
+  (0x004B, 0x0327, 0x0136),
+  (0x004B, 0x0331, 0x1E34),
+  (0x004C, 0x0301, 0x0139),
+  (0x004C, 0x030C, 0x013D),
+  (0x004C, 0x0323, 0x1E36),
+  (0x004C, 0x0327, 0x013B),
+  (0x004C, 0x032D, 0x1E3C),
+  (0x004C, 0x0331, 0x1E3A),
+  (0x004D, 0x0301, 0x1E3E),
+  (0x004D, 0x0307, 0x1E40),
+  (0x004D, 0x0323, 0x1E42),
+  (0x004E, 0x0300, 0x01F8),
+  (0x004E, 0x0301, 0x0143),
+  (0x004E, 0x0303, 0x00D1),
+  (0x004E, 0x0307, 0x1E44),
+  (0x004E, 0x030C, 0x0147),
+  (0x004E, 0x0323, 0x1E46),
+  (0x004E, 0x0327, 0x0145),
+  (0x004E, 0x032D, 0x1E4A),
+  (0x004E, 0x0331, 0x1E48),
+  (0x004F, 0x0300, 0x00D2),
+  (0x004F, 0x0301, 0x00D3),
+  (0x004F, 0x0302, 0x00D4),
+  (0x004F, 0x0303, 0x00D5),
+  (0x004F, 0x0304, 0x014C),
+  (0x004F, 0x0306, 0x014E),
+  (0x004F, 0x0307, 0x022E),
+  (0x004F, 0x0308, 0x00D6),
+  (0x004F, 0x0309, 0x1ECE),
+  (0x004F, 0x030B, 0x0150),
+  (0x004F, 0x030C, 0x01D1),
+  (0x004F, 0x030F, 0x020C),
+  (0x004F, 0x0311, 0x020E),
+  (0x004F, 0x031B, 0x01A0),
+  (0x004F, 0x0323, 0x1ECC),
+  (0x004F, 0x0328, 0x01EA),
+  (0x0050, 0x0301, 0x1E54),
+  (0x0050, 0x0307, 0x1E56),
+  (0x0052, 0x0301, 0x0154),
+  (0x0052, 0x0307, 0x1E58),
+  (0x0052, 0x030C, 0x0158),
+  (0x0052, 0x030F, 0x0210),
+  (0x0052, 0x0311, 0x0212),
+  (0x0052, 0x0323, 0x1E5A),
+  (0x0052, 0x0327, 0x0156),
+  (0x0052, 0x0331, 0x1E5E),
+  (0x0053, 0x0301, 0x015A),
+  (0x0053, 0x0302, 0x015C),
+  (0x0053, 0x0307, 0x1E60),
+  (0x0053, 0x030C, 0x0160),
+  (0x0053, 0x0323, 0x1E62),
+  (0x0053, 0x0326, 0x0218),
+  (0x0053, 0x0327, 0x015E),
+  (0x0054, 0x0307, 0x1E6A),
+  (0x0054, 0x030C, 0x0164),
+  (0x0054, 0x0323, 0x1E6C),
+  (0x0054, 0x0326, 0x021A),
+  (0x0054, 0x0327, 0x0162),
+  (0x0054, 0x032D, 0x1E70),
+  (0x0054, 0x0331, 0x1E6E),
+  (0x0055, 0x0300, 0x00D9),
+  (0x0055, 0x0301, 0x00DA),
+  (0x0055, 0x0302, 0x00DB),
+  (0x0055, 0x0303, 0x0168),
+  (0x0055, 0x0304, 0x016A),
+  (0x0055, 0x0306, 0x016C),
+  (0x0055, 0x0308, 0x00DC),
+  (0x0055, 0x0309, 0x1EE6),
+  (0x0055, 0x030A, 0x016E),
+  (0x0055, 0x030B, 0x0170),
+  (0x0055, 0x030C, 0x01D3),
+  (0x0055, 0x030F, 0x0214),
+  (0x0055, 0x0311, 0x0216),
+  (0x0055, 0x031B, 0x01AF),
+  (0x0055, 0x0323, 0x1EE4),
+  (0x0055, 0x0324, 0x1E72),
+  (0x0055, 0x0328, 0x0172),
+  (0x0055, 0x032D, 0x1E76),
+  (0x0055, 0x0330, 0x1E74),
+  (0x0056, 0x0303, 0x1E7C),
+  (0x0056, 0x0323, 0x1E7E),
+  (0x0057, 0x0300, 0x1E80),
+  (0x0057, 0x0301, 0x1E82),
+  (0x0057, 0x0302, 0x0174),
+  (0x0057, 0x0307, 0x1E86),
+  (0x0057, 0x0308, 0x1E84),
+  (0x0057, 0x0323, 0x1E88),
+  (0x0058, 0x0307, 0x1E8A),
+  (0x0058, 0x0308, 0x1E8C),
+  (0x0059, 0x0300, 0x1EF2),
+  (0x0059, 0x0301, 0x00DD),
+  (0x0059, 0x0302, 0x0176),
+  (0x0059, 0x0303, 0x1EF8),
+  (0x0059, 0x0304, 0x0232),
+  (0x0059, 0x0307, 0x1E8E),
+  (0x0059, 0x0308, 0x0178),
+  (0x0059, 0x0309, 0x1EF6),
+  (0x0059, 0x0323, 0x1EF4),
+  (0x005A, 0x0301, 0x0179),
+  (0x005A, 0x0302, 0x1E90),
+  (0x005A, 0x0307, 0x017B),
+  (0x005A, 0x030C, 0x017D),
+  (0x005A, 0x0323, 0x1E92),
+  (0x005A, 0x0331, 0x1E94),
+  (0x0061, 0x0300, 0x00E0),
+  (0x0061, 0x0301, 0x00E1),
+  (0x0061, 0x0302, 0x00E2),
+  (0x0061, 0x0303, 0x00E3),
+  (0x0061, 0x0304, 0x0101),
+  (0x0061, 0x0306, 0x0103),
+  (0x0061, 0x0307, 0x0227),
+  (0x0061, 0x0308, 0x00E4),
+  (0x0061, 0x0309, 0x1EA3),
+  (0x0061, 0x030A, 0x00E5),
+  (0x0061, 0x030C, 0x01CE),
+  (0x0061, 0x030F, 0x0201),
+  (0x0061, 0x0311, 0x0203),
+  (0x0061, 0x0323, 0x1EA1),
+  (0x0061, 0x0325, 0x1E01),
+  (0x0061, 0x0328, 0x0105),
+  (0x0062, 0x0307, 0x1E03),
+  (0x0062, 0x0323, 0x1E05),
+  (0x0062, 0x0331, 0x1E07),
+  (0x0063, 0x0301, 0x0107),
+  (0x0063, 0x0302, 0x0109),
+  (0x0063, 0x0307, 0x010B),
+  (0x0063, 0x030C, 0x010D),
+  (0x0063, 0x0327, 0x00E7),
+  (0x0064, 0x0307, 0x1E0B),
+  (0x0064, 0x030C, 0x010F),
+  (0x0064, 0x0323, 0x1E0D),
+  (0x0064, 0x0327, 0x1E11),
+  (0x0064, 0x032D, 0x1E13),
+  (0x0064, 0x0331, 0x1E0F),
+  (0x0065, 0x0300, 0x00E8),
+  (0x0065, 0x0301, 0x00E9),
+  (0x0065, 0x0302, 0x00EA),
+  (0x0065, 0x0303, 0x1EBD),
+  (0x0065, 0x0304, 0x0113),
+  (0x0065, 0x0306, 0x0115),
+  (0x0065, 0x0307, 0x0117),
+  (0x0065, 0x0308, 0x00EB),
+  (0x0065, 0x0309, 0x1EBB),
+  (0x0065, 0x030C, 0x011B),
+  (0x0065, 0x030F, 0x0205),
+  (0x0065, 0x0311, 0x0207),
+  (0x0065, 0x0323, 0x1EB9),
+  (0x0065, 0x0327, 0x0229),
+  (0x0065, 0x0328, 0x0119),
+  (0x0065, 0x032D, 0x1E19),
+  (0x0065, 0x0330, 0x1E1B),
+  (0x0066, 0x0307, 0x1E1F),
+  (0x0067, 0x0301, 0x01F5),
+  (0x0067, 0x0302, 0x011D),
+  (0x0067, 0x0304, 0x1E21),
+  (0x0067, 0x0306, 0x011F),
+  (0x0067, 0x0307, 0x0121),
+  (0x0067, 0x030C, 0x01E7),
+  (0x0067, 0x0327, 0x0123),
+  (0x0068, 0x0302, 0x0125),
+  (0x0068, 0x0307, 0x1E23),
+  (0x0068, 0x0308, 0x1E27),
+  (0x0068, 0x030C, 0x021F),
+  (0x0068, 0x0323, 0x1E25),
+  (0x0068, 0x0327, 0x1E29),
+  (0x0068, 0x032E, 0x1E2B),
+  (0x0068, 0x0331, 0x1E96),
+  (0x0069, 0x0300, 0x00EC),
+  (0x0069, 0x0301, 0x00ED),
+  (0x0069, 0x0302, 0x00EE),
+  (0x0069, 0x0303, 0x0129),
+  (0x0069, 0x0304, 0x012B),
+  (0x0069, 0x0306, 0x012D),
+  (0x0069, 0x0308, 0x00EF),
+  (0x0069, 0x0309, 0x1EC9),
+  (0x0069, 0x030C, 0x01D0),
+  (0x0069, 0x030F, 0x0209),
+  (0x0069, 0x0311, 0x020B),
+  (0x0069, 0x0323, 0x1ECB),
+  (0x0069, 0x0328, 0x012F),
+  (0x0069, 0x0330, 0x1E2D),
+  (0x006A, 0x0302, 0x0135),
+  (0x006A, 0x030C, 0x01F0),
+  (0x006B, 0x0301, 0x1E31),
+  (0x006B, 0x030C, 0x01E9),
+  (0x006B, 0x0323, 0x1E33),
+  (0x006B, 0x0327, 0x0137),
+  (0x006B, 0x0331, 0x1E35),
+  (0x006C, 0x0301, 0x013A),
+  (0x006C, 0x030C, 0x013E),
+  (0x006C, 0x0323, 0x1E37),
+  (0x006C, 0x0327, 0x013C),
+  (0x006C, 0x032D, 0x1E3D),
+  (0x006C, 0x0331, 0x1E3B),
+  (0x006D, 0x0301, 0x1E3F),
+  (0x006D, 0x0307, 0x1E41),
+  (0x006D, 0x0323, 0x1E43),
+  (0x006E, 0x0300, 0x01F9),
+  (0x006E, 0x0301, 0x0144),
+  (0x006E, 0x0303, 0x00F1),
+  (0x006E, 0x0307, 0x1E45),
+  (0x006E, 0x030C, 0x0148),
+  (0x006E, 0x0323, 0x1E47),
+  (0x006E, 0x0327, 0x0146),
+  (0x006E, 0x032D, 0x1E4B),
+  (0x006E, 0x0331, 0x1E49),
+  (0x006F, 0x0300, 0x00F2),
+  (0x006F, 0x0301, 0x00F3),
+  (0x006F, 0x0302, 0x00F4),
+  (0x006F, 0x0303, 0x00F5),
+  (0x006F, 0x0304, 0x014D),
+  (0x006F, 0x0306, 0x014F),
+  (0x006F, 0x0307, 0x022F),
+  (0x006F, 0x0308, 0x00F6),
+  (0x006F, 0x0309, 0x1ECF),
+  (0x006F, 0x030B, 0x0151),
+  (0x006F, 0x030C, 0x01D2),
+  (0x006F, 0x030F, 0x020D),
+  (0x006F, 0x0311, 0x020F),
+  (0x006F, 0x031B, 0x01A1),
+  (0x006F, 0x0323, 0x1ECD),
+  (0x006F, 0x0328, 0x01EB),
+  (0x0070, 0x0301, 0x1E55),
+  (0x0070, 0x0307, 0x1E57),
+  (0x0072, 0x0301, 0x0155),
+  (0x0072, 0x0307, 0x1E59),
+  (0x0072, 0x030C, 0x0159),
+  (0x0072, 0x030F, 0x0211),
+  (0x0072, 0x0311, 0x0213),
+  (0x0072, 0x0323, 0x1E5B),
+  (0x0072, 0x0327, 0x0157),
+  (0x0072, 0x0331, 0x1E5F),
+  (0x0073, 0x0301, 0x015B),
+  (0x0073, 0x0302, 0x015D),
+  (0x0073, 0x0307, 0x1E61),
+  (0x0073, 0x030C, 0x0161),
+  (0x0073, 0x0323, 0x1E63),
+  (0x0073, 0x0326, 0x0219),
+  (0x0073, 0x0327, 0x015F),
+  (0x0074, 0x0307, 0x1E6B),
+  (0x0074, 0x0308, 0x1E97),
+  (0x0074, 0x030C, 0x0165),
+  (0x0074, 0x0323, 0x1E6D),
+  (0x0074, 0x0326, 0x021B),
+  (0x0074, 0x0327, 0x0163),
+  (0x0074, 0x032D, 0x1E71),
+  (0x0074, 0x0331, 0x1E6F),
+  (0x0075, 0x0300, 0x00F9),
+  (0x0075, 0x0301, 0x00FA),
+  (0x0075, 0x0302, 0x00FB),
+  (0x0075, 0x0303, 0x0169),
+  (0x0075, 0x0304, 0x016B),
+  (0x0075, 0x0306, 0x016D),
+  (0x0075, 0x0308, 0x00FC),
+  (0x0075, 0x0309, 0x1EE7),
+  (0x0075, 0x030A, 0x016F),
+  (0x0075, 0x030B, 0x0171),
+  (0x0075, 0x030C, 0x01D4),
+  (0x0075, 0x030F, 0x0215),
+  (0x0075, 0x0311, 0x0217),
+  (0x0075, 0x031B, 0x01B0),
+  (0x0075, 0x0323, 0x1EE5),
+  (0x0075, 0x0324, 0x1E73),
+  (0x0075, 0x0328, 0x0173),
+  (0x0075, 0x032D, 0x1E77),
+  (0x0075, 0x0330, 0x1E75),
+  (0x0076, 0x0303, 0x1E7D),
+  (0x0076, 0x0323, 0x1E7F),
+  (0x0077, 0x0300, 0x1E81),
+  (0x0077, 0x0301, 0x1E83),
+  (0x0077, 0x0302, 0x0175),
+  (0x0077, 0x0307, 0x1E87),
+  (0x0077, 0x0308, 0x1E85),
+  (0x0077, 0x030A, 0x1E98),
+  (0x0077, 0x0323, 0x1E89),
+  (0x0078, 0x0307, 0x1E8B),
+  (0x0078, 0x0308, 0x1E8D),
+  (0x0079, 0x0300, 0x1EF3),
+  (0x0079, 0x0301, 0x00FD),
+  (0x0079, 0x0302, 0x0177),
+  (0x0079, 0x0303, 0x1EF9),
+  (0x0079, 0x0304, 0x0233),
+  (0x0079, 0x0307, 0x1E8F),
+  (0x0079, 0x0308, 0x00FF),
+  (0x0079, 0x0309, 0x1EF7),
+  (0x0079, 0x030A, 0x1E99),
+  (0x0079, 0x0323, 0x1EF5),
+  (0x007A, 0x0301, 0x017A),
+  (0x007A, 0x0302, 0x1E91),
+  (0x007A, 0x0307, 0x017C),
+  (0x007A, 0x030C, 0x017E),
+  (0x007A, 0x0323, 0x1E93),
+  (0x007A, 0x0331, 0x1E95),
+  (0x00A8, 0x0300, 0x1FED),
+  (0x00A8, 0x0301, 0x0385),
+  (0x00A8, 0x0342, 0x1FC1),
+  (0x00C2, 0x0300, 0x1EA6),
+  (0x00C2, 0x0301, 0x1EA4),
+  (0x00C2, 0x0303, 0x1EAA),
+  (0x00C2, 0x0309, 0x1EA8),
+  (0x00C4, 0x0304, 0x01DE),
+  (0x00C5, 0x0301, 0x01FA),
+  (0x00C6, 0x0301, 0x01FC),
+  (0x00C6, 0x0304, 0x01E2),
+  (0x00C7, 0x0301, 0x1E08),
+  (0x00CA, 0x0300, 0x1EC0),
+  (0x00CA, 0x0301, 0x1EBE),
+  (0x00CA, 0x0303, 0x1EC4),
+  (0x00CA, 0x0309, 0x1EC2),
+  (0x00CF, 0x0301, 0x1E2E),
+  (0x00D4, 0x0300, 0x1ED2),
+  (0x00D4, 0x0301, 0x1ED0),
+  (0x00D4, 0x0303, 0x1ED6),
+  (0x00D4, 0x0309, 0x1ED4),
+  (0x00D5, 0x0301, 0x1E4C),
+  (0x00D5, 0x0304, 0x022C),
+  (0x00D5, 0x0308, 0x1E4E),
+  (0x00D6, 0x0304, 0x022A),
+  (0x00D8, 0x0301, 0x01FE),
+  (0x00DC, 0x0300, 0x01DB),
+  (0x00DC, 0x0301, 0x01D7),
+  (0x00DC, 0x0304, 0x01D5),
+  (0x00DC, 0x030C, 0x01D9),
+  (0x00E2, 0x0300, 0x1EA7),
+  (0x00E2, 0x0301, 0x1EA5),
+  (0x00E2, 0x0303, 0x1EAB),
+  (0x00E2, 0x0309, 0x1EA9),
+  (0x00E4, 0x0304, 0x01DF),
+  (0x00E5, 0x0301, 0x01FB),
+  (0x00E6, 0x0301, 0x01FD),
+  (0x00E6, 0x0304, 0x01E3),
+  (0x00E7, 0x0301, 0x1E09),
+  (0x00EA, 0x0300, 0x1EC1),
+  (0x00EA, 0x0301, 0x1EBF),
+  (0x00EA, 0x0303, 0x1EC5),
+  (0x00EA, 0x0309, 0x1EC3),
+  (0x00EF, 0x0301, 0x1E2F),
+  (0x00F4, 0x0300, 0x1ED3),
+  (0x00F4, 0x0301, 0x1ED1),
+  (0x00F4, 0x0303, 0x1ED7),
+  (0x00F4, 0x0309, 0x1ED5),
+  (0x00F5, 0x0301, 0x1E4D),
+  (0x00F5, 0x0304, 0x022D),
+  (0x00F5, 0x0308, 0x1E4F),
+  (0x00F6, 0x0304, 0x022B),
+  (0x00F8, 0x0301, 0x01FF),
+  (0x00FC, 0x0300, 0x01DC),
+  (0x00FC, 0x0301, 0x01D8),
+  (0x00FC, 0x0304, 0x01D6),
+  (0x00FC, 0x030C, 0x01DA),
+  (0x0102, 0x0300, 0x1EB0),
+  (0x0102, 0x0301, 0x1EAE),
+  (0x0102, 0x0303, 0x1EB4),
+  (0x0102, 0x0309, 0x1EB2),
+  (0x0103, 0x0300, 0x1EB1),
+  (0x0103, 0x0301, 0x1EAF),
+  (0x0103, 0x0303, 0x1EB5),
+  (0x0103, 0x0309, 0x1EB3),
+  (0x0112, 0x0300, 0x1E14),
+  (0x0112, 0x0301, 0x1E16),
+  (0x0113, 0x0300, 0x1E15),
+  (0x0113, 0x0301, 0x1E17),
+  (0x014C, 0x0300, 0x1E50),
+  (0x014C, 0x0301, 0x1E52),
+  (0x014D, 0x0300, 0x1E51),
+  (0x014D, 0x0301, 0x1E53),
+  (0x015A, 0x0307, 0x1E64),
+  (0x015B, 0x0307, 0x1E65),
+  (0x0160, 0x0307, 0x1E66),
+  (0x0161, 0x0307, 0x1E67),
+  (0x0168, 0x0301, 0x1E78),
+  (0x0169, 0x0301, 0x1E79),
+  (0x016A, 0x0308, 0x1E7A),
+  (0x016B, 0x0308, 0x1E7B),
+  (0x017F, 0x0307, 0x1E9B),
+  (0x01A0, 0x0300, 0x1EDC),
+  (0x01A0, 0x0301, 0x1EDA),
+  (0x01A0, 0x0303, 0x1EE0),
+  (0x01A0, 0x0309, 0x1EDE),
+  (0x01A0, 0x0323, 0x1EE2),
+  (0x01A1, 0x0300, 0x1EDD),
+  (0x01A1, 0x0301, 0x1EDB),
+  (0x01A1, 0x0303, 0x1EE1),
+  (0x01A1, 0x0309, 0x1EDF),
+  (0x01A1, 0x0323, 0x1EE3),
+  (0x01AF, 0x0300, 0x1EEA),
+  (0x01AF, 0x0301, 0x1EE8),
+  (0x01AF, 0x0303, 0x1EEE),
+  (0x01AF, 0x0309, 0x1EEC),
+  (0x01AF, 0x0323, 0x1EF0),
+  (0x01B0, 0x0300, 0x1EEB),
+  (0x01B0, 0x0301, 0x1EE9),
+  (0x01B0, 0x0303, 0x1EEF),
+  (0x01B0, 0x0309, 0x1EED),
+  (0x01B0, 0x0323, 0x1EF1),
+  (0x01B7, 0x030C, 0x01EE),
+  (0x01EA, 0x0304, 0x01EC),
+  (0x01EB, 0x0304, 0x01ED),
+  (0x0226, 0x0304, 0x01E0),
+  (0x0227, 0x0304, 0x01E1),
+  (0x0228, 0x0306, 0x1E1C),
+  (0x0229, 0x0306, 0x1E1D),
+  (0x022E, 0x0304, 0x0230),
+  (0x022F, 0x0304, 0x0231),
+  (0x0292, 0x030C, 0x01EF),
+  (0x0391, 0x0300, 0x1FBA),
+  (0x0391, 0x0301, 0x0386),
+  (0x0391, 0x0304, 0x1FB9),
+  (0x0391, 0x0306, 0x1FB8),
+  (0x0391, 0x0313, 0x1F08),
+  (0x0391, 0x0314, 0x1F09),
+  (0x0391, 0x0345, 0x1FBC),
+  (0x0395, 0x0300, 0x1FC8),
+  (0x0395, 0x0301, 0x0388),
+  (0x0395, 0x0313, 0x1F18),
+  (0x0395, 0x0314, 0x1F19),
+  (0x0397, 0x0300, 0x1FCA),
+  (0x0397, 0x0301, 0x0389),
+  (0x0397, 0x0313, 0x1F28),
+  (0x0397, 0x0314, 0x1F29),
+  (0x0397, 0x0345, 0x1FCC),
+  (0x0399, 0x0300, 0x1FDA),
+  (0x0399, 0x0301, 0x038A),
+  (0x0399, 0x0304, 0x1FD9),
+  (0x0399, 0x0306, 0x1FD8),
+  (0x0399, 0x0308, 0x03AA),
+  (0x0399, 0x0313, 0x1F38),
+  (0x0399, 0x0314, 0x1F39),
+  (0x039F, 0x0300, 0x1FF8),
+  (0x039F, 0x0301, 0x038C),
+  (0x039F, 0x0313, 0x1F48),
+  (0x039F, 0x0314, 0x1F49),
+  (0x03A1, 0x0314, 0x1FEC),
+  (0x03A5, 0x0300, 0x1FEA),
+  (0x03A5, 0x0301, 0x038E),
+  (0x03A5, 0x0304, 0x1FE9),
+  (0x03A5, 0x0306, 0x1FE8),
+  (0x03A5, 0x0308, 0x03AB),
+  (0x03A5, 0x0314, 0x1F59),
+  (0x03A9, 0x0300, 0x1FFA),
+  (0x03A9, 0x0301, 0x038F),
+  (0x03A9, 0x0313, 0x1F68),
+  (0x03A9, 0x0314, 0x1F69),
+  (0x03A9, 0x0345, 0x1FFC),
+  (0x03AC, 0x0345, 0x1FB4),
+  (0x03AE, 0x0345, 0x1FC4),
+  (0x03B1, 0x0300, 0x1F70),
+  (0x03B1, 0x0301, 0x03AC),
+  (0x03B1, 0x0304, 0x1FB1),
+  (0x03B1, 0x0306, 0x1FB0),
+  (0x03B1, 0x0313, 0x1F00),
+  (0x03B1, 0x0314, 0x1F01),
+  (0x03B1, 0x0342, 0x1FB6),
+  (0x03B1, 0x0345, 0x1FB3),
+  (0x03B5, 0x0300, 0x1F72),
+  (0x03B5, 0x0301, 0x03AD),
+  (0x03B5, 0x0313, 0x1F10),
+  (0x03B5, 0x0314, 0x1F11),
+  (0x03B7, 0x0300, 0x1F74),
+  (0x03B7, 0x0301, 0x03AE),
+  (0x03B7, 0x0313, 0x1F20),
+  (0x03B7, 0x0314, 0x1F21),
+  (0x03B7, 0x0342, 0x1FC6),
+  (0x03B7, 0x0345, 0x1FC3),
+  (0x03B9, 0x0300, 0x1F76),
+  (0x03B9, 0x0301, 0x03AF),
+  (0x03B9, 0x0304, 0x1FD1),
+  (0x03B9, 0x0306, 0x1FD0),
+  (0x03B9, 0x0308, 0x03CA),
+  (0x03B9, 0x0313, 0x1F30),
+  (0x03B9, 0x0314, 0x1F31),
+  (0x03B9, 0x0342, 0x1FD6),
+  (0x03BF, 0x0300, 0x1F78),
+  (0x03BF, 0x0301, 0x03CC),
+  (0x03BF, 0x0313, 0x1F40),
+  (0x03BF, 0x0314, 0x1F41),
+  (0x03C1, 0x0313, 0x1FE4),
+  (0x03C1, 0x0314, 0x1FE5),
+  (0x03C5, 0x0300, 0x1F7A),
+  (0x03C5, 0x0301, 0x03CD),
+  (0x03C5, 0x0304, 0x1FE1),
+  (0x03C5, 0x0306, 0x1FE0),
+  (0x03C5, 0x0308, 0x03CB),
+  (0x03C5, 0x0313, 0x1F50),
+  (0x03C5, 0x0314, 0x1F51),
+  (0x03C5, 0x0342, 0x1FE6),
+  (0x03C9, 0x0300, 0x1F7C),
+  (0x03C9, 0x0301, 0x03CE),
+  (0x03C9, 0x0313, 0x1F60),
+  (0x03C9, 0x0314, 0x1F61),
+  (0x03C9, 0x0342, 0x1FF6),
+  (0x03C9, 0x0345, 0x1FF3),
+  (0x03CA, 0x0300, 0x1FD2),
+  (0x03CA, 0x0301, 0x0390),
+  (0x03CA, 0x0342, 0x1FD7),
+  (0x03CB, 0x0300, 0x1FE2),
+  (0x03CB, 0x0301, 0x03B0),
+  (0x03CB, 0x0342, 0x1FE7),
+  (0x03CE, 0x0345, 0x1FF4),
+  (0x03D2, 0x0301, 0x03D3),
+  (0x03D2, 0x0308, 0x03D4),
+  (0x0406, 0x0308, 0x0407),
+  (0x0410, 0x0306, 0x04D0),
+  (0x0410, 0x0308, 0x04D2),
+  (0x0413, 0x0301, 0x0403),
+  (0x0415, 0x0300, 0x0400),
+  (0x0415, 0x0306, 0x04D6),
+  (0x0415, 0x0308, 0x0401),
+  (0x0416, 0x0306, 0x04C1),
+  (0x0416, 0x0308, 0x04DC),
+  (0x0417, 0x0308, 0x04DE),
+  (0x0418, 0x0300, 0x040D),
+  (0x0418, 0x0304, 0x04E2),
+  (0x0418, 0x0306, 0x0419),
+  (0x0418, 0x0308, 0x04E4),
+  (0x041A, 0x0301, 0x040C),
+  (0x041E, 0x0308, 0x04E6),
+  (0x0423, 0x0304, 0x04EE),
+  (0x0423, 0x0306, 0x040E),
+  (0x0423, 0x0308, 0x04F0),
+  (0x0423, 0x030B, 0x04F2),
+  (0x0427, 0x0308, 0x04F4),
+  (0x042B, 0x0308, 0x04F8),
+  (0x042D, 0x0308, 0x04EC),
+  (0x0430, 0x0306, 0x04D1),
+  (0x0430, 0x0308, 0x04D3),
+  (0x0433, 0x0301, 0x0453),
+  (0x0435, 0x0300, 0x0450),
+  (0x0435, 0x0306, 0x04D7),
+  (0x0435, 0x0308, 0x0451),
+  (0x0436, 0x0306, 0x04C2),
+  (0x0436, 0x0308, 0x04DD),
+  (0x0437, 0x0308, 0x04DF),
+  (0x0438, 0x0300, 0x045D),
+  (0x0438, 0x0304, 0x04E3),
+  (0x0438, 0x0306, 0x0439),
+  (0x0438, 0x0308, 0x04E5),
+  (0x043A, 0x0301, 0x045C),
+  (0x043E, 0x0308, 0x04E7),
+  (0x0443, 0x0304, 0x04EF),
+  (0x0443, 0x0306, 0x045E),
+  (0x0443, 0x0308, 0x04F1),
+  (0x0443, 0x030B, 0x04F3),
+  (0x0447, 0x0308, 0x04F5),
+  (0x044B, 0x0308, 0x04F9),
+  (0x044D, 0x0308, 0x04ED),
+  (0x0456, 0x0308, 0x0457),
+  (0x0474, 0x030F, 0x0476),
+  (0x0475, 0x030F, 0x0477),
+  (0x04D8, 0x0308, 0x04DA),
+  (0x04D9, 0x0308, 0x04DB),
+  (0x04E8, 0x0308, 0x04EA),
+  (0x04E9, 0x0308, 0x04EB),
+  (0x0627, 0x0653, 0x0622),
+  (0x0627, 0x0654, 0x0623),
+  (0x0627, 0x0655, 0x0625),
+  (0x0648, 0x0654, 0x0624),
+  (0x064A, 0x0654, 0x0626),
+  (0x06C1, 0x0654, 0x06C2),
+  (0x06D2, 0x0654, 0x06D3),
+  (0x06D5, 0x0654, 0x06C0),
+  (0x0928, 0x093C, 0x0929),
+  (0x0930, 0x093C, 0x0931),
+  (0x0933, 0x093C, 0x0934),
+  (0x09C7, 0x09BE, 0x09CB),
+  (0x09C7, 0x09D7, 0x09CC),
+  (0x0B47, 0x0B3E, 0x0B4B),
+  (0x0B47, 0x0B56, 0x0B48),
+  (0x0B47, 0x0B57, 0x0B4C),
+  (0x0B92, 0x0BD7, 0x0B94),
+  (0x0BC6, 0x0BBE, 0x0BCA),
+  (0x0BC6, 0x0BD7, 0x0BCC),
+  (0x0BC7, 0x0BBE, 0x0BCB),
+  (0x0C46, 0x0C56, 0x0C48),
+  (0x0CBF, 0x0CD5, 0x0CC0),
+  (0x0CC6, 0x0CC2, 0x0CCA),
+  (0x0CC6, 0x0CD5, 0x0CC7),
+  (0x0CC6, 0x0CD6, 0x0CC8),
+  (0x0CCA, 0x0CD5, 0x0CCB),
+  (0x0D46, 0x0D3E, 0x0D4A),
+  (0x0D46, 0x0D57, 0x0D4C),
+  (0x0D47, 0x0D3E, 0x0D4B),
+  (0x0DD9, 0x0DCA, 0x0DDA),
+  (0x0DD9, 0x0DCF, 0x0DDC),
+  (0x0DD9, 0x0DDF, 0x0DDE),
+  (0x0DDC, 0x0DCA, 0x0DDD),
+  (0x1025, 0x102E, 0x1026),
+  (0x1B05, 0x1B35, 0x1B06),
+  (0x1B07, 0x1B35, 0x1B08),
+  (0x1B09, 0x1B35, 0x1B0A),
+  (0x1B0B, 0x1B35, 0x1B0C),
+  (0x1B0D, 0x1B35, 0x1B0E),
+  (0x1B11, 0x1B35, 0x1B12),
+  (0x1B3A, 0x1B35, 0x1B3B),
+  (0x1B3C, 0x1B35, 0x1B3D),
+  (0x1B3E, 0x1B35, 0x1B40),
+  (0x1B3F, 0x1B35, 0x1B41),
+  (0x1B42, 0x1B35, 0x1B43),
+  (0x1E36, 0x0304, 0x1E38),
+  (0x1E37, 0x0304, 0x1E39),
+  (0x1E5A, 0x0304, 0x1E5C),
+  (0x1E5B, 0x0304, 0x1E5D),
+  (0x1E62, 0x0307, 0x1E68),
+  (0x1E63, 0x0307, 0x1E69),
+  (0x1EA0, 0x0302, 0x1EAC),
+  (0x1EA0, 0x0306, 0x1EB6),
+  (0x1EA1, 0x0302, 0x1EAD),
+  (0x1EA1, 0x0306, 0x1EB7),
+  (0x1EB8, 0x0302, 0x1EC6),
+  (0x1EB9, 0x0302, 0x1EC7),
+  (0x1ECC, 0x0302, 0x1ED8),
+  (0x1ECD, 0x0302, 0x1ED9),
+  (0x1F00, 0x0300, 0x1F02),
+  (0x1F00, 0x0301, 0x1F04),
+  (0x1F00, 0x0342, 0x1F06),
+  (0x1F00, 0x0345, 0x1F80),
+  (0x1F01, 0x0300, 0x1F03),
+  (0x1F01, 0x0301, 0x1F05),
+  (0x1F01, 0x0342, 0x1F07),
+  (0x1F01, 0x0345, 0x1F81),
+  (0x1F02, 0x0345, 0x1F82),
+  (0x1F03, 0x0345, 0x1F83),
+  (0x1F04, 0x0345, 0x1F84),
+  (0x1F05, 0x0345, 0x1F85),
+  (0x1F06, 0x0345, 0x1F86),
+  (0x1F07, 0x0345, 0x1F87),
+  (0x1F08, 0x0300, 0x1F0A),
+  (0x1F08, 0x0301, 0x1F0C),
+  (0x1F08, 0x0342, 0x1F0E),
+  (0x1F08, 0x0345, 0x1F88),
+  (0x1F09, 0x0300, 0x1F0B),
+  (0x1F09, 0x0301, 0x1F0D),
+  (0x1F09, 0x0342, 0x1F0F),
+  (0x1F09, 0x0345, 0x1F89),
+  (0x1F0A, 0x0345, 0x1F8A),
+  (0x1F0B, 0x0345, 0x1F8B),
+  (0x1F0C, 0x0345, 0x1F8C),
+  (0x1F0D, 0x0345, 0x1F8D),
+  (0x1F0E, 0x0345, 0x1F8E),
+  (0x1F0F, 0x0345, 0x1F8F),
+  (0x1F10, 0x0300, 0x1F12),
+  (0x1F10, 0x0301, 0x1F14),
+  (0x1F11, 0x0300, 0x1F13),
+  (0x1F11, 0x0301, 0x1F15),
+  (0x1F18, 0x0300, 0x1F1A),
+  (0x1F18, 0x0301, 0x1F1C),
+  (0x1F19, 0x0300, 0x1F1B),
+  (0x1F19, 0x0301, 0x1F1D),
+  (0x1F20, 0x0300, 0x1F22),
+  (0x1F20, 0x0301, 0x1F24),
+  (0x1F20, 0x0342, 0x1F26),
+  (0x1F20, 0x0345, 0x1F90),
+  (0x1F21, 0x0300, 0x1F23),
+  (0x1F21, 0x0301, 0x1F25),
+  (0x1F21, 0x0342, 0x1F27),
+  (0x1F21, 0x0345, 0x1F91),
+  (0x1F22, 0x0345, 0x1F92),
+  (0x1F23, 0x0345, 0x1F93),
+  (0x1F24, 0x0345, 0x1F94),
+  (0x1F25, 0x0345, 0x1F95),
+  (0x1F26, 0x0345, 0x1F96),
+  (0x1F27, 0x0345, 0x1F97),
+  (0x1F28, 0x0300, 0x1F2A),
+  (0x1F28, 0x0301, 0x1F2C),
+  (0x1F28, 0x0342, 0x1F2E),
+  (0x1F28, 0x0345, 0x1F98),
+  (0x1F29, 0x0300, 0x1F2B),
+  (0x1F29, 0x0301, 0x1F2D),
+  (0x1F29, 0x0342, 0x1F2F),
+  (0x1F29, 0x0345, 0x1F99),
+  (0x1F2A, 0x0345, 0x1F9A),
+  (0x1F2B, 0x0345, 0x1F9B),
+  (0x1F2C, 0x0345, 0x1F9C),
+  (0x1F2D, 0x0345, 0x1F9D),
+  (0x1F2E, 0x0345, 0x1F9E),
+  (0x1F2F, 0x0345, 0x1F9F),
+  (0x1F30, 0x0300, 0x1F32),
+  (0x1F30, 0x0301, 0x1F34),
+  (0x1F30, 0x0342, 0x1F36),
+  (0x1F31, 0x0300, 0x1F33),
+  (0x1F31, 0x0301, 0x1F35),
+  (0x1F31, 0x0342, 0x1F37),
+  (0x1F38, 0x0300, 0x1F3A),
+  (0x1F38, 0x0301, 0x1F3C),
+  (0x1F38, 0x0342, 0x1F3E),
+  (0x1F39, 0x0300, 0x1F3B),
+  (0x1F39, 0x0301, 0x1F3D),
+  (0x1F39, 0x0342, 0x1F3F),
+  (0x1F40, 0x0300, 0x1F42),
+  (0x1F40, 0x0301, 0x1F44),
+  (0x1F41, 0x0300, 0x1F43),
+  (0x1F41, 0x0301, 0x1F45),
+  (0x1F48, 0x0300, 0x1F4A),
+  (0x1F48, 0x0301, 0x1F4C),
+  (0x1F49, 0x0300, 0x1F4B),
+  (0x1F49, 0x0301, 0x1F4D),
+  (0x1F50, 0x0300, 0x1F52),
+  (0x1F50, 0x0301, 0x1F54),
+  (0x1F50, 0x0342, 0x1F56),
+  (0x1F51, 0x0300, 0x1F53),
+  (0x1F51, 0x0301, 0x1F55),
+  (0x1F51, 0x0342, 0x1F57),
+  (0x1F59, 0x0300, 0x1F5B),
+  (0x1F59, 0x0301, 0x1F5D),
+  (0x1F59, 0x0342, 0x1F5F),
+  (0x1F60, 0x0300, 0x1F62),
+  (0x1F60, 0x0301, 0x1F64),
+  (0x1F60, 0x0342, 0x1F66),
+  (0x1F60, 0x0345, 0x1FA0),
+  (0x1F61, 0x0300, 0x1F63),
+  (0x1F61, 0x0301, 0x1F65),
+  (0x1F61, 0x0342, 0x1F67),
+  (0x1F61, 0x0345, 0x1FA1),
+  (0x1F62, 0x0345, 0x1FA2),
+  (0x1F63, 0x0345, 0x1FA3),
+  (0x1F64, 0x0345, 0x1FA4),
+  (0x1F65, 0x0345, 0x1FA5),
+  (0x1F66, 0x0345, 0x1FA6),
+  (0x1F67, 0x0345, 0x1FA7),
+  (0x1F68, 0x0300, 0x1F6A),
+  (0x1F68, 0x0301, 0x1F6C),
+  (0x1F68, 0x0342, 0x1F6E),
+  (0x1F68, 0x0345, 0x1FA8),
+  (0x1F69, 0x0300, 0x1F6B),
+  (0x1F69, 0x0301, 0x1F6D),
+  (0x1F69, 0x0342, 0x1F6F),
+  (0x1F69, 0x0345, 0x1FA9),
+  (0x1F6A, 0x0345, 0x1FAA),
+  (0x1F6B, 0x0345, 0x1FAB),
+  (0x1F6C, 0x0345, 0x1FAC),
+  (0x1F6D, 0x0345, 0x1FAD),
+  (0x1F6E, 0x0345, 0x1FAE),
+  (0x1F6F, 0x0345, 0x1FAF),
+  (0x1F70, 0x0345, 0x1FB2),
+  (0x1F74, 0x0345, 0x1FC2),
+  (0x1F7C, 0x0345, 0x1FF2),
+  (0x1FB6, 0x0345, 0x1FB7),
+  (0x1FBF, 0x0300, 0x1FCD),
+  (0x1FBF, 0x0301, 0x1FCE),
+  (0x1FBF, 0x0342, 0x1FCF),
+  (0x1FC6, 0x0345, 0x1FC7),
+  (0x1FF6, 0x0345, 0x1FF7),
+  (0x1FFE, 0x0300, 0x1FDD),
+  (0x1FFE, 0x0301, 0x1FDE),
+  (0x1FFE, 0x0342, 0x1FDF),
+  (0x2190, 0x0338, 0x219A),
+  (0x2192, 0x0338, 0x219B),
+  (0x2194, 0x0338, 0x21AE),
+  (0x21D0, 0x0338, 0x21CD),
+  (0x21D2, 0x0338, 0x21CF),
+  (0x21D4, 0x0338, 0x21CE),
+  (0x2203, 0x0338, 0x2204),
+  (0x2208, 0x0338, 0x2209),
+  (0x220B, 0x0338, 0x220C),
+  (0x2223, 0x0338, 0x2224),
+  (0x2225, 0x0338, 0x2226),
+  (0x223C, 0x0338, 0x2241),
+  (0x2243, 0x0338, 0x2244),
+  (0x2245, 0x0338, 0x2247),
+  (0x2248, 0x0338, 0x2249),
+  (0x224D, 0x0338, 0x226D),
+  (0x2261, 0x0338, 0x2262),
+  (0x2264, 0x0338, 0x2270),
+  (0x2265, 0x0338, 0x2271),
+  (0x2272, 0x0338, 0x2274),
+  (0x2273, 0x0338, 0x2275),
+  (0x2276, 0x0338, 0x2278),
+  (0x2277, 0x0338, 0x2279),
+  (0x227A, 0x0338, 0x2280),
+  (0x227B, 0x0338, 0x2281),
+  (0x227C, 0x0338, 0x22E0),
+  (0x227D, 0x0338, 0x22E1),
+  (0x2282, 0x0338, 0x2284),
+  (0x2283, 0x0338, 0x2285),
+  (0x2286, 0x0338, 0x2288),
+  (0x2287, 0x0338, 0x2289),
+  (0x2291, 0x0338, 0x22E2),
+  (0x2292, 0x0338, 0x22E3),
+  (0x22A2, 0x0338, 0x22AC),
+  (0x22A8, 0x0338, 0x22AD),
+  (0x22A9, 0x0338, 0x22AE),
+  (0x22AB, 0x0338, 0x22AF),
+  (0x22B2, 0x0338, 0x22EA),
+  (0x22B3, 0x0338, 0x22EB),
+  (0x22B4, 0x0338, 0x22EC),
+  (0x22B5, 0x0338, 0x22ED),
+  (0x3046, 0x3099, 0x3094),
+  (0x304B, 0x3099, 0x304C),
+  (0x304D, 0x3099, 0x304E),
+  (0x304F, 0x3099, 0x3050),
+  (0x3051, 0x3099, 0x3052),
+  (0x3053, 0x3099, 0x3054),
+  (0x3055, 0x3099, 0x3056),
+  (0x3057, 0x3099, 0x3058),
+  (0x3059, 0x3099, 0x305A),
+  (0x305B, 0x3099, 0x305C),
+  (0x305D, 0x3099, 0x305E),
+  (0x305F, 0x3099, 0x3060),
+  (0x3061, 0x3099, 0x3062),
+  (0x3064, 0x3099, 0x3065),
+  (0x3066, 0x3099, 0x3067),
+  (0x3068, 0x3099, 0x3069),
+  (0x306F, 0x3099, 0x3070),
+  (0x306F, 0x309A, 0x3071),
+  (0x3072, 0x3099, 0x3073),
+  (0x3072, 0x309A, 0x3074),
+  (0x3075, 0x3099, 0x3076),
+  (0x3075, 0x309A, 0x3077),
+  (0x3078, 0x3099, 0x3079),
+  (0x3078, 0x309A, 0x307A),
+  (0x307B, 0x3099, 0x307C),
+  (0x307B, 0x309A, 0x307D),
+  (0x309D, 0x3099, 0x309E),
+  (0x30A6, 0x3099, 0x30F4),
+  (0x30AB, 0x3099, 0x30AC),
+  (0x30AD, 0x3099, 0x30AE),
+  (0x30AF, 0x3099, 0x30B0),
+  (0x30B1, 0x3099, 0x30B2),
+  (0x30B3, 0x3099, 0x30B4),
+  (0x30B5, 0x3099, 0x30B6),
+  (0x30B7, 0x3099, 0x30B8),
+  (0x30B9, 0x3099, 0x30BA),
+  (0x30BB, 0x3099, 0x30BC),
+  (0x30BD, 0x3099, 0x30BE),
+  (0x30BF, 0x3099, 0x30C0),
+  (0x30C1, 0x3099, 0x30C2),
+  (0x30C4, 0x3099, 0x30C5),
+  (0x30C6, 0x3099, 0x30C7),
+  (0x30C8, 0x3099, 0x30C9),
+  (0x30CF, 0x3099, 0x30D0),
+  (0x30CF, 0x309A, 0x30D1),
+  (0x30D2, 0x3099, 0x30D3),
+  (0x30D2, 0x309A, 0x30D4),
+  (0x30D5, 0x3099, 0x30D6),
+  (0x30D5, 0x309A, 0x30D7),
+  (0x30D8, 0x3099, 0x30D9),
+  (0x30D8, 0x309A, 0x30DA),
+  (0x30DB, 0x3099, 0x30DC),
+  (0x30DB, 0x309A, 0x30DD),
+  (0x30EF, 0x3099, 0x30F7),
+  (0x30F0, 0x3099, 0x30F8),
+  (0x30F1, 0x3099, 0x30F9),
+  (0x30F2, 0x3099, 0x30FA),
+  (0x30FD, 0x3099, 0x30FE),
+  (0x11099, 0x110BA, 0x1109A),
+  (0x1109B, 0x110BA, 0x1109C),
+  (0x110A5, 0x110BA, 0x110AB),
+  (0x11131, 0x11127, 0x1112E),
+  (0x11132, 0x11127, 0x1112F),
+  (0x11347, 0x1133E, 0x1134B),
+  (0x11347, 0x11357, 0x1134C),
+  (0x114B9, 0x114B0, 0x114BC),
+  (0x114B9, 0x114BA, 0x114BB),
+  (0x114B9, 0x114BD, 0x114BE),
+  (0x115B8, 0x115AF, 0x115BA),
+  (0x115B9, 0x115AF, 0x115BB),
+  (0x11935, 0x11930, 0x11938),
+];
+
+/// The ranges of code points with a `Mark` general category.
+pub(crate) const MARKS: &[(u32, u32)] = &[
+  (0x0300, 0x036F),
+  (0x0483, 0x0489),
+  (0x0591, 0x05BD),
+  (0x05BF, 0x05BF),
+  (0x05C1, 0x05C2),
+  (0x05C4, 0x05C5),
+  (0x05C7, 0x05C7),
+  (0x0610, 0x061A),
+  (0x064B, 0x065F),
+  (0x0670, 0x0670),
+  (0x06D6, 0x06DC),
+  (0x06DF, 0x06E4),
+  (0x06E7, 0x06E8),
+  (0x06EA, 0x06ED),
+  (0x0711, 0x0711),
+  (0x0730, 0x074A),
+  (0x07A6, 0x07B0),
+  (0x07EB, 0x07F3),
+  (0x07FD, 0x07FD),
+  (0x0816, 0x0819),
+  (0x081B, 0x0823),
+  (0x0825, 0x0827),
+  (0x0829, 0x082D),
+  (0x0859, 0x085B),
+  (0x08D3, 0x08E1),
+  (0x08E3, 0x0903),
+  (0x093A, 0x093C),
+  (0x093E, 0x094F),
+  (0x0951, 0x0957),
+  (0x0962, 0x0963),
+  (0x0981, 0x0983),
+  (0x09BC, 0x09BC),
+  (0x09BE, 0x09C4),
+  (0x09C7, 0x09C8),
+  (0x09CB, 0x09CD),
+  (0x09D7, 0x09D7),
+  (0x09E2, 0x09E3),
+  (0x09FE, 0x09FE),
+  (0x0A01, 0x0A03),
+  (0x0A3C, 0x0A3C),
+  (0x0A3E, 0x0A42),
+  (0x0A47, 0x0A48),
+  (0x0A4B, 0x0A4D),
+  (0x0A51, 0x0A51),
+  (0x0A70, 0x0A71),
+  (0x0A75, 0x0A75),
+  (0x0A81, 0x0A83),
+  (0x0ABC, 0x0ABC),
+  (0x0ABE, 0x0AC5),
+  (0x0AC7, 0x0AC9),
+  (0x0ACB, 0x0ACD),
+  (0x0AE2, 0x0AE3),
+  (0x0AFA, 0x0AFF),
+  (0x0B01, 0x0B03),
+  (0x0B3C, 0x0B3C),
+  (0x0B3E, 0x0B44),
+  (0x0B47, 0x0B48),
+  (0x0B4B, 0x0B4D),
+  (0x0B55, 0x0B57),
+  (0x0B62, 0x0B63),
+  (0x0B82, 0x0B82),
+  (0x0BBE, 0x0BC2),
+  (0x0BC6, 0x0BC8),
+  (0x0BCA, 0x0BCD),
+  (0x0BD7, 0x0BD7),
+  (0x0C00, 0x0C04),
+  (0x0C3E, 0x0C44),
+  (0x0C46, 0x0C48),
+  (0x0C4A, 0x0C4D),
+  (0x0C55, 0x0C56),
+  (0x0C62, 0x0C63),
+  (0x0C81, 0x0C83),
+  (0x0CBC, 0x0CBC),
+  (0x0CBE, 0x0CC4),
+  (0x0CC6, 0x0CC8),
+  (0x0CCA, 0x0CCD),
+  (0x0CD5, 0x0CD6),
+  (0x0CE2, 0x0CE3),
+  (0x0D00, 0x0D03),
+  (0x0D3B, 0x0D3C),
+  (0x0D3E, 0x0D44),
+  (0x0D46, 0x0D48),
+  (0x0D4A, 0x0D4D),
+  (0x0D57, 0x0D57),
+  (0x0D62, 0x0D63),
+  (0x0D81, 0x0D83),
+  (0x0DCA, 0x0DCA),
+  (0x0DCF, 0x0DD4),
+  (0x0DD6, 0x0DD6),
+  (0x0DD8, 0x0DDF),
+  (0x0DF2, 0x0DF3),
+  (0x0E31, 0x0E31),
+  (0x0E34, 0x0E3A),
+  (0x0E47, 0x0E4E),
+  (0x0EB1, 0x0EB1),
+  (0x0EB4, 0x0EBC),
+  (0x0EC8, 0x0ECD),
+  (0x0F18, 0x0F19),
+  (0x0F35, 0x0F35),
+  (0x0F37, 0x0F37),
+  (0x0F39, 0x0F39),
+  (0x0F3E, 0x0F3F),
+  (0x0F71, 0x0F84),
+  (0x0F86, 0x0F87),
+  (0x0F8D, 0x0F97),
+  (0x0F99, 0x0FBC),
+  (0x0FC6, 0x0FC6),
+  (0x102B, 0x103E),
+  (0x1056, 0x1059),
+  (0x105E, 0x1060),
+  (0x1062, 0x1064),
+  (0x1067, 0x106D),
+  (0x1071, 0x1074),
+  (0x1082, 0x108D),
+  (0x108F, 0x108F),
+  (0x109A, 0x109D),
+  (0x135D, 0x135F),
+  (0x1712, 0x1714),
+  (0x1732, 0x1734),
+  (0x1752, 0x1753),
+  (0x1772, 0x1773),
+  (0x17B4, 0x17D3),
+  (0x17DD, 0x17DD),
+  (0x180B, 0x180D),
+  (0x1885, 0x1886),
+  (0x18A9, 0x18A9),
+  (0x1920, 0x192B),
+  (0x1930, 0x193B),
+  (0x1A17, 0x1A1B),
+  (0x1A55, 0x1A5E),
+  (0x1A60, 0x1A7C),
+  (0x1A7F, 0x1A7F),
+  (0x1AB0, 0x1AC0),
+  (0x1B00, 0x1B04),
+  (0x1B34, 0x1B44),
+  (0x1B6B, 0x1B73),
+  (0x1B80, 0x1B82),
+  (0x1BA1, 0x1BAD),
+  (0x1BE6, 0x1BF3),
+  (0x1C24, 0x1C37),
+  (0x1CD0, 0x1CD2),
+  (0x1CD4, 0x1CE8),
+  (0x1CED, 0x1CED),
+  (0x1CF4, 0x1CF4),
+  (0x1CF7, 0x1CF9),
+  (0x1DC0, 0x1DF9),
+  (0x1DFB, 0x1DFF),
+  (0x20D0, 0x20F0),
+  (0x2CEF, 0x2CF1),
+  (0x2D7F, 0x2D7F),
+  (0x2DE0, 0x2DFF),
+  (0x302A, 0x302F),
+  (0x3099, 0x309A),
+  (0xA66F, 0xA672),
+  (0xA674, 0xA67D),
+  (0xA69E, 0xA69F),
+  (0xA6F0, 0xA6F1),
+  (0xA802, 0xA802),
+  (0xA806, 0xA806),
+  (0xA80B, 0xA80B),
+  (0xA823, 0xA827),
+  (0xA82C, 0xA82C),
+  (0xA880, 0xA881),
+  (0xA8B4, 0xA8C5),
+  (0xA8E0, 0xA8F1),
+  (0xA8FF, 0xA8FF),
+  (0xA926, 0xA92D),
+  (0xA947, 0xA953),
+  (0xA980, 0xA983),
+  (0xA9B3, 0xA9C0),
+  (0xA9E5, 0xA9E5),
+  (0xAA29, 0xAA36),
+  (0xAA43, 0xAA43),
+  (0xAA4C, 0xAA4D),
+  (0xAA7B, 0xAA7D),
+  (0xAAB0, 0xAAB0),
+  (0xAAB2, 0xAAB4),
+  (0xAAB7, 0xAAB8),
+  (0xAABE, 0xAABF),
+  (0xAAC1, 0xAAC1),
+  (0xAAEB, 0xAAEF),
+  (0xAAF5, 0xAAF6),
+  (0xABE3, 0xABEA),
+  (0xABEC, 0xABED),
+  (0xFB1E, 0xFB1E),
+  (0xFE00, 0xFE0F),
+  (0xFE20, 0xFE2F),
+  (0x101FD, 0x101FD),
+  (0x102E0, 0x102E0),
+  (0x10376, 0x1037A),
+  (0x10A01, 0x10A03),
+  (0x10A05, 0x10A06),
+  (0x10A0C, 0x10A0F),
+  (0x10A38, 0x10A3A),
+  (0x10A3F, 0x10A3F),
+  (0x10AE5, 0x10AE6),
+  (0x10D24, 0x10D27),
+  (0x10EAB, 0x10EAC),
+  (0x10F46, 0x10F50),
+  (0x11000, 0x11002),
+  (0x11038, 0x11046),
+  (0x1107F, 0x11082),
+  (0x110B0, 0x110BA),
+  (0x11100, 0x11102),
+  (0x11127, 0x11134),
+  (0x11145, 0x11146),
+  (0x11173, 0x11173),
+  (0x11180, 0x11182),
+  (0x111B3, 0x111C0),
+  (0x111C9, 0x111CC),
+  (0x111CE, 0x111CF),
+  (0x1122C, 0x11237),
+  (0x1123E, 0x1123E),
+  (0x112DF, 0x112EA),
+  (0x11300, 0x11303),
+  (0x1133B, 0x1133C),
+  (0x1133E, 0x11344),
+  (0x11347, 0x11348),
+  (0x1134B, 0x1134D),
+  (0x11357, 0x11357),
+  (0x11362, 0x11363),
+  (0x11366, 0x1136C),
+  (0x11370, 0x11374),
+  (0x11435, 0x11446),
+  (0x1145E, 0x1145E),
+  (0x114B0, 0x114C3),
+  (0x115AF, 0x115B5),
+  (0x115B8, 0x115C0),
+  (0x115DC, 0x115DD),
+  (0x11630, 0x11640),
+  (0x116AB, 0x116B7),
+  (0x1171D, 0x1172B),
+  (0x1182C, 0x1183A),
+  (0x11930, 0x11935),
+  (0x11937, 0x11938),
+  (0x1193B, 0x1193E),
+  (0x11940, 0x11940),
+  (0x11942, 0x11943),
+  (0x119D1, 0x119D7),
+  (0x119DA, 0x119E0),
+  (0x119E4, 0x119E4),
+  (0x11A01, 0x11A0A),
+  (0x11A33, 0x11A39),
+  (0x11A3B, 0x11A3E),
+  (0x11A47, 0x11A47),
+  (0x11A51, 0x11A5B),
+  (0x11A8A, 0x11A99),
+  (0x11C2F, 0x11C36),
+  (0x11C38, 0x11C3F),
+  (0x11C92, 0x11CA7),
+  (0x11CA9, 0x11CB6),
+  (0x11D31, 0x11D36),
+  (0x11D3A, 0x11D3A),
+  (0x11D3C, 0x11D3D),
+  (0x11D3F, 0x11D45),
+  (0x11D47, 0x11D47),
+  (0x11D8A, 0x11D8E),
+  (0x11D90, 0x11D91),
+  (0x11D93, 0x11D97),
+  (0x11EF3, 0x11EF6),
+  (0x16AF0, 0x16AF4),
+  (0x16B30, 0x16B36),
+  (0x16F4F, 0x16F4F),
+  (0x16F51, 0x16F87),
+  (0x16F8F, 0x16F92),
+  (0x16FE4, 0x16FE4),
+  (0x16FF0, 0x16FF1),
+  (0x1BC9D, 0x1BC9E),
+  (0x1D165, 0x1D169),
+  (0x1D16D, 0x1D172),
+  (0x1D17B, 0x1D182),
+  (0x1D185, 0x1D18B),
+  (0x1D1AA, 0x1D1AD),
+  (0x1D242, 0x1D244),
+  (0x1DA00, 0x1DA36),
+  (0x1DA3B, 0x1DA6C),
+  (0x1DA75, 0x1DA75),
+  (0x1DA84, 0x1DA84),
+  (0x1DA9B, 0x1DA9F),
+  (0x1DAA1, 0x1DAAF),
+  (0x1E000, 0x1E006),
+  (0x1E008, 0x1E018),
+  (0x1E01B, 0x1E021),
+  (0x1E023, 0x1E024),
+  (0x1E026, 0x1E02A),
+  (0x1E130, 0x1E136),
+  (0x1E2EC, 0x1E2EF),
+  (0x1E8D0, 0x1E8D6),
+  (0x1E944, 0x1E94A),
+  (0xE0100, 0xE01EF),
+];
+
+/// The ranges of code points with a bidi class other than `L`.
+pub(crate) const BIDI_CLASS: &[(u32, u32, BidiClass)] = &[
+  (0x0000, 0x0008, BN),
+  (0x0009, 0x0009, Other),
+  (0x000A, 0x000A, Other),
+  (0x000B, 0x000B, Other),
+  (0x000C, 0x000C, Other),
+  (0x000D, 0x000D, Other),
+  (0x000E, 0x001B, BN),
+  (0x001C, 0x001E, Other),
+  (0x001F, 0x001F, Other),
+  (0x0020, 0x0020, Other),
+  (0x0021, 0x0022, ON),
+  (0x0023, 0x0025, ET),
+  (0x0026, 0x002A, ON),
+  (0x002B, 0x002B, ES),
+  (0x002C, 0x002C, CS),
+  (0x002D, 0x002D, ES),
+  (0x002E, 0x002F, CS),
+  (0x0030, 0x0039, EN),
+  (0x003A, 0x003A, CS),
+  (0x003B, 0x0040, ON),
+  (0x005B, 0x0060, ON),
+  (0x007B, 0x007E, ON),
+  (0x007F, 0x0084, BN),
+  (0x0085, 0x0085, Other),
+  (0x0086, 0x009F, BN),
+  (0x00A0, 0x00A0, CS),
+  (0x00A1, 0x00A1, ON),
+  (0x00A2, 0x00A5, ET),
+  (0x00A6, 0x00A9, ON),
+  (0x00AB, 0x00AC, ON),
+  (0x00AD, 0x00AD, BN),
+  (0x00AE, 0x00AF, ON),
+  (0x00B0, 0x00B1, ET),
+  (0x00B2, 0x00B3, EN),
+  (0x00B4, 0x00B4, ON),
+  (0x00B6, 0x00B8, ON),
+  (0x00B9, 0x00B9, EN),
+  (0x00BB, 0x00BF, ON),
+  (0x00D7, 0x00D7, ON),
+  (0x00F7, 0x00F7, ON),
+  (0x02B9, 0x02BA, ON),
+  (0x02C2, 0x02CF, ON),
+  (0x02D2, 0x02DF, ON),
+  (0x02E5, 0x02ED, ON),
+  (0x02EF, 0x02FF, ON),
+  (0x0300, 0x036F, NSM),
+  (0x0374, 0x0375, ON),
+  (0x037E, 0x037E, ON),
+  (0x0384, 0x0385, ON),
+  (0x0387, 0x0387, ON),
+  (0x03F6, 0x03F6, ON),
+  (0x0483, 0x0489, NSM),
+  (0x058A, 0x058A, ON),
+  (0x058D, 0x058E, ON),
+  (0x058F, 0x058F, ET),
+  (0x0591, 0x05BD, NSM),
+  (0x05BE, 0x05BE, R),
+  (0x05BF, 0x05BF, NSM),
+  (0x05C0, 0x05C0, R),
+  (0x05C1, 0x05C2, NSM),
+  (0x05C3, 0x05C3, R),
+  (0x05C4, 0x05C5, NSM),
+  (0x05C6, 0x05C6, R),
+  (0x05C7, 0x05C7, NSM),
+  (0x05D0, 0x05EA, R),
+  (0x05EF, 0x05F4, R),
+  (0x0600, 0x0605, AN),
+  (0x0606, 0x0607, ON),
+  (0x0608, 0x0608, AL),
+  (0x0609, 0x060A, ET),
+  (0x060B, 0x060B, AL),
+  (0x060C, 0x060C, CS),
+  (0x060D, 0x060D, AL),
+  (0x060E, 0x060F, ON),
+  (0x0610, 0x061A, NSM),
+  (0x061B, 0x061C, AL),
+  (0x061E, 0x064A, AL),
+  (0x064B, 0x065F, NSM),
+  (0x0660, 0x0669, AN),
+  (0x066A, 0x066A, ET),
+  (0x066B, 0x066C, AN),
+  (0x066D, 0x066F, AL),
+  (0x0670, 0x0670, NSM),
+  (0x0671, 0x06D5, AL),
+  (0x06D6, 0x06DC, NSM),
+  (0x06DD, 0x06DD, AN),
+  (0x06DE, 0x06DE, ON),
+  (0x06DF, 0x06E4, NSM),
+  (0x06E5, 0x06E6, AL),
+  (0x06E7, 0x06E8, NSM),
+  (0x06E9, 0x06E9, ON),
+  (0x06EA, 0x06ED, NSM),
+  (0x06EE, 0x06EF, AL),
+  (0x06F0, 0x06F9, EN),
+  (0x06FA, 0x070D, AL),
+  (0x070F, 0x0710, AL),
+  (0x0711, 0x0711, NSM),
+  (0x0712, 0x072F, AL),
+  (0x0730, 0x074A, NSM),
+  (0x074D, 0x07A5, AL),
+  (0x07A6, 0x07B0, NSM),
+  (0x07B1, 0x07B1, AL),
+  (0x07C0, 0x07EA, R),
+  (0x07EB, 0x07F3, NSM),
+  (0x07F4, 0x07F5, R),
+  (0x07F6, 0x07F9, ON),
+  (0x07FA, 0x07FA, R),
+  (0x07FD, 0x07FD, NSM),
+  (0x07FE, 0x0815, R),
+  (0x0816, 0x0819, NSM),
+  (0x081A, 0x081A, R),
+  (0x081B, 0x0823, NSM),
+  (0x0824, 0x0824, R),
+  (0x0825, 0x0827, NSM),
+  (0x0828, 0x0828, R),
+  (0x0829, 0x082D, NSM),
+  (0x0830, 0x083E, R),
+  (0x0840, 0x0858, R),
+  (0x0859, 0x085B, NSM),
+  (0x085E, 0x085E, R),
+  (0x0860, 0x086A, AL),
+  (0x08A0, 0x08B4, AL),
+  (0x08B6, 0x08C7, AL),
+  (0x08D3, 0x08E1, NSM),
+  (0x08E2, 0x08E2, AN),
+  (0x08E3, 0x0902, NSM),
+  (0x093A, 0x093A, NSM),
+  (0x093C, 0x093C, NSM),
+  (0x0941, 0x0948, NSM),
+  (0x094D, 0x094D, NSM),
+  (0x0951, 0x0957, NSM),
+  (0x0962, 0x0963, NSM),
+  (0x0981, 0x0981, NSM),
+  (0x09BC, 0x09BC, NSM),
+  (0x09C1, 0x09C4, NSM),
+  (0x09CD, 0x09CD, NSM),
+  (0x09E2, 0x09E3, NSM),
+  (0x09F2, 0x09F3, ET),
+  (0x09FB, 0x09FB, ET),
+  (0x09FE, 0x09FE, NSM),
+  (0x0A01, 0x0A02, NSM),
+  (0x0A3C, 0x0A3C, NSM),
+  (0x0A41, 0x0A42, NSM),
+  (0x0A47, 0x0A48, NSM),
+  (0x0A4B, 0x0A4D, NSM),
+  (0x0A51, 0x0A51, NSM),
+  (0x0A70, 0x0A71, NSM),
+  (0x0A75, 0x0A75, NSM),
+  (0x0A81, 0x0A82, NSM),
+  (0x0ABC, 0x0ABC, NSM),
+  (0x0AC1, 0x0AC5, NSM),
+  (0x0AC7, 0x0AC8, NSM),
+  (0x0ACD, 0x0ACD, NSM),
+  (0x0AE2, 0x0AE3, NSM),
+  (0x0AF1, 0x0AF1, ET),
+  (0x0AFA, 0x0AFF, NSM),
+  (0x0B01, 0x0B01, NSM),
+  (0x0B3C, 0x0B3C, NSM),
+  (0x0B3F, 0x0B3F, NSM),
+  (0x0B41, 0x0B44, NSM),
+  (0x0B4D, 0x0B4D, NSM),
+  (0x0B55, 0x0B56, NSM),
+  (0x0B62, 0x0B63, NSM),
+  (0x0B82, 0x0B82, NSM),
+  (0x0BC0, 0x0BC0, NSM),
+  (0x0BCD, 0x0BCD, NSM),
+  (0x0BF3, 0x0BF8, ON),
+  (0x0BF9, 0x0BF9, ET),
+  (0x0BFA, 0x0BFA, ON),
+  (0x0C00, 0x0C00, NSM),
+  (0x0C04, 0x0C04, NSM),
+  (0x0C3E, 0x0C40, NSM),
+  (0x0C46, 0x0C48, NSM),
+  (0x0C4A, 0x0C4D, NSM),
+  (0x0C55, 0x0C56, NSM),
+  (0x0C62, 0x0C63, NSM),
+  (0x0C78, 0x0C7E, ON),
+  (0x0C81, 0x0C81, NSM),
+  (0x0CBC, 0x0CBC, NSM),
+  (0x0CCC, 0x0CCD, NSM),
+  (0x0CE2, 0x0CE3, NSM),
+  (0x0D00, 0x0D01, NSM),
+  (0x0D3B, 0x0D3C, NSM),
+  (0x0D41, 0x0D44, NSM),
+  (0x0D4D, 0x0D4D, NSM),
+  (0x0D62, 0x0D63, NSM),
+  (0x0D81, 0x0D81, NSM),
+  (0x0DCA, 0x0DCA, NSM),
+  (0x0DD2, 0x0DD4, NSM),
+  (0x0DD6, 0x0DD6, NSM),
+  (0x0E31, 0x0E31, NSM),
+  (0x0E34, 0x0E3A, NSM),
+  (0x0E3F, 0x0E3F, ET),
+  (0x0E47, 0x0E4E, NSM),
+  (0x0EB1, 0x0EB1, NSM),
+  (0x0EB4, 0x0EBC, NSM),
+  (0x0EC8, 0x0ECD, NSM),
+  (0x0F18, 0x0F19, NSM),
+  (0x0F35, 0x0F35, NSM),
+  (0x0F37, 0x0F37, NSM),
+  (0x0F39, 0x0F39, NSM),
+  (0x0F3A, 0x0F3D, ON),
+  (0x0F71, 0x0F7E, NSM),
+  (0x0F80, 0x0F84, NSM),
+  (0x0F86, 0x0F87, NSM),
+  (0x0F8D, 0x0F97, NSM),
+  (0x0F99, 0x0FBC, NSM),
+  (0x0FC6, 0x0FC6, NSM),
+  (0x102D, 0x1030, NSM),
+  (0x1032, 0x1037, NSM),
+  (0x1039, 0x103A, NSM),
+  (0x103D, 0x103E, NSM),
+  (0x1058, 0x1059, NSM),
+  (0x105E, 0x1060, NSM),
+  (0x1071, 0x1074, NSM),
+  (0x1082, 0x1082, NSM),
+  (0x1085, 0x1086, NSM),
+  (0x108D, 0x108D, NSM),
+  (0x109D, 0x109D, NSM),
+  (0x135D, 0x135F, NSM),
+  (0x1390, 0x1399, ON),
+  (0x1400, 0x1400, ON),
+  (0x1680, 0x1680, Other),
+  (0x169B, 0x169C, ON),
+  (0x1712, 0x1714, NSM),
+  (0x1732, 0x1734, NSM),
+  (0x1752, 0x1753, NSM),
+  (0x1772, 0x1773, NSM),
+  (0x17B4, 0x17B5, NSM),
+  (0x17B7, 0x17BD, NSM),
+  (0x17C6, 0x17C6, NSM),
+  (0x17C9, 0x17D3, NSM),
+  (0x17DB, 0x17DB, ET),
+  (0x17DD, 0x17DD, NSM),
+  (0x17F0, 0x17F9, ON),
+  (0x1800, 0x180A, ON),
+  (0x180B, 0x180D, NSM),
+  (0x180E, 0x180E, BN),
+  (0x1885, 0x1886, NSM),
+  (0x18A9, 0x18A9, NSM),
+  (0x1920, 0x1922, NSM),
+  (0x1927, 0x1928, NSM),
+  (0x1932, 0x1932, NSM),
+  (0x1939, 0x193B, NSM),
+  (0x1940, 0x1940, ON),
+  (0x1944, 0x1945, ON),
+  (0x19DE, 0x19FF, ON),
+  (0x1A17, 0x1A18, NSM),
+  (0x1A1B, 0x1A1B, NSM),
+  (0x1A56, 0x1A56, NSM),
+  (0x1A58, 0x1A5E, NSM),
+  (0x1A60, 0x1A60, NSM),
+  (0x1A62, 0x1A62, NSM),
+  (0x1A65, 0x1A6C, NSM),
+  (0x1A73, 0x1A7C, NSM),
+  (0x1A7F, 0x1A7F, NSM),
+  (0x1AB0, 0x1AC0, NSM),
+  (0x1B00, 0x1B03, NSM),
+  (0x1B34, 0x1B34, NSM),
+  (0x1B36, 0x1B3A, NSM),
+  (0x1B3C, 0x1B3C, NSM),
+  (0x1B42, 0x1B42, NSM),
+  (0x1B6B, 0x1B73, NSM),
+  (0x1B80, 0x1B81, NSM),
+  (0x1BA2, 0x1BA5, NSM),
+  (0x1BA8, 0x1BA9, NSM),
+  (0x1BAB, 0x1BAD, NSM),
+  (0x1BE6, 0x1BE6, NSM),
+  (0x1BE8, 0x1BE9, NSM),
+  (0x1BED, 0x1BED, NSM),
+  (0x1BEF, 0x1BF1, NSM),
+  (0x1C2C, 0x1C33, NSM),
+  (0x1C36, 0x1C37, NSM),
+  (0x1CD0, 0x1CD2, NSM),
+  (0x1CD4, 0x1CE0, NSM),
+  (0x1CE2, 0x1CE8, NSM),
+  (0x1CED, 0x1CED, NSM),
+  (0x1CF4, 0x1CF4, NSM),
+  (0x1CF8, 0x1CF9, NSM),
+  (0x1DC0, 0x1DF9, NSM),
+  (0x1DFB, 0x1DFF, NSM),
+  (0x1FBD, 0x1FBD, ON),
+  (0x1FBF, 0x1FC1, ON),
+  (0x1FCD, 0x1FCF, ON),
+  (0x1FDD, 0x1FDF, ON),
+  (0x1FED, 0x1FEF, ON),
+  (0x1FFD, 0x1FFE, ON),
+  (0x2000, 0x200A, Other),
+  (0x200B, 0x200D, BN),
+  (0x200F, 0x200F, R),
+  (0x2010, 0x2027, ON),
+  (0x2028, 0x2028, Other),
+  (0x2029, 0x2029, Other),
+  (0x202A, 0x202A, Other),
+  (0x202B, 0x202B, Other),
+  (0x202C, 0x202C, Other),
+  (0x202D, 0x202D, Other),
+  (0x202E, 0x202E, Other),
+  (0x202F, 0x202F, CS),
+  (0x2030, 0x2034, ET),
+  (0x2035, 0x2043, ON),
+  (0x2044, 0x2044, CS),
+  (0x2045, 0x205E, ON),
+  (0x205F, 0x205F, Other),
+  (0x2060, 0x2064, BN),
+  (0x2066, 0x2066, Other),
+  (0x2067, 0x2067, Other),
+  (0x2068, 0x2068, Other),
+  (0x2069, 0x2069, Other),
+  (0x206A, 0x206F, BN),
+  (0x2070, 0x2070, EN),
+  (0x2074, 0x2079, EN),
+  (0x207A, 0x207B, ES),
+  (0x207C, 0x207E, ON),
+  (0x2080, 0x2089, EN),
+  (0x208A, 0x208B, ES),
+  (0x208C, 0x208E, ON),
+  (0x20A0, 0x20BF, ET),
+  (0x20D0, 0x20F0, NSM),
+  (0x2100, 0x2101, ON),
+  (0x2103, 0x2106, ON),
+  (0x2108, 0x2109, ON),
+  (0x2114, 0x2114, ON),
+  (0x2116, 0x2118, ON),
+  (0x211E, 0x2123, ON),
+  (0x2125, 0x2125, ON),
+  (0x2127, 0x2127, ON),
+  (0x2129, 0x2129, ON),
+  (0x212E, 0x212E, ET),
+  (0x213A, 0x213B, ON),
+  (0x2140, 0x2144, ON),
+  (0x214A, 0x214D, ON),
+  (0x2150, 0x215F, ON),
+  (0x2189, 0x218B, ON),
+  (0x2190, 0x2211, ON),
+  (0x2212, 0x2212, ES),
+  (0x2213, 0x2213, ET),
+  (0x2214, 0x2335, ON),
+  (0x237B, 0x2394, ON),
+  (0x2396, 0x2426, ON),
+  (0x2440, 0x244A, ON),
+  (0x2460, 0x2487, ON),
+  (0x2488, 0x249B, EN),
+  (0x24EA, 0x26AB, ON),
+  (0x26AD, 0x27FF, ON),
+  (0x2900, 0x2B73, ON),
+  (0x2B76, 0x2B95, ON),
+  (0x2B97, 0x2BFF, ON),
+  (0x2CE5, 0x2CEA, ON),
+  (0x2CEF, 0x2CF1, NSM),
+  (0x2CF9, 0x2CFF, ON),
+  (0x2D7F, 0x2D7F, NSM),
+  (0x2DE0, 0x2DFF, NSM),
+  (0x2E00, 0x2E52, ON),
+  (0x2E80, 0x2E99, ON),
+  (0x2E9B, 0x2EF3, ON),
+  (0x2F00, 0x2FD5, ON),
+  (0x2FF0, 0x2FFB, ON),
+  (0x3000, 0x3000, Other),
+  (0x3001, 0x3004, ON),
+  (0x3008, 0x3020, ON),
+  (0x302A, 0x302D, NSM),
+  (0x3030, 0x3030, ON),
+  (0x3036, 0x3037, ON),
+  (0x303D, 0x303F, ON),
+  (0x3099, 0x309A, NSM),
+  (0x309B, 0x309C, ON),
+  (0x30A0, 0x30A0, ON),
+  (0x30FB, 0x30FB, ON),
+  (0x31C0, 0x31E3, ON),
+  (0x321D, 0x321E, ON),
+  (0x3250, 0x325F, ON),
+  (0x327C, 0x327E, ON),
+  (0x32B1, 0x32BF, ON),
+  (0x32CC, 0x32CF, ON),
+  (0x3377, 0x337A, ON),
+  (0x33DE, 0x33DF, ON),
+  (0x33FF, 0x33FF, ON),
+  (0x4DC0, 0x4DFF, ON),
+  (0xA490, 0xA4C6, ON),
+  (0xA60D, 0xA60F, ON),
+  (0xA66F, 0xA672, NSM),
+  (0xA673, 0xA673, ON),
+  (0xA674, 0xA67D, NSM),
+  (0xA67E, 0xA67F, ON),
+  (0xA69E, 0xA69F, NSM),
+  (0xA6F0, 0xA6F1, NSM),
+  (0xA700, 0xA721, ON),
+  (0xA788, 0xA788, ON),
+  (0xA802, 0xA802, NSM),
+  (0xA806, 0xA806, NSM),
+  (0xA80B, 0xA80B, NSM),
+  (0xA825, 0xA826, NSM),
+  (0xA828, 0xA82B, ON),
+  (0xA82C, 0xA82C, NSM),
+  (0xA838, 0xA839, ET),
+  (0xA874, 0xA877, ON),
+  (0xA8C4, 0xA8C5, NSM),
+  (0xA8E0, 0xA8F1, NSM),
+  (0xA8FF, 0xA8FF, NSM),
+  (0xA926, 0xA92D, NSM),
+  (0xA947, 0xA951, NSM),
+  (0xA980, 0xA982, NSM),
+  (0xA9B3, 0xA9B3, NSM),
+  (0xA9B6, 0xA9B9, NSM),
+  (0xA9BC, 0xA9BD, NSM),
+  (0xA9E5, 0xA9E5, NSM),
+  (0xAA29, 0xAA2E, NSM),
+  (0xAA31, 0xAA32, NSM),
+  (0xAA35, 0xAA36, NSM),
+  (0xAA43, 0xAA43, NSM),
+  (0xAA4C, 0xAA4C, NSM),
+  (0xAA7C, 0xAA7C, NSM),
+  (0xAAB0, 0xAAB0, NSM),
+  (0xAAB2, 0xAAB4, NSM),
+  (0xAAB7, 0xAAB8, NSM),
+  (0xAABE, 0xAABF, NSM),
+  (0xAAC1, 0xAAC1, NSM),
+  (0xAAEC, 0xAAED, NSM),
+  (0xAAF6, 0xAAF6, NSM),
+  (0xAB6A, 0xAB6B, ON),
+  (0xABE5, 0xABE5, NSM),
+  (0xABE8, 0xABE8, NSM),
+  (0xABED, 0xABED, NSM),
+  (0xFB1D, 0xFB1D, R),
+  (0xFB1E, 0xFB1E, NSM),
+  (0xFB1F, 0xFB28, R),
+  (0xFB29, 0xFB29, ES),
+  (0xFB2A, 0xFB36, R),
+  (0xFB38, 0xFB3C, R),
+  (0xFB3E, 0xFB3E, R),
+  (0xFB40, 0xFB41, R),
+  (0xFB43, 0xFB44, R),
+  (0xFB46, 0xFB4F, R),
+  (0xFB50, 0xFBC1, AL),
+  (0xFBD3, 0xFD3D, AL),
+  (0xFD3E, 0xFD3F, ON),
+  (0xFD50, 0xFD8F, AL),
+  (0xFD92, 0xFDC7, AL),
+  (0xFDF0, 0xFDFC, AL),
+  (0xFDFD, 0xFDFD, ON),
+  (0xFE00, 0xFE0F, NSM),
+  (0xFE10, 0xFE19, ON),
+  (0xFE20, 0xFE2F, NSM),
+  (0xFE30, 0xFE4F, ON),
+  (0xFE50, 0xFE50, CS),
+  (0xFE51, 0xFE51, ON),
+  (0xFE52, 0xFE52, CS),
+  (0xFE54, 0xFE54, ON),
+  (0xFE55, 0xFE55, CS),
+  (0xFE56, 0xFE5E, ON),
+  (0xFE5F, 0xFE5F, ET),
+  (0xFE60, 0xFE61, ON),
+  (0xFE62, 0xFE63, ES),
+  (0xFE64, 0xFE66, ON),
+  (0xFE68, 0xFE68, ON),
+  (0xFE69, 0xFE6A, ET),
+  (0xFE6B, 0xFE6B, ON),
+  (0xFE70, 0xFE74, AL),
+  (0xFE76, 0xFEFC, AL),
+  (0xFEFF, 0xFEFF, BN),
+  (0xFF01, 0xFF02, ON),
+  (0xFF03, 0xFF05, ET),
+  (0xFF06, 0xFF0A, ON),
+  (0xFF0B, 0xFF0B, ES),
+  (0xFF0C, 0xFF0C, CS),
+  (0xFF0D, 0xFF0D, ES),
+  (0xFF0E, 0xFF0F, CS),
+  (0xFF10, 0xFF19, EN),
+  (0xFF1A, 0xFF1A, CS),
+  (0xFF1B, 0xFF20, ON),
+  (0xFF3B, 0xFF40, ON),
+  (0xFF5B, 0xFF65, ON),
+  (0xFFE0, 0xFFE1, ET),
+  (0xFFE2, 0xFFE4, ON),
+  (0xFFE5, 0xFFE6, ET),
+  (0xFFE8, 0xFFEE, ON),
+  (0xFFF9, 0xFFFD, ON),
+  (0x10101, 0x10101, ON),
+  (0x10140, 0x1018C, ON),
+  (0x10190, 0x1019C, ON),
+  (0x101A0, 0x101A0, ON),
+  (0x101FD, 0x101FD, NSM),
+  (0x102E0, 0x102E0, NSM),
+  (0x102E1, 0x102FB, EN),
+  (0x10376, 0x1037A, NSM),
+  (0x10800, 0x10805, R),
+  (0x10808, 0x10808, R),
+  (0x1080A, 0x10835, R),
+  (0x10837, 0x10838, R),
+  (0x1083C, 0x1083C, R),
+  (0x1083F, 0x10855, R),
+  (0x10857, 0x1089E, R),
+  (0x108A7, 0x108AF, R),
+  (0x108E0, 0x108F2, R),
+  (0x108F4, 0x108F5, R),
+  (0x108FB, 0x1091B, R),
+  (0x1091F, 0x1091F, ON),
+  (0x10920, 0x10939, R),
+  (0x1093F, 0x1093F, R),
+  (0x10980, 0x109B7, R),
+  (0x109BC, 0x109CF, R),
+  (0x109D2, 0x10A00, R),
+  (0x10A01, 0x10A03, NSM),
+  (0x10A05, 0x10A06, NSM),
+  (0x10A0C, 0x10A0F, NSM),
+  (0x10A10, 0x10A13, R),
+  (0x10A15, 0x10A17, R),
+  (0x10A19, 0x10A35, R),
+  (0x10A38, 0x10A3A, NSM),
+  (0x10A3F, 0x10A3F, NSM),
+  (0x10A40, 0x10A48, R),
+  (0x10A50, 0x10A58, R),
+  (0x10A60, 0x10A9F, R),
+  (0x10AC0, 0x10AE4, R),
+  (0x10AE5, 0x10AE6, NSM),
+  (0x10AEB, 0x10AF6, R),
+  (0x10B00, 0x10B35, R),
+  (0x10B39, 0x10B3F, ON),
+  (0x10B40, 0x10B55, R),
+  (0x10B58, 0x10B72, R),
+  (0x10B78, 0x10B91, R),
+  (0x10B99, 0x10B9C, R),
+  (0x10BA9, 0x10BAF, R),
+  (0x10C00, 0x10C48, R),
+  (0x10C80, 0x10CB2, R),
+  (0x10CC0, 0x10CF2, R),
+  (0x10CFA, 0x10CFF, R),
+  (0x10D00, 0x10D23, AL),
+  (0x10D24, 0x10D27, NSM),
+  (0x10D30, 0x10D39, AN),
+  (0x10E60, 0x10E7E, AN),
+  (0x10E80, 0x10EA9, R),
+  (0x10EAB, 0x10EAC, NSM),
+  (0x10EAD, 0x10EAD, R),
+  (0x10EB0, 0x10EB1, R),
+  (0x10F00, 0x10F27, R),
+  (0x10F30, 0x10F45, AL),
+  (0x10F46, 0x10F50, NSM),
+  (0x10F51, 0x10F59, AL),
+  (0x10FB0, 0x10FCB, R),
+  (0x10FE0, 0x10FF6, R),
+  (0x11001, 0x11001, NSM),
+  (0x11038, 0x11046, NSM),
+  (0x11052, 0x11065, ON),
+  (0x1107F, 0x11081, NSM),
+  (0x110B3, 0x110B6, NSM),
+  (0x110B9, 0x110BA, NSM),
+  (0x11100, 0x11102, NSM),
+  (0x11127, 0x1112B, NSM),
+  (0x1112D, 0x11134, NSM),
+  (0x11173, 0x11173, NSM),
+  (0x11180, 0x11181, NSM),
+  (0x111B6, 0x111BE, NSM),
+  (0x111C9, 0x111CC, NSM),
+  (0x111CF, 0x111CF, NSM),
+  (0x1122F, 0x11231, NSM),
+  (0x11234, 0x11234, NSM),
+  (0x11236, 0x11237, NSM),
+  (0x1123E, 0x1123E, NSM),
+  (0x112DF, 0x112DF, NSM),
+  (0x112E3, 0x112EA, NSM),
+  (0x11300, 0x11301, NSM),
+  (0x1133B, 0x1133C, NSM),
+  (0x11340, 0x11340, NSM),
+  (0x11366, 0x1136C, NSM),
+  (0x11370, 0x11374, NSM),
+  (0x11438, 0x1143F, NSM),
+  (0x11442, 0x11444, NSM),
+  (0x11446, 0x11446, NSM),
+  (0x1145E, 0x1145E, NSM),
+  (0x114B3, 0x114B8, NSM),
+  (0x114BA, 0x114BA, NSM),
+  (0x114BF, 0x114C0, NSM),
+  (0x114C2, 0x114C3, NSM),
+  (0x115B2, 0x115B5, NSM),
+  (0x115BC, 0x115BD, NSM),
+  (0x115BF, 0x115C0, NSM),
+  (0x115DC, 0x115DD, NSM),
+  (0x11633, 0x1163A, NSM),
+  (0x1163D, 0x1163D, NSM),
+  (0x1163F, 0x11640, NSM),
+  (0x11660, 0x1166C, ON),
+  (0x116AB, 0x116AB, NSM),
+  (0x116AD, 0x116AD, NSM),
+  (0x116B0, 0x116B5, NSM),
+  (0x116B7, 0x116B7, NSM),
+  (0x1171D, 0x1171F, NSM),
+  (0x11722, 0x11725, NSM),
+  (0x11727, 0x1172B, NSM),
+  (0x1182F, 0x11837, NSM),
+  (0x11839, 0x1183A, NSM),
+  (0x1193B, 0x1193C, NSM),
+  (0x1193E, 0x1193E, NSM),
+  (0x11943, 0x11943, NSM),
+  (0x119D4, 0x119D7, NSM),
+  (0x119DA, 0x119DB, NSM),
+  (0x119E0, 0x119E0, NSM),
+  (0x11A01, 0x11A06, NSM),
+  (0x11A09, 0x11A0A, NSM),
+  (0x11A33, 0x11A38, NSM),
+  (0x11A3B, 0x11A3E, NSM),
+  (0x11A47, 0x11A47, NSM),
+  (0x11A51, 0x11A56, NSM),
+  (0x11A59, 0x11A5B, NSM),
+  (0x11A8A, 0x11A96, NSM),
+  (0x11A98, 0x11A99, NSM),
+  (0x11C30, 0x11C36, NSM),
+  (0x11C38, 0x11C3D, NSM),
+  (0x11C92, 0x11CA7, NSM),
+  (0x11CAA, 0x11CB0, NSM),
+  (0x11CB2, 0x11CB3, NSM),
+  (0x11CB5, 0x11CB6, NSM),
+  (0x11D31, 0x11D36, NSM),
+  (0x11D3A, 0x11D3A, NSM),
+  (0x11D3C, 0x11D3D, NSM),
+  (0x11D3F, 0x11D45, NSM),
+  (0x11D47, 0x11D47, NSM),
+  (0x11D90, 0x11D91, NSM),
+  (0x11D95, 0x11D95, NSM),
+  (0x11D97, 0x11D97, NSM),
+  (0x11EF3, 0x11EF4, NSM),
+  (0x11FD5, 0x11FDC, ON),
+  (0x11FDD, 0x11FE0, ET),
+  (0x11FE1, 0x11FF1, ON),
+  (0x16AF0, 0x16AF4, NSM),
+  (0x16B30, 0x16B36, NSM),
+  (0x16F4F, 0x16F4F, NSM),
+  (0x16F8F, 0x16F92, NSM),
+  (0x16FE2, 0x16FE2, ON),
+  (0x16FE4, 0x16FE4, NSM),
+  (0x1BC9D, 0x1BC9E, NSM),
+  (0x1BCA0, 0x1BCA3, BN),
+  (0x1D167, 0x1D169, NSM),
+  (0x1D173, 0x1D17A, BN),
+  (0x1D17B, 0x1D182, NSM),
+  (0x1D185, 0x1D18B, NSM),
+  (0x1D1AA, 0x1D1AD, NSM),
+  (0x1D200, 0x1D241, ON),
+  (0x1D242, 0x1D244, NSM),
+  (0x1D245, 0x1D245, ON),
+  (0x1D300, 0x1D356, ON),
+  (0x1D6DB, 0x1D6DB, ON),
+  (0x1D715, 0x1D715, ON),
+  (0x1D74F, 0x1D74F, ON),
+  (0x1D789, 0x1D789, ON),
+  (0x1D7C3, 0x1D7C3, ON),
+  (0x1D7CE, 0x1D7FF, EN),
+  (0x1DA00, 0x1DA36, NSM),
+  (0x1DA3B, 0x1DA6C, NSM),
+  (0x1DA75, 0x1DA75, NSM),
+  (0x1DA84, 0x1DA84, NSM),
+  (0x1DA9B, 0x1DA9F, NSM),
+  (0x1DAA1, 0x1DAAF, NSM),
+  (0x1E000, 0x1E006, NSM),
+  (0x1E008, 0x1E018, NSM),
+  (0x1E01B, 0x1E021, NSM),
+  (0x1E023, 0x1E024, NSM),
+  (0x1E026, 0x1E02A, NSM),
+  (0x1E130, 0x1E136, NSM),
+  (0x1E2EC, 0x1E2EF, NSM),
+  (0x1E2FF, 0x1E2FF, ET),
+  (0x1E800, 0x1E8C4, R),
+  (0x1E8C7, 0x1E8CF, R),
+  (0x1E8D0, 0x1E8D6, NSM),
+  (0x1E900, 0x1E943, R),
+  (0x1E944, 0x1E94A, NSM),
+  (0x1E94B, 0x1E94B, R),
+  (0x1E950, 0x1E959, R),
+  (0x1E95E, 0x1E95F, R),
+  (0x1EC71, 0x1ECB4, AL),
+  (0x1ED01, 0x1ED3D, AL),
+  (0x1EE00, 0x1EE03, AL),
+  (0x1EE05, 0x1EE1F, AL),
+  (0x1EE21, 0x1EE22, AL),
+  (0x1EE24, 0x1EE24, AL),
+  (0x1EE27, 0x1EE27, AL),
+  (0x1EE29, 0x1EE32, AL),
+  (0x1EE34, 0x1EE37, AL),
+  (0x1EE39, 0x1EE39, AL),
+  (0x1EE3B, 0x1EE3B, AL),
+  (0x1EE42, 0x1EE42, AL),
+  (0x1EE47, 0x1EE47, AL),
+  (0x1EE49, 0x1EE49, AL),
+  (0x1EE4B, 0x1EE4B, AL),
+  (0x1EE4D, 0x1EE4F, AL),
+  (0x1EE51, 0x1EE52, AL),
+  (0x1EE54, 0x1EE54, AL),
+  (0x1EE57, 0x1EE57, AL),
+  (0x1EE59, 0x1EE59, AL),
+  (0x1EE5B, 0x1EE5B, AL),
+  (0x1EE5D, 0x1EE5D, AL),
+  (0x1EE5F, 0x1EE5F, AL),
+  (0x1EE61, 0x1EE62, AL),
+  (0x1EE64, 0x1EE64, AL),
+  (0x1EE67, 0x1EE6A, AL),
+  (0x1EE6C, 0x1EE72, AL),
+  (0x1EE74, 0x1EE77, AL),
+  (0x1EE79, 0x1EE7C, AL),
+  (0x1EE7E, 0x1EE7E, AL),
+  (0x1EE80, 0x1EE89, AL),
+  (0x1EE8B, 0x1EE9B, AL),
+  (0x1EEA1, 0x1EEA3, AL),
+  (0x1EEA5, 0x1EEA9, AL),
+  (0x1EEAB, 0x1EEBB, AL),
+  (0x1EEF0, 0x1EEF1, ON),
+  (0x1F000, 0x1F02B, ON),
+  (0x1F030, 0x1F093, ON),
+  (0x1F0A0, 0x1F0AE, ON),
+  (0x1F0B1, 0x1F0BF, ON),
+  (0x1F0C1, 0x1F0CF, ON),
+  (0x1F0D1, 0x1F0F5, ON),
+  (0x1F100, 0x1F10A, EN),
+  (0x1F10B, 0x1F10F, ON),
+  (0x1F12F, 0x1F12F, ON),
+  (0x1F16A, 0x1F16F, ON),
+  (0x1F1AD, 0x1F1AD, ON),
+  (0x1F260, 0x1F265, ON),
+  (0x1F300, 0x1F6D7, ON),
+  (0x1F6E0, 0x1F6EC, ON),
+  (0x1F6F0, 0x1F6FC, ON),
+  (0x1F700, 0x1F773, ON),
+  (0x1F780, 0x1F7D8, ON),
+  (0x1F7E0, 0x1F7EB, ON),
+  (0x1F800, 0x1F80B, ON),
+  (0x1F810, 0x1F847, ON),
+  (0x1F850, 0x1F859, ON),
+  (0x1F860, 0x1F887, ON),
+  (0x1F890, 0x1F8AD, ON),
+  (0x1F8B0, 0x1F8B1, ON),
+  (0x1F900, 0x1F978, ON),
+  (0x1F97A, 0x1F9CB, ON),
+  (0x1F9CD, 0x1FA53, ON),
+  (0x1FA60, 0x1FA6D, ON),
+  (0x1FA70, 0x1FA74, ON),
+  (0x1FA78, 0x1FA7A, ON),
+  (0x1FA80, 0x1FA86, ON),
+  (0x1FA90, 0x1FAA8, ON),
+  (0x1FAB0, 0x1FAB6, ON),
+  (0x1FAC0, 0x1FAC2, ON),
+  (0x1FAD0, 0x1FAD6, ON),
+  (0x1FB00, 0x1FB92, ON),
+  (0x1FB94, 0x1FBCA, ON),
+  (0x1FBF0, 0x1FBF9, EN),
+  (0xE0001, 0xE0001, BN),
+  (0xE0020, 0xE007F, BN),
+  (0xE0100, 0xE01EF, NSM),
+];
+
+/// The ranges of code points with a joining type other than `U`.
+pub(crate) const JOINING_TYPE: &[(u32, u32, JoiningType)] = &[
+  (0x00AD, 0x00AD, JoiningType::T),
+  (0x0300, 0x036F, JoiningType::T),
+  (0x0483, 0x0489, JoiningType::T),
+  (0x0591, 0x05BD, JoiningType::T),
+  (0x05BF, 0x05BF, JoiningType::T),
+  (0x05C1, 0x05C2, JoiningType::T),
+  (0x05C4, 0x05C5, JoiningType::T),
+  (0x05C7, 0x05C7, JoiningType::T),
+  (0x0610, 0x061A, JoiningType::T),
+  (0x061C, 0x061C, JoiningType::T),
+  (0x0620, 0x0620, JoiningType::D),
+  (0x0622, 0x0625, JoiningType::R),
+  (0x0626, 0x0626, JoiningType::D),
+  (0x0627, 0x0627, JoiningType::R),
+  (0x0628, 0x0628, JoiningType::D),
+  (0x0629, 0x0629, JoiningType::R),
+  (0x062A, 0x062E, JoiningType::D),
+  (0x062F, 0x0632, JoiningType::R),
+  (0x0633, 0x063F, JoiningType::D),
+  (0x0640, 0x0640, JoiningType::C),
+  (0x0641, 0x0647, JoiningType::D),
+  (0x0648, 0x0648, JoiningType::R),
+  (0x0649, 0x064A, JoiningType::D),
+  (0x064B, 0x065F, JoiningType::T),
+  (0x066E, 0x066F, JoiningType::D),
+  (0x0670, 0x0670, JoiningType::T),
+  (0x0671, 0x0673, JoiningType::R),
+  (0x0675, 0x0677, JoiningType::R),
+  (0x0678, 0x0687, JoiningType::D),
+  (0x0688, 0x0699, JoiningType::R),
+  (0x069A, 0x06BF, JoiningType::D),
+  (0x06C0, 0x06C0, JoiningType::R),
+  (0x06C1, 0x06C2, JoiningType::D),
+  (0x06C3, 0x06CB, JoiningType::R),
+  (0x06CC, 0x06CC, JoiningType::D),
+  (0x06CD, 0x06CD, JoiningType::R),
+  (0x06CE, 0x06CE, JoiningType::D),
+  (0x06CF, 0x06CF, JoiningType::R),
+  (0x06D0, 0x06D1, JoiningType::D),
+  (0x06D2, 0x06D3, JoiningType::R),
+  (0x06D5, 0x06D5, JoiningType::R),
+  (0x06D6, 0x06DC, JoiningType::T),
+  (0x06DF, 0x06E4, JoiningType::T),
+  (0x06E7, 0x06E8, JoiningType::T),
+  (0x06EA, 0x06ED, JoiningType::T),
+  (0x06EE, 0x06EF, JoiningType::R),
+  (0x06FA, 0x06FC, JoiningType::D),
+  (0x06FF, 0x06FF, JoiningType::D),
+  (0x070F, 0x070F, JoiningType::T),
+  (0x0710, 0x0710, JoiningType::R),
+  (0x0711, 0x0711, JoiningType::T),
+  (0x0712, 0x0714, JoiningType::D),
+  (0x0715, 0x0719, JoiningType::R),
+  (0x071A, 0x071D, JoiningType::D),
+  (0x071E, 0x071E, JoiningType::R),
+  (0x071F, 0x0727, JoiningType::D),
+  (0x0728, 0x0728, JoiningType::R),
+  (0x0729, 0x0729, JoiningType::D),
+  (0x072A, 0x072A, JoiningType::R),
+  (0x072B, 0x072B, JoiningType::D),
+  (0x072C, 0x072C, JoiningType::R),
+  (0x072D, 0x072E, JoiningType::D),
+  (0x072F, 0x072F, JoiningType::R),
+  (0x0730, 0x074A, JoiningType::T),
+  (0x074D, 0x074D, JoiningType::R),
+  (0x074E, 0x0758, JoiningType::D),
+  (0x0759, 0x075B, JoiningType::R),
+  (0x075C, 0x076A, JoiningType::D),
+  (0x076B, 0x076C, JoiningType::R),
+  (0x076D, 0x0770, JoiningType::D),
+  (0x0771, 0x0771, JoiningType::R),
+  (0x0772, 0x0772, JoiningType::D),
+  (0x0773, 0x0774, JoiningType::R),
+  (0x0775, 0x0777, JoiningType::D),
+  (0x0778, 0x0779, JoiningType::R),
+  (0x077A, 0x077F, JoiningType::D),
+  (0x07A6, 0x07B0, JoiningType::T),
+  (0x07CA, 0x07EA, JoiningType::D),
+  (0x07EB, 0x07F3, JoiningType::T),
+  (0x07FA, 0x07FA, JoiningType::C),
+  (0x07FD, 0x07FD, JoiningType::T),
+  (0x0816, 0x0819, JoiningType::T),
+  (0x081B, 0x0823, JoiningType::T),
+  (0x0825, 0x0827, JoiningType::T),
+  (0x0829, 0x082D, JoiningType::T),
+  (0x0840, 0x0840, JoiningType::R),
+  (0x0841, 0x0845, JoiningType::D),
+  (0x0846, 0x0847, JoiningType::R),
+  (0x0848, 0x0848, JoiningType::D),
+  (0x0849, 0x0849, JoiningType::R),
+  (0x084A, 0x0853, JoiningType::D),
+  (0x0854, 0x0854, JoiningType::R),
+  (0x0855, 0x0855, JoiningType::D),
+  (0x0856, 0x0858, JoiningType::R),
+  (0x0859, 0x085B, JoiningType::T),
+  (0x0860, 0x0860, JoiningType::D),
+  (0x0862, 0x0865, JoiningType::D),
+  (0x0867, 0x0867, JoiningType::R),
+  (0x0868, 0x0868, JoiningType::D),
+  (0x0869, 0x086A, JoiningType::R),
+  (0x08A0, 0x08A9, JoiningType::D),
+  (0x08AA, 0x08AC, JoiningType::R),
+  (0x08AE, 0x08AE, JoiningType::R),
+  (0x08AF, 0x08B0, JoiningType::D),
+  (0x08B1, 0x08B2, JoiningType::R),
+  (0x08B3, 0x08B4, JoiningType::D),
+  (0x08B6, 0x08B8, JoiningType::D),
+  (0x08B9, 0x08B9, JoiningType::R),
+  (0x08BA, 0x08C7, JoiningType::D),
+  (0x08D3, 0x08E1, JoiningType::T),
+  (0x08E3, 0x0902, JoiningType::T),
+  (0x093A, 0x093A, JoiningType::T),
+  (0x093C, 0x093C, JoiningType::T),
+  (0x0941, 0x0948, JoiningType::T),
+  (0x094D, 0x094D, JoiningType::T),
+  (0x0951, 0x0957, JoiningType::T),
+  (0x0962, 0x0963, JoiningType::T),
+  (0x0981, 0x0981, JoiningType::T),
+  (0x09BC, 0x09BC, JoiningType::T),
+  (0x09C1, 0x09C4, JoiningType::T),
+  (0x09CD, 0x09CD, JoiningType::T),
+  (0x09E2, 0x09E3, JoiningType::T),
+  (0x09FE, 0x09FE, JoiningType::T),
+  (0x0A01, 0x0A02, JoiningType::T),
+  (0x0A3C, 0x0A3C, JoiningType::T),
+  (0x0A41, 0x0A42, JoiningType::T),
+  (0x0A47, 0x0A48, JoiningType::T),
+  (0x0A4B, 0x0A4D, JoiningType::T),
+  (0x0A51, 0x0A51, JoiningType::T),
+  (0x0A70, 0x0A71, JoiningType::T),
+  (0x0A75, 0x0A75, JoiningType::T),
+  (0x0A81, 0x0A82, JoiningType::T),
+  (0x0ABC, 0x0ABC, JoiningType::T),
+  (0x0AC1, 0x0AC5, JoiningType::T),
+  (0x0AC7, 0x0AC8, JoiningType::T),
+  (0x0ACD, 0x0ACD, JoiningType::T),
+  (0x0AE2, 0x0AE3, JoiningType::T),
+  (0x0AFA, 0x0AFF, JoiningType::T),
+  (0x0B01, 0x0B01, JoiningType::T),
+  (0x0B3C, 0x0B3C, JoiningType::T),
+  (0x0B3F, 0x0B3F, JoiningType::T),
+  (0x0B41, 0x0B44, JoiningType::T),
+  (0x0B4D, 0x0B4D, JoiningType::T),
+  (0x0B55, 0x0B56, JoiningType::T),
+  (0x0B62, 0x0B63, JoiningType::T),
+  (0x0B82, 0x0B82, JoiningType::T),
+  (0x0BC0, 0x0BC0, JoiningType::T),
+  (0x0BCD, 0x0BCD, JoiningType::T),
+  (0x0C00, 0x0C00, JoiningType::T),
+  (0x0C04, 0x0C04, JoiningType::T),
+  (0x0C3E, 0x0C40, JoiningType::T),
+  (0x0C46, 0x0C48, JoiningType::T),
+  (0x0C4A, 0x0C4D, JoiningType::T),
+  (0x0C55, 0x0C56, JoiningType::T),
+  (0x0C62, 0x0C63, JoiningType::T),
+  (0x0C81, 0x0C81, JoiningType::T),
+  (0x0CBC, 0x0CBC, JoiningType::T),
+  (0x0CBF, 0x0CBF, JoiningType::T),
+  (0x0CC6, 0x0CC6, JoiningType::T),
+  (0x0CCC, 0x0CCD, JoiningType::T),
+  (0x0CE2, 0x0CE3, JoiningType::T),
+  (0x0D00, 0x0D01, JoiningType::T),
+  (0x0D3B, 0x0D3C, JoiningType::T),
+  (0x0D41, 0x0D44, JoiningType::T),
+  (0x0D4D, 0x0D4D, JoiningType::T),
+  (0x0D62, 0x0D63, JoiningType::T),
+  (0x0D81, 0x0D81, JoiningType::T),
+  (0x0DCA, 0x0DCA, JoiningType::T),
+  (0x0DD2, 0x0DD4, JoiningType::T),
+  (0x0DD6, 0x0DD6, JoiningType::T),
+  (0x0E31, 0x0E31, JoiningType::T),
+  (0x0E34, 0x0E3A, JoiningType::T),
+  (0x0E47, 0x0E4E, JoiningType::T),
+  (0x0EB1, 0x0EB1, JoiningType::T),
+  (0x0EB4, 0x0EBC, JoiningType::T),
+  (0x0EC8, 0x0ECD, JoiningType::T),
+  (0x0F18, 0x0F19, JoiningType::T),
+  (0x0F35, 0x0F35, JoiningType::T),
+  (0x0F37, 0x0F37, JoiningType::T),
+  (0x0F39, 0x0F39, JoiningType::T),
+  (0x0F71, 0x0F7E, JoiningType::T),
+  (0x0F80, 0x0F84, JoiningType::T),
+  (0x0F86, 0x0F87, JoiningType::T),
+  (0x0F8D, 0x0F97, JoiningType::T),
+  (0x0F99, 0x0FBC, JoiningType::T),
+  (0x0FC6, 0x0FC6, JoiningType::T),
+  (0x102D, 0x1030, JoiningType::T),
+  (0x1032, 0x1037, JoiningType::T),
+  (0x1039, 0x103A, JoiningType::T),
+  (0x103D, 0x103E, JoiningType::T),
+  (0x1058, 0x1059, JoiningType::T),
+  (0x105E, 0x1060, JoiningType::T),
+  (0x1071, 0x1074, JoiningType::T),
+  (0x1082, 0x1082, JoiningType::T),
+  (0x1085, 0x1086, JoiningType::T),
+  (0x108D, 0x108D, JoiningType::T),
+  (0x109D, 0x109D, JoiningType::T),
+  (0x135D, 0x135F, JoiningType::T),
+  (0x1712, 0x1714, JoiningType::T),
+  (0x1732, 0x1733, JoiningType::T),
+  (0x1752, 0x1753, JoiningType::T),
+  (0x1772, 0x1773, JoiningType::T),
+  (0x17B4, 0x17B5, JoiningType::T),
+  (0x17B7, 0x17BD, JoiningType::T),
+  (0x17C6, 0x17C6, JoiningType::T),
+  (0x17C9, 0x17D3, JoiningType::T),
+  (0x17DD, 0x17DD, JoiningType::T),
+  (0x1807, 0x1807, JoiningType::D),
+  (0x180A, 0x180A, JoiningType::C),
+  (0x180B, 0x180D, JoiningType::T),
+  (0x1820, 0x1878, JoiningType::D),
+  (0x1885, 0x1886, JoiningType::T),
+  (0x1887, 0x18A8, JoiningType::D),
+  (0x18A9, 0x18A9, JoiningType::T),
+  (0x18AA, 0x18AA, JoiningType::D),
+  (0x1920, 0x1922, JoiningType::T),
+  (0x1927, 0x1928, JoiningType::T),
+  (0x1932, 0x1932, JoiningType::T),
+  (0x1939, 0x193B, JoiningType::T),
+  (0x1A17, 0x1A18, JoiningType::T),
+  (0x1A1B, 0x1A1B, JoiningType::T),
+  (0x1A56, 0x1A56, JoiningType::T),
+  (0x1A58, 0x1A5E, JoiningType::T),
+  (0x1A60, 0x1A60, JoiningType::T),
+  (0x1A62, 0x1A62, JoiningType::T),
+  (0x1A65, 0x1A6C, JoiningType::T),
+  (0x1A73, 0x1A7C, JoiningType::T),
+  (0x1A7F, 0x1A7F, JoiningType::T),
+  (0x1AB0, 0x1AC0, JoiningType::T),
+  (0x1B00, 0x1B03, JoiningType::T),
+  (0x1B34, 0x1B34, JoiningType::T),
+  (0x1B36, 0x1B3A, JoiningType::T),
+  (0x1B3C, 0x1B3C, JoiningType::T),
+  (0x1B42, 0x1B42, JoiningType::T),
+  (0x1B6B, 0x1B73, JoiningType::T),
+  (0x1B80, 0x1B81, JoiningType::T),
+  (0x1BA2, 0x1BA5, JoiningType::T),
+  (0x1BA8, 0x1BA9, JoiningType::T),
+  (0x1BAB, 0x1BAD, JoiningType::T),
+  (0x1BE6, 0x1BE6, JoiningType::T),
+  (0x1BE8, 0x1BE9, JoiningType::T),
+  (0x1BED, 0x1BED, JoiningType::T),
+  (0x1BEF, 0x1BF1, JoiningType::T),
+  (0x1C2C, 0x1C33, JoiningType::T),
+  (0x1C36, 0x1C37, JoiningType::T),
+  (0x1CD0, 0x1CD2, JoiningType::T),
+  (0x1CD4, 0x1CE0, JoiningType::T),
+  (0x1CE2, 0x1CE8, JoiningType::T),
+  (0x1CED, 0x1CED, JoiningType::T),
+  (0x1CF4, 0x1CF4, JoiningType::T),
+  (0x1CF8, 0x1CF9, JoiningType::T),
+  (0x1DC0, 0x1DF9, JoiningType::T),
+  (0x1DFB, 0x1DFF, JoiningType::T),
+  (0x200B, 0x200B, JoiningType::T),
+  (0x200D, 0x200D, JoiningType::C),
+  (0x200E, 0x200F, JoiningType::T),
+  (0x202A, 0x202E, JoiningType::T),
+  (0x2060, 0x2064, JoiningType::T),
+  (0x206A, 0x206F, JoiningType::T),
+  (0x20D0, 0x20F0, JoiningType::T),
+  (0x2CEF, 0x2CF1, JoiningType::T),
+  (0x2D7F, 0x2D7F, JoiningType::T),
+  (0x2DE0, 0x2DFF, JoiningType::T),
+  (0x302A, 0x302D, JoiningType::T),
+  (0x3099, 0x309A, JoiningType::T),
+  (0xA66F, 0xA672, JoiningType::T),
+  (0xA674, 0xA67D, JoiningType::T),
+  (0xA69E, 0xA69F, JoiningType::T),
+  (0xA6F0, 0xA6F1, JoiningType::T),
+  (0xA802, 0xA802, JoiningType::T),
+  (0xA806, 0xA806, JoiningType::T),
+  (0xA80B, 0xA80B, JoiningType::T),
+  (0xA825, 0xA826, JoiningType::T),
+  (0xA82C, 0xA82C, JoiningType::T),
+  (0xA840, 0xA871, JoiningType::D),
+  (0xA872, 0xA872, JoiningType::L),
+  (0xA8C4, 0xA8C5, JoiningType::T),
+  (0xA8E0, 0xA8F1, JoiningType::T),
+  (0xA8FF, 0xA8FF, JoiningType::T),
+  (0xA926, 0xA92D, JoiningType::T),
+  (0xA947, 0xA951, JoiningType::T),
+  (0xA980, 0xA982, JoiningType::T),
+  (0xA9B3, 0xA9B3, JoiningType::T),
+  (0xA9B6, 0xA9B9, JoiningType::T),
+  (0xA9BC, 0xA9BD, JoiningType::T),
+  (0xA9E5, 0xA9E5, JoiningType::T),
+  (0xAA29, 0xAA2E, JoiningType::T),
+  (0xAA31, 0xAA32, JoiningType::T),
+  (0xAA35, 0xAA36, JoiningType::T),
+  (0xAA43, 0xAA43, JoiningType::T),
+  (0xAA4C, 0xAA4C, JoiningType::T),
+  (0xAA7C, 0xAA7C, JoiningType::T),
+  (0xAAB0, 0xAAB0, JoiningType::T),
+  (0xAAB2, 0xAAB4, JoiningType::T),
+  (0xAAB7, 0xAAB8, JoiningType::T),
+  (0xAABE, 0xAABF, JoiningType::T),
+  (0xAAC1, 0xAAC1, JoiningType::T),
+  (0xAAEC, 0xAAED, JoiningType::T),
+  (0xAAF6, 0xAAF6, JoiningType::T),
+  (0xABE5, 0xABE5, JoiningType::T),
+  (0xABE8, 0xABE8, JoiningType::T),
+  (0xABED, 0xABED, JoiningType::T),
+  (0xFB1E, 0xFB1E, JoiningType::T),
+  (0xFE00, 0xFE0F, JoiningType::T),
+  (0xFE20, 0xFE2F, JoiningType::T),
+  (0xFEFF, 0xFEFF, JoiningType::T),
+  (0xFFF9, 0xFFFB, JoiningType::T),
+  (0x101FD, 0x101FD, JoiningType::T),
+  (0x102E0, 0x102E0, JoiningType::T),
+  (0x10376, 0x1037A, JoiningType::T),
+  (0x10A01, 0x10A03, JoiningType::T),
+  (0x10A05, 0x10A06, JoiningType::T),
+  (0x10A0C, 0x10A0F, JoiningType::T),
+  (0x10A38, 0x10A3A, JoiningType::T),
+  (0x10A3F, 0x10A3F, JoiningType::T),
+  (0x10AC0, 0x10AC4, JoiningType::D),
+  (0x10AC5, 0x10AC5, JoiningType::R),
+  (0x10AC7, 0x10AC7, JoiningType::R),
+  (0x10AC9, 0x10ACA, JoiningType::R),
+  (0x10ACD, 0x10ACD, JoiningType::L),
+  (0x10ACE, 0x10AD2, JoiningType::R),
+  (0x10AD3, 0x10AD6, JoiningType::D),
+  (0x10AD7, 0x10AD7, JoiningType::L),
+  (0x10AD8, 0x10ADC, JoiningType::D),
+  (0x10ADD, 0x10ADD, JoiningType::R),
+  (0x10ADE, 0x10AE0, JoiningType::D),
+  (0x10AE1, 0x10AE1, JoiningType::R),
+  (0x10AE4, 0x10AE4, JoiningType::R),
+  (0x10AE5, 0x10AE6, JoiningType::T),
+  (0x10AEB, 0x10AEE, JoiningType::D),
+  (0x10AEF, 0x10AEF, JoiningType::R),
+  (0x10B80, 0x10B80, JoiningType::D),
+  (0x10B81, 0x10B81, JoiningType::R),
+  (0x10B82, 0x10B82, JoiningType::D),
+  (0x10B83, 0x10B85, JoiningType::R),
+  (0x10B86, 0x10B88, JoiningType::D),
+  (0x10B89, 0x10B89, JoiningType::R),
+  (0x10B8A, 0x10B8B, JoiningType::D),
+  (0x10B8C, 0x10B8C, JoiningType::R),
+  (0x10B8D, 0x10B8D, JoiningType::D),
+  (0x10B8E, 0x10B8F, JoiningType::R),
+  (0x10B90, 0x10B90, JoiningType::D),
+  (0x10B91, 0x10B91, JoiningType::R),
+  (0x10BA9, 0x10BAC, JoiningType::R),
+  (0x10BAD, 0x10BAE, JoiningType::D),
+  (0x10D00, 0x10D00, JoiningType::L),
+  (0x10D01, 0x10D21, JoiningType::D),
+  (0x10D22, 0x10D22, JoiningType::R),
+  (0x10D23, 0x10D23, JoiningType::D),
+  (0x10D24, 0x10D27, JoiningType::T),
+  (0x10EAB, 0x10EAC, JoiningType::T),
+  (0x10F30, 0x10F32, JoiningType::D),
+  (0x10F33, 0x10F33, JoiningType::R),
+  (0x10F34, 0x10F44, JoiningType::D),
+  (0x10F46, 0x10F50, JoiningType::T),
+  (0x10F51, 0x10F53, JoiningType::D),
+  (0x10F54, 0x10F54, JoiningType::R),
+  (0x10FB0, 0x10FB0, JoiningType::D),
+  (0x10FB2, 0x10FB3, JoiningType::D),
+  (0x10FB4, 0x10FB6, JoiningType::R),
+  (0x10FB8, 0x10FB8, JoiningType::D),
+  (0x10FB9, 0x10FBA, JoiningType::R),
+  (0x10FBB, 0x10FBC, JoiningType::D),
+  (0x10FBD, 0x10FBD, JoiningType::R),
+  (0x10FBE, 0x10FBF, JoiningType::D),
+  (0x10FC1, 0x10FC1, JoiningType::D),
+  (0x10FC2, 0x10FC3, JoiningType::R),
+  (0x10FC4, 0x10FC4, JoiningType::D),
+  (0x10FC9, 0x10FC9, JoiningType::R),
+  (0x10FCA, 0x10FCA, JoiningType::D),
+  (0x10FCB, 0x10FCB, JoiningType::L),
+  (0x11001, 0x11001, JoiningType::T),
+  (0x11038, 0x11046, JoiningType::T),
+  (0x1107F, 0x11081, JoiningType::T),
+  (0x110B3, 0x110B6, JoiningType::T),
+  (0x110B9, 0x110BA, JoiningType::T),
+  (0x11100, 0x11102, JoiningType::T),
+  (0x11127, 0x1112B, JoiningType::T),
+  (0x1112D, 0x11134, JoiningType::T),
+  (0x11173, 0x11173, JoiningType::T),
+  (0x11180, 0x11181, JoiningType::T),
+  (0x111B6, 0x111BE, JoiningType::T),
+  (0x111C9, 0x111CC, JoiningType::T),
+  (0x111CF, 0x111CF, JoiningType::T),
+  (0x1122F, 0x11231, JoiningType::T),
+  (0x11234, 0x11234, JoiningType::T),
+  (0x11236, 0x11237, JoiningType::T),
+  (0x1123E, 0x1123E, JoiningType::T),
+  (0x112DF, 0x112DF, JoiningType::T),
+  (0x112E3, 0x112EA, JoiningType::T),
+  (0x11300, 0x11301, JoiningType::T),
+  (0x1133B, 0x1133C, JoiningType::T),
+  (0x11340, 0x11340, JoiningType::T),
+  (0x11366, 0x1136C, JoiningType::T),
+  (0x11370, 0x11374, JoiningType::T),
+  (0x11438, 0x1143F, JoiningType::T),
+  (0x11442, 0x11444, JoiningType::T),
+  (0x11446, 0x11446, JoiningType::T),
+  (0x1145E, 0x1145E, JoiningType::T),
+  (0x114B3, 0x114B8, JoiningType::T),
+  (0x114BA, 0x114BA, JoiningType::T),
+  (0x114BF, 0x114C0, JoiningType::T),
+  (0x114C2, 0x114C3, JoiningType::T),
+  (0x115B2, 0x115B5, JoiningType::T),
+  (0x115BC, 0x115BD, JoiningType::T),
+  (0x115BF, 0x115C0, JoiningType::T),
+  (0x115DC, 0x115DD, JoiningType::T),
+  (0x11633, 0x1163A, JoiningType::T),
+  (0x1163D, 0x1163D, JoiningType::T),
+  (0x1163F, 0x11640, JoiningType::T),
+  (0x116AB, 0x116AB, JoiningType::T),
+  (0x116AD, 0x116AD, JoiningType::T),
+  (0x116B0, 0x116B5, JoiningType::T),
+  (0x116B7, 0x116B7, JoiningType::T),
+  (0x1171D, 0x1171F, JoiningType::T),
+  (0x11722, 0x11725, JoiningType::T),
+  (0x11727, 0x1172B, JoiningType::T),
+  (0x1182F, 0x11837, JoiningType::T),
+  (0x11839, 0x1183A, JoiningType::T),
+  (0x1193B, 0x1193C, JoiningType::T),
+  (0x1193E, 0x1193E, JoiningType::T),
+  (0x11943, 0x11943, JoiningType::T),
+  (0x119D4, 0x119D7, JoiningType::T),
+  (0x119DA, 0x119DB, JoiningType::T),
+  (0x119E0, 0x119E0, JoiningType::T),
+  (0x11A01, 0x11A0A, JoiningType::T),
+  (0x11A33, 0x11A38, JoiningType::T),
+  (0x11A3B, 0x11A3E, JoiningType::T),
+  (0x11A47, 0x11A47, JoiningType::T),
+  (0x11A51, 0x11A56, JoiningType::T),
+  (0x11A59, 0x11A5B, JoiningType::T),
+  (0x11A8A, 0x11A96, JoiningType::T),
+  (0x11A98, 0x11A99, JoiningType::T),
+  (0x11C30, 0x11C36, JoiningType::T),
+  (0x11C38, 0x11C3D, JoiningType::T),
+  (0x11C3F, 0x11C3F, JoiningType::T),
+  (0x11C92, 0x11CA7, JoiningType::T),
+  (0x11CAA, 0x11CB0, JoiningType::T),
+  (0x11CB2, 0x11CB3, JoiningType::T),
+  (0x11CB5, 0x11CB6, JoiningType::T),
+  (0x11D31, 0x11D36, JoiningType::T),
+  (0x11D3A, 0x11D3A, JoiningType::T),
+  (0x11D3C, 0x11D3D, JoiningType::T),
+  (0x11D3F, 0x11D45, JoiningType::T),
+  (0x11D47, 0x11D47, JoiningType::T),
+  (0x11D90, 0x11D91, JoiningType::T),
+  (0x11D95, 0x11D95, JoiningType::T),
+  (0x11D97, 0x11D97, JoiningType::T),
+  (0x11EF3, 0x11EF4, JoiningType::T),
+  (0x13430, 0x13438, JoiningType::T),
+  (0x16AF0, 0x16AF4, JoiningType::T),
+  (0x16B30, 0x16B36, JoiningType::T),
+  (0x16F4F, 0x16F4F, JoiningType::T),
+  (0x16F8F, 0x16F92, JoiningType::T),
+  (0x16FE4, 0x16FE4, JoiningType::T),
+  (0x1BC9D, 0x1BC9E, JoiningType::T),
+  (0x1BCA0, 0x1BCA3, JoiningType::T),
+  (0x1D167, 0x1D169, JoiningType::T),
+  (0x1D173, 0x1D182, JoiningType::T),
+  (0x1D185, 0x1D18B, JoiningType::T),
+  (0x1D1AA, 0x1D1AD, JoiningType::T),
+  (0x1D242, 0x1D244, JoiningType::T),
+  (0x1DA00, 0x1DA36, JoiningType::T),
+  (0x1DA3B, 0x1DA6C, JoiningType::T),
+  (0x1DA75, 0x1DA75, JoiningType::T),
+  (0x1DA84, 0x1DA84, JoiningType::T),
+  (0x1DA9B, 0x1DA9F, JoiningType::T),
+  (0x1DAA1, 0x1DAAF, JoiningType::T),
+  (0x1E000, 0x1E006, JoiningType::T),
+  (0x1E008, 0x1E018, JoiningType::T),
+  (0x1E01B, 0x1E021, JoiningType::T),
+  (0x1E023, 0x1E024, JoiningType::T),
+  (0x1E026, 0x1E02A, JoiningType::T),
+  (0x1E130, 0x1E136, JoiningType::T),
+  (0x1E2EC, 0x1E2EF, JoiningType::T),
+  (0x1E8D0, 0x1E8D6, JoiningType::T),
+  (0x1E900, 0x1E943, JoiningType::D),
+  (0x1E944, 0x1E94B, JoiningType::T),
+  (0xE0001, 0xE0001, JoiningType::T),
+  (0xE0020, 0xE007F, JoiningType::T),
+  (0xE0100, 0xE01EF, JoiningType::T),
+];
@@ -3,9 +3,29 @@
 
 /// Base64 utilities.
 pub mod base64;
+/// `Content-Disposition` and RFC 8187 ext-value utilities.
+pub mod content_disposition;
+/// HTTP cookie utilities.
+pub mod cookie;
 /// Global JavaScript functions.
 pub mod globals;
+/// Hexadecimal utilities.
+pub mod hex;
+/// Punycode and IDNA (UTS #46) utilities.
+pub mod idna;
 /// Number related utilities.
 pub mod number;
+/// Nested query string utilities, like the `qs` package.
+pub mod qs;
 /// A fast pseudorandom number generator.
 pub mod rng;
+/// Destinations that codecs write to.
+pub mod sink;
+/// `Uint8Array` base64 and hexadecimal utilities.
+pub mod uint8array;
+/// RFC 3986 URI utilities.
+pub mod uri;
+/// WHATWG URL utilities.
+pub mod url;
+/// UTF-7 and IMAP modified UTF-7 utilities.
+pub mod utf7;
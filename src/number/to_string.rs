@@ -1,5 +1,6 @@
 use crate::number::radii::{BINARY_RADIX, HEXATRIDECIMAL_RADIX};
 use crate::number::{from_character_byte, next_floating_point, BASE_36_LUT};
+use crate::sink::{push_ascii, Sink};
 
 use std::mem::MaybeUninit;
 
@@ -39,6 +40,9 @@ const DEFAULT_BUFFER_SIZE: usize = 2200;
 /// ```
 ///
 /// # Notes
+/// The output can be any [`Sink`], which is why the sink is an `impl Trait` argument, so that only
+/// the radix needs to be given explicitly.
+///
 /// This function uses a static assertion and will fail at compile time if the radix
 /// is not within [2, 36].
 ///
@@ -46,17 +50,17 @@ const DEFAULT_BUFFER_SIZE: usize = 2200;
 /// [Number.prototype.toString](https://tc39.es/ecma262/#sec-number.prototype.tostring)
 /// and uses a similar implementation as
 /// [v8's](https://github.com/v8/v8/blob/master/src/numbers/conversions.cc#L1269).
-pub fn to_string<const RADIX: u8>(num: f64, bytes: &mut Vec<u8>) {
+pub fn to_string<const RADIX: u8>(num: f64, bytes: &mut (impl Sink + ?Sized)) {
   static_assert!(RADIX: u8 where RADIX >= BINARY_RADIX && RADIX <= HEXATRIDECIMAL_RADIX);
 
   if num.is_nan() {
-    return bytes.extend_from_slice(b"NaN");
+    return push_ascii(bytes, b"NaN");
   }
 
   // IEEE-754 spec states that positive 0 and negative 0 should be equal, so we don't need to
   // explicitly check for -0.0
   if num == 0.0 {
-    return bytes.extend_from_slice(b"0");
+    return push_ascii(bytes, b"0");
   }
 
   let is_negative = num.is_sign_negative();
@@ -68,7 +72,7 @@ pub fn to_string<const RADIX: u8>(num: f64, bytes: &mut Vec<u8>) {
       b"Infinity"
     };
 
-    return bytes.extend_from_slice(inf_bytes);
+    return push_ascii(bytes, inf_bytes);
   }
 
   let abs_value = num.abs();
@@ -183,7 +187,7 @@ pub fn to_string<const RADIX: u8>(num: f64, bytes: &mut Vec<u8>) {
     // initialized.
     let set_slice = &*(temp_buffer.get_unchecked(count..end_count) as *const _ as *const [u8]);
 
-    bytes.extend_from_slice(set_slice);
+    push_ascii(bytes, set_slice);
   }
 }
 
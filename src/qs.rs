@@ -0,0 +1,479 @@
+use crate::globals::{decode_uri_component, encode_str, ENCODE_URI_COMPONENT_SET};
+use crate::sink::Sink;
+
+use std::collections::BTreeMap;
+
+/// A value of a nested query string.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Value {
+  /// A string, from a `name=value` pair.
+  String(String),
+  /// A list, from `name[]` or `name[0]` keys.
+  Array(Vec<Value>),
+  /// A map, from `name[key]` keys, which keeps the order keys are added in.
+  Object(Vec<(String, Value)>),
+}
+
+impl Value {
+  /// Returns the value of a key, if this is an object that has it.
+  pub fn get(&self, key: &str) -> Option<&Value> {
+    match self {
+      Value::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+      _ => None,
+    }
+  }
+
+  /// Returns the string, if this is one.
+  pub fn as_str(&self) -> Option<&str> {
+    match self {
+      Value::String(string) => Some(string),
+      _ => None,
+    }
+  }
+
+  /// Returns the values, if this is an array.
+  pub fn as_array(&self) -> Option<&[Value]> {
+    match self {
+      Value::Array(values) => Some(values),
+      _ => None,
+    }
+  }
+
+  /// Returns the entries, if this is an object.
+  pub fn as_object(&self) -> Option<&[(String, Value)]> {
+    match self {
+      Value::Object(entries) => Some(entries),
+      _ => None,
+    }
+  }
+}
+
+/// The options of [`parse`].
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ParseOptions {
+  /// The maximum number of bracketed segments of a key that are nested, where the rest of the key
+  /// is kept as a single literal segment.
+  pub depth: usize,
+  /// The maximum index of an array, where a larger index makes an object instead.
+  pub array_limit: usize,
+  /// The maximum number of pairs that are parsed, where the rest are ignored.
+  pub parameter_limit: usize,
+}
+
+impl Default for ParseOptions {
+  #[inline]
+  fn default() -> Self {
+    ParseOptions {
+      depth: 5,
+      array_limit: 20,
+      parameter_limit: 1000,
+    }
+  }
+}
+
+/// How arrays are written by [`stringify`].
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ArrayFormat {
+  /// `a[0]=b&a[1]=c`
+  Indices,
+  /// `a[]=b&a[]=c`
+  Brackets,
+  /// `a=b&a=c`
+  Repeat,
+}
+
+impl Default for ArrayFormat {
+  #[inline]
+  fn default() -> Self {
+    ArrayFormat::Indices
+  }
+}
+
+/// The options of [`stringify`].
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct StringifyOptions {
+  /// How arrays are written.
+  pub array_format: ArrayFormat,
+  /// Whether keys are written without encoding, so that their brackets stay readable.
+  pub encode_values_only: bool,
+}
+
+/// A value while it's parsed, where arrays can have holes until they're compacted.
+enum Node {
+  String(String),
+  Array(BTreeMap<usize, Node>),
+  Object(Vec<(String, Node)>),
+}
+
+/// Parses a query string to a nested object, ignoring a leading `?`.
+///
+/// # Notes
+/// This function follows the parser of the [qs](https://github.com/ljharb/qs) package, where
+/// `user[name]=a&user[tags][]=x&user[tags][]=y` parses to
+/// `{ user: { name: "a", tags: ["x", "y"] } }`. Keys and values are decoded with
+/// [`decode_uri_component`] after `+` is replaced with a space, and are kept as they are when
+/// that fails.
+///
+/// The limits of the options bound the work and memory that a query string can cause. An array
+/// never gets an index above `array_limit`, so an array that would is parsed as an object with its
+/// indices as keys.
+pub fn parse(string: &str, options: &ParseOptions) -> Value {
+  let string = string.strip_prefix('?').unwrap_or(string);
+  let mut pairs: Vec<(String, Node)> = Vec::new();
+
+  for part in string.split('&').take(options.parameter_limit) {
+    // A `]=` finds the end of keys like `a[b=c]=d`
+    let idx = match part.find("]=") {
+      Some(idx) => Some(idx + 1),
+      None => part.find('='),
+    };
+    let (key, value) = match idx {
+      Some(idx) => (decode(&part[..idx]), decode(&part[idx + 1..])),
+      None => (decode(part), String::new()),
+    };
+
+    if key.is_empty() {
+      continue;
+    }
+
+    match pairs.iter_mut().find(|(k, _)| *k == key) {
+      Some((_, existing)) => {
+        let combined = std::mem::replace(existing, Node::String(String::new()));
+
+        *existing = push(combined, Node::String(value));
+      }
+      None => pairs.push((key, Node::String(value))),
+    }
+  }
+
+  let mut object = Node::Object(Vec::new());
+
+  for (key, value) in pairs {
+    let chain = split_key(&key, options.depth);
+
+    object = merge(object, parse_chain(&chain, value, options));
+  }
+
+  compact(object, options)
+}
+
+/// Decodes a key or value, keeping it as it is when it's malformed.
+fn decode(string: &str) -> String {
+  let string = string.replace('+', " ");
+  let mut decoded = String::with_capacity(string.len());
+
+  match decode_uri_component(string.as_bytes(), &mut decoded) {
+    Ok(()) => decoded,
+    Err(_) => string,
+  }
+}
+
+/// A segment of a key.
+enum Segment<'a> {
+  /// The part before the first bracketed segment, or a bracketed segment past the depth and
+  /// everything after it, which are literal keys.
+  Literal(&'a str),
+  /// The inside of a bracketed segment, where `[]` appends and `[0]` is an array index.
+  Bracketed(&'a str),
+}
+
+/// Splits a key into its segments, where the segments after `depth` are kept together as one.
+fn split_key(key: &str, depth: usize) -> Vec<Segment<'_>> {
+  let mut chain = Vec::new();
+  let mut segment = if depth > 0 {
+    next_segment(key, 0)
+  } else {
+    None
+  };
+  let parent = match segment {
+    Some((start, _)) => &key[..start],
+    None => key,
+  };
+
+  if !parent.is_empty() {
+    chain.push(Segment::Literal(parent));
+  }
+
+  let mut count = 0;
+
+  while let Some((start, end)) = segment {
+    if count == depth {
+      chain.push(Segment::Literal(&key[start..]));
+      break;
+    }
+
+    chain.push(Segment::Bracketed(&key[start + 1..end - 1]));
+    count += 1;
+    segment = next_segment(key, end);
+  }
+
+  chain
+}
+
+/// Returns the range of the next `[...]` segment, which contains no brackets, from an index.
+fn next_segment(key: &str, from: usize) -> Option<(usize, usize)> {
+  let bytes = key.as_bytes();
+  let mut start = from + bytes[from..].iter().position(|&byte| byte == b'[')?;
+
+  loop {
+    let len = bytes[start + 1..]
+      .iter()
+      .position(|&byte| byte == b'[' || byte == b']')?;
+    let end = start + 1 + len;
+
+    if bytes[end] == b']' {
+      return Some((start, end + 1));
+    }
+
+    start = end;
+  }
+}
+
+/// Builds the nested value of a key's segments, from the innermost one outwards.
+fn parse_chain(chain: &[Segment<'_>], value: Node, options: &ParseOptions) -> Node {
+  let mut leaf = value;
+
+  for segment in chain.iter().rev() {
+    leaf = match *segment {
+      Segment::Bracketed("") => push(Node::Array(BTreeMap::new()), leaf),
+      Segment::Bracketed(key) => match parse_index(key) {
+        Some(index) if index <= options.array_limit => {
+          let mut values = BTreeMap::new();
+
+          values.insert(index, leaf);
+          Node::Array(values)
+        }
+        _ => Node::Object(vec![(key.to_owned(), leaf)]),
+      },
+      Segment::Literal(key) => Node::Object(vec![(key.to_owned(), leaf)]),
+    };
+  }
+
+  leaf
+}
+
+/// Parses a canonical array index, so `01` and `+1` are object keys.
+fn parse_index(key: &str) -> Option<usize> {
+  let index = key.parse::<usize>().ok()?;
+
+  if index.to_string() == key {
+    Some(index)
+  } else {
+    None
+  }
+}
+
+/// Appends a value to an array, or makes an array of both values.
+fn push(target: Node, source: Node) -> Node {
+  let mut values = match target {
+    Node::Array(values) => values,
+    target => {
+      let mut values = BTreeMap::new();
+
+      values.insert(0, target);
+      values
+    }
+  };
+
+  match source {
+    Node::Array(source) => {
+      for value in source.into_values() {
+        let len = array_len(&values);
+
+        values.insert(len, value);
+      }
+    }
+    source => {
+      let len = array_len(&values);
+
+      values.insert(len, source);
+    }
+  }
+
+  Node::Array(values)
+}
+
+fn array_len(values: &BTreeMap<usize, Node>) -> usize {
+  values.keys().next_back().map_or(0, |&idx| idx + 1)
+}
+
+/// Merges the value of a pair into the value of the pairs before it.
+fn merge(target: Node, source: Node) -> Node {
+  match (target, source) {
+    (Node::Array(mut target), Node::String(source)) => {
+      let len = array_len(&target);
+
+      target.insert(len, Node::String(source));
+      Node::Array(target)
+    }
+    // A string merged into an object is a key without a value, as in `a[b]=c&a=d`
+    (Node::Object(mut target), Node::String(source)) => {
+      if !target.iter().any(|(k, _)| *k == source) {
+        target.push((source, Node::String(String::new())));
+      }
+
+      Node::Object(target)
+    }
+    (target @ Node::String(_), source) => push(target, source),
+    (Node::Array(mut target), Node::Array(source)) => {
+      for (idx, value) in source {
+        match target.remove(&idx) {
+          // Only arrays and objects at the same index are merged
+          Some(existing) if !is_string(&existing) && !is_string(&value) => {
+            target.insert(idx, merge(existing, value));
+          }
+          Some(existing) => {
+            target.insert(idx, existing);
+
+            let len = array_len(&target);
+
+            target.insert(len, value);
+          }
+          None => {
+            target.insert(idx, value);
+          }
+        }
+      }
+
+      Node::Array(target)
+    }
+    (Node::Array(target), source) => merge(Node::Object(array_to_object(target)), source),
+    (Node::Object(mut target), Node::Array(source)) => {
+      merge_entries(&mut target, array_to_object(source));
+      Node::Object(target)
+    }
+    (Node::Object(mut target), Node::Object(source)) => {
+      merge_entries(&mut target, source);
+      Node::Object(target)
+    }
+  }
+}
+
+fn is_string(node: &Node) -> bool {
+  matches!(node, Node::String(_))
+}
+
+fn merge_entries(target: &mut Vec<(String, Node)>, source: Vec<(String, Node)>) {
+  for (key, value) in source {
+    match target.iter().position(|(k, _)| *k == key) {
+      Some(idx) => {
+        let existing = std::mem::replace(&mut target[idx].1, Node::String(String::new()));
+
+        target[idx].1 = merge(existing, value);
+      }
+      None => target.push((key, value)),
+    }
+  }
+}
+
+fn array_to_object(values: BTreeMap<usize, Node>) -> Vec<(String, Node)> {
+  values
+    .into_iter()
+    .map(|(idx, value)| (idx.to_string(), value))
+    .collect()
+}
+
+/// Removes the holes of arrays, turning arrays with an index above the limit into objects.
+fn compact(node: Node, options: &ParseOptions) -> Value {
+  match node {
+    Node::String(string) => Value::String(string),
+    Node::Array(values) if array_len(&values) > options.array_limit + 1 => Value::Object(
+      array_to_object(values)
+        .into_iter()
+        .map(|(key, value)| (key, compact(value, options)))
+        .collect(),
+    ),
+    Node::Array(values) => Value::Array(
+      values
+        .into_values()
+        .map(|value| compact(value, options))
+        .collect(),
+    ),
+    Node::Object(entries) => Value::Object(
+      entries
+        .into_iter()
+        .map(|(key, value)| (key, compact(value, options)))
+        .collect(),
+    ),
+  }
+}
+
+/// Serializes a nested object as a query string.
+///
+/// # Notes
+/// This function follows the serializer of the [qs](https://github.com/ljharb/qs) package, where
+/// keys and values are encoded with [`encode_uri_component`] and empty arrays and objects are
+/// left out. The indices of an array at the top level are used as its keys, while a string at the
+/// top level has no key and is left out.
+///
+/// [`encode_uri_component`]: crate::globals::encode_uri_component
+pub fn stringify<S: Sink + ?Sized>(value: &Value, options: &StringifyOptions, output: &mut S) {
+  let mut first = true;
+
+  match value {
+    Value::String(_) => {}
+    Value::Array(values) => {
+      for (idx, value) in values.iter().enumerate() {
+        stringify_inner(value, &idx.to_string(), options, &mut first, output);
+      }
+    }
+    Value::Object(entries) => {
+      for (key, value) in entries {
+        stringify_inner(value, key, options, &mut first, output);
+      }
+    }
+  }
+}
+
+fn stringify_inner<S: Sink + ?Sized>(
+  value: &Value,
+  prefix: &str,
+  options: &StringifyOptions,
+  first: &mut bool,
+  output: &mut S,
+) {
+  match value {
+    Value::String(string) => {
+      if !*first {
+        output.push_str("&");
+      }
+
+      *first = false;
+
+      if options.encode_values_only {
+        output.push_str(prefix);
+      } else {
+        encode(prefix, output);
+      }
+
+      output.push_str("=");
+      encode(string, output);
+    }
+    Value::Array(values) => {
+      for (idx, value) in values.iter().enumerate() {
+        let prefix = match options.array_format {
+          ArrayFormat::Indices => format!("{}[{}]", prefix, idx),
+          ArrayFormat::Brackets => format!("{}[]", prefix),
+          ArrayFormat::Repeat => prefix.to_owned(),
+        };
+
+        stringify_inner(value, &prefix, options, first, output);
+      }
+    }
+    Value::Object(entries) => {
+      for (key, value) in entries {
+        stringify_inner(
+          value,
+          &format!("{}[{}]", prefix, key),
+          options,
+          first,
+          output,
+        );
+      }
+    }
+  }
+}
+
+fn encode<S: Sink + ?Sized>(string: &str, output: &mut S) {
+  encode_str(string, &ENCODE_URI_COMPONENT_SET, output);
+}
@@ -11,6 +11,13 @@ pub struct Rng {
   state: u64,
 }
 
+impl Default for Rng {
+  #[inline]
+  fn default() -> Self {
+    Rng::new()
+  }
+}
+
 impl Rng {
   /// Creates a [`Rng`] instance with the `seed` set to `0`.
   #[inline]
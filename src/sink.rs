@@ -0,0 +1,232 @@
+use std::fmt;
+use std::io;
+
+/// A destination that codecs write text to.
+///
+/// # Notes
+/// Writing never fails. Sinks that can fail, such as [`FmtSink`] and [`IoSink`], keep the first
+/// error and ignore everything written after it, which is reported once the sink is finished.
+pub trait Sink {
+  /// Appends a string.
+  fn push_str(&mut self, string: &str);
+
+  /// Hints that at least `additional` more bytes will be written.
+  #[inline]
+  fn reserve(&mut self, additional: usize) {
+    let _ = additional;
+  }
+}
+
+/// A [`Sink`] that also takes bytes that may not be valid UTF-8, for codecs that decode to
+/// arbitrary bytes.
+pub trait ByteSink: Sink {
+  /// Appends bytes.
+  fn push_bytes(&mut self, bytes: &[u8]);
+}
+
+/// Appends bytes that the caller knows are ASCII.
+#[inline]
+pub(crate) fn push_ascii<S: Sink + ?Sized>(sink: &mut S, ascii: &[u8]) {
+  debug_assert!(ascii.is_ascii());
+
+  // SAFETY: ASCII is valid UTF-8.
+  sink.push_str(unsafe { std::str::from_utf8_unchecked(ascii) });
+}
+
+impl Sink for Vec<u8> {
+  #[inline]
+  fn push_str(&mut self, string: &str) {
+    self.extend_from_slice(string.as_bytes());
+  }
+
+  #[inline]
+  fn reserve(&mut self, additional: usize) {
+    Vec::reserve(self, additional);
+  }
+}
+
+impl ByteSink for Vec<u8> {
+  #[inline]
+  fn push_bytes(&mut self, bytes: &[u8]) {
+    self.extend_from_slice(bytes);
+  }
+}
+
+impl Sink for String {
+  #[inline]
+  fn push_str(&mut self, string: &str) {
+    String::push_str(self, string);
+  }
+
+  #[inline]
+  fn reserve(&mut self, additional: usize) {
+    String::reserve(self, additional);
+  }
+}
+
+/// A [`Sink`] that writes to a [`fmt::Write`], such as a [`fmt::Formatter`].
+#[derive(Debug)]
+pub struct FmtSink<W> {
+  writer: W,
+  result: fmt::Result,
+}
+
+impl<W: fmt::Write> FmtSink<W> {
+  /// Creates a sink that writes to `writer`.
+  pub fn new(writer: W) -> Self {
+    FmtSink {
+      writer,
+      result: Ok(()),
+    }
+  }
+
+  /// Returns the writer, or the first error it returned.
+  pub fn finish(self) -> Result<W, fmt::Error> {
+    self.result.map(|_| self.writer)
+  }
+}
+
+impl<W: fmt::Write> Sink for FmtSink<W> {
+  #[inline]
+  fn push_str(&mut self, string: &str) {
+    if self.result.is_ok() {
+      self.result = self.writer.write_str(string);
+    }
+  }
+}
+
+/// A [`ByteSink`] that writes to an [`io::Write`], such as a file or a socket.
+///
+/// # Notes
+/// Codecs write a few bytes at a time, so unbuffered writers should be wrapped in an
+/// [`io::BufWriter`].
+#[derive(Debug)]
+pub struct IoSink<W> {
+  writer: W,
+  error: Option<io::Error>,
+}
+
+impl<W: io::Write> IoSink<W> {
+  /// Creates a sink that writes to `writer`.
+  pub fn new(writer: W) -> Self {
+    IoSink {
+      writer,
+      error: None,
+    }
+  }
+
+  /// Returns the writer, or the first error it returned.
+  pub fn finish(self) -> io::Result<W> {
+    match self.error {
+      Some(error) => Err(error),
+      None => Ok(self.writer),
+    }
+  }
+}
+
+impl<W: io::Write> Sink for IoSink<W> {
+  #[inline]
+  fn push_str(&mut self, string: &str) {
+    self.push_bytes(string.as_bytes());
+  }
+}
+
+impl<W: io::Write> ByteSink for IoSink<W> {
+  #[inline]
+  fn push_bytes(&mut self, bytes: &[u8]) {
+    if self.error.is_none() {
+      self.error = self.writer.write_all(bytes).err();
+    }
+  }
+}
+
+/// An error when a [`SliceSink`] is too short for everything that was written to it.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct OverflowError {
+  required: usize,
+}
+
+impl OverflowError {
+  /// The length the buffer needed to be.
+  #[inline]
+  pub const fn required(&self) -> usize {
+    self.required
+  }
+}
+
+impl fmt::Display for OverflowError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "the buffer is too short, {} bytes are required",
+      self.required
+    )
+  }
+}
+
+impl std::error::Error for OverflowError {}
+
+/// A [`ByteSink`] that writes to the start of a fixed buffer.
+///
+/// # Notes
+/// Once something doesn't fit, nothing else is written, so that a character is never cut in half.
+/// The length that the buffer needed to be is still counted.
+#[derive(Debug)]
+pub struct SliceSink<'a> {
+  buffer: &'a mut [u8],
+  len: usize,
+  required: usize,
+}
+
+impl<'a> SliceSink<'a> {
+  /// Creates a sink that writes to the start of `buffer`.
+  pub fn new(buffer: &'a mut [u8]) -> Self {
+    SliceSink {
+      buffer,
+      len: 0,
+      required: 0,
+    }
+  }
+
+  /// The number of bytes written.
+  #[inline]
+  pub const fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Whether nothing was written.
+  #[inline]
+  pub const fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Returns the number of bytes written, or an error when something didn't fit.
+  pub fn finish(self) -> Result<usize, OverflowError> {
+    if self.required > self.buffer.len() {
+      return Err(OverflowError {
+        required: self.required,
+      });
+    }
+
+    Ok(self.len)
+  }
+}
+
+impl Sink for SliceSink<'_> {
+  #[inline]
+  fn push_str(&mut self, string: &str) {
+    self.push_bytes(string.as_bytes());
+  }
+}
+
+impl ByteSink for SliceSink<'_> {
+  #[inline]
+  fn push_bytes(&mut self, bytes: &[u8]) {
+    self.required += bytes.len();
+
+    if self.required <= self.buffer.len() {
+      self.buffer[self.len..self.required].copy_from_slice(bytes);
+      self.len = self.required;
+    }
+  }
+}
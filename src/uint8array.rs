@@ -0,0 +1,377 @@
+use crate::base64::{encode_base64_with, STANDARD, URL_SAFE};
+use crate::globals::utils::hex_value;
+use crate::hex::encode_hex;
+use crate::sink::{ByteSink, Sink, SliceSink};
+
+use std::fmt;
+
+/// The base64 alphabet to use, corresponding to the `alphabet` option.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Base64Alphabet {
+  /// The standard base64 alphabet, `"base64"`.
+  Base64,
+  /// The URL and filename safe base64 alphabet, `"base64url"`.
+  Base64Url,
+}
+
+impl Default for Base64Alphabet {
+  #[inline]
+  fn default() -> Self {
+    Base64Alphabet::Base64
+  }
+}
+
+/// How to handle the last chunk of a base64 string, corresponding to the `lastChunkHandling`
+/// option.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum LastChunkHandling {
+  /// Decodes a partial last chunk, with or without padding, ignoring any overflowing bits,
+  /// `"loose"`.
+  Loose,
+  /// Requires the last chunk to be padded and its overflowing bits to be zero, `"strict"`.
+  Strict,
+  /// Stops decoding before a partial last chunk without padding, `"stop-before-partial"`.
+  StopBeforePartial,
+}
+
+impl Default for LastChunkHandling {
+  #[inline]
+  fn default() -> Self {
+    LastChunkHandling::Loose
+  }
+}
+
+/// The options of `Uint8Array.fromBase64` and `Uint8Array.prototype.setFromBase64`.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct FromBase64Options {
+  /// The alphabet of the string.
+  pub alphabet: Base64Alphabet,
+  /// How the last chunk of the string is handled.
+  pub last_chunk_handling: LastChunkHandling,
+}
+
+/// The options of `Uint8Array.prototype.toBase64`.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct ToBase64Options {
+  /// The alphabet to encode with.
+  pub alphabet: Base64Alphabet,
+  /// Whether to omit the trailing `=` characters.
+  pub omit_padding: bool,
+}
+
+/// The number of characters read and bytes written by `Uint8Array.prototype.setFromBase64` and
+/// `Uint8Array.prototype.setFromHex`.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ReadWritten {
+  /// The number of bytes of the string that were read.
+  pub read: usize,
+  /// The number of bytes that were written.
+  pub written: usize,
+}
+
+/// The `SyntaxError` thrown when decoding a malformed string.
+///
+/// # Notes
+/// The bytes decoded before the error was encountered are still written by
+/// [`set_from_base64`] and [`set_from_hex`], as the spec requires.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct SyntaxError {
+  /// The number of bytes of the string that were read before the error.
+  pub read: usize,
+  /// The number of bytes that were decoded before the error.
+  pub written: usize,
+}
+
+impl fmt::Display for SyntaxError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "malformed input after reading {} bytes", self.read)
+  }
+}
+
+impl std::error::Error for SyntaxError {}
+
+/// Decodes a base64 string.
+///
+/// # Notes
+/// This function is functionally equivalent to JavaScript's
+/// [Uint8Array.fromBase64](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.frombase64).
+/// Nothing is appended to `decoded` when an error is returned.
+pub fn from_base64(
+  string: &[u8],
+  options: FromBase64Options,
+  decoded: &mut Vec<u8>,
+) -> Result<(), SyntaxError> {
+  let start = decoded.len();
+  let result = from_base64_inner(string, options, usize::MAX, decoded);
+
+  match result {
+    Ok(_) => Ok(()),
+    Err(read) => {
+      let written = decoded.len() - start;
+
+      decoded.truncate(start);
+      Err(SyntaxError { read, written })
+    }
+  }
+}
+
+/// Decodes a base64 string into a fixed size buffer, stopping before a chunk that wouldn't fit.
+///
+/// # Notes
+/// This function is functionally equivalent to JavaScript's
+/// [Uint8Array.prototype.setFromBase64](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.setfrombase64).
+pub fn set_from_base64(
+  string: &[u8],
+  options: FromBase64Options,
+  into: &mut [u8],
+) -> Result<ReadWritten, SyntaxError> {
+  let max_length = into.len();
+  let mut sink = SliceSink::new(into);
+
+  match from_base64_inner(string, options, max_length, &mut sink) {
+    Ok(read) => Ok(ReadWritten {
+      read,
+      written: sink.len(),
+    }),
+    Err(read) => Err(SyntaxError {
+      read,
+      written: sink.len(),
+    }),
+  }
+}
+
+/// Encodes bytes to a base64 string.
+///
+/// # Notes
+/// This function is functionally equivalent to JavaScript's
+/// [Uint8Array.prototype.toBase64](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.tobase64).
+pub fn to_base64<S: Sink + ?Sized>(bytes: &[u8], options: ToBase64Options, encoded: &mut S) {
+  let alphabet = match options.alphabet {
+    Base64Alphabet::Base64 => &STANDARD,
+    Base64Alphabet::Base64Url => &URL_SAFE,
+  };
+
+  encode_base64_with(bytes, alphabet, !options.omit_padding, encoded);
+}
+
+/// Decodes a hexadecimal string.
+///
+/// # Notes
+/// This function is functionally equivalent to JavaScript's
+/// [Uint8Array.fromHex](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.fromhex).
+/// Nothing is appended to `decoded` when an error is returned.
+pub fn from_hex(string: &[u8], decoded: &mut Vec<u8>) -> Result<(), SyntaxError> {
+  let start = decoded.len();
+
+  match from_hex_inner(string, usize::MAX, decoded) {
+    Ok(_) => Ok(()),
+    Err(read) => {
+      let written = decoded.len() - start;
+
+      decoded.truncate(start);
+      Err(SyntaxError { read, written })
+    }
+  }
+}
+
+/// Decodes a hexadecimal string into a fixed size buffer, stopping once it's full.
+///
+/// # Notes
+/// This function is functionally equivalent to JavaScript's
+/// [Uint8Array.prototype.setFromHex](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.setfromhex).
+pub fn set_from_hex(string: &[u8], into: &mut [u8]) -> Result<ReadWritten, SyntaxError> {
+  let max_length = into.len();
+  let mut sink = SliceSink::new(into);
+
+  match from_hex_inner(string, max_length, &mut sink) {
+    Ok(read) => Ok(ReadWritten {
+      read,
+      written: sink.len(),
+    }),
+    Err(read) => Err(SyntaxError {
+      read,
+      written: sink.len(),
+    }),
+  }
+}
+
+/// Encodes bytes to a lowercase hexadecimal string.
+///
+/// # Notes
+/// This function is functionally equivalent to JavaScript's
+/// [Uint8Array.prototype.toHex](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.tohex).
+#[inline]
+pub fn to_hex<S: Sink + ?Sized>(bytes: &[u8], encoded: &mut S) {
+  encode_hex(bytes, encoded);
+}
+
+/// The [FromBase64](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-frombase64) abstract
+/// operation, returning the number of bytes read from the string.
+fn from_base64_inner<S: ByteSink + ?Sized>(
+  string: &[u8],
+  options: FromBase64Options,
+  max_length: usize,
+  output: &mut S,
+) -> Result<usize, usize> {
+  if max_length == 0 {
+    return Ok(0);
+  }
+
+  let length = string.len();
+  let mut written = 0;
+  let mut read = 0;
+  let mut chunk = [0; 4];
+  let mut chunk_length = 0;
+  let mut index = 0;
+
+  loop {
+    index = skip_ascii_whitespace(string, index);
+
+    if index == length {
+      if chunk_length > 0 {
+        match options.last_chunk_handling {
+          LastChunkHandling::StopBeforePartial => return Ok(read),
+          LastChunkHandling::Loose if chunk_length > 1 => {
+            decode_final_chunk(&chunk[..chunk_length], false, output).map_err(|_| read)?;
+          }
+          _ => return Err(read),
+        }
+      }
+
+      return Ok(length);
+    }
+
+    let mut current = string[index];
+
+    index += 1;
+
+    if current == b'=' {
+      if chunk_length < 2 {
+        return Err(read);
+      }
+
+      index = skip_ascii_whitespace(string, index);
+
+      if chunk_length == 2 {
+        if index == length {
+          return match options.last_chunk_handling {
+            LastChunkHandling::StopBeforePartial => Ok(read),
+            _ => Err(read),
+          };
+        }
+
+        if string[index] == b'=' {
+          index = skip_ascii_whitespace(string, index + 1);
+        }
+      }
+
+      if index < length {
+        return Err(read);
+      }
+
+      let strict = options.last_chunk_handling == LastChunkHandling::Strict;
+
+      decode_final_chunk(&chunk[..chunk_length], strict, output).map_err(|_| read)?;
+
+      return Ok(length);
+    }
+
+    if options.alphabet == Base64Alphabet::Base64Url {
+      current = match current {
+        b'+' | b'/' => return Err(read),
+        b'-' => b'+',
+        b'_' => b'/',
+        _ => current,
+      };
+    }
+
+    let value = STANDARD.value(current).ok_or(read)?;
+    let remaining = max_length - written;
+
+    // Stop before a chunk that wouldn't fit in the remaining space.
+    if remaining == 1 && chunk_length == 2 || remaining == 2 && chunk_length == 3 {
+      return Ok(read);
+    }
+
+    chunk[chunk_length] = value;
+    chunk_length += 1;
+
+    if chunk_length == 4 {
+      let total = (chunk[0] as u32) << 18
+        | (chunk[1] as u32) << 12
+        | (chunk[2] as u32) << 6
+        | chunk[3] as u32;
+
+      output.push_bytes(&[(total >> 16) as u8, (total >> 8) as u8, total as u8]);
+      written += 3;
+      chunk_length = 0;
+      read = index;
+
+      if written == max_length {
+        return Ok(read);
+      }
+    }
+  }
+}
+
+/// The [DecodeFinalBase64Chunk](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-decodefinalbase64chunk)
+/// abstract operation, for a chunk of 2 or 3 values.
+fn decode_final_chunk<S: ByteSink + ?Sized>(
+  chunk: &[u8],
+  strict: bool,
+  output: &mut S,
+) -> Result<(), ()> {
+  let total = chunk.iter().enumerate().fold(0u32, |total, (idx, &value)| {
+    total | (value as u32) << (18 - idx * 6)
+  });
+  let bytes = [(total >> 16) as u8, (total >> 8) as u8, total as u8];
+  let decoded = &bytes[..chunk.len() - 1];
+
+  // The bits after the last decoded byte must be zero in strict mode.
+  if strict && bytes[chunk.len() - 1] != 0 {
+    return Err(());
+  }
+
+  output.push_bytes(decoded);
+
+  Ok(())
+}
+
+/// The [FromHex](https://tc39.es/proposal-arraybuffer-base64/spec/#sec-fromhex) abstract operation,
+/// returning the number of bytes read from the string.
+fn from_hex_inner<S: ByteSink + ?Sized>(
+  string: &[u8],
+  max_length: usize,
+  output: &mut S,
+) -> Result<usize, usize> {
+  if !string.len().is_multiple_of(2) {
+    return Err(0);
+  }
+
+  let mut read = 0;
+  let mut written = 0;
+
+  while read < string.len() && written < max_length {
+    let value = hex_value(string[read], string[read + 1]);
+
+    if value > u8::MAX as u32 {
+      return Err(read);
+    }
+
+    read += 2;
+    written += 1;
+    output.push_bytes(&[value as u8]);
+  }
+
+  Ok(read)
+}
+
+/// Skips tabs, line feeds, form feeds, carriage returns and spaces.
+#[inline]
+fn skip_ascii_whitespace(string: &[u8], mut index: usize) -> usize {
+  while index < string.len() && matches!(string[index], b'\t' | b'\n' | b'\x0C' | b'\r' | b' ') {
+    index += 1;
+  }
+
+  index
+}
@@ -0,0 +1,287 @@
+mod normalize;
+mod resolve;
+mod template;
+
+pub use resolve::remove_dot_segments;
+pub use template::*;
+
+use crate::globals::utils::hex_value;
+use crate::globals::{AsciiSet, UNRESERVED_SET};
+
+use std::fmt;
+use std::net::Ipv6Addr;
+
+/// A URI reference, which is either a URI or a relative reference, as defined by
+/// [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986#section-4.1).
+///
+/// # Notes
+/// The components borrow from the parsed string, and keep their percent encoding.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct UriRef<'a> {
+  input: &'a str,
+  scheme: Option<&'a str>,
+  authority: Option<Authority<'a>>,
+  path: &'a str,
+  query: Option<&'a str>,
+  fragment: Option<&'a str>,
+}
+
+/// The authority of a URI reference.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+struct Authority<'a> {
+  input: &'a str,
+  userinfo: Option<&'a str>,
+  host: &'a str,
+  port: Option<&'a str>,
+}
+
+/// An error when parsing a URI reference.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ParseError {
+  /// The scheme didn't start with a letter or contained an invalid character.
+  InvalidScheme,
+  /// The userinfo contained an invalid character or percent encoding.
+  InvalidUserinfo,
+  /// The host wasn't an IP literal, an IPv4 address or a registered name.
+  InvalidHost,
+  /// The port contained a character that isn't a digit.
+  InvalidPort,
+  /// The path contained an invalid character or percent encoding.
+  InvalidPath,
+  /// The query contained an invalid character or percent encoding.
+  InvalidQuery,
+  /// The fragment contained an invalid character or percent encoding.
+  InvalidFragment,
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      ParseError::InvalidScheme => "invalid scheme",
+      ParseError::InvalidUserinfo => "invalid userinfo",
+      ParseError::InvalidHost => "invalid host",
+      ParseError::InvalidPort => "invalid port",
+      ParseError::InvalidPath => "invalid path",
+      ParseError::InvalidQuery => "invalid query",
+      ParseError::InvalidFragment => "invalid fragment",
+    })
+  }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The characters that are `unreserved` in RFC 3986.
+pub(crate) const UNRESERVED: AsciiSet = UNRESERVED_SET.complement();
+/// The characters that are `sub-delims` in RFC 3986.
+const SUB_DELIMS: AsciiSet = AsciiSet::from_bytes(b"!$&'()*+,;=");
+/// The characters of a registered name, besides percent encoded ones.
+const REG_NAME: AsciiSet = UNRESERVED.union(&SUB_DELIMS);
+/// The characters of userinfo, besides percent encoded ones.
+const USERINFO: AsciiSet = REG_NAME.add(b':');
+/// The characters of a path, besides percent encoded ones.
+const PATH: AsciiSet = REG_NAME.union(&AsciiSet::from_bytes(b":@/"));
+/// The characters of a query or fragment, besides percent encoded ones.
+const QUERY: AsciiSet = PATH.add(b'?');
+
+impl<'a> UriRef<'a> {
+  /// Parses a URI reference, splitting it into its components.
+  ///
+  /// # Notes
+  /// This function splits the components like the regular expression of
+  /// [RFC 3986 Appendix B](https://datatracker.ietf.org/doc/html/rfc3986#appendix-B), then
+  /// checks each of them against the grammar of the RFC. Characters that aren't ASCII must be
+  /// percent encoded.
+  pub fn parse(input: &'a str) -> Result<UriRef<'a>, ParseError> {
+    let (rest, fragment) = match input.find('#') {
+      Some(idx) => (&input[..idx], Some(&input[idx + 1..])),
+      None => (input, None),
+    };
+    let (rest, query) = match rest.find('?') {
+      Some(idx) => (&rest[..idx], Some(&rest[idx + 1..])),
+      None => (rest, None),
+    };
+    let (scheme, rest) = match rest.find([':', '/']) {
+      Some(idx) if rest.as_bytes()[idx] == b':' => (Some(&rest[..idx]), &rest[idx + 1..]),
+      _ => (None, rest),
+    };
+    let (authority, path) = match rest.strip_prefix("//") {
+      Some(rest) => {
+        let end = rest.find('/').unwrap_or(rest.len());
+
+        (Some(Authority::parse(&rest[..end])?), &rest[end..])
+      }
+      None => (None, rest),
+    };
+
+    if let Some(scheme) = scheme {
+      if !is_scheme(scheme) {
+        return Err(ParseError::InvalidScheme);
+      }
+    }
+
+    if !is_valid(path, &PATH) {
+      return Err(ParseError::InvalidPath);
+    }
+
+    if query.is_some_and(|query| !is_valid(query, &QUERY)) {
+      return Err(ParseError::InvalidQuery);
+    }
+
+    if fragment.is_some_and(|fragment| !is_valid(fragment, &QUERY)) {
+      return Err(ParseError::InvalidFragment);
+    }
+
+    Ok(UriRef {
+      input,
+      scheme,
+      authority,
+      path,
+      query,
+      fragment,
+    })
+  }
+
+  /// Returns the URI reference as it was parsed.
+  pub fn as_str(&self) -> &'a str {
+    self.input
+  }
+
+  /// Whether the URI reference is a relative reference, which has no scheme.
+  pub fn is_relative(&self) -> bool {
+    self.scheme.is_none()
+  }
+
+  /// Returns the scheme, without the `:` after it.
+  pub fn scheme(&self) -> Option<&'a str> {
+    self.scheme
+  }
+
+  /// Returns the authority, without the `//` before it.
+  pub fn authority(&self) -> Option<&'a str> {
+    self.authority.map(|authority| authority.input)
+  }
+
+  /// Returns the userinfo of the authority, without the `@` after it.
+  pub fn userinfo(&self) -> Option<&'a str> {
+    self.authority.and_then(|authority| authority.userinfo)
+  }
+
+  /// Returns the host of the authority, which keeps the brackets of an IP literal.
+  pub fn host(&self) -> Option<&'a str> {
+    self.authority.map(|authority| authority.host)
+  }
+
+  /// Returns the port of the authority, without the `:` before it. The port may be empty.
+  pub fn port(&self) -> Option<&'a str> {
+    self.authority.and_then(|authority| authority.port)
+  }
+
+  /// Returns the path, which may be empty.
+  pub fn path(&self) -> &'a str {
+    self.path
+  }
+
+  /// Returns the query, without the `?` before it.
+  pub fn query(&self) -> Option<&'a str> {
+    self.query
+  }
+
+  /// Returns the fragment, without the `#` before it.
+  pub fn fragment(&self) -> Option<&'a str> {
+    self.fragment
+  }
+}
+
+impl fmt::Display for UriRef<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.input)
+  }
+}
+
+impl<'a> Authority<'a> {
+  fn parse(input: &'a str) -> Result<Authority<'a>, ParseError> {
+    let (userinfo, rest) = match input.rfind('@') {
+      Some(idx) => (Some(&input[..idx]), &input[idx + 1..]),
+      None => (None, input),
+    };
+
+    if userinfo.is_some_and(|userinfo| !is_valid(userinfo, &USERINFO)) {
+      return Err(ParseError::InvalidUserinfo);
+    }
+
+    // The port comes after the last `:`, unless that's within an IP literal
+    let (host, port) = match rest.rfind(':') {
+      Some(idx) if !rest[idx..].contains(']') => (&rest[..idx], Some(&rest[idx + 1..])),
+      _ => (rest, None),
+    };
+
+    if !is_host(host) {
+      return Err(ParseError::InvalidHost);
+    }
+
+    if port.is_some_and(|port| !port.bytes().all(|byte| byte.is_ascii_digit())) {
+      return Err(ParseError::InvalidPort);
+    }
+
+    Ok(Authority {
+      input,
+      userinfo,
+      host,
+      port,
+    })
+  }
+}
+
+/// Whether the string is a scheme, which is a letter followed by letters, digits, `+`, `-` and
+/// `.`.
+fn is_scheme(scheme: &str) -> bool {
+  let mut bytes = scheme.bytes();
+
+  bytes.next().is_some_and(|byte| byte.is_ascii_alphabetic())
+    && bytes.all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'-' | b'.'))
+}
+
+/// Whether the string is an IP literal, an IPv4 address or a registered name, where the last two
+/// share the same characters.
+fn is_host(host: &str) -> bool {
+  match host
+    .strip_prefix('[')
+    .and_then(|host| host.strip_suffix(']'))
+  {
+    Some(literal) => match literal.strip_prefix(|ch| ch == 'v' || ch == 'V') {
+      // IPvFuture
+      Some(future) => match future.find('.') {
+        Some(idx) => {
+          idx != 0
+            && future[..idx].bytes().all(|byte| byte.is_ascii_hexdigit())
+            && idx + 1 != future.len()
+            && future[idx + 1..]
+              .bytes()
+              .all(|byte| USERINFO.contains(byte))
+        }
+        None => false,
+      },
+      None => literal.parse::<Ipv6Addr>().is_ok(),
+    },
+    None => is_valid(host, &REG_NAME),
+  }
+}
+
+/// Whether every byte of the string is in `allowed` or part of a valid percent encoded byte.
+fn is_valid(component: &str, allowed: &AsciiSet) -> bool {
+  let bytes = component.as_bytes();
+  let mut idx = 0;
+
+  while idx < bytes.len() {
+    match bytes[idx] {
+      b'%' => match bytes.get(idx + 1..idx + 3) {
+        Some(&[one, two]) if hex_value(one, two) <= u8::MAX as u32 => idx += 3,
+        _ => return false,
+      },
+      byte if byte < 0x80 && allowed.contains(byte) => idx += 1,
+      _ => return false,
+    }
+  }
+
+  true
+}
@@ -0,0 +1,96 @@
+use crate::globals::utils::{byte_to_hex, hex_value};
+use crate::uri::{remove_dot_segments, UriRef, UNRESERVED};
+
+impl UriRef<'_> {
+  /// Returns the normalized URI reference.
+  ///
+  /// # Notes
+  /// This function follows the
+  /// [syntax-based normalization](https://datatracker.ietf.org/doc/html/rfc3986#section-6.2.2) of
+  /// RFC 3986, where the scheme and host are lowercased, escapes of unreserved characters are
+  /// decoded, the hexadecimal digits of other escapes are uppercased, and dot segments are
+  /// removed from the path.
+  ///
+  /// Dot segments are only removed when there's a scheme, since the ones of a relative reference
+  /// are significant when it's resolved.
+  pub fn normalize(&self) -> String {
+    let mut normalized = String::with_capacity(self.input.len());
+
+    if let Some(scheme) = self.scheme {
+      normalized.push_str(&scheme.to_ascii_lowercase());
+      normalized.push(':');
+    }
+
+    if let Some(authority) = self.authority {
+      normalized.push_str("//");
+
+      if let Some(userinfo) = authority.userinfo {
+        normalize_escapes(userinfo, false, &mut normalized);
+        normalized.push('@');
+      }
+
+      normalize_escapes(authority.host, true, &mut normalized);
+
+      if let Some(port) = authority.port {
+        normalized.push(':');
+        normalized.push_str(port);
+      }
+    }
+
+    let mut path = String::with_capacity(self.path.len());
+
+    normalize_escapes(self.path, false, &mut path);
+
+    if self.scheme.is_some() {
+      path = remove_dot_segments(&path);
+    }
+
+    normalized.push_str(&path);
+
+    if let Some(query) = self.query {
+      normalized.push('?');
+      normalize_escapes(query, false, &mut normalized);
+    }
+
+    if let Some(fragment) = self.fragment {
+      normalized.push('#');
+      normalize_escapes(fragment, false, &mut normalized);
+    }
+
+    normalized
+  }
+}
+
+/// Writes a component with escapes of unreserved characters decoded, and the hexadecimal digits
+/// of other escapes uppercased. The component is lowercased as well when `lowercase` is set.
+fn normalize_escapes(component: &str, lowercase: bool, normalized: &mut String) {
+  let bytes = component.as_bytes();
+  let mut idx = 0;
+
+  while idx < bytes.len() {
+    let mut byte = bytes[idx];
+
+    // Escapes were checked when parsing
+    if byte == b'%' {
+      byte = hex_value(bytes[idx + 1], bytes[idx + 2]) as u8;
+      idx += 3;
+
+      if !UNRESERVED.contains(byte) {
+        let [one, two] = byte_to_hex(byte);
+
+        normalized.push('%');
+        normalized.push(one as char);
+        normalized.push(two as char);
+        continue;
+      }
+    } else {
+      idx += 1;
+    }
+
+    if lowercase {
+      byte = byte.to_ascii_lowercase();
+    }
+
+    normalized.push(byte as char);
+  }
+}
@@ -0,0 +1,143 @@
+use crate::uri::UriRef;
+
+impl UriRef<'_> {
+  /// Resolves a URI reference against this URI, which is the base URI, returning the target URI.
+  ///
+  /// # Notes
+  /// This function follows the strict
+  /// [reference resolution](https://datatracker.ietf.org/doc/html/rfc3986#section-5.2) of
+  /// RFC 3986, where a reference with a scheme is never treated as relative. The fragment of the
+  /// base URI is ignored.
+  pub fn resolve(&self, reference: &UriRef<'_>) -> String {
+    let mut target = String::with_capacity(self.input.len() + reference.input.len());
+
+    if let Some(scheme) = reference.scheme {
+      push_components(
+        &mut target,
+        Some(scheme),
+        reference.authority(),
+        &remove_dot_segments(reference.path),
+        reference.query,
+      );
+    } else if let Some(authority) = reference.authority() {
+      push_components(
+        &mut target,
+        self.scheme,
+        Some(authority),
+        &remove_dot_segments(reference.path),
+        reference.query,
+      );
+    } else if reference.path.is_empty() {
+      push_components(
+        &mut target,
+        self.scheme,
+        self.authority(),
+        self.path,
+        reference.query.or(self.query),
+      );
+    } else {
+      let path = if reference.path.starts_with('/') {
+        remove_dot_segments(reference.path)
+      } else {
+        remove_dot_segments(&self.merge(reference.path))
+      };
+
+      push_components(
+        &mut target,
+        self.scheme,
+        self.authority(),
+        &path,
+        reference.query,
+      );
+    }
+
+    if let Some(fragment) = reference.fragment {
+      target.push('#');
+      target.push_str(fragment);
+    }
+
+    target
+  }
+
+  /// Merges a relative path with the path of this URI, as described in
+  /// [RFC 3986 Section 5.2.3](https://datatracker.ietf.org/doc/html/rfc3986#section-5.2.3).
+  fn merge(&self, path: &str) -> String {
+    if self.authority.is_some() && self.path.is_empty() {
+      return format!("/{}", path);
+    }
+
+    match self.path.rfind('/') {
+      Some(idx) => format!("{}{}", &self.path[..=idx], path),
+      None => path.to_owned(),
+    }
+  }
+}
+
+/// Removes the `.` and `..` segments of a path.
+///
+/// # Notes
+/// This function follows the
+/// [remove_dot_segments](https://datatracker.ietf.org/doc/html/rfc3986#section-5.2.4) algorithm
+/// of RFC 3986, where `..` segments that would go above the root are dropped.
+pub fn remove_dot_segments(path: &str) -> String {
+  let mut output = String::with_capacity(path.len());
+  let mut input = path;
+
+  while !input.is_empty() {
+    if let Some(rest) = input
+      .strip_prefix("../")
+      .or_else(|| input.strip_prefix("./"))
+    {
+      input = rest;
+    } else if input.starts_with("/./") {
+      input = &input[2..];
+    } else if input == "/." {
+      input = "/";
+    } else if input.starts_with("/../") || input == "/.." {
+      input = if input.len() == 3 { "/" } else { &input[3..] };
+
+      // Removes the last segment of the output, along with the `/` before it
+      output.truncate(output.rfind('/').unwrap_or(0));
+    } else if input == "." || input == ".." {
+      input = "";
+    } else {
+      // Moves the first segment, along with the `/` before it, to the output
+      let start = if input.starts_with('/') { 1 } else { 0 };
+      let end = input[start..]
+        .find('/')
+        .map_or(input.len(), |idx| idx + start);
+
+      output.push_str(&input[..end]);
+      input = &input[end..];
+    }
+  }
+
+  output
+}
+
+/// Writes the components of a URI, as described in
+/// [RFC 3986 Section 5.3](https://datatracker.ietf.org/doc/html/rfc3986#section-5.3).
+pub(crate) fn push_components(
+  target: &mut String,
+  scheme: Option<&str>,
+  authority: Option<&str>,
+  path: &str,
+  query: Option<&str>,
+) {
+  if let Some(scheme) = scheme {
+    target.push_str(scheme);
+    target.push(':');
+  }
+
+  if let Some(authority) = authority {
+    target.push_str("//");
+    target.push_str(authority);
+  }
+
+  target.push_str(path);
+
+  if let Some(query) = query {
+    target.push('?');
+    target.push_str(query);
+  }
+}
@@ -0,0 +1,413 @@
+use crate::globals::utils::hex_value;
+use crate::globals::{encode_str, AsciiSet, UNRESERVED_SET};
+use crate::uri::UNRESERVED;
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// A URI Template, as defined by [RFC 6570](https://datatracker.ietf.org/doc/html/rfc6570).
+///
+/// # Notes
+/// Every level of the RFC is supported, including prefix modifiers, explode modifiers and
+/// composite values.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct UriTemplate {
+  parts: Vec<Part>,
+}
+
+/// The value of a template variable.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Value {
+  /// A string.
+  String(String),
+  /// A list of strings, which is undefined when it's empty.
+  List(Vec<String>),
+  /// Name-value pairs, which are expanded in order and are undefined when there are none.
+  Map(Vec<(String, String)>),
+}
+
+impl From<&str> for Value {
+  fn from(value: &str) -> Self {
+    Value::String(value.to_owned())
+  }
+}
+
+impl From<String> for Value {
+  fn from(value: String) -> Self {
+    Value::String(value)
+  }
+}
+
+/// An error when parsing a URI Template.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum TemplateError {
+  /// An expression was opened with `{` but never closed, or a `}` was found outside of one.
+  UnbalancedBraces,
+  /// An expression started with an operator that's reserved for future extensions.
+  ReservedOperator,
+  /// A variable name was empty or contained an invalid character.
+  InvalidVariableName,
+  /// A prefix modifier wasn't a number from 1 to 9999.
+  InvalidPrefix,
+}
+
+impl fmt::Display for TemplateError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      TemplateError::UnbalancedBraces => "unbalanced braces",
+      TemplateError::ReservedOperator => "reserved operator",
+      TemplateError::InvalidVariableName => "invalid variable name",
+      TemplateError::InvalidPrefix => "invalid prefix modifier",
+    })
+  }
+}
+
+impl std::error::Error for TemplateError {}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+enum Part {
+  Literal(String),
+  Expression(Operator, Vec<Variable>),
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+struct Variable {
+  name: String,
+  modifier: Modifier,
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+enum Modifier {
+  None,
+  /// Only the given number of characters of the value is expanded.
+  Prefix(usize),
+  /// Each item of a composite value is expanded as if it were its own variable.
+  Explode,
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+enum Operator {
+  Simple,
+  Reserved,
+  Fragment,
+  Label,
+  PathSegment,
+  PathParameter,
+  Query,
+  QueryContinuation,
+}
+
+/// How an operator expands its variables, as described in
+/// [RFC 6570 Appendix A](https://datatracker.ietf.org/doc/html/rfc6570#appendix-A).
+struct Expansion {
+  first: &'static str,
+  separator: u8,
+  named: bool,
+  if_empty: &'static str,
+  allow_reserved: bool,
+}
+
+/// The characters that are percent encoded by the operators that allow reserved characters.
+const RESERVED_EXPANSION_SET: AsciiSet = UNRESERVED
+  .union(&AsciiSet::from_bytes(b":/?#[]@!$&'()*+,;="))
+  .complement();
+
+impl Operator {
+  fn expansion(self) -> Expansion {
+    let (first, separator, named, if_empty, allow_reserved) = match self {
+      Operator::Simple => ("", b',', false, "", false),
+      Operator::Reserved => ("", b',', false, "", true),
+      Operator::Fragment => ("#", b',', false, "", true),
+      Operator::Label => (".", b'.', false, "", false),
+      Operator::PathSegment => ("/", b'/', false, "", false),
+      Operator::PathParameter => (";", b';', true, "", false),
+      Operator::Query => ("?", b'&', true, "=", false),
+      Operator::QueryContinuation => ("&", b'&', true, "=", false),
+    };
+
+    Expansion {
+      first,
+      separator,
+      named,
+      if_empty,
+      allow_reserved,
+    }
+  }
+}
+
+impl UriTemplate {
+  /// Parses a URI Template.
+  pub fn parse(template: &str) -> Result<UriTemplate, TemplateError> {
+    let mut parts = Vec::new();
+    let mut rest = template;
+
+    while !rest.is_empty() {
+      let start = rest.find(['{', '}']).unwrap_or(rest.len());
+
+      if start != 0 {
+        parts.push(Part::Literal(rest[..start].to_owned()));
+      }
+
+      rest = &rest[start..];
+
+      let expression = match rest.strip_prefix('{') {
+        Some(expression) => expression,
+        None if rest.is_empty() => break,
+        None => return Err(TemplateError::UnbalancedBraces),
+      };
+      let end = expression
+        .find(['{', '}'])
+        .filter(|&end| expression.as_bytes()[end] == b'}')
+        .ok_or(TemplateError::UnbalancedBraces)?;
+
+      parts.push(parse_expression(&expression[..end])?);
+      rest = &expression[end + 1..];
+    }
+
+    Ok(UriTemplate { parts })
+  }
+
+  /// Expands the template with the given variables, where missing variables are undefined.
+  pub fn expand<K>(&self, variables: &HashMap<K, Value>) -> String
+  where
+    K: Borrow<str> + Hash + Eq,
+  {
+    let mut expanded = Vec::new();
+
+    for part in &self.parts {
+      match part {
+        Part::Literal(literal) => push_encoded(literal, true, &mut expanded),
+        Part::Expression(operator, list) => {
+          let expansion = operator.expansion();
+          let mut first = true;
+
+          for variable in list {
+            let value = match variables.get(variable.name.as_str()) {
+              Some(Value::List(items)) if items.is_empty() => continue,
+              Some(Value::Map(pairs)) if pairs.is_empty() => continue,
+              Some(value) => value,
+              None => continue,
+            };
+
+            if first {
+              expanded.extend_from_slice(expansion.first.as_bytes());
+              first = false;
+            } else {
+              expanded.push(expansion.separator);
+            }
+
+            expand_variable(variable, value, &expansion, &mut expanded);
+          }
+        }
+      }
+    }
+
+    // SAFETY: Every byte written was either percent encoded or is ASCII.
+    unsafe { String::from_utf8_unchecked(expanded) }
+  }
+}
+
+fn parse_expression(expression: &str) -> Result<Part, TemplateError> {
+  let operator = match expression.as_bytes().first() {
+    Some(b'+') => Some(Operator::Reserved),
+    Some(b'#') => Some(Operator::Fragment),
+    Some(b'.') => Some(Operator::Label),
+    Some(b'/') => Some(Operator::PathSegment),
+    Some(b';') => Some(Operator::PathParameter),
+    Some(b'?') => Some(Operator::Query),
+    Some(b'&') => Some(Operator::QueryContinuation),
+    Some(b'=' | b',' | b'!' | b'@' | b'|') => return Err(TemplateError::ReservedOperator),
+    _ => None,
+  };
+  let list = match operator {
+    Some(_) => &expression[1..],
+    None => expression,
+  };
+  let variables = list
+    .split(',')
+    .map(parse_variable)
+    .collect::<Result<Vec<_>, _>>()?;
+
+  Ok(Part::Expression(
+    operator.unwrap_or(Operator::Simple),
+    variables,
+  ))
+}
+
+fn parse_variable(variable: &str) -> Result<Variable, TemplateError> {
+  let (name, modifier) = if let Some(name) = variable.strip_suffix('*') {
+    (name, Modifier::Explode)
+  } else if let Some(idx) = variable.find(':') {
+    let prefix = &variable[idx + 1..];
+
+    if prefix.is_empty() || prefix.len() > 4 || !prefix.bytes().all(|byte| byte.is_ascii_digit()) {
+      return Err(TemplateError::InvalidPrefix);
+    }
+
+    match prefix.parse() {
+      Ok(0) | Err(_) => return Err(TemplateError::InvalidPrefix),
+      Ok(length) => (&variable[..idx], Modifier::Prefix(length)),
+    }
+  } else {
+    (variable, Modifier::None)
+  };
+
+  if !is_variable_name(name) {
+    return Err(TemplateError::InvalidVariableName);
+  }
+
+  Ok(Variable {
+    name: name.to_owned(),
+    modifier,
+  })
+}
+
+/// Whether the name is made of letters, digits, `_` and percent encoded bytes, which may be
+/// separated by single dots.
+fn is_variable_name(name: &str) -> bool {
+  let bytes = name.as_bytes();
+  let mut after_char = false;
+  let mut idx = 0;
+
+  while idx < bytes.len() {
+    match bytes[idx] {
+      b'%' => match bytes.get(idx + 1..idx + 3) {
+        Some(&[one, two]) if hex_value(one, two) <= u8::MAX as u32 => idx += 2,
+        _ => return false,
+      },
+      b'.' if after_char => {
+        after_char = false;
+        idx += 1;
+        continue;
+      }
+      byte if byte.is_ascii_alphanumeric() || byte == b'_' => {}
+      _ => return false,
+    }
+
+    after_char = true;
+    idx += 1;
+  }
+
+  after_char
+}
+
+fn expand_variable(
+  variable: &Variable,
+  value: &Value,
+  expansion: &Expansion,
+  expanded: &mut Vec<u8>,
+) {
+  let name = variable.name.as_str();
+
+  match value {
+    Value::String(string) => {
+      push_name(name, string.is_empty(), expansion, expanded);
+
+      let string = match variable.modifier {
+        Modifier::Prefix(length) => match string.char_indices().nth(length) {
+          Some((idx, _)) => &string[..idx],
+          None => string,
+        },
+        _ => string,
+      };
+
+      push_encoded(string, expansion.allow_reserved, expanded);
+    }
+    Value::List(items) if variable.modifier == Modifier::Explode => {
+      for (idx, item) in items.iter().enumerate() {
+        if idx != 0 {
+          expanded.push(expansion.separator);
+        }
+
+        push_name(name, item.is_empty(), expansion, expanded);
+        push_encoded(item, expansion.allow_reserved, expanded);
+      }
+    }
+    Value::List(items) => {
+      push_name(name, false, expansion, expanded);
+
+      for (idx, item) in items.iter().enumerate() {
+        if idx != 0 {
+          expanded.push(b',');
+        }
+
+        push_encoded(item, expansion.allow_reserved, expanded);
+      }
+    }
+    Value::Map(pairs) if variable.modifier == Modifier::Explode => {
+      for (idx, (key, value)) in pairs.iter().enumerate() {
+        if idx != 0 {
+          expanded.push(expansion.separator);
+        }
+
+        // Exploded pairs are always named by their key
+        push_encoded(key, expansion.allow_reserved, expanded);
+
+        if !value.is_empty() || !expansion.named {
+          expanded.push(b'=');
+        } else {
+          expanded.extend_from_slice(expansion.if_empty.as_bytes());
+        }
+
+        push_encoded(value, expansion.allow_reserved, expanded);
+      }
+    }
+    Value::Map(pairs) => {
+      push_name(name, false, expansion, expanded);
+
+      for (idx, (key, value)) in pairs.iter().enumerate() {
+        if idx != 0 {
+          expanded.push(b',');
+        }
+
+        push_encoded(key, expansion.allow_reserved, expanded);
+        expanded.push(b',');
+        push_encoded(value, expansion.allow_reserved, expanded);
+      }
+    }
+  }
+}
+
+/// Writes the name of a variable for the operators that name their variables.
+fn push_name(name: &str, is_empty: bool, expansion: &Expansion, expanded: &mut Vec<u8>) {
+  if !expansion.named {
+    return;
+  }
+
+  expanded.extend_from_slice(name.as_bytes());
+
+  if is_empty {
+    expanded.extend_from_slice(expansion.if_empty.as_bytes());
+  } else {
+    expanded.push(b'=');
+  }
+}
+
+/// Percent encodes every character that isn't unreserved, or that isn't reserved either when
+/// `allow_reserved` is set, in which case escapes are kept as is.
+fn push_encoded(string: &str, allow_reserved: bool, expanded: &mut Vec<u8>) {
+  let bytes = string.as_bytes();
+  let set = if allow_reserved {
+    &RESERVED_EXPANSION_SET
+  } else {
+    &UNRESERVED_SET
+  };
+  let mut start = 0;
+  let mut idx = 0;
+
+  while allow_reserved && idx < bytes.len() {
+    match bytes.get(idx..idx + 3) {
+      Some(&[b'%', one, two]) if hex_value(one, two) <= u8::MAX as u32 => {
+        encode_str(&string[start..idx], set, expanded);
+        expanded.extend_from_slice(&bytes[idx..idx + 3]);
+        idx += 3;
+        start = idx;
+      }
+      _ => idx += 1,
+    }
+  }
+
+  encode_str(&string[start..], set, expanded);
+}
@@ -0,0 +1,132 @@
+use crate::globals::utils::byte_to_hex;
+use crate::globals::{encode_str, percent_decode, AsciiSet, PATH_SET};
+use crate::url::host::Host;
+use crate::url::Url;
+
+use std::ffi::OsString;
+use std::fmt;
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+use std::path::{Path, PathBuf};
+
+/// The percent-encode set of file URL paths, which adds `%` so that escapes in a path aren't
+/// decoded, `\` which special URLs read as `/`, and `|` so that a first segment like `C|` isn't read
+/// as a Windows drive letter.
+const FILE_PATH_SET: AsciiSet = PATH_SET.add(b'%').add(b'\\').add(b'|');
+
+/// An error when converting between a file URL and a path.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FilePathError {
+  /// The path wasn't absolute.
+  RelativePath,
+  /// The path contained a `.` or `..` segment, which a URL can't represent.
+  DotSegment,
+  /// The URL's scheme wasn't `file`.
+  NotFileUrl,
+  /// The URL had a host other than `localhost`, which POSIX paths can't represent.
+  HasHost,
+  /// The URL's path contained an escaped `/`, `%2F`.
+  EncodedSlash,
+}
+
+impl fmt::Display for FilePathError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      FilePathError::RelativePath => "the path must be absolute",
+      FilePathError::DotSegment => "the path must not contain . or .. segments",
+      FilePathError::NotFileUrl => "the URL must be a file URL",
+      FilePathError::HasHost => "the file URL must not have a host",
+      FilePathError::EncodedSlash => "the file URL must not include encoded / characters",
+    })
+  }
+}
+
+impl std::error::Error for FilePathError {}
+
+impl Url {
+  /// Converts an absolute POSIX path to a file URL.
+  ///
+  /// # Notes
+  /// This function is similar to Node's
+  /// [url.pathToFileURL](https://nodejs.org/api/url.html#urlpathtofileurlpath), except that a
+  /// relative path is an error instead of being resolved against the current directory. A path
+  /// with `.` or `..` segments is an error too, since a URL can't contain them and resolving them
+  /// without the file system would change what a symbolic link points to. Empty segments and a
+  /// trailing `/` are kept.
+  ///
+  /// The path, even one that isn't valid UTF-8, converts back to itself with
+  /// [`Url::to_file_path`], even after the URL is serialized and parsed again.
+  pub fn from_file_path(path: &Path) -> Result<Url, FilePathError> {
+    if !path.is_absolute() {
+      return Err(FilePathError::RelativePath);
+    }
+
+    // The first segment is empty, since the path starts with `/`
+    let segments = path.as_os_str().as_bytes()[1..]
+      .split(|&byte| byte == b'/')
+      .map(|segment| match segment {
+        b"." | b".." => Err(FilePathError::DotSegment),
+        segment => Ok(encode_segment(segment)),
+      })
+      .collect::<Result<Vec<_>, _>>()?;
+
+    let mut url = Url::empty();
+
+    url.scheme = "file".to_owned();
+    url.host = Some(Host::Empty);
+    *url.segments_mut() = segments;
+
+    Ok(url)
+  }
+
+  /// Converts a file URL to a POSIX path.
+  ///
+  /// # Notes
+  /// This function functionally behaves the same as Node's
+  /// [url.fileURLToPath](https://nodejs.org/api/url.html#urlfileurltopathurl) on POSIX systems,
+  /// where the path is percent decoded to bytes that don't need to be valid UTF-8, and the query
+  /// and fragment are ignored.
+  pub fn to_file_path(&self) -> Result<PathBuf, FilePathError> {
+    if self.scheme != "file" {
+      return Err(FilePathError::NotFileUrl);
+    }
+
+    if !matches!(self.host, None | Some(Host::Empty)) {
+      return Err(FilePathError::HasHost);
+    }
+
+    let pathname = self.pathname();
+    let has_encoded_slash = pathname
+      .as_bytes()
+      .windows(3)
+      .any(|window| window.eq_ignore_ascii_case(b"%2F"));
+
+    if has_encoded_slash {
+      return Err(FilePathError::EncodedSlash);
+    }
+
+    let mut decoded = Vec::with_capacity(pathname.len());
+
+    percent_decode(pathname.as_bytes(), &mut decoded);
+
+    Ok(PathBuf::from(OsString::from_vec(decoded)))
+  }
+}
+
+/// Percent encodes a segment of a path, where each byte of invalid UTF-8 is escaped on its own.
+fn encode_segment(bytes: &[u8]) -> String {
+  let mut encoded = String::with_capacity(bytes.len());
+
+  for chunk in bytes.utf8_chunks() {
+    encode_str(chunk.valid(), &FILE_PATH_SET, &mut encoded);
+
+    for &byte in chunk.invalid() {
+      let [one, two] = byte_to_hex(byte);
+
+      encoded.push('%');
+      encoded.push(one as char);
+      encoded.push(two as char);
+    }
+  }
+
+  encoded
+}
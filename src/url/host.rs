@@ -0,0 +1,392 @@
+use crate::globals::{encode_str, percent_decode, C0_CONTROL_SET};
+use crate::idna::{self, Config};
+use crate::url::ParseError;
+
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// The host of a URL.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub(crate) enum Host {
+  /// An ASCII lowercase domain.
+  Domain(String),
+  /// An IPv4 address.
+  Ipv4(Ipv4Addr),
+  /// An IPv6 address.
+  Ipv6(Ipv6Addr),
+  /// A percent encoded host of a URL that isn't special.
+  Opaque(String),
+  /// The empty host.
+  Empty,
+}
+
+impl Host {
+  /// Parses a host, following the [host parser](https://url.spec.whatwg.org/#host-parsing).
+  ///
+  /// Opaque hosts are parsed when `is_opaque` is set, which is the case for URLs that aren't
+  /// special.
+  pub(crate) fn parse(input: &str, is_opaque: bool) -> Result<Host, ParseError> {
+    if let Some(address) = input.strip_prefix('[') {
+      return match address.strip_suffix(']') {
+        Some(address) => parse_ipv6(address).map(Host::Ipv6),
+        None => Err(ParseError::InvalidIpv6Address),
+      };
+    }
+
+    if is_opaque {
+      return parse_opaque(input);
+    }
+
+    let mut decoded = Vec::with_capacity(input.len());
+
+    percent_decode(input.as_bytes(), &mut decoded);
+
+    let domain = domain_to_ascii(&String::from_utf8_lossy(&decoded))?;
+
+    if ends_in_number(&domain) {
+      return parse_ipv4(&domain).map(Host::Ipv4);
+    }
+
+    Ok(Host::Domain(domain))
+  }
+}
+
+impl fmt::Display for Host {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Host::Domain(host) | Host::Opaque(host) => f.write_str(host),
+      Host::Ipv4(address) => write!(f, "{}", address),
+      Host::Ipv6(address) => {
+        f.write_str("[")?;
+        fmt_ipv6(address, f)?;
+        f.write_str("]")
+      }
+      Host::Empty => Ok(()),
+    }
+  }
+}
+
+/// Converts a domain to ASCII, following
+/// [domain to ASCII](https://url.spec.whatwg.org/#concept-domain-to-ascii).
+fn domain_to_ascii(domain: &str) -> Result<String, ParseError> {
+  let domain = match idna::domain_to_ascii(domain, &Config::URL) {
+    Ok(domain) if !domain.is_empty() => domain,
+    _ => return Err(ParseError::InvalidDomain),
+  };
+
+  if domain.bytes().any(is_forbidden_domain_byte) {
+    return Err(ParseError::InvalidHostCharacter);
+  }
+
+  Ok(domain)
+}
+
+/// Parses the host of a URL that isn't special.
+fn parse_opaque(input: &str) -> Result<Host, ParseError> {
+  if input.bytes().any(is_forbidden_host_byte) {
+    return Err(ParseError::InvalidHostCharacter);
+  }
+
+  if input.is_empty() {
+    return Ok(Host::Empty);
+  }
+
+  let mut host = String::with_capacity(input.len());
+
+  encode_str(input, &C0_CONTROL_SET, &mut host);
+
+  Ok(Host::Opaque(host))
+}
+
+/// Whether the byte is a [forbidden host code point](https://url.spec.whatwg.org/#forbidden-host-code-point).
+#[inline]
+fn is_forbidden_host_byte(byte: u8) -> bool {
+  matches!(
+    byte,
+    b'\0'
+      | b'\t'
+      | b'\n'
+      | b'\r'
+      | b' '
+      | b'#'
+      | b'/'
+      | b':'
+      | b'<'
+      | b'>'
+      | b'?'
+      | b'@'
+      | b'['
+      | b'\\'
+      | b']'
+      | b'^'
+      | b'|'
+  )
+}
+
+/// Whether the byte is a [forbidden domain code point](https://url.spec.whatwg.org/#forbidden-domain-code-point).
+#[inline]
+fn is_forbidden_domain_byte(byte: u8) -> bool {
+  is_forbidden_host_byte(byte) || byte < 0x20 || byte == b'%' || byte == 0x7F
+}
+
+/// Whether the last label of a domain is a number, in which case the domain is parsed as an IPv4
+/// address.
+fn ends_in_number(domain: &str) -> bool {
+  let mut labels = domain.rsplit('.');
+  let mut last = labels.next().unwrap_or_default();
+
+  if last.is_empty() {
+    match labels.next() {
+      Some(label) => last = label,
+      None => return false,
+    }
+  }
+
+  if !last.is_empty() && last.bytes().all(|byte| byte.is_ascii_digit()) {
+    return true;
+  }
+
+  parse_ipv4_number(last).is_some()
+}
+
+/// Parses an IPv4 address, where each of the up to 4 parts can be decimal, octal or hexadecimal.
+fn parse_ipv4(input: &str) -> Result<Ipv4Addr, ParseError> {
+  let mut parts = input.split('.').collect::<Vec<_>>();
+
+  if parts.len() > 1 && parts.last() == Some(&"") {
+    parts.pop();
+  }
+
+  if parts.len() > 4 {
+    return Err(ParseError::InvalidIpv4Address);
+  }
+
+  let mut numbers = [0; 4];
+
+  for (number, part) in numbers.iter_mut().zip(&parts) {
+    *number = parse_ipv4_number(part).ok_or(ParseError::InvalidIpv4Address)?;
+  }
+
+  let numbers = &numbers[..parts.len()];
+  let (&last, leading) = numbers.split_last().ok_or(ParseError::InvalidIpv4Address)?;
+
+  if leading.iter().any(|&number| number > 255) || last >= 1 << (8 * (5 - numbers.len())) {
+    return Err(ParseError::InvalidIpv4Address);
+  }
+
+  let address = leading
+    .iter()
+    .enumerate()
+    .fold(last, |address, (idx, &number)| {
+      address + (number << (8 * (3 - idx)))
+    });
+
+  Ok(Ipv4Addr::from(address as u32))
+}
+
+/// Parses a part of an IPv4 address, which is hexadecimal when prefixed by `0x` and octal when
+/// prefixed by `0`.
+///
+/// Numbers that don't fit in a [`u64`] saturate, as they're invalid parts either way.
+fn parse_ipv4_number(input: &str) -> Option<u64> {
+  if input.is_empty() {
+    return None;
+  }
+
+  let bytes = input.as_bytes();
+  let (digits, radix) = match bytes {
+    [b'0', b'x' | b'X', rest @ ..] => (rest, 16),
+    [b'0', rest @ ..] if !rest.is_empty() => (rest, 8),
+    _ => (bytes, 10),
+  };
+
+  digits.iter().try_fold(0u64, |number, &byte| {
+    let digit = (byte as char).to_digit(radix)?;
+
+    Some(
+      number
+        .saturating_mul(radix as u64)
+        .saturating_add(digit as u64),
+    )
+  })
+}
+
+/// Parses an IPv6 address, which may be compressed and may end with an IPv4 address.
+fn parse_ipv6(input: &str) -> Result<Ipv6Addr, ParseError> {
+  const ERROR: ParseError = ParseError::InvalidIpv6Address;
+
+  let input = input.as_bytes();
+  let mut address = [0u16; 8];
+  let mut piece_index = 0;
+  let mut compress = None;
+  let mut idx = 0;
+
+  if input.first() == Some(&b':') {
+    if input.get(1) != Some(&b':') {
+      return Err(ERROR);
+    }
+
+    idx += 2;
+    piece_index += 1;
+    compress = Some(piece_index);
+  }
+
+  while idx < input.len() {
+    if piece_index == 8 {
+      return Err(ERROR);
+    }
+
+    if input[idx] == b':' {
+      if compress.is_some() {
+        return Err(ERROR);
+      }
+
+      idx += 1;
+      piece_index += 1;
+      compress = Some(piece_index);
+      continue;
+    }
+
+    let mut value = 0;
+    let mut length = 0;
+
+    while length < 4 && idx < input.len() && input[idx].is_ascii_hexdigit() {
+      value = (value << 4) | (input[idx] as char).to_digit(16).unwrap_or_default() as u16;
+      idx += 1;
+      length += 1;
+    }
+
+    match input.get(idx) {
+      Some(b'.') => {
+        if length == 0 || piece_index > 6 {
+          return Err(ERROR);
+        }
+
+        idx -= length;
+        parse_ipv4_in_ipv6(&input[idx..], &mut address[piece_index..])?;
+        piece_index += 2;
+        break;
+      }
+      Some(b':') => {
+        idx += 1;
+
+        if idx == input.len() {
+          return Err(ERROR);
+        }
+      }
+      Some(_) => return Err(ERROR),
+      None => {}
+    }
+
+    address[piece_index] = value;
+    piece_index += 1;
+  }
+
+  match compress {
+    Some(compress) => {
+      let mut swaps = piece_index - compress;
+
+      piece_index = 7;
+
+      while piece_index != 0 && swaps > 0 {
+        address.swap(piece_index, compress + swaps - 1);
+        piece_index -= 1;
+        swaps -= 1;
+      }
+    }
+    None if piece_index != 8 => return Err(ERROR),
+    None => {}
+  }
+
+  Ok(Ipv6Addr::from(address))
+}
+
+/// Parses the dotted decimal IPv4 address that ends an IPv6 address into its last 2 pieces.
+fn parse_ipv4_in_ipv6(input: &[u8], pieces: &mut [u16]) -> Result<(), ParseError> {
+  const ERROR: ParseError = ParseError::InvalidIpv6Address;
+
+  let mut numbers_seen = 0;
+  let mut idx = 0;
+
+  while idx < input.len() {
+    if numbers_seen > 0 {
+      if input[idx] == b'.' && numbers_seen < 4 {
+        idx += 1;
+      } else {
+        return Err(ERROR);
+      }
+    }
+
+    let mut piece: Option<u16> = None;
+
+    while let Some(&digit @ b'0'..=b'9') = input.get(idx) {
+      let digit = (digit - b'0') as u16;
+
+      piece = match piece {
+        None => Some(digit),
+        // Leading zeros aren't permitted
+        Some(0) => return Err(ERROR),
+        Some(piece) => Some(piece * 10 + digit),
+      };
+
+      if piece > Some(255) {
+        return Err(ERROR);
+      }
+
+      idx += 1;
+    }
+
+    let piece = piece.ok_or(ERROR)?;
+
+    pieces[numbers_seen / 2] = (pieces[numbers_seen / 2] << 8) | piece;
+    numbers_seen += 1;
+  }
+
+  if numbers_seen != 4 {
+    return Err(ERROR);
+  }
+
+  Ok(())
+}
+
+/// Writes an IPv6 address, compressing the first longest run of zero pieces.
+fn fmt_ipv6(address: &Ipv6Addr, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+  let pieces = address.segments();
+  let mut compress = None;
+  let mut longest = 1;
+  let mut idx = 0;
+
+  while idx < pieces.len() {
+    let length = pieces[idx..]
+      .iter()
+      .take_while(|&&piece| piece == 0)
+      .count();
+
+    if length > longest {
+      longest = length;
+      compress = Some(idx);
+    }
+
+    idx += length.max(1);
+  }
+
+  let mut idx = 0;
+
+  while idx < pieces.len() {
+    if compress == Some(idx) {
+      f.write_str(if idx == 0 { "::" } else { ":" })?;
+      idx += longest;
+      continue;
+    }
+
+    write!(f, "{:x}", pieces[idx])?;
+
+    if idx != 7 {
+      f.write_str(":")?;
+    }
+
+    idx += 1;
+  }
+
+  Ok(())
+}
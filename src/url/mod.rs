@@ -0,0 +1,450 @@
+#[cfg(unix)]
+mod file_path;
+mod host;
+mod parser;
+mod pattern;
+mod search_params;
+
+#[cfg(unix)]
+pub use file_path::*;
+pub use pattern::*;
+pub use search_params::*;
+
+use crate::globals::{encode_str, USERINFO_SET};
+use host::Host;
+use parser::State;
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A URL, as defined by the [URL Standard](https://url.spec.whatwg.org/#concept-url).
+///
+/// # Notes
+/// The getters and setters behave the same as the ones of
+/// [JavaScript's URL](https://developer.mozilla.org/en-US/docs/Web/API/URL), where a setter that's
+/// given an invalid value leaves the URL unchanged.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Url {
+  scheme: String,
+  username: String,
+  password: String,
+  host: Option<Host>,
+  port: Option<u16>,
+  path: Path,
+  query: Option<String>,
+  fragment: Option<String>,
+}
+
+/// The path of a URL.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+enum Path {
+  /// The path of a URL that isn't special and has no `/` after its scheme.
+  Opaque(String),
+  /// The percent encoded segments of a path.
+  Segments(Vec<String>),
+}
+
+/// An error when parsing a URL.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ParseError {
+  /// The scheme contained an invalid character.
+  InvalidScheme,
+  /// The URL is relative, but there's no base URL or the base URL has an opaque path.
+  RelativeUrlWithoutBase,
+  /// The host was empty, but the URL requires one.
+  EmptyHost,
+  /// The host contained a forbidden character.
+  InvalidHostCharacter,
+  /// The domain couldn't be converted to ASCII.
+  InvalidDomain,
+  /// The host was an invalid IPv4 address.
+  InvalidIpv4Address,
+  /// The host was an invalid IPv6 address.
+  InvalidIpv6Address,
+  /// The port wasn't a number up to 65535.
+  InvalidPort,
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      ParseError::InvalidScheme => "invalid scheme",
+      ParseError::RelativeUrlWithoutBase => "relative URL without a base",
+      ParseError::EmptyHost => "empty host",
+      ParseError::InvalidHostCharacter => "invalid host character",
+      ParseError::InvalidDomain => "invalid domain",
+      ParseError::InvalidIpv4Address => "invalid IPv4 address",
+      ParseError::InvalidIpv6Address => "invalid IPv6 address",
+      ParseError::InvalidPort => "invalid port",
+    })
+  }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Url {
+  /// Parses an absolute URL, or a URL relative to `base`.
+  ///
+  /// # Notes
+  /// This function follows the [URL parser](https://url.spec.whatwg.org/#concept-url-parser) and
+  /// functionally behaves the same as JavaScript's `new URL(input, base)`.
+  pub fn parse(input: &str, base: Option<&Url>) -> Result<Url, ParseError> {
+    let input = input.trim_matches(|ch: char| ch <= ' ');
+    let mut url = Url::empty();
+
+    parser::parse(input, base, &mut url, None)?;
+
+    Ok(url)
+  }
+
+  fn empty() -> Url {
+    Url {
+      scheme: String::new(),
+      username: String::new(),
+      password: String::new(),
+      host: None,
+      port: None,
+      path: Path::Segments(Vec::new()),
+      query: None,
+      fragment: None,
+    }
+  }
+
+  /// Returns the serialized URL.
+  pub fn href(&self) -> String {
+    self.to_string()
+  }
+
+  /// Returns the serialized origin of the URL, which is `null` for URLs that don't have a host
+  /// that identifies them.
+  pub fn origin(&self) -> String {
+    match self.scheme.as_str() {
+      "blob" => match Url::parse(&self.pathname(), None) {
+        Ok(url) if url.scheme == "http" || url.scheme == "https" => url.origin(),
+        _ => "null".to_owned(),
+      },
+      "ftp" | "http" | "https" | "ws" | "wss" => {
+        format!("{}://{}", self.scheme, self.host())
+      }
+      _ => "null".to_owned(),
+    }
+  }
+
+  /// Returns the scheme, followed by `:`.
+  pub fn protocol(&self) -> String {
+    format!("{}:", self.scheme)
+  }
+
+  /// Returns the percent encoded username.
+  pub fn username(&self) -> &str {
+    &self.username
+  }
+
+  /// Returns the percent encoded password.
+  pub fn password(&self) -> &str {
+    &self.password
+  }
+
+  /// Returns the serialized host along with the port, if there is one.
+  pub fn host(&self) -> String {
+    match (&self.host, self.port) {
+      (Some(host), Some(port)) => format!("{}:{}", host, port),
+      (Some(host), None) => host.to_string(),
+      (None, _) => String::new(),
+    }
+  }
+
+  /// Returns the serialized host.
+  pub fn hostname(&self) -> String {
+    self
+      .host
+      .as_ref()
+      .map(ToString::to_string)
+      .unwrap_or_default()
+  }
+
+  /// Returns the port, which is empty when there's no port or the port is the default port of the
+  /// scheme.
+  pub fn port(&self) -> String {
+    self.port.map(|port| port.to_string()).unwrap_or_default()
+  }
+
+  /// Returns the serialized path.
+  pub fn pathname(&self) -> String {
+    match &self.path {
+      Path::Opaque(path) => path.clone(),
+      Path::Segments(segments) => segments.iter().fold(String::new(), |mut path, segment| {
+        path.push('/');
+        path.push_str(segment);
+        path
+      }),
+    }
+  }
+
+  /// Returns the query, prefixed by `?`, or an empty string when the query is empty.
+  pub fn search(&self) -> String {
+    match self.query.as_deref() {
+      None | Some("") => String::new(),
+      Some(query) => format!("?{}", query),
+    }
+  }
+
+  /// Returns the pairs of the query.
+  pub fn search_params(&self) -> UrlSearchParams {
+    let query = self.query.as_deref().unwrap_or_default();
+
+    parse_urlencoded(query.as_bytes()).into_iter().collect()
+  }
+
+  /// Returns the fragment, prefixed by `#`, or an empty string when the fragment is empty.
+  pub fn hash(&self) -> String {
+    match self.fragment.as_deref() {
+      None | Some("") => String::new(),
+      Some(fragment) => format!("#{}", fragment),
+    }
+  }
+
+  /// Replaces the URL by parsing `href`.
+  pub fn set_href(&mut self, href: &str) -> Result<(), ParseError> {
+    *self = Url::parse(href, None)?;
+
+    Ok(())
+  }
+
+  /// Sets the scheme, ignoring anything after `:`.
+  ///
+  /// # Notes
+  /// A special scheme, such as `http`, can't be changed to a scheme that isn't special and the
+  /// other way around.
+  pub fn set_protocol(&mut self, protocol: &str) {
+    let input = format!("{}:", protocol);
+
+    let _ = parser::parse(&input, None, self, Some(State::SchemeStart));
+  }
+
+  /// Sets the username, percent encoding it.
+  pub fn set_username(&mut self, username: &str) {
+    if self.cannot_have_credentials_or_port() {
+      return;
+    }
+
+    self.username.clear();
+    encode_str(username, &USERINFO_SET, &mut self.username);
+  }
+
+  /// Sets the password, percent encoding it.
+  pub fn set_password(&mut self, password: &str) {
+    if self.cannot_have_credentials_or_port() {
+      return;
+    }
+
+    self.password.clear();
+    encode_str(password, &USERINFO_SET, &mut self.password);
+  }
+
+  /// Sets the host, along with the port if one is given.
+  pub fn set_host(&mut self, host: &str) {
+    if self.has_opaque_path() {
+      return;
+    }
+
+    let _ = parser::parse(host, None, self, Some(State::Host));
+  }
+
+  /// Sets the host, leaving the port as is.
+  pub fn set_hostname(&mut self, hostname: &str) {
+    if self.has_opaque_path() {
+      return;
+    }
+
+    let _ = parser::parse(hostname, None, self, Some(State::Hostname));
+  }
+
+  /// Sets the port, which is removed when `port` is empty. Anything after the leading digits is
+  /// ignored.
+  pub fn set_port(&mut self, port: &str) {
+    if self.cannot_have_credentials_or_port() {
+      return;
+    }
+
+    if port.is_empty() {
+      self.port = None;
+      return;
+    }
+
+    let _ = parser::parse(port, None, self, Some(State::Port));
+  }
+
+  /// Sets the path.
+  pub fn set_pathname(&mut self, pathname: &str) {
+    if self.has_opaque_path() {
+      return;
+    }
+
+    self.path = Path::Segments(Vec::new());
+
+    let _ = parser::parse(pathname, None, self, Some(State::PathStart));
+  }
+
+  /// Sets the query, ignoring a leading `?`. The query is removed when `search` is empty.
+  pub fn set_search(&mut self, search: &str) {
+    if search.is_empty() {
+      self.query = None;
+      self.strip_trailing_spaces_from_opaque_path();
+      return;
+    }
+
+    let search = search.strip_prefix('?').unwrap_or(search);
+
+    self.query = Some(String::new());
+
+    let _ = parser::parse(search, None, self, Some(State::Query));
+  }
+
+  /// Sets the query to the serialized pairs. The query is removed when there are no pairs.
+  pub fn set_search_params(&mut self, params: &UrlSearchParams) {
+    let query = params.to_string();
+
+    if query.is_empty() {
+      self.query = None;
+      self.strip_trailing_spaces_from_opaque_path();
+    } else {
+      self.query = Some(query);
+    }
+  }
+
+  /// Sets the fragment, ignoring a leading `#`. The fragment is removed when `hash` is empty.
+  pub fn set_hash(&mut self, hash: &str) {
+    if hash.is_empty() {
+      self.fragment = None;
+      self.strip_trailing_spaces_from_opaque_path();
+      return;
+    }
+
+    let hash = hash.strip_prefix('#').unwrap_or(hash);
+
+    self.fragment = Some(String::new());
+
+    let _ = parser::parse(hash, None, self, Some(State::Fragment));
+  }
+
+  /// Whether the scheme is one of `ftp`, `file`, `http`, `https`, `ws` or `wss`.
+  pub fn is_special(&self) -> bool {
+    is_special_scheme(&self.scheme)
+  }
+
+  /// Whether the URL has an opaque path, like `mailto:user@example.com` does.
+  pub fn has_opaque_path(&self) -> bool {
+    matches!(self.path, Path::Opaque(_))
+  }
+
+  fn includes_credentials(&self) -> bool {
+    !self.username.is_empty() || !self.password.is_empty()
+  }
+
+  fn cannot_have_credentials_or_port(&self) -> bool {
+    matches!(self.host, None | Some(Host::Empty)) || self.scheme == "file"
+  }
+
+  /// Returns the segments of the path, replacing an opaque path with an empty path.
+  fn segments_mut(&mut self) -> &mut Vec<String> {
+    if let Path::Opaque(_) = self.path {
+      self.path = Path::Segments(Vec::new());
+    }
+
+    match &mut self.path {
+      Path::Segments(segments) => segments,
+      Path::Opaque(_) => unreachable!(),
+    }
+  }
+
+  /// Removes the last path segment, unless it's the drive letter of a file URL.
+  fn shorten_path(&mut self) {
+    let is_file = self.scheme == "file";
+    let segments = self.segments_mut();
+
+    if is_file && segments.len() == 1 && parser::is_normalized_windows_drive_letter(&segments[0]) {
+      return;
+    }
+
+    segments.pop();
+  }
+
+  fn strip_trailing_spaces_from_opaque_path(&mut self) {
+    if self.fragment.is_some() || self.query.is_some() {
+      return;
+    }
+
+    if let Path::Opaque(path) = &mut self.path {
+      path.truncate(path.trim_end_matches(' ').len());
+    }
+  }
+}
+
+impl fmt::Display for Url {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}:", self.scheme)?;
+
+    if let Some(host) = &self.host {
+      f.write_str("//")?;
+
+      if self.includes_credentials() {
+        f.write_str(&self.username)?;
+
+        if !self.password.is_empty() {
+          write!(f, ":{}", self.password)?;
+        }
+
+        f.write_str("@")?;
+      }
+
+      write!(f, "{}", host)?;
+
+      if let Some(port) = self.port {
+        write!(f, ":{}", port)?;
+      }
+    }
+
+    // Keeps a path starting with `//` from being read as a host.
+    if let (None, Path::Segments(segments)) = (&self.host, &self.path) {
+      if segments.len() > 1 && segments[0].is_empty() {
+        f.write_str("/.")?;
+      }
+    }
+
+    f.write_str(&self.pathname())?;
+
+    if let Some(query) = &self.query {
+      write!(f, "?{}", query)?;
+    }
+
+    if let Some(fragment) = &self.fragment {
+      write!(f, "#{}", fragment)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl FromStr for Url {
+  type Err = ParseError;
+
+  fn from_str(input: &str) -> Result<Self, Self::Err> {
+    Url::parse(input, None)
+  }
+}
+
+/// Whether the scheme is [special](https://url.spec.whatwg.org/#special-scheme).
+fn is_special_scheme(scheme: &str) -> bool {
+  matches!(scheme, "ftp" | "file" | "http" | "https" | "ws" | "wss")
+}
+
+/// The default port of a special scheme.
+fn default_port(scheme: &str) -> Option<u16> {
+  match scheme {
+    "ftp" => Some(21),
+    "http" | "ws" => Some(80),
+    "https" | "wss" => Some(443),
+    _ => None,
+  }
+}
@@ -0,0 +1,730 @@
+use crate::globals::{
+  encode_str, C0_CONTROL_SET, FRAGMENT_SET, PATH_SET, QUERY_SET, SPECIAL_QUERY_SET, USERINFO_SET,
+};
+use crate::url::host::Host;
+use crate::url::{default_port, is_special_scheme, ParseError, Path, Url};
+
+/// A state of the [basic URL parser](https://url.spec.whatwg.org/#concept-basic-url-parser).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum State {
+  SchemeStart,
+  Scheme,
+  NoScheme,
+  SpecialRelativeOrAuthority,
+  PathOrAuthority,
+  Relative,
+  RelativeSlash,
+  SpecialAuthoritySlashes,
+  SpecialAuthorityIgnoreSlashes,
+  Authority,
+  Host,
+  Hostname,
+  Port,
+  File,
+  FileSlash,
+  FileHost,
+  PathStart,
+  Path,
+  OpaquePath,
+  Query,
+  Fragment,
+}
+
+/// Runs the basic URL parser over `input`, modifying `url` in place.
+///
+/// When a state override is given, parsing stops early once the part of `url` that's being set is
+/// parsed. Any part that was set before a failure is kept, like the specification does.
+pub(crate) fn parse(
+  input: &str,
+  base: Option<&Url>,
+  url: &mut Url,
+  state_override: Option<State>,
+) -> Result<(), ParseError> {
+  let input = input
+    .chars()
+    .filter(|&ch| !matches!(ch, '\t' | '\n' | '\r'))
+    .collect::<Vec<_>>();
+
+  Parser {
+    input: &input,
+    base,
+    url,
+    state: state_override.unwrap_or(State::SchemeStart),
+    state_override,
+    buffer: String::new(),
+    at_sign_seen: false,
+    inside_brackets: false,
+    password_token_seen: false,
+    pointer: 0,
+  }
+  .run()
+}
+
+struct Parser<'a> {
+  input: &'a [char],
+  base: Option<&'a Url>,
+  url: &'a mut Url,
+  state: State,
+  state_override: Option<State>,
+  buffer: String,
+  at_sign_seen: bool,
+  inside_brackets: bool,
+  password_token_seen: bool,
+  // Signed, since the pointer is decreased to -1 to restart or to revisit the first character.
+  pointer: isize,
+}
+
+impl Parser<'_> {
+  fn run(&mut self) -> Result<(), ParseError> {
+    loop {
+      let c = self.current();
+
+      if self.step(c)? == Step::Return {
+        return Ok(());
+      }
+
+      if self.pointer >= self.input.len() as isize {
+        return Ok(());
+      }
+
+      self.pointer += 1;
+    }
+  }
+
+  /// The character at the pointer, where [`None`] is the end of the input.
+  #[inline]
+  fn current(&self) -> Option<char> {
+    if self.pointer < 0 {
+      return None;
+    }
+
+    self.input.get(self.pointer as usize).copied()
+  }
+
+  /// The characters after the pointer.
+  #[inline]
+  fn remaining(&self) -> &[char] {
+    self
+      .input
+      .get((self.pointer + 1).max(0) as usize..)
+      .unwrap_or_default()
+  }
+
+  #[inline]
+  fn is_special(&self) -> bool {
+    self.url.is_special()
+  }
+
+  /// Whether the character ends the authority, which `\` does for special URLs.
+  #[inline]
+  fn ends_authority(&self, c: Option<char>) -> bool {
+    match c {
+      None | Some('/' | '?' | '#') => true,
+      Some('\\') => self.is_special(),
+      _ => false,
+    }
+  }
+
+  /// Whether the character is a path separator, which `\` is for special URLs.
+  #[inline]
+  fn is_separator(&self, c: Option<char>) -> bool {
+    c == Some('/') || (c == Some('\\') && self.is_special())
+  }
+
+  fn step(&mut self, c: Option<char>) -> Result<Step, ParseError> {
+    match self.state {
+      State::SchemeStart => match c {
+        Some(c) if c.is_ascii_alphabetic() => {
+          self.buffer.push(c.to_ascii_lowercase());
+          self.state = State::Scheme;
+        }
+        _ if self.state_override.is_none() => {
+          self.state = State::NoScheme;
+          self.pointer -= 1;
+        }
+        _ => return Err(ParseError::InvalidScheme),
+      },
+      State::Scheme => match c {
+        Some(c) if c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.') => {
+          self.buffer.push(c.to_ascii_lowercase());
+        }
+        Some(':') => return self.scheme(),
+        _ if self.state_override.is_none() => {
+          self.buffer.clear();
+          self.state = State::NoScheme;
+          self.pointer = -1;
+        }
+        _ => return Err(ParseError::InvalidScheme),
+      },
+      State::NoScheme => {
+        let base = match self.base {
+          Some(base) if !base.has_opaque_path() || c == Some('#') => base,
+          _ => return Err(ParseError::RelativeUrlWithoutBase),
+        };
+
+        if base.has_opaque_path() {
+          self.url.scheme = base.scheme.clone();
+          self.url.path = base.path.clone();
+          self.url.query = base.query.clone();
+          self.url.fragment = Some(String::new());
+          self.state = State::Fragment;
+        } else {
+          self.state = if base.scheme == "file" {
+            State::File
+          } else {
+            State::Relative
+          };
+          self.pointer -= 1;
+        }
+      }
+      State::SpecialRelativeOrAuthority => {
+        if c == Some('/') && self.remaining().first() == Some(&'/') {
+          self.state = State::SpecialAuthorityIgnoreSlashes;
+          self.pointer += 1;
+        } else {
+          self.state = State::Relative;
+          self.pointer -= 1;
+        }
+      }
+      State::PathOrAuthority => {
+        if c == Some('/') {
+          self.state = State::Authority;
+        } else {
+          self.state = State::Path;
+          self.pointer -= 1;
+        }
+      }
+      State::Relative => self.relative(c),
+      State::RelativeSlash => {
+        if self.is_special() && matches!(c, Some('/' | '\\')) {
+          self.state = State::SpecialAuthorityIgnoreSlashes;
+        } else if c == Some('/') {
+          self.state = State::Authority;
+        } else {
+          if let Some(base) = self.base {
+            self.url.username = base.username.clone();
+            self.url.password = base.password.clone();
+            self.url.host = base.host.clone();
+            self.url.port = base.port;
+          }
+
+          self.state = State::Path;
+          self.pointer -= 1;
+        }
+      }
+      State::SpecialAuthoritySlashes => {
+        if c == Some('/') && self.remaining().first() == Some(&'/') {
+          self.pointer += 1;
+        } else {
+          self.pointer -= 1;
+        }
+
+        self.state = State::SpecialAuthorityIgnoreSlashes;
+      }
+      State::SpecialAuthorityIgnoreSlashes => {
+        if !matches!(c, Some('/' | '\\')) {
+          self.state = State::Authority;
+          self.pointer -= 1;
+        }
+      }
+      State::Authority => self.authority(c)?,
+      State::Host | State::Hostname => return self.host(c),
+      State::Port => return self.port(c),
+      State::File => self.file(c),
+      State::FileSlash => self.file_slash(c),
+      State::FileHost => return self.file_host(c),
+      State::PathStart => self.path_start(c),
+      State::Path => self.path(c),
+      State::OpaquePath => match c {
+        Some('?') => {
+          self.url.query = Some(String::new());
+          self.state = State::Query;
+        }
+        Some('#') => {
+          self.url.fragment = Some(String::new());
+          self.state = State::Fragment;
+        }
+        Some(c) => {
+          if let Path::Opaque(path) = &mut self.url.path {
+            encode_str(c.encode_utf8(&mut [0; 4]), &C0_CONTROL_SET, path);
+          }
+        }
+        None => {}
+      },
+      State::Query => match c {
+        Some('#') if self.state_override.is_none() => {
+          self.url.fragment = Some(String::new());
+          self.state = State::Fragment;
+        }
+        Some(c) => {
+          let set = if self.is_special() {
+            &SPECIAL_QUERY_SET
+          } else {
+            &QUERY_SET
+          };
+
+          let query = self.url.query.get_or_insert_with(String::new);
+
+          encode_str(c.encode_utf8(&mut [0; 4]), set, query);
+        }
+        None => {}
+      },
+      State::Fragment => {
+        if let Some(c) = c {
+          let fragment = self.url.fragment.get_or_insert_with(String::new);
+
+          encode_str(c.encode_utf8(&mut [0; 4]), &FRAGMENT_SET, fragment);
+        }
+      }
+    }
+
+    Ok(Step::Continue)
+  }
+
+  /// Sets the scheme once `:` ends it.
+  fn scheme(&mut self) -> Result<Step, ParseError> {
+    if self.state_override.is_some() {
+      let url = &*self.url;
+
+      // The specialness of a scheme can't change, and a file URL can't have credentials, a port
+      // or an empty host.
+      if url.is_special() != is_special_scheme(&self.buffer)
+        || (self.buffer == "file" && (url.includes_credentials() || url.port.is_some()))
+        || (url.scheme == "file" && url.host == Some(Host::Empty))
+      {
+        return Ok(Step::Return);
+      }
+    }
+
+    self.url.scheme = std::mem::take(&mut self.buffer);
+
+    if self.state_override.is_some() {
+      if self.url.port.is_some() && self.url.port == default_port(&self.url.scheme) {
+        self.url.port = None;
+      }
+
+      return Ok(Step::Return);
+    }
+
+    if self.url.scheme == "file" {
+      self.state = State::File;
+    } else if self.is_special() {
+      self.state = match self.base {
+        Some(base) if base.scheme == self.url.scheme => State::SpecialRelativeOrAuthority,
+        _ => State::SpecialAuthoritySlashes,
+      };
+    } else if self.remaining().first() == Some(&'/') {
+      self.state = State::PathOrAuthority;
+      self.pointer += 1;
+    } else {
+      self.url.path = Path::Opaque(String::new());
+      self.state = State::OpaquePath;
+    }
+
+    Ok(Step::Continue)
+  }
+
+  fn relative(&mut self, c: Option<char>) {
+    let base = match self.base {
+      Some(base) => base,
+      None => return,
+    };
+
+    self.url.scheme = base.scheme.clone();
+
+    if self.is_separator(c) {
+      self.state = State::RelativeSlash;
+      return;
+    }
+
+    self.url.username = base.username.clone();
+    self.url.password = base.password.clone();
+    self.url.host = base.host.clone();
+    self.url.port = base.port;
+    self.url.path = base.path.clone();
+    self.url.query = base.query.clone();
+
+    match c {
+      Some('?') => {
+        self.url.query = Some(String::new());
+        self.state = State::Query;
+      }
+      Some('#') => {
+        self.url.fragment = Some(String::new());
+        self.state = State::Fragment;
+      }
+      Some(_) => {
+        self.url.query = None;
+        self.url.shorten_path();
+        self.state = State::Path;
+        self.pointer -= 1;
+      }
+      None => {}
+    }
+  }
+
+  fn authority(&mut self, c: Option<char>) -> Result<(), ParseError> {
+    if c == Some('@') {
+      if self.at_sign_seen {
+        self.buffer.insert_str(0, "%40");
+      }
+
+      self.at_sign_seen = true;
+
+      for ch in self.buffer.chars() {
+        if ch == ':' && !self.password_token_seen {
+          self.password_token_seen = true;
+          continue;
+        }
+
+        let credential = if self.password_token_seen {
+          &mut self.url.password
+        } else {
+          &mut self.url.username
+        };
+
+        encode_str(ch.encode_utf8(&mut [0; 4]), &USERINFO_SET, credential);
+      }
+
+      self.buffer.clear();
+    } else if self.ends_authority(c) {
+      if self.at_sign_seen && self.buffer.is_empty() {
+        return Err(ParseError::EmptyHost);
+      }
+
+      self.pointer -= self.buffer.chars().count() as isize + 1;
+      self.buffer.clear();
+      self.state = State::Host;
+    } else if let Some(c) = c {
+      self.buffer.push(c);
+    }
+
+    Ok(())
+  }
+
+  fn host(&mut self, c: Option<char>) -> Result<Step, ParseError> {
+    if self.state_override.is_some() && self.url.scheme == "file" {
+      self.pointer -= 1;
+      self.state = State::FileHost;
+    } else if c == Some(':') && !self.inside_brackets {
+      if self.buffer.is_empty() {
+        return Err(ParseError::EmptyHost);
+      }
+
+      if self.state_override == Some(State::Hostname) {
+        return Err(ParseError::InvalidHostCharacter);
+      }
+
+      self.url.host = Some(Host::parse(&self.buffer, !self.is_special())?);
+      self.buffer.clear();
+      self.state = State::Port;
+    } else if self.ends_authority(c) {
+      self.pointer -= 1;
+
+      if self.buffer.is_empty() {
+        if self.is_special() {
+          return Err(ParseError::EmptyHost);
+        }
+
+        if self.state_override.is_some()
+          && (self.url.includes_credentials() || self.url.port.is_some())
+        {
+          return Ok(Step::Return);
+        }
+      }
+
+      self.url.host = Some(Host::parse(&self.buffer, !self.is_special())?);
+      self.buffer.clear();
+      self.state = State::PathStart;
+
+      if self.state_override.is_some() {
+        return Ok(Step::Return);
+      }
+    } else if let Some(c) = c {
+      match c {
+        '[' => self.inside_brackets = true,
+        ']' => self.inside_brackets = false,
+        _ => {}
+      }
+
+      self.buffer.push(c);
+    }
+
+    Ok(Step::Continue)
+  }
+
+  fn port(&mut self, c: Option<char>) -> Result<Step, ParseError> {
+    match c {
+      Some(c) if c.is_ascii_digit() => self.buffer.push(c),
+      _ if self.ends_authority(c) || self.state_override.is_some() => {
+        if !self.buffer.is_empty() {
+          let port = self
+            .buffer
+            .bytes()
+            .try_fold(0u32, |port, digit| {
+              let port = port * 10 + (digit - b'0') as u32;
+
+              if port > u16::MAX as u32 {
+                None
+              } else {
+                Some(port)
+              }
+            })
+            .ok_or(ParseError::InvalidPort)? as u16;
+
+          self.url.port = if default_port(&self.url.scheme) == Some(port) {
+            None
+          } else {
+            Some(port)
+          };
+          self.buffer.clear();
+
+          if self.state_override.is_some() {
+            return Ok(Step::Return);
+          }
+        }
+
+        if self.state_override.is_some() {
+          return Err(ParseError::InvalidPort);
+        }
+
+        self.state = State::PathStart;
+        self.pointer -= 1;
+      }
+      _ => return Err(ParseError::InvalidPort),
+    }
+
+    Ok(Step::Continue)
+  }
+
+  fn file(&mut self, c: Option<char>) {
+    self.url.scheme = "file".to_owned();
+    self.url.host = Some(Host::Empty);
+
+    if matches!(c, Some('/' | '\\')) {
+      self.state = State::FileSlash;
+      return;
+    }
+
+    match self.base {
+      Some(base) if base.scheme == "file" => {
+        self.url.host = base.host.clone();
+        self.url.path = base.path.clone();
+        self.url.query = base.query.clone();
+
+        match c {
+          Some('?') => {
+            self.url.query = Some(String::new());
+            self.state = State::Query;
+          }
+          Some('#') => {
+            self.url.fragment = Some(String::new());
+            self.state = State::Fragment;
+          }
+          Some(_) => {
+            self.url.query = None;
+
+            if starts_with_windows_drive_letter(&self.input[self.pointer as usize..]) {
+              self.url.path = Path::Segments(Vec::new());
+            } else {
+              self.url.shorten_path();
+            }
+
+            self.state = State::Path;
+            self.pointer -= 1;
+          }
+          None => {}
+        }
+      }
+      _ => {
+        self.state = State::Path;
+        self.pointer -= 1;
+      }
+    }
+  }
+
+  fn file_slash(&mut self, c: Option<char>) {
+    if matches!(c, Some('/' | '\\')) {
+      self.state = State::FileHost;
+      return;
+    }
+
+    if let Some(base) = self.base.filter(|base| base.scheme == "file") {
+      self.url.host = base.host.clone();
+
+      let rest = self.input.get(self.pointer as usize..).unwrap_or_default();
+
+      if !starts_with_windows_drive_letter(rest) {
+        if let Path::Segments(segments) = &base.path {
+          match segments.first() {
+            Some(first) if is_normalized_windows_drive_letter(first) => {
+              self.url.path = Path::Segments(vec![first.clone()]);
+            }
+            _ => {}
+          }
+        }
+      }
+    }
+
+    self.state = State::Path;
+    self.pointer -= 1;
+  }
+
+  fn file_host(&mut self, c: Option<char>) -> Result<Step, ParseError> {
+    if !matches!(c, None | Some('/' | '\\' | '?' | '#')) {
+      if let Some(c) = c {
+        self.buffer.push(c);
+      }
+
+      return Ok(Step::Continue);
+    }
+
+    self.pointer -= 1;
+
+    if self.state_override.is_none() && is_windows_drive_letter(&self.buffer) {
+      // The buffer isn't reset, so that the drive letter becomes the first path segment.
+      self.state = State::Path;
+    } else if self.buffer.is_empty() {
+      self.url.host = Some(Host::Empty);
+
+      if self.state_override.is_some() {
+        return Ok(Step::Return);
+      }
+
+      self.state = State::PathStart;
+    } else {
+      let mut host = Host::parse(&self.buffer, !self.is_special())?;
+
+      if host == Host::Domain("localhost".to_owned()) {
+        host = Host::Empty;
+      }
+
+      self.url.host = Some(host);
+
+      if self.state_override.is_some() {
+        return Ok(Step::Return);
+      }
+
+      self.buffer.clear();
+      self.state = State::PathStart;
+    }
+
+    Ok(Step::Continue)
+  }
+
+  fn path_start(&mut self, c: Option<char>) {
+    if self.is_special() {
+      self.state = State::Path;
+
+      if !matches!(c, Some('/' | '\\')) {
+        self.pointer -= 1;
+      }
+    } else if self.state_override.is_none() && c == Some('?') {
+      self.url.query = Some(String::new());
+      self.state = State::Query;
+    } else if self.state_override.is_none() && c == Some('#') {
+      self.url.fragment = Some(String::new());
+      self.state = State::Fragment;
+    } else if c.is_some() {
+      self.state = State::Path;
+
+      if c != Some('/') {
+        self.pointer -= 1;
+      }
+    } else if self.state_override.is_some() && self.url.host.is_none() {
+      self.url.segments_mut().push(String::new());
+    }
+  }
+
+  fn path(&mut self, c: Option<char>) {
+    let is_end = c.is_none() || (self.state_override.is_none() && matches!(c, Some('?' | '#')));
+
+    if !is_end && !self.is_separator(c) {
+      if let Some(c) = c {
+        encode_str(c.encode_utf8(&mut [0; 4]), &PATH_SET, &mut self.buffer);
+      }
+
+      return;
+    }
+
+    let is_separator = self.is_separator(c);
+
+    if is_double_dot_segment(&self.buffer) {
+      self.url.shorten_path();
+
+      if !is_separator {
+        self.url.segments_mut().push(String::new());
+      }
+    } else if is_single_dot_segment(&self.buffer) {
+      if !is_separator {
+        self.url.segments_mut().push(String::new());
+      }
+    } else {
+      let mut segment = std::mem::take(&mut self.buffer);
+
+      if self.url.scheme == "file"
+        && self.url.segments_mut().is_empty()
+        && is_windows_drive_letter(&segment)
+      {
+        segment.replace_range(1..2, ":");
+      }
+
+      self.url.segments_mut().push(segment);
+    }
+
+    self.buffer.clear();
+
+    match c {
+      Some('?') if is_end => {
+        self.url.query = Some(String::new());
+        self.state = State::Query;
+      }
+      Some('#') if is_end => {
+        self.url.fragment = Some(String::new());
+        self.state = State::Fragment;
+      }
+      _ => {}
+    }
+  }
+}
+
+/// What the parser does after a step.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Step {
+  /// Moves on to the next character.
+  Continue,
+  /// Stops parsing successfully.
+  Return,
+}
+
+/// Whether the segment is `.` or its percent encoded variant.
+fn is_single_dot_segment(segment: &str) -> bool {
+  segment == "." || segment.eq_ignore_ascii_case("%2e")
+}
+
+/// Whether the segment is `..` or any of its percent encoded variants.
+fn is_double_dot_segment(segment: &str) -> bool {
+  matches!(
+    segment.to_ascii_lowercase().as_str(),
+    ".." | ".%2e" | "%2e." | "%2e%2e"
+  )
+}
+
+/// Whether the string is an ASCII letter followed by `:` or `|`.
+pub(crate) fn is_windows_drive_letter(string: &str) -> bool {
+  matches!(string.as_bytes(), [letter, b':' | b'|'] if letter.is_ascii_alphabetic())
+}
+
+/// Whether the string is an ASCII letter followed by `:`.
+pub(crate) fn is_normalized_windows_drive_letter(string: &str) -> bool {
+  matches!(string.as_bytes(), [letter, b':'] if letter.is_ascii_alphabetic())
+}
+
+/// Whether the characters start with a Windows drive letter that's the whole first segment.
+fn starts_with_windows_drive_letter(input: &[char]) -> bool {
+  match input {
+    [letter, ':' | '|'] => letter.is_ascii_alphabetic(),
+    [letter, ':' | '|', '/' | '\\' | '?' | '#', ..] => letter.is_ascii_alphabetic(),
+    _ => false,
+  }
+}
@@ -0,0 +1,121 @@
+use super::UrlPatternError;
+use crate::globals::{encode_str, AsciiSet, C0_CONTROL_SET, FRAGMENT_SET, QUERY_SET, USERINFO_SET};
+use crate::url::parser::{self, State};
+use crate::url::Url;
+
+/// Percent encodes a string, removing ASCII tabs and newlines like the URL parser does when
+/// `strip_newlines` is set.
+fn encode(value: &str, set: &AsciiSet, strip_newlines: bool) -> String {
+  let mut encoded = String::with_capacity(value.len());
+
+  if strip_newlines && value.contains(['\t', '\n', '\r']) {
+    encode_str(&value.replace(['\t', '\n', '\r'], ""), set, &mut encoded);
+  } else {
+    encode_str(value, set, &mut encoded);
+  }
+
+  encoded
+}
+
+pub(super) fn protocol(value: &str) -> Result<String, UrlPatternError> {
+  if value.is_empty() {
+    return Ok(String::new());
+  }
+
+  Url::parse(&format!("{}://dummy.invalid/", value), None)
+    .map(|url| url.scheme)
+    .map_err(|_| UrlPatternError::InvalidComponent)
+}
+
+pub(super) fn username(value: &str) -> Result<String, UrlPatternError> {
+  Ok(encode(value, &USERINFO_SET, false))
+}
+
+pub(super) fn password(value: &str) -> Result<String, UrlPatternError> {
+  Ok(encode(value, &USERINFO_SET, false))
+}
+
+/// Canonicalizes a hostname, which is parsed as the host of a special URL unless `protocol` is a
+/// scheme that isn't special.
+pub(super) fn hostname(value: &str, protocol: Option<&str>) -> Result<String, UrlPatternError> {
+  if value.is_empty() {
+    return Ok(String::new());
+  }
+
+  let mut url = Url::empty();
+
+  url.scheme = match protocol {
+    Some(protocol) if !protocol.is_empty() => protocol.to_owned(),
+    _ => "https".to_owned(),
+  };
+
+  parser::parse(value, None, &mut url, Some(State::Hostname))
+    .map_err(|_| UrlPatternError::InvalidComponent)?;
+
+  Ok(url.hostname())
+}
+
+pub(super) fn ipv6_hostname(value: &str) -> Result<String, UrlPatternError> {
+  if !value
+    .chars()
+    .all(|ch| ch.is_ascii_hexdigit() || matches!(ch, '[' | ']' | ':'))
+  {
+    return Err(UrlPatternError::InvalidComponent);
+  }
+
+  Ok(value.to_ascii_lowercase())
+}
+
+/// Canonicalizes a port, which is empty when it's the default port of `protocol`.
+pub(super) fn port(value: &str, protocol: Option<&str>) -> Result<String, UrlPatternError> {
+  if value.is_empty() {
+    return Ok(String::new());
+  }
+
+  let mut url = Url::empty();
+
+  url.scheme = protocol.unwrap_or_default().to_owned();
+
+  parser::parse(value, None, &mut url, Some(State::Port))
+    .map_err(|_| UrlPatternError::InvalidComponent)?;
+
+  Ok(url.port())
+}
+
+/// Canonicalizes a path, resolving its dot segments.
+pub(super) fn pathname(value: &str) -> Result<String, UrlPatternError> {
+  if value.is_empty() {
+    return Ok(String::new());
+  }
+
+  // A relative path is made absolute, so that its first segment is parsed like the others
+  let has_leading_slash = value.starts_with('/');
+  let value = if has_leading_slash {
+    value.to_owned()
+  } else {
+    format!("/-{}", value)
+  };
+  let mut url = Url::empty();
+
+  parser::parse(&value, None, &mut url, Some(State::PathStart))
+    .map_err(|_| UrlPatternError::InvalidComponent)?;
+
+  let pathname = url.pathname();
+
+  match pathname.strip_prefix("/-") {
+    Some(stripped) if !has_leading_slash => Ok(stripped.to_owned()),
+    _ => Ok(pathname),
+  }
+}
+
+pub(super) fn opaque_pathname(value: &str) -> Result<String, UrlPatternError> {
+  Ok(encode(value, &C0_CONTROL_SET, true))
+}
+
+pub(super) fn search(value: &str) -> Result<String, UrlPatternError> {
+  Ok(encode(value, &QUERY_SET, true))
+}
+
+pub(super) fn hash(value: &str) -> Result<String, UrlPatternError> {
+  Ok(encode(value, &FRAGMENT_SET, true))
+}
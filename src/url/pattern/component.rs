@@ -0,0 +1,261 @@
+use super::parser::{
+  self, escape_pattern, escape_regexp, Encode, Modifier, Options, Part, PartKind,
+  FULL_WILDCARD_REGEXP,
+};
+use super::regexp::RegExp;
+use super::tokenizer::is_valid_name_char;
+use super::UrlPatternError;
+
+/// A compiled [component](https://urlpattern.spec.whatwg.org/#component) of a pattern.
+#[derive(Clone, Debug)]
+pub(super) struct Component {
+  /// The normalized pattern string.
+  pub(super) pattern: String,
+  regexp: RegExp,
+  group_names: Vec<String>,
+  pub(super) has_regexp_groups: bool,
+}
+
+impl Component {
+  /// Compiles a pattern string, where a missing pattern matches anything.
+  pub(super) fn compile(
+    input: Option<&str>,
+    encode: Encode<'_>,
+    options: &Options,
+  ) -> Result<Component, UrlPatternError> {
+    let parts = parser::parse(input.unwrap_or("*"), options, encode)?;
+    let (regexp, group_names) = generate_regexp(&parts, options);
+    let regexp =
+      RegExp::new(&regexp, options.ignore_case).map_err(|_| UrlPatternError::InvalidRegExp)?;
+
+    Ok(Component {
+      pattern: generate_pattern(&parts, options),
+      regexp,
+      group_names,
+      has_regexp_groups: parts.iter().any(|part| part.kind == PartKind::Regexp),
+    })
+  }
+
+  /// Matches the component against an input, returning the named groups.
+  pub(super) fn exec(&self, input: &str) -> Option<Vec<(String, Option<String>)>> {
+    let captures = self.regexp.captures(input)?;
+
+    Some(
+      self
+        .group_names
+        .iter()
+        .zip(captures)
+        .map(|(name, capture)| (name.clone(), capture.map(str::to_owned)))
+        .collect(),
+    )
+  }
+
+  /// Whether the component matches one of the special schemes, when it's a protocol component.
+  pub(super) fn matches_special_scheme(&self) -> bool {
+    ["ftp", "file", "http", "https", "ws", "wss"]
+      .iter()
+      .any(|scheme| self.regexp.captures(scheme).is_some())
+  }
+}
+
+/// Generates the regexp of a list of parts, along with the names of its groups.
+fn generate_regexp(parts: &[Part], options: &Options) -> (String, Vec<String>) {
+  let mut regexp = String::from("^");
+  let mut names = Vec::new();
+
+  for part in parts {
+    if part.kind == PartKind::FixedText {
+      if part.modifier == Modifier::None {
+        escape_regexp(&part.value, &mut regexp);
+      } else {
+        regexp.push_str("(?:");
+        escape_regexp(&part.value, &mut regexp);
+        regexp.push(')');
+        regexp.push_str(part.modifier.as_str());
+      }
+
+      continue;
+    }
+
+    names.push(part.name.clone());
+
+    let value = match part.kind {
+      PartKind::SegmentWildcard => options.segment_wildcard_regexp(),
+      PartKind::FullWildcard => FULL_WILDCARD_REGEXP.to_owned(),
+      _ => part.value.clone(),
+    };
+    let is_single = matches!(part.modifier, Modifier::None | Modifier::Optional);
+
+    if part.prefix.is_empty() && part.suffix.is_empty() {
+      if is_single {
+        regexp.push('(');
+        regexp.push_str(&value);
+        regexp.push(')');
+        regexp.push_str(part.modifier.as_str());
+      } else {
+        regexp.push_str("((?:");
+        regexp.push_str(&value);
+        regexp.push(')');
+        regexp.push_str(part.modifier.as_str());
+        regexp.push(')');
+      }
+
+      continue;
+    }
+
+    regexp.push_str("(?:");
+    escape_regexp(&part.prefix, &mut regexp);
+
+    if is_single {
+      regexp.push('(');
+      regexp.push_str(&value);
+      regexp.push(')');
+      escape_regexp(&part.suffix, &mut regexp);
+      regexp.push(')');
+      regexp.push_str(part.modifier.as_str());
+      continue;
+    }
+
+    // Repeated groups are captured together, with the prefix and suffix between each value
+    regexp.push_str("((?:");
+    regexp.push_str(&value);
+    regexp.push_str(")(?:");
+    escape_regexp(&part.suffix, &mut regexp);
+    escape_regexp(&part.prefix, &mut regexp);
+    regexp.push_str("(?:");
+    regexp.push_str(&value);
+    regexp.push_str("))*)");
+    escape_regexp(&part.suffix, &mut regexp);
+    regexp.push(')');
+
+    if part.modifier == Modifier::ZeroOrMore {
+      regexp.push('?');
+    }
+  }
+
+  regexp.push('$');
+
+  (regexp, names)
+}
+
+/// Generates the normalized pattern string of a list of parts, following the
+/// [generate a pattern string](https://urlpattern.spec.whatwg.org/#generate-a-pattern-string)
+/// algorithm.
+fn generate_pattern(parts: &[Part], options: &Options) -> String {
+  let mut pattern = String::new();
+
+  for (idx, part) in parts.iter().enumerate() {
+    let previous = idx.checked_sub(1).map(|idx| &parts[idx]);
+    let next = parts.get(idx + 1);
+
+    if part.kind == PartKind::FixedText {
+      if part.modifier == Modifier::None {
+        escape_pattern(&part.value, &mut pattern);
+      } else {
+        pattern.push('{');
+        escape_pattern(&part.value, &mut pattern);
+        pattern.push('}');
+        pattern.push_str(part.modifier.as_str());
+      }
+
+      continue;
+    }
+
+    let has_custom_name = !part.name.starts_with(|ch: char| ch.is_ascii_digit());
+    let mut needs_grouping =
+      !part.suffix.is_empty() || (!part.prefix.is_empty() && !options.is_prefix(&part.prefix));
+
+    // A name would otherwise run into the text or the regexp after it
+    if let Some(next) = next {
+      if !needs_grouping
+        && has_custom_name
+        && part.kind == PartKind::SegmentWildcard
+        && part.modifier == Modifier::None
+        && next.prefix.is_empty()
+        && next.suffix.is_empty()
+      {
+        needs_grouping = if next.kind == PartKind::FixedText {
+          next
+            .value
+            .chars()
+            .next()
+            .is_some_and(|ch| is_valid_name_char(ch, false))
+        } else {
+          next.name.starts_with(|ch: char| ch.is_ascii_digit())
+        };
+      }
+    }
+
+    // The prefix would otherwise become part of the group
+    if let Some(previous) = previous {
+      if !needs_grouping
+        && part.prefix.is_empty()
+        && previous.kind == PartKind::FixedText
+        && previous.value.chars().next_back() == options.prefix
+        && options.prefix.is_some()
+      {
+        needs_grouping = true;
+      }
+    }
+
+    if needs_grouping {
+      pattern.push('{');
+    }
+
+    escape_pattern(&part.prefix, &mut pattern);
+
+    if has_custom_name {
+      pattern.push(':');
+      pattern.push_str(&part.name);
+    }
+
+    match part.kind {
+      PartKind::Regexp => {
+        pattern.push('(');
+        pattern.push_str(&part.value);
+        pattern.push(')');
+      }
+      PartKind::SegmentWildcard if !has_custom_name => {
+        pattern.push('(');
+        pattern.push_str(&options.segment_wildcard_regexp());
+        pattern.push(')');
+      }
+      PartKind::FullWildcard => {
+        let is_standalone = previous.is_none_or(|previous| {
+          previous.kind == PartKind::FixedText || previous.modifier != Modifier::None
+        });
+
+        if !has_custom_name && (is_standalone || needs_grouping || !part.prefix.is_empty()) {
+          pattern.push('*');
+        } else {
+          pattern.push('(');
+          pattern.push_str(FULL_WILDCARD_REGEXP);
+          pattern.push(')');
+        }
+      }
+      _ => {}
+    }
+
+    // Escapes the suffix, so that it isn't read as part of the name
+    if part.kind == PartKind::SegmentWildcard
+      && has_custom_name
+      && part
+        .suffix
+        .chars()
+        .next()
+        .is_some_and(|ch| is_valid_name_char(ch, false))
+    {
+      pattern.push('\\');
+    }
+
+    escape_pattern(&part.suffix, &mut pattern);
+
+    if needs_grouping {
+      pattern.push('}');
+    }
+
+    pattern.push_str(part.modifier.as_str());
+  }
+
+  pattern
+}
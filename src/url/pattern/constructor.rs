@@ -0,0 +1,319 @@
+use super::canonicalize;
+use super::component::Component;
+use super::parser::Options;
+use super::tokenizer::{tokenize, Policy, Token, TokenKind};
+use super::{UrlPatternError, UrlPatternInit};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum State {
+  Init,
+  Protocol,
+  Authority,
+  Username,
+  Password,
+  Hostname,
+  Port,
+  Pathname,
+  Search,
+  Hash,
+  Done,
+}
+
+/// Splits a constructor string like `https://*.example.com/users/:id` into the pattern strings of
+/// its components, following the
+/// [parse a constructor string](https://urlpattern.spec.whatwg.org/#parse-a-constructor-string)
+/// algorithm.
+pub(super) fn parse(input: &str) -> Result<UrlPatternInit, UrlPatternError> {
+  let mut parser = Parser {
+    input,
+    tokens: tokenize(input, Policy::Lenient)?,
+    result: UrlPatternInit::default(),
+    component_start: 0,
+    index: 0,
+    increment: 1,
+    group_depth: 0,
+    ipv6_bracket_depth: 0,
+    protocol_matches_special_scheme: false,
+    state: State::Init,
+  };
+
+  while parser.index < parser.tokens.len() {
+    parser.increment = 1;
+
+    if parser.tokens[parser.index].kind == TokenKind::End {
+      match parser.state {
+        State::Init => {
+          // The string has no protocol, so it's relative to the base URL
+          parser.rewind();
+
+          if parser.is_hash_prefix() {
+            parser.change_state(State::Hash, 1);
+          } else if parser.is_search_prefix() {
+            parser.change_state(State::Search, 1);
+          } else {
+            parser.change_state(State::Pathname, 0);
+          }
+
+          parser.index += parser.increment;
+          continue;
+        }
+        State::Authority => {
+          parser.rewind_and_set_state(State::Hostname);
+          parser.index += parser.increment;
+          continue;
+        }
+        _ => {
+          parser.change_state(State::Done, 0);
+          break;
+        }
+      }
+    }
+
+    if parser.tokens[parser.index].kind == TokenKind::Open {
+      parser.group_depth += 1;
+      parser.index += parser.increment;
+      continue;
+    }
+
+    if parser.group_depth > 0 {
+      if parser.tokens[parser.index].kind == TokenKind::Close {
+        parser.group_depth -= 1;
+      } else {
+        parser.index += parser.increment;
+        continue;
+      }
+    }
+
+    match parser.state {
+      State::Init => {
+        if parser.is_char(parser.index, ":") {
+          parser.rewind_and_set_state(State::Protocol);
+        }
+      }
+      State::Protocol => {
+        if parser.is_char(parser.index, ":") {
+          parser.compute_protocol_matches_special_scheme()?;
+
+          if parser.is_char(parser.index + 1, "/") && parser.is_char(parser.index + 2, "/") {
+            parser.change_state(State::Authority, 3);
+          } else if parser.protocol_matches_special_scheme {
+            parser.change_state(State::Authority, 1);
+          } else {
+            parser.change_state(State::Pathname, 1);
+          }
+        }
+      }
+      State::Authority => {
+        if parser.is_char(parser.index, "@") {
+          parser.rewind_and_set_state(State::Username);
+        } else if parser.is_char(parser.index, "/")
+          || parser.is_search_prefix()
+          || parser.is_hash_prefix()
+        {
+          parser.rewind_and_set_state(State::Hostname);
+        }
+      }
+      State::Username => {
+        if parser.is_char(parser.index, ":") {
+          parser.change_state(State::Password, 1);
+        } else if parser.is_char(parser.index, "@") {
+          parser.change_state(State::Hostname, 1);
+        }
+      }
+      State::Password => {
+        if parser.is_char(parser.index, "@") {
+          parser.change_state(State::Hostname, 1);
+        }
+      }
+      State::Hostname => {
+        if parser.is_char(parser.index, "[") {
+          parser.ipv6_bracket_depth += 1;
+        } else if parser.is_char(parser.index, "]") {
+          parser.ipv6_bracket_depth = parser.ipv6_bracket_depth.saturating_sub(1);
+        } else if parser.is_char(parser.index, ":") && parser.ipv6_bracket_depth == 0 {
+          parser.change_state(State::Port, 1);
+        } else if parser.is_char(parser.index, "/") {
+          parser.change_state(State::Pathname, 0);
+        } else if parser.is_search_prefix() {
+          parser.change_state(State::Search, 1);
+        } else if parser.is_hash_prefix() {
+          parser.change_state(State::Hash, 1);
+        }
+      }
+      State::Port => {
+        if parser.is_char(parser.index, "/") {
+          parser.change_state(State::Pathname, 0);
+        } else if parser.is_search_prefix() {
+          parser.change_state(State::Search, 1);
+        } else if parser.is_hash_prefix() {
+          parser.change_state(State::Hash, 1);
+        }
+      }
+      State::Pathname => {
+        if parser.is_search_prefix() {
+          parser.change_state(State::Search, 1);
+        } else if parser.is_hash_prefix() {
+          parser.change_state(State::Hash, 1);
+        }
+      }
+      State::Search => {
+        if parser.is_hash_prefix() {
+          parser.change_state(State::Hash, 1);
+        }
+      }
+      State::Hash | State::Done => {}
+    }
+
+    parser.index += parser.increment;
+  }
+
+  if parser.result.hostname.is_some() && parser.result.port.is_none() {
+    parser.result.port = Some(String::new());
+  }
+
+  Ok(parser.result)
+}
+
+struct Parser<'a> {
+  input: &'a str,
+  tokens: Vec<Token<'a>>,
+  result: UrlPatternInit,
+  /// The index of the first token of the current component.
+  component_start: usize,
+  index: usize,
+  increment: usize,
+  group_depth: usize,
+  ipv6_bracket_depth: usize,
+  protocol_matches_special_scheme: bool,
+  state: State,
+}
+
+impl Parser<'_> {
+  /// Returns a token, or the end token when the index is past it.
+  fn token(&self, index: usize) -> &Token<'_> {
+    self
+      .tokens
+      .get(index)
+      .unwrap_or_else(|| &self.tokens[self.tokens.len() - 1])
+  }
+
+  /// Whether a token is the given character, and not a special character of a pattern string.
+  fn is_char(&self, index: usize, value: &str) -> bool {
+    let token = self.token(index);
+
+    token.value == value
+      && matches!(
+        token.kind,
+        TokenKind::Char | TokenKind::EscapedChar | TokenKind::InvalidChar
+      )
+  }
+
+  fn is_hash_prefix(&self) -> bool {
+    self.is_char(self.index, "#")
+  }
+
+  /// Whether the token is a `?` that starts the search, rather than a modifier.
+  fn is_search_prefix(&self) -> bool {
+    if self.is_char(self.index, "?") {
+      return true;
+    }
+
+    if self.tokens[self.index].value != "?" {
+      return false;
+    }
+
+    if self.index == 0 {
+      return true;
+    }
+
+    !matches!(
+      self.token(self.index - 1).kind,
+      TokenKind::Name | TokenKind::Regexp | TokenKind::Close | TokenKind::Asterisk
+    )
+  }
+
+  fn rewind(&mut self) {
+    self.index = self.component_start;
+    self.increment = 0;
+  }
+
+  fn rewind_and_set_state(&mut self, state: State) {
+    self.rewind();
+    self.state = state;
+  }
+
+  /// Returns the input from the start of the current component up to the current token.
+  fn component_string(&self) -> String {
+    let start = self.token(self.component_start).index;
+    let end = self.tokens[self.index].index;
+
+    self.input[start..end].to_owned()
+  }
+
+  fn change_state(&mut self, state: State, skip: usize) {
+    let value = self.component_string();
+    let result = &mut self.result;
+    let field = match self.state {
+      State::Protocol => Some(&mut result.protocol),
+      State::Username => Some(&mut result.username),
+      State::Password => Some(&mut result.password),
+      State::Hostname => Some(&mut result.hostname),
+      State::Port => Some(&mut result.port),
+      State::Pathname => Some(&mut result.pathname),
+      State::Search => Some(&mut result.search),
+      State::Hash => Some(&mut result.hash),
+      State::Init | State::Authority | State::Done => None,
+    };
+
+    if let Some(field) = field {
+      *field = Some(value);
+    }
+
+    // The components that are skipped over are empty, rather than wildcards
+    if self.state != State::Init && state != State::Done {
+      use State::*;
+
+      let before_hostname = matches!(self.state, Protocol | Authority | Username | Password);
+      let before_pathname = before_hostname || matches!(self.state, Hostname | Port);
+
+      if before_hostname
+        && matches!(state, Port | Pathname | Search | Hash)
+        && result.hostname.is_none()
+      {
+        result.hostname = Some(String::new());
+      }
+
+      if before_pathname && matches!(state, Search | Hash) && result.pathname.is_none() {
+        result.pathname = Some(if self.protocol_matches_special_scheme {
+          "/".to_owned()
+        } else {
+          String::new()
+        });
+      }
+
+      if (before_pathname || self.state == Pathname) && state == Hash && result.search.is_none() {
+        result.search = Some(String::new());
+      }
+    }
+
+    self.state = state;
+    self.index += skip;
+    self.component_start = self.index;
+    self.increment = 0;
+  }
+
+  fn compute_protocol_matches_special_scheme(&mut self) -> Result<(), UrlPatternError> {
+    let protocol = self.component_string();
+    let component = Component::compile(
+      Some(&protocol),
+      &canonicalize::protocol,
+      &Options::default(),
+    )?;
+
+    if component.matches_special_scheme() {
+      self.protocol_matches_special_scheme = true;
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,608 @@
+mod canonicalize;
+mod component;
+mod constructor;
+mod parser;
+mod regexp;
+mod tokenizer;
+
+use crate::url::{is_special_scheme, ParseError, Url};
+use component::Component;
+use parser::{escape_pattern, Options};
+
+use std::fmt;
+
+/// The pattern strings of the components of a [`UrlPattern`], along with a base URL that the
+/// missing components are taken from, like JavaScript's `URLPatternInit` dictionary.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct UrlPatternInit {
+  /// The pattern of the scheme, without the trailing `:`.
+  pub protocol: Option<String>,
+  /// The pattern of the username.
+  pub username: Option<String>,
+  /// The pattern of the password.
+  pub password: Option<String>,
+  /// The pattern of the hostname.
+  pub hostname: Option<String>,
+  /// The pattern of the port.
+  pub port: Option<String>,
+  /// The pattern of the path.
+  pub pathname: Option<String>,
+  /// The pattern of the query, without the leading `?`.
+  pub search: Option<String>,
+  /// The pattern of the fragment, without the leading `#`.
+  pub hash: Option<String>,
+  /// The URL that the components before the first given component are taken from.
+  pub base_url: Option<String>,
+}
+
+/// The options of a [`UrlPattern`].
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct UrlPatternOptions {
+  /// Whether the path, query and fragment are matched case-insensitively.
+  pub ignore_case: bool,
+}
+
+/// An error when creating a [`UrlPattern`].
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum UrlPatternError {
+  /// A pattern string was malformed or used a group name more than once.
+  InvalidPattern,
+  /// A regexp group wasn't a valid regular expression.
+  InvalidRegExp,
+  /// The fixed text of a component couldn't be canonicalized, such as a port that isn't a number.
+  InvalidComponent,
+  /// The base URL couldn't be parsed.
+  InvalidBaseUrl(ParseError),
+  /// A constructor string had no protocol, and no base URL was given.
+  BaseUrlRequired,
+}
+
+impl fmt::Display for UrlPatternError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      UrlPatternError::InvalidPattern => f.write_str("invalid pattern string"),
+      UrlPatternError::InvalidRegExp => f.write_str("invalid regexp group"),
+      UrlPatternError::InvalidComponent => f.write_str("invalid component"),
+      UrlPatternError::InvalidBaseUrl(error) => write!(f, "invalid base URL: {}", error),
+      UrlPatternError::BaseUrlRequired => f.write_str("a relative pattern requires a base URL"),
+    }
+  }
+}
+
+impl std::error::Error for UrlPatternError {}
+
+/// The result of matching a component of a [`UrlPattern`].
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct UrlPatternComponentResult {
+  /// The canonicalized component of the input.
+  pub input: String,
+  /// The groups of the pattern in order, where unnamed groups are named by their index. A group
+  /// is [`None`] when it's optional and didn't match.
+  pub groups: Vec<(String, Option<String>)>,
+}
+
+impl UrlPatternComponentResult {
+  /// Returns the value of a group, or [`None`] if the group doesn't exist or didn't match.
+  pub fn group(&self, name: &str) -> Option<&str> {
+    self
+      .groups
+      .iter()
+      .find(|(group, _)| group == name)
+      .and_then(|(_, value)| value.as_deref())
+  }
+}
+
+/// The result of [`UrlPattern::exec`], with the result of each component.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct UrlPatternResult {
+  /// The result of the scheme.
+  pub protocol: UrlPatternComponentResult,
+  /// The result of the username.
+  pub username: UrlPatternComponentResult,
+  /// The result of the password.
+  pub password: UrlPatternComponentResult,
+  /// The result of the hostname.
+  pub hostname: UrlPatternComponentResult,
+  /// The result of the port.
+  pub port: UrlPatternComponentResult,
+  /// The result of the path.
+  pub pathname: UrlPatternComponentResult,
+  /// The result of the query.
+  pub search: UrlPatternComponentResult,
+  /// The result of the fragment.
+  pub hash: UrlPatternComponentResult,
+}
+
+/// A pattern that URLs are matched against, as defined by the
+/// [URL Pattern Standard](https://urlpattern.spec.whatwg.org/).
+///
+/// # Notes
+/// Each component is a pattern string, where `:name` is a group that matches up to the next `/` in
+/// the path or `.` in the hostname, `*` matches anything, `(…)` is a group that matches a regular
+/// expression, and `{…}` groups fixed text with a group. Groups and `{…}` can be followed by the
+/// `?`, `*` and `+` modifiers. The fixed text of each component is canonicalized like the URL
+/// parser does, so `/café` matches the path `/caf%C3%A9`.
+///
+/// Regular expressions follow the syntax of JavaScript's regular expressions with the `v` flag,
+/// except that Unicode property escapes, `\q{…}` and modifier groups aren't supported.
+#[derive(Clone, Debug)]
+pub struct UrlPattern {
+  protocol: Component,
+  username: Component,
+  password: Component,
+  hostname: Component,
+  port: Component,
+  pathname: Component,
+  search: Component,
+  hash: Component,
+}
+
+/// The components of an input, or of the processed pattern strings.
+#[derive(Default)]
+struct Components {
+  protocol: String,
+  username: String,
+  password: String,
+  hostname: String,
+  port: String,
+  pathname: String,
+  search: String,
+  hash: String,
+}
+
+/// Whether [`process_init`] processes pattern strings or the components of an input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Kind {
+  Pattern,
+  Url,
+}
+
+impl UrlPattern {
+  /// Creates a pattern from a constructor string, such as `https://*.example.com/users/:id`, where
+  /// a relative string is resolved against `base_url`.
+  ///
+  /// # Notes
+  /// This function functionally behaves the same as JavaScript's
+  /// `new URLPattern(input, baseURL, options)`, where components that aren't in the string and come
+  /// after the ones that are match anything.
+  pub fn parse(
+    input: &str,
+    base_url: Option<&str>,
+    options: UrlPatternOptions,
+  ) -> Result<UrlPattern, UrlPatternError> {
+    let mut init = constructor::parse(input)?;
+
+    if base_url.is_none() && init.protocol.is_none() {
+      return Err(UrlPatternError::BaseUrlRequired);
+    }
+
+    init.base_url = base_url.map(str::to_owned);
+
+    UrlPattern::from_init(&init, options)
+  }
+
+  /// Creates a pattern from the pattern strings of its components, where the missing components
+  /// match anything unless they're taken from the base URL.
+  pub fn from_init(
+    init: &UrlPatternInit,
+    options: UrlPatternOptions,
+  ) -> Result<UrlPattern, UrlPatternError> {
+    let mut processed = process_init(init, Kind::Pattern, None)?;
+
+    if is_special_scheme(&processed.protocol)
+      && crate::url::default_port(&processed.protocol).map(|port| port.to_string())
+        == Some(processed.port.clone())
+    {
+      processed.port.clear();
+    }
+
+    let protocol = Component::compile(
+      Some(&processed.protocol),
+      &canonicalize::protocol,
+      &Options::default(),
+    )?;
+    let hostname = if is_ipv6_pattern(&processed.hostname) {
+      Component::compile(
+        Some(&processed.hostname),
+        &canonicalize::ipv6_hostname,
+        &Options::HOSTNAME,
+      )?
+    } else {
+      Component::compile(
+        Some(&processed.hostname),
+        &|value| canonicalize::hostname(value, None),
+        &Options::HOSTNAME,
+      )?
+    };
+    let options = Options {
+      ignore_case: options.ignore_case,
+      ..Options::default()
+    };
+    let pathname = if protocol.matches_special_scheme() {
+      Component::compile(
+        Some(&processed.pathname),
+        &canonicalize::pathname,
+        &Options {
+          ignore_case: options.ignore_case,
+          ..Options::PATHNAME
+        },
+      )?
+    } else {
+      Component::compile(
+        Some(&processed.pathname),
+        &canonicalize::opaque_pathname,
+        &options,
+      )?
+    };
+
+    Ok(UrlPattern {
+      protocol,
+      username: Component::compile(
+        Some(&processed.username),
+        &canonicalize::username,
+        &Options::default(),
+      )?,
+      password: Component::compile(
+        Some(&processed.password),
+        &canonicalize::password,
+        &Options::default(),
+      )?,
+      hostname,
+      port: Component::compile(
+        Some(&processed.port),
+        &|value| canonicalize::port(value, None),
+        &Options::default(),
+      )?,
+      pathname,
+      search: Component::compile(Some(&processed.search), &canonicalize::search, &options)?,
+      hash: Component::compile(Some(&processed.hash), &canonicalize::hash, &options)?,
+    })
+  }
+
+  /// Returns the normalized pattern of the scheme.
+  pub fn protocol(&self) -> &str {
+    &self.protocol.pattern
+  }
+
+  /// Returns the normalized pattern of the username.
+  pub fn username(&self) -> &str {
+    &self.username.pattern
+  }
+
+  /// Returns the normalized pattern of the password.
+  pub fn password(&self) -> &str {
+    &self.password.pattern
+  }
+
+  /// Returns the normalized pattern of the hostname.
+  pub fn hostname(&self) -> &str {
+    &self.hostname.pattern
+  }
+
+  /// Returns the normalized pattern of the port.
+  pub fn port(&self) -> &str {
+    &self.port.pattern
+  }
+
+  /// Returns the normalized pattern of the path.
+  pub fn pathname(&self) -> &str {
+    &self.pathname.pattern
+  }
+
+  /// Returns the normalized pattern of the query.
+  pub fn search(&self) -> &str {
+    &self.search.pattern
+  }
+
+  /// Returns the normalized pattern of the fragment.
+  pub fn hash(&self) -> &str {
+    &self.hash.pattern
+  }
+
+  /// Whether any component has a regexp group.
+  pub fn has_regexp_groups(&self) -> bool {
+    [
+      &self.protocol,
+      &self.username,
+      &self.password,
+      &self.hostname,
+      &self.port,
+      &self.pathname,
+      &self.search,
+      &self.hash,
+    ]
+    .iter()
+    .any(|component| component.has_regexp_groups)
+  }
+
+  /// Whether a URL, which is resolved against `base_url` when it's relative, matches the pattern.
+  /// A URL that can't be parsed doesn't match.
+  ///
+  /// # Notes
+  /// Regexp groups have a step budget, see [`UrlPattern::exec`].
+  pub fn test(&self, input: &str, base_url: Option<&str>) -> bool {
+    self.exec(input, base_url).is_some()
+  }
+
+  /// Whether a parsed URL matches the pattern.
+  pub fn test_url(&self, url: &Url) -> bool {
+    self.exec_url(url).is_some()
+  }
+
+  /// Whether the components of an input match the pattern.
+  pub fn test_init(&self, init: &UrlPatternInit) -> bool {
+    self.exec_init(init).is_some()
+  }
+
+  /// Matches a URL, which is resolved against `base_url` when it's relative, returning the groups
+  /// of each component. A URL that can't be parsed doesn't match.
+  ///
+  /// # Notes
+  /// This function functionally behaves the same as JavaScript's `URLPattern.prototype.exec`,
+  /// except that the result doesn't include the inputs. Regexp groups backtrack, so a component
+  /// has a budget of about a million steps, plus some for each of its bytes that grow with the
+  /// pattern, and it doesn't match once that runs out rather than taking exponential time, which
+  /// patterns like `(?:a+)+b` would.
+  pub fn exec(&self, input: &str, base_url: Option<&str>) -> Option<UrlPatternResult> {
+    let base_url = match base_url {
+      Some(base_url) => Some(Url::parse(base_url, None).ok()?),
+      None => None,
+    };
+
+    self.exec_url(&Url::parse(input, base_url.as_ref()).ok()?)
+  }
+
+  /// Matches a parsed URL, returning the groups of each component.
+  pub fn exec_url(&self, url: &Url) -> Option<UrlPatternResult> {
+    self.exec_components(Components::from_url(url))
+  }
+
+  /// Matches the components of an input, returning the groups of each component. The components
+  /// are canonicalized first, and an input that can't be canonicalized doesn't match.
+  pub fn exec_init(&self, init: &UrlPatternInit) -> Option<UrlPatternResult> {
+    let input = process_init(init, Kind::Url, Some(Components::default())).ok()?;
+
+    self.exec_components(input)
+  }
+
+  fn exec_components(&self, input: Components) -> Option<UrlPatternResult> {
+    let exec = |component: &Component, input: String| {
+      let groups = component.exec(&input)?;
+
+      Some(UrlPatternComponentResult { input, groups })
+    };
+
+    Some(UrlPatternResult {
+      protocol: exec(&self.protocol, input.protocol)?,
+      username: exec(&self.username, input.username)?,
+      password: exec(&self.password, input.password)?,
+      hostname: exec(&self.hostname, input.hostname)?,
+      port: exec(&self.port, input.port)?,
+      pathname: exec(&self.pathname, input.pathname)?,
+      search: exec(&self.search, input.search)?,
+      hash: exec(&self.hash, input.hash)?,
+    })
+  }
+}
+
+impl Components {
+  fn from_url(url: &Url) -> Components {
+    Components {
+      protocol: url.scheme.clone(),
+      username: url.username.clone(),
+      password: url.password.clone(),
+      hostname: url.hostname(),
+      port: url.port(),
+      pathname: url.pathname(),
+      search: url.query.clone().unwrap_or_default(),
+      hash: url.fragment.clone().unwrap_or_default(),
+    }
+  }
+}
+
+/// Processes the components of an init, following the
+/// [process a URLPatternInit](https://urlpattern.spec.whatwg.org/#process-a-urlpatterninit)
+/// algorithm.
+///
+/// Patterns start with every component missing, where missing components end up as `*`, while
+/// inputs start with `defaults` of empty components.
+fn process_init(
+  init: &UrlPatternInit,
+  kind: Kind,
+  defaults: Option<Components>,
+) -> Result<Components, UrlPatternError> {
+  let mut result = [None, None, None, None, None, None, None, None];
+
+  if let Some(defaults) = defaults {
+    result = [
+      Some(defaults.protocol),
+      Some(defaults.username),
+      Some(defaults.password),
+      Some(defaults.hostname),
+      Some(defaults.port),
+      Some(defaults.pathname),
+      Some(defaults.search),
+      Some(defaults.hash),
+    ];
+  }
+
+  let [protocol, username, password, hostname, port, pathname, search, hash] = &mut result;
+  let base_url = match &init.base_url {
+    Some(base_url) => Some(Url::parse(base_url, None).map_err(UrlPatternError::InvalidBaseUrl)?),
+    None => None,
+  };
+
+  if let Some(base_url) = &base_url {
+    let process = |value: &str| match kind {
+      Kind::Pattern => {
+        let mut escaped = String::with_capacity(value.len());
+
+        escape_pattern(value, &mut escaped);
+        escaped
+      }
+      Kind::Url => value.to_owned(),
+    };
+
+    // The components before the first given one are taken from the base URL
+    let given = [
+      init.protocol.is_some(),
+      init.hostname.is_some(),
+      init.port.is_some(),
+      init.pathname.is_some(),
+      init.search.is_some(),
+      init.hash.is_some(),
+    ];
+    let given_before = |count: usize| given[..count].iter().any(|&given| given);
+
+    if !given_before(1) {
+      *protocol = Some(process(&base_url.scheme));
+    }
+
+    if kind == Kind::Url && !given_before(3) && init.username.is_none() {
+      *username = Some(process(&base_url.username));
+
+      if init.password.is_none() {
+        *password = Some(process(&base_url.password));
+      }
+    }
+
+    if !given_before(2) {
+      *hostname = Some(process(&base_url.hostname()));
+    }
+
+    if !given_before(3) {
+      *port = Some(process(&base_url.port()));
+    }
+
+    if !given_before(4) {
+      *pathname = Some(process(&base_url.pathname()));
+    }
+
+    if !given_before(5) {
+      *search = Some(process(base_url.query.as_deref().unwrap_or_default()));
+    }
+
+    if !given_before(6) {
+      *hash = Some(process(base_url.fragment.as_deref().unwrap_or_default()));
+    }
+  }
+
+  let is_pattern = kind == Kind::Pattern;
+
+  if let Some(value) = &init.protocol {
+    let value = value.strip_suffix(':').unwrap_or(value);
+
+    *protocol = Some(if is_pattern {
+      value.to_owned()
+    } else {
+      canonicalize::protocol(value)?
+    });
+  }
+
+  if let Some(value) = &init.username {
+    *username = Some(if is_pattern {
+      value.clone()
+    } else {
+      canonicalize::username(value)?
+    });
+  }
+
+  if let Some(value) = &init.password {
+    *password = Some(if is_pattern {
+      value.clone()
+    } else {
+      canonicalize::password(value)?
+    });
+  }
+
+  if let Some(value) = &init.hostname {
+    *hostname = Some(if is_pattern {
+      value.clone()
+    } else {
+      canonicalize::hostname(value, protocol.as_deref())?
+    });
+  }
+
+  if let Some(value) = &init.port {
+    *port = Some(if is_pattern {
+      value.clone()
+    } else {
+      canonicalize::port(value, protocol.as_deref())?
+    });
+  }
+
+  if let Some(value) = &init.pathname {
+    let mut value = value.clone();
+
+    // A relative path is resolved against the directory of the base URL's path
+    if let Some(base_url) = &base_url {
+      if !base_url.has_opaque_path() && !is_absolute_pathname(&value, kind) {
+        let mut base_path = String::new();
+
+        if is_pattern {
+          escape_pattern(&base_url.pathname(), &mut base_path);
+        } else {
+          base_path = base_url.pathname();
+        }
+
+        if let Some(idx) = base_path.rfind('/') {
+          value.insert_str(0, &base_path[..=idx]);
+        }
+      }
+    }
+
+    *pathname = Some(match protocol.as_deref() {
+      _ if is_pattern => value,
+      Some(protocol) if !protocol.is_empty() && !is_special_scheme(protocol) => {
+        canonicalize::opaque_pathname(&value)?
+      }
+      _ => canonicalize::pathname(&value)?,
+    });
+  }
+
+  if let Some(value) = &init.search {
+    let value = value.strip_prefix('?').unwrap_or(value);
+
+    *search = Some(if is_pattern {
+      value.to_owned()
+    } else {
+      canonicalize::search(value)?
+    });
+  }
+
+  if let Some(value) = &init.hash {
+    let value = value.strip_prefix('#').unwrap_or(value);
+
+    *hash = Some(if is_pattern {
+      value.to_owned()
+    } else {
+      canonicalize::hash(value)?
+    });
+  }
+
+  let [protocol, username, password, hostname, port, pathname, search, hash] =
+    result.map(|value| value.unwrap_or_else(|| "*".to_owned()));
+
+  Ok(Components {
+    protocol,
+    username,
+    password,
+    hostname,
+    port,
+    pathname,
+    search,
+    hash,
+  })
+}
+
+/// Whether a path is absolute, where a pattern can also start with an escaped or grouped `/`.
+fn is_absolute_pathname(value: &str, kind: Kind) -> bool {
+  value.starts_with('/')
+    || (kind == Kind::Pattern && (value.starts_with("\\/") || value.starts_with("{/")))
+}
+
+/// Whether a hostname pattern is an IPv6 address, which is canonicalized by lowercasing it.
+fn is_ipv6_pattern(value: &str) -> bool {
+  value.len() >= 2
+    && (value.starts_with('[') || value.starts_with("{[") || value.starts_with("\\["))
+}
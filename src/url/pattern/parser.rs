@@ -0,0 +1,350 @@
+use super::tokenizer::{tokenize, Policy, Token, TokenKind};
+use super::UrlPatternError;
+
+/// The regexp of a `*` wildcard.
+pub(super) const FULL_WILDCARD_REGEXP: &str = ".*";
+
+/// An encoding callback, which canonicalizes the fixed text of a component.
+pub(super) type Encode<'a> = &'a dyn Fn(&str) -> Result<String, UrlPatternError>;
+
+/// The [options](https://urlpattern.spec.whatwg.org/#options-header) of a component.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct Options {
+  /// The character that a segment wildcard like `:id` can't match.
+  pub(super) delimiter: Option<char>,
+  /// The character that's treated as the prefix of a group when it comes right before it.
+  pub(super) prefix: Option<char>,
+  pub(super) ignore_case: bool,
+}
+
+impl Options {
+  pub(super) const HOSTNAME: Options = Options {
+    delimiter: Some('.'),
+    prefix: None,
+    ignore_case: false,
+  };
+
+  pub(super) const PATHNAME: Options = Options {
+    delimiter: Some('/'),
+    prefix: Some('/'),
+    ignore_case: false,
+  };
+
+  /// Returns the regexp of a segment wildcard, which matches anything but the delimiter.
+  pub(super) fn segment_wildcard_regexp(&self) -> String {
+    let mut regexp = String::from("[^");
+
+    if let Some(delimiter) = self.delimiter {
+      escape_regexp(delimiter.encode_utf8(&mut [0; 4]), &mut regexp);
+    }
+
+    regexp.push_str("]+?");
+    regexp
+  }
+
+  /// Whether a string is the prefix character.
+  pub(super) fn is_prefix(&self, value: &str) -> bool {
+    let mut chars = value.chars();
+
+    match (chars.next(), chars.next()) {
+      (Some(ch), None) => Some(ch) == self.prefix,
+      _ => false,
+    }
+  }
+}
+
+/// Appends a string, escaping the characters that are special in regexps.
+pub(super) fn escape_regexp(string: &str, escaped: &mut String) {
+  for ch in string.chars() {
+    if matches!(
+      ch,
+      '.' | '+' | '*' | '?' | '^' | '$' | '{' | '}' | '(' | ')' | '[' | ']' | '|' | '/' | '\\'
+    ) {
+      escaped.push('\\');
+    }
+
+    escaped.push(ch);
+  }
+}
+
+/// Appends a string, escaping the characters that are special in pattern strings.
+pub(super) fn escape_pattern(string: &str, escaped: &mut String) {
+  for ch in string.chars() {
+    if matches!(ch, '+' | '*' | '?' | ':' | '{' | '}' | '(' | ')' | '\\') {
+      escaped.push('\\');
+    }
+
+    escaped.push(ch);
+  }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum PartKind {
+  FixedText,
+  Regexp,
+  /// A group that matches up to the delimiter, like `:id` does.
+  SegmentWildcard,
+  /// A group that matches anything, like `*` does.
+  FullWildcard,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum Modifier {
+  None,
+  Optional,
+  ZeroOrMore,
+  OneOrMore,
+}
+
+impl Modifier {
+  pub(super) fn as_str(self) -> &'static str {
+    match self {
+      Modifier::None => "",
+      Modifier::Optional => "?",
+      Modifier::ZeroOrMore => "*",
+      Modifier::OneOrMore => "+",
+    }
+  }
+}
+
+/// A [part](https://urlpattern.spec.whatwg.org/#part) of a pattern string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct Part {
+  pub(super) kind: PartKind,
+  /// The text of a fixed part, or the regexp of a regexp part.
+  pub(super) value: String,
+  pub(super) modifier: Modifier,
+  pub(super) name: String,
+  pub(super) prefix: String,
+  pub(super) suffix: String,
+}
+
+/// Parses a pattern string into parts, following the
+/// [parse a pattern string](https://urlpattern.spec.whatwg.org/#parse-a-pattern-string)
+/// algorithm.
+pub(super) fn parse(
+  input: &str,
+  options: &Options,
+  encode: Encode<'_>,
+) -> Result<Vec<Part>, UrlPatternError> {
+  let mut parser = Parser {
+    tokens: tokenize(input, Policy::Strict)?,
+    encode,
+    segment_wildcard_regexp: options.segment_wildcard_regexp(),
+    parts: Vec::new(),
+    pending_fixed_value: String::new(),
+    index: 0,
+    next_numeric_name: 0,
+  };
+
+  while parser.index < parser.tokens.len() {
+    let char_token = parser.consume(TokenKind::Char);
+    let name_token = parser.consume(TokenKind::Name);
+    let regexp_or_wildcard_token = parser.consume_regexp_or_wildcard(name_token.is_none());
+
+    if name_token.is_some() || regexp_or_wildcard_token.is_some() {
+      let mut prefix = char_token.map_or("", |token| token.value);
+
+      if !prefix.is_empty() && !options.is_prefix(prefix) {
+        parser.pending_fixed_value.push_str(prefix);
+        prefix = "";
+      }
+
+      parser.flush_pending_fixed_value()?;
+
+      let modifier_token = parser.consume_modifier();
+
+      parser.add_part(
+        prefix,
+        name_token,
+        regexp_or_wildcard_token,
+        "",
+        modifier_token,
+      )?;
+      continue;
+    }
+
+    if let Some(token) = char_token.or_else(|| parser.consume(TokenKind::EscapedChar)) {
+      parser.pending_fixed_value.push_str(token.value);
+      continue;
+    }
+
+    if parser.consume(TokenKind::Open).is_some() {
+      let prefix = parser.consume_text();
+      let name_token = parser.consume(TokenKind::Name);
+      let regexp_or_wildcard_token = parser.consume_regexp_or_wildcard(name_token.is_none());
+      let suffix = parser.consume_text();
+
+      parser.consume_required(TokenKind::Close)?;
+
+      let modifier_token = parser.consume_modifier();
+
+      parser.add_part(
+        &prefix,
+        name_token,
+        regexp_or_wildcard_token,
+        &suffix,
+        modifier_token,
+      )?;
+      continue;
+    }
+
+    parser.flush_pending_fixed_value()?;
+    parser.consume_required(TokenKind::End)?;
+  }
+
+  Ok(parser.parts)
+}
+
+struct Parser<'a, 'e> {
+  tokens: Vec<Token<'a>>,
+  encode: Encode<'e>,
+  segment_wildcard_regexp: String,
+  parts: Vec<Part>,
+  pending_fixed_value: String,
+  index: usize,
+  next_numeric_name: usize,
+}
+
+impl<'a> Parser<'a, '_> {
+  fn consume(&mut self, kind: TokenKind) -> Option<Token<'a>> {
+    let token = self.tokens[self.index];
+
+    if token.kind != kind {
+      return None;
+    }
+
+    self.index += 1;
+    Some(token)
+  }
+
+  fn consume_regexp_or_wildcard(&mut self, no_name: bool) -> Option<Token<'a>> {
+    let token = self.consume(TokenKind::Regexp);
+
+    if token.is_none() && no_name {
+      return self.consume(TokenKind::Asterisk);
+    }
+
+    token
+  }
+
+  fn consume_modifier(&mut self) -> Option<Token<'a>> {
+    self
+      .consume(TokenKind::OtherModifier)
+      .or_else(|| self.consume(TokenKind::Asterisk))
+  }
+
+  fn consume_required(&mut self, kind: TokenKind) -> Result<Token<'a>, UrlPatternError> {
+    self.consume(kind).ok_or(UrlPatternError::InvalidPattern)
+  }
+
+  fn consume_text(&mut self) -> String {
+    let mut text = String::new();
+
+    while let Some(token) = self
+      .consume(TokenKind::Char)
+      .or_else(|| self.consume(TokenKind::EscapedChar))
+    {
+      text.push_str(token.value);
+    }
+
+    text
+  }
+
+  fn flush_pending_fixed_value(&mut self) -> Result<(), UrlPatternError> {
+    if self.pending_fixed_value.is_empty() {
+      return Ok(());
+    }
+
+    let value = (self.encode)(&std::mem::take(&mut self.pending_fixed_value))?;
+
+    self.parts.push(Part {
+      kind: PartKind::FixedText,
+      value,
+      modifier: Modifier::None,
+      name: String::new(),
+      prefix: String::new(),
+      suffix: String::new(),
+    });
+
+    Ok(())
+  }
+
+  fn add_part(
+    &mut self,
+    prefix: &str,
+    name_token: Option<Token<'_>>,
+    regexp_or_wildcard_token: Option<Token<'_>>,
+    suffix: &str,
+    modifier_token: Option<Token<'_>>,
+  ) -> Result<(), UrlPatternError> {
+    let modifier = match modifier_token.map(|token| token.value) {
+      None => Modifier::None,
+      Some("?") => Modifier::Optional,
+      Some("*") => Modifier::ZeroOrMore,
+      Some(_) => Modifier::OneOrMore,
+    };
+
+    if name_token.is_none() && regexp_or_wildcard_token.is_none() && modifier == Modifier::None {
+      self.pending_fixed_value.push_str(prefix);
+      return Ok(());
+    }
+
+    self.flush_pending_fixed_value()?;
+
+    if name_token.is_none() && regexp_or_wildcard_token.is_none() {
+      if prefix.is_empty() {
+        return Ok(());
+      }
+
+      self.parts.push(Part {
+        kind: PartKind::FixedText,
+        value: (self.encode)(prefix)?,
+        modifier,
+        name: String::new(),
+        prefix: String::new(),
+        suffix: String::new(),
+      });
+      return Ok(());
+    }
+
+    let regexp = match regexp_or_wildcard_token {
+      None => self.segment_wildcard_regexp.clone(),
+      Some(token) if token.kind == TokenKind::Asterisk => FULL_WILDCARD_REGEXP.to_owned(),
+      Some(token) => token.value.to_owned(),
+    };
+    let (kind, value) = if regexp == self.segment_wildcard_regexp {
+      (PartKind::SegmentWildcard, String::new())
+    } else if regexp == FULL_WILDCARD_REGEXP {
+      (PartKind::FullWildcard, String::new())
+    } else {
+      (PartKind::Regexp, regexp)
+    };
+
+    let name = match name_token {
+      Some(token) => token.value.to_owned(),
+      None => {
+        self.next_numeric_name += 1;
+        (self.next_numeric_name - 1).to_string()
+      }
+    };
+
+    if self.parts.iter().any(|part| part.name == name) {
+      return Err(UrlPatternError::InvalidPattern);
+    }
+
+    let prefix = (self.encode)(prefix)?;
+    let suffix = (self.encode)(suffix)?;
+
+    self.parts.push(Part {
+      kind,
+      value,
+      modifier,
+      name,
+      prefix,
+      suffix,
+    });
+
+    Ok(())
+  }
+}
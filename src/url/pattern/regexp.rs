@@ -0,0 +1,1240 @@
+use std::cell::Cell;
+use std::ops::Range;
+
+/// How many instructions a match can run, across every start position and lookaround, before it
+/// gives up, on top of as many as the program has for each byte of the input.
+const STEP_LIMIT: usize = 1 << 20;
+
+/// A compiled regular expression, following the syntax of ECMAScript regular expressions with the
+/// `v` flag.
+///
+/// # Notes
+/// Matching backtracks like ECMAScript engines do, so captures are the same as the ones of
+/// `RegExp.prototype.exec`, but a match that runs out of its step budget fails instead of taking
+/// exponential time on patterns like `(?:a+)+b`. Unicode property escapes, `\q{…}` string literals
+/// and modifier groups aren't supported and are rejected.
+#[derive(Clone, Debug)]
+pub(crate) struct RegExp {
+  program: Vec<Inst>,
+  group_count: usize,
+  ignore_case: bool,
+}
+
+/// An error when a regular expression is invalid or unsupported.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RegExpError;
+
+type Captures = Vec<Option<(usize, usize)>>;
+
+#[derive(Clone, Debug)]
+enum Node {
+  Empty,
+  Char(char),
+  /// `.`, which matches anything except line terminators.
+  Any,
+  Class(ClassSet),
+  Start,
+  End,
+  WordBoundary {
+    negated: bool,
+  },
+  Capture(usize, Box<Node>),
+  Concat(Vec<Node>),
+  Alternation(Vec<Node>),
+  Repeat {
+    node: Box<Node>,
+    min: u32,
+    max: Option<u32>,
+    greedy: bool,
+    groups: Range<usize>,
+  },
+  Backreference(usize),
+  NamedBackreference(String),
+  Look {
+    node: Box<Node>,
+    ahead: bool,
+    negated: bool,
+  },
+}
+
+/// The contents of a character class, where the operations are the ones of the `v` flag.
+#[derive(Clone, Debug)]
+enum ClassSet {
+  Union(Vec<ClassItem>),
+  Intersection(Vec<ClassItem>),
+  Subtraction(Vec<ClassItem>),
+}
+
+#[derive(Clone, Debug)]
+enum ClassItem {
+  Range(char, char),
+  Escape(ClassEscape),
+  Nested { set: ClassSet, negated: bool },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ClassEscape {
+  Digit(bool),
+  Word(bool),
+  Space(bool),
+}
+
+impl ClassEscape {
+  fn contains(self, ch: char) -> bool {
+    match self {
+      ClassEscape::Digit(negated) => ch.is_ascii_digit() != negated,
+      ClassEscape::Word(negated) => is_word_char(ch) != negated,
+      ClassEscape::Space(negated) => is_space(ch) != negated,
+    }
+  }
+}
+
+impl ClassItem {
+  fn contains(&self, ch: char) -> bool {
+    match self {
+      ClassItem::Range(start, end) => *start <= ch && ch <= *end,
+      ClassItem::Escape(escape) => escape.contains(ch),
+      ClassItem::Nested { set, negated } => set.contains(ch) != *negated,
+    }
+  }
+}
+
+impl ClassSet {
+  fn contains(&self, ch: char) -> bool {
+    match self {
+      ClassSet::Union(items) => items.iter().any(|item| item.contains(ch)),
+      ClassSet::Intersection(items) => items.iter().all(|item| item.contains(ch)),
+      ClassSet::Subtraction(items) => {
+        items[0].contains(ch) && !items[1..].iter().any(|item| item.contains(ch))
+      }
+    }
+  }
+}
+
+fn is_word_char(ch: char) -> bool {
+  ch.is_ascii_alphanumeric() || ch == '_'
+}
+
+/// Whether a character is `WhiteSpace` or a `LineTerminator`, which `\s` matches.
+fn is_space(ch: char) -> bool {
+  matches!(
+    ch,
+    '\t' | '\n' | '\u{B}' | '\u{C}' | '\r' | ' ' | '\u{A0}' | '\u{1680}' | '\u{2000}'
+      ..='\u{200A}' | '\u{2028}' | '\u{2029}' | '\u{202F}' | '\u{205F}' | '\u{3000}' | '\u{FEFF}'
+  )
+}
+
+fn is_line_terminator(ch: char) -> bool {
+  matches!(ch, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+/// Folds the case of a character, when it has a single character lowercase mapping.
+fn fold_case(ch: char) -> char {
+  let mut lowercase = ch.to_lowercase();
+
+  match (lowercase.next(), lowercase.next()) {
+    (Some(lower), None) => lower,
+    _ => ch,
+  }
+}
+
+impl RegExp {
+  /// Compiles a regular expression, where `ignore_case` is the `i` flag.
+  pub(crate) fn new(pattern: &str, ignore_case: bool) -> Result<RegExp, RegExpError> {
+    let chars = pattern.chars().collect::<Vec<_>>();
+    let mut parser = Parser {
+      group_count: count_groups(&chars),
+      chars,
+      pos: 0,
+      group_names: vec![None],
+      named_backreferences: Vec::new(),
+    };
+    let node = parser.disjunction()?;
+
+    if parser.pos != parser.chars.len() {
+      return Err(RegExpError);
+    }
+
+    for name in &parser.named_backreferences {
+      if !parser.group_names.contains(&Some(name.clone())) {
+        return Err(RegExpError);
+      }
+    }
+
+    let mut compiler = Compiler {
+      group_names: &parser.group_names,
+      program: Vec::new(),
+    };
+
+    compiler.emit(&node);
+    compiler.program.push(Inst::Match);
+
+    Ok(RegExp {
+      program: compiler.program,
+      group_count: parser.group_count,
+      ignore_case,
+    })
+  }
+
+  /// Returns the captures of the first match in `input`, excluding the whole match, or [`None`]
+  /// when nothing matches or the match runs out of steps.
+  pub(crate) fn captures<'a>(&self, input: &'a str) -> Option<Vec<Option<&'a str>>> {
+    let matcher = Matcher {
+      regexp: self,
+      input,
+      steps: Cell::new(STEP_LIMIT.saturating_add(input.len().saturating_mul(self.program.len()))),
+    };
+    let mut captures = vec![None; self.group_count + 1];
+
+    for start in (0..=input.len()).filter(|&idx| input.is_char_boundary(idx)) {
+      if matcher.run(0, start, &mut captures, None) {
+        return Some(
+          captures[1..]
+            .iter()
+            .map(|capture| capture.map(|(start, end)| &input[start..end]))
+            .collect(),
+        );
+      }
+
+      if matcher.steps.get() == 0 {
+        break;
+      }
+    }
+
+    None
+  }
+}
+
+/// Counts the capturing groups, so that backreferences to later groups can be validated.
+fn count_groups(chars: &[char]) -> usize {
+  let mut count = 0;
+  let mut class_depth = 0;
+  let mut idx = 0;
+
+  while idx < chars.len() {
+    match chars[idx] {
+      '\\' => idx += 1,
+      '[' => class_depth += 1,
+      ']' if class_depth > 0 => class_depth -= 1,
+      '(' if class_depth == 0 => match chars.get(idx + 1..idx + 3) {
+        Some(['?', '<']) => {
+          if !matches!(chars.get(idx + 3), Some('=' | '!')) {
+            count += 1;
+          }
+        }
+        Some(['?', _]) => {}
+        _ => count += 1,
+      },
+      _ => {}
+    }
+
+    idx += 1;
+  }
+
+  count
+}
+
+struct Parser {
+  chars: Vec<char>,
+  pos: usize,
+  group_count: usize,
+  group_names: Vec<Option<String>>,
+  named_backreferences: Vec<String>,
+}
+
+impl Parser {
+  fn peek(&self) -> Option<char> {
+    self.chars.get(self.pos).copied()
+  }
+
+  fn eat(&mut self, ch: char) -> bool {
+    if self.peek() == Some(ch) {
+      self.pos += 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn eat_str(&mut self, string: &str) -> bool {
+    let len = string.chars().count();
+
+    if self.chars[self.pos..]
+      .iter()
+      .take(len)
+      .copied()
+      .eq(string.chars())
+    {
+      self.pos += len;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn next(&mut self) -> Result<char, RegExpError> {
+    let ch = self.peek().ok_or(RegExpError)?;
+
+    self.pos += 1;
+    Ok(ch)
+  }
+
+  fn disjunction(&mut self) -> Result<Node, RegExpError> {
+    let mut alternatives = vec![self.alternative()?];
+
+    while self.eat('|') {
+      alternatives.push(self.alternative()?);
+    }
+
+    Ok(if alternatives.len() == 1 {
+      alternatives.pop().unwrap()
+    } else {
+      Node::Alternation(alternatives)
+    })
+  }
+
+  fn alternative(&mut self) -> Result<Node, RegExpError> {
+    let mut terms = Vec::new();
+
+    while !matches!(self.peek(), None | Some('|' | ')')) {
+      terms.push(self.term()?);
+    }
+
+    Ok(match terms.len() {
+      0 => Node::Empty,
+      1 => terms.pop().unwrap(),
+      _ => Node::Concat(terms),
+    })
+  }
+
+  fn term(&mut self) -> Result<Node, RegExpError> {
+    let first_group = self.group_names.len();
+    let atom = match self.next()? {
+      '^' => return Ok(Node::Start),
+      '$' => return Ok(Node::End),
+      '\\' if self.eat('b') => return Ok(Node::WordBoundary { negated: false }),
+      '\\' if self.eat('B') => return Ok(Node::WordBoundary { negated: true }),
+      '(' if self.eat('?') => {
+        let look = match () {
+          _ if self.eat('=') => Some((true, false)),
+          _ if self.eat('!') => Some((true, true)),
+          _ if self.eat_str("<=") => Some((false, false)),
+          _ if self.eat_str("<!") => Some((false, true)),
+          _ => None,
+        };
+
+        if let Some((ahead, negated)) = look {
+          let node = self.group_body()?;
+
+          // Assertions can't be quantified with the `u` and `v` flags
+          return Ok(Node::Look {
+            node: Box::new(node),
+            ahead,
+            negated,
+          });
+        }
+
+        if self.eat(':') {
+          self.group_body()?
+        } else if self.eat('<') {
+          let name = self.group_name()?;
+
+          if self.group_names.contains(&Some(name.clone())) {
+            return Err(RegExpError);
+          }
+
+          self.capture(Some(name))?
+        } else {
+          return Err(RegExpError);
+        }
+      }
+      '(' => self.capture(None)?,
+      '.' => Node::Any,
+      '[' => {
+        let negated = self.eat('^');
+        let set = self.class_contents()?;
+
+        Node::Class(ClassSet::Union(vec![ClassItem::Nested { set, negated }]))
+      }
+      '\\' => self.atom_escape()?,
+      '*' | '+' | '?' | '{' | '}' | ']' | ')' => return Err(RegExpError),
+      ch => Node::Char(ch),
+    };
+
+    let (min, max) = if self.eat('*') {
+      (0, None)
+    } else if self.eat('+') {
+      (1, None)
+    } else if self.eat('?') {
+      (0, Some(1))
+    } else if self.eat('{') {
+      let min = self.number()?;
+      let max = if !self.eat(',') {
+        Some(min)
+      } else if self.peek() == Some('}') {
+        None
+      } else {
+        Some(self.number()?)
+      };
+
+      if !self.eat('}') || max.is_some_and(|max| max < min) {
+        return Err(RegExpError);
+      }
+
+      (min, max)
+    } else {
+      return Ok(atom);
+    };
+
+    let greedy = !self.eat('?');
+
+    Ok(Node::Repeat {
+      node: Box::new(atom),
+      min,
+      max,
+      greedy,
+      groups: first_group..self.group_names.len(),
+    })
+  }
+
+  fn number(&mut self) -> Result<u32, RegExpError> {
+    let start = self.pos;
+    let mut number = 0u32;
+
+    while let Some(digit) = self.peek().and_then(|ch| ch.to_digit(10)) {
+      number = number.saturating_mul(10).saturating_add(digit);
+      self.pos += 1;
+    }
+
+    if self.pos == start {
+      return Err(RegExpError);
+    }
+
+    Ok(number)
+  }
+
+  fn group_body(&mut self) -> Result<Node, RegExpError> {
+    let node = self.disjunction()?;
+
+    if !self.eat(')') {
+      return Err(RegExpError);
+    }
+
+    Ok(node)
+  }
+
+  fn capture(&mut self, name: Option<String>) -> Result<Node, RegExpError> {
+    let index = self.group_names.len();
+
+    self.group_names.push(name);
+
+    Ok(Node::Capture(index, Box::new(self.group_body()?)))
+  }
+
+  /// Parses a group name up to its closing `>`.
+  fn group_name(&mut self) -> Result<String, RegExpError> {
+    let mut name = String::new();
+
+    loop {
+      match self.next()? {
+        '>' => break,
+        ch if ch == '$'
+          || ch == '_'
+          || ch.is_alphabetic()
+          || (!name.is_empty() && ch.is_alphanumeric()) =>
+        {
+          name.push(ch)
+        }
+        _ => return Err(RegExpError),
+      }
+    }
+
+    if name.is_empty() {
+      return Err(RegExpError);
+    }
+
+    Ok(name)
+  }
+
+  fn atom_escape(&mut self) -> Result<Node, RegExpError> {
+    match self.peek().ok_or(RegExpError)? {
+      '1'..='9' => {
+        let index = self.number()? as usize;
+
+        if index > self.group_count {
+          return Err(RegExpError);
+        }
+
+        Ok(Node::Backreference(index))
+      }
+      'k' => {
+        self.pos += 1;
+
+        if !self.eat('<') {
+          return Err(RegExpError);
+        }
+
+        let name = self.group_name()?;
+
+        self.named_backreferences.push(name.clone());
+        Ok(Node::NamedBackreference(name))
+      }
+      _ => match self.character_escape(false)? {
+        Escaped::Char(ch) => Ok(Node::Char(ch)),
+        Escaped::Class(escape) => Ok(Node::Class(ClassSet::Union(vec![ClassItem::Escape(
+          escape,
+        )]))),
+      },
+    }
+  }
+
+  /// Parses an escape after `\`, other than a backreference or a word boundary.
+  fn character_escape(&mut self, in_class: bool) -> Result<Escaped, RegExpError> {
+    let ch = self.next()?;
+
+    Ok(Escaped::Char(match ch {
+      'd' | 'D' => return Ok(Escaped::Class(ClassEscape::Digit(ch == 'D'))),
+      'w' | 'W' => return Ok(Escaped::Class(ClassEscape::Word(ch == 'W'))),
+      's' | 'S' => return Ok(Escaped::Class(ClassEscape::Space(ch == 'S'))),
+      'f' => '\u{C}',
+      'n' => '\n',
+      'r' => '\r',
+      't' => '\t',
+      'v' => '\u{B}',
+      'b' if in_class => '\u{8}',
+      'c' => match self.next()? {
+        letter if letter.is_ascii_alphabetic() => (letter as u8 % 32) as char,
+        _ => return Err(RegExpError),
+      },
+      '0' if !self.peek().is_some_and(|ch| ch.is_ascii_digit()) => '\0',
+      'x' => {
+        let value = self.hex_digits(2)?;
+
+        char::from_u32(value).ok_or(RegExpError)?
+      }
+      'u' => self.unicode_escape()?,
+      '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|' | '/' => {
+        ch
+      }
+      '-' if in_class => ch,
+      '&' | '!' | '#' | '%' | ',' | ':' | ';' | '<' | '=' | '>' | '@' | '`' | '~' if in_class => ch,
+      _ => return Err(RegExpError),
+    }))
+  }
+
+  fn hex_digits(&mut self, len: usize) -> Result<u32, RegExpError> {
+    let mut value = 0;
+
+    for _ in 0..len {
+      value = value * 16 + self.next()?.to_digit(16).ok_or(RegExpError)?;
+    }
+
+    Ok(value)
+  }
+
+  /// Parses `\uXXXX`, a surrogate pair of them, or `\u{X…}` after the `u`.
+  fn unicode_escape(&mut self) -> Result<char, RegExpError> {
+    if self.eat('{') {
+      let mut value = 0u32;
+      let start = self.pos;
+
+      while let Some(digit) = self.peek().and_then(|ch| ch.to_digit(16)) {
+        value = value.saturating_mul(16).saturating_add(digit);
+        self.pos += 1;
+      }
+
+      if self.pos == start || !self.eat('}') {
+        return Err(RegExpError);
+      }
+
+      return char::from_u32(value).ok_or(RegExpError);
+    }
+
+    let value = self.hex_digits(4)?;
+
+    if (0xD800..0xDC00).contains(&value) && self.eat_str("\\u") {
+      let low = self.hex_digits(4)?;
+
+      if (0xDC00..0xE000).contains(&low) {
+        return char::from_u32(0x10000 + ((value - 0xD800) << 10) + (low - 0xDC00))
+          .ok_or(RegExpError);
+      }
+    }
+
+    char::from_u32(value).ok_or(RegExpError)
+  }
+
+  /// Parses the contents of a class up to its closing `]`.
+  fn class_contents(&mut self) -> Result<ClassSet, RegExpError> {
+    if self.eat(']') {
+      return Ok(ClassSet::Union(Vec::new()));
+    }
+
+    let first = self.class_operand()?;
+
+    let operator = if self.eat_str("&&") {
+      Some("&&")
+    } else if self.eat_str("--") {
+      Some("--")
+    } else {
+      None
+    };
+
+    if let Some(operator) = operator {
+      let first = match first {
+        ClassOperand::Char(ch) => ClassItem::Range(ch, ch),
+        ClassOperand::Item(item) => item,
+      };
+      let mut items = vec![first, self.class_operand()?.into_item()];
+
+      while self.eat_str(operator) {
+        items.push(self.class_operand()?.into_item());
+      }
+
+      if !self.eat(']') {
+        return Err(RegExpError);
+      }
+
+      return Ok(if operator == "&&" {
+        ClassSet::Intersection(items)
+      } else {
+        ClassSet::Subtraction(items)
+      });
+    }
+
+    let mut items = Vec::new();
+    let mut operand = first;
+
+    loop {
+      match operand {
+        ClassOperand::Char(start) if self.peek() == Some('-') => {
+          self.pos += 1;
+
+          match self.class_operand()? {
+            ClassOperand::Char(end) if start <= end => items.push(ClassItem::Range(start, end)),
+            _ => return Err(RegExpError),
+          }
+        }
+        operand => items.push(operand.into_item()),
+      }
+
+      if self.eat(']') {
+        return Ok(ClassSet::Union(items));
+      }
+
+      // The set operators can't be mixed with a union
+      if self.chars[self.pos..].starts_with(&['&', '&'])
+        || self.chars[self.pos..].starts_with(&['-', '-'])
+      {
+        return Err(RegExpError);
+      }
+
+      operand = self.class_operand()?;
+    }
+  }
+
+  fn class_operand(&mut self) -> Result<ClassOperand, RegExpError> {
+    match self.next()? {
+      '[' => {
+        let negated = self.eat('^');
+        let set = self.class_contents()?;
+
+        Ok(ClassOperand::Item(ClassItem::Nested { set, negated }))
+      }
+      '\\' => match self.character_escape(true)? {
+        Escaped::Char(ch) => Ok(ClassOperand::Char(ch)),
+        Escaped::Class(escape) => Ok(ClassOperand::Item(ClassItem::Escape(escape))),
+      },
+      '(' | ')' | ']' | '{' | '}' | '/' | '-' | '|' => Err(RegExpError),
+      ch => {
+        const DOUBLE_PUNCTUATORS: &str = "&!#$%*+,.:;<=>?@^`~";
+
+        if DOUBLE_PUNCTUATORS.contains(ch) && self.peek() == Some(ch) {
+          return Err(RegExpError);
+        }
+
+        Ok(ClassOperand::Char(ch))
+      }
+    }
+  }
+}
+
+enum Escaped {
+  Char(char),
+  Class(ClassEscape),
+}
+
+enum ClassOperand {
+  Char(char),
+  Item(ClassItem),
+}
+
+impl ClassOperand {
+  fn into_item(self) -> ClassItem {
+    match self {
+      ClassOperand::Char(ch) => ClassItem::Range(ch, ch),
+      ClassOperand::Item(item) => item,
+    }
+  }
+}
+
+/// An instruction of a compiled regular expression.
+#[derive(Clone, Debug)]
+enum Inst {
+  /// Ends a match, or the body of a lookaround.
+  Match,
+  Char(char),
+  /// `.`, which matches anything except line terminators.
+  Any,
+  Class(ClassSet),
+  Start,
+  End,
+  WordBoundary {
+    negated: bool,
+  },
+  Backreference(usize),
+  /// Continues at `first`, and backtracks to `second`.
+  Split {
+    first: usize,
+    second: usize,
+  },
+  Jump(usize),
+  /// Pushes the start of a capture.
+  CaptureStart,
+  /// Pops the start of a capture and sets it.
+  CaptureEnd(usize),
+  /// Pushes the count of a repeat.
+  RepeatStart,
+  /// Chooses between another iteration of a repeat, at the next instruction, and `exit`.
+  RepeatCheck {
+    min: u32,
+    max: Option<u32>,
+    greedy: bool,
+    exit: usize,
+  },
+  /// Starts an iteration, without the captures of the previous one.
+  RepeatIteration(Range<usize>),
+  /// Ends an iteration and goes back to `check`, unless the iteration matched nothing past the
+  /// minimum.
+  RepeatEnd {
+    min: u32,
+    check: usize,
+  },
+  /// Pops the count of a repeat.
+  RepeatExit,
+  /// Repeats the single character instruction after it, which needs no state to backtrack.
+  RepeatChar {
+    min: u32,
+    max: Option<u32>,
+    greedy: bool,
+  },
+  /// Asserts the body after it, continuing at `next`.
+  Look {
+    ahead: bool,
+    negated: bool,
+    next: usize,
+  },
+}
+
+/// Compiles the nodes of a regular expression to instructions.
+struct Compiler<'a> {
+  group_names: &'a [Option<String>],
+  program: Vec<Inst>,
+}
+
+impl Compiler<'_> {
+  fn emit(&mut self, node: &Node) {
+    match node {
+      Node::Empty => {}
+      Node::Char(ch) => self.program.push(Inst::Char(*ch)),
+      Node::Any => self.program.push(Inst::Any),
+      Node::Class(set) => self.program.push(Inst::Class(set.clone())),
+      Node::Start => self.program.push(Inst::Start),
+      Node::End => self.program.push(Inst::End),
+      Node::WordBoundary { negated } => self.program.push(Inst::WordBoundary { negated: *negated }),
+      Node::Capture(index, node) => {
+        self.program.push(Inst::CaptureStart);
+        self.emit(node);
+        self.program.push(Inst::CaptureEnd(*index));
+      }
+      Node::Concat(nodes) => nodes.iter().for_each(|node| self.emit(node)),
+      Node::Alternation(nodes) => {
+        let mut jumps = Vec::new();
+
+        for (idx, node) in nodes.iter().enumerate() {
+          if idx + 1 == nodes.len() {
+            self.emit(node);
+            break;
+          }
+
+          let split = self.program.len();
+
+          self.program.push(Inst::Split {
+            first: split + 1,
+            second: 0,
+          });
+          self.emit(node);
+          jumps.push(self.program.len());
+          self.program.push(Inst::Jump(0));
+
+          let second = self.program.len();
+
+          if let Inst::Split { second: target, .. } = &mut self.program[split] {
+            *target = second;
+          }
+        }
+
+        let end = self.program.len();
+
+        for jump in jumps {
+          self.program[jump] = Inst::Jump(end);
+        }
+      }
+      Node::Repeat {
+        node,
+        min,
+        max,
+        greedy,
+        groups,
+      } => {
+        if let Node::Char(_) | Node::Any | Node::Class(_) = **node {
+          self.program.push(Inst::RepeatChar {
+            min: *min,
+            max: *max,
+            greedy: *greedy,
+          });
+          self.emit(node);
+          return;
+        }
+
+        self.program.push(Inst::RepeatStart);
+
+        let check = self.program.len();
+
+        self.program.push(Inst::RepeatCheck {
+          min: *min,
+          max: *max,
+          greedy: *greedy,
+          exit: 0,
+        });
+        self.program.push(Inst::RepeatIteration(groups.clone()));
+        self.emit(node);
+        self.program.push(Inst::RepeatEnd { min: *min, check });
+
+        let end = self.program.len();
+
+        if let Inst::RepeatCheck { exit, .. } = &mut self.program[check] {
+          *exit = end;
+        }
+
+        self.program.push(Inst::RepeatExit);
+      }
+      Node::Backreference(index) => self.program.push(Inst::Backreference(*index)),
+      Node::NamedBackreference(name) => {
+        let index = self
+          .group_names
+          .iter()
+          .position(|group| group.as_deref() == Some(name.as_str()))
+          .unwrap_or_default();
+
+        self.program.push(Inst::Backreference(index));
+      }
+      Node::Look {
+        node,
+        ahead,
+        negated,
+      } => {
+        let look = self.program.len();
+
+        self.program.push(Inst::Look {
+          ahead: *ahead,
+          negated: *negated,
+          next: 0,
+        });
+        self.emit(node);
+        self.program.push(Inst::Match);
+
+        let end = self.program.len();
+
+        if let Inst::Look { next, .. } = &mut self.program[look] {
+          *next = end;
+        }
+      }
+    }
+  }
+}
+
+/// What's undone when backtracking, back to the last choice that's left to try.
+enum Trail {
+  /// Continues at `pc` and `pos`.
+  Branch {
+    pc: usize,
+    pos: usize,
+  },
+  /// Gives back a character of a greedy [`Inst::RepeatChar`], down to `min_end`.
+  Greedy {
+    pc: usize,
+    min_end: usize,
+    pos: usize,
+  },
+  /// Takes another character for a lazy [`Inst::RepeatChar`], up to `remaining` more.
+  Lazy {
+    pc: usize,
+    pos: usize,
+    remaining: Option<u32>,
+  },
+  Capture(usize, Option<(usize, usize)>),
+  Captures(Captures),
+  /// Pops a value that was pushed.
+  Push,
+  /// Pushes back a value that was popped.
+  Pop(usize),
+  /// Sets back the last value.
+  Set(usize),
+}
+
+struct Matcher<'a> {
+  regexp: &'a RegExp,
+  input: &'a str,
+  /// The instructions that are left to run, shared with lookarounds.
+  steps: Cell<usize>,
+}
+
+impl Matcher<'_> {
+  fn char_at(&self, pos: usize) -> Option<char> {
+    self.input[pos..].chars().next()
+  }
+
+  fn char_before(&self, pos: usize) -> Option<char> {
+    self.input[..pos].chars().next_back()
+  }
+
+  fn class_contains(&self, set: &ClassSet, ch: char) -> bool {
+    if set.contains(ch) {
+      return true;
+    }
+
+    if !self.regexp.ignore_case {
+      return false;
+    }
+
+    let folded = fold_case(ch);
+    let mut uppercase = ch.to_uppercase();
+    let upper = match (uppercase.next(), uppercase.next()) {
+      (Some(upper), None) => upper,
+      _ => ch,
+    };
+
+    set.contains(folded) || set.contains(upper)
+  }
+
+  fn is_word_boundary(&self, pos: usize) -> bool {
+    let before = self.char_before(pos).is_some_and(is_word_char);
+    let after = self.char_at(pos).is_some_and(is_word_char);
+
+    before != after
+  }
+
+  /// Matches a single character instruction at `pos`, returning the position after it.
+  fn step(&self, inst: &Inst, pos: usize) -> Option<usize> {
+    let ch = self.char_at(pos)?;
+    let matched = match inst {
+      Inst::Char(expected) => {
+        ch == *expected || (self.regexp.ignore_case && fold_case(ch) == fold_case(*expected))
+      }
+      Inst::Any => !is_line_terminator(ch),
+      Inst::Class(set) => self.class_contains(set, ch),
+      _ => false,
+    };
+
+    if matched {
+      Some(pos + ch.len_utf8())
+    } else {
+      None
+    }
+  }
+
+  /// Runs the program from `pc` at `pos`, until a match that ends at `end` when it's given.
+  ///
+  /// # Notes
+  /// Choices are kept on the heap rather than the call stack, so long inputs can't overflow it.
+  /// Only lookarounds run recursively, which nest as deep as the pattern does. The captures are
+  /// left as they were when nothing matches, and nothing matches once the steps run out.
+  fn run(
+    &self,
+    mut pc: usize,
+    mut pos: usize,
+    captures: &mut Captures,
+    end: Option<usize>,
+  ) -> bool {
+    let program = &self.regexp.program;
+    let mut stack = Vec::new();
+    let mut trail = Vec::new();
+
+    loop {
+      let steps = self.steps.get();
+
+      if steps == 0 {
+        return false;
+      }
+
+      self.steps.set(steps - 1);
+
+      let next = match &program[pc] {
+        Inst::Match if end.is_none_or(|end| end == pos) => return true,
+        Inst::Match => None,
+        inst @ (Inst::Char(_) | Inst::Any | Inst::Class(_)) => self.step(inst, pos).map(|next| {
+          pos = next;
+          pc + 1
+        }),
+        Inst::Start => Some(pc + 1).filter(|_| pos == 0),
+        Inst::End => Some(pc + 1).filter(|_| pos == self.input.len()),
+        Inst::WordBoundary { negated } => {
+          Some(pc + 1).filter(|_| self.is_word_boundary(pos) != *negated)
+        }
+        Inst::Backreference(index) => self.backreference(*index, pos, captures).map(|next| {
+          pos = next;
+          pc + 1
+        }),
+        Inst::Split { first, second } => {
+          trail.push(Trail::Branch { pc: *second, pos });
+          Some(*first)
+        }
+        Inst::Jump(target) => Some(*target),
+        Inst::CaptureStart => {
+          stack.push(pos);
+          trail.push(Trail::Push);
+          Some(pc + 1)
+        }
+        Inst::CaptureEnd(index) => {
+          let start = stack.pop().unwrap();
+          let previous = captures[*index].replace((start, pos));
+
+          trail.push(Trail::Pop(start));
+          trail.push(Trail::Capture(*index, previous));
+          Some(pc + 1)
+        }
+        Inst::RepeatCheck {
+          min,
+          max,
+          greedy,
+          exit,
+        } => {
+          let count = *stack.last().unwrap();
+          let can_stop = count >= *min as usize;
+          let can_continue = max.is_none_or(|max| count < max as usize);
+
+          // A greedy repeat tries another iteration before the rest of the pattern, and a lazy
+          // one after
+          match (can_continue, can_stop) {
+            (true, true) if *greedy => {
+              trail.push(Trail::Branch { pc: *exit, pos });
+              Some(pc + 1)
+            }
+            (true, true) => {
+              trail.push(Trail::Branch { pc: pc + 1, pos });
+              Some(*exit)
+            }
+            (true, false) => Some(pc + 1),
+            (false, true) => Some(*exit),
+            (false, false) => None,
+          }
+        }
+        Inst::RepeatIteration(groups) => {
+          for idx in groups.clone() {
+            if let Some(previous) = captures[idx].take() {
+              trail.push(Trail::Capture(idx, Some(previous)));
+            }
+          }
+
+          stack.push(pos);
+          trail.push(Trail::Push);
+          Some(pc + 1)
+        }
+        Inst::RepeatEnd { min, check } => {
+          let start = stack.pop().unwrap();
+          let count = stack.last_mut().unwrap();
+
+          trail.push(Trail::Pop(start));
+
+          // An iteration past the minimum that matches nothing ends the loop
+          if pos == start && *count >= *min as usize {
+            None
+          } else {
+            trail.push(Trail::Set(*count));
+            *count = count.saturating_add(1);
+            Some(*check)
+          }
+        }
+        Inst::RepeatStart => {
+          stack.push(0);
+          trail.push(Trail::Push);
+          Some(pc + 1)
+        }
+        Inst::RepeatExit => {
+          trail.push(Trail::Pop(stack.pop().unwrap()));
+          Some(pc + 1)
+        }
+        Inst::RepeatChar { min, max, greedy } => self
+          .repeat_char(pc, pos, *min, *max, *greedy, &mut trail)
+          .map(|next| {
+            pos = next;
+            pc + 2
+          }),
+        Inst::Look {
+          ahead,
+          negated,
+          next,
+        } => {
+          let mut inner = captures.clone();
+          let found = if *ahead {
+            self.run(pc + 1, pos, &mut inner, None)
+          } else {
+            (0..=pos)
+              .rev()
+              .filter(|&start| self.input.is_char_boundary(start))
+              .any(|start| self.run(pc + 1, start, &mut inner, Some(pos)))
+          };
+
+          match (found, *negated) {
+            (false, true) => Some(*next),
+            (true, false) => {
+              trail.push(Trail::Captures(std::mem::replace(captures, inner)));
+              Some(*next)
+            }
+            _ => None,
+          }
+        }
+      };
+
+      match next {
+        Some(next) => pc = next,
+        None => match self.backtrack(&mut trail, &mut stack, captures) {
+          Some((next, next_pos)) => {
+            pc = next;
+            pos = next_pos;
+          }
+          None => return false,
+        },
+      }
+    }
+  }
+
+  /// Matches as many characters as a greedy [`Inst::RepeatChar`] can, or as few as a lazy one
+  /// can, leaving how to take the others back on the trail.
+  fn repeat_char(
+    &self,
+    pc: usize,
+    mut pos: usize,
+    min: u32,
+    max: Option<u32>,
+    greedy: bool,
+    trail: &mut Vec<Trail>,
+  ) -> Option<usize> {
+    let inst = &self.regexp.program[pc + 1];
+
+    for _ in 0..min {
+      pos = self.step(inst, pos)?;
+    }
+
+    let remaining = max.map(|max| max - min);
+
+    if !greedy {
+      if remaining != Some(0) {
+        trail.push(Trail::Lazy { pc, pos, remaining });
+      }
+
+      return Some(pos);
+    }
+
+    let min_end = pos;
+    let mut count = 0;
+
+    while remaining.is_none_or(|remaining| count < remaining) {
+      match self.step(inst, pos) {
+        Some(next) => pos = next,
+        None => break,
+      }
+
+      count += 1;
+    }
+
+    if pos > min_end {
+      trail.push(Trail::Greedy { pc, min_end, pos });
+    }
+
+    Some(pos)
+  }
+
+  /// Undoes the trail back to the last choice that's left to try, returning where it continues.
+  fn backtrack(
+    &self,
+    trail: &mut Vec<Trail>,
+    stack: &mut Vec<usize>,
+    captures: &mut Captures,
+  ) -> Option<(usize, usize)> {
+    loop {
+      match trail.pop()? {
+        Trail::Branch { pc, pos } => return Some((pc, pos)),
+        Trail::Greedy { pc, min_end, pos } => {
+          // Every character taken was matched by the repeat, so it starts at a char boundary
+          let pos = pos - self.char_before(pos).map_or(0, char::len_utf8);
+
+          if pos > min_end {
+            trail.push(Trail::Greedy { pc, min_end, pos });
+          }
+
+          return Some((pc + 2, pos));
+        }
+        Trail::Lazy { pc, pos, remaining } => {
+          if let Some(pos) = self.step(&self.regexp.program[pc + 1], pos) {
+            let remaining = remaining.map(|remaining| remaining - 1);
+
+            if remaining != Some(0) {
+              trail.push(Trail::Lazy { pc, pos, remaining });
+            }
+
+            return Some((pc + 2, pos));
+          }
+        }
+        Trail::Capture(index, previous) => captures[index] = previous,
+        Trail::Captures(previous) => *captures = previous,
+        Trail::Push => {
+          stack.pop();
+        }
+        Trail::Pop(value) => stack.push(value),
+        Trail::Set(value) => *stack.last_mut().unwrap() = value,
+      }
+    }
+  }
+
+  /// Matches the text of a capture at `pos`, returning the position after it. A capture that
+  /// isn't set matches nothing.
+  fn backreference(&self, index: usize, pos: usize, captures: &Captures) -> Option<usize> {
+    let (start, end) = match captures.get(index).copied().flatten() {
+      Some(capture) => capture,
+      None => return Some(pos),
+    };
+    let captured = &self.input[start..end];
+    let rest = &self.input[pos..];
+    let len = if self.regexp.ignore_case {
+      let mut rest_chars = rest.char_indices();
+      let mut len = 0;
+
+      for expected in captured.chars() {
+        match rest_chars.next() {
+          Some((idx, ch)) if fold_case(ch) == fold_case(expected) => len = idx + ch.len_utf8(),
+          _ => return None,
+        }
+      }
+
+      len
+    } else if rest.starts_with(captured) {
+      captured.len()
+    } else {
+      return None;
+    };
+
+    Some(pos + len)
+  }
+}
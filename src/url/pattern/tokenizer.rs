@@ -0,0 +1,211 @@
+use super::UrlPatternError;
+use crate::idna::properties::is_mark;
+
+/// The kind of a [token](https://urlpattern.spec.whatwg.org/#token).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum TokenKind {
+  /// `{`
+  Open,
+  /// `}`
+  Close,
+  /// A regexp group, such as `(\d+)`, whose value excludes the parentheses.
+  Regexp,
+  /// A named group, such as `:id`, whose value excludes the `:`.
+  Name,
+  Char,
+  /// A character after `\`.
+  EscapedChar,
+  /// `+` or `?`
+  OtherModifier,
+  /// `*`
+  Asterisk,
+  End,
+  /// A character that's invalid where it is, which is only kept by the lenient policy.
+  InvalidChar,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) struct Token<'a> {
+  pub(super) kind: TokenKind,
+  /// The byte index of the token in the input.
+  pub(super) index: usize,
+  pub(super) value: &'a str,
+}
+
+/// Whether invalid input is an error, or kept as [`TokenKind::InvalidChar`] tokens.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum Policy {
+  Strict,
+  Lenient,
+}
+
+/// Splits a pattern string into tokens, following the
+/// [tokenize](https://urlpattern.spec.whatwg.org/#tokenize) algorithm.
+pub(super) fn tokenize(input: &str, policy: Policy) -> Result<Vec<Token<'_>>, UrlPatternError> {
+  let mut tokenizer = Tokenizer {
+    input,
+    policy,
+    tokens: Vec::new(),
+    index: 0,
+  };
+
+  while let Some(ch) = tokenizer.char_at(tokenizer.index) {
+    let next = tokenizer.index + ch.len_utf8();
+
+    match ch {
+      '*' => tokenizer.push(TokenKind::Asterisk, next, tokenizer.index, next),
+      '+' | '?' => tokenizer.push(TokenKind::OtherModifier, next, tokenizer.index, next),
+      '\\' => match tokenizer.char_at(next) {
+        Some(escaped) => {
+          let end = next + escaped.len_utf8();
+
+          tokenizer.push(TokenKind::EscapedChar, end, next, end);
+        }
+        None => tokenizer.error(next, tokenizer.index, next)?,
+      },
+      '{' => tokenizer.push(TokenKind::Open, next, tokenizer.index, next),
+      '}' => tokenizer.push(TokenKind::Close, next, tokenizer.index, next),
+      ':' => {
+        let mut end = next;
+
+        while let Some(ch) = tokenizer.char_at(end) {
+          if !is_valid_name_char(ch, end == next) {
+            break;
+          }
+
+          end += ch.len_utf8();
+        }
+
+        if end == next {
+          tokenizer.error(next, tokenizer.index, next)?;
+        } else {
+          tokenizer.push(TokenKind::Name, end, next, end);
+        }
+      }
+      '(' => tokenizer.regexp(next)?,
+      _ => tokenizer.push(TokenKind::Char, next, tokenizer.index, next),
+    }
+  }
+
+  let end = input.len();
+
+  tokenizer.push(TokenKind::End, end, end, end);
+
+  Ok(tokenizer.tokens)
+}
+
+/// Whether a character can be part of a group name, which is an `IdentifierName`.
+///
+/// # Notes
+/// The `ID_Start` and `ID_Continue` properties are approximated by the alphabetic and alphanumeric
+/// properties, along with marks and the characters that are in them for compatibility.
+pub(super) fn is_valid_name_char(ch: char, first: bool) -> bool {
+  let is_id_start = ch.is_alphabetic()
+    || matches!(
+      ch,
+      '\u{1885}' | '\u{1886}' | '\u{2118}' | '\u{212E}' | '\u{309B}' | '\u{309C}'
+    );
+
+  if first {
+    ch == '$' || ch == '_' || is_id_start
+  } else {
+    matches!(
+      ch,
+      '$' | '_' | '\u{200C}' | '\u{200D}' | '\u{B7}' | '\u{387}' | '\u{1369}'
+        ..='\u{1371}' | '\u{19DA}' | '\u{30FB}' | '\u{FF65}'
+    ) || is_id_start
+      || ch.is_numeric()
+      || is_mark(ch)
+  }
+}
+
+struct Tokenizer<'a> {
+  input: &'a str,
+  policy: Policy,
+  tokens: Vec<Token<'a>>,
+  /// The index of the next token.
+  index: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+  fn char_at(&self, index: usize) -> Option<char> {
+    self.input[index..].chars().next()
+  }
+
+  /// Adds a token whose value is `input[value_start..value_end]`, moving on to `next`.
+  fn push(&mut self, kind: TokenKind, next: usize, value_start: usize, value_end: usize) {
+    self.tokens.push(Token {
+      kind,
+      index: self.index,
+      value: &self.input[value_start..value_end],
+    });
+    self.index = next;
+  }
+
+  /// Fails with the strict policy, and adds an invalid character token otherwise.
+  fn error(
+    &mut self,
+    next: usize,
+    value_start: usize,
+    value_end: usize,
+  ) -> Result<(), UrlPatternError> {
+    match self.policy {
+      Policy::Strict => Err(UrlPatternError::InvalidPattern),
+      Policy::Lenient => {
+        self.push(TokenKind::InvalidChar, next, value_start, value_end);
+        Ok(())
+      }
+    }
+  }
+
+  /// Tokenizes a regexp group, where `start` is the index after its `(`.
+  fn regexp(&mut self, start: usize) -> Result<(), UrlPatternError> {
+    let mut depth = 1;
+    let mut pos = start;
+
+    while let Some(ch) = self.char_at(pos) {
+      let next = pos + ch.len_utf8();
+
+      if !ch.is_ascii() || (pos == start && ch == '?') {
+        return self.error(start, self.index, start);
+      }
+
+      match ch {
+        '\\' => match self.char_at(next) {
+          Some(escaped) if escaped.is_ascii() => {
+            pos = next + 1;
+            continue;
+          }
+          _ => return self.error(start, self.index, start),
+        },
+        ')' => {
+          depth -= 1;
+
+          if depth == 0 {
+            let end = pos;
+
+            if end == start {
+              return self.error(start, self.index, start);
+            }
+
+            self.push(TokenKind::Regexp, next, start, end);
+            return Ok(());
+          }
+        }
+        // Nested groups must not capture, so that the groups match the names
+        '(' => {
+          depth += 1;
+
+          if self.char_at(next) != Some('?') {
+            return self.error(start, self.index, start);
+          }
+        }
+        _ => {}
+      }
+
+      pos = next;
+    }
+
+    self.error(start, self.index, start)
+  }
+}
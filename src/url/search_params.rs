@@ -0,0 +1,189 @@
+use crate::globals::{encode_str, percent_decode, FORM_URLENCODED_SET};
+
+use std::fmt;
+use std::iter::FromIterator;
+
+/// A list of name-value pairs of a query string, which keeps the order pairs are added in.
+///
+/// # Notes
+/// This type functionally behaves the same as
+/// [JavaScript's URLSearchParams](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams).
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct UrlSearchParams {
+  pairs: Vec<(String, String)>,
+}
+
+impl UrlSearchParams {
+  /// Parses a query string, ignoring a leading `?`.
+  pub fn new(init: &str) -> UrlSearchParams {
+    let init = init.strip_prefix('?').unwrap_or(init);
+
+    UrlSearchParams {
+      pairs: parse_urlencoded(init.as_bytes()),
+    }
+  }
+
+  /// Returns the number of pairs.
+  pub fn size(&self) -> usize {
+    self.pairs.len()
+  }
+
+  /// Appends a pair.
+  pub fn append(&mut self, name: &str, value: &str) {
+    self.pairs.push((name.to_owned(), value.to_owned()));
+  }
+
+  /// Removes every pair with the given name, and the given value if there is one.
+  pub fn delete(&mut self, name: &str, value: Option<&str>) {
+    self
+      .pairs
+      .retain(|(n, v)| n != name || value.is_some_and(|value| v != value));
+  }
+
+  /// Returns the value of the first pair with the given name.
+  pub fn get(&self, name: &str) -> Option<&str> {
+    self
+      .pairs
+      .iter()
+      .find(|(n, _)| n == name)
+      .map(|(_, v)| v.as_str())
+  }
+
+  /// Returns the values of every pair with the given name.
+  pub fn get_all(&self, name: &str) -> Vec<&str> {
+    self
+      .pairs
+      .iter()
+      .filter(|(n, _)| n == name)
+      .map(|(_, v)| v.as_str())
+      .collect()
+  }
+
+  /// Whether there's a pair with the given name, and the given value if there is one.
+  pub fn has(&self, name: &str, value: Option<&str>) -> bool {
+    self
+      .pairs
+      .iter()
+      .any(|(n, v)| n == name && value.is_none_or(|value| v == value))
+  }
+
+  /// Sets the value of the first pair with the given name, removing every other pair with that
+  /// name. The pair is appended when there's none with the given name.
+  pub fn set(&mut self, name: &str, value: &str) {
+    match self.pairs.iter().position(|(n, _)| n == name) {
+      Some(idx) => {
+        self.pairs[idx].1 = value.to_owned();
+
+        let mut position = 0;
+
+        self.pairs.retain(|(n, _)| {
+          let keep = position <= idx || n != name;
+
+          position += 1;
+          keep
+        });
+      }
+      None => self.append(name, value),
+    }
+  }
+
+  /// Sorts the pairs by their names, keeping the order of pairs with the same name.
+  ///
+  /// # Notes
+  /// Names are compared by their UTF-16 code units, like JavaScript does.
+  pub fn sort(&mut self) {
+    self
+      .pairs
+      .sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+  }
+
+  /// Returns an iterator over the pairs, in the order they were added.
+  pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+    self.pairs.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+  }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for UrlSearchParams {
+  fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+    UrlSearchParams {
+      pairs: iter
+        .into_iter()
+        .map(|(name, value)| (name.into(), value.into()))
+        .collect(),
+    }
+  }
+}
+
+impl fmt::Display for UrlSearchParams {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut serialized = String::new();
+
+    serialize_urlencoded(self.iter(), &mut serialized);
+
+    f.write_str(&serialized)
+  }
+}
+
+/// Parses an `application/x-www-form-urlencoded` string to a list of name-value pairs.
+///
+/// # Notes
+/// This function follows the
+/// [application/x-www-form-urlencoded parser](https://url.spec.whatwg.org/#concept-urlencoded-parser),
+/// where `+` is decoded as a space and invalid UTF-8 is replaced with U+FFFD.
+pub fn parse_urlencoded(bytes: &[u8]) -> Vec<(String, String)> {
+  bytes
+    .split(|&byte| byte == b'&')
+    .filter(|sequence| !sequence.is_empty())
+    .map(|sequence| {
+      let (name, value) = match sequence.iter().position(|&byte| byte == b'=') {
+        Some(idx) => (&sequence[..idx], &sequence[idx + 1..]),
+        None => (sequence, &[][..]),
+      };
+
+      (decode_urlencoded(name), decode_urlencoded(value))
+    })
+    .collect()
+}
+
+/// Serializes name-value pairs as an `application/x-www-form-urlencoded` string.
+///
+/// # Notes
+/// This function follows the
+/// [application/x-www-form-urlencoded serializer](https://url.spec.whatwg.org/#concept-urlencoded-serializer),
+/// where a space is encoded as `+`.
+pub fn serialize_urlencoded<'a, I>(pairs: I, serialized: &mut String)
+where
+  I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+  for (idx, (name, value)) in pairs.into_iter().enumerate() {
+    if idx != 0 {
+      serialized.push('&');
+    }
+
+    encode_urlencoded(name, serialized);
+    serialized.push('=');
+    encode_urlencoded(value, serialized);
+  }
+}
+
+fn decode_urlencoded(bytes: &[u8]) -> String {
+  let replaced = bytes
+    .iter()
+    .map(|&byte| if byte == b'+' { b' ' } else { byte })
+    .collect::<Vec<_>>();
+
+  let mut decoded = Vec::with_capacity(replaced.len());
+
+  percent_decode(&replaced, &mut decoded);
+  String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn encode_urlencoded(string: &str, encoded: &mut String) {
+  for (idx, part) in string.split(' ').enumerate() {
+    if idx != 0 {
+      encoded.push('+');
+    }
+
+    encode_str(part, &FORM_URLENCODED_SET, encoded);
+  }
+}
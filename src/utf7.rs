@@ -0,0 +1,316 @@
+use crate::base64::{decode_base64_with, encode_base64_with, Alphabet, IMAP, STANDARD};
+use crate::sink::{push_ascii, Sink};
+
+use std::fmt;
+
+/// An error when decoding a UTF-7 encoded string.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Utf7Error {
+  /// A character that can't appear outside of a shift sequence was encountered.
+  InvalidCharacter,
+  /// A shift sequence was empty, wasn't valid base64 or didn't decode to whole UTF-16 code units.
+  InvalidShift,
+  /// A shift sequence wasn't closed.
+  UnterminatedShift,
+  /// A shift sequence decoded to an unpaired surrogate.
+  UnpairedSurrogate,
+}
+
+impl fmt::Display for Utf7Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Utf7Error::InvalidCharacter => "invalid character",
+      Utf7Error::InvalidShift => "invalid shift sequence",
+      Utf7Error::UnterminatedShift => "unterminated shift sequence",
+      Utf7Error::UnpairedSurrogate => "unpaired surrogate",
+    })
+  }
+}
+
+impl std::error::Error for Utf7Error {}
+
+/// Encodes a string to UTF-7.
+///
+/// # Notes
+/// Characters in the sets D and O of [RFC 2152](https://datatracker.ietf.org/doc/html/rfc2152),
+/// along with space, tab, carriage return and line feed, are written directly, with `+` being
+/// written as `+-`. Every other character is written as base64 encoded UTF-16.
+pub fn encode_utf7<S: Sink + ?Sized>(string: &str, encoded: &mut S) {
+  let mut units = Vec::new();
+  let mut chars = string.chars().peekable();
+
+  while let Some(ch) = chars.next() {
+    if is_utf7_direct(ch) {
+      push_ascii(encoded, &[ch as u8]);
+      continue;
+    }
+
+    if ch == '+' {
+      encoded.push_str("+-");
+      continue;
+    }
+
+    units.clear();
+    push_utf16(ch, &mut units);
+
+    while let Some(&next) = chars.peek() {
+      if is_utf7_direct(next) || next == '+' {
+        break;
+      }
+
+      push_utf16(next, &mut units);
+      chars.next();
+    }
+
+    encoded.push_str("+");
+    encode_base64_with(&units, &STANDARD, false, encoded);
+
+    // The shift is implicitly closed by any character outside of the base64 alphabet, except for
+    // `-` which would be absorbed.
+    match chars.peek() {
+      Some(&next) if next != '-' && STANDARD.value(next as u8).is_none() => {}
+      _ => encoded.push_str("-"),
+    }
+  }
+}
+
+/// Decodes a UTF-7 encoded string to UTF-8.
+///
+/// # Notes
+/// A shift sequence is closed by `-`, which is absorbed, or by any other character that's not in
+/// the base64 alphabet. Shift sequences must decode to whole UTF-16 code units, without any
+/// unpaired surrogates, and leave fewer than 6 unused bits that are all zero.
+///
+/// The end of the input implicitly closes a shift sequence that meets these rules, like
+/// `+AOk` decoding to `é`. Otherwise, including for a `+` at the very end,
+/// [`Utf7Error::UnterminatedShift`] is returned. Nothing is written to `decoded` when an error is
+/// returned.
+pub fn decode_utf7<S: Sink + ?Sized>(bytes: &[u8], decoded: &mut S) -> Result<(), Utf7Error> {
+  // Decodes to a buffer first, so that nothing is written on errors
+  let mut buffer = String::with_capacity(bytes.len());
+
+  decode_utf7_inner(bytes, &mut buffer)?;
+  decoded.push_str(&buffer);
+
+  Ok(())
+}
+
+fn decode_utf7_inner(bytes: &[u8], decoded: &mut String) -> Result<(), Utf7Error> {
+  let mut idx = 0;
+
+  while idx < bytes.len() {
+    let current = bytes[idx];
+
+    if current >= 0x80 {
+      return Err(Utf7Error::InvalidCharacter);
+    }
+
+    idx += 1;
+
+    if current != b'+' {
+      decoded.push(current as char);
+      continue;
+    }
+
+    let start = idx;
+
+    while idx < bytes.len() && STANDARD.value(bytes[idx]).is_some() {
+      idx += 1;
+    }
+
+    let shifted = &bytes[start..idx];
+    let closed = bytes.get(idx) == Some(&b'-');
+
+    if closed {
+      idx += 1;
+
+      // `+-` is an escaped `+`.
+      if shifted.is_empty() {
+        decoded.push('+');
+        continue;
+      }
+    } else if shifted.is_empty() && idx == bytes.len() {
+      return Err(Utf7Error::UnterminatedShift);
+    } else if shifted.is_empty() {
+      return Err(Utf7Error::InvalidShift);
+    }
+
+    decode_shift(shifted, &STANDARD, decoded).map_err(|error| match error {
+      // The end of the input closes a shift, but only on a whole UTF-16 code unit
+      Utf7Error::InvalidShift if !closed && idx == bytes.len() => Utf7Error::UnterminatedShift,
+      error => error,
+    })?;
+  }
+
+  Ok(())
+}
+
+/// Encodes a string to IMAP modified UTF-7, as used for mailbox names.
+///
+/// # Notes
+/// This function follows [RFC 3501 §5.1.3](https://datatracker.ietf.org/doc/html/rfc3501#section-5.1.3).
+/// Printable ASCII characters are written directly, with `&` being written as `&-`. Every other
+/// character is written as UTF-16 encoded in modified base64 between `&` and `-`.
+pub fn encode_imap_utf7<S: Sink + ?Sized>(string: &str, encoded: &mut S) {
+  let mut units = Vec::new();
+  let mut chars = string.chars().peekable();
+
+  while let Some(ch) = chars.next() {
+    if is_imap_direct(ch) {
+      push_ascii(encoded, &[ch as u8]);
+
+      if ch == '&' {
+        encoded.push_str("-");
+      }
+
+      continue;
+    }
+
+    units.clear();
+    push_utf16(ch, &mut units);
+
+    while let Some(&next) = chars.peek() {
+      if is_imap_direct(next) {
+        break;
+      }
+
+      push_utf16(next, &mut units);
+      chars.next();
+    }
+
+    encoded.push_str("&");
+    encode_base64_with(&units, &IMAP, false, encoded);
+    encoded.push_str("-");
+  }
+}
+
+/// Decodes an IMAP modified UTF-7 encoded string to UTF-8.
+///
+/// # Notes
+/// Besides the rules of [`decode_utf7`], shift sequences must be explicitly closed by `-`, must
+/// not encode printable ASCII characters and must not directly follow another shift sequence.
+/// Nothing is written to `decoded` when an error is returned.
+pub fn decode_imap_utf7<S: Sink + ?Sized>(bytes: &[u8], decoded: &mut S) -> Result<(), Utf7Error> {
+  // Decodes to a buffer first, so that nothing is written on errors
+  let mut buffer = String::with_capacity(bytes.len());
+
+  decode_imap_utf7_inner(bytes, &mut buffer)?;
+  decoded.push_str(&buffer);
+
+  Ok(())
+}
+
+fn decode_imap_utf7_inner(bytes: &[u8], decoded: &mut String) -> Result<(), Utf7Error> {
+  let mut idx = 0;
+  let mut previous_shift_end = None;
+
+  while idx < bytes.len() {
+    let current = bytes[idx];
+
+    if !(0x20..0x7F).contains(&current) {
+      return Err(Utf7Error::InvalidCharacter);
+    }
+
+    idx += 1;
+
+    if current != b'&' {
+      decoded.push(current as char);
+      continue;
+    }
+
+    let start = idx;
+
+    while idx < bytes.len() && IMAP.value(bytes[idx]).is_some() {
+      idx += 1;
+    }
+
+    let shifted = &bytes[start..idx];
+
+    if bytes.get(idx) != Some(&b'-') {
+      return Err(Utf7Error::UnterminatedShift);
+    }
+
+    idx += 1;
+
+    // `&-` is an escaped `&`.
+    if shifted.is_empty() {
+      decoded.push('&');
+      continue;
+    }
+
+    // Null shifts, that is `-&` while shifted, aren't permitted.
+    if previous_shift_end == Some(start - 1) {
+      return Err(Utf7Error::InvalidShift);
+    }
+
+    let decoded_start = decoded.len();
+
+    decode_shift(shifted, &IMAP, decoded)?;
+
+    if decoded[decoded_start..]
+      .bytes()
+      .any(|byte| (0x20..0x7F).contains(&byte))
+    {
+      return Err(Utf7Error::InvalidShift);
+    }
+
+    previous_shift_end = Some(idx);
+  }
+
+  Ok(())
+}
+
+/// Decodes the base64 encoded UTF-16 of a shift sequence to UTF-8.
+fn decode_shift(
+  shifted: &[u8],
+  alphabet: &Alphabet,
+  decoded: &mut String,
+) -> Result<(), Utf7Error> {
+  let mut bytes = Vec::with_capacity(shifted.len());
+
+  decode_base64_with(shifted, alphabet, false, &mut bytes).map_err(|_| Utf7Error::InvalidShift)?;
+
+  if !bytes.len().is_multiple_of(2) {
+    return Err(Utf7Error::InvalidShift);
+  }
+
+  let units = bytes
+    .chunks_exact(2)
+    .map(|pair| u16::from_be_bytes([pair[0], pair[1]]));
+
+  for ch in char::decode_utf16(units) {
+    let ch = ch.map_err(|_| Utf7Error::UnpairedSurrogate)?;
+
+    decoded.push(ch);
+  }
+
+  Ok(())
+}
+
+/// Appends the big endian UTF-16 encoding of a character.
+#[inline]
+fn push_utf16(ch: char, units: &mut Vec<u8>) {
+  for unit in ch.encode_utf16(&mut [0; 2]) {
+    units.extend_from_slice(&unit.to_be_bytes());
+  }
+}
+
+/// Whether the character is written directly in UTF-7.
+#[inline]
+fn is_utf7_direct(ch: char) -> bool {
+  matches!(
+    ch,
+    // Set D
+    'A'..='Z' | 'a'..='z' | '0'..='9'
+    | '\'' | '(' | ')' | ',' | '-' | '.' | '/' | ':' | '?' | ' ' | '\t' | '\r' | '\n'
+    // Set O
+    | '!' | '"' | '#' | '$' | '%' | '&' | '*' | ';' | '<' | '=' | '>' | '@' | '[' | ']' | '^'
+    | '_' | '`' | '{' | '|' | '}'
+  )
+}
+
+/// Whether the character is written directly in IMAP modified UTF-7.
+#[inline]
+fn is_imap_direct(ch: char) -> bool {
+  (' '..='~').contains(&ch)
+}
@@ -0,0 +1,67 @@
+use draco_utilities::globals::{
+  encode_inner, AsciiSet, C0_CONTROL_SET, COMPONENT_SET, FORM_URLENCODED_SET, FRAGMENT_SET,
+  PATH_SET, QUERY_SET, SPECIAL_QUERY_SET, UNRESERVED_SET, USERINFO_SET,
+};
+
+/// Returns the printable ASCII characters in the set.
+fn printable(set: &AsciiSet) -> String {
+  (0x20..0x7F)
+    .filter(|&byte| set.contains(byte))
+    .map(char::from)
+    .collect()
+}
+
+#[test]
+fn set_operations() {
+  const SET: AsciiSet = AsciiSet::from_bytes(b"abc").add(b'd').remove(b'a');
+
+  assert!(!SET.contains(b'a'));
+  assert!(SET.contains(b'b') && SET.contains(b'd'));
+  assert_eq!(
+    SET.union(&AsciiSet::from_bytes(b"a")),
+    AsciiSet::from_range(b'a', b'd')
+  );
+  assert!(SET.complement().contains(b'a'));
+  assert!(SET.complement().contains(0xFF));
+  assert_eq!(SET.complement().complement(), SET);
+  assert_eq!(AsciiSet::EMPTY.complement(), AsciiSet::from_range(0, 0xFF));
+}
+
+#[test]
+fn url_sets() {
+  for byte in (0x00..0x20).chain(0x7F..=0xFF) {
+    assert!(C0_CONTROL_SET.contains(byte), "{:#X}", byte);
+  }
+
+  assert_eq!(printable(&C0_CONTROL_SET), "");
+  assert_eq!(printable(&FRAGMENT_SET), " \"<>`");
+  assert_eq!(printable(&QUERY_SET), " \"#<>");
+  assert_eq!(printable(&SPECIAL_QUERY_SET), " \"#'<>");
+  assert_eq!(printable(&PATH_SET), " \"#<>?`{}");
+  assert_eq!(printable(&USERINFO_SET), " \"#/:;<=>?@[\\]^`{|}");
+  assert_eq!(printable(&COMPONENT_SET), " \"#$%&+,/:;<=>?@[\\]^`{|}");
+  assert_eq!(
+    printable(&FORM_URLENCODED_SET),
+    " !\"#$%&'()+,/:;<=>?@[\\]^`{|}~"
+  );
+}
+
+#[test]
+fn unreserved_set() {
+  let unreserved = printable(&UNRESERVED_SET.complement());
+
+  assert_eq!(
+    unreserved,
+    "-.0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz~"
+  );
+  assert!(UNRESERVED_SET.contains(0x80));
+}
+
+#[test]
+fn encode_with_custom_set() {
+  const SET: AsciiSet = UNRESERVED_SET.remove(b'/');
+  let mut encoded = Vec::new();
+
+  encode_inner("/a b/ü~!".as_bytes(), &SET, &mut encoded).unwrap();
+  assert_eq!(encoded, b"/a%20b/%C3%BC~%21");
+}
@@ -1,4 +1,7 @@
-use draco_utilities::base64::{decode_base64, encode_base64};
+use draco_utilities::base64::{
+  decode_base64, decode_base64_with, encode_base64, encode_base64_with, DecodeErrorKind, IMAP,
+  STANDARD, URL_SAFE,
+};
 
 #[test]
 fn encode() {
@@ -120,3 +123,56 @@ fn decode_moby_dick() {
   let result = std::str::from_utf8(&decoded).unwrap();
   assert_eq!(result, expected);
 }
+
+#[test]
+fn encode_with_alphabet() {
+  let mut encoded = Vec::new();
+
+  encode_base64_with(b"\xFB\xFF\xBF", &URL_SAFE, true, &mut encoded);
+  encode_base64_with(b"\xFB\xFF", &IMAP, false, &mut encoded);
+  encode_base64_with(b"\xFB", &STANDARD, true, &mut encoded);
+
+  let result = std::str::from_utf8(&encoded).unwrap();
+  assert_eq!(result, "-_-_+,8+w==");
+}
+
+#[test]
+fn decode_with_alphabet() {
+  let mut decoded = Vec::new();
+
+  decode_base64_with(b"-_-_", &URL_SAFE, true, &mut decoded).unwrap();
+  decode_base64_with(b"+,8", &IMAP, false, &mut decoded).unwrap();
+  decode_base64_with(b"+w==", &STANDARD, true, &mut decoded).unwrap();
+
+  assert_eq!(decoded, b"\xFB\xFF\xBF\xFB\xFF\xFB");
+}
+
+#[test]
+fn decode_with_alphabet_invalid() {
+  let mut decoded = Vec::new();
+  let mut kind = |bytes: &[u8], padding| {
+    decode_base64_with(bytes, &STANDARD, padding, &mut decoded).map_err(|e| e.kind())
+  };
+
+  assert_eq!(kind(b"+w", true), Err(DecodeErrorKind::InvalidLength));
+  assert_eq!(kind(b"+w==", false), Err(DecodeErrorKind::InvalidCharacter));
+  assert_eq!(kind(b"+x", false), Err(DecodeErrorKind::TrailingBits));
+  assert_eq!(kind(b"-_", false), Err(DecodeErrorKind::InvalidCharacter));
+}
+
+#[test]
+fn decode_error_location() {
+  let mut decoded = Vec::new();
+  let error = decode_base64_with(b"Zm9vYm*y", &STANDARD, true, &mut decoded).unwrap_err();
+
+  assert_eq!(error.kind(), DecodeErrorKind::InvalidCharacter);
+  assert_eq!(error.index(), 6);
+  assert_eq!(error.sequence(), b"*");
+  assert_eq!(error.to_string(), "invalid character `*` at index 6");
+
+  let error = decode_base64(b"Zm9v!mFy", &mut decoded).unwrap_err();
+
+  assert_eq!(error.kind(), DecodeErrorKind::InvalidCharacter);
+  assert_eq!(error.index(), 4);
+  assert_eq!(error.sequence(), b"!");
+}
@@ -0,0 +1,16 @@
+// Each test crate only uses some of the helpers
+#![allow(dead_code)]
+
+use serde_json::Value;
+
+/// Reads a file of `tests/fixtures`.
+pub fn fixture(name: &str) -> String {
+  let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+
+  std::fs::read_to_string(path).unwrap()
+}
+
+/// Reads a JSON file of `tests/fixtures`.
+pub fn json_fixture(name: &str) -> Value {
+  serde_json::from_str(&fixture(name)).unwrap()
+}
@@ -0,0 +1,209 @@
+use draco_utilities::content_disposition::{
+  decode_ext_value, encode_ext_value, ContentDisposition, ContentDispositionError, ExtValueError,
+};
+
+#[test]
+fn ext_values() {
+  let tests = [
+    ("€.txt", "UTF-8''%E2%82%AC.txt"),
+    ("a b;c\"d'e*f%", "UTF-8''a%20b%3Bc%22d%27e%2Af%25"),
+    ("!#$&+-.^_`|~", "UTF-8''!#$&+-.^_`|~"),
+    ("", "UTF-8''"),
+  ];
+
+  for (value, expected) in tests.iter() {
+    let mut encoded = String::new();
+
+    encode_ext_value(value, &mut encoded);
+    assert_eq!(encoded, *expected);
+
+    let mut decoded = String::new();
+
+    decode_ext_value(&encoded, &mut decoded).unwrap();
+    assert_eq!(decoded, *value);
+  }
+
+  let tests = [
+    ("utf-8'en'%C2%A3%20rates", Ok("£ rates")),
+    ("iso-8859-1'en'%A3%20rates", Ok("£ rates")),
+    ("UTF-8'de-CH'a", Ok("a")),
+    ("UTF-8''a'b", Err(ExtValueError::InvalidFormat)),
+    ("UTF-8'a", Err(ExtValueError::InvalidFormat)),
+    ("'en'a", Err(ExtValueError::InvalidFormat)),
+    ("UTF-8'e n'a", Err(ExtValueError::InvalidFormat)),
+    ("UTF-8''a b", Err(ExtValueError::InvalidFormat)),
+    ("UTF-8''%E2%82", Err(ExtValueError::InvalidUtf8)),
+    ("UTF-8''%ZZ", Err(ExtValueError::InvalidFormat)),
+    ("UTF-8''%2", Err(ExtValueError::InvalidFormat)),
+    ("UTF-8''€", Err(ExtValueError::InvalidFormat)),
+    ("Shift_JIS''a", Err(ExtValueError::UnsupportedCharset)),
+  ];
+
+  for (ext_value, expected) in tests.iter() {
+    let mut decoded = String::from("x");
+    let result = decode_ext_value(ext_value, &mut decoded);
+
+    match expected {
+      Ok(value) => {
+        assert_eq!(result, Ok(()), "{:?}", ext_value);
+        assert_eq!(decoded, format!("x{}", value));
+      }
+      Err(error) => {
+        assert_eq!(result, Err(*error), "{:?}", ext_value);
+        assert_eq!(decoded, "x");
+      }
+    }
+  }
+}
+
+#[test]
+fn parse() {
+  let tests: [(&str, &str, Option<&str>); 10] = [
+    ("inline", "inline", None),
+    (
+      "Attachment; FILENAME=file.txt",
+      "attachment",
+      Some("file.txt"),
+    ),
+    (
+      "attachment; filename=\"a \\\"b\\\\c.txt\"",
+      "attachment",
+      Some("a \"b\\c.txt"),
+    ),
+    (
+      "attachment; filename=\"EURO rates\"; filename*=utf-8''%e2%82%ac%20rates",
+      "attachment",
+      Some("€ rates"),
+    ),
+    (
+      "attachment; filename*=UTF-8''%E2%82%AC.txt; filename=\"_.txt\"",
+      "attachment",
+      Some("€.txt"),
+    ),
+    (
+      "attachment; filename=a.txt; filename=b.txt",
+      "attachment",
+      Some("a.txt"),
+    ),
+    (
+      "attachment;filename=\"€.txt\";",
+      "attachment",
+      Some("€.txt"),
+    ),
+    ("attachment; filename=\"a;b\"", "attachment", Some("a;b")),
+    (
+      "form-data; name=\"field\"; filename=\"\"",
+      "form-data",
+      Some(""),
+    ),
+    (" attachment ; filename = a ", "attachment", Some("a")),
+  ];
+
+  for (header, disposition_type, filename) in tests.iter() {
+    let disposition = ContentDisposition::parse(header).unwrap();
+
+    assert_eq!(
+      disposition.disposition_type(),
+      *disposition_type,
+      "{:?}",
+      header
+    );
+    assert_eq!(disposition.filename(), *filename, "{:?}", header);
+  }
+
+  let disposition = ContentDisposition::parse("form-data; name=field; Filename=a").unwrap();
+
+  assert_eq!(disposition.parameter("NAME"), Some("field"));
+  assert_eq!(
+    disposition.parameters().collect::<Vec<_>>(),
+    [("name", "field"), ("filename", "a")]
+  );
+
+  let errors = [
+    ("", ContentDispositionError::InvalidType),
+    ("\"attachment\"", ContentDispositionError::InvalidType),
+    (
+      "attachment filename=a",
+      ContentDispositionError::InvalidParameter,
+    ),
+    (
+      "attachment; filename",
+      ContentDispositionError::InvalidParameter,
+    ),
+    ("attachment; =a", ContentDispositionError::InvalidParameter),
+    (
+      "attachment; filename=",
+      ContentDispositionError::InvalidParameter,
+    ),
+    (
+      "attachment; filename=\"a",
+      ContentDispositionError::InvalidParameter,
+    ),
+    (
+      "attachment; filename=a b",
+      ContentDispositionError::InvalidParameter,
+    ),
+    (
+      "attachment; filename*=a",
+      ContentDispositionError::InvalidExtValue(ExtValueError::InvalidFormat),
+    ),
+  ];
+
+  for (header, error) in errors.iter() {
+    assert_eq!(
+      ContentDisposition::parse(header),
+      Err(*error),
+      "{:?}",
+      header
+    );
+  }
+}
+
+#[test]
+fn format() {
+  let tests = [
+    ("file.txt", "attachment; filename=file.txt"),
+    ("my file.txt", "attachment; filename=\"my file.txt\""),
+    ("a \"b\\c", "attachment; filename=\"a \\\"b\\\\c\""),
+    (
+      "€ rates.txt",
+      "attachment; filename=\"_ rates.txt\"; filename*=UTF-8''%E2%82%AC%20rates.txt",
+    ),
+    (
+      "100%20.txt",
+      "attachment; filename=\"100%20.txt\"; filename*=UTF-8''100%2520.txt",
+    ),
+    ("100%.txt", "attachment; filename=100%.txt"),
+    (
+      "a\"\u{7}.txt",
+      "attachment; filename=\"a__.txt\"; filename*=UTF-8''a%22%07.txt",
+    ),
+    ("", "attachment; filename=\"\""),
+  ];
+
+  for (filename, expected) in tests.iter() {
+    let disposition = ContentDisposition::attachment(filename);
+    let header = disposition.to_string();
+
+    assert_eq!(header, *expected);
+    assert_eq!(ContentDisposition::parse(&header), Ok(disposition));
+  }
+
+  let mut disposition = ContentDisposition::new("Form-Data").unwrap();
+
+  disposition.set_parameter("Name", "field").unwrap();
+  disposition.set_parameter("filename", "ü").unwrap();
+  disposition.set_parameter("name", "other").unwrap();
+  assert_eq!(
+    disposition.to_string(),
+    "form-data; name=other; filename=\"_\"; filename*=UTF-8''%C3%BC"
+  );
+  assert_eq!(
+    disposition.set_parameter("filename*", "a"),
+    Err(ContentDispositionError::InvalidParameter)
+  );
+  assert_eq!(
+    ContentDisposition::new("a b"),
+    Err(ContentDispositionError::InvalidType)
+  );
+}
@@ -0,0 +1,135 @@
+use draco_utilities::cookie::{
+  is_valid_cookie_name, is_valid_cookie_value, parse_cookie, CookieError, SameSite, SetCookie,
+};
+
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn parse_header() {
+  let tests: [(&str, &[(&str, &str)]); 8] = [
+    ("a=b", &[("a", "b")]),
+    ("a=b; c=d;e=f", &[("a", "b"), ("c", "d"), ("e", "f")]),
+    (" a = b ;\tc=\"d e\" ", &[("a", "b"), ("c", "d e")]),
+    ("a=b=c; d=", &[("a", "b=c"), ("d", "")]),
+    ("a; =b; c=d;;", &[("c", "d")]),
+    (
+      "a=%E4%BD%A0%20b; c=%; d=%ZZ%C3",
+      &[("a", "你 b"), ("c", "%"), ("d", "%ZZ\u{FFFD}")],
+    ),
+    ("a=\"b; c=d\"", &[("a", "\"b"), ("c", "d\"")]),
+    ("a=1; a=2", &[("a", "1"), ("a", "2")]),
+  ];
+
+  for (header, expected) in tests.iter() {
+    let expected = expected
+      .iter()
+      .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+      .collect::<Vec<_>>();
+
+    assert_eq!(parse_cookie(header), expected, "{:?}", header);
+  }
+}
+
+#[test]
+fn validation() {
+  for name in ["a", "SID", "__Host-id", "a!#$%&'*+-.^_`|~1"].iter() {
+    assert!(is_valid_cookie_name(name), "{:?}", name);
+  }
+
+  for name in ["", "a b", "a=b", "a;b", "a\"", "(a)", "ü", "a\t"].iter() {
+    assert!(!is_valid_cookie_name(name), "{:?}", name);
+  }
+
+  for value in ["", "b", "\"\"", "\"abc\"", "a!#$%&'()*+-./:<=>?@[]^_`{|}~"].iter() {
+    assert!(is_valid_cookie_value(value), "{:?}", value);
+  }
+
+  for value in [
+    "a b", "a,b", "a;b", "a\\b", "\"", "\"a", "a\"", "\"a\"b\"", "é", "\x7F",
+  ]
+  .iter()
+  {
+    assert!(!is_valid_cookie_value(value), "{:?}", value);
+  }
+}
+
+#[test]
+fn set_cookie() {
+  assert_eq!(SetCookie::new("a", "b").unwrap().to_string(), "a=b");
+
+  let cookie = SetCookie::new("__Host-id", "\"a1\"")
+    .unwrap()
+    .expires(UNIX_EPOCH + Duration::from_secs(784111777))
+    .max_age(3600)
+    .domain("example.com")
+    .unwrap()
+    .path("/docs")
+    .unwrap()
+    .secure(true)
+    .http_only(true)
+    .same_site(SameSite::Lax)
+    .partitioned(true);
+
+  assert_eq!(cookie.name(), "__Host-id");
+  assert_eq!(cookie.value(), "\"a1\"");
+  assert_eq!(
+    cookie.to_string(),
+    "__Host-id=\"a1\"; Expires=Sun, 06 Nov 1994 08:49:37 GMT; Max-Age=3600; \
+     Domain=example.com; Path=/docs; Secure; HttpOnly; SameSite=Lax; Partitioned"
+  );
+
+  let cookie = SetCookie::new("a", "")
+    .unwrap()
+    .max_age(0)
+    .secure(true)
+    .secure(false)
+    .same_site(SameSite::None);
+
+  assert_eq!(cookie.to_string(), "a=; Max-Age=0; SameSite=None");
+
+  let dates = [
+    (0, "Thu, 01 Jan 1970 00:00:00 GMT"),
+    (951782400, "Tue, 29 Feb 2000 00:00:00 GMT"),
+    (253402300799, "Fri, 31 Dec 9999 23:59:59 GMT"),
+  ];
+
+  for (seconds, expected) in dates.iter() {
+    let cookie = SetCookie::new("a", "b")
+      .unwrap()
+      .expires(UNIX_EPOCH + Duration::from_secs(*seconds));
+
+    assert_eq!(cookie.to_string(), format!("a=b; Expires={}", expected));
+  }
+
+  let cookie = SetCookie::new("a", "b")
+    .unwrap()
+    .expires(UNIX_EPOCH - Duration::from_secs(1));
+
+  assert_eq!(
+    cookie.to_string(),
+    "a=b; Expires=Wed, 31 Dec 1969 23:59:59 GMT"
+  );
+}
+
+#[test]
+fn set_cookie_errors() {
+  assert_eq!(SetCookie::new("", "b"), Err(CookieError::InvalidName));
+  assert_eq!(SetCookie::new("a b", "b"), Err(CookieError::InvalidName));
+  assert_eq!(SetCookie::new("a", "b;c"), Err(CookieError::InvalidValue));
+  assert_eq!(SetCookie::new("a", "ü"), Err(CookieError::InvalidValue));
+
+  let cookie = SetCookie::new("a", "b").unwrap();
+
+  assert_eq!(
+    cookie.clone().path("/; Domain=evil.com"),
+    Err(CookieError::InvalidAttributeValue)
+  );
+  assert_eq!(
+    cookie.domain("example.com\r\n"),
+    Err(CookieError::InvalidAttributeValue)
+  );
+  assert_eq!(
+    CookieError::InvalidValue.to_string(),
+    "invalid cookie value"
+  );
+}
@@ -1,4 +1,10 @@
-use draco_utilities::globals::decode_uri;
+use draco_utilities::globals::{
+  decode_uri, decode_uri_component, decode_uri_component_cow, decode_uri_component_lossy,
+  decode_uri_component_utf16, decode_uri_cow, decode_uri_inner, decode_uri_lossy, decode_uri_to,
+  decode_uri_to_slice, decoded_uri_len, percent_decode, AsciiSet, DecodeOutput, UriErrorKind,
+};
+
+use std::borrow::Cow;
 
 #[test]
 fn decode_uri_string() {
@@ -21,3 +27,286 @@ fn decode_invalid_uri_string() {
 
   assert!(decode_uri("%E0%A4%A".as_bytes(), &mut decoded).is_err());
 }
+
+#[test]
+fn decode_three_and_four_octets() {
+  let mut decoded = Vec::new();
+
+  decode_uri_component(b"%E2%82%AC%F0%9F%98%80", &mut decoded).unwrap();
+  assert_eq!(std::str::from_utf8(&decoded).unwrap(), "\u{20AC}\u{1F600}");
+}
+
+#[test]
+fn decode_reserved() {
+  let mut decoded = Vec::new();
+
+  decode_uri(b"%23%41%2f%C3%A9", &mut decoded).unwrap();
+  assert_eq!(std::str::from_utf8(&decoded).unwrap(), "%23A%2f\u{e9}");
+}
+
+#[test]
+fn decode_every_code_point() {
+  let mut encoded = Vec::new();
+  let mut expected = String::new();
+
+  for ch in (0..=0x10FFFF).filter_map(std::char::from_u32) {
+    for byte in ch.encode_utf8(&mut [0; 4]).bytes() {
+      encoded.extend_from_slice(format!("%{:02X}", byte).as_bytes());
+    }
+
+    expected.push(ch);
+  }
+
+  let mut decoded = Vec::new();
+  let mut units = Vec::new();
+
+  decode_uri_component(&encoded, &mut decoded).unwrap();
+  decode_uri_component_utf16(&encoded, &mut units).unwrap();
+
+  assert!(std::str::from_utf8(&decoded).unwrap() == expected);
+  assert!(units == expected.encode_utf16().collect::<Vec<_>>());
+}
+
+#[test]
+fn decode_invalid_sequences() {
+  let cases: &[&[u8]] = &[
+    b"%C0%80",
+    b"%E0%80%80",
+    b"%F0%80%80%80",
+    b"%ED%A0%80",
+    b"%F4%90%80%80",
+    b"%80",
+    b"%C2",
+    b"%C2%41",
+    b"%C2%C2",
+    b"%C2C2",
+    b"%ZZ",
+    b"%4",
+    b"%F8%80%80%80%80",
+  ];
+
+  for bytes in cases {
+    let mut decoded = Vec::new();
+
+    assert!(
+      decode_uri_component(bytes, &mut decoded).is_err(),
+      "{:?}",
+      std::str::from_utf8(bytes)
+    );
+  }
+}
+
+#[test]
+fn decode_output_modes() {
+  let bytes = b"\xED\xA0\x80%41\xC3\xA9";
+  let mut utf8 = Vec::new();
+  let mut wtf8 = Vec::new();
+  let mut utf16 = Vec::new();
+
+  assert_eq!(
+    decode_uri_to(bytes, &AsciiSet::EMPTY, DecodeOutput::Utf8(&mut utf8)).map_err(|e| e.kind()),
+    Err(UriErrorKind::Surrogate)
+  );
+
+  decode_uri_to(bytes, &AsciiSet::EMPTY, DecodeOutput::Wtf8(&mut wtf8)).unwrap();
+  decode_uri_to(bytes, &AsciiSet::EMPTY, DecodeOutput::Utf16(&mut utf16)).unwrap();
+
+  assert_eq!(wtf8, b"\xED\xA0\x80A\xC3\xA9");
+  assert_eq!(utf16, [0xD800, 0x41, 0xE9]);
+}
+
+#[test]
+fn decode_wtf8_surrogate_pair() {
+  let mut wtf8 = Vec::new();
+
+  assert!(decode_uri_to(
+    b"\xED\xA0\xBD\xED\xB8\x80",
+    &AsciiSet::EMPTY,
+    DecodeOutput::Wtf8(&mut wtf8)
+  )
+  .is_err());
+}
+
+#[test]
+fn decode_error_location() {
+  let cases: &[(&[u8], UriErrorKind, usize, &[u8])] = &[
+    (b"abc%", UriErrorKind::Truncated, 3, b"%"),
+    (b"a%4", UriErrorKind::Truncated, 1, b"%4"),
+    (b"a%ZZb", UriErrorKind::InvalidHexDigit, 1, b"%ZZ"),
+    (b"%80", UriErrorKind::InvalidLeadByte, 0, b"%80"),
+    (b"x%C2%41", UriErrorKind::InvalidContinuation, 1, b"%C2%41"),
+    (b"%E2%82", UriErrorKind::Truncated, 0, b"%E2%82"),
+    (b"%C0%AF", UriErrorKind::Overlong, 0, b"%C0%AF"),
+    (b"%ED%A0%80", UriErrorKind::Surrogate, 0, b"%ED%A0%80"),
+    (
+      b"%F4%90%80%80",
+      UriErrorKind::OutOfRange,
+      0,
+      b"%F4%90%80%80",
+    ),
+    (b"ab\xC3", UriErrorKind::Truncated, 2, b"\xC3"),
+    (b"\xFF", UriErrorKind::InvalidLeadByte, 0, b"\xFF"),
+  ];
+
+  for &(bytes, kind, index, sequence) in cases {
+    let mut decoded = Vec::new();
+    let error = decode_uri_component(bytes, &mut decoded).unwrap_err();
+
+    assert_eq!(error.kind(), kind, "{:?}", bytes);
+    assert_eq!(error.index(), index, "{:?}", bytes);
+    assert_eq!(error.sequence(), sequence, "{:?}", bytes);
+  }
+}
+
+#[test]
+fn decode_error_display() {
+  let mut decoded = Vec::new();
+  let error = decode_uri(b"/path/%C2%41", &mut decoded).unwrap_err();
+
+  assert_eq!(
+    error.to_string(),
+    "invalid UTF-8 continuation byte `%C2%41` at index 6"
+  );
+
+  let error: Box<dyn std::error::Error> = Box::new(error);
+
+  assert!(error.source().is_none());
+}
+
+#[test]
+fn decode_long_strings() {
+  // Moves an escape or a literal multi-byte character through strings longer than a SIMD stride
+  for length in [15, 16, 31, 32, 33, 64, 100] {
+    for position in 0..length {
+      for (special, expected) in [("%41", "A"), ("%C3%A9", "é"), ("é", "é"), ("%23", "%23")] {
+        let mut string = "a".repeat(length);
+
+        string.replace_range(position..position + 1, special);
+
+        let expected = string.replace(special, expected);
+        let mut decoded = Vec::new();
+
+        decode_uri(string.as_bytes(), &mut decoded).unwrap();
+        assert_eq!(decoded, expected.as_bytes(), "{:?}", string);
+      }
+
+      let mut string = "a".repeat(length).into_bytes();
+
+      string[position] = 0xFF;
+
+      let error = decode_uri_component(&string, &mut Vec::new()).unwrap_err();
+
+      assert_eq!(error.kind(), UriErrorKind::InvalidLeadByte);
+      assert_eq!(error.index(), position);
+    }
+  }
+}
+
+#[test]
+fn decode_uri_cow_borrows() {
+  assert!(matches!(
+    decode_uri_cow("a/b%2Fc%23ä"),
+    Ok(Cow::Borrowed(_))
+  ));
+  assert!(matches!(
+    decode_uri_component_cow("abc"),
+    Ok(Cow::Borrowed(_))
+  ));
+
+  assert_eq!(decode_uri_cow("a%2Fb%20c").unwrap(), "a%2Fb c");
+  assert_eq!(decode_uri_component_cow("a%2Fb%E2%82%AC").unwrap(), "a/b€");
+
+  let error = decode_uri_component_cow("abc%E2%82").unwrap_err();
+
+  assert_eq!(error.kind(), UriErrorKind::Truncated);
+  assert_eq!(error.index(), 3);
+}
+
+#[test]
+fn decode_uri_lengths() {
+  let reserved = AsciiSet::from_bytes(b"#/?");
+  let inputs = [
+    "",
+    "abc",
+    "a%20b%2Fc%3F",
+    "%C3%BCn%C3%AF%E2%82%AC%F0%9F%98%80ä",
+    &"a%2F%41".repeat(40),
+  ];
+
+  for input in inputs.iter() {
+    for set in [AsciiSet::EMPTY, reserved].iter() {
+      let mut expected = Vec::new();
+
+      decode_uri_inner(input.as_bytes(), set, &mut expected).unwrap();
+      assert_eq!(decoded_uri_len(input.as_bytes(), set), expected.len());
+
+      let mut buffer = vec![0; expected.len()];
+      let written = decode_uri_to_slice(input.as_bytes(), set, &mut buffer).unwrap();
+
+      assert_eq!(&buffer[..written], &expected[..]);
+    }
+  }
+
+  let mut buffer = [0; 8];
+  let error = decode_uri_to_slice(b"ab%E2%82", &AsciiSet::EMPTY, &mut buffer).unwrap_err();
+
+  assert_eq!(error.kind(), UriErrorKind::Truncated);
+}
+
+#[test]
+#[should_panic]
+fn decode_uri_to_short_slice() {
+  let _ = decode_uri_to_slice(b"%41%42", &AsciiSet::EMPTY, &mut [0; 1]);
+}
+
+#[test]
+fn decode_uri_lossy_strings() {
+  let tests: &[(&[u8], &str, bool)] = &[
+    (b"a%20b", "a b", false),
+    (b"%zz%4%", "%zz%4%", false),
+    (b"%E2%82%AC%2F", "€/", false),
+    (b"%E2%82", "\u{FFFD}", true),
+    (b"%C3%28x", "\u{FFFD}(x", true),
+    (b"%F0%90%80%41", "\u{FFFD}A", true),
+    (b"%ED%A0%80", "\u{FFFD}\u{FFFD}\u{FFFD}", true),
+    (b"a\xFFb%FF", "a\u{FFFD}b\u{FFFD}", true),
+    (b"%EF%BB%BFa", "\u{FEFF}a", false),
+  ];
+
+  for &(input, expected, replaced) in tests {
+    let mut decoded = b"x".to_vec();
+
+    assert_eq!(
+      decode_uri_component_lossy(input, &mut decoded),
+      replaced,
+      "{:?}",
+      input
+    );
+    assert_eq!(decoded, [b"x", expected.as_bytes()].concat(), "{:?}", input);
+  }
+
+  let mut decoded = Vec::new();
+
+  assert!(!decode_uri_lossy(b"%2F%3f%41%", &mut decoded));
+  assert_eq!(decoded, b"%2F%3fA%");
+}
+
+#[test]
+fn percent_decode_bytes() {
+  let tests: &[(&[u8], &[u8])] = &[
+    (b"a%20b", b"a b"),
+    (b"%zz%4%", b"%zz%4%"),
+    (b"%2F%3f%41%", b"/?A%"),
+    (b"%E2%82%FF", b"\xE2\x82\xFF"),
+    (b"a\xFFb", b"a\xFFb"),
+    (&[b'%'; 40], &[b'%'; 40]),
+  ];
+
+  for &(input, expected) in tests {
+    let mut decoded = b"x".to_vec();
+
+    percent_decode(input, &mut decoded);
+
+    assert_eq!(decoded, [b"x", expected].concat(), "{:?}", input);
+  }
+}
@@ -1,4 +1,10 @@
-use draco_utilities::globals::encode_uri;
+use draco_utilities::globals::{
+  encode_inner, encode_inner_to_slice, encode_str, encode_uri, encode_uri_component,
+  encode_uri_component_cow, encode_uri_component_utf16, encode_uri_cow, encode_uri_utf16,
+  encoded_uri_len, AsciiSet, UriErrorKind, COMPONENT_SET,
+};
+
+use std::borrow::Cow;
 
 #[test]
 fn encode_uri_string() {
@@ -15,3 +21,180 @@ fn encode_uri_string() {
 
   assert_eq!(result, expected);
 }
+
+#[test]
+fn encode_uri_utf16_string() {
+  let units: Vec<u16> = "a\u{e9}\u{1F600} #".encode_utf16().collect();
+  let mut uri = Vec::new();
+  let mut component = Vec::new();
+
+  encode_uri_utf16(&units, &mut uri).unwrap();
+  encode_uri_component_utf16(&units, &mut component).unwrap();
+
+  assert_eq!(
+    std::str::from_utf8(&uri).unwrap(),
+    "a%C3%A9%F0%9F%98%80%20#"
+  );
+  assert_eq!(
+    std::str::from_utf8(&component).unwrap(),
+    "a%C3%A9%F0%9F%98%80%20%23"
+  );
+}
+
+#[test]
+fn encode_uri_utf16_matches_utf8() {
+  let string =
+    "https://developer.mozilla.org/ru/docs/JavaScript_\u{448}\u{435}\u{43B}\u{43B}\u{44B}";
+  let units: Vec<u16> = string.encode_utf16().collect();
+  let mut from_utf8 = Vec::new();
+  let mut from_utf16 = Vec::new();
+
+  encode_uri(string.as_bytes(), &mut from_utf8).unwrap();
+  encode_uri_utf16(&units, &mut from_utf16).unwrap();
+
+  assert_eq!(from_utf8, from_utf16);
+}
+
+#[test]
+fn encode_uri_utf16_lone_surrogates() {
+  let cases: &[&[u16]] = &[
+    &[0xD800],
+    &[0x61, 0xDC00],
+    &[0xD800, 0x61],
+    &[0xDC00, 0xD800],
+  ];
+
+  for units in cases {
+    let mut encoded = Vec::new();
+
+    assert_eq!(
+      encode_uri_component_utf16(units, &mut encoded).map_err(|e| e.kind()),
+      Err(UriErrorKind::Surrogate),
+      "{:?}",
+      units
+    );
+  }
+}
+
+#[test]
+fn encode_uri_multi_byte() {
+  let mut encoded = Vec::new();
+
+  encode_uri("€😀".as_bytes(), &mut encoded).unwrap();
+  assert_eq!(encoded, b"%E2%82%AC%F0%9F%98%80");
+}
+
+#[test]
+fn encode_uri_error_location() {
+  let mut encoded = Vec::new();
+  let error = encode_uri(b"ab\xE2\x82", &mut encoded).unwrap_err();
+
+  assert_eq!(error.kind(), UriErrorKind::Truncated);
+  assert_eq!(error.index(), 2);
+  assert_eq!(error.sequence(), b"\xE2\x82");
+
+  let error = encode_uri_utf16(&[0x61, 0x62, 0xDC00], &mut encoded).unwrap_err();
+
+  assert_eq!(error.kind(), UriErrorKind::Surrogate);
+  assert_eq!(error.index(), 2);
+  assert_eq!(error.sequence(), b"\xED\xB0\x80");
+  assert_eq!(
+    error.to_string(),
+    "surrogate code point `\\xed\\xb0\\x80` at index 2"
+  );
+}
+
+#[test]
+fn encode_uri_component_long_strings() {
+  const UNRESERVED: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!'()*-._~";
+
+  // Moves an encoded character through strings longer than a SIMD stride
+  for length in [15, 16, 31, 32, 33, 64, 100] {
+    for position in 0..length {
+      for special in ["/", " ", "é", "😀"] {
+        let mut string = "a".repeat(length);
+
+        string.replace_range(position..position + 1, special);
+
+        let expected = string
+          .bytes()
+          .map(|byte| {
+            if UNRESERVED.contains(&byte) {
+              (byte as char).to_string()
+            } else {
+              format!("%{:02X}", byte)
+            }
+          })
+          .collect::<String>();
+        let mut encoded = Vec::new();
+
+        encode_uri_component(string.as_bytes(), &mut encoded).unwrap();
+        assert_eq!(encoded, expected.as_bytes(), "{:?}", string);
+      }
+    }
+  }
+}
+
+#[test]
+fn encode_uri_cow_borrows() {
+  assert!(matches!(
+    encode_uri_cow("https://a.b/c?d=e#f"),
+    Cow::Borrowed(_)
+  ));
+  assert!(matches!(
+    encode_uri_component_cow("abc-._~"),
+    Cow::Borrowed(_)
+  ));
+  assert!(matches!(encode_uri_component_cow(""), Cow::Borrowed(_)));
+
+  assert_eq!(encode_uri_cow("https://a.b/ä b"), "https://a.b/%C3%A4%20b");
+  assert_eq!(encode_uri_component_cow("a/b?c"), "a%2Fb%3Fc");
+  assert_eq!(encode_uri_component_cow("€"), "%E2%82%AC");
+}
+
+#[test]
+fn encode_str_matches_encode_inner() {
+  let set = AsciiSet::from_bytes(b" #%?");
+  let strings = ["", "a b", "/a#b?c%d", "ünï cödé 😀", &"x y".repeat(100)];
+
+  for string in strings.iter() {
+    let mut encoded = String::new();
+    let mut expected = String::new();
+
+    encode_str(string, &set, &mut encoded);
+    encode_inner(string.as_bytes(), &set, &mut expected).unwrap();
+
+    assert_eq!(encoded, expected, "{:?}", string);
+  }
+}
+
+#[test]
+fn encode_uri_lengths() {
+  let inputs = ["", "abc", "a b/c?d", "ünïcödé €😀", &"a%b".repeat(40)];
+
+  for input in inputs.iter() {
+    for set in [AsciiSet::EMPTY, COMPONENT_SET, AsciiSet::EMPTY.complement()].iter() {
+      let mut expected = Vec::new();
+
+      encode_inner(input.as_bytes(), set, &mut expected).unwrap();
+      assert_eq!(encoded_uri_len(input.as_bytes(), set), expected.len());
+
+      let mut buffer = vec![0; expected.len() + 1];
+      let written = encode_inner_to_slice(input.as_bytes(), set, &mut buffer).unwrap();
+
+      assert_eq!(&buffer[..written], &expected[..]);
+    }
+  }
+
+  let mut buffer = [0; 8];
+  let error = encode_inner_to_slice(b"ab\xE2\x82", &COMPONENT_SET, &mut buffer).unwrap_err();
+
+  assert_eq!(error.kind(), UriErrorKind::Truncated);
+}
+
+#[test]
+#[should_panic]
+fn encode_uri_to_short_slice() {
+  let _ = encode_inner_to_slice("ä".as_bytes(), &COMPONENT_SET, &mut [0; 5]);
+}
@@ -1,4 +1,6 @@
-use draco_utilities::globals::escape;
+use draco_utilities::globals::{escape, escape_cow, escape_utf16};
+
+use std::borrow::Cow;
 
 #[test]
 fn escape_reserved() {
@@ -32,7 +34,7 @@ fn escape_bytes() {
 }
 
 #[test]
-fn escape_utf16() {
+fn escape_two_octets() {
   let expected = "%u0107";
   let mut bytes = Vec::new();
 
@@ -40,3 +42,70 @@ fn escape_utf16() {
   let result = std::str::from_utf8(&bytes).unwrap();
   assert_eq!(result, expected);
 }
+
+#[test]
+fn escape_multi_byte() {
+  let expected = "%u20AC%uD83D%uDE00%FF%u0100%7F%80%uD800x";
+  let mut bytes = Vec::new();
+
+  // A lone surrogate in WTF-8
+  escape(
+    b"\xE2\x82\xAC\xF0\x9F\x98\x80\xC3\xBF\xC4\x80\x7F\xC2\x80\xED\xA0\x80x",
+    &mut bytes,
+  );
+  assert_eq!(std::str::from_utf8(&bytes).unwrap(), expected);
+
+  bytes.clear();
+  escape_utf16(
+    &[
+      0x20AC, 0xD83D, 0xDE00, 0xFF, 0x100, 0x7F, 0x80, 0xD800, 0x78,
+    ],
+    &mut bytes,
+  );
+  assert_eq!(std::str::from_utf8(&bytes).unwrap(), expected);
+}
+
+#[test]
+fn escape_invalid_utf8() {
+  let mut bytes = Vec::new();
+
+  escape(b"a\xE2\x82b\xF0\x9F\x98", &mut bytes);
+  assert_eq!(bytes, b"a%E2%82b%F0%9F%98");
+}
+
+#[test]
+fn escape_long_strings() {
+  // Moves an escaped character through strings longer than a SIMD stride
+  for length in [15, 16, 31, 32, 33, 64, 100] {
+    for position in 0..length {
+      for (special, escaped) in [
+        (" ", "%20"),
+        ("~", "%7E"),
+        ("ä", "%E4"),
+        ("\u{0107}", "%u0107"),
+        ("€", "%u20AC"),
+        ("😀", "%uD83D%uDE00"),
+      ] {
+        let mut string = "a".repeat(length);
+
+        string.replace_range(position..position + 1, special);
+
+        let mut bytes = Vec::new();
+
+        escape(string.as_bytes(), &mut bytes);
+        assert_eq!(
+          bytes,
+          string.replace(special, escaped).as_bytes(),
+          "{:?}",
+          string
+        );
+      }
+    }
+  }
+}
+
+#[test]
+fn escape_cow_borrows() {
+  assert!(matches!(escape_cow("abc123@*_+-./"), Cow::Borrowed(_)));
+  assert_eq!(escape_cow("abc 123ä"), "abc%20123%E4");
+}
@@ -0,0 +1,52 @@
+use draco_utilities::hex::{decode_hex, encode_hex, DecodeErrorKind};
+use draco_utilities::sink::IoSink;
+
+#[test]
+fn encode() {
+  let mut encoded = String::new();
+
+  encode_hex(b"\x00\x7F\xAB\xFF", &mut encoded);
+  assert_eq!(encoded, "007fabff");
+}
+
+#[test]
+fn decode() {
+  let mut decoded = Vec::new();
+
+  decode_hex(b"007fAbfF", &mut decoded).unwrap();
+  assert_eq!(decoded, b"\x00\x7F\xAB\xFF");
+
+  // Longer than the buffer bytes are decoded to
+  let bytes = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+  let mut encoded = Vec::new();
+  let mut sink = IoSink::new(Vec::new());
+
+  encode_hex(&bytes, &mut encoded);
+  decode_hex(&encoded, &mut sink).unwrap();
+  assert_eq!(sink.finish().unwrap(), bytes);
+}
+
+#[test]
+fn decode_invalid() {
+  let tests: &[(&[u8], DecodeErrorKind, usize, u8)] = &[
+    (b"abc", DecodeErrorKind::InvalidLength, 2, b'c'),
+    (b"0g", DecodeErrorKind::InvalidCharacter, 1, b'g'),
+    (b"00g0", DecodeErrorKind::InvalidCharacter, 2, b'g'),
+    (b"00 g", DecodeErrorKind::InvalidCharacter, 2, b' '),
+  ];
+
+  for &(input, kind, index, byte) in tests {
+    let mut decoded = b"x".to_vec();
+    let error = decode_hex(input, &mut decoded).unwrap_err();
+
+    assert_eq!(decoded, b"x", "{:?}", input);
+    assert_eq!(error.kind(), kind, "{:?}", input);
+    assert_eq!(error.index(), index, "{:?}", input);
+    assert_eq!(error.byte(), byte, "{:?}", input);
+  }
+
+  assert_eq!(
+    decode_hex(b"0g", &mut Vec::new()).unwrap_err().to_string(),
+    "invalid hexadecimal digit `g` at index 1"
+  );
+}
@@ -0,0 +1,240 @@
+mod common;
+
+use draco_utilities::idna::{
+  decode_punycode, domain_to_ascii, domain_to_unicode, encode_punycode, Config, IdnaError,
+  PunycodeError,
+};
+
+/// Replaces the `\uXXXX` escapes of the UTS #46 test file.
+fn unescape(input: &str) -> String {
+  let mut unescaped = String::with_capacity(input.len());
+  let mut rest = input;
+
+  while let Some(idx) = rest.find("\\u") {
+    unescaped.push_str(&rest[..idx]);
+
+    let code_point = u32::from_str_radix(&rest[idx + 2..idx + 6], 16).unwrap();
+
+    unescaped.push(char::from_u32(code_point).unwrap_or('\u{FFFD}'));
+    rest = &rest[idx + 6..];
+  }
+
+  unescaped.push_str(rest);
+  unescaped
+}
+
+/// Returns the error codes of a status.
+fn errors(status: &str) -> Vec<&str> {
+  status
+    .trim_matches(|ch| ch == '[' || ch == ']')
+    .split(", ")
+    .filter(|code| !code.is_empty())
+    .collect()
+}
+
+fn check(
+  failures: &mut Vec<String>,
+  line: usize,
+  operation: &str,
+  actual: Result<String, IdnaError>,
+  (expected, status): (&str, &str),
+  ignored: &[&str],
+) {
+  let errors = errors(status);
+  let passed = if errors.is_empty() {
+    actual.as_deref() == Ok(expected)
+  } else if errors.iter().any(|code| ignored.contains(code)) {
+    // Errors of checks that differ between implementations are allowed either way
+    true
+  } else {
+    actual.is_err()
+  };
+
+  if !passed {
+    failures.push(format!(
+      "line {}: {} gave {:?}, expected {:?} {}",
+      line, operation, actual, expected, status
+    ));
+  }
+}
+
+#[test]
+fn uts46_conformance() {
+  let contents = common::fixture("IdnaTestV2.txt");
+  let mut failures = Vec::new();
+  let mut count = 0;
+
+  for (idx, line) in contents.lines().enumerate() {
+    let line = line.split('#').next().unwrap();
+
+    if line.trim().is_empty() {
+      continue;
+    }
+
+    let columns = line.split(';').map(str::trim).collect::<Vec<_>>();
+    let source = unescape(columns[0]);
+    let to_unicode = match columns[1] {
+      "" => source.clone(),
+      column => unescape(column),
+    };
+    let to_unicode_status = columns[2];
+    let to_ascii_n = match columns[3] {
+      "" => to_unicode.clone(),
+      column => unescape(column),
+    };
+    let to_ascii_n_status = match columns[4] {
+      "" => to_unicode_status,
+      column => column,
+    };
+    let to_ascii_t = match columns[5] {
+      "" => to_ascii_n.clone(),
+      column => unescape(column),
+    };
+    let to_ascii_t_status = match columns[6] {
+      "" => to_ascii_n_status,
+      column => column,
+    };
+
+    let config = Config::default();
+    let transitional = Config {
+      transitional_processing: true,
+      ..config
+    };
+
+    // Empty labels are only errors for ToASCII, which checks the lengths
+    check(
+      &mut failures,
+      idx + 1,
+      "toUnicode",
+      domain_to_unicode(&source, &config),
+      (&to_unicode, to_unicode_status),
+      &["X4_2", "A4_1", "A4_2"],
+    );
+    check(
+      &mut failures,
+      idx + 1,
+      "toAsciiN",
+      domain_to_ascii(&source, &config),
+      (&to_ascii_n, to_ascii_n_status),
+      &[],
+    );
+    check(
+      &mut failures,
+      idx + 1,
+      "toAsciiT",
+      domain_to_ascii(&source, &transitional),
+      (&to_ascii_t, to_ascii_t_status),
+      &[],
+    );
+    count += 1;
+  }
+
+  assert!(count > 6000);
+  assert!(failures.is_empty(), "{:#?}", failures);
+}
+
+#[test]
+fn punycode_fixtures() {
+  let tests = common::json_fixture("punycode_tests.json");
+
+  for test in tests.as_array().unwrap() {
+    let decoded = test["decoded"].as_str().unwrap();
+    let encoded = test["encoded"].as_str().unwrap();
+    let mut output = String::new();
+
+    encode_punycode(decoded, &mut output).unwrap();
+    assert_eq!(output, encoded, "{}", test["description"]);
+
+    output.clear();
+    decode_punycode(encoded, &mut output).unwrap();
+    assert_eq!(output, decoded, "{}", test["description"]);
+  }
+
+  let tests = common::json_fixture("bad_punycode_tests.json");
+
+  for test in tests.as_array().unwrap() {
+    let mut output = String::from("a");
+
+    if let Some(decoded) = test["decoded"].as_str() {
+      assert_eq!(
+        encode_punycode(decoded, &mut output),
+        Err(PunycodeError::Overflow)
+      );
+      assert_eq!(output, "a");
+    }
+
+    if let Some(encoded) = test["encoded"].as_str() {
+      assert!(decode_punycode(encoded, &mut output).is_err());
+      assert_eq!(output, "a");
+    }
+  }
+}
+
+#[test]
+fn punycode_errors() {
+  let tests = [
+    ("ü-tda", PunycodeError::InvalidCharacter),
+    ("a-b!", PunycodeError::InvalidCharacter),
+    ("tz", PunycodeError::Truncated),
+    ("999999999999", PunycodeError::Overflow),
+    ("ib9b", PunycodeError::InvalidCodePoint),
+  ];
+
+  for (encoded, error) in tests.iter() {
+    let mut decoded = String::new();
+
+    assert_eq!(
+      decode_punycode(encoded, &mut decoded),
+      Err(*error),
+      "{:?}",
+      encoded
+    );
+    assert!(decoded.is_empty());
+  }
+}
+
+#[test]
+fn url_config() {
+  let tests = [
+    ("EXAMPLE.com", Ok("example.com")),
+    ("Bücher.example", Ok("xn--bcher-kva.example")),
+    ("faß.de", Ok("xn--fa-hia.de")),
+    ("a_b.-c-.ab--d", Ok("a_b.-c-.ab--d")),
+    ("xn--bcher-kva.example", Ok("xn--bcher-kva.example")),
+    ("example.com.", Ok("example.com.")),
+    ("a..b", Ok("a..b")),
+    ("\u{3002}a\u{FF0E}b", Ok(".a.b")),
+    ("xn--", Err(IdnaError::InvalidPunycode)),
+    ("xn--abc-", Err(IdnaError::InvalidPunycode)),
+    ("xn--ab-.com", Err(IdnaError::InvalidPunycode)),
+    ("xn--a.com", Err(IdnaError::DisallowedCharacter)),
+    ("a\u{FFFD}b", Err(IdnaError::DisallowedCharacter)),
+    ("\u{0301}a", Err(IdnaError::LeadingCombiningMark)),
+    ("a\u{200D}b", Err(IdnaError::InvalidJoiner)),
+    ("1.\u{05D0}", Err(IdnaError::InvalidBidi)),
+  ];
+
+  for (domain, expected) in tests.iter() {
+    assert_eq!(
+      domain_to_ascii(domain, &Config::URL)
+        .as_deref()
+        .map_err(|error| *error),
+      *expected,
+      "{:?}",
+      domain
+    );
+  }
+
+  assert_eq!(
+    domain_to_unicode("xn--bcher-kva.EXAMPLE", &Config::URL).as_deref(),
+    Ok("bücher.example")
+  );
+  assert_eq!(
+    domain_to_ascii("a.b..c", &Config::default()),
+    Err(IdnaError::InvalidLength)
+  );
+  assert_eq!(
+    domain_to_ascii(&"a".repeat(64), &Config::default()),
+    Err(IdnaError::InvalidLength)
+  );
+}
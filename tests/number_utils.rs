@@ -1,3 +1,5 @@
+#![allow(clippy::approx_constant)]
+
 use draco_utilities::number::utils;
 
 mod byte_conversion {
@@ -0,0 +1,207 @@
+use draco_utilities::qs::{parse, stringify, ArrayFormat, ParseOptions, StringifyOptions, Value};
+use serde_json::json;
+
+/// Converts a value to JSON, so that expected values can be written with `json!`.
+fn to_json(value: &Value) -> serde_json::Value {
+  match value {
+    Value::String(string) => json!(string),
+    Value::Array(values) => values.iter().map(to_json).collect(),
+    Value::Object(entries) => serde_json::Value::Object(
+      entries
+        .iter()
+        .map(|(key, value)| (key.clone(), to_json(value)))
+        .collect(),
+    ),
+  }
+}
+
+fn from_json(value: &serde_json::Value) -> Value {
+  match value {
+    serde_json::Value::Array(values) => Value::Array(values.iter().map(from_json).collect()),
+    serde_json::Value::Object(entries) => Value::Object(
+      entries
+        .iter()
+        .map(|(key, value)| (key.clone(), from_json(value)))
+        .collect(),
+    ),
+    value => Value::String(value.as_str().unwrap().to_owned()),
+  }
+}
+
+#[test]
+fn parse_nested() {
+  let tests = [
+    (
+      "user[name]=a&user[tags][]=x&user[tags][]=y",
+      json!({ "user": { "name": "a", "tags": ["x", "y"] } }),
+    ),
+    ("?a[b][c]=d", json!({ "a": { "b": { "c": "d" } } })),
+    ("a=b&a=c", json!({ "a": ["b", "c"] })),
+    ("a[]=b&a[]=c", json!({ "a": ["b", "c"] })),
+    ("a[1]=c&a[0]=b", json!({ "a": ["b", "c"] })),
+    ("a[1]=b&a[15]=c", json!({ "a": ["b", "c"] })),
+    ("a[]=&a[]=b", json!({ "a": ["", "b"] })),
+    ("a[][b]=c", json!({ "a": [{ "b": "c" }] })),
+    (
+      "a[0][b]=c&a[0][d]=e",
+      json!({ "a": [{ "b": "c", "d": "e" }] }),
+    ),
+    ("a[0]=b&a[b]=c", json!({ "a": { "0": "b", "b": "c" } })),
+    ("a[b]=c&a=d", json!({ "a": { "b": "c", "d": "" } })),
+    ("a[01]=b", json!({ "a": { "01": "b" } })),
+    ("a[b=c]=d", json!({ "a": { "b=c": "d" } })),
+    ("a=b&&=c&d", json!({ "a": "b", "d": "" })),
+    (
+      "a[b+c]=d+e&f=%E4%BD%A0",
+      json!({ "a": { "b c": "d e" }, "f": "你" }),
+    ),
+    ("a=%ZZ+b&c=%C3", json!({ "a": "%ZZ b", "c": "%C3" })),
+  ];
+
+  for (string, expected) in tests.iter() {
+    assert_eq!(
+      to_json(&parse(string, &ParseOptions::default())),
+      *expected,
+      "{:?}",
+      string
+    );
+  }
+}
+
+#[test]
+fn parse_limits() {
+  let options = ParseOptions::default();
+
+  assert_eq!(
+    to_json(&parse("a[b][c][d][e][f][g][h][i]=j", &options)),
+    json!({ "a": { "b": { "c": { "d": { "e": { "f": { "[g][h][i]": "j" } } } } } } })
+  );
+  assert_eq!(
+    to_json(&parse(
+      "a[b][c]=d",
+      &ParseOptions {
+        depth: 1,
+        ..options
+      }
+    )),
+    json!({ "a": { "b": { "[c]": "d" } } })
+  );
+  assert_eq!(
+    to_json(&parse(
+      "a[b]=c",
+      &ParseOptions {
+        depth: 0,
+        ..options
+      }
+    )),
+    json!({ "a[b]": "c" })
+  );
+  assert_eq!(
+    to_json(&parse(
+      "a=b&c=d&e=f",
+      &ParseOptions {
+        parameter_limit: 2,
+        ..options
+      }
+    )),
+    json!({ "a": "b", "c": "d" })
+  );
+  assert_eq!(
+    to_json(&parse("a[20]=b&b[21]=c", &options)),
+    json!({ "a": ["b"], "b": { "21": "c" } })
+  );
+  assert_eq!(
+    to_json(&parse(
+      "a[1]=b",
+      &ParseOptions {
+        array_limit: 0,
+        ..options
+      }
+    )),
+    json!({ "a": { "1": "b" } })
+  );
+  assert_eq!(
+    to_json(&parse("a[99999999999999999999999]=b", &options)),
+    json!({ "a": { "99999999999999999999999": "b" } })
+  );
+
+  // Appended values can't get past the limit either
+  let string = "a[]=x&".repeat(22);
+
+  assert_eq!(
+    parse(&string, &options)
+      .get("a")
+      .and_then(Value::as_object)
+      .map(<[_]>::len),
+    Some(22)
+  );
+  assert_eq!(
+    parse(
+      &string,
+      &ParseOptions {
+        array_limit: 21,
+        ..options
+      }
+    )
+    .get("a")
+    .and_then(Value::as_array)
+    .map(<[_]>::len),
+    Some(22)
+  );
+}
+
+fn stringified(value: serde_json::Value, options: StringifyOptions) -> String {
+  let mut output = String::new();
+
+  stringify(&from_json(&value), &options, &mut output);
+  output
+}
+
+#[test]
+fn stringify_formats() {
+  let value = json!({ "a": ["b", "c"] });
+  let tests = [
+    (ArrayFormat::Indices, "a%5B0%5D=b&a%5B1%5D=c"),
+    (ArrayFormat::Brackets, "a%5B%5D=b&a%5B%5D=c"),
+    (ArrayFormat::Repeat, "a=b&a=c"),
+  ];
+
+  for (array_format, expected) in tests.iter() {
+    let options = StringifyOptions {
+      array_format: *array_format,
+      ..StringifyOptions::default()
+    };
+
+    assert_eq!(stringified(value.clone(), options), *expected);
+  }
+
+  let options = StringifyOptions {
+    array_format: ArrayFormat::Brackets,
+    encode_values_only: true,
+  };
+
+  assert_eq!(
+    stringified(
+      json!({ "user": { "name": "a b", "tags": ["x&y", "é"] } }),
+      options
+    ),
+    "user[name]=a%20b&user[tags][]=x%26y&user[tags][]=%C3%A9"
+  );
+  assert_eq!(
+    stringified(json!({ "a": [], "b": {}, "c": "" }), options),
+    "c="
+  );
+  assert_eq!(stringified(json!(["a", "b"]), options), "0=a&1=b");
+  assert_eq!(stringified(json!("a"), options), "");
+}
+
+#[test]
+fn round_trip() {
+  let value = json!({
+    "user": { "name": "a=b&c", "tags": ["x", "y"], "roles": [{ "id": "1" }, { "id": "2" }] },
+    "q": "50% off",
+  });
+  let string = stringified(value.clone(), StringifyOptions::default());
+
+  assert_eq!(to_json(&parse(&string, &ParseOptions::default())), value);
+}
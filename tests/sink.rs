@@ -0,0 +1,136 @@
+use draco_utilities::base64::{decode_base64, encode_base64};
+use draco_utilities::globals::{decode_uri_component, encode_uri_component, escape, unescape};
+use draco_utilities::number::to_string;
+use draco_utilities::sink::{FmtSink, IoSink, SliceSink};
+
+use std::fmt;
+use std::io;
+
+#[test]
+fn string_sinks() {
+  let mut encoded = String::new();
+
+  encode_uri_component("a b/ü".as_bytes(), &mut encoded).unwrap();
+  assert_eq!(encoded, "a%20b%2F%C3%BC");
+
+  let mut decoded = String::new();
+
+  decode_uri_component(encoded.as_bytes(), &mut decoded).unwrap();
+  assert_eq!(decoded, "a b/ü");
+
+  let mut escaped = String::new();
+
+  escape("ü€".as_bytes(), &mut escaped);
+  assert_eq!(escaped, "%FC%u20AC");
+
+  let mut unescaped = String::new();
+
+  unescape(escaped.as_bytes(), &mut unescaped);
+  assert_eq!(unescaped, "ü€");
+
+  let mut base64 = String::new();
+
+  encode_base64(b"hello", &mut base64);
+  assert_eq!(base64, "aGVsbG8=");
+
+  let mut number = String::new();
+
+  to_string::<16>(255.5, &mut number);
+  assert_eq!(number, "ff.8");
+}
+
+struct Encoded<'a>(&'a str);
+
+impl fmt::Display for Encoded<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut sink = FmtSink::new(f);
+
+    encode_uri_component(self.0.as_bytes(), &mut sink).map_err(|_| fmt::Error)?;
+    sink.finish().map(|_| ())
+  }
+}
+
+#[test]
+fn fmt_sink() {
+  assert_eq!(Encoded("a&b=c").to_string(), "a%26b%3Dc");
+  assert_eq!(format!("[{}]", Encoded("")), "[]");
+}
+
+#[derive(Debug)]
+struct FailingWriter {
+  written: Vec<u8>,
+  limit: usize,
+}
+
+impl io::Write for FailingWriter {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    if self.written.len() + buf.len() > self.limit {
+      return Err(io::Error::other("full"));
+    }
+
+    self.written.extend_from_slice(buf);
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    Ok(())
+  }
+}
+
+#[test]
+fn io_sink() {
+  let mut sink = IoSink::new(Vec::new());
+
+  decode_base64(b"AP8=", &mut sink).unwrap();
+  assert_eq!(sink.finish().unwrap(), [0x00, 0xFF]);
+
+  let mut sink = IoSink::new(FailingWriter {
+    written: Vec::new(),
+    limit: 4,
+  });
+
+  encode_uri_component(b"ab cd ef", &mut sink).unwrap();
+
+  let error = sink.finish().unwrap_err();
+
+  assert_eq!(error.to_string(), "full");
+}
+
+#[test]
+fn slice_sink() {
+  let mut buffer = [0; 16];
+  let mut sink = SliceSink::new(&mut buffer);
+
+  encode_uri_component(b"a b", &mut sink).unwrap();
+  assert_eq!(sink.len(), 5);
+  assert_eq!(sink.finish(), Ok(5));
+  assert_eq!(&buffer[..5], b"a%20b");
+
+  let mut buffer = [0; 4];
+  let mut sink = SliceSink::new(&mut buffer);
+
+  // Nothing is written after the first escape that doesn't fit
+  encode_uri_component(b"ab cd", &mut sink).unwrap();
+  assert_eq!(sink.len(), 2);
+
+  let error = sink.finish().unwrap_err();
+
+  assert_eq!(error.required(), 7);
+  assert_eq!(
+    error.to_string(),
+    "the buffer is too short, 7 bytes are required"
+  );
+  assert_eq!(&buffer[..2], b"ab");
+
+  let mut buffer = [0; 2];
+  let mut sink = SliceSink::new(&mut buffer);
+
+  decode_base64(b"AP8=", &mut sink).unwrap();
+  assert_eq!(sink.finish(), Ok(2));
+
+  let mut sink = SliceSink::new(&mut []);
+
+  assert!(sink.is_empty());
+  to_string::<10>(f64::NAN, &mut sink);
+  assert_eq!(sink.finish().map_err(|error| error.required()), Err(3));
+}
@@ -0,0 +1,131 @@
+use draco_utilities::globals::{
+  decode_uri, decode_uri_component, encode_uri, encode_uri_component, UriDecoder, UriEncoder,
+  UriError,
+};
+
+const DECODE_INPUTS: &[&[u8]] = &[
+  b"https://a.b/%E2%82%AC%F0%9F%98%80%2F?q=%C3%A9#%23",
+  "ä€😀%41%e2%82%ac".as_bytes(),
+  b"abc%E2%82",
+  b"abc%E2%8",
+  b"abc%E2abcdef",
+  b"abc%ZZ",
+  b"abc%C3%28",
+  b"\xF0\x9F\x98",
+  b"%ED%A0%80",
+];
+
+const ENCODE_INPUTS: &[&[u8]] = &[
+  "https://a.b/ä €?q=😀#x".as_bytes(),
+  b"abc\xE2\x82",
+  b"abc\xE2abc",
+  b"abc\xFF",
+];
+
+type Function = fn(&[u8], &mut Vec<u8>) -> Result<(), UriError>;
+
+/// Feeds the input split at `splits`, returning the output along with the first error.
+fn feed<F>(input: &[u8], splits: &[usize], mut feed: F) -> (Vec<u8>, Result<(), UriError>)
+where
+  F: FnMut(Option<&[u8]>, &mut Vec<u8>) -> Result<(), UriError>,
+{
+  let mut output = Vec::new();
+  let mut start = 0;
+
+  for &end in splits.iter().chain(Some(&input.len())) {
+    if let Err(error) = feed(Some(&input[start..end]), &mut output) {
+      return (output, Err(error));
+    }
+
+    start = end;
+  }
+
+  let result = feed(None, &mut output);
+
+  (output, result)
+}
+
+fn check_decoder(new: fn() -> UriDecoder, one_shot: Function) {
+  for &input in DECODE_INPUTS {
+    let mut expected = Vec::new();
+    let expected_result = one_shot(input, &mut expected);
+    let run = |splits: &[usize]| {
+      let mut decoder = new();
+      let (output, result) = feed(input, splits, |chunk, output| match chunk {
+        Some(chunk) => decoder.feed(chunk, output),
+        None => decoder.finish(),
+      });
+
+      // Only the output before the error matches, since the decoder stops at the chunk
+      if result.is_ok() {
+        assert_eq!(output, expected, "{:?} {:?}", input, splits);
+      }
+
+      assert_eq!(result, expected_result, "{:?} {:?}", input, splits);
+    };
+
+    for split in 0..=input.len() {
+      run(&[split]);
+    }
+
+    run(&(1..input.len()).collect::<Vec<_>>());
+  }
+}
+
+fn check_encoder(new: fn() -> UriEncoder, one_shot: Function) {
+  for &input in ENCODE_INPUTS {
+    let mut expected = Vec::new();
+    let expected_result = one_shot(input, &mut expected);
+    let run = |splits: &[usize]| {
+      let mut encoder = new();
+      let (output, result) = feed(input, splits, |chunk, output| match chunk {
+        Some(chunk) => encoder.feed(chunk, output),
+        None => encoder.finish(),
+      });
+
+      if result.is_ok() {
+        assert_eq!(output, expected, "{:?} {:?}", input, splits);
+      }
+
+      assert_eq!(result, expected_result, "{:?} {:?}", input, splits);
+    };
+
+    for split in 0..=input.len() {
+      run(&[split]);
+    }
+
+    run(&(1..input.len()).collect::<Vec<_>>());
+  }
+}
+
+#[test]
+fn decoder_matches_one_shot() {
+  check_decoder(UriDecoder::uri, decode_uri);
+  check_decoder(UriDecoder::component, decode_uri_component);
+}
+
+#[test]
+fn encoder_matches_one_shot() {
+  check_encoder(UriEncoder::uri, encode_uri);
+  check_encoder(UriEncoder::component, encode_uri_component);
+}
+
+#[test]
+fn decoder_holds_split_escapes() {
+  let mut decoder = UriDecoder::component();
+  let mut decoded = Vec::new();
+
+  decoder.feed(b"a%E2%8", &mut decoded).unwrap();
+  assert_eq!(decoded, b"a");
+
+  decoder.feed(b"2%AC", &mut decoded).unwrap();
+  assert_eq!(decoded, "a€".as_bytes());
+
+  decoder.feed(b"%", &mut decoded).unwrap();
+  assert_eq!(decoder.finish().unwrap_err().index(), 10);
+
+  // The decoder is reset after finishing
+  decoder.feed(b"%41", &mut decoded).unwrap();
+  assert!(decoder.finish().is_ok());
+  assert_eq!(decoded, "a€A".as_bytes());
+}
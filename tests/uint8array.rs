@@ -0,0 +1,250 @@
+use draco_utilities::uint8array::{
+  from_base64, from_hex, set_from_base64, set_from_hex, to_base64, to_hex, Base64Alphabet,
+  FromBase64Options, LastChunkHandling, ReadWritten, SyntaxError, ToBase64Options,
+};
+
+fn options(last_chunk_handling: LastChunkHandling) -> FromBase64Options {
+  FromBase64Options {
+    alphabet: Base64Alphabet::Base64,
+    last_chunk_handling,
+  }
+}
+
+fn decode(string: &str, last_chunk_handling: LastChunkHandling) -> Option<Vec<u8>> {
+  let mut decoded = Vec::new();
+
+  from_base64(
+    string.as_bytes(),
+    options(last_chunk_handling),
+    &mut decoded,
+  )
+  .ok()
+  .map(|_| decoded)
+}
+
+mod base64 {
+  use super::*;
+
+  #[test]
+  fn to_base64_alphabets() {
+    let mut encoded = Vec::new();
+
+    to_base64(b"\xFB\xFF", ToBase64Options::default(), &mut encoded);
+    to_base64(
+      b"\xFB\xFF",
+      ToBase64Options {
+        alphabet: Base64Alphabet::Base64Url,
+        omit_padding: false,
+      },
+      &mut encoded,
+    );
+    to_base64(
+      b"\xFB\xFF",
+      ToBase64Options {
+        alphabet: Base64Alphabet::Base64Url,
+        omit_padding: true,
+      },
+      &mut encoded,
+    );
+
+    assert_eq!(encoded, b"+/8=-_8=-_8");
+  }
+
+  #[test]
+  fn from_base64_whitespace() {
+    let expected = Some(b"foobar".to_vec());
+
+    assert_eq!(decode("Zm9vYmFy", LastChunkHandling::Loose), expected);
+    assert_eq!(
+      decode(" Zm9v\tYm\nFy\x0C\r", LastChunkHandling::Strict),
+      expected
+    );
+    assert_eq!(decode("Zm9vYmFy\u{A0}", LastChunkHandling::Loose), None);
+  }
+
+  #[test]
+  fn from_base64_alphabet() {
+    let url = FromBase64Options {
+      alphabet: Base64Alphabet::Base64Url,
+      last_chunk_handling: LastChunkHandling::Loose,
+    };
+    let mut decoded = Vec::new();
+
+    from_base64(b"-_8", url, &mut decoded).unwrap();
+    assert_eq!(decoded, b"\xFB\xFF");
+    assert!(from_base64(b"+/8", url, &mut decoded).is_err());
+    assert!(from_base64(b"-_8", options(LastChunkHandling::Loose), &mut decoded).is_err());
+  }
+
+  #[test]
+  fn from_base64_last_chunk() {
+    use LastChunkHandling::*;
+
+    let cases: &[(&str, [Option<&[u8]>; 3])] = &[
+      ("Zg==", [Some(b"f"), Some(b"f"), Some(b"f")]),
+      ("Zg", [Some(b"f"), None, Some(b"")]),
+      ("Zg=", [None, None, Some(b"")]),
+      ("Zh==", [Some(b"f"), None, Some(b"f")]),
+      ("Zm8=", [Some(b"fo"), Some(b"fo"), Some(b"fo")]),
+      ("Zm9=", [Some(b"fo"), None, Some(b"fo")]),
+      ("Zm8", [Some(b"fo"), None, Some(b"")]),
+      ("Zm9vZ", [None, None, Some(b"foo")]),
+      ("Zm9vZ=", [None, None, None]),
+      ("Zg==Zg==", [None, None, None]),
+      ("Zg=A", [None, None, None]),
+      ("Zg = = ", [Some(b"f"), Some(b"f"), Some(b"f")]),
+      ("=", [None, None, None]),
+      ("", [Some(b""), Some(b""), Some(b"")]),
+    ];
+
+    for (string, expected) in cases {
+      for (handling, expected) in [Loose, Strict, StopBeforePartial].iter().zip(expected) {
+        assert_eq!(
+          decode(string, *handling),
+          expected.map(|bytes| bytes.to_vec()),
+          "{:?} with {:?}",
+          string,
+          handling
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn from_base64_error_leaves_output() {
+    let mut decoded = b"prefix".to_vec();
+    let result = from_base64(b"Zm9vYmFy!", FromBase64Options::default(), &mut decoded);
+
+    assert_eq!(
+      result,
+      Err(SyntaxError {
+        read: 8,
+        written: 6
+      })
+    );
+    assert_eq!(decoded, b"prefix");
+  }
+
+  #[test]
+  fn set_from_base64_target_size() {
+    use LastChunkHandling::*;
+
+    let cases: &[(&str, usize, LastChunkHandling, usize, usize)] = &[
+      ("Zm9vYmFy", 5, Loose, 4, 3),
+      ("Zm9vYmE=", 4, Loose, 4, 3),
+      ("Zm9vYmFy", 6, Loose, 8, 6),
+      ("Zm9vYmE=", 5, Loose, 8, 5),
+      ("Zm9vYmE", 5, Loose, 7, 5),
+      ("Zm9vYmE=", 5, StopBeforePartial, 8, 5),
+      ("Zm9vYmE", 5, StopBeforePartial, 4, 3),
+      ("Zm9vYmE", 8, StopBeforePartial, 4, 3),
+      ("Zm9v", 1, Loose, 0, 0),
+      ("!!!!", 0, Loose, 0, 0),
+    ];
+
+    for &(string, size, handling, read, written) in cases {
+      let mut target = vec![0xFF; size];
+      let result = set_from_base64(string.as_bytes(), options(handling), &mut target);
+
+      assert_eq!(
+        result,
+        Ok(ReadWritten { read, written }),
+        "{:?} into {}",
+        string,
+        size
+      );
+      assert!(target[written..].iter().all(|&byte| byte == 0xFF));
+    }
+  }
+
+  #[test]
+  fn set_from_base64_writes_before_error() {
+    let mut target = [0xFF; 8];
+    let result = set_from_base64(b"Zm9vYmFy=", FromBase64Options::default(), &mut target);
+
+    assert_eq!(
+      result,
+      Err(SyntaxError {
+        read: 8,
+        written: 6
+      })
+    );
+    assert_eq!(&target, b"foobar\xFF\xFF");
+  }
+}
+
+mod hex {
+  use super::*;
+
+  #[test]
+  fn to_hex_lowercase() {
+    let mut encoded = Vec::new();
+
+    to_hex(b"\x00\xCA\xFE", &mut encoded);
+    assert_eq!(encoded, b"00cafe");
+  }
+
+  #[test]
+  fn from_hex_mixed_case() {
+    let mut decoded = Vec::new();
+
+    from_hex(b"CAfe00", &mut decoded).unwrap();
+    assert_eq!(decoded, b"\xCA\xFE\x00");
+  }
+
+  #[test]
+  fn from_hex_invalid() {
+    let mut decoded = Vec::new();
+
+    assert_eq!(
+      from_hex(b"cafe0", &mut decoded),
+      Err(SyntaxError {
+        read: 0,
+        written: 0
+      })
+    );
+    assert_eq!(
+      from_hex(b"ca fe", &mut decoded),
+      Err(SyntaxError {
+        read: 0,
+        written: 0
+      })
+    );
+    assert_eq!(
+      from_hex(b"cafe 0", &mut decoded),
+      Err(SyntaxError {
+        read: 4,
+        written: 2
+      })
+    );
+    assert!(decoded.is_empty());
+  }
+
+  #[test]
+  fn set_from_hex_target_size() {
+    let mut target = [0xFF; 2];
+
+    assert_eq!(
+      set_from_hex(b"aabbcc", &mut target),
+      Ok(ReadWritten {
+        read: 4,
+        written: 2
+      })
+    );
+    assert_eq!(target, [0xAA, 0xBB]);
+  }
+
+  #[test]
+  fn set_from_hex_writes_before_error() {
+    let mut target = [0xFF; 4];
+
+    assert_eq!(
+      set_from_hex(b"aabbxx", &mut target),
+      Err(SyntaxError {
+        read: 4,
+        written: 2
+      })
+    );
+    assert_eq!(target, [0xAA, 0xBB, 0xFF, 0xFF]);
+  }
+}
@@ -1,4 +1,8 @@
-use draco_utilities::globals::unescape;
+use draco_utilities::globals::{
+  escape, unescape, unescape_cow, unescape_to, unescape_utf16, DecodeOutput,
+};
+
+use std::borrow::Cow;
 
 #[test]
 fn unescape_combined() {
@@ -34,7 +38,7 @@ fn unescape_bytes() {
 }
 
 #[test]
-fn unescape_utf16() {
+fn unescape_two_octets() {
   let expected = "\u{0107}";
   let mut bytes = Vec::new();
 
@@ -42,3 +46,107 @@ fn unescape_utf16() {
   let result = std::str::from_utf8(&bytes).unwrap();
   assert_eq!(result, expected);
 }
+
+#[test]
+fn unescape_multi_byte() {
+  let mut bytes = Vec::new();
+
+  unescape(
+    b"%u20AC%uD83D%uDE00%FF%u0100%uD83Dx%uDE00%uD83D",
+    &mut bytes,
+  );
+  assert_eq!(
+    std::str::from_utf8(&bytes).unwrap(),
+    "€😀ÿĀ\u{FFFD}x\u{FFFD}\u{FFFD}"
+  );
+}
+
+#[test]
+fn unescape_lone_surrogates() {
+  let input = b"%uD83D%uDE00%uD83Dx%uDE00%u20AC%E4%uD83D";
+  let mut units = Vec::new();
+
+  // Matches `unescape` in JavaScript
+  unescape_utf16(input, &mut units);
+  assert_eq!(
+    units,
+    [0xD83D, 0xDE00, 0xD83D, 0x78, 0xDE00, 0x20AC, 0xE4, 0xD83D]
+  );
+
+  let mut bytes = Vec::new();
+
+  unescape_to(input, DecodeOutput::Wtf8(&mut bytes));
+  assert_eq!(
+    bytes,
+    b"\xF0\x9F\x98\x80\xED\xA0\xBDx\xED\xB8\x80\xE2\x82\xAC\xC3\xA4\xED\xA0\xBD"
+  );
+}
+
+#[test]
+fn unescape_escape_round_trip() {
+  let strings = [
+    "",
+    "abc123@*_+-./",
+    "a b%c%u0041",
+    "ÿĀ€\u{FFFD}\u{FFFF}",
+    "😀\u{10000}\u{10FFFF}x😀",
+    "\u{7F}\u{80}\u{7FF}\u{800}",
+  ];
+
+  for string in strings.iter() {
+    let mut escaped = Vec::new();
+    let mut unescaped = Vec::new();
+
+    escape(string.as_bytes(), &mut escaped);
+    unescape(&escaped, &mut unescaped);
+    assert_eq!(unescaped, string.as_bytes());
+  }
+
+  let every_char = (0..=0x10FFFF)
+    .step_by(7)
+    .filter_map(std::char::from_u32)
+    .collect::<String>();
+  let mut escaped = Vec::new();
+  let mut unescaped = Vec::new();
+
+  escape(every_char.as_bytes(), &mut escaped);
+  unescape(&escaped, &mut unescaped);
+  assert_eq!(unescaped, every_char.as_bytes());
+}
+
+#[test]
+fn unescape_long_strings() {
+  // Moves an escape through strings longer than a SIMD stride
+  for length in [15, 16, 31, 32, 33, 64, 100] {
+    for position in 0..length {
+      for (special, unescaped) in [
+        ("%20", " "),
+        ("%u0107", "\u{0107}"),
+        ("%u20AC", "€"),
+        ("%uD83D%uDE00", "😀"),
+        ("%zz", "%zz"),
+        ("ä", "ä"),
+      ] {
+        let mut string = "a".repeat(length);
+
+        string.replace_range(position..position + 1, special);
+
+        let mut bytes = Vec::new();
+
+        unescape(string.as_bytes(), &mut bytes);
+        assert_eq!(
+          bytes,
+          string.replace(special, unescaped).as_bytes(),
+          "{:?}",
+          string
+        );
+      }
+    }
+  }
+}
+
+#[test]
+fn unescape_cow_borrows() {
+  assert!(matches!(unescape_cow("abc%zz%u12%"), Cow::Borrowed(_)));
+  assert_eq!(unescape_cow("abc%zz%20%u0107"), "abc%zz \u{0107}");
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use draco_utilities::uri::{TemplateError, UriTemplate, Value};
+use serde_json::Value as Json;
+
+use std::collections::HashMap;
+
+fn string(value: &Json) -> String {
+  match value {
+    Json::String(string) => string.clone(),
+    value => value.to_string(),
+  }
+}
+
+fn variables(json: &Json) -> HashMap<String, Value> {
+  json
+    .as_object()
+    .unwrap()
+    .iter()
+    .map(|(name, value)| {
+      let value = match value {
+        Json::Array(items) => Value::List(items.iter().map(string).collect()),
+        Json::Object(pairs) => Value::Map(
+          pairs
+            .iter()
+            .map(|(key, value)| (key.clone(), string(value)))
+            .collect(),
+        ),
+        value => Value::String(string(value)),
+      };
+
+      (name.clone(), value)
+    })
+    .collect()
+}
+
+fn run_fixture(name: &str) {
+  let groups = common::json_fixture(name);
+  let mut failures = Vec::new();
+
+  for (group, tests) in groups.as_object().unwrap() {
+    let variables = variables(&tests["variables"]);
+
+    for test in tests["testcases"].as_array().unwrap() {
+      let template = test[0].as_str().unwrap();
+      let expanded = UriTemplate::parse(template).map(|template| template.expand(&variables));
+      let passed = match (&expanded, &test[1]) {
+        (Ok(expanded), Json::String(expected)) => expanded == expected,
+        (Ok(expanded), Json::Array(expected)) => expected.iter().any(|e| e == expanded.as_str()),
+        _ => false,
+      };
+
+      if !passed {
+        failures.push(format!(
+          "{}: {:?} expanded to {:?}, expected {}",
+          group, template, expanded, test[1]
+        ));
+      }
+    }
+  }
+
+  assert!(failures.is_empty(), "{:#?}", failures);
+}
+
+#[test]
+fn spec_examples() {
+  run_fixture("spec-examples.json");
+}
+
+#[test]
+fn extended_tests() {
+  run_fixture("extended-tests.json");
+}
+
+#[test]
+fn expand() {
+  let template = UriTemplate::parse("/users{/id}{?fields*,q}{#frag:3}").unwrap();
+  let mut variables = HashMap::new();
+
+  variables.insert("id", Value::from("a b"));
+  variables.insert(
+    "fields",
+    Value::List(vec!["name".to_owned(), "ä".to_owned()]),
+  );
+  variables.insert("frag", Value::from("€uro/%41"));
+
+  assert_eq!(
+    template.expand(&variables),
+    "/users/a%20b?fields=name&fields=%C3%A4#%E2%82%ACur"
+  );
+
+  variables.clear();
+  assert_eq!(template.expand(&variables), "/users");
+
+  let template = UriTemplate::parse("{+path}/x%zz y{&empty}").unwrap();
+
+  variables.insert("path", Value::from("/a%2Fb%zz"));
+  variables.insert("empty", Value::from(""));
+  assert_eq!(template.expand(&variables), "/a%2Fb%25zz/x%25zz%20y&empty=");
+}
+
+#[test]
+fn parse_errors() {
+  let tests = [
+    ("{var", TemplateError::UnbalancedBraces),
+    ("var}", TemplateError::UnbalancedBraces),
+    ("{a{b}", TemplateError::UnbalancedBraces),
+    ("{=var}", TemplateError::ReservedOperator),
+    ("{|var}", TemplateError::ReservedOperator),
+    ("{}", TemplateError::InvalidVariableName),
+    ("{a,}", TemplateError::InvalidVariableName),
+    ("{a..b}", TemplateError::InvalidVariableName),
+    ("{.a.}", TemplateError::InvalidVariableName),
+    ("{a-b}", TemplateError::InvalidVariableName),
+    ("{a%2}", TemplateError::InvalidVariableName),
+    ("{a:0}", TemplateError::InvalidPrefix),
+    ("{a:10000}", TemplateError::InvalidPrefix),
+    ("{a:}", TemplateError::InvalidPrefix),
+  ];
+
+  for (template, error) in tests.iter() {
+    assert_eq!(UriTemplate::parse(template), Err(*error), "{:?}", template);
+  }
+}
@@ -0,0 +1,437 @@
+mod common;
+
+use draco_utilities::url::{
+  Url, UrlPattern, UrlPatternComponentResult, UrlPatternError, UrlPatternInit, UrlPatternOptions,
+  UrlPatternResult,
+};
+use serde_json::Value;
+
+use std::time::{Duration, Instant};
+
+const COMPONENTS: [&str; 8] = [
+  "protocol", "username", "password", "hostname", "port", "pathname", "search", "hash",
+];
+
+fn init(value: &Value) -> UrlPatternInit {
+  let field = |key: &str| value[key].as_str().map(str::to_owned);
+
+  UrlPatternInit {
+    protocol: field("protocol"),
+    username: field("username"),
+    password: field("password"),
+    hostname: field("hostname"),
+    port: field("port"),
+    pathname: field("pathname"),
+    search: field("search"),
+    hash: field("hash"),
+    base_url: field("baseURL"),
+  }
+}
+
+fn patterns(pattern: &UrlPattern) -> [&str; 8] {
+  [
+    pattern.protocol(),
+    pattern.username(),
+    pattern.password(),
+    pattern.hostname(),
+    pattern.port(),
+    pattern.pathname(),
+    pattern.search(),
+    pattern.hash(),
+  ]
+}
+
+fn results(result: &UrlPatternResult) -> [&UrlPatternComponentResult; 8] {
+  [
+    &result.protocol,
+    &result.username,
+    &result.password,
+    &result.hostname,
+    &result.port,
+    &result.pathname,
+    &result.search,
+    &result.hash,
+  ]
+}
+
+/// Returns the pattern of a component that a test doesn't list, which is a wildcard unless it's
+/// taken from the base URL.
+fn default_pattern(test: &Value, component: usize, base_url: Option<&str>) -> String {
+  let name = COMPONENTS[component];
+  let input = &test["pattern"][0];
+
+  if let Some(value) = input[name].as_str() {
+    return value.to_owned();
+  }
+
+  // A component of an init after one that's given matches anything
+  let inherits = match name {
+    "username" | "password" => false,
+    _ => COMPONENTS[..component]
+      .iter()
+      .filter(|name| !matches!(**name, "username" | "password"))
+      .all(|name| input[*name].is_null()),
+  };
+
+  match base_url {
+    Some(base_url) if inherits => {
+      let base_url = Url::parse(base_url, None).unwrap();
+
+      match name {
+        "protocol" => base_url.protocol().trim_end_matches(':').to_owned(),
+        "hostname" => base_url.hostname(),
+        "port" => base_url.port(),
+        "pathname" => base_url.pathname(),
+        "search" => base_url.search().trim_start_matches('?').to_owned(),
+        _ => base_url.hash().trim_start_matches('#').to_owned(),
+      }
+    }
+    _ => "*".to_owned(),
+  }
+}
+
+/// Checks the patterns of each component against the expected values of a test.
+fn check_patterns(test: &Value, pattern: &UrlPattern, base_url: Option<&str>) -> Vec<String> {
+  let empty = &test["exactly_empty_components"];
+  let expected = &test["expected_obj"];
+
+  patterns(pattern)
+    .iter()
+    .enumerate()
+    .filter_map(|(idx, actual)| {
+      let name = COMPONENTS[idx];
+      let is_empty = empty
+        .as_array()
+        .is_some_and(|empty| empty.iter().any(|value| value == name));
+      let expected = match expected[name].as_str() {
+        Some(expected) => expected.to_owned(),
+        None if is_empty => String::new(),
+        None => default_pattern(test, idx, base_url),
+      };
+
+      if *actual == expected {
+        None
+      } else {
+        Some(format!(
+          "{} pattern: expected {:?}, got {:?}",
+          name, expected, actual
+        ))
+      }
+    })
+    .collect()
+}
+
+/// Checks the result of each component against the expected match of a test.
+fn check_result(test: &Value, result: &UrlPatternResult) -> Vec<String> {
+  let empty = &test["exactly_empty_components"];
+  let expected = &test["expected_match"];
+
+  results(result)
+    .iter()
+    .enumerate()
+    .filter_map(|(idx, actual)| {
+      let name = COMPONENTS[idx];
+      let is_empty = empty
+        .as_array()
+        .is_some_and(|empty| empty.iter().any(|value| value == name));
+      let (input, mut groups) = match expected[name].as_object() {
+        Some(expected) => (
+          expected["input"].as_str().unwrap().to_owned(),
+          expected["groups"]
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(name, value)| (name.clone(), value.as_str().map(str::to_owned)))
+            .collect(),
+        ),
+        None if is_empty => (String::new(), Vec::new()),
+        None => (String::new(), vec![("0".to_owned(), Some(String::new()))]),
+      };
+      let mut actual_groups = actual.groups.clone();
+
+      groups.sort();
+      actual_groups.sort();
+
+      if actual.input == input && actual_groups == groups {
+        None
+      } else {
+        Some(format!(
+          "{} result: expected {:?} {:?}, got {:?} {:?}",
+          name, input, groups, actual.input, actual_groups
+        ))
+      }
+    })
+    .collect()
+}
+
+#[test]
+fn wpt_url_pattern_test_data() {
+  let tests = common::json_fixture("urlpatterntestdata.json");
+  let mut failures = Vec::new();
+  let mut passed = 0;
+  let mut skipped = 0;
+
+  for test in tests.as_array().unwrap() {
+    if test.get("skip").is_some() {
+      continue;
+    }
+
+    let args = test["pattern"].as_array().unwrap();
+    let options = UrlPatternOptions {
+      ignore_case: args
+        .iter()
+        .any(|arg| arg["ignoreCase"].as_bool() == Some(true)),
+    };
+    let base_url = args.get(1).and_then(Value::as_str);
+    let expects_error = test["expected_obj"] == "error";
+
+    let input = args.first().unwrap_or(&Value::Null);
+
+    // A base URL after the options, or along with an init, is a TypeError in JavaScript that
+    // can't be represented
+    if (input.is_string() && args.get(2).is_some_and(Value::is_string))
+      || (!input.is_string() && base_url.is_some())
+    {
+      skipped += 1;
+      continue;
+    }
+
+    let pattern = match input {
+      Value::String(input) => UrlPattern::parse(input, base_url, options),
+      input => UrlPattern::from_init(&init(input), options),
+    };
+
+    let errors = match (&pattern, expects_error) {
+      (Ok(pattern), false) => {
+        let base_url = base_url.or_else(|| test["pattern"][0]["baseURL"].as_str());
+        let mut errors = check_patterns(test, pattern, base_url);
+        let inputs = test["inputs"].as_array().map_or(&[][..], Vec::as_slice);
+
+        let result = match inputs {
+          [] => pattern.exec_init(&UrlPatternInit::default()),
+          [Value::String(input)] => pattern.exec(input, None),
+          [Value::String(input), Value::String(base_url)] => pattern.exec(input, Some(base_url)),
+          [input] => pattern.exec_init(&init(input)),
+          // An init can't be matched against a separate base URL
+          _ => {
+            skipped += 1;
+            continue;
+          }
+        };
+
+        match (&result, &test["expected_match"]) {
+          (Some(result), Value::Object(_)) => errors.extend(check_result(test, result)),
+          (None, Value::Object(_)) => errors.push("expected a match".to_owned()),
+          (Some(_), _) => errors.push("unexpected match".to_owned()),
+          (None, _) => {}
+        }
+
+        errors
+      }
+      (Ok(_), true) => vec!["expected an error".to_owned()],
+      (Err(error), false) => vec![format!("unexpected error {}", error)],
+      (Err(_), true) => Vec::new(),
+    };
+
+    if errors.is_empty() {
+      passed += 1;
+    } else {
+      failures.push(format!(
+        "{} with {}: {}",
+        test["pattern"],
+        test["inputs"],
+        errors.join(", ")
+      ));
+    }
+  }
+
+  assert!(
+    failures.is_empty(),
+    "{}\n{} failed",
+    failures.join("\n"),
+    failures.len()
+  );
+  assert!(passed > 300, "only {} tests passed", passed);
+  assert!(skipped < 10, "{} tests were skipped", skipped);
+}
+
+#[test]
+fn named_groups() {
+  let pattern = UrlPattern::parse(
+    "https://:tenant.example.com/users/:id/posts/:post?",
+    None,
+    UrlPatternOptions::default(),
+  )
+  .unwrap();
+
+  let result = pattern
+    .exec("https://acme.example.com/users/42/posts", None)
+    .unwrap();
+
+  assert_eq!(result.hostname.group("tenant"), Some("acme"));
+  assert_eq!(result.pathname.group("id"), Some("42"));
+  assert_eq!(result.pathname.group("post"), None);
+  assert_eq!(result.pathname.input, "/users/42/posts");
+
+  assert!(pattern.test("https://acme.example.com/users/42/posts/7", None));
+  assert!(!pattern.test("https://acme.example.com/users/42/7", None));
+  assert!(!pattern.test("https://example.com/users/42/posts", None));
+  assert!(!pattern.test("not a url", None));
+}
+
+#[test]
+fn regexp_groups_and_wildcards() {
+  let pattern = UrlPattern::from_init(
+    &UrlPatternInit {
+      pathname: Some("/files/:id(\\d+)/*".to_owned()),
+      ..UrlPatternInit::default()
+    },
+    UrlPatternOptions::default(),
+  )
+  .unwrap();
+
+  assert!(pattern.has_regexp_groups());
+  assert_eq!(pattern.pathname(), "/files/:id(\\d+)/*");
+
+  let result = pattern
+    .exec("http://example.com/files/12/a/b.txt?raw", None)
+    .unwrap();
+
+  assert_eq!(result.pathname.group("id"), Some("12"));
+  assert_eq!(result.pathname.group("0"), Some("a/b.txt"));
+  assert_eq!(result.search.group("0"), Some("raw"));
+
+  assert!(!pattern.test("http://example.com/files/abc/b.txt", None));
+}
+
+#[test]
+fn modifiers() {
+  let pattern = UrlPattern::from_init(
+    &UrlPatternInit {
+      pathname: Some("/docs{/:section}*".to_owned()),
+      ..UrlPatternInit::default()
+    },
+    UrlPatternOptions::default(),
+  )
+  .unwrap();
+
+  let groups = |input: &str| {
+    let url = Url::parse(input, None).unwrap();
+
+    pattern
+      .exec_url(&url)
+      .map(|result| result.pathname.group("section").map(str::to_owned))
+  };
+
+  assert_eq!(groups("https://example.com/docs"), Some(None));
+  assert_eq!(
+    groups("https://example.com/docs/a"),
+    Some(Some("a".to_owned()))
+  );
+  assert_eq!(
+    groups("https://example.com/docs/a/b"),
+    Some(Some("a/b".to_owned()))
+  );
+  assert_eq!(groups("https://example.com/docsa"), None);
+}
+
+#[test]
+fn long_inputs() {
+  // Matching backtracks on the heap, so inputs of any length can't overflow the stack
+  let segments = "/a".repeat(1 << 19);
+  let tests = [
+    ("https://example.com/files/*", "0"),
+    ("https://example.com/files/:name", "name"),
+    ("https://example.com/files{/:segment}*", "segment"),
+  ];
+
+  for (pattern, group) in tests.iter() {
+    let pattern = UrlPattern::parse(pattern, None, UrlPatternOptions::default()).unwrap();
+    let input = match *group {
+      "name" => format!("https://example.com/files/{}", "a".repeat(1 << 20)),
+      _ => format!("https://example.com/files{}", segments),
+    };
+    let result = pattern.exec(&input, None).unwrap();
+
+    assert_eq!(
+      result.pathname.group(group).unwrap().len(),
+      input.len() - "https://example.com/files/".len(),
+      "{}",
+      pattern.pathname()
+    );
+    assert!(!pattern.test(&format!("{}?", input.replace("files", "file")), None));
+  }
+}
+
+#[test]
+fn catastrophic_backtracking() {
+  // Nested repeats are exponential in the input, until the step budget gives up
+  let pattern = UrlPattern::parse(
+    "https://example.com/:path((?:a+)+b)",
+    None,
+    UrlPatternOptions::default(),
+  )
+  .unwrap();
+  let input = format!("https://example.com/{}c", "a".repeat(40));
+  let start = Instant::now();
+
+  assert!(!pattern.test(&input, None));
+  assert!(start.elapsed() < Duration::from_secs(5));
+  assert!(pattern.test("https://example.com/aaab", None));
+}
+
+#[test]
+fn relative_patterns() {
+  let pattern = UrlPattern::parse(
+    "/api/:version/*",
+    Some("https://example.com"),
+    UrlPatternOptions { ignore_case: true },
+  )
+  .unwrap();
+
+  assert_eq!(pattern.protocol(), "https");
+  assert_eq!(pattern.hostname(), "example.com");
+  assert!(pattern.test("https://example.com/API/v2/users", None));
+  assert!(pattern.test("/api/v2/users", Some("https://example.com")));
+  assert!(!pattern.test("https://example.org/api/v2/users", None));
+
+  assert_eq!(
+    UrlPattern::parse("/api/*", None, UrlPatternOptions::default()).unwrap_err(),
+    UrlPatternError::BaseUrlRequired
+  );
+}
+
+#[test]
+fn pattern_errors() {
+  let errors = [
+    ("/:id/:id", UrlPatternError::InvalidPattern),
+    ("/(foo", UrlPatternError::InvalidPattern),
+    ("/{/:id", UrlPatternError::InvalidPattern),
+    ("/(a(b))", UrlPatternError::InvalidPattern),
+    ("/([a-)", UrlPatternError::InvalidRegExp),
+  ];
+
+  for (pathname, error) in errors.iter() {
+    let init = UrlPatternInit {
+      pathname: Some((*pathname).to_owned()),
+      ..UrlPatternInit::default()
+    };
+
+    assert_eq!(
+      UrlPattern::from_init(&init, UrlPatternOptions::default()).unwrap_err(),
+      *error,
+      "{}",
+      pathname
+    );
+  }
+
+  let init = UrlPatternInit {
+    port: Some("port".to_owned()),
+    ..UrlPatternInit::default()
+  };
+
+  assert_eq!(
+    UrlPattern::from_init(&init, UrlPatternOptions::default()).unwrap_err(),
+    UrlPatternError::InvalidComponent
+  );
+}
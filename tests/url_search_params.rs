@@ -0,0 +1,121 @@
+use draco_utilities::url::{parse_urlencoded, serialize_urlencoded, Url, UrlSearchParams};
+
+#[test]
+fn parse() {
+  let pairs = parse_urlencoded(b"a=1&b=2+3&a=%zz&&c&=d&e=%FF%41&%E2%82%AC=%F0%9F%98%80");
+  let expected = &[
+    ("a", "1"),
+    ("b", "2 3"),
+    ("a", "%zz"),
+    ("c", ""),
+    ("", "d"),
+    ("e", "\u{FFFD}A"),
+    ("€", "😀"),
+  ];
+
+  assert_eq!(pairs.len(), expected.len());
+
+  for ((name, value), &(expected_name, expected_value)) in pairs.iter().zip(expected) {
+    assert_eq!(name, expected_name);
+    assert_eq!(value, expected_value);
+  }
+}
+
+#[test]
+fn serialize() {
+  let mut serialized = String::new();
+
+  serialize_urlencoded(vec![("a b", "~!*()'-._ +&=é"), ("", "")], &mut serialized);
+
+  assert_eq!(serialized, "a+b=%7E%21*%28%29%27-._+%2B%26%3D%C3%A9&=");
+}
+
+#[test]
+fn round_trip() {
+  let params = UrlSearchParams::new("?a=1&b=2+3&a=%zz&&c&=d&e=%FF%41");
+
+  assert_eq!(params.size(), 6);
+  assert_eq!(params.to_string(), "a=1&b=2+3&a=%25zz&c=&=d&e=%EF%BF%BDA");
+  assert_eq!(UrlSearchParams::new(&params.to_string()), params);
+}
+
+#[test]
+fn get_and_has() {
+  let params = UrlSearchParams::new("a=1&b=2&a=3");
+
+  assert_eq!(params.get("a"), Some("1"));
+  assert_eq!(params.get("c"), None);
+  assert_eq!(params.get_all("a"), vec!["1", "3"]);
+  assert!(params.get_all("c").is_empty());
+  assert!(params.has("b", None));
+  assert!(params.has("a", Some("3")));
+  assert!(!params.has("a", Some("2")));
+}
+
+#[test]
+fn set_and_delete() {
+  let mut params = UrlSearchParams::new("a=1&b=2&a=3&a=4");
+
+  params.set("a", "x");
+  assert_eq!(params.to_string(), "a=x&b=2");
+
+  params.set("c", "y");
+  assert_eq!(params.to_string(), "a=x&b=2&c=y");
+
+  params.delete("b", Some("3"));
+  assert_eq!(params.to_string(), "a=x&b=2&c=y");
+
+  params.delete("b", Some("2"));
+  params.delete("c", None);
+  assert_eq!(params.to_string(), "a=x");
+
+  params.append("a", "z");
+  assert_eq!(
+    params.iter().collect::<Vec<_>>(),
+    vec![("a", "x"), ("a", "z")]
+  );
+}
+
+#[test]
+fn sort() {
+  let mut params = UrlSearchParams::new("z=1&a=2&\u{FFFD}=3&\u{1F600}=4&a=5&b=6");
+
+  params.sort();
+
+  // Astral characters are surrogate pairs, which sort before U+FFFD in UTF-16
+  assert_eq!(
+    params.iter().collect::<Vec<_>>(),
+    vec![
+      ("a", "2"),
+      ("a", "5"),
+      ("b", "6"),
+      ("z", "1"),
+      ("\u{1F600}", "4"),
+      ("\u{FFFD}", "3")
+    ]
+  );
+}
+
+#[test]
+fn from_pairs() {
+  let params = vec![("q", "rust url"), ("page", "2")]
+    .into_iter()
+    .collect::<UrlSearchParams>();
+
+  assert_eq!(params.to_string(), "q=rust+url&page=2");
+}
+
+#[test]
+fn url_search_params() {
+  let mut url = Url::parse("https://example.com/?a=b+c&d", None).unwrap();
+  let mut params = url.search_params();
+
+  assert_eq!(params.get("a"), Some("b c"));
+
+  params.append("e", "f&g");
+  url.set_search_params(&params);
+  assert_eq!(url.href(), "https://example.com/?a=b+c&d=&e=f%26g");
+
+  url.set_search_params(&UrlSearchParams::default());
+  assert_eq!(url.href(), "https://example.com/");
+}
@@ -0,0 +1,114 @@
+use draco_utilities::utf7::{
+  decode_imap_utf7, decode_utf7, encode_imap_utf7, encode_utf7, Utf7Error,
+};
+
+#[test]
+fn encode_utf7_string() {
+  let cases: &[(&str, &str)] = &[
+    ("Hi Mom -\u{263A}-!", "Hi Mom -+Jjo--!"),
+    ("\u{65E5}\u{672C}\u{8A9E}", "+ZeVnLIqe-"),
+    ("A\u{2262}\u{0391}.", "A+ImIDkQ."),
+    ("a+b", "a+-b"),
+    ("~\\", "+AH4AXA-"),
+    ("\u{1F600}", "+2D3eAA-"),
+    ("\u{e9}-", "+AOk--"),
+    ("\u{e9}a", "+AOk-a"),
+  ];
+
+  for (string, expected) in cases {
+    let mut encoded = String::new();
+
+    encode_utf7(string, &mut encoded);
+    assert_eq!(encoded, *expected);
+  }
+}
+
+#[test]
+fn decode_utf7_string() {
+  let cases: &[(&str, &str)] = &[
+    ("Hi Mom -+Jjo--!", "Hi Mom -\u{263A}-!"),
+    ("+ZeVnLIqe-", "\u{65E5}\u{672C}\u{8A9E}"),
+    ("A+ImIDkQ.", "A\u{2262}\u{0391}."),
+    ("a+-b", "a+b"),
+    ("+AOk", "\u{e9}"),
+    ("+2D3eAA-", "\u{1F600}"),
+  ];
+
+  for (string, expected) in cases {
+    let mut decoded = String::new();
+
+    decode_utf7(string.as_bytes(), &mut decoded).unwrap();
+    assert_eq!(decoded, *expected);
+  }
+}
+
+#[test]
+fn decode_invalid_utf7() {
+  let cases: &[(&[u8], Utf7Error)] = &[
+    (b"+!", Utf7Error::InvalidShift),
+    (b"+AOkA-", Utf7Error::InvalidShift),
+    (b"+AOl-", Utf7Error::InvalidShift),
+    (b"+2D3-", Utf7Error::InvalidShift),
+    (b"+2D0-", Utf7Error::UnpairedSurrogate),
+    (b"caf\xC3\xA9", Utf7Error::InvalidCharacter),
+    (b"+AOkA", Utf7Error::UnterminatedShift),
+    (b"+AOl", Utf7Error::UnterminatedShift),
+    (b"+2D3", Utf7Error::UnterminatedShift),
+    (b"+2D0", Utf7Error::UnpairedSurrogate),
+    (b"+", Utf7Error::UnterminatedShift),
+    (b"a+", Utf7Error::UnterminatedShift),
+    (b"a+!", Utf7Error::InvalidShift),
+  ];
+
+  for (bytes, expected) in cases {
+    let mut decoded = b"x".to_vec();
+
+    assert_eq!(decode_utf7(bytes, &mut decoded), Err(*expected));
+    assert_eq!(decoded, b"x", "{:?}", bytes);
+  }
+}
+
+#[test]
+fn encode_imap_utf7_string() {
+  let cases: &[(&str, &str)] = &[
+    (
+      "~peter/mail/\u{65E5}\u{672C}\u{8A9E}/\u{53F0}\u{5317}",
+      "~peter/mail/&ZeVnLIqe-/&U,BTFw-",
+    ),
+    ("Tom & Jerry", "Tom &- Jerry"),
+    ("Entw\u{fc}rfe", "Entw&APw-rfe"),
+    ("\u{1F600}", "&2D3eAA-"),
+  ];
+
+  for (string, expected) in cases {
+    let mut encoded = String::new();
+
+    encode_imap_utf7(string, &mut encoded);
+    assert_eq!(encoded, *expected);
+
+    let mut decoded = String::new();
+
+    decode_imap_utf7(encoded.as_bytes(), &mut decoded).unwrap();
+    assert_eq!(decoded, *string);
+  }
+}
+
+#[test]
+fn decode_invalid_imap_utf7() {
+  let cases: &[(&[u8], Utf7Error)] = &[
+    (b"&ZeVnLIqe", Utf7Error::UnterminatedShift),
+    (b"&ZeVnLIqe/-", Utf7Error::UnterminatedShift),
+    (b"&AGE-", Utf7Error::InvalidShift),
+    (b"&APw-&APw-", Utf7Error::InvalidShift),
+    (b"&U,BTF-", Utf7Error::InvalidShift),
+    (b"&2D0-", Utf7Error::UnpairedSurrogate),
+    (b"tab\there", Utf7Error::InvalidCharacter),
+  ];
+
+  for (bytes, expected) in cases {
+    let mut decoded = b"x".to_vec();
+
+    assert_eq!(decode_imap_utf7(bytes, &mut decoded), Err(*expected));
+    assert_eq!(decoded, b"x", "{:?}", bytes);
+  }
+}
@@ -0,0 +1,167 @@
+use draco_utilities::globals::utils::decode_code_point;
+use draco_utilities::globals::{
+  decode_uri_component, encode_uri_component, validate_utf8, UriErrorKind,
+};
+
+/// A xorshift generator, so that the generated inputs are the same on every run.
+struct Random(u64);
+
+impl Random {
+  fn next(&mut self) -> u64 {
+    self.0 ^= self.0 << 13;
+    self.0 ^= self.0 >> 7;
+    self.0 ^= self.0 << 17;
+    self.0
+  }
+
+  fn below(&mut self, max: usize) -> usize {
+    (self.next() % max as u64) as usize
+  }
+}
+
+/// Sequences that are valid on their own, or a part of one.
+const PIECES: &[&[u8]] = &[
+  b"a",
+  b"%",
+  b"0123456789abcdef",
+  "é".as_bytes(),
+  "€".as_bytes(),
+  "😀".as_bytes(),
+  "\u{10FFFF}".as_bytes(),
+  b"\xC0\x80",
+  b"\xC1\xBF",
+  b"\xE0\x9F\xBF",
+  b"\xED\xA0\x80",
+  b"\xED\xBF\xBF",
+  b"\xF0\x8F\xBF\xBF",
+  b"\xF4\x90\x80\x80",
+  b"\xF5\x80\x80\x80",
+  b"\xF8",
+  b"\xFF",
+  b"\x80",
+  b"\xE2\x82",
+  b"\xF0\x9F\x98",
+];
+
+#[test]
+fn validate_matches_std() {
+  let mut random = Random(0x2545_F491_4F6C_DD1D);
+
+  for _ in 0..20_000 {
+    let mut input = Vec::new();
+    let length = random.below(160);
+
+    // Mostly valid input, so that the invalid sequences are at every offset of a SIMD chunk
+    while input.len() < length {
+      let piece = if random.below(8) == 0 {
+        PIECES[random.below(PIECES.len())]
+      } else {
+        PIECES[random.below(7)]
+      };
+
+      input.extend_from_slice(piece);
+    }
+
+    let expected = std::str::from_utf8(&input)
+      .map(|_| ())
+      .map_err(|error| error.valid_up_to());
+    let actual = validate_utf8(&input).map_err(|error| error.index());
+
+    assert_eq!(actual, expected, "{:?}", input);
+  }
+}
+
+#[test]
+fn validate_error_kinds() {
+  let cases: &[(&[u8], UriErrorKind, &[u8])] = &[
+    (b"\xC0\x80", UriErrorKind::Overlong, b"\xC0\x80"),
+    (b"\xE0\x80\xAF", UriErrorKind::Overlong, b"\xE0\x80\xAF"),
+    (
+      b"\xF0\x80\x80\xAF",
+      UriErrorKind::Overlong,
+      b"\xF0\x80\x80\xAF",
+    ),
+    (b"\xED\xA0\x80", UriErrorKind::Surrogate, b"\xED\xA0\x80"),
+    (
+      b"\xF4\x90\x80\x80",
+      UriErrorKind::OutOfRange,
+      b"\xF4\x90\x80\x80",
+    ),
+    (b"\xF8\x88\x80\x80", UriErrorKind::InvalidLeadByte, b"\xF8"),
+    (b"\xBF", UriErrorKind::InvalidLeadByte, b"\xBF"),
+    (
+      b"\xE2\x82x",
+      UriErrorKind::InvalidContinuation,
+      b"\xE2\x82x",
+    ),
+    (b"\xE2\x82", UriErrorKind::Truncated, b"\xE2\x82"),
+  ];
+
+  for &(sequence, kind, expected) in cases {
+    // Long inputs are checked with SIMD first
+    for &padding in &[0, 15, 31, 100] {
+      let mut input = vec![b'a'; padding];
+
+      input.extend_from_slice("é".as_bytes());
+      input.extend_from_slice(sequence);
+
+      // A sequence is only cut off at the end of the input
+      if kind != UriErrorKind::Truncated {
+        input.extend_from_slice(&vec![b'b'; padding]);
+      }
+
+      let error = validate_utf8(&input).unwrap_err();
+
+      assert_eq!(error.kind(), kind, "{:?}", input);
+      assert_eq!(error.index(), padding + 2, "{:?}", input);
+      assert_eq!(error.sequence(), expected, "{:?}", input);
+    }
+  }
+}
+
+#[test]
+fn decode_code_points() {
+  assert_eq!(decode_code_point(b"a", false), Ok((0x61, 1)));
+  assert_eq!(decode_code_point("😀!".as_bytes(), false), Ok((0x1F600, 4)));
+  assert_eq!(decode_code_point(b"\xED\xA0\x80", true), Ok((0xD800, 3)));
+  assert_eq!(
+    decode_code_point(b"\xED\xA0\x80", false),
+    Err((UriErrorKind::Surrogate, 3))
+  );
+  assert_eq!(
+    decode_code_point(b"\xE2\x82", false),
+    Err((UriErrorKind::Truncated, 2))
+  );
+  assert_eq!(
+    decode_code_point(b"", false),
+    Err((UriErrorKind::Truncated, 0))
+  );
+}
+
+#[test]
+fn encoders_and_decoders_validate() {
+  let mut output = Vec::new();
+  let inputs: &[&[u8]] = &[
+    b"\xC0\x80",
+    b"\xF4\x90\x80\x80",
+    b"\xED\xA0\x80",
+    b"\xED\xBF\xBF",
+  ];
+
+  for &input in inputs {
+    let mut long = b"/path?".repeat(20);
+
+    long.extend_from_slice(input);
+
+    assert!(encode_uri_component(input, &mut output).is_err());
+    assert!(encode_uri_component(&long, &mut output).is_err());
+    assert!(decode_uri_component(input, &mut output).is_err());
+    assert!(decode_uri_component(&long, &mut output).is_err());
+  }
+
+  // The escapes before an invalid sequence are decoded first
+  let error = decode_uri_component(b"%4G\xC0\x80", &mut output).unwrap_err();
+
+  assert_eq!(error.kind(), UriErrorKind::InvalidHexDigit);
+  assert_eq!(error.index(), 0);
+}
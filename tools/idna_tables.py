@@ -0,0 +1,270 @@
+"""Generates src/idna/tables.rs.
+
+Usage:
+    python3.9 tools/idna_tables.py IdnaMappingTable.txt joining_type_tables.rs > src/idna/tables.rs
+
+The IDNA mapping table is the one of UTS #46 13.0.0, from
+https://www.unicode.org/Public/idna/13.0.0/IdnaMappingTable.txt. Normalization, general categories
+and bidi classes come from the `unicodedata` module, which has to be built from version 13.0.0 of
+the Unicode Character Database, as Python 3.9 and 3.10 are. Joining types come from
+src/joining_type_tables.rs of the unicode-joining-type 0.7.0 crate, and only the ones of code points
+assigned in 13.0.0 are kept.
+"""
+
+import re
+import sys
+import unicodedata
+
+UNICODE_VERSION = "13.0.0"
+JOINING_TYPE_VERSION = "15.0.0"
+
+if len(sys.argv) != 3:
+    sys.exit(__doc__)
+
+if unicodedata.unidata_version != UNICODE_VERSION:
+    sys.exit("unicodedata has version %s of the Unicode Character Database instead of %s"
+             % (unicodedata.unidata_version, UNICODE_VERSION))
+
+MAPPING_TABLE = sys.argv[1]
+JOINING_TABLE = sys.argv[2]
+
+mapping_source = open(MAPPING_TABLE, encoding="utf-8").read()
+joining_source = open(JOINING_TABLE, encoding="utf-8").read()
+
+if "# Version: %s\n" % UNICODE_VERSION not in mapping_source:
+    sys.exit("%s isn't version %s" % (MAPPING_TABLE, UNICODE_VERSION))
+
+if "// Unicode version: %s." % JOINING_TYPE_VERSION not in joining_source:
+    sys.exit("%s isn't version %s" % (JOINING_TABLE, JOINING_TYPE_VERSION))
+
+out = []
+w = out.append
+
+w("// This file is generated by tools/idna_tables.py from IdnaMappingTable.txt %s and version"
+  % UNICODE_VERSION)
+w("// %s of the Unicode Character Database. Do not edit it by hand." % UNICODE_VERSION)
+w("")
+w("use crate::idna::properties::BidiClass::{self, *};")
+w("use crate::idna::properties::JoiningType;")
+w("use crate::idna::properties::Status::{self, *};")
+w("")
+
+# IDNA mapping
+strings = []
+string_offsets = {}
+string_len = 0
+
+
+def intern(mapping):
+    global string_len
+    if mapping not in string_offsets:
+        string_offsets[mapping] = string_len
+        strings.append(mapping)
+        string_len += len(mapping.encode("utf-8"))
+    return string_offsets[mapping], len(mapping.encode("utf-8"))
+
+
+rows = []
+for line in mapping_source.splitlines():
+    line = line.split("#")[0].strip()
+    if not line:
+        continue
+    fields = [field.strip() for field in line.split(";")]
+    bounds = fields[0].split("..")
+    start = int(bounds[0], 16)
+    end = int(bounds[-1], 16)
+    status = fields[1]
+    mapping = None
+    if status in ("mapped", "deviation", "disallowed_STD3_mapped"):
+        text = fields[2] if len(fields) > 2 else ""
+        mapping = "".join(chr(int(cp, 16)) for cp in text.split())
+    rows.append((start, end, status, mapping))
+
+merged = []
+for start, end, status, mapping in rows:
+    if merged and merged[-1][2] == status and merged[-1][3] == mapping and merged[-1][1] + 1 == start:
+        merged[-1] = (merged[-1][0], end, status, mapping)
+    else:
+        merged.append((start, end, status, mapping))
+
+names = {
+    "valid": "Valid",
+    "ignored": "Ignored",
+    "mapped": "Mapped",
+    "deviation": "Deviation",
+    "disallowed": "Disallowed",
+    "disallowed_STD3_valid": "DisallowedStd3Valid",
+    "disallowed_STD3_mapped": "DisallowedStd3Mapped",
+}
+
+entries = []
+for start, end, status, mapping in merged:
+    name = names[status]
+    if mapping is None:
+        entries.append("  (0x%04X, %s)," % (start, name))
+    else:
+        offset, length = intern(mapping)
+        entries.append("  (0x%04X, %s(%d, %d))," % (start, name, offset, length))
+
+assert string_len < 65536
+
+w("/// The status of each range of code points, which ends where the next one starts.")
+w("pub(crate) const MAPPING: &[(u32, Status)] = &[")
+out.extend(entries)
+w("];")
+w("")
+
+
+def rust_string(text):
+    escaped = []
+    for ch in text:
+        cp = ord(ch)
+        if ch == '"' or ch == "\\":
+            escaped.append("\\" + ch)
+        elif cp < 0x20 or cp == 0x7F or unicodedata.category(ch) in ("Mn", "Me", "Mc", "Cf", "Zs", "Cn", "Co", "Zl", "Zp", "Cs") and cp > 0x7F:
+            escaped.append("\\u{%X}" % cp)
+        else:
+            escaped.append(ch)
+    return "".join(escaped)
+
+
+def string_const(name, doc, parts):
+    w("/// " + doc)
+    w("pub(crate) const %s: &str = concat!(" % name)
+    for part in parts:
+        w('  "%s",' % rust_string(part))
+    w(");")
+    w("")
+
+
+string_const("MAPPED", "The strings that code points are mapped to.", strings)
+
+# Normalization
+ccc_ranges = []
+for cp in range(0x110000):
+    ccc = unicodedata.combining(chr(cp))
+    if ccc == 0:
+        continue
+    if ccc_ranges and ccc_ranges[-1][2] == ccc and ccc_ranges[-1][1] + 1 == cp:
+        ccc_ranges[-1][1] = cp
+    else:
+        ccc_ranges.append([cp, cp, ccc])
+
+w("/// The ranges of code points with a non-zero canonical combining class.")
+w("pub(crate) const COMBINING_CLASS: &[(u32, u32, u8)] = &[")
+for start, end, ccc in ccc_ranges:
+    w("  (0x%04X, 0x%04X, %d)," % (start, end, ccc))
+w("];")
+w("")
+
+
+def is_hangul(cp):
+    return 0xAC00 <= cp <= 0xD7A3
+
+
+decomposed = []
+decomposed_len = 0
+decompositions = []
+compositions = []
+for cp in range(0x110000):
+    if is_hangul(cp) or 0xD800 <= cp <= 0xDFFF:
+        continue
+    ch = chr(cp)
+    raw = unicodedata.decomposition(ch)
+    if not raw or raw.startswith("<"):
+        continue
+    nfd = unicodedata.normalize("NFD", ch)
+    length = len(nfd.encode("utf-8"))
+    decompositions.append("  (0x%04X, %d, %d)," % (cp, decomposed_len, length))
+    decomposed.append(nfd)
+    decomposed_len += length
+    pair = [int(part, 16) for part in raw.split()]
+    if len(pair) == 2 and unicodedata.normalize("NFC", nfd) == ch:
+        compositions.append((pair[0], pair[1], cp))
+
+assert decomposed_len < 65536
+
+w("/// The full canonical decomposition of each code point, besides Hangul syllables, as an")
+w("/// offset and a length in `DECOMPOSED`.")
+w("pub(crate) const DECOMPOSITION: &[(u32, u16, u8)] = &[")
+out.extend(decompositions)
+w("];")
+w("")
+string_const("DECOMPOSED", "The canonical decompositions of code points.", decomposed)
+
+compositions.sort()
+w("/// The primary composite of each pair of code points, besides Hangul syllables.")
+w("pub(crate) const COMPOSITION: &[(u32, u32, u32)] = &[")
+for first, second, composite in compositions:
+    w("  (0x%04X, 0x%04X, 0x%04X)," % (first, second, composite))
+w("];")
+w("")
+
+# Marks
+marks = []
+for cp in range(0x110000):
+    if unicodedata.category(chr(cp)).startswith("M"):
+        if marks and marks[-1][1] + 1 == cp:
+            marks[-1][1] = cp
+        else:
+            marks.append([cp, cp])
+
+w("/// The ranges of code points with a `Mark` general category.")
+w("pub(crate) const MARKS: &[(u32, u32)] = &[")
+for start, end in marks:
+    w("  (0x%04X, 0x%04X)," % (start, end))
+w("];")
+w("")
+
+# Bidi classes
+bidi = []
+for cp in range(0x110000):
+    cls = unicodedata.bidirectional(chr(cp))
+    if cls in ("", "L"):
+        continue
+    if bidi and bidi[-1][2] == cls and bidi[-1][1] + 1 == cp:
+        bidi[-1][1] = cp
+    else:
+        bidi.append([cp, cp, cls])
+
+bidi_names = {
+    "R": "R", "AL": "AL", "AN": "AN", "EN": "EN", "ES": "ES", "CS": "CS", "ET": "ET",
+    "ON": "ON", "BN": "BN", "NSM": "NSM",
+}
+w("/// The ranges of code points with a bidi class other than `L`.")
+w("pub(crate) const BIDI_CLASS: &[(u32, u32, BidiClass)] = &[")
+for start, end, cls in bidi:
+    w("  (0x%04X, 0x%04X, %s)," % (start, end, bidi_names.get(cls, "Other")))
+w("];")
+w("")
+
+# Joining types
+joining_names = {
+    "DualJoining": "D",
+    "JoinCausing": "C",
+    "LeftJoining": "L",
+    "RightJoining": "R",
+    "Transparent": "T",
+}
+joining = []
+pattern = re.compile(r"\((\d+), (\d+), JoiningType::(\w+)\)")
+for match in pattern.finditer(joining_source):
+    start, end, kind = int(match.group(1)), int(match.group(2)), match.group(3)
+    if kind == "NonJoining":
+        continue
+    for cp in range(start, end + 1):
+        # Code points assigned after the pinned version are left out
+        if unicodedata.category(chr(cp)) == "Cn":
+            continue
+        if joining and joining[-1][2] == joining_names[kind] and joining[-1][1] + 1 == cp:
+            joining[-1][1] = cp
+        else:
+            joining.append([cp, cp, joining_names[kind]])
+
+w("/// The ranges of code points with a joining type other than `U`.")
+w("pub(crate) const JOINING_TYPE: &[(u32, u32, JoiningType)] = &[")
+for start, end, kind in joining:
+    w("  (0x%04X, 0x%04X, JoiningType::%s)," % (start, end, kind))
+w("];")
+
+sys.stdout.write("\n".join(out) + "\n")